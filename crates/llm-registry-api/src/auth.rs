@@ -1,14 +1,18 @@
 //! Authentication middleware
 //!
 //! This module provides JWT-based authentication middleware for protecting API routes.
+//! With an observatory configured, every allow or deny decision made by
+//! [`require_auth`] and [`require_role`] is emitted as an `AccessDecision`
+//! governance event.
 
 use axum::{
     body::Body,
-    extract::{Request, State},
+    extract::{OriginalUri, Request, State},
     http::{header::AUTHORIZATION, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use llm_registry_service::adapters::ObservatoryAdapter;
 use std::sync::Arc;
 use tracing::{debug, warn};
 
@@ -56,10 +60,14 @@ impl AuthUser {
     }
 }
 
+/// Principal recorded for requests denied before a token was validated
+pub const ANONYMOUS_PRINCIPAL: &str = "anonymous";
+
 /// Authentication state containing JWT manager
 #[derive(Clone)]
 pub struct AuthState {
     jwt_manager: Arc<JwtManager>,
+    observatory: Option<Arc<ObservatoryAdapter>>,
 }
 
 impl AuthState {
@@ -67,13 +75,80 @@ impl AuthState {
     pub fn new(jwt_manager: JwtManager) -> Self {
         Self {
             jwt_manager: Arc::new(jwt_manager),
+            observatory: None,
         }
     }
 
+    /// Emit an `AccessDecision` event for every allow or deny decision
+    pub fn with_observatory(mut self, observatory: Arc<ObservatoryAdapter>) -> Self {
+        self.observatory = Some(observatory);
+        self
+    }
+
     /// Get JWT manager reference
     pub fn jwt_manager(&self) -> &JwtManager {
         &self.jwt_manager
     }
+
+    /// Validate the bearer token of `request`
+    fn authenticate(&self, request: &Request) -> Result<Claims, AuthError> {
+        let auth_header = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|h| h.to_str().ok())
+            .ok_or(AuthError::MissingToken)?;
+
+        let token = JwtManager::extract_token_from_header(auth_header)
+            .map_err(|_| AuthError::InvalidToken)?;
+
+        self.jwt_manager.validate_token(token).map_err(|e| match e {
+            TokenError::Expired => AuthError::ExpiredToken,
+            TokenError::NotYetValid => AuthError::InvalidToken,
+            _ => AuthError::InvalidToken,
+        })
+    }
+
+    /// Report an access decision on `target` to the observatory
+    ///
+    /// Every decision is emitted, denials included. The event is only
+    /// buffered; a full batch is flushed in the background rather than on
+    /// the request path.
+    async fn record_decision(&self, target: &AccessTarget, principal: Option<&str>, allowed: bool) {
+        let Some(ref observatory) = self.observatory else {
+            return;
+        };
+        if let Err(e) = observatory
+            .record_access_decision(
+                principal.unwrap_or(ANONYMOUS_PRINCIPAL),
+                &target.resource,
+                &target.action,
+                allowed,
+            )
+            .await
+        {
+            warn!(error = %e, "Failed to emit access decision event");
+        }
+    }
+}
+
+/// Resource path and method an access decision is about
+struct AccessTarget {
+    resource: String,
+    action: String,
+}
+
+impl AccessTarget {
+    fn of(request: &Request) -> Self {
+        // Nested routers see a stripped path; report the one the client used
+        let resource = request
+            .extensions()
+            .get::<OriginalUri>()
+            .map_or_else(|| request.uri().path(), |uri| uri.path());
+        Self {
+            resource: resource.to_string(),
+            action: request.method().to_string(),
+        }
+    }
 }
 
 /// Required authentication middleware
@@ -104,28 +179,19 @@ pub async fn require_auth(
 ) -> Result<Response, AuthError> {
     debug!("Authenticating request");
 
-    // Extract Authorization header
-    let auth_header = request
-        .headers()
-        .get(AUTHORIZATION)
-        .and_then(|h| h.to_str().ok())
-        .ok_or(AuthError::MissingToken)?;
-
-    // Extract token from header
-    let token = JwtManager::extract_token_from_header(auth_header)
-        .map_err(|_| AuthError::InvalidToken)?;
-
-    // Validate token
-    let claims = auth_state
-        .jwt_manager
-        .validate_token(token)
-        .map_err(|e| match e {
-            TokenError::Expired => AuthError::ExpiredToken,
-            TokenError::NotYetValid => AuthError::InvalidToken,
-            _ => AuthError::InvalidToken,
-        })?;
+    let target = AccessTarget::of(&request);
+    let claims = match auth_state.authenticate(&request) {
+        Ok(claims) => claims,
+        Err(e) => {
+            auth_state.record_decision(&target, None, false).await;
+            return Err(e);
+        }
+    };
 
     debug!("User authenticated: {}", claims.sub);
+    auth_state
+        .record_decision(&target, Some(&claims.sub), true)
+        .await;
 
     // Add user to request extensions
     request.extensions_mut().insert(AuthUser::new(claims));
@@ -211,32 +277,29 @@ pub async fn require_role(
     debug!("Authenticating request with role check");
 
     // First authenticate
-    let auth_header = request
-        .headers()
-        .get(AUTHORIZATION)
-        .and_then(|h| h.to_str().ok())
-        .ok_or(AuthError::MissingToken)?;
-
-    let token = JwtManager::extract_token_from_header(auth_header)
-        .map_err(|_| AuthError::InvalidToken)?;
-
-    let claims = auth_state
-        .jwt_manager
-        .validate_token(token)
-        .map_err(|e| match e {
-            TokenError::Expired => AuthError::ExpiredToken,
-            TokenError::NotYetValid => AuthError::InvalidToken,
-            _ => AuthError::InvalidToken,
-        })?;
+    let target = AccessTarget::of(&request);
+    let claims = match auth_state.authenticate(&request) {
+        Ok(claims) => claims,
+        Err(e) => {
+            auth_state.record_decision(&target, None, false).await;
+            return Err(e);
+        }
+    };
 
     // Check roles
     let role_refs: Vec<&str> = allowed_roles.iter().map(|s| s.as_str()).collect();
     if !claims.has_any_role(&role_refs) {
         warn!("User {} lacks required role", claims.sub);
+        auth_state
+            .record_decision(&target, Some(&claims.sub), false)
+            .await;
         return Err(AuthError::InsufficientPermissions);
     }
 
     debug!("User authenticated with role: {}", claims.sub);
+    auth_state
+        .record_decision(&target, Some(&claims.sub), true)
+        .await;
     request.extensions_mut().insert(AuthUser::new(claims));
 
     Ok(next.run(request).await)
@@ -286,32 +349,22 @@ pub enum AuthError {
 impl IntoResponse for AuthError {
     fn into_response(self) -> Response {
         let (status, message) = match self {
-            AuthError::MissingToken => (
-                StatusCode::UNAUTHORIZED,
-                "Missing authentication token",
-            ),
-            AuthError::InvalidToken => (
-                StatusCode::UNAUTHORIZED,
-                "Invalid authentication token",
-            ),
-            AuthError::ExpiredToken => (
-                StatusCode::UNAUTHORIZED,
-                "Authentication token has expired",
-            ),
-            AuthError::Unauthenticated => (
-                StatusCode::UNAUTHORIZED,
-                "Authentication required",
-            ),
-            AuthError::InsufficientPermissions => (
-                StatusCode::FORBIDDEN,
-                "Insufficient permissions",
-            ),
+            AuthError::MissingToken => (StatusCode::UNAUTHORIZED, "Missing authentication token"),
+            AuthError::InvalidToken => (StatusCode::UNAUTHORIZED, "Invalid authentication token"),
+            AuthError::ExpiredToken => {
+                (StatusCode::UNAUTHORIZED, "Authentication token has expired")
+            }
+            AuthError::Unauthenticated => (StatusCode::UNAUTHORIZED, "Authentication required"),
+            AuthError::InsufficientPermissions => {
+                (StatusCode::FORBIDDEN, "Insufficient permissions")
+            }
         };
 
         let error_response = ErrorResponse {
             status: status.as_u16(),
             error: message.to_string(),
             code: None,
+            details: None,
             timestamp: chrono::Utc::now(),
             execution: None,
         };
@@ -346,6 +399,7 @@ mod tests {
         routing::get,
         Router,
     };
+    use llm_registry_service::adapters::GovernanceEvent;
     use tower::ServiceExt;
 
     fn create_test_jwt_manager() -> JwtManager {
@@ -438,12 +492,13 @@ mod tests {
             }
         }
 
-        let app = Router::new()
-            .route("/public", get(handler))
-            .layer(middleware::from_fn_with_state(
-                auth_state.clone(),
-                optional_auth,
-            ));
+        let app =
+            Router::new()
+                .route("/public", get(handler))
+                .layer(middleware::from_fn_with_state(
+                    auth_state.clone(),
+                    optional_auth,
+                ));
 
         let request = Request::builder()
             .uri("/public")
@@ -464,12 +519,13 @@ mod tests {
             "Public content"
         }
 
-        let app = Router::new()
-            .route("/public", get(handler))
-            .layer(middleware::from_fn_with_state(
-                auth_state.clone(),
-                optional_auth,
-            ));
+        let app =
+            Router::new()
+                .route("/public", get(handler))
+                .layer(middleware::from_fn_with_state(
+                    auth_state.clone(),
+                    optional_auth,
+                ));
 
         let request = Request::builder()
             .uri("/public")
@@ -480,6 +536,140 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    fn access_decisions(events: Vec<GovernanceEvent>) -> Vec<(String, String, String, bool)> {
+        events
+            .into_iter()
+            .filter_map(|event| match event {
+                GovernanceEvent::AccessDecision {
+                    principal,
+                    resource,
+                    action,
+                    allowed,
+                } => Some((principal, resource, action, allowed)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_denied_request_emits_one_access_decision() {
+        let observatory = Arc::new(ObservatoryAdapter::default());
+        let auth_state =
+            AuthState::new(create_test_jwt_manager()).with_observatory(observatory.clone());
+
+        let app = Router::new().nest(
+            "/v1",
+            Router::new()
+                .route("/protected", get(protected_handler))
+                .layer(middleware::from_fn_with_state(auth_state, require_auth)),
+        );
+
+        let request = Request::builder()
+            .method("DELETE")
+            .uri("/v1/protected")
+            .header(AUTHORIZATION, "Bearer invalid.token.here")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let decisions = access_decisions(observatory.buffered_events().await);
+        assert_eq!(
+            decisions,
+            [(
+                ANONYMOUS_PRINCIPAL.to_string(),
+                "/v1/protected".to_string(),
+                "DELETE".to_string(),
+                false
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_every_anonymous_denial_is_emitted() {
+        let jwt_manager = create_test_jwt_manager();
+        let token = jwt_manager.generate_token("user123").unwrap();
+        let observatory = Arc::new(ObservatoryAdapter::default());
+        let auth_state = AuthState::new(jwt_manager).with_observatory(observatory.clone());
+        let app = Router::new()
+            .route("/protected", get(protected_handler))
+            .layer(middleware::from_fn_with_state(auth_state, require_auth));
+
+        for _ in 0..5 {
+            let request = Request::builder()
+                .uri("/protected")
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        let request = Request::builder()
+            .uri("/protected")
+            .header(AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(app.oneshot(request).await.unwrap().status(), StatusCode::OK);
+
+        let decisions = access_decisions(observatory.buffered_events().await);
+        let principals: Vec<&str> = decisions.iter().map(|d| d.0.as_str()).collect();
+        assert_eq!(
+            principals,
+            [ANONYMOUS_PRINCIPAL; 5]
+                .into_iter()
+                .chain(["user123"])
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_role_denial_emitted_when_access_events_filtered() {
+        use llm_registry_service::adapters::EventFilter;
+
+        let jwt_manager = create_test_jwt_manager();
+        let token = jwt_manager.generate_token("user123").unwrap();
+        let filter = EventFilter::deny(&["access_decision"]);
+        let observatory = Arc::new(ObservatoryAdapter::default().with_event_filter(filter));
+        let auth_state = AuthState::new(jwt_manager).with_observatory(observatory.clone());
+
+        let send = |app: Router| {
+            let request = Request::builder()
+                .uri("/protected")
+                .header(AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap();
+            app.oneshot(request)
+        };
+
+        // An allowed request is dropped by the filter
+        let app = Router::new()
+            .route("/protected", get(protected_handler))
+            .layer(middleware::from_fn_with_state(
+                auth_state.clone(),
+                require_auth,
+            ));
+        assert_eq!(send(app).await.unwrap().status(), StatusCode::OK);
+        assert!(observatory.buffered_events().await.is_empty());
+
+        let app = Router::new()
+            .route("/protected", get(protected_handler))
+            .layer(middleware::from_fn_with_state(
+                (auth_state, vec!["admin".to_string()]),
+                require_role,
+            ));
+        assert_eq!(send(app).await.unwrap().status(), StatusCode::FORBIDDEN);
+
+        let decisions = access_decisions(observatory.buffered_events().await);
+        assert_eq!(
+            decisions,
+            [(
+                "user123".to_string(),
+                "/protected".to_string(),
+                "GET".to_string(),
+                false
+            )]
+        );
+    }
+
     #[test]
     fn test_auth_user() {
         let claims = crate::jwt::Claims::new("user123", "test", "test", 3600)
@@ -161,9 +161,7 @@ pub async fn refresh_token(
         .jwt_manager()
         .refresh_access_token(&request.refresh_token)
         .map_err(|e| match e {
-            crate::jwt::TokenError::Expired => {
-                ApiError::unauthorized("Refresh token has expired")
-            }
+            crate::jwt::TokenError::Expired => ApiError::unauthorized("Refresh token has expired"),
             crate::jwt::TokenError::InvalidClaims(_) => {
                 ApiError::bad_request("Invalid refresh token")
             }
@@ -178,9 +176,7 @@ pub async fn refresh_token(
 
 /// Get current user information
 #[instrument(skip(user))]
-pub async fn me(
-    Extension(user): Extension<AuthUser>,
-) -> ApiResult<Json<ApiResponse<UserInfo>>> {
+pub async fn me(Extension(user): Extension<AuthUser>) -> ApiResult<Json<ApiResponse<UserInfo>>> {
     debug!("Current user info requested");
 
     let user_info = UserInfo::from_claims(&user.claims);
@@ -246,7 +242,9 @@ pub async fn generate_api_key(
     let api_key = state
         .jwt_manager()
         .generate_token_with_claims(claims)
-        .map_err(|e| ApiError::internal_server_error(format!("Failed to generate API key: {}", e)))?;
+        .map_err(|e| {
+            ApiError::internal_server_error(format!("Failed to generate API key: {}", e))
+        })?;
 
     let response = ApiKeyResponse { api_key };
 
@@ -0,0 +1,125 @@
+//! Request body size limits
+//!
+//! Registration and update payloads are buffered and parsed as JSON before
+//! any validation runs, so an oversized body would consume memory first and
+//! fail later. The [`limit_body_size`] middleware rejects such requests up
+//! front with `413 Payload Too Large`, reporting the configured limit.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::header,
+    middleware::Next,
+    response::Response,
+};
+use llm_registry_core::execution::SpanCollector;
+use llm_registry_service::adapters::config_manager::ValidationConstraints;
+
+use crate::error::ApiError;
+
+/// Body size limit applied when none is configured
+///
+/// Matches the default [`ValidationConstraints::max_metadata_size`].
+pub fn default_max_body_size() -> usize {
+    ValidationConstraints::default().max_metadata_size as usize
+}
+
+/// Middleware that rejects request bodies larger than `limit` bytes
+pub async fn limit_body_size(
+    State(limit): State<usize>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let collector = request.extensions().get::<SpanCollector>().cloned();
+    let too_large = || {
+        let err = ApiError::payload_too_large(limit);
+        match collector {
+            Some(ref collector) => {
+                err.with_execution(collector.finalize_failed("Request body too large"))
+            }
+            None => err,
+        }
+    };
+
+    // A declared length over the limit is rejected without reading the body
+    let declared = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if declared.is_some_and(|length| length > limit) {
+        return Err(too_large());
+    }
+
+    // Chunked or mis-declared bodies are cut off once they pass the limit
+    let (parts, body) = request.into_parts();
+    let bytes = axum::body::to_bytes(body, limit)
+        .await
+        .map_err(|_| too_large())?;
+
+    Ok(next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+    use crate::test_support;
+    use axum::{body::Body, http::StatusCode};
+    use llm_registry_core::execution::SpanId;
+    use tower::ServiceExt;
+
+    const LIMIT: usize = 2048;
+
+    /// POST /v1/assets with a registration body padded to exactly `size` bytes
+    async fn post_sized(size: usize) -> (StatusCode, serde_json::Value) {
+        let (state, _, _) = test_support::app_state();
+        let app = crate::routes::build_router(state.with_max_body_size(LIMIT));
+
+        let template = test_support::asset("model", "1.0.0");
+        let mut body = serde_json::json!({
+            "asset_type": template.asset_type,
+            "name": template.metadata.name,
+            "version": template.metadata.version,
+            "storage": template.storage,
+            "checksum": template.checksum,
+            "description": "",
+        });
+        let padding = size - body.to_string().len();
+        body["description"] = "x".repeat(padding).into();
+        let body = body.to_string();
+        assert_eq!(body.len(), size);
+
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/v1/assets")
+            .header(HEADER_EXECUTION_ID, uuid::Uuid::new_v4().to_string())
+            .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_body_under_limit_accepted() {
+        let (status, _) = post_sized(LIMIT).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_body_over_limit_rejected() {
+        let (status, body) = post_sized(LIMIT + 1).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(body["code"], "PAYLOAD_TOO_LARGE");
+        assert_eq!(body["details"]["limit_bytes"], LIMIT);
+        assert!(body["execution"].is_object());
+    }
+}
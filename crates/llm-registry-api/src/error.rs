@@ -9,7 +9,7 @@ use axum::{
     Json,
 };
 use llm_registry_core::execution::ExecutionResult;
-use llm_registry_service::ServiceError;
+use llm_registry_service::{RegistryError, ServiceError};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -19,6 +19,9 @@ pub struct ApiError {
     status_code: StatusCode,
     message: String,
     error_code: Option<String>,
+    /// Structured details for programmatic handling (boxed to keep
+    /// `Result<_, ApiError>` small)
+    details: Option<Box<serde_json::Value>>,
     /// Execution spans to include in the error response (for agentics tracing).
     execution: Option<ExecutionResult>,
 }
@@ -30,6 +33,7 @@ impl ApiError {
             status_code,
             message: message.into(),
             error_code: None,
+            details: None,
             execution: None,
         }
     }
@@ -44,6 +48,7 @@ impl ApiError {
             status_code,
             message: message.into(),
             error_code: Some(error_code.into()),
+            details: None,
             execution: None,
         }
     }
@@ -55,6 +60,12 @@ impl ApiError {
         self
     }
 
+    /// Attach structured details to this error
+    pub fn with_details(mut self, details: serde_json::Value) -> Self {
+        self.details = Some(Box::new(details));
+        self
+    }
+
     /// Create a bad request error (400)
     pub fn bad_request(message: impl Into<String>) -> Self {
         Self::new(StatusCode::BAD_REQUEST, message)
@@ -70,6 +81,35 @@ impl ApiError {
         Self::new(StatusCode::CONFLICT, message)
     }
 
+    /// Create a precondition failed error (412)
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        Self::with_code(
+            StatusCode::PRECONDITION_FAILED,
+            message,
+            "PRECONDITION_FAILED",
+        )
+    }
+
+    /// Create a payload too large error (413) carrying the body size limit
+    pub fn payload_too_large(limit_bytes: usize) -> Self {
+        Self::with_code(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!("Request body exceeds the limit of {} bytes", limit_bytes),
+            "PAYLOAD_TOO_LARGE",
+        )
+        .with_details(serde_json::json!({ "limit_bytes": limit_bytes }))
+    }
+
+    /// Create a gateway timeout error (504) carrying the exceeded timeout
+    pub fn gateway_timeout(timeout_ms: u64) -> Self {
+        Self::with_code(
+            StatusCode::GATEWAY_TIMEOUT,
+            format!("Request did not complete within {} ms", timeout_ms),
+            "GATEWAY_TIMEOUT",
+        )
+        .with_details(serde_json::json!({ "timeout_ms": timeout_ms }))
+    }
+
     /// Create an unprocessable entity error (422)
     pub fn unprocessable_entity(message: impl Into<String>) -> Self {
         Self::new(StatusCode::UNPROCESSABLE_ENTITY, message)
@@ -112,6 +152,10 @@ pub struct ErrorResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code: Option<String>,
 
+    /// Structured error details (e.g. the limit that was exceeded)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+
     /// Timestamp of the error
     pub timestamp: chrono::DateTime<chrono::Utc>,
 
@@ -126,6 +170,7 @@ impl IntoResponse for ApiError {
             status: self.status_code.as_u16(),
             error: self.message,
             code: self.error_code,
+            details: self.details.map(|details| *details),
             timestamp: chrono::Utc::now(),
             execution: self.execution,
         };
@@ -137,65 +182,33 @@ impl IntoResponse for ApiError {
 /// Convert ServiceError to ApiError
 impl From<ServiceError> for ApiError {
     fn from(err: ServiceError) -> Self {
-        match err {
-            ServiceError::NotFound(msg) => {
-                ApiError::with_code(StatusCode::NOT_FOUND, msg, "NOT_FOUND")
+        ApiError::from(RegistryError::from(err))
+    }
+}
+
+/// Convert RegistryError to ApiError using its status and code
+impl From<RegistryError> for ApiError {
+    fn from(err: RegistryError) -> Self {
+        let status_code =
+            StatusCode::from_u16(err.status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let code = err.code();
+        let message = match err {
+            RegistryError::Service(
+                ServiceError::NotFound(msg)
+                | ServiceError::PreconditionFailed(msg)
+                | ServiceError::InvalidInput(msg)
+                | ServiceError::NotPermitted(msg),
+            ) => msg,
+            RegistryError::Service(ServiceError::AlreadyExists { name, version }) => {
+                format!("Asset {}@{} already exists", name, version)
             }
-            ServiceError::AlreadyExists { name, version } => ApiError::with_code(
-                StatusCode::CONFLICT,
-                format!("Asset {}@{} already exists", name, version),
-                "ALREADY_EXISTS",
-            ),
-            ServiceError::ValidationFailed(msg) => ApiError::with_code(
-                StatusCode::UNPROCESSABLE_ENTITY,
-                format!("Validation failed: {}", msg),
-                "VALIDATION_FAILED",
-            ),
-            ServiceError::ChecksumVerificationFailed(msg) => ApiError::with_code(
-                StatusCode::UNPROCESSABLE_ENTITY,
-                format!("Checksum verification failed: {}", msg),
-                "CHECKSUM_MISMATCH",
-            ),
-            ServiceError::CircularDependency(msg) => ApiError::with_code(
-                StatusCode::UNPROCESSABLE_ENTITY,
-                format!("Circular dependency detected: {}", msg),
-                "CIRCULAR_DEPENDENCY",
-            ),
-            ServiceError::DependencyNotFound(msg) => ApiError::with_code(
-                StatusCode::UNPROCESSABLE_ENTITY,
-                format!("Dependency not found: {}", msg),
-                "DEPENDENCY_NOT_FOUND",
-            ),
-            ServiceError::VersionConflict(msg) => ApiError::with_code(
-                StatusCode::CONFLICT,
-                format!("Version conflict: {}", msg),
-                "VERSION_CONFLICT",
-            ),
-            ServiceError::PolicyValidationFailed {
+            RegistryError::Service(ServiceError::PolicyValidationFailed {
                 policy_name,
                 message,
-            } => ApiError::with_code(
-                StatusCode::UNPROCESSABLE_ENTITY,
-                format!("Policy '{}' validation failed: {}", policy_name, message),
-                "POLICY_VALIDATION_FAILED",
-            ),
-            ServiceError::InvalidInput(msg) => {
-                ApiError::with_code(StatusCode::BAD_REQUEST, msg, "INVALID_INPUT")
-            }
-            ServiceError::NotPermitted(msg) => {
-                ApiError::with_code(StatusCode::FORBIDDEN, msg, "NOT_PERMITTED")
-            }
-            ServiceError::Database(msg) => ApiError::with_code(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Database error: {}", msg),
-                "DATABASE_ERROR",
-            ),
-            ServiceError::Internal(msg) => ApiError::with_code(
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Internal error: {}", msg),
-                "INTERNAL_ERROR",
-            ),
-        }
+            }) => format!("Policy '{}' validation failed: {}", policy_name, message),
+            other => other.to_string(),
+        };
+        ApiError::with_code(status_code, message, code)
     }
 }
 
@@ -233,12 +246,60 @@ mod tests {
         assert_eq!(api_err.status_code, StatusCode::NOT_FOUND);
     }
 
+    #[test]
+    fn test_precondition_failed_conversion() {
+        let api_err: ApiError = ServiceError::PreconditionFailed("stale".to_string()).into();
+        assert_eq!(api_err.status_code, StatusCode::PRECONDITION_FAILED);
+        assert_eq!(api_err.error_code.as_deref(), Some("PRECONDITION_FAILED"));
+    }
+
+    #[test]
+    fn test_registry_error_conversion() {
+        use llm_registry_service::adapters::config_manager::ConfigAdapterError;
+        use llm_registry_service::adapters::observatory::ObservatoryError;
+        use llm_registry_service::adapters::schema_registry::SchemaAdapterError;
+
+        let cases: Vec<(RegistryError, StatusCode, &str)> = vec![
+            (
+                SchemaAdapterError::Unavailable("down".into()).into(),
+                StatusCode::SERVICE_UNAVAILABLE,
+                "SCHEMA_REGISTRY_UNAVAILABLE",
+            ),
+            (
+                ConfigAdapterError::ValidationFailed("bad".into()).into(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "CONFIG_VALIDATION_FAILED",
+            ),
+            (
+                ObservatoryError::TraceNotFound("t-1".into()).into(),
+                StatusCode::NOT_FOUND,
+                "TRACE_NOT_FOUND",
+            ),
+            (
+                ServiceError::AlreadyExists {
+                    name: "model".into(),
+                    version: "1.0.0".into(),
+                }
+                .into(),
+                StatusCode::CONFLICT,
+                "ALREADY_EXISTS",
+            ),
+        ];
+
+        for (err, status, code) in cases {
+            let api_err = ApiError::from(err);
+            assert_eq!(api_err.status_code, status);
+            assert_eq!(api_err.error_code.as_deref(), Some(code));
+        }
+    }
+
     #[test]
     fn test_error_response_serialization() {
         let response = ErrorResponse {
             status: 404,
             error: "Not found".to_string(),
             code: Some("NOT_FOUND".to_string()),
+            details: None,
             timestamp: chrono::Utc::now(),
             execution: None,
         };
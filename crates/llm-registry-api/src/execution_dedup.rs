@@ -0,0 +1,240 @@
+//! Deduplication of ingested execution records
+//!
+//! data-core retries deliveries to `POST /api/v1/executions`, so the same
+//! `execution_id` can arrive more than once. The first delivery is recorded
+//! in an [`ExecutionDedupStore`] and confirmed once its record is persisted;
+//! a repeat within the store's TTL is answered as a duplicate instead of
+//! being accepted again, or asked to retry while the first is in flight.
+
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long an accepted execution id is remembered by default
+pub const DEFAULT_EXECUTION_DEDUP_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Outcome of recording an execution id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupOutcome {
+    /// First delivery; the caller must `confirm` or `release` the id
+    Recorded,
+    /// An earlier delivery is recorded but not yet confirmed
+    InFlight,
+    /// An earlier delivery was confirmed and has not expired
+    Duplicate,
+}
+
+/// Storage for recently accepted execution ids
+///
+/// Implementations must check and record in one step so concurrent
+/// deliveries of the same id accept exactly one; a Redis-backed store would
+/// use `SET key pending NX EX ttl`, `SET key accepted XX KEEPTTL` and `DEL`.
+#[async_trait]
+pub trait ExecutionDedupStore: Send + Sync {
+    /// Record `execution_id` as in flight unless it is already recorded
+    async fn record(&self, execution_id: &str) -> DedupOutcome;
+
+    /// Mark a recorded `execution_id` as accepted, once its record is stored
+    async fn confirm(&self, execution_id: &str);
+
+    /// Forget `execution_id`, so its next delivery is accepted again
+    async fn release(&self, execution_id: &str);
+}
+
+/// Releases a recorded execution id when dropped before it was confirmed
+///
+/// Covers handlers cancelled mid-flight, for example when data-core
+/// disconnects, so the id is not left in flight until it expires.
+pub struct DedupReservation {
+    store: Arc<dyn ExecutionDedupStore>,
+    execution_id: Option<String>,
+}
+
+impl DedupReservation {
+    /// Guard `execution_id`, recorded in `store`
+    pub fn new(store: Arc<dyn ExecutionDedupStore>, execution_id: impl Into<String>) -> Self {
+        Self {
+            store,
+            execution_id: Some(execution_id.into()),
+        }
+    }
+
+    /// Mark the id as accepted, so its retries are answered as duplicates
+    pub async fn confirm(mut self) {
+        if let Some(execution_id) = self.execution_id.take() {
+            self.store.confirm(&execution_id).await;
+        }
+    }
+
+    /// Forget the id now rather than when the guard is dropped
+    pub async fn release(mut self) {
+        if let Some(execution_id) = self.execution_id.take() {
+            self.store.release(&execution_id).await;
+        }
+    }
+}
+
+impl Drop for DedupReservation {
+    fn drop(&mut self) {
+        let Some(execution_id) = self.execution_id.take() else {
+            return;
+        };
+        // Release is async, so it runs on the runtime the handler was on
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let store = self.store.clone();
+            runtime.spawn(async move { store.release(&execution_id).await });
+        }
+    }
+}
+
+/// A recorded execution id
+struct DedupEntry {
+    recorded_at: Instant,
+    confirmed: bool,
+}
+
+/// Recorded ids, plus the order they expire in
+#[derive(Default)]
+struct DedupEntries {
+    by_id: HashMap<String, DedupEntry>,
+    /// Ids by recording time, oldest first; with a fixed TTL this is also
+    /// expiry order. Released or re-recorded ids leave stale items behind,
+    /// recognised by a recording time that no longer matches.
+    expiry: VecDeque<(Instant, String)>,
+}
+
+impl DedupEntries {
+    /// Drop entries recorded `ttl` or longer ago
+    fn prune(&mut self, ttl: Duration) {
+        while let Some((recorded_at, _)) = self.expiry.front() {
+            if recorded_at.elapsed() < ttl {
+                break;
+            }
+            let (recorded_at, id) = self.expiry.pop_front().unwrap();
+            if self
+                .by_id
+                .get(&id)
+                .is_some_and(|entry| entry.recorded_at == recorded_at)
+            {
+                self.by_id.remove(&id);
+            }
+        }
+    }
+}
+
+/// Process-local [`ExecutionDedupStore`] with a fixed TTL
+pub struct InMemoryExecutionDedupStore {
+    ttl: Duration,
+    seen: Mutex<DedupEntries>,
+}
+
+impl InMemoryExecutionDedupStore {
+    /// Create a store whose entries expire after `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            seen: Mutex::new(DedupEntries::default()),
+        }
+    }
+}
+
+impl Default for InMemoryExecutionDedupStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_EXECUTION_DEDUP_TTL)
+    }
+}
+
+#[async_trait]
+impl ExecutionDedupStore for InMemoryExecutionDedupStore {
+    async fn record(&self, execution_id: &str) -> DedupOutcome {
+        let mut seen = self.seen.lock().unwrap();
+        // Expired entries are dropped, oldest first, whenever an id is recorded
+        seen.prune(self.ttl);
+        match seen.by_id.get(execution_id) {
+            Some(entry) if entry.confirmed => DedupOutcome::Duplicate,
+            Some(_) => DedupOutcome::InFlight,
+            None => {
+                let recorded_at = Instant::now();
+                seen.by_id.insert(
+                    execution_id.to_string(),
+                    DedupEntry {
+                        recorded_at,
+                        confirmed: false,
+                    },
+                );
+                seen.expiry
+                    .push_back((recorded_at, execution_id.to_string()));
+                DedupOutcome::Recorded
+            }
+        }
+    }
+
+    async fn confirm(&self, execution_id: &str) {
+        if let Some(entry) = self.seen.lock().unwrap().by_id.get_mut(execution_id) {
+            entry.confirmed = true;
+        }
+    }
+
+    async fn release(&self, execution_id: &str) {
+        self.seen.lock().unwrap().by_id.remove(execution_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_record_once_within_ttl() {
+        let store = InMemoryExecutionDedupStore::default();
+        assert_eq!(store.record("exec-1").await, DedupOutcome::Recorded);
+        assert_eq!(store.record("exec-1").await, DedupOutcome::InFlight);
+        store.confirm("exec-1").await;
+        assert_eq!(store.record("exec-1").await, DedupOutcome::Duplicate);
+        assert_eq!(store.record("exec-2").await, DedupOutcome::Recorded);
+        store.release("exec-1").await;
+        assert_eq!(store.record("exec-1").await, DedupOutcome::Recorded);
+
+        let store = InMemoryExecutionDedupStore::new(Duration::ZERO);
+        assert_eq!(store.record("exec-1").await, DedupOutcome::Recorded);
+        assert_eq!(store.record("exec-1").await, DedupOutcome::Recorded);
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_are_pruned_in_order() {
+        let store = InMemoryExecutionDedupStore::new(Duration::from_millis(100));
+        store.record("exec-1").await;
+        store.record("exec-2").await;
+        // Re-recorded after release: the stale expiry item must not evict it
+        store.release("exec-1").await;
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        store.record("exec-1").await;
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        assert_eq!(store.record("exec-3").await, DedupOutcome::Recorded);
+        {
+            let seen = store.seen.lock().unwrap();
+            let mut ids: Vec<&str> = seen.by_id.keys().map(String::as_str).collect();
+            ids.sort();
+            assert_eq!(ids, ["exec-1", "exec-3"]);
+            assert_eq!(seen.expiry.len(), 2);
+        }
+        assert_eq!(store.record("exec-1").await, DedupOutcome::InFlight);
+    }
+
+    #[tokio::test]
+    async fn test_reservation_released_unless_confirmed() {
+        let store: Arc<dyn ExecutionDedupStore> = Arc::new(InMemoryExecutionDedupStore::default());
+
+        store.record("exec-1").await;
+        drop(DedupReservation::new(store.clone(), "exec-1"));
+        tokio::task::yield_now().await;
+        assert_eq!(store.record("exec-1").await, DedupOutcome::Recorded);
+
+        DedupReservation::new(store.clone(), "exec-1")
+            .confirm()
+            .await;
+        assert_eq!(store.record("exec-1").await, DedupOutcome::Duplicate);
+    }
+}
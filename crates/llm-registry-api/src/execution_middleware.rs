@@ -1,67 +1,315 @@
 //! Agentics execution context middleware
 //!
 //! This middleware enforces that every `/v1/*` request carries a valid execution
-//! context from the calling Core. Requests without the required headers are
-//! rejected with 400 Bad Request.
+//! context from the calling Core, either as the `X-Execution-Id` /
+//! `X-Parent-Span-Id` headers or as a W3C `traceparent` header. Requests
+//! without either form are
+//! rejected with 400 Bad Request, unless the middleware runs in lenient mode
+//! (see [`ExecutionContextLayer`]), where a missing context is synthesized so
+//! callers without a Core in front of them can use the API directly.
+//! `OPTIONS` requests are passed through untouched, since browsers send CORS
+//! preflights without custom headers.
 //!
 //! On success the middleware inserts an [`ExecutionContext`] and a
 //! [`SpanCollector`] (with the repo-level span already started) into the
-//! request extensions, where downstream handlers can extract them.
+//! request extensions, where downstream handlers can extract them. When an
+//! observatory is configured, the finalized span tree is forwarded to it once
+//! the handler has produced its response, unless the caller opted out of
+//! sampling via `X-Sampling-Priority` or the `traceparent` flags. With a
+//! [`SpanExportQueue`] configured instead, the collector queues the tree
+//! itself when finalized and a background task exports it.
 
 use axum::{
     extract::Request,
-    http::StatusCode,
+    http::{HeaderMap, Method, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
     Json,
 };
+use futures::future::BoxFuture;
 use llm_registry_core::execution::{
-    ExecutionContext, ExecutionId, SpanCollector, SpanId,
+    ExecutionContext, ExecutionId, SpanCollector, SpanId, TraceParent,
 };
-use tracing::debug;
+use llm_registry_core::SpanExportQueue;
+use llm_registry_service::ObservatoryAdapter;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use tracing::{debug, warn};
 
 use crate::error::ErrorResponse;
 
-/// Header name for the execution-wide identifier.
-pub const HEADER_EXECUTION_ID: &str = "x-execution-id";
-/// Header name for the parent span ID from the calling Core.
-pub const HEADER_PARENT_SPAN_ID: &str = "x-parent-span-id";
+pub use llm_registry_core::execution::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+/// W3C Trace Context header, used when the custom headers are absent.
+pub const HEADER_TRACEPARENT: &str = "traceparent";
+/// Sampling priority; a value of zero or below opts out of span export.
+pub const HEADER_SAMPLING_PRIORITY: &str = "x-sampling-priority";
+
+/// Names of the headers carrying the execution context
+///
+/// Defaults to `X-Execution-Id` and `X-Parent-Span-Id`. Header lookup is
+/// case-insensitive; the names are used as given in error messages.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExecutionHeaderNames {
+    /// Header carrying the execution-wide identifier
+    pub execution_id: String,
+    /// Header carrying the caller's span ID
+    pub parent_span_id: String,
+}
+
+impl Default for ExecutionHeaderNames {
+    fn default() -> Self {
+        Self {
+            execution_id: "X-Execution-Id".to_string(),
+            parent_span_id: "X-Parent-Span-Id".to_string(),
+        }
+    }
+}
+
+impl ExecutionHeaderNames {
+    /// Use custom header names
+    pub fn new(execution_id: impl Into<String>, parent_span_id: impl Into<String>) -> Self {
+        Self {
+            execution_id: execution_id.into(),
+            parent_span_id: parent_span_id.into(),
+        }
+    }
+}
 
 /// Middleware that enforces execution context headers on `/v1/*` routes.
 ///
 /// Follows the same pattern as [`crate::auth::require_auth`]:
 /// extract from headers → validate → insert into extensions → call next.
+/// This is the strict mode of [`ExecutionContextLayer`].
 pub async fn require_execution_context(
     mut request: Request,
     next: Next,
 ) -> Result<Response, Response> {
-    let headers = request.headers();
+    let ctx = extract_context(request.headers(), &ExecutionHeaderNames::default(), false)
+        .map_err(IntoResponse::into_response)?;
+    attach_context(&mut request, ctx, None);
+
+    Ok(next.run(request).await)
+}
+
+/// Layer that attaches an execution context to every request
+///
+/// In strict mode requests without `X-Execution-Id` / `X-Parent-Span-Id`
+/// are rejected with 400. In lenient mode a missing execution ID is
+/// synthesized and a missing parent span is replaced by a fresh root span;
+/// a malformed `X-Parent-Span-Id` is still rejected. The header names can be
+/// overridden with [`ExecutionContextLayer::with_header_names`], and the
+/// finalized spans exported with [`ExecutionContextLayer::with_observatory`]
+/// or, off the request path, [`ExecutionContextLayer::with_export_queue`].
+#[derive(Clone, Default)]
+pub struct ExecutionContextLayer {
+    lenient: bool,
+    header_names: Arc<ExecutionHeaderNames>,
+    observatory: Option<Arc<ObservatoryAdapter>>,
+    export_queue: Option<SpanExportQueue>,
+}
+
+impl ExecutionContextLayer {
+    /// Create the layer, optionally in lenient mode
+    pub fn new(lenient: bool) -> Self {
+        Self {
+            lenient,
+            header_names: Arc::default(),
+            observatory: None,
+            export_queue: None,
+        }
+    }
+
+    /// Read the context from custom header names
+    pub fn with_header_names(mut self, header_names: ExecutionHeaderNames) -> Self {
+        self.header_names = Arc::new(header_names);
+        self
+    }
+
+    /// Forward each finalized execution to the observatory
+    pub fn with_observatory(mut self, observatory: Arc<ObservatoryAdapter>) -> Self {
+        self.observatory = Some(observatory);
+        self
+    }
+
+    /// Queue each finalized execution for background export
+    ///
+    /// Takes precedence over [`Self::with_observatory`].
+    pub fn with_export_queue(mut self, queue: SpanExportQueue) -> Self {
+        self.export_queue = Some(queue);
+        self
+    }
+}
+
+impl<S> Layer<S> for ExecutionContextLayer {
+    type Service = ExecutionContextService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ExecutionContextService {
+            inner,
+            lenient: self.lenient,
+            header_names: self.header_names.clone(),
+            observatory: self.observatory.clone(),
+            export_queue: self.export_queue.clone(),
+        }
+    }
+}
+
+/// Service produced by [`ExecutionContextLayer`]
+#[derive(Clone)]
+pub struct ExecutionContextService<S> {
+    inner: S,
+    lenient: bool,
+    header_names: Arc<ExecutionHeaderNames>,
+    observatory: Option<Arc<ObservatoryAdapter>>,
+    export_queue: Option<SpanExportQueue>,
+}
+
+impl<S> Service<Request> for ExecutionContextService<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Response, Infallible>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request) -> Self::Future {
+        if request.method() == Method::OPTIONS {
+            let clone = self.inner.clone();
+            let mut inner = std::mem::replace(&mut self.inner, clone);
+            return Box::pin(inner.call(request));
+        }
+
+        match extract_context(request.headers(), &self.header_names, self.lenient) {
+            Ok(ctx) => {
+                let collector = attach_context(&mut request, ctx, self.export_queue.as_ref());
+                // The queue is fed by the collector itself on finalize
+                let observatory = self
+                    .observatory
+                    .clone()
+                    .filter(|_| self.export_queue.is_none());
+                // Call the instance that was driven to readiness
+                let clone = self.inner.clone();
+                let mut inner = std::mem::replace(&mut self.inner, clone);
+                Box::pin(async move {
+                    // Outbound adapter calls continue this execution
+                    let response = collector.clone().scope(inner.call(request)).await?;
+                    let result = collector.finalized().filter(|_| collector.sampled());
+                    if let (Some(observatory), Some(result)) = (observatory, result) {
+                        if let Err(e) = observatory.record_execution(result).await {
+                            warn!(error = %e, "Failed to export execution spans");
+                        }
+                    }
+                    Ok(response)
+                })
+            }
+            Err(rejection) => Box::pin(async move { Ok(rejection.into_response()) }),
+        }
+    }
+}
+
+/// Why a request's execution context was rejected
+enum ContextRejection {
+    Missing(String),
+    Invalid(String),
+}
+
+impl IntoResponse for ContextRejection {
+    fn into_response(self) -> Response {
+        match self {
+            ContextRejection::Missing(message) => missing_header_response(&message),
+            ContextRejection::Invalid(message) => invalid_header_response(&message),
+        }
+    }
+}
+
+/// Read the execution context from the request headers
+fn extract_context(
+    headers: &HeaderMap,
+    names: &ExecutionHeaderNames,
+    lenient: bool,
+) -> Result<ExecutionContext, ContextRejection> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+
+    // An explicit sampling priority overrides the traceparent flags
+    let sampling_priority = match header(HEADER_SAMPLING_PRIORITY) {
+        Some(value) => Some(value.trim().parse::<i32>().map(|p| p > 0).map_err(|_| {
+            ContextRejection::Invalid(format!(
+                "Invalid {}: expected an integer, got '{}'",
+                HEADER_SAMPLING_PRIORITY, value
+            ))
+        })?),
+        None => None,
+    };
+
+    // The custom headers win; `traceparent` is only consulted without them
+    let has_custom = headers.contains_key(names.execution_id.as_str())
+        || headers.contains_key(names.parent_span_id.as_str());
+    if !has_custom {
+        if let Some(value) = headers.get(HEADER_TRACEPARENT) {
+            let parent = value
+                .to_str()
+                .map_err(|e| e.to_string())
+                .and_then(TraceParent::parse)
+                .map_err(|e| ContextRejection::Invalid(format!("Invalid traceparent: {}", e)))?;
+            return Ok(ExecutionContext {
+                execution_id: parent.execution_id(),
+                parent_span_id: parent.parent_span_id(),
+                sampled: sampling_priority.unwrap_or_else(|| parent.sampled()),
+            });
+        }
+    }
 
     // Extract X-Execution-Id
-    let execution_id = headers
-        .get(HEADER_EXECUTION_ID)
-        .and_then(|v| v.to_str().ok())
-        .ok_or_else(|| {
-            missing_header_response("Missing required header: X-Execution-Id")
-        })?;
+    let execution_id = match header(&names.execution_id) {
+        Some(id) => ExecutionId::new(id),
+        None if lenient => ExecutionId::new(uuid::Uuid::new_v4().to_string()),
+        None => {
+            return Err(ContextRejection::Missing(format!(
+                "Missing required header: {}",
+                names.execution_id
+            )))
+        }
+    };
 
     // Extract X-Parent-Span-Id
-    let parent_span_id_str = headers
-        .get(HEADER_PARENT_SPAN_ID)
-        .and_then(|v| v.to_str().ok())
-        .ok_or_else(|| {
-            missing_header_response("Missing required header: X-Parent-Span-Id")
-        })?;
-
-    let parent_span_id = SpanId::from_string(parent_span_id_str).map_err(|e| {
-        invalid_header_response(&format!("Invalid X-Parent-Span-Id: {}", e))
-    })?;
-
-    let ctx = ExecutionContext {
-        execution_id: ExecutionId::new(execution_id),
-        parent_span_id,
+    let parent_span_id = match header(&names.parent_span_id) {
+        Some(value) => SpanId::from_string(value).map_err(|e| {
+            ContextRejection::Invalid(format!("Invalid {}: {}", names.parent_span_id, e))
+        })?,
+        None if lenient => SpanId::new(),
+        None => {
+            return Err(ContextRejection::Missing(format!(
+                "Missing required header: {}",
+                names.parent_span_id
+            )))
+        }
     };
 
+    Ok(ExecutionContext {
+        execution_id,
+        parent_span_id,
+        sampled: sampling_priority.unwrap_or(true),
+    })
+}
+
+/// Insert the context and a span collector into the request extensions
+///
+/// Returns a handle to the collector so the finalized spans can be read back
+/// after the handler ran.
+fn attach_context(
+    request: &mut Request,
+    ctx: ExecutionContext,
+    export_queue: Option<&SpanExportQueue>,
+) -> SpanCollector {
     debug!(
         execution_id = %ctx.execution_id,
         parent_span_id = %ctx.parent_span_id,
@@ -69,13 +317,15 @@ pub async fn require_execution_context(
     );
 
     // Create span collector (repo-level span started automatically)
-    let collector = SpanCollector::new(&ctx);
+    let mut collector = SpanCollector::new(&ctx);
+    if let Some(queue) = export_queue {
+        collector = collector.with_export_queue(queue.clone());
+    }
 
     // Insert into request extensions for handler extraction
     request.extensions_mut().insert(ctx);
-    request.extensions_mut().insert(collector);
-
-    Ok(next.run(request).await)
+    request.extensions_mut().insert(collector.clone());
+    collector
 }
 
 fn missing_header_response(message: &str) -> Response {
@@ -83,6 +333,7 @@ fn missing_header_response(message: &str) -> Response {
         status: 400,
         error: message.to_string(),
         code: Some("MISSING_EXECUTION_CONTEXT".to_string()),
+        details: None,
         timestamp: chrono::Utc::now(),
         execution: None,
     };
@@ -94,8 +345,269 @@ fn invalid_header_response(message: &str) -> Response {
         status: 400,
         error: message.to_string(),
         code: Some("INVALID_EXECUTION_CONTEXT".to_string()),
+        details: None,
         timestamp: chrono::Utc::now(),
         execution: None,
     };
     (StatusCode::BAD_REQUEST, Json(body)).into_response()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, extract::Extension, routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn whoami(Extension(ctx): Extension<ExecutionContext>) -> String {
+        format!("{} {}", ctx.execution_id, ctx.parent_span_id)
+    }
+
+    async fn finish(Extension(collector): Extension<SpanCollector>) -> String {
+        collector.finalize();
+        collector.sampled().to_string()
+    }
+
+    async fn send(lenient: bool, headers: &[(&str, String)]) -> (StatusCode, String) {
+        send_through(ExecutionContextLayer::new(lenient), headers).await
+    }
+
+    async fn send_through(
+        layer: ExecutionContextLayer,
+        headers: &[(&str, String)],
+    ) -> (StatusCode, String) {
+        send_to(layer, "/v1/whoami", headers).await
+    }
+
+    async fn send_to(
+        layer: ExecutionContextLayer,
+        uri: &str,
+        headers: &[(&str, String)],
+    ) -> (StatusCode, String) {
+        let app = Router::new().nest(
+            "/v1",
+            Router::new()
+                .route("/whoami", get(whoami))
+                .route("/finish", get(finish))
+                .layer(layer),
+        );
+
+        let mut builder = axum::http::Request::builder().uri(uri);
+        for (name, value) in headers {
+            builder = builder.header(*name, value);
+        }
+        let response = app
+            .oneshot(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn context_headers(span_id: &SpanId) -> Vec<(&'static str, String)> {
+        vec![
+            (HEADER_EXECUTION_ID, "exec-1".to_string()),
+            (HEADER_PARENT_SPAN_ID, span_id.to_string()),
+        ]
+    }
+
+    const TRACEPARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[tokio::test]
+    async fn test_traceparent_only() {
+        let (status, body) = send(false, &[(HEADER_TRACEPARENT, TRACEPARENT.to_string())]).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let parent = TraceParent::parse(TRACEPARENT).unwrap();
+        assert_eq!(
+            body,
+            format!(
+                "4bf92f3577b34da6a3ce929d0e0e4736 {}",
+                parent.parent_span_id()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_headers_win_over_traceparent() {
+        let span_id = SpanId::new();
+        let mut headers = context_headers(&span_id);
+        headers.push((HEADER_TRACEPARENT, TRACEPARENT.to_string()));
+
+        let (status, body) = send(false, &headers).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("exec-1 {}", span_id));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_traceparent_rejected() {
+        for lenient in [false, true] {
+            let (status, body) = send(
+                lenient,
+                &[(HEADER_TRACEPARENT, "00-not-a-trace-01".to_string())],
+            )
+            .await;
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert!(body.contains("INVALID_EXECUTION_CONTEXT"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_header_names() {
+        let layer = ExecutionContextLayer::new(false)
+            .with_header_names(ExecutionHeaderNames::new("x-trace-id", "x-span-id"));
+        let span_id = SpanId::new();
+
+        let (status, body) = send_through(
+            layer.clone(),
+            &[
+                ("x-trace-id", "exec-1".to_string()),
+                ("x-span-id", span_id.to_string()),
+            ],
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("exec-1 {}", span_id));
+
+        // The default names are no longer read, and errors name the configured header
+        let (status, body) = send_through(layer, &context_headers(&span_id)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.contains("Missing required header: x-trace-id"));
+    }
+
+    #[tokio::test]
+    async fn test_strict_rejects_missing_context() {
+        let (status, body) = send(false, &[]).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.contains("MISSING_EXECUTION_CONTEXT"));
+        assert!(body.contains("Missing required header: X-Execution-Id"));
+
+        let (status, _) = send(false, &[(HEADER_EXECUTION_ID, "exec-1".to_string())]).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_options_bypasses_context_requirement() {
+        let app = Router::new().nest(
+            "/v1",
+            Router::new()
+                .route("/whoami", axum::routing::options(|| async { "preflight" }))
+                .layer(ExecutionContextLayer::new(false)),
+        );
+        let request = axum::http::Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/v1/whoami")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_strict_accepts_context() {
+        let span_id = SpanId::new();
+        let (status, body) = send(false, &context_headers(&span_id)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("exec-1 {}", span_id));
+    }
+
+    #[tokio::test]
+    async fn test_lenient_synthesizes_missing_context() {
+        let (status, body) = send(true, &[]).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (execution_id, span_id) = body.split_once(' ').unwrap();
+        assert!(uuid::Uuid::parse_str(execution_id).is_ok());
+        assert!(SpanId::from_string(span_id).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lenient_keeps_provided_context() {
+        let span_id = SpanId::new();
+        let (status, body) = send(true, &context_headers(&span_id)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, format!("exec-1 {}", span_id));
+
+        let (status, body) = send(true, &[(HEADER_PARENT_SPAN_ID, "not-a-span".to_string())]).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.contains("INVALID_EXECUTION_CONTEXT"));
+    }
+
+    #[tokio::test]
+    async fn test_sampling_decision_parsed() {
+        let span_id = SpanId::new();
+        let sampled = |headers: Vec<(&'static str, String)>| async move {
+            send_to(ExecutionContextLayer::new(false), "/v1/finish", &headers).await
+        };
+
+        assert_eq!(sampled(context_headers(&span_id)).await.1, "true");
+
+        let mut headers = context_headers(&span_id);
+        headers.push((HEADER_SAMPLING_PRIORITY, "0".to_string()));
+        assert_eq!(sampled(headers).await.1, "false");
+
+        let unsampled = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00";
+        assert_eq!(
+            sampled(vec![(HEADER_TRACEPARENT, unsampled.to_string())])
+                .await
+                .1,
+            "false"
+        );
+
+        // The explicit priority overrides the traceparent flags
+        let headers = vec![
+            (HEADER_TRACEPARENT, unsampled.to_string()),
+            (HEADER_SAMPLING_PRIORITY, "1".to_string()),
+        ];
+        assert_eq!(sampled(headers).await.1, "true");
+
+        let mut headers = context_headers(&span_id);
+        headers.push((HEADER_SAMPLING_PRIORITY, "keep".to_string()));
+        let (status, body) = sampled(headers).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body.contains("INVALID_EXECUTION_CONTEXT"));
+    }
+
+    #[tokio::test]
+    async fn test_unsampled_executions_not_exported() {
+        let observatory = Arc::new(ObservatoryAdapter::default());
+        let layer = ExecutionContextLayer::new(false).with_observatory(observatory.clone());
+        let span_id = SpanId::new();
+
+        let (status, _) = send_to(layer.clone(), "/v1/finish", &context_headers(&span_id)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let mut headers = context_headers(&span_id);
+        headers.push((HEADER_SAMPLING_PRIORITY, "-1".to_string()));
+        let (status, body) = send_to(layer.clone(), "/v1/finish", &headers).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "false");
+
+        // Never finalized: nothing to export
+        send_to(layer, "/v1/whoami", &context_headers(&span_id)).await;
+
+        assert_eq!(observatory.buffered_executions().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_export_queue_receives_finalized_executions() {
+        use llm_registry_core::SpanExportQueueConfig;
+
+        let observatory = Arc::new(ObservatoryAdapter::default());
+        let queue = SpanExportQueue::spawn(observatory.clone(), SpanExportQueueConfig::default());
+        let layer = ExecutionContextLayer::new(false)
+            .with_observatory(observatory.clone())
+            .with_export_queue(queue.clone());
+        let span_id = SpanId::new();
+
+        send_to(layer.clone(), "/v1/finish", &context_headers(&span_id)).await;
+        send_to(layer, "/v1/finish", &context_headers(&span_id)).await;
+
+        queue.flush_and_shutdown().await;
+        assert_eq!(queue.stats().exported, 2);
+        assert_eq!(observatory.buffered_executions().await.len(), 2);
+    }
+}
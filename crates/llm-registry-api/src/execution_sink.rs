@@ -0,0 +1,90 @@
+//! Durable storage for ingested execution records
+//!
+//! `POST /api/v1/executions` persists every accepted record through an
+//! [`ExecutionRecordSink`] before answering `202`. A sink failure is
+//! reported as `500` so data-core retries the delivery.
+
+use crate::handlers::ExecutionRecordRequest;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tokio::fs::OpenOptions;
+use tokio::io::AsyncWriteExt;
+
+/// Destination for accepted execution records
+#[async_trait]
+pub trait ExecutionRecordSink: Send + Sync {
+    /// Durably store `record`; an error means it was not stored
+    async fn persist(&self, record: &ExecutionRecordRequest) -> Result<(), String>;
+}
+
+/// [`ExecutionRecordSink`] appending one JSON line per record to a file
+///
+/// The file and its parent directories are created on first write. Each
+/// record is synced to disk before `persist` returns.
+pub struct FileExecutionRecordSink {
+    path: PathBuf,
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl FileExecutionRecordSink {
+    /// Create a sink appending to `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            write_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// File records are appended to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl ExecutionRecordSink for FileExecutionRecordSink {
+    async fn persist(&self, record: &ExecutionRecordRequest) -> Result<(), String> {
+        let mut line = serde_json::to_vec(record)
+            .map_err(|e| format!("Failed to serialize execution record: {}", e))?;
+        line.push(b'\n');
+
+        let fail = |e: std::io::Error| format!("Failed to write {}: {}", self.path.display(), e);
+        let _guard = self.write_lock.lock().await;
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent).await.map_err(fail)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+            .map_err(fail)?;
+        file.write_all(&line).await.map_err(fail)?;
+        file.sync_data().await.map_err(fail)
+    }
+}
+
+/// Process-local [`ExecutionRecordSink`] keeping records in memory
+///
+/// Records do not survive a restart; deployments should configure a
+/// [`FileExecutionRecordSink`] or a database-backed sink.
+#[derive(Default)]
+pub struct InMemoryExecutionRecordSink {
+    records: Mutex<Vec<ExecutionRecordRequest>>,
+}
+
+impl InMemoryExecutionRecordSink {
+    /// Records persisted so far, oldest first
+    pub fn records(&self) -> Vec<ExecutionRecordRequest> {
+        self.records.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl ExecutionRecordSink for InMemoryExecutionRecordSink {
+    async fn persist(&self, record: &ExecutionRecordRequest) -> Result<(), String> {
+        self.records.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+//! Governance context middleware
+//!
+//! Organizations can require extra audit context (e.g. a ticket id or
+//! change-request id) on every mutating `/v1/*` request. Values are supplied
+//! as `X-Governance-<key>` headers. Mutating requests missing a required key
+//! are rejected with 400 Bad Request; all supplied values are stamped onto the
+//! governance events emitted while handling the request.
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use llm_registry_core::execution::SpanCollector;
+use llm_registry_service::GovernanceContext;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::error::ErrorResponse;
+
+/// Header prefix carrying governance context values.
+pub const HEADER_GOVERNANCE_PREFIX: &str = "x-governance-";
+
+/// Governance context requirements
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GovernanceConfig {
+    /// Context keys every mutating request must supply (e.g. `ticket-id`)
+    #[serde(default)]
+    pub required_context_keys: Vec<String>,
+}
+
+impl GovernanceConfig {
+    /// Create a config with no required keys
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require a context key on mutating requests
+    pub fn with_required_key(mut self, key: impl Into<String>) -> Self {
+        self.required_context_keys
+            .push(key.into().to_ascii_lowercase());
+        self
+    }
+}
+
+/// Collect `X-Governance-*` headers into a governance context
+pub fn extract_governance_context(headers: &HeaderMap) -> GovernanceContext {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let key = name.as_str().strip_prefix(HEADER_GOVERNANCE_PREFIX)?;
+            let value = value.to_str().ok()?.trim();
+            (!key.is_empty() && !value.is_empty()).then(|| (key.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+fn is_mutating(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    )
+}
+
+/// Middleware that enforces and propagates governance context on `/v1/*` routes.
+///
+/// Must run inside [`crate::execution_middleware::require_execution_context`]
+/// so rejections can carry the execution graph.
+pub async fn require_governance_context(
+    State(config): State<Arc<GovernanceConfig>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let context = extract_governance_context(request.headers());
+
+    if is_mutating(request.method()) {
+        let missing: Vec<&str> = config
+            .required_context_keys
+            .iter()
+            .map(String::as_str)
+            .filter(|key| context.get(&key.to_ascii_lowercase()).is_none())
+            .collect();
+
+        if !missing.is_empty() {
+            let collector = request.extensions().get::<SpanCollector>().cloned();
+            return Err(missing_context_response(&missing, collector));
+        }
+    }
+
+    debug!(keys = ?context.iter().map(|(k, _)| k).collect::<Vec<_>>(), "Governance context extracted");
+
+    let mut request = request;
+    request.extensions_mut().insert(context.clone());
+
+    Ok(context.scope(next.run(request)).await)
+}
+
+fn missing_context_response(missing: &[&str], collector: Option<SpanCollector>) -> Response {
+    let headers: Vec<String> = missing
+        .iter()
+        .map(|key| format!("X-Governance-{}", key))
+        .collect();
+
+    let body = ErrorResponse {
+        status: 400,
+        error: format!(
+            "Missing required governance context: {}",
+            headers.join(", ")
+        ),
+        code: Some("MISSING_GOVERNANCE_CONTEXT".to_string()),
+        details: None,
+        timestamp: chrono::Utc::now(),
+        execution: collector.map(|c| c.finalize_failed("Missing governance context")),
+    };
+    (StatusCode::BAD_REQUEST, Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http, middleware, routing::post, Extension, Router};
+    use tower::ServiceExt;
+
+    fn request(method: Method, ticket: Option<&str>) -> http::Request<Body> {
+        let mut builder = http::Request::builder().method(method).uri("/assets");
+        if let Some(ticket) = ticket {
+            builder = builder.header("X-Governance-Ticket-Id", ticket);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    async fn echo_ticket(Extension(context): Extension<GovernanceContext>) -> String {
+        let current = GovernanceContext::current().unwrap_or_default();
+        format!(
+            "{}:{}",
+            context.get("ticket-id").unwrap_or("-"),
+            current.get("ticket-id").unwrap_or("-")
+        )
+    }
+
+    fn app(config: GovernanceConfig) -> Router {
+        Router::new()
+            .route("/assets", post(echo_ticket).get(echo_ticket))
+            .layer(middleware::from_fn_with_state(
+                Arc::new(config),
+                require_governance_context,
+            ))
+    }
+
+    #[tokio::test]
+    async fn test_missing_required_key_rejected() {
+        let response = app(GovernanceConfig::new().with_required_key("ticket-id"))
+            .oneshot(request(Method::POST, None))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_reads_not_subject_to_requirements() {
+        let response = app(GovernanceConfig::new().with_required_key("ticket-id"))
+            .oneshot(request(Method::GET, None))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_context_propagated_to_handler() {
+        let response = app(GovernanceConfig::new().with_required_key("Ticket-Id"))
+            .oneshot(request(Method::POST, Some("OPS-42")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"OPS-42:OPS-42");
+    }
+}
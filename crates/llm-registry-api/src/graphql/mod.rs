@@ -9,7 +9,11 @@ pub mod types;
 
 use async_graphql::{http::GraphiQLSource, EmptySubscription, Schema};
 use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
-use axum::{extract::State, response::{Html, IntoResponse}, Extension};
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse},
+    Extension,
+};
 use llm_registry_service::ServiceRegistry;
 use std::sync::Arc;
 
@@ -3,11 +3,9 @@
 //! This module implements all GraphQL mutation operations.
 
 use async_graphql::{Context, InputObject, Object, Result};
-use llm_registry_core::{
-    AssetId, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
-};
+use llm_registry_core::Version;
+use llm_registry_core::{AssetId, Checksum, HashAlgorithm, StorageBackend, StorageLocation};
 use llm_registry_service::{RegisterAssetRequest, ServiceRegistry, UpdateAssetRequest};
-use semver::Version;
 use std::sync::Arc;
 
 use super::types::{
@@ -38,6 +36,9 @@ pub struct RegisterAssetInput {
     /// Key-value annotations
     #[graphql(default)]
     pub annotations: Vec<AnnotationInput>,
+    /// Structured key-value labels
+    #[graphql(default)]
+    pub labels: Vec<AnnotationInput>,
     /// Storage path
     pub storage_path: String,
     /// Storage backend type (s3, gcs, azure, local)
@@ -79,6 +80,12 @@ pub struct UpdateAssetInput {
     /// Annotation keys to remove
     #[graphql(default)]
     pub remove_annotations: Vec<String>,
+    /// Labels to add/update
+    #[graphql(default)]
+    pub add_labels: Vec<AnnotationInput>,
+    /// Label keys to remove
+    #[graphql(default)]
+    pub remove_labels: Vec<String>,
 }
 
 /// Annotation key-value pair
@@ -104,15 +111,15 @@ impl Mutation {
         let _user = ctx.data_opt::<AuthUser>();
 
         // Parse version
-        let version = Version::parse(&input.version)
-            .map_err(|e| ApiError::bad_request(format!("Invalid version: {}", e)))?;
+        let version =
+            Version::parse(&input.version).map_err(|e| ApiError::bad_request(e.to_string()))?;
 
         // Parse hash algorithm
         let algorithm = match input.checksum_algorithm.to_uppercase().as_str() {
             "SHA256" => HashAlgorithm::SHA256,
             "SHA3_256" | "SHA3-256" => HashAlgorithm::SHA3_256,
             "BLAKE3" => HashAlgorithm::BLAKE3,
-            _ => return Err(ApiError::bad_request("Invalid checksum algorithm"))?
+            _ => return Err(ApiError::bad_request("Invalid checksum algorithm"))?,
         };
 
         // Create storage backend
@@ -151,12 +158,15 @@ impl Mutation {
                 .into_iter()
                 .map(|a| (a.key, a.value))
                 .collect(),
+            labels: input.labels.into_iter().map(|l| (l.key, l.value)).collect(),
             storage,
             checksum,
             provenance: None,
             dependencies: vec![],
             size_bytes: input.size_bytes,
             content_type: input.content_type,
+            retention_override: None,
+            dry_run: None,
         };
 
         let response = services
@@ -202,6 +212,13 @@ impl Mutation {
                 .map(|a| (a.key, a.value))
                 .collect(),
             remove_annotations: input.remove_annotations,
+            add_labels: input
+                .add_labels
+                .into_iter()
+                .map(|l| (l.key, l.value))
+                .collect(),
+            remove_labels: input.remove_labels,
+            expected_version: None,
         };
 
         let response = services
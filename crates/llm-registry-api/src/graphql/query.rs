@@ -4,7 +4,7 @@
 
 use async_graphql::{Context, Object, Result};
 use llm_registry_core::AssetId;
-use llm_registry_service::{SearchAssetsRequest, ServiceRegistry, SortField, SortOrder};
+use llm_registry_service::{SearchAssetsRequest, ServiceRegistry};
 use std::sync::Arc;
 
 use super::types::{GqlAsset, GqlAssetConnection, GqlAssetFilter, GqlDependencyNode};
@@ -45,15 +45,22 @@ impl Query {
         // Build search request
         let mut search_request = SearchAssetsRequest {
             text: None,
+            q: None,
             asset_types: vec![],
             tags: vec![],
+            tag_match: None,
+            label_selector: None,
             author: None,
             storage_backend: None,
+            content_hash: None,
             exclude_deprecated: true,
+            include_deprecated: false,
             limit,
             offset,
-            sort_by: SortField::CreatedAt,
-            sort_order: SortOrder::Descending,
+            sort_by: None,
+            sort_order: None,
+            include_expired: false,
+            facets: None,
         };
 
         // Apply filters if provided
@@ -64,6 +71,7 @@ impl Query {
             if let Some(tags) = f.tags {
                 search_request.tags = tags;
             }
+            search_request.label_selector = f.label_selector;
             search_request.text = f.name;
             // Note: GqlAssetFilter has a status field but SearchAssetsRequest doesn't have one directly
             // We can use exclude_deprecated based on status if needed
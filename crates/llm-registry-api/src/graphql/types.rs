@@ -61,6 +61,19 @@ impl GqlAsset {
             .collect()
     }
 
+    /// Structured key-value labels
+    async fn labels(&self) -> Vec<GqlAnnotation> {
+        self.0
+            .metadata
+            .labels
+            .iter()
+            .map(|(k, v)| GqlAnnotation {
+                key: k.clone(),
+                value: v.clone(),
+            })
+            .collect()
+    }
+
     /// File size in bytes
     async fn size_bytes(&self) -> Option<u64> {
         self.0.metadata.size_bytes
@@ -257,6 +270,8 @@ pub struct GqlAssetFilter {
     pub status: Option<GqlAssetStatus>,
     /// Filter by tag (must have all specified tags)
     pub tags: Option<Vec<String>>,
+    /// Filter by labels, e.g. `team=ml-platform,tier in (gold,silver)`
+    pub label_selector: Option<String>,
     /// Filter by name (partial match)
     pub name: Option<String>,
 }
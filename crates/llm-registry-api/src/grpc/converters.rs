@@ -5,12 +5,12 @@
 
 use super::proto;
 use crate::error::ApiError;
+use llm_registry_core::Version;
 use llm_registry_core::{
-    Asset, AssetId, AssetMetadata, AssetReference, AssetStatus, AssetType, Checksum,
-    HashAlgorithm, Provenance, StorageBackend, StorageLocation,
+    Asset, AssetId, AssetMetadata, AssetReference, AssetStatus, AssetType, Checksum, HashAlgorithm,
+    Provenance, StorageBackend, StorageLocation,
 };
 use llm_registry_service::{DependencyNode, SortField, SortOrder};
-use semver::Version;
 
 // ============================================================================
 // Enum Conversions
@@ -182,7 +182,11 @@ impl From<AssetMetadata> for proto::AssetMetadata {
 impl From<StorageLocation> for proto::StorageLocation {
     fn from(storage: StorageLocation) -> Self {
         let (backend_type, config) = match storage.backend {
-            StorageBackend::S3 { bucket, region, endpoint } => (
+            StorageBackend::S3 {
+                bucket,
+                region,
+                endpoint,
+            } => (
                 proto::StorageBackend::S3 as i32,
                 Some(proto::storage_config::Config::S3(proto::S3Config {
                     bucket,
@@ -197,12 +201,17 @@ impl From<StorageLocation> for proto::StorageLocation {
                     project_id,
                 })),
             ),
-            StorageBackend::AzureBlob { account_name, container } => (
+            StorageBackend::AzureBlob {
+                account_name,
+                container,
+            } => (
                 proto::StorageBackend::AzureBlob as i32,
-                Some(proto::storage_config::Config::Azure(proto::AzureBlobConfig {
-                    account_name,
-                    container,
-                })),
+                Some(proto::storage_config::Config::Azure(
+                    proto::AzureBlobConfig {
+                        account_name,
+                        container,
+                    },
+                )),
             ),
             StorageBackend::MinIO { bucket, endpoint } => (
                 proto::StorageBackend::Minio as i32,
@@ -364,8 +373,7 @@ impl TryFrom<proto::AssetReference> for AssetReference {
             }
             Some(proto::asset_reference::Reference::NameVersion(nv)) => {
                 // Validate version format
-                Version::parse(&nv.version)
-                    .map_err(|e| ApiError::bad_request(format!("Invalid version: {}", e)))?;
+                Version::parse(&nv.version).map_err(|e| ApiError::bad_request(e.to_string()))?;
                 Ok(AssetReference::ByNameVersion {
                     name: nv.name,
                     version: nv.version,
@@ -402,5 +410,5 @@ pub fn parse_timestamp(s: &str) -> Result<chrono::DateTime<chrono::Utc>, ApiErro
 
 /// Convert Version string to semver::Version
 pub fn parse_version(s: &str) -> Result<Version, ApiError> {
-    Version::parse(s).map_err(|e| ApiError::bad_request(format!("Invalid version: {}", e)))
+    Version::parse(s).map_err(|e| ApiError::bad_request(e.to_string()))
 }
@@ -14,13 +14,11 @@ pub mod proto {
 pub use proto::registry_service_server::{RegistryService, RegistryServiceServer};
 pub use service::RegistryServiceImpl;
 
-use tonic::transport::Server;
 use std::net::SocketAddr;
+use tonic::transport::Server;
 
 /// Build a gRPC server with the registry service
-pub fn build_grpc_server(
-    service: RegistryServiceImpl,
-) -> tonic::transport::server::Router {
+pub fn build_grpc_server(service: RegistryServiceImpl) -> tonic::transport::server::Router {
     Server::builder().add_service(RegistryServiceServer::new(service))
 }
 
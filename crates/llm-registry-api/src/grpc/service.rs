@@ -43,8 +43,8 @@ impl RegistryService for RegistryServiceImpl {
         let asset_type = asset_type_from_i32(req.asset_type)
             .map_err(|e| Status::invalid_argument(e.to_string()))?;
 
-        let version = parse_version(&req.version)
-            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let version =
+            parse_version(&req.version).map_err(|e| Status::invalid_argument(e.to_string()))?;
 
         let storage = req
             .storage
@@ -64,11 +64,8 @@ impl RegistryService for RegistryServiceImpl {
             .transpose()
             .map_err(|e: ApiError| Status::invalid_argument(e.to_string()))?;
 
-        let dependencies: Result<Vec<AssetReference>, ApiError> = req
-            .dependencies
-            .into_iter()
-            .map(|d| d.try_into())
-            .collect();
+        let dependencies: Result<Vec<AssetReference>, ApiError> =
+            req.dependencies.into_iter().map(|d| d.try_into()).collect();
         let dependencies = dependencies.map_err(|e| Status::invalid_argument(e.to_string()))?;
 
         let domain_request = RegisterAssetRequest {
@@ -79,12 +76,15 @@ impl RegistryService for RegistryServiceImpl {
             license: req.license,
             tags: req.tags,
             annotations: req.annotations,
+            labels: Default::default(),
             storage,
             checksum,
             provenance,
             dependencies,
             size_bytes: req.size_bytes,
             content_type: req.content_type,
+            retention_override: None,
+            dry_run: None,
         };
 
         // Execute registration
@@ -147,15 +147,22 @@ impl RegistryService for RegistryServiceImpl {
 
         let search_request = SearchAssetsRequest {
             text: req.text,
+            q: None,
             asset_types,
             tags: req.tags,
+            tag_match: None,
+            label_selector: None,
             author: req.author,
             storage_backend: req.storage_backend,
+            content_hash: None,
             exclude_deprecated: req.exclude_deprecated,
+            include_deprecated: false,
             limit: req.limit,
             offset: req.offset,
-            sort_by,
-            sort_order,
+            sort_by: Some(sort_by.as_str().to_string()),
+            sort_order: Some(sort_order),
+            include_expired: false,
+            facets: None,
         };
 
         let response = self
@@ -201,6 +208,9 @@ impl RegistryService for RegistryServiceImpl {
             remove_tags: req.remove_tags,
             add_annotations: req.add_annotations,
             remove_annotations: req.remove_annotations,
+            add_labels: Default::default(),
+            remove_labels: Vec::new(),
+            expected_version: None,
         };
 
         let response = self
@@ -6,26 +6,60 @@
 //! invocation, attaches artifacts, and returns an [`ExecutionEnvelope`].
 
 use axum::{
-    extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    body::Body,
+    extract::{rejection::JsonRejection, Extension, FromRequest, Path, Query, Request, State},
+    http::{header, HeaderMap, HeaderName, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
-use llm_registry_core::execution::{SpanArtifact, SpanCollector, SpanStatus};
-use llm_registry_core::AssetId;
+use llm_registry_core::execution::{AgentService, SpanArtifact, SpanCollector, SpanId, SpanStatus};
+use llm_registry_core::{Asset, AssetId, AssetType, TimestampFormat};
+use llm_registry_service::adapters::config_manager::{
+    BreakerState, ConfigAdapterError, ConfigSource, Environment, RegistryConfig,
+    ValidationConstraints,
+};
+use llm_registry_service::adapters::observatory::RegistryMetrics;
+use llm_registry_service::adapters::schema_registry::{
+    SchemaFallbackMode, MODEL_METADATA_SCHEMA, PIPELINE_DESCRIPTOR_SCHEMA,
+    SCHEMA_UNVALIDATED_ANNOTATION,
+};
+use llm_registry_service::adapters::{
+    ConfigConsumer, ConfigManagerAdapter, GovernanceEvent, SchemaConsumer, TelemetryState,
+};
 use llm_registry_service::{
-    GetDependencyGraphRequest, RegisterAssetRequest, SearchAssetsRequest, ServiceRegistry,
-    UpdateAssetRequest,
+    AssetComparison, AssetPatch, DependentsReindex, DeprecateAssetRequest,
+    GetDependencyGraphRequest, IntegrityReport, ObservatoryAdapter, RegisterAssetRequest,
+    RegisterFromRefRequest, RegistryError, ResolveLatestRequest, RetentionOverride,
+    SearchAssetsRequest, ServiceError, ServiceRegistry, TagCount, TransitiveDependent,
+    UpdateAssetRequest, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE,
 };
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tracing::{debug, info, instrument};
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, instrument, warn};
 
 use crate::{
+    auth::AuthUser,
+    body_limit::default_max_body_size,
     error::{ApiError, ApiResult},
+    execution_dedup::{
+        DedupOutcome, DedupReservation, ExecutionDedupStore, InMemoryExecutionDedupStore,
+    },
+    execution_middleware::ExecutionHeaderNames,
+    execution_sink::{ExecutionRecordSink, InMemoryExecutionRecordSink},
+    governance_middleware::GovernanceConfig,
+    idempotency::{
+        self, validate_key, IdempotencyKey, IdempotencyStore, IdempotentRegistration,
+        InMemoryIdempotencyStore, Reservation, ReservationGuard, HEADER_IDEMPOTENCY_KEY,
+    },
+    metrics_middleware::ApiMetrics,
+    projection::{FieldsParams, Projected},
     responses::{
-        created_with_execution, deleted_with_execution, ok_with_execution, ComponentHealth,
-        ExecutionEnvelope, HealthResponse, PaginatedExecutionEnvelope, PaginationMeta,
+        created_with_execution, deleted_with_execution, no_content, ok_with_execution,
+        ComponentHealth, ExecutionEnvelope, HealthResponse, PaginatedExecutionEnvelope,
+        PaginationMeta,
     },
+    timeout::RequestTimeouts,
 };
 
 /// Application state shared across handlers
@@ -33,6 +67,34 @@ use crate::{
 pub struct AppState {
     /// Service registry
     pub services: Arc<ServiceRegistry>,
+    /// Governance context requirements for mutating requests
+    pub governance: Arc<GovernanceConfig>,
+    /// Observatory telemetry adapter
+    pub observatory: Arc<ObservatoryAdapter>,
+    /// Wire format for response timestamps
+    pub timestamp_format: TimestampFormat,
+    /// Config manager adapter, checked for staleness by the health endpoint
+    pub config_manager: Option<Arc<ConfigManagerAdapter>>,
+    /// Schema registry consumer, probed by the health endpoint
+    pub schema_registry: Option<Arc<dyn SchemaConsumer>>,
+    /// Prometheus metrics rendered by the metrics endpoint
+    pub metrics: Arc<ApiMetrics>,
+    /// Synthesize a missing execution context instead of rejecting the request
+    pub lenient_execution_context: bool,
+    /// Headers the execution context is read from
+    pub execution_headers: ExecutionHeaderNames,
+    /// Registrations recorded under `Idempotency-Key` headers
+    pub idempotency: Arc<dyn IdempotencyStore>,
+    /// Largest accepted body, in bytes, on routes carrying asset metadata
+    pub max_body_size: usize,
+    /// Handler deadlines on `/v1` routes
+    pub request_timeouts: RequestTimeouts,
+    /// Largest page `list_assets` returns; larger limits are clamped
+    pub max_page_size: i64,
+    /// Recently ingested execution ids, used to answer retries as duplicates
+    pub execution_dedup: Arc<dyn ExecutionDedupStore>,
+    /// Where accepted execution records are persisted
+    pub execution_sink: Arc<dyn ExecutionRecordSink>,
 }
 
 impl AppState {
@@ -40,42 +102,484 @@ impl AppState {
     pub fn new(services: ServiceRegistry) -> Self {
         Self {
             services: Arc::new(services),
+            governance: Arc::new(GovernanceConfig::default()),
+            observatory: Arc::new(
+                ObservatoryAdapter::default().with_recent_events(MAX_GOVERNANCE_EVENTS_LIMIT),
+            ),
+            timestamp_format: TimestampFormat::default(),
+            config_manager: None,
+            schema_registry: None,
+            metrics: Arc::new(ApiMetrics::new()),
+            lenient_execution_context: false,
+            execution_headers: ExecutionHeaderNames::default(),
+            idempotency: Arc::new(InMemoryIdempotencyStore::default()),
+            max_body_size: default_max_body_size(),
+            request_timeouts: RequestTimeouts::default(),
+            max_page_size: MAX_PAGE_SIZE,
+            execution_dedup: Arc::new(InMemoryExecutionDedupStore::default()),
+            execution_sink: Arc::new(InMemoryExecutionRecordSink::default()),
         }
     }
+
+    /// Set the governance context requirements
+    pub fn with_governance(mut self, governance: GovernanceConfig) -> Self {
+        self.governance = Arc::new(governance);
+        self
+    }
+
+    /// Set the observatory telemetry adapter
+    pub fn with_observatory(mut self, observatory: Arc<ObservatoryAdapter>) -> Self {
+        self.observatory = observatory;
+        self
+    }
+
+    /// Set the wire format for response timestamps
+    pub fn with_timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.timestamp_format = format;
+        self
+    }
+
+    /// Set the config manager adapter
+    pub fn with_config_manager(mut self, config_manager: Arc<ConfigManagerAdapter>) -> Self {
+        self.config_manager = Some(config_manager);
+        self
+    }
+
+    /// Set the schema registry consumer
+    pub fn with_schema_registry(mut self, schema_registry: Arc<dyn SchemaConsumer>) -> Self {
+        self.schema_registry = Some(schema_registry);
+        self
+    }
+
+    /// Set the Prometheus metrics
+    pub fn with_metrics(mut self, metrics: Arc<ApiMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Accept `/v1` requests without an execution context
+    pub fn with_lenient_execution_context(mut self, lenient: bool) -> Self {
+        self.lenient_execution_context = lenient;
+        self
+    }
+
+    /// Read the execution context from custom header names
+    pub fn with_execution_headers(mut self, names: ExecutionHeaderNames) -> Self {
+        self.execution_headers = names;
+        self
+    }
+
+    /// Set the store backing `Idempotency-Key` replays
+    pub fn with_idempotency_store(mut self, store: Arc<dyn IdempotencyStore>) -> Self {
+        self.idempotency = store;
+        self
+    }
+
+    /// Set the largest accepted body on routes carrying asset metadata
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = bytes;
+        self
+    }
+
+    /// Set the handler deadlines on `/v1` routes
+    pub fn with_request_timeouts(mut self, timeouts: RequestTimeouts) -> Self {
+        self.request_timeouts = timeouts;
+        self
+    }
+
+    /// Set the largest page `list_assets` returns (at least 1)
+    pub fn with_max_page_size(mut self, max: i64) -> Self {
+        self.max_page_size = max.max(1);
+        self
+    }
+
+    /// Set the store deduplicating ingested execution records
+    pub fn with_execution_dedup(mut self, store: Arc<dyn ExecutionDedupStore>) -> Self {
+        self.execution_dedup = store;
+        self
+    }
+
+    /// Set where accepted execution records are persisted
+    pub fn with_execution_sink(mut self, sink: Arc<dyn ExecutionRecordSink>) -> Self {
+        self.execution_sink = sink;
+        self
+    }
+}
+
+/// Page size actually served for a requested `limit`
+///
+/// Limits above `max` are clamped to it; zero or negative limits fall back
+/// to [`DEFAULT_PAGE_SIZE`].
+fn effective_limit(requested: i64, max: i64) -> i64 {
+    if requested <= 0 {
+        DEFAULT_PAGE_SIZE.min(max)
+    } else {
+        requested.min(max)
+    }
+}
+
+/// Attach a handler's primary result artifact, logging instead of storing `null` on failure
+fn attach_result<T: Serialize + ?Sized>(
+    collector: &SpanCollector,
+    span_id: SpanId,
+    name: &str,
+    data: &T,
+) {
+    if let Err(e) = collector.attach_artifact_checked(span_id, name, data) {
+        warn!(artifact = name, error = %e, "Failed to attach result artifact");
+    }
 }
 
 // ============================================================================
 // Asset Management Handlers
 // ============================================================================
 
+/// Response carrying the asset's entity tag in an `ETag` header
+pub type WithEtag<T> = ([(HeaderName, String); 1], Json<T>);
+
+fn etag_header(asset: &Asset) -> [(HeaderName, String); 1] {
+    [(header::ETAG, format!("\"{}\"", asset.etag()))]
+}
+
+/// Extract the entity tag from an `If-Match` header, if present
+fn parse_if_match(headers: &HeaderMap) -> Result<Option<String>, String> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+
+    let value = value
+        .to_str()
+        .map_err(|_| "Invalid If-Match header".to_string())?
+        .trim();
+    let tag = value.strip_prefix("W/").unwrap_or(value).trim_matches('"');
+
+    if tag.is_empty() {
+        return Err("Invalid If-Match header: empty entity tag".to_string());
+    }
+    Ok(Some(tag.to_string()))
+}
+
+/// Validation constraints from the config manager, or the defaults without one
+async fn validation_constraints(state: &AppState) -> ValidationConstraints {
+    match state.config_manager {
+        Some(ref config) => config
+            .get_validation_constraints()
+            .await
+            .unwrap_or_default(),
+        None => ValidationConstraints::default(),
+    }
+}
+
+/// Outcome of a registration admitted by [`check_registration_schema`]
+#[derive(Debug, Default)]
+struct SchemaCheck {
+    /// Warnings to surface on the registration response
+    warnings: Vec<String>,
+    /// Admitted by the queueing fallback without being validated
+    unvalidated: bool,
+}
+
+/// Validate a model or pipeline registration against its canonical schema
+///
+/// Schema errors reject the request only in strict mode; an unreachable
+/// schema registry only does when the schema fallback is
+/// [`SchemaFallbackMode::Reject`]. A configured fallback overrides the
+/// schema registry's own.
+async fn check_registration_schema(
+    state: &AppState,
+    collector: &SpanCollector,
+    request: &RegisterAssetRequest,
+) -> Result<SchemaCheck, ApiError> {
+    let schema_name = match request.asset_type {
+        AssetType::Model => MODEL_METADATA_SCHEMA,
+        AssetType::Pipeline => PIPELINE_DESCRIPTOR_SCHEMA,
+        _ => return Ok(SchemaCheck::default()),
+    };
+    let Some(ref schema_registry) = state.schema_registry else {
+        return Ok(SchemaCheck::default());
+    };
+    let fallback = match state.config_manager {
+        Some(_) => validation_constraints(state).await.schema_fallback,
+        None => schema_registry.fallback(),
+    };
+
+    let span_id = collector.begin_agent_span(AgentService::Validation.as_str());
+    let data = serde_json::to_value(request).unwrap_or_default();
+    let result = schema_registry
+        .validate_with_fallback(
+            schema_name,
+            schema_registry.default_namespace(),
+            &data,
+            fallback,
+        )
+        .await;
+
+    match result {
+        Ok(validation) if validation.valid => {
+            attach_result(collector, span_id, "schema_validation", &validation);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            Ok(SchemaCheck {
+                warnings: validation.warnings,
+                unvalidated: validation.unvalidated,
+            })
+        }
+        Ok(validation) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "schema_errors".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "schema": schema_name,
+                        "schema_id": validation.schema_id,
+                        "errors": validation.errors,
+                    }),
+                },
+            );
+
+            if validation_constraints(state).await.strict_mode {
+                collector.end_agent_span(span_id, SpanStatus::Failed);
+                let exec = collector.finalize();
+                return Err(ApiError::with_code(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    format!(
+                        "Schema validation failed against {}: {}",
+                        schema_name,
+                        validation.errors.join("; ")
+                    ),
+                    "SCHEMA_VALIDATION_FAILED",
+                )
+                .with_details(serde_json::json!({ "errors": validation.errors }))
+                .with_execution(exec));
+            }
+
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let mut warnings = validation.warnings;
+            warnings.extend(
+                validation
+                    .errors
+                    .into_iter()
+                    .map(|e| format!("{} schema: {}", schema_name, e)),
+            );
+            Ok(SchemaCheck {
+                warnings,
+                unvalidated: false,
+            })
+        }
+        Err(e) if fallback == SchemaFallbackMode::Reject => {
+            warn!(schema = schema_name, error = %e, "Schema validation unavailable - registration rejected");
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "schema_validation_unavailable".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::with_code(
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("{} schema validation unavailable: {}", schema_name, e),
+                "SCHEMA_VALIDATION_UNAVAILABLE",
+            )
+            .with_execution(exec))
+        }
+        Err(e) => {
+            // Permissive and queueing fallbacks let registration proceed
+            warn!(schema = schema_name, error = %e, "Schema validation skipped");
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "schema_validation_skipped".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            Ok(SchemaCheck {
+                warnings: vec![format!("{} schema validation skipped: {}", schema_name, e)],
+                unvalidated: fallback == SchemaFallbackMode::Queue,
+            })
+        }
+    }
+}
+
 /// Register a new asset
-#[instrument(skip(state, collector))]
+///
+/// With an `Idempotency-Key` header, a repeated request replays the response
+/// of the first successful registration under that key; keys are scoped to
+/// the caller and bound to the request body.
+#[instrument(skip(state, collector, auth_user, headers))]
 pub async fn register_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
-    Json(request): Json<RegisterAssetRequest>,
-) -> ApiResult<(StatusCode, Json<ExecutionEnvelope<llm_registry_service::RegisterAssetResponse>>)> {
-    info!(
-        "Registering asset: {}@{}",
-        request.name, request.version
-    );
+    auth_user: Option<Extension<AuthUser>>,
+    headers: HeaderMap,
+    Json(mut request): Json<RegisterAssetRequest>,
+) -> ApiResult<(
+    StatusCode,
+    Json<ExecutionEnvelope<llm_registry_service::RegisterAssetResponse>>,
+)> {
+    info!("Registering asset: {}@{}", request.name, request.version);
+
+    // Dry runs persist nothing, so there is nothing to replay
+    let dry_run = request.dry_run == Some(true);
+    let idempotency_key = match headers.get(HEADER_IDEMPOTENCY_KEY).filter(|_| !dry_run) {
+        Some(value) => match value
+            .to_str()
+            .map_err(|e| e.to_string())
+            .and_then(|key| validate_key(key).map(str::to_string))
+        {
+            Ok(key) => Some(key),
+            Err(e) => {
+                let exec = collector.finalize_failed("Invalid Idempotency-Key header");
+                return Err(ApiError::bad_request(e).with_execution(exec));
+            }
+        },
+        None => None,
+    };
+
+    // Reserve the key before registering, so concurrent retries cannot both register
+    let principal = auth_user.as_ref().map(|Extension(user)| user.user_id());
+    let reservation = match idempotency_key {
+        Some(key) => {
+            let key = IdempotencyKey::new(principal, key);
+            match state
+                .idempotency
+                .reserve(&key, &idempotency::body_hash(&request))
+            {
+                Reservation::Reserved => {
+                    Some(ReservationGuard::new(state.idempotency.clone(), key))
+                }
+                Reservation::Completed(recorded) => {
+                    debug!(asset_id = %recorded.response.asset.id, "Replaying idempotent registration");
+                    return Ok(created_with_execution(
+                        recorded.response,
+                        recorded.execution,
+                    ));
+                }
+                Reservation::InFlight => {
+                    let exec = collector.finalize_failed("Idempotent registration in progress");
+                    return Err(ApiError::with_code(
+                        StatusCode::CONFLICT,
+                        "A request with this Idempotency-Key is still being processed; retry later",
+                        "IDEMPOTENCY_KEY_IN_USE",
+                    )
+                    .with_execution(exec));
+                }
+                Reservation::Full => {
+                    let exec = collector.finalize_failed("Idempotency store full");
+                    return Err(ApiError::with_code(
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        "Too many idempotent registrations are pending replay; retry later",
+                        "IDEMPOTENCY_STORE_FULL",
+                    )
+                    .with_execution(exec));
+                }
+                Reservation::Mismatch => {
+                    let exec =
+                        collector.finalize_failed("Idempotency-Key reused with another request");
+                    return Err(ApiError::with_code(
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        "Idempotency-Key was already used with a different request body",
+                        "IDEMPOTENCY_KEY_MISMATCH",
+                    )
+                    .with_execution(exec));
+                }
+            }
+        }
+        None => None,
+    };
+
+    let schema = check_registration_schema(&state, &collector, &request).await?;
+    if schema.unvalidated {
+        request.annotations.insert(
+            SCHEMA_UNVALIDATED_ANNOTATION.to_string(),
+            "true".to_string(),
+        );
+    }
+
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
+
+    let started = Instant::now();
+    let result = state.services.registration().register_asset(request).await;
+
+    let result = result.map(|mut response| {
+        response.warnings.extend(schema.warnings);
+        response
+    });
+
+    match result {
+        Ok(response) if response.dry_run => {
+            attach_result(&collector, span_id, "validated_asset", &response.asset);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok((StatusCode::OK, ok_with_execution(response, exec)))
+        }
+        Ok(response) => {
+            state.metrics.record_registration(started.elapsed());
+            attach_result(&collector, span_id, "registered_asset", &response.asset);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            if let Some(reservation) = reservation {
+                reservation.complete(IdempotentRegistration {
+                    response: response.clone(),
+                    execution: exec.clone(),
+                });
+            }
+            Ok(created_with_execution(response, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Register an asset from a Git or OCI reference
+#[instrument(skip(state, collector, request), fields(reference = %request.reference))]
+pub async fn register_asset_from_ref(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Json(request): Json<RegisterFromRefRequest>,
+) -> ApiResult<(
+    StatusCode,
+    Json<ExecutionEnvelope<llm_registry_service::RegisterFromRefResponse>>,
+)> {
+    info!("Registering asset from reference: {}", request.reference);
 
-    let span_id = collector.begin_agent_span("RegistrationService");
+    let span_id = collector.begin_agent_span(AgentService::SourceRegistration.as_str());
 
+    let started = Instant::now();
     let result = state
         .services
-        .registration()
-        .register_asset(request)
+        .source_registration()
+        .register_from_ref(request)
         .await;
 
     match result {
         Ok(response) => {
+            state.metrics.record_registration(started.elapsed());
             let _ = collector.attach_artifact(
                 span_id,
                 SpanArtifact {
                     name: "registered_asset".to_string(),
                     content_type: Some("application/json".to_string()),
-                    data: serde_json::to_value(&response.asset).unwrap_or_default(),
+                    data: serde_json::json!({
+                        "asset_id": response.asset.id.to_string(),
+                        "reference": response.reference,
+                        "resolved_digest": response.resolved_digest,
+                    }),
                 },
             );
             collector.end_agent_span(span_id, SpanStatus::Ok);
@@ -99,12 +603,15 @@ pub async fn register_asset(
 }
 
 /// Get asset by ID
+///
+/// A `fields` query parameter limits the asset to the named fields.
 #[instrument(skip(state, collector))]
 pub async fn get_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
     Path(id): Path<String>,
-) -> ApiResult<Json<ExecutionEnvelope<llm_registry_core::Asset>>> {
+    Query(fields): Query<FieldsParams>,
+) -> ApiResult<WithEtag<ExecutionEnvelope<Projected<Asset>>>> {
     debug!("Getting asset: {}", id);
 
     let asset_id = id.parse::<AssetId>().map_err(|e| {
@@ -113,27 +620,25 @@ pub async fn get_asset(
         err.with_execution(exec)
     })?;
 
-    let span_id = collector.begin_agent_span("SearchService");
+    let projection = fields.projection().map_err(|e| {
+        let exec = collector.finalize_failed("Invalid fields");
+        ApiError::bad_request(e).with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
 
-    let result = state
-        .services
-        .search()
-        .get_asset(&asset_id)
-        .await;
+    let result = state.services.search().get_asset(&asset_id).await;
 
     match result {
         Ok(Some(asset)) => {
-            let _ = collector.attach_artifact(
-                span_id,
-                SpanArtifact {
-                    name: "asset".to_string(),
-                    content_type: Some("application/json".to_string()),
-                    data: serde_json::to_value(&asset).unwrap_or_default(),
-                },
-            );
+            attach_result(&collector, span_id, "asset", &asset);
             collector.end_agent_span(span_id, SpanStatus::Ok);
             let exec = collector.finalize();
-            Ok(ok_with_execution(asset, exec))
+            let etag = etag_header(&asset);
+            Ok((
+                etag,
+                ok_with_execution(Projected::new(asset, projection), exec),
+            ))
         }
         Ok(None) => {
             let _ = collector.attach_artifact(
@@ -165,24 +670,60 @@ pub async fn get_asset(
 }
 
 /// List/search assets with pagination
+///
+/// A `fields` query parameter limits each asset to the named fields. The
+/// `SearchService` span carries a `query_timing` artifact splitting the
+/// latency into parameter validation, the search itself, and shaping the
+/// page; encoding the response body happens afterwards and is not included.
 #[instrument(skip(state, collector))]
 pub async fn list_assets(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
-    Query(params): Query<SearchAssetsRequest>,
-) -> ApiResult<Json<PaginatedExecutionEnvelope<llm_registry_core::Asset>>> {
+    Query(mut params): Query<SearchAssetsRequest>,
+    Query(fields): Query<FieldsParams>,
+) -> ApiResult<Json<PaginatedExecutionEnvelope<Projected<Asset>>>> {
     debug!("Searching assets with filters: {:?}", params);
+    let validate_started = Instant::now();
 
-    let span_id = collector.begin_agent_span("SearchService");
+    let limit = effective_limit(params.limit, state.max_page_size);
+    if limit != params.limit {
+        debug!(
+            requested = params.limit,
+            effective = limit,
+            "Adjusted search page size"
+        );
+        params.limit = limit;
+    }
 
-    let result = state
-        .services
-        .search()
-        .search_assets(params)
-        .await;
+    if let Err(e) = params.sort_field() {
+        let exec = collector.finalize_failed("Invalid sort field");
+        return Err(ApiError::bad_request(e).with_execution(exec));
+    }
+
+    if let Err(e) = params.label_selector() {
+        let exec = collector.finalize_failed("Invalid label selector");
+        return Err(ApiError::bad_request(e).with_execution(exec));
+    }
+
+    let projection = match fields.projection() {
+        Ok(projection) => projection,
+        Err(e) => {
+            let exec = collector.finalize_failed("Invalid fields");
+            return Err(ApiError::bad_request(e).with_execution(exec));
+        }
+    };
+
+    let validate_elapsed = validate_started.elapsed();
+
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
+
+    let started = Instant::now();
+    let result = state.services.search().search_assets(params).await;
+    let db_elapsed = started.elapsed();
 
     match result {
         Ok(response) => {
+            state.metrics.record_search(db_elapsed);
             let _ = collector.attach_artifact(
                 span_id,
                 SpanArtifact {
@@ -194,20 +735,41 @@ pub async fn list_assets(
                     }),
                 },
             );
-            collector.end_agent_span(span_id, SpanStatus::Ok);
-            let exec = collector.finalize();
 
+            let shape_started = Instant::now();
             let has_more = response.offset + response.assets.len() as i64
                 > response.total.min(response.offset + response.limit);
+            let items: Vec<_> = response
+                .assets
+                .into_iter()
+                .map(|asset| Projected::new(asset, projection.clone()))
+                .collect();
+            let shape_elapsed = shape_started.elapsed();
+
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "query_timing".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "validate_ms": validate_elapsed.as_secs_f64() * 1000.0,
+                        "db_ms": db_elapsed.as_secs_f64() * 1000.0,
+                        "shape_ms": shape_elapsed.as_secs_f64() * 1000.0,
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
 
             Ok(Json(PaginatedExecutionEnvelope {
-                items: response.assets,
+                items,
                 pagination: PaginationMeta {
                     total: response.total,
                     offset: response.offset,
                     limit: response.limit,
                     has_more,
                 },
+                facets: response.facets,
                 execution: exec,
             }))
         }
@@ -233,8 +795,9 @@ pub async fn update_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
     Path(id): Path<String>,
+    headers: HeaderMap,
     Json(mut request): Json<UpdateAssetRequest>,
-) -> ApiResult<Json<ExecutionEnvelope<llm_registry_service::UpdateAssetResponse>>> {
+) -> ApiResult<WithEtag<ExecutionEnvelope<llm_registry_service::UpdateAssetResponse>>> {
     info!("Updating asset: {}", id);
 
     let asset_id = id.parse::<AssetId>().map_err(|e| {
@@ -246,27 +809,136 @@ pub async fn update_asset(
     // Set asset ID from path
     request.asset_id = asset_id;
 
-    let span_id = collector.begin_agent_span("RegistrationService");
+    // If-Match takes precedence over an expected_version in the body
+    match parse_if_match(&headers) {
+        Ok(Some(expected)) => request.expected_version = Some(expected),
+        Ok(None) => {}
+        Err(e) => {
+            let exec = collector.finalize_failed("Invalid If-Match header");
+            return Err(ApiError::bad_request(e).with_execution(exec));
+        }
+    }
 
-    let result = state
-        .services
-        .registration()
-        .update_asset(request)
-        .await;
+    apply_update(
+        &collector,
+        state.services.registration().update_asset(request),
+    )
+    .await
+}
 
-    match result {
-        Ok(response) => {
-            let _ = collector.attach_artifact(
-                span_id,
-                SpanArtifact {
-                    name: "updated_asset".to_string(),
-                    content_type: Some("application/json".to_string()),
-                    data: serde_json::to_value(&response.asset).unwrap_or_default(),
-                },
+/// Media type of an RFC 7386 JSON Merge Patch body
+pub const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+
+/// Body of `PATCH /v1/assets/{id}`, chosen by the request's `Content-Type`
+#[derive(Debug, Clone)]
+pub enum AssetPatchBody {
+    /// `application/json`: a field-list [`AssetPatch`]
+    Fields(Box<AssetPatch>),
+    /// `application/merge-patch+json`: a merge patch of the asset's metadata
+    MergePatch(serde_json::Value),
+}
+
+impl<S: Send + Sync> FromRequest<S> for AssetPatchBody {
+    type Rejection = JsonRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let merge_patch = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .is_some_and(|media_type| {
+                media_type
+                    .trim()
+                    .eq_ignore_ascii_case(MERGE_PATCH_CONTENT_TYPE)
+            });
+
+        if merge_patch {
+            let Json(patch) = Json::<serde_json::Value>::from_request(req, state).await?;
+            Ok(Self::MergePatch(patch))
+        } else {
+            let Json(patch) = Json::<AssetPatch>::from_request(req, state).await?;
+            Ok(Self::Fields(Box::new(patch)))
+        }
+    }
+}
+
+/// Partially update an asset
+///
+/// A JSON body is an [`AssetPatch`]: only fields present are applied and
+/// everything else is left untouched. An `application/merge-patch+json`
+/// body is applied to the asset's metadata with RFC 7386 semantics (`null`
+/// deletes a key, objects merge recursively), and the result is checked
+/// against the validation constraints.
+#[instrument(skip(state, collector, body))]
+pub async fn patch_asset(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    body: AssetPatchBody,
+) -> ApiResult<WithEtag<ExecutionEnvelope<llm_registry_service::UpdateAssetResponse>>> {
+    info!("Patching asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let if_match = match parse_if_match(&headers) {
+        Ok(if_match) => if_match,
+        Err(e) => {
+            let exec = collector.finalize_failed("Invalid If-Match header");
+            return Err(ApiError::bad_request(e).with_execution(exec));
+        }
+    };
+
+    match body {
+        AssetPatchBody::Fields(patch) => {
+            let mut request = patch.into_update_request(asset_id);
+            // If-Match takes precedence over an expected_version in the body
+            if if_match.is_some() {
+                request.expected_version = if_match;
+            }
+            apply_update(
+                &collector,
+                state.services.registration().update_asset(request),
+            )
+            .await
+        }
+        AssetPatchBody::MergePatch(patch) => {
+            let constraints = validation_constraints(&state).await;
+            let registration = state.services.registration();
+            let update = registration.merge_patch_metadata(
+                &asset_id,
+                &patch,
+                if_match.as_deref(),
+                &constraints,
             );
+            apply_update(&collector, update).await
+        }
+    }
+}
+
+/// Run an update through the registration service inside an agent span
+///
+/// `update` is not polled before the span begins.
+async fn apply_update(
+    collector: &SpanCollector,
+    update: impl std::future::Future<
+        Output = llm_registry_service::ServiceResult<llm_registry_service::UpdateAssetResponse>,
+    >,
+) -> ApiResult<WithEtag<ExecutionEnvelope<llm_registry_service::UpdateAssetResponse>>> {
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
+
+    match update.await {
+        Ok(response) => {
+            attach_result(collector, span_id, "updated_asset", &response.asset);
             collector.end_agent_span(span_id, SpanStatus::Ok);
             let exec = collector.finalize();
-            Ok(ok_with_execution(response, exec))
+            let etag = etag_header(&response.asset);
+            Ok((etag, ok_with_execution(response, exec)))
         }
         Err(e) => {
             let _ = collector.attach_artifact(
@@ -284,14 +956,30 @@ pub async fn update_asset(
     }
 }
 
+/// Query parameters for deleting an asset
+#[derive(Debug, Default, Deserialize)]
+pub struct DeleteAssetParams {
+    /// Delete permanently instead of soft-deleting
+    #[serde(default)]
+    pub hard: bool,
+}
+
 /// Delete asset
-#[instrument(skip(state, collector))]
+///
+/// Soft-deletes by default, so the asset can be restored within the grace
+/// window; `?hard=true` deletes it permanently.
+#[instrument(skip(state, collector, auth_user))]
 pub async fn delete_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
+    auth_user: Option<Extension<AuthUser>>,
     Path(id): Path<String>,
-) -> ApiResult<(StatusCode, Json<ExecutionEnvelope<crate::responses::EmptyResponse>>)> {
-    info!("Deleting asset: {}", id);
+    Query(params): Query<DeleteAssetParams>,
+) -> ApiResult<(
+    StatusCode,
+    Json<ExecutionEnvelope<crate::responses::EmptyResponse>>,
+)> {
+    info!("Deleting asset: {} (hard: {})", id, params.hard);
 
     let asset_id = id.parse::<AssetId>().map_err(|e| {
         let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
@@ -299,16 +987,28 @@ pub async fn delete_asset(
         err.with_execution(exec)
     })?;
 
-    let span_id = collector.begin_agent_span("RegistrationService");
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
 
-    let result = state
-        .services
-        .registration()
-        .delete_asset(&asset_id)
-        .await;
+    let registration = state.services.registration();
+    let result = if params.hard {
+        registration.purge_asset(&asset_id).await
+    } else {
+        registration.delete_asset(&asset_id).await
+    };
 
     match result {
         Ok(()) => {
+            let deleted_by = auth_user
+                .map(|Extension(user)| user.claims.sub)
+                .unwrap_or_else(|| "anonymous".to_string());
+            if let Err(e) = state
+                .observatory
+                .trace_asset_deletion(&id, &deleted_by, params.hard)
+                .await
+            {
+                warn!("Failed to emit asset deletion event: {}", e);
+            }
+
             let _ = collector.attach_artifact(
                 span_id,
                 SpanArtifact {
@@ -337,19 +1037,15 @@ pub async fn delete_asset(
     }
 }
 
-// ============================================================================
-// Dependency Handlers
-// ============================================================================
-
-/// Get dependency graph for an asset
-#[instrument(skip(state, collector))]
-pub async fn get_dependencies(
+/// Restore a soft-deleted asset within the grace window
+#[instrument(skip(state, collector, auth_user))]
+pub async fn restore_asset(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
+    auth_user: Option<Extension<AuthUser>>,
     Path(id): Path<String>,
-    Query(params): Query<DependencyGraphParams>,
-) -> ApiResult<Json<ExecutionEnvelope<llm_registry_service::DependencyGraphResponse>>> {
-    debug!("Getting dependency graph for asset: {}", id);
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+    info!("Restoring asset: {}", id);
 
     let asset_id = id.parse::<AssetId>().map_err(|e| {
         let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
@@ -357,32 +1053,32 @@ pub async fn get_dependencies(
         err.with_execution(exec)
     })?;
 
-    let request = GetDependencyGraphRequest {
-        asset_id,
-        max_depth: params.max_depth.unwrap_or(-1),
-    };
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
 
-    let span_id = collector.begin_agent_span("SearchService");
-
-    let result = state
-        .services
-        .search()
-        .get_dependency_graph(request)
-        .await;
+    match state.services.registration().restore_asset(&asset_id).await {
+        Ok(asset) => {
+            let restored_by = auth_user
+                .map(|Extension(user)| user.claims.sub)
+                .unwrap_or_else(|| "anonymous".to_string());
+            if let Err(e) = state
+                .observatory
+                .trace_asset_restoration(&id, &restored_by)
+                .await
+            {
+                warn!("Failed to emit asset restoration event: {}", e);
+            }
 
-    match result {
-        Ok(response) => {
             let _ = collector.attach_artifact(
                 span_id,
                 SpanArtifact {
-                    name: "dependency_graph".to_string(),
-                    content_type: Some("application/json".to_string()),
-                    data: serde_json::to_value(&response).unwrap_or_default(),
+                    name: "restored_asset_id".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(id),
                 },
             );
             collector.end_agent_span(span_id, SpanStatus::Ok);
             let exec = collector.finalize();
-            Ok(ok_with_execution(response, exec))
+            Ok(ok_with_execution(asset, exec))
         }
         Err(e) => {
             let _ = collector.attach_artifact(
@@ -400,49 +1096,119 @@ pub async fn get_dependencies(
     }
 }
 
-/// Query parameters for dependency graph
+/// Query parameters for comparing two assets
 #[derive(Debug, Deserialize)]
-pub struct DependencyGraphParams {
-    /// Maximum depth to traverse (-1 for unlimited)
-    pub max_depth: Option<i32>,
+pub struct CompareAssetsParams {
+    /// Asset compared from
+    pub a: String,
+    /// Asset compared to
+    pub b: String,
 }
 
-/// Get reverse dependencies (dependents)
+/// Compare two assets, typically two versions of the same one
+///
+/// Reports metadata, tag, and dependency differences from `a` to `b`.
 #[instrument(skip(state, collector))]
-pub async fn get_dependents(
+pub async fn compare_asset_versions(
     State(state): State<AppState>,
     Extension(collector): Extension<SpanCollector>,
-    Path(id): Path<String>,
-) -> ApiResult<Json<ExecutionEnvelope<Vec<llm_registry_core::Asset>>>> {
-    debug!("Getting dependents for asset: {}", id);
+    Query(params): Query<CompareAssetsParams>,
+) -> ApiResult<Json<ExecutionEnvelope<AssetComparison>>> {
+    debug!("Comparing assets {} and {}", params.a, params.b);
 
-    let asset_id = id.parse::<AssetId>().map_err(|e| {
-        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
-        let exec = collector.finalize_failed("Invalid asset ID");
-        err.with_execution(exec)
-    })?;
+    let mut ids = Vec::with_capacity(2);
+    for (param, id) in [("a", &params.a), ("b", &params.b)] {
+        match id.parse::<AssetId>() {
+            Ok(asset_id) => ids.push((param, asset_id)),
+            Err(e) => {
+                let exec = collector.finalize_failed("Invalid asset ID");
+                return Err(ApiError::bad_request(format!(
+                    "Invalid asset ID for '{}': {}",
+                    param, e
+                ))
+                .with_execution(exec));
+            }
+        }
+    }
 
-    let span_id = collector.begin_agent_span("SearchService");
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
 
-    let result = state
-        .services
-        .search()
-        .get_reverse_dependencies(&asset_id)
-        .await;
+    let mut assets = Vec::with_capacity(2);
+    for (param, asset_id) in ids {
+        let error = match state.services.search().get_asset(&asset_id).await {
+            Ok(Some(asset)) => {
+                assets.push(asset);
+                continue;
+            }
+            Ok(None) => ApiError::not_found(format!("Asset '{}' not found: {}", param, asset_id))
+                .with_details(serde_json::json!({ "missing": param })),
+            Err(e) => ApiError::from(e),
+        };
 
-    match result {
-        Ok(dependents) => {
+        let _ = collector.attach_artifact(
+            span_id,
+            SpanArtifact {
+                name: "error".to_string(),
+                content_type: Some("text/plain".to_string()),
+                data: serde_json::Value::String(error.to_string()),
+            },
+        );
+        collector.end_agent_span(span_id, SpanStatus::Failed);
+        let exec = collector.finalize();
+        return Err(error.with_execution(exec));
+    }
+
+    let comparison = llm_registry_service::compare_assets(&assets[0], &assets[1]);
+    attach_result(&collector, span_id, "asset_comparison", &comparison);
+    collector.end_agent_span(span_id, SpanStatus::Ok);
+    let exec = collector.finalize();
+    Ok(ok_with_execution(comparison, exec))
+}
+
+/// Query parameters for resolving the latest version of an asset
+#[derive(Debug, Default, Deserialize)]
+pub struct ResolveLatestParams {
+    /// Semver range the version must satisfy, such as `^1.2`
+    pub constraint: Option<String>,
+    /// Also consider deprecated versions
+    #[serde(default)]
+    pub include_deprecated: bool,
+    /// Also consider versions past their TTL
+    #[serde(default)]
+    pub include_expired: bool,
+}
+
+/// Query parameters for listing tags
+#[derive(Debug, Default, Deserialize)]
+pub struct TagsParams {
+    /// Only list tags starting with this prefix
+    pub prefix: Option<String>,
+}
+
+/// List tags with the number of assets carrying each, most used first
+#[instrument(skip(state, collector))]
+pub async fn list_tags(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Query(params): Query<TagsParams>,
+) -> ApiResult<Json<ExecutionEnvelope<Vec<TagCount>>>> {
+    debug!("Listing tags");
+
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
+
+    match state.services.search().list_tag_counts(params.prefix).await {
+        Ok(tags) => {
             let _ = collector.attach_artifact(
                 span_id,
                 SpanArtifact {
-                    name: "dependents".to_string(),
+                    name: "tag_count".to_string(),
                     content_type: Some("application/json".to_string()),
-                    data: serde_json::json!({ "count": dependents.len() }),
+                    data: serde_json::json!(tags.len()),
                 },
             );
             collector.end_agent_span(span_id, SpanStatus::Ok);
             let exec = collector.finalize();
-            Ok(ok_with_execution(dependents, exec))
+            Ok(ok_with_execution(tags, exec))
         }
         Err(e) => {
             let _ = collector.attach_artifact(
@@ -460,154 +1226,5440 @@ pub async fn get_dependents(
     }
 }
 
-// ============================================================================
-// Health & Metrics Handlers (NOT instrumented with execution spans —
-// these are infrastructure endpoints outside the /v1 execution boundary)
-// ============================================================================
+/// Summarize asset counts, storage usage and request activity
+///
+/// Counts come from a single aggregate query, cached briefly by the search
+/// service; registration and search figures cover this instance's lifetime.
+#[instrument(skip(state, collector))]
+pub async fn get_stats(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<Json<ExecutionEnvelope<RegistryMetrics>>> {
+    debug!("Computing registry stats");
 
-/// Health check endpoint
-#[instrument(skip(state))]
-pub async fn health_check(State(state): State<AppState>) -> ApiResult<HealthResponse> {
-    debug!("Health check requested");
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
 
-    // For now, simple health check
-    // In production, you'd check database connectivity, etc.
-    let mut response = HealthResponse::healthy()
-        .with_version(env!("CARGO_PKG_VERSION"));
+    match state.services.search().registry_stats().await {
+        Ok(asset_stats) => {
+            let stats = state
+                .metrics
+                .registry_metrics()
+                .with_asset_stats(&asset_stats);
+            attach_result(&collector, span_id, "asset_stats", &asset_stats);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(stats, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
 
-    // Add database health check
-    // Try to perform a simple database operation
-    let db_health = match state.services.search().list_all_tags().await {
-        Ok(_) => ComponentHealth::healthy(),
-        Err(e) => ComponentHealth::unhealthy(format!("Database error: {}", e)),
-    };
+/// Resolve the highest active version of an asset by name
+///
+/// Versions are ordered by semver precedence. Responds 404 when no version
+/// qualifies.
+#[instrument(skip(state, collector))]
+pub async fn resolve_latest_asset(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(name): Path<String>,
+    Query(params): Query<ResolveLatestParams>,
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+    debug!("Resolving latest version of {}", name);
 
-    response = response
-        .with_check("database", db_health)
-        .with_check("service", ComponentHealth::healthy())
-        .compute_status();
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
+
+    let request = ResolveLatestRequest {
+        name: name.clone(),
+        constraint: params.constraint.clone(),
+        include_deprecated: params.include_deprecated,
+        include_expired: params.include_expired,
+    };
+    let error = match state.services.search().resolve_latest(request).await {
+        Ok(Some(asset)) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "resolved_version".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "asset_id": asset.id.to_string(),
+                        "version": asset.metadata.version.to_string(),
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            return Ok(ok_with_execution(asset, exec));
+        }
+        Ok(None) => match params.constraint {
+            Some(constraint) => ApiError::not_found(format!(
+                "No active version of '{}' matches '{}'",
+                name, constraint
+            )),
+            None => ApiError::not_found(format!("No active version of '{}'", name)),
+        },
+        Err(e) => ApiError::from(e),
+    };
 
-    Ok(response)
+    let _ = collector.attach_artifact(
+        span_id,
+        SpanArtifact {
+            name: "error".to_string(),
+            content_type: Some("text/plain".to_string()),
+            data: serde_json::Value::String(error.to_string()),
+        },
+    );
+    collector.end_agent_span(span_id, SpanStatus::Failed);
+    let exec = collector.finalize();
+    Err(error.with_execution(exec))
 }
 
-/// Metrics endpoint (Prometheus format)
+/// Deprecate an asset, keeping it queryable
 ///
-/// This endpoint exposes Prometheus metrics for monitoring.
-/// Metrics are collected throughout the application lifecycle.
-#[instrument]
-pub async fn metrics() -> ApiResult<String> {
-    debug!("Metrics requested");
+/// Deprecated assets drop out of default search results; pass
+/// `include_deprecated=true` to list them.
+#[instrument(skip(state, collector, auth_user, request))]
+pub async fn deprecate_asset(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    auth_user: Option<Extension<AuthUser>>,
+    Path(id): Path<String>,
+    Json(request): Json<DeprecateAssetRequest>,
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+    info!("Deprecating asset: {}", id);
 
-    // Return basic info - actual metrics are handled by the server binary
-    // which has access to the prometheus registry
-    let metrics = format!(
-        "# HELP llm_registry_info Registry information\n\
-         # TYPE llm_registry_info gauge\n\
-         llm_registry_info{{version=\"{}\"}} 1\n",
-        env!("CARGO_PKG_VERSION")
-    );
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
 
-    Ok(metrics)
-}
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
+    let reason = request.reason.clone();
 
-// ============================================================================
-// Version & Info Handlers
-// ============================================================================
+    let result = state
+        .services
+        .registration()
+        .deprecate_asset(&asset_id, request)
+        .await;
 
-/// Get API version information
-#[instrument]
-pub async fn version_info() -> ApiResult<Json<crate::responses::ApiResponse<VersionInfo>>> {
-    let info = VersionInfo {
-        version: env!("CARGO_PKG_VERSION").to_string(),
-        api_version: "v1".to_string(),
-        build_timestamp: option_env!("BUILD_TIMESTAMP")
-            .unwrap_or("unknown")
-            .to_string(),
-    };
+    match result {
+        Ok(asset) => {
+            let deprecated_by = auth_user
+                .map(|Extension(user)| user.claims.sub)
+                .unwrap_or_else(|| "anonymous".to_string());
+            if let Err(e) = state
+                .observatory
+                .trace_asset_deprecation(&asset.id.to_string(), reason.trim(), &deprecated_by)
+                .await
+            {
+                warn!("Failed to emit asset deprecation event: {}", e);
+            }
 
-    Ok(Json(crate::responses::ok(info)))
+            attach_result(&collector, span_id, "deprecated_asset", &asset);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(asset, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
 }
 
-/// Version information
-#[derive(Debug, Serialize, Deserialize)]
-pub struct VersionInfo {
-    /// Semantic version
-    pub version: String,
+/// Maximum number of ids accepted by a single batch delete
+pub const MAX_BATCH_DELETE: usize = 1000;
 
-    /// API version
-    pub api_version: String,
+/// Outcome of deleting one asset in a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteResult {
+    /// Asset ID as supplied by the caller
+    pub id: String,
+    /// Whether the asset was deleted
+    pub deleted: bool,
+    /// Why the asset was not deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
 
-    /// Build timestamp
-    pub build_timestamp: String,
+/// Delete several assets, reporting the outcome per id
+///
+/// Failures (malformed ids, missing assets, assets with dependents) are
+/// reported per item and never fail the whole request. Each delete runs in
+/// its own agent span.
+#[instrument(skip(state, collector, auth_user, ids), fields(count = ids.len()))]
+pub async fn batch_delete_assets(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    auth_user: Option<Extension<AuthUser>>,
+    Json(ids): Json<Vec<String>>,
+) -> ApiResult<Json<ExecutionEnvelope<Vec<DeleteResult>>>> {
+    info!("Batch deleting {} assets", ids.len());
+
+    if ids.len() > MAX_BATCH_DELETE {
+        let exec = collector.finalize_failed("Batch too large");
+        return Err(ApiError::bad_request(format!(
+            "Batch delete accepts at most {} ids, got {}",
+            MAX_BATCH_DELETE,
+            ids.len()
+        ))
+        .with_execution(exec));
+    }
+
+    let deleted_by = auth_user
+        .map(|Extension(user)| user.claims.sub)
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    let mut results = Vec::with_capacity(ids.len());
+    for id in ids {
+        let asset_id = match id.parse::<AssetId>() {
+            Ok(asset_id) => asset_id,
+            Err(e) => {
+                results.push(DeleteResult {
+                    id,
+                    deleted: false,
+                    error: Some(format!("Invalid asset ID: {}", e)),
+                });
+                continue;
+            }
+        };
+
+        let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
+
+        match state.services.registration().delete_asset(&asset_id).await {
+            Ok(()) => {
+                if let Err(e) = state
+                    .observatory
+                    .trace_asset_deletion(&id, &deleted_by, false)
+                    .await
+                {
+                    warn!("Failed to emit asset deletion event: {}", e);
+                }
+
+                let _ = collector.attach_artifact(
+                    span_id,
+                    SpanArtifact {
+                        name: "deleted_asset_id".to_string(),
+                        content_type: Some("text/plain".to_string()),
+                        data: serde_json::Value::String(id.clone()),
+                    },
+                );
+                collector.end_agent_span(span_id, SpanStatus::Ok);
+                results.push(DeleteResult {
+                    id,
+                    deleted: true,
+                    error: None,
+                });
+            }
+            Err(e) => {
+                let _ = collector.attach_artifact(
+                    span_id,
+                    SpanArtifact {
+                        name: "error".to_string(),
+                        content_type: Some("text/plain".to_string()),
+                        data: serde_json::Value::String(e.to_string()),
+                    },
+                );
+                collector.end_agent_span(span_id, SpanStatus::Failed);
+                results.push(DeleteResult {
+                    id,
+                    deleted: false,
+                    error: Some(e.to_string()),
+                });
+            }
+        }
+    }
+
+    debug!(
+        deleted = results.iter().filter(|r| r.deleted).count(),
+        failed = results.iter().filter(|r| !r.deleted).count(),
+        "Batch delete finished"
+    );
+
+    let exec = collector.finalize();
+    Ok(ok_with_execution(results, exec))
 }
 
 // ============================================================================
-// Execution Ingestion Handler (data-core fanout)
+// Dependency Handlers
 // ============================================================================
 
-/// Payload from data-core execution fanout
-#[derive(Debug, Deserialize)]
-pub struct ExecutionRecordRequest {
-    /// Source system
-    pub source: String,
+/// Get dependency graph for an asset
+#[instrument(skip(state, collector))]
+pub async fn get_dependencies(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Query(params): Query<DependencyGraphParams>,
+) -> ApiResult<Json<ExecutionEnvelope<llm_registry_service::DependencyGraphResponse>>> {
+    debug!("Getting dependency graph for asset: {}", id);
 
-    /// Event type
-    pub event_type: String,
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
 
-    /// Execution identifier
-    pub execution_id: String,
+    let request = GetDependencyGraphRequest {
+        asset_id,
+        max_depth: params.max_depth.unwrap_or(-1),
+    };
 
-    /// ISO-8601 timestamp
-    pub timestamp: String,
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
 
-    /// Lineage/execution data
-    pub payload: serde_json::Value,
-}
+    let result = state.services.search().get_dependency_graph(request).await;
 
-/// Response for accepted execution records
-#[derive(Debug, Serialize)]
-pub struct ExecutionAcceptedResponse {
-    pub status: String,
-    pub execution_id: String,
+    match result {
+        Ok(response) => {
+            attach_result(&collector, span_id, "dependency_graph", &response);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(response, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
 }
 
-/// Accept an execution record from data-core fanout.
+/// Add several dependencies to an asset in one operation
 ///
-/// This endpoint lives outside the execution-context middleware because it
-/// *receives* execution records rather than participating in the span system.
-#[instrument(skip(request), fields(execution_id = %request.execution_id, source = %request.source))]
-pub async fn receive_execution(
-    Json(request): Json<ExecutionRecordRequest>,
-) -> (StatusCode, Json<ExecutionAcceptedResponse>) {
+/// Either all listed assets become dependencies or none do. A target that
+/// does not exist or would introduce a cycle fails the request with its id
+/// in `details.dependency_id`.
+#[instrument(skip(state, collector, dependency_ids))]
+pub async fn add_dependencies(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Json(dependency_ids): Json<Vec<AssetId>>,
+) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
     info!(
-        execution_id = %request.execution_id,
-        source = %request.source,
-        event_type = %request.event_type,
-        "Accepted execution record from data-core"
+        "Adding {} dependencies to asset: {}",
+        dependency_ids.len(),
+        id
     );
 
-    (
-        StatusCode::ACCEPTED,
-        Json(ExecutionAcceptedResponse {
-            status: "accepted".to_string(),
-            execution_id: request.execution_id,
-        }),
-    )
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
+
+    match state
+        .services
+        .registration()
+        .add_dependencies(&asset_id, &dependency_ids)
+        .await
+    {
+        Ok(asset) => {
+            attach_result(&collector, span_id, "added_dependencies", &dependency_ids);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(asset, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+
+            let offending = match &e {
+                ServiceError::DependencyNotFound(dep_id)
+                | ServiceError::CircularDependency(dep_id) => Some(dep_id.clone()),
+                _ => None,
+            };
+            let mut err = ApiError::from(e);
+            if let Some(dep_id) = offending {
+                err = err.with_details(serde_json::json!({ "dependency_id": dep_id }));
+            }
+            Err(err.with_execution(exec))
+        }
+    }
+}
+
+/// Path parameters identifying a single dependency edge
+#[derive(Debug, Deserialize)]
+pub struct DependencyEdgePath {
+    /// The dependent asset
+    pub id: String,
+    /// The asset depended upon
+    pub dep_id: String,
+}
+
+/// Remove a single dependency from an asset
+///
+/// Responds `204 No Content` on success and `404 Not Found` when the asset
+/// does not depend on `dep_id`.
+#[instrument(skip(state, collector))]
+pub async fn remove_dependency(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(path): Path<DependencyEdgePath>,
+) -> ApiResult<StatusCode> {
+    info!("Removing dependency {} from asset {}", path.dep_id, path.id);
+
+    let mut ids = Vec::with_capacity(2);
+    for raw in [&path.id, &path.dep_id] {
+        match raw.parse::<AssetId>() {
+            Ok(asset_id) => ids.push(asset_id),
+            Err(e) => {
+                let exec = collector.finalize_failed("Invalid asset ID");
+                return Err(
+                    ApiError::bad_request(format!("Invalid asset ID: {}", e)).with_execution(exec)
+                );
+            }
+        }
+    }
+    let (asset_id, dependency_id) = (ids[0], ids[1]);
+
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
+
+    match state
+        .services
+        .registration()
+        .remove_dependency(&asset_id, &dependency_id)
+        .await
+    {
+        Ok(()) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "removed_dependency".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({
+                        "asset_id": path.id,
+                        "dependency_id": path.dep_id,
+                    }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            collector.finalize();
+            Ok(no_content())
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Render an asset's dependency graph as Graphviz DOT
+///
+/// Nodes are labelled `name@version` and edges point from dependent to
+/// dependency. Edges closing a detected cycle are drawn dashed.
+#[instrument(skip(state, collector))]
+pub async fn get_dependencies_dot(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Query(params): Query<DependencyGraphParams>,
+) -> ApiResult<Response> {
+    debug!("Rendering dependency graph for asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let request = GetDependencyGraphRequest {
+        asset_id,
+        max_depth: params.max_depth.unwrap_or(-1),
+    };
+
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
+
+    let search = state.services.search();
+    let result = match search.get_dependency_graph(request).await {
+        Ok(graph) => search.get_asset(&asset_id).await.map(|root| (graph, root)),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok((graph, root)) => {
+            let root_label = match root {
+                Some(root) => format!("{}@{}", root.metadata.name, root.metadata.version),
+                None => asset_id.to_string(),
+            };
+            let dot = dependency_graph_dot(&root_label, &graph);
+            attach_result(&collector, span_id, "dependency_graph", &graph);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            collector.finalize();
+            Ok((
+                [(header::CONTENT_TYPE, "text/vnd.graphviz; charset=utf-8")],
+                dot,
+            )
+                .into_response())
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Serialize a dependency graph as a DOT `digraph`
+fn dependency_graph_dot(
+    root_label: &str,
+    graph: &llm_registry_service::DependencyGraphResponse,
+) -> String {
+    use std::collections::HashMap;
+    use std::fmt::Write;
+
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+
+    // The root sits one level above its direct dependencies
+    let mut depths: HashMap<AssetId, i32> = HashMap::from([(graph.root, -1)]);
+    depths.extend(
+        graph
+            .dependencies
+            .iter()
+            .map(|node| (node.asset_id, node.depth)),
+    );
+
+    let closes_cycle = |from: &AssetId, to: &AssetId| {
+        depths[to] <= depths[from]
+            && graph
+                .cycles
+                .iter()
+                .any(|cycle| cycle.contains(from) && cycle.contains(to))
+    };
+
+    let mut dot = String::from("digraph dependencies {\n");
+    let _ = writeln!(
+        dot,
+        "  {} [label={}];",
+        quote(&graph.root.to_string()),
+        quote(root_label)
+    );
+    for node in &graph.dependencies {
+        let label = format!("{}@{}", node.name, node.version);
+        let _ = writeln!(
+            dot,
+            "  {} [label={}];",
+            quote(&node.asset_id.to_string()),
+            quote(&label)
+        );
+    }
+
+    let root_edges = graph
+        .dependencies
+        .iter()
+        .filter(|node| node.depth == 0)
+        .map(|node| (graph.root, node.asset_id));
+    let node_edges = graph.dependencies.iter().flat_map(|node| {
+        node.dependencies
+            .iter()
+            // Edges leaving the traversed depth have no node to point at
+            .filter(|dep| depths.contains_key(dep))
+            .map(move |dep| (node.asset_id, *dep))
+    });
+    for (from, to) in root_edges.chain(node_edges) {
+        let style = if closes_cycle(&from, &to) {
+            " [style=dashed]"
+        } else {
+            ""
+        };
+        let _ = writeln!(
+            dot,
+            "  {} -> {}{};",
+            quote(&from.to_string()),
+            quote(&to.to_string()),
+            style
+        );
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Query parameters for dependency graph
+#[derive(Debug, Deserialize)]
+pub struct DependencyGraphParams {
+    /// Maximum depth to traverse (-1 for unlimited)
+    pub max_depth: Option<i32>,
+}
+
+/// Query parameters for dependents
+#[derive(Debug, Deserialize)]
+pub struct DependentsParams {
+    /// Include transitive dependents with their hop distance
+    pub transitive: Option<bool>,
+}
+
+/// Direct dependents, or transitive dependents with hop distances
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum DependentsResponse {
+    /// Assets that depend on the target directly
+    Direct(Vec<Asset>),
+    /// Every downstream asset, nearest first
+    Transitive(Vec<TransitiveDependent>),
+}
+
+impl DependentsResponse {
+    fn len(&self) -> usize {
+        match self {
+            DependentsResponse::Direct(assets) => assets.len(),
+            DependentsResponse::Transitive(dependents) => dependents.len(),
+        }
+    }
+}
+
+/// Get reverse dependencies (dependents)
+#[instrument(skip(state, collector))]
+pub async fn get_dependents(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Query(params): Query<DependentsParams>,
+) -> ApiResult<Json<ExecutionEnvelope<DependentsResponse>>> {
+    debug!("Getting dependents for asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let transitive = params.transitive.unwrap_or(false);
+    let span_id = collector.begin_agent_span(AgentService::Search.as_str());
+
+    let search = state.services.search();
+    let result = if transitive {
+        search
+            .get_transitive_dependents(&asset_id)
+            .await
+            .map(DependentsResponse::Transitive)
+    } else {
+        search
+            .get_reverse_dependencies(&asset_id)
+            .await
+            .map(DependentsResponse::Direct)
+    };
+
+    match result {
+        Ok(dependents) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "dependents".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "count": dependents.len(), "transitive": transitive }),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(dependents, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Rebuild the dependents index from the assets' forward edges
+///
+/// Repairs drift left by bulk imports that bypassed registration. Safe to
+/// run repeatedly and while the registry is serving traffic.
+#[instrument(skip(state, collector))]
+pub async fn reindex_dependents(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<Json<ExecutionEnvelope<DependentsReindex>>> {
+    info!("Rebuilding dependents index");
+
+    let span_id = collector.begin_agent_span(AgentService::Registration.as_str());
+
+    match state.services.registration().reindex_dependents().await {
+        Ok(report) => {
+            attach_result(&collector, span_id, "reindex_report", &report);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(report, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+// ============================================================================
+// Health & Metrics Handlers (NOT instrumented with execution spans —
+// these are infrastructure endpoints outside the /v1 execution boundary)
+// ============================================================================
+
+/// Age after which cached configuration is reported as stale
+pub const CONFIG_STALE_AFTER: Duration = Duration::from_secs(300);
+
+/// Health check endpoint
+///
+/// Combined probe kept for existing clients; it reports the same checks as
+/// [`readiness`].
+#[instrument(skip(state))]
+pub async fn health_check(State(state): State<AppState>) -> ApiResult<HealthResponse> {
+    debug!("Health check requested");
+    Ok(dependency_health(&state).await)
+}
+
+/// Liveness probe
+///
+/// Answers `200` whenever the process can serve requests; dependencies are
+/// not checked, so an outage never gets the pod restarted.
+pub async fn liveness() -> HealthResponse {
+    HealthResponse::healthy().with_version(env!("CARGO_PKG_VERSION"))
+}
+
+/// Readiness probe
+///
+/// Runs the dependency checks and answers `503` while the database is down.
+#[instrument(skip(state))]
+pub async fn readiness(State(state): State<AppState>) -> ApiResult<HealthResponse> {
+    debug!("Readiness check requested");
+    Ok(dependency_health(&state).await)
+}
+
+/// Probe the registry's dependencies
+///
+/// The database is always probed; the config manager and schema registry are
+/// checked when wired into [`AppState`]. Only a failing database makes the
+/// service unhealthy; problems with upstream adapters report `degraded`.
+async fn dependency_health(state: &AppState) -> HealthResponse {
+    let mut response = HealthResponse::healthy().with_version(env!("CARGO_PKG_VERSION"));
+
+    // Try to perform a simple database operation
+    let started = Instant::now();
+    let db_health = match state.services.search().list_all_tags().await {
+        Ok(_) => ComponentHealth::healthy(),
+        Err(e) => ComponentHealth::unhealthy(format!("Database error: {}", e)),
+    };
+    response = response
+        .with_check("database", db_health.with_latency(started.elapsed()))
+        .with_check("service", ComponentHealth::healthy());
+
+    let started = Instant::now();
+    // A disabled observatory is intentionally off, not broken
+    let telemetry_state = state.observatory.state();
+    let stats = state.observatory.telemetry_stats();
+    let observatory_health = match telemetry_state {
+        TelemetryState::Disabled | TelemetryState::Healthy => ComponentHealth::healthy(),
+        TelemetryState::Degraded => ComponentHealth::degraded(format!(
+            "Event flush failing: {}",
+            stats.last_flush_error.as_deref().unwrap_or("unknown error")
+        )),
+    };
+    let pending_events = state.observatory.pending_events().await;
+    let observatory_health = observatory_health
+        .with_metrics(
+            [
+                ("state".to_string(), serde_json::json!(telemetry_state)),
+                (
+                    "pending_events".to_string(),
+                    serde_json::json!(pending_events),
+                ),
+                (
+                    "dropped_events".to_string(),
+                    serde_json::json!(stats.dropped_events),
+                ),
+                (
+                    "failed_flushes".to_string(),
+                    serde_json::json!(stats.failed_flushes),
+                ),
+                (
+                    "last_flush_error".to_string(),
+                    serde_json::json!(stats.last_flush_error),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        )
+        .with_latency(started.elapsed());
+    response = response.with_check("observatory", observatory_health);
+
+    if let Some(ref config_manager) = state.config_manager {
+        let started = Instant::now();
+        let breaker = config_manager.breaker_status();
+        let config_health = if breaker.state != BreakerState::Closed {
+            ComponentHealth::degraded(format!(
+                "Config manager circuit breaker {} after {} failed fetches",
+                breaker.state.as_str(),
+                breaker.consecutive_failures
+            ))
+        } else if config_manager.is_stale(CONFIG_STALE_AFTER).await {
+            ComponentHealth::degraded(format!(
+                "Configuration not refreshed in the last {}s",
+                CONFIG_STALE_AFTER.as_secs()
+            ))
+        } else {
+            ComponentHealth::healthy()
+        };
+        let config_health = config_health.with_metrics(
+            [
+                (
+                    "breaker_state".to_string(),
+                    serde_json::json!(breaker.state),
+                ),
+                (
+                    "consecutive_failures".to_string(),
+                    serde_json::json!(breaker.consecutive_failures),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        response = response.with_check(
+            "config_manager",
+            config_health.with_latency(started.elapsed()),
+        );
+    }
+
+    if let Some(ref schema_registry) = state.schema_registry {
+        let started = Instant::now();
+        let schema_health = match schema_registry
+            .list_schemas(schema_registry.default_namespace())
+            .await
+        {
+            Ok(schemas) => ComponentHealth::healthy().with_metrics(
+                [("schemas".to_string(), serde_json::json!(schemas.len()))]
+                    .into_iter()
+                    .collect(),
+            ),
+            Err(e) => ComponentHealth::degraded(format!("Schema registry error: {}", e)),
+        };
+        response = response.with_check(
+            "schema_registry",
+            schema_health.with_latency(started.elapsed()),
+        );
+    }
+
+    response.compute_status()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Metrics endpoint (Prometheus format)
+///
+/// This endpoint exposes Prometheus metrics for monitoring.
+/// Metrics are collected throughout the application lifecycle.
+#[instrument(skip(state))]
+pub async fn metrics(State(state): State<AppState>) -> ApiResult<String> {
+    debug!("Metrics requested");
+
+    let mut metrics = state
+        .metrics
+        .render()
+        .map_err(ApiError::internal_server_error)?;
+
+    let stats = state.observatory.telemetry_stats();
+    metrics.push_str(&format!(
+        "# HELP llm_registry_governance_events_dropped_total Governance events lost before reaching the observatory\n\
+         # TYPE llm_registry_governance_events_dropped_total counter\n\
+         llm_registry_governance_events_dropped_total {}\n\
+         # HELP llm_registry_governance_failed_flushes_total Governance event flushes that failed after all retries\n\
+         # TYPE llm_registry_governance_failed_flushes_total counter\n\
+         llm_registry_governance_failed_flushes_total {}\n\
+         # HELP llm_registry_governance_events_filtered_total Governance events discarded by the event filter\n\
+         # TYPE llm_registry_governance_events_filtered_total counter\n\
+         llm_registry_governance_events_filtered_total {}\n",
+        stats.dropped_events, stats.failed_flushes, stats.filtered_events
+    ));
+    if let Some(kind) = stats.last_flush_error_kind {
+        metrics.push_str(&format!(
+            "# HELP llm_registry_governance_last_flush_error Kind of the most recent governance flush error\n\
+             # TYPE llm_registry_governance_last_flush_error gauge\n\
+             llm_registry_governance_last_flush_error{{kind=\"{}\"}} 1\n",
+            kind
+        ));
+    }
+
+    Ok(metrics)
+}
+
+// ============================================================================
+// Export Handlers (NOT instrumented with execution spans — infrastructure
+// dump outside the /v1 execution boundary, like metrics)
+// ============================================================================
+
+/// Number of assets fetched per page while exporting
+pub const EXPORT_PAGE_SIZE: i64 = 500;
+
+/// Query parameters for exporting assets
+#[derive(Debug, Default, Deserialize)]
+pub struct ExportAssetsParams {
+    /// Also export soft-deleted assets
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+/// Stream every asset as newline-delimited JSON
+///
+/// Deprecated and expired assets are included; soft-deleted ones only with
+/// `include_deleted`. Assets are paged through the search service oldest
+/// first, resuming after the last `(created_at, id)` seen, so writes during
+/// the export neither skip nor repeat assets and memory use is bounded by
+/// [`EXPORT_PAGE_SIZE`] regardless of registry size.
+#[instrument(skip(state))]
+pub async fn export_assets(
+    State(state): State<AppState>,
+    Query(params): Query<ExportAssetsParams>,
+) -> Response {
+    info!(
+        include_deleted = params.include_deleted,
+        "Exporting all assets"
+    );
+
+    struct Cursor {
+        services: Arc<ServiceRegistry>,
+        include_deleted: bool,
+        after: Option<(chrono::DateTime<chrono::Utc>, AssetId)>,
+        done: bool,
+    }
+
+    let cursor = Cursor {
+        services: state.services.clone(),
+        include_deleted: params.include_deleted,
+        after: None,
+        done: false,
+    };
+
+    let pages = futures::stream::unfold(cursor, |mut cursor| async move {
+        if cursor.done {
+            return None;
+        }
+
+        let page = match cursor
+            .services
+            .search()
+            .export_page(cursor.after, cursor.include_deleted, EXPORT_PAGE_SIZE)
+            .await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                tracing::error!(after = ?cursor.after, error = %e, "Asset export aborted");
+                cursor.done = true;
+                return Some((Err(std::io::Error::other(e.to_string())), cursor));
+            }
+        };
+
+        let last = page.last()?;
+        cursor.done = (page.len() as i64) < EXPORT_PAGE_SIZE;
+        cursor.after = Some((last.created_at, last.id));
+
+        let mut chunk = Vec::new();
+        for asset in &page {
+            if let Err(e) = serde_json::to_writer(&mut chunk, asset) {
+                return Some((Err(std::io::Error::other(e)), cursor));
+            }
+            chunk.push(b'\n');
+        }
+        Some((Ok(chunk), cursor))
+    });
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        Body::from_stream(pages),
+    )
+        .into_response()
+}
+
+// ============================================================================
+// Version & Info Handlers
+// ============================================================================
+
+/// Get API version information
+#[instrument]
+pub async fn version_info() -> ApiResult<Json<crate::responses::ApiResponse<VersionInfo>>> {
+    let info = VersionInfo {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        api_version: "v1".to_string(),
+        build_timestamp: option_env!("BUILD_TIMESTAMP")
+            .unwrap_or("unknown")
+            .to_string(),
+    };
+
+    Ok(Json(crate::responses::ok(info)))
+}
+
+/// Version information
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VersionInfo {
+    /// Semantic version
+    pub version: String,
+
+    /// API version
+    pub api_version: String,
+
+    /// Build timestamp
+    pub build_timestamp: String,
+}
+
+// ============================================================================
+// Config Handlers (NOT instrumented with execution spans — read-only
+// infrastructure view outside the /v1 execution boundary)
+// ============================================================================
+
+/// Active registry configuration and where it came from
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConfigResponse {
+    /// The active configuration
+    pub config: RegistryConfig,
+    /// Environment the config manager serves
+    pub environment: Environment,
+    /// Configuration namespace
+    pub namespace: String,
+    /// When the configuration was last refreshed, if ever
+    pub last_refresh: Option<chrono::DateTime<chrono::Utc>>,
+    /// Whether the configuration came from upstream or the built-in defaults
+    pub source: ConfigSource,
+}
+
+/// Get the active registry configuration
+///
+/// Returns 503 when no config manager is attached.
+#[instrument(skip(state))]
+pub async fn get_config(
+    State(state): State<AppState>,
+) -> ApiResult<Json<crate::responses::ApiResponse<ConfigResponse>>> {
+    let Some(ref config_manager) = state.config_manager else {
+        return Err(RegistryError::from(ConfigAdapterError::Unavailable(
+            "config manager not configured".to_string(),
+        ))
+        .into());
+    };
+
+    let config = config_manager
+        .get_config()
+        .await
+        .map_err(|e| ApiError::from(RegistryError::from(e)))?;
+
+    Ok(Json(crate::responses::ok(ConfigResponse {
+        config,
+        environment: config_manager.environment(),
+        namespace: config_manager.namespace().to_string(),
+        last_refresh: config_manager.last_refresh().await,
+        source: config_manager.source().await,
+    })))
+}
+
+// ============================================================================
+// Integrity Handlers
+// ============================================================================
+
+/// Query parameters for content verification
+#[derive(Debug, Default, Deserialize)]
+pub struct VerifyAssetParams {
+    /// Algorithm to verify with; defaults to the one the checksum was recorded with
+    pub algorithm: Option<String>,
+}
+
+/// Re-hash an asset's stored content and compare it to its recorded checksum
+///
+/// A mismatch is reported in the response rather than as an error. Every
+/// completed check is emitted to the observatory as an `integrity_verified`
+/// governance event.
+#[instrument(skip(state, collector))]
+pub async fn verify_asset(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Query(params): Query<VerifyAssetParams>,
+) -> ApiResult<Json<ExecutionEnvelope<IntegrityReport>>> {
+    info!("Verifying stored content of asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let constraints = validation_constraints(&state).await;
+
+    let span_id = collector.begin_agent_span(AgentService::Integrity.as_str());
+
+    let result = state
+        .services
+        .integrity()
+        .verify_content(&asset_id, params.algorithm.as_deref(), &constraints)
+        .await;
+
+    match result {
+        Ok(report) => {
+            if let Err(e) = state
+                .observatory
+                .trace_integrity_verification(
+                    &report.asset_id.to_string(),
+                    &report.algorithm.to_string(),
+                    report.verified,
+                )
+                .await
+            {
+                warn!("Failed to emit integrity verification event: {}", e);
+            }
+
+            attach_result(&collector, span_id, "integrity_report", &report);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(report, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+// ============================================================================
+// Retention Handlers
+// ============================================================================
+
+/// Set or clear the retention override of an asset version
+#[instrument(skip(state, collector))]
+pub async fn set_retention_override(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(id): Path<String>,
+    Json(retention_override): Json<RetentionOverride>,
+) -> ApiResult<Json<ExecutionEnvelope<llm_registry_core::Asset>>> {
+    info!("Setting retention override for asset: {}", id);
+
+    let asset_id = id.parse::<AssetId>().map_err(|e| {
+        let err = ApiError::bad_request(format!("Invalid asset ID: {}", e));
+        let exec = collector.finalize_failed("Invalid asset ID");
+        err.with_execution(exec)
+    })?;
+
+    let span_id = collector.begin_agent_span(AgentService::Retention.as_str());
+
+    let result = state
+        .services
+        .retention()
+        .set_retention_override(&asset_id, retention_override)
+        .await;
+
+    match result {
+        Ok(asset) => {
+            attach_result(&collector, span_id, "updated_asset", &asset);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(asset, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+/// Preview which versions of an asset automatic pruning would remove
+#[instrument(skip(state, collector))]
+pub async fn preview_retention(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<ExecutionEnvelope<llm_registry_service::retention::RetentionPreview>>> {
+    debug!("Previewing retention for asset: {}", name);
+
+    let span_id = collector.begin_agent_span(AgentService::Retention.as_str());
+
+    let result = state.services.retention().preview_retention(&name).await;
+
+    match result {
+        Ok(preview) => {
+            attach_result(&collector, span_id, "retention_preview", &preview);
+            collector.end_agent_span(span_id, SpanStatus::Ok);
+            let exec = collector.finalize();
+            Ok(ok_with_execution(preview, exec))
+        }
+        Err(e) => {
+            let _ = collector.attach_artifact(
+                span_id,
+                SpanArtifact {
+                    name: "error".to_string(),
+                    content_type: Some("text/plain".to_string()),
+                    data: serde_json::Value::String(e.to_string()),
+                },
+            );
+            collector.end_agent_span(span_id, SpanStatus::Failed);
+            let exec = collector.finalize();
+            Err(ApiError::from(e).with_execution(exec))
+        }
+    }
+}
+
+// ============================================================================
+// Capability Handlers
+// ============================================================================
+
+/// Status and parameters of one optional feature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Capability {
+    /// Whether the feature is available on this deployment
+    pub enabled: bool,
+    /// Feature-specific parameters (limits, supported values, ...)
+    #[serde(default, skip_serializing_if = "serde_json::Map::is_empty")]
+    pub parameters: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Capability {
+    /// An enabled feature with the given parameters (a JSON object)
+    pub fn enabled(parameters: serde_json::Value) -> Self {
+        Self {
+            enabled: true,
+            parameters: match parameters {
+                serde_json::Value::Object(map) => map,
+                _ => serde_json::Map::new(),
+            },
+        }
+    }
+
+    /// A feature this deployment does not offer
+    pub fn disabled() -> Self {
+        Self {
+            enabled: false,
+            parameters: serde_json::Map::new(),
+        }
+    }
+}
+
+/// Optional features enabled on this deployment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilitiesResponse {
+    /// API version
+    pub api_version: String,
+    /// Feature name -> status and parameters
+    pub capabilities: std::collections::BTreeMap<String, Capability>,
+}
+
+/// Describe the optional features this deployment supports
+pub async fn capabilities(state: &AppState) -> CapabilitiesResponse {
+    use crate::negotiation::ResponseFormat;
+    use llm_registry_core::HashAlgorithm;
+    use llm_registry_service::{SortField, MIN_CONTENT_HASH_PREFIX};
+    use serde_json::json;
+
+    // Only the implemented algorithms the validation constraints allow
+    let constraints = validation_constraints(state).await;
+    let algorithms: Vec<String> = HashAlgorithm::ALL
+        .iter()
+        .map(|a| a.to_string())
+        .filter(|a| constraints.is_algorithm_allowed(a))
+        .collect();
+
+    let capabilities = [
+        (
+            "offset_pagination",
+            Capability::enabled(json!({
+                "default_page_size": DEFAULT_PAGE_SIZE,
+                "max_page_size": state.max_page_size,
+            })),
+        ),
+        ("cursor_pagination", Capability::disabled()),
+        (
+            "sorting",
+            Capability::enabled(json!({
+                "fields": SortField::ALL.iter().map(|f| f.as_str()).collect::<Vec<_>>(),
+                "orders": ["asc", "desc"],
+            })),
+        ),
+        (
+            "checksums",
+            Capability::enabled(json!({
+                "algorithms": algorithms,
+            })),
+        ),
+        (
+            "content_hash_search",
+            Capability::enabled(json!({ "min_prefix_length": MIN_CONTENT_HASH_PREFIX })),
+        ),
+        ("signing", Capability::disabled()),
+        (
+            "response_formats",
+            Capability::enabled(json!({
+                "formats": ResponseFormat::ALL.iter().map(|f| f.content_type()).collect::<Vec<_>>(),
+                "default": ResponseFormat::Json.content_type(),
+                "header": "Accept",
+            })),
+        ),
+        (
+            "msgpack",
+            Capability::enabled(json!({ "content_type": ResponseFormat::MsgPack.content_type() })),
+        ),
+        (
+            "cbor",
+            Capability::enabled(json!({ "content_type": ResponseFormat::Cbor.content_type() })),
+        ),
+        (
+            "timestamp_formats",
+            Capability::enabled(json!({
+                "formats": ["rfc3339", "unix_millis", "unix_nanos"],
+                "default": state.timestamp_format.as_str(),
+                "header": "X-Timestamp-Format",
+            })),
+        ),
+        (
+            "soft_delete",
+            Capability::enabled(json!({
+                "restore_grace_days": state.services.registration().restore_grace().num_days(),
+                "hard_delete_param": "hard",
+            })),
+        ),
+        (
+            "partial_update",
+            Capability::enabled(json!({ "method": "PATCH" })),
+        ),
+        (
+            "optimistic_concurrency",
+            Capability::enabled(json!({ "header": "If-Match" })),
+        ),
+        ("retention_overrides", Capability::enabled(json!({}))),
+        (
+            "governance_context",
+            Capability::enabled(json!({
+                "header_prefix": "X-Governance-",
+                "required_keys": state.governance.required_context_keys,
+            })),
+        ),
+    ];
+
+    CapabilitiesResponse {
+        api_version: "v1".to_string(),
+        capabilities: capabilities
+            .into_iter()
+            .map(|(name, capability)| (name.to_string(), capability))
+            .collect(),
+    }
+}
+
+/// List the optional features enabled on this deployment
+///
+/// Lets clients adapt to a deployment without probing for features.
+#[instrument(skip(state, collector))]
+pub async fn get_capabilities(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<Json<ExecutionEnvelope<CapabilitiesResponse>>> {
+    let response = capabilities(&state).await;
+    let exec = collector.finalize();
+    Ok(ok_with_execution(response, exec))
+}
+
+// ============================================================================
+// Admin Handlers
+// ============================================================================
+
+/// Agent span names this service can emit
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpanTypesResponse {
+    /// Distinct agent span names
+    pub span_types: Vec<String>,
+}
+
+/// List the agent span names this service can produce
+///
+/// Lets downstream tooling pre-build facets without observing live traffic.
+#[instrument(skip(collector))]
+pub async fn list_span_types(
+    Extension(collector): Extension<SpanCollector>,
+) -> ApiResult<Json<ExecutionEnvelope<SpanTypesResponse>>> {
+    let response = SpanTypesResponse {
+        span_types: AgentService::ALL
+            .iter()
+            .map(|service| service.as_str().to_string())
+            .collect(),
+    };
+
+    let exec = collector.finalize();
+    Ok(ok_with_execution(response, exec))
+}
+
+/// Governance events returned when no limit is given
+pub const DEFAULT_GOVERNANCE_EVENTS_LIMIT: usize = 50;
+
+/// Most governance events returned by one request
+pub const MAX_GOVERNANCE_EVENTS_LIMIT: usize = 500;
+
+/// Query parameters for listing recent governance events
+#[derive(Debug, Default, Deserialize)]
+pub struct GovernanceEventsParams {
+    /// Number of events to return, clamped to 1..=500
+    pub limit: Option<usize>,
+}
+
+/// Recently flushed governance events
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GovernanceEventsResponse {
+    /// Events, newest first
+    pub events: Vec<GovernanceEvent>,
+    /// Limit applied after clamping
+    pub limit: usize,
+}
+
+/// List governance events the service recently emitted
+///
+/// Reads the observatory's recent-events buffer; events still waiting to be
+/// flushed are not included.
+#[instrument(skip(state, collector))]
+pub async fn list_governance_events(
+    State(state): State<AppState>,
+    Extension(collector): Extension<SpanCollector>,
+    Query(params): Query<GovernanceEventsParams>,
+) -> ApiResult<Json<ExecutionEnvelope<GovernanceEventsResponse>>> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_GOVERNANCE_EVENTS_LIMIT)
+        .clamp(1, MAX_GOVERNANCE_EVENTS_LIMIT);
+
+    let response = GovernanceEventsResponse {
+        events: state.observatory.recent_events(limit),
+        limit,
+    };
+
+    let exec = collector.finalize();
+    Ok(ok_with_execution(response, exec))
+}
+
+// ============================================================================
+// Execution Ingestion Handler (data-core fanout)
+// ============================================================================
+
+/// Largest accepted execution `payload`, measured as serialized JSON
+pub const MAX_EXECUTION_PAYLOAD_BYTES: usize = 256 * 1024;
+
+/// Payload from data-core execution fanout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRecordRequest {
+    /// Source system
+    pub source: String,
+
+    /// Event type; one of [`ExecutionEventType`]
+    pub event_type: String,
+
+    /// Execution identifier
+    pub execution_id: String,
+
+    /// ISO-8601 timestamp
+    pub timestamp: String,
+
+    /// Lineage/execution data
+    pub payload: serde_json::Value,
+}
+
+/// Kinds of execution record data-core fans out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionEventType {
+    /// An execution began
+    ExecutionStarted,
+    /// An execution finished successfully
+    ExecutionCompleted,
+    /// An execution finished with an error
+    ExecutionFailed,
+    /// Lineage between assets was observed during an execution
+    LineageRecorded,
+}
+
+impl ExecutionEventType {
+    /// Wire names of every event type
+    pub const ALL: [&'static str; 4] = [
+        "execution_started",
+        "execution_completed",
+        "execution_failed",
+        "lineage_recorded",
+    ];
+}
+
+impl std::str::FromStr for ExecutionEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "execution_started" => Ok(Self::ExecutionStarted),
+            "execution_completed" => Ok(Self::ExecutionCompleted),
+            "execution_failed" => Ok(Self::ExecutionFailed),
+            "lineage_recorded" => Ok(Self::LineageRecorded),
+            other => Err(format!(
+                "Unknown event_type '{}' (expected one of: {})",
+                other,
+                Self::ALL.join(", ")
+            )),
+        }
+    }
+}
+
+/// An execution record that passed ingestion validation
+#[derive(Debug, Clone)]
+pub struct ExecutionRecord {
+    pub source: String,
+    pub event_type: ExecutionEventType,
+    pub execution_id: String,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub payload: serde_json::Value,
+}
+
+impl ExecutionRecordRequest {
+    /// Validate the record, rejecting anything downstream reconciliation
+    /// could not trust
+    pub fn validate(&self) -> Result<ExecutionRecord, String> {
+        for (field, value) in [
+            ("source", &self.source),
+            ("execution_id", &self.execution_id),
+        ] {
+            if value.trim().is_empty() {
+                return Err(format!("{} must not be empty", field));
+            }
+        }
+        let event_type = self.event_type.parse()?;
+        let timestamp = chrono::DateTime::parse_from_rfc3339(&self.timestamp)
+            .map_err(|e| {
+                format!(
+                    "Invalid timestamp '{}': expected ISO-8601 ({})",
+                    self.timestamp, e
+                )
+            })?
+            .with_timezone(&chrono::Utc);
+
+        Ok(ExecutionRecord {
+            source: self.source.clone(),
+            event_type,
+            execution_id: self.execution_id.clone(),
+            timestamp,
+            payload: self.payload.clone(),
+        })
+    }
+}
+
+/// Response for accepted execution records
+#[derive(Debug, Serialize)]
+pub struct ExecutionAcceptedResponse {
+    pub status: String,
+    pub execution_id: String,
+}
+
+/// Accept an execution record from data-core fanout.
+///
+/// This endpoint lives outside the execution-context middleware because it
+/// *receives* execution records rather than participating in the span system.
+/// Malformed records are rejected with `400`, and oversized payloads with
+/// `413`, because downstream reconciliation trusts a `202`. A retry of an
+/// execution id already accepted within the dedup window gets `200` with
+/// `status: "duplicate"`, and one whose first delivery is still being
+/// persisted gets a retryable `503`. Records are persisted before the `202`;
+/// a persistence failure is a `500` so data-core retries.
+#[instrument(skip(state, request), fields(execution_id = %request.execution_id, source = %request.source))]
+pub async fn receive_execution(
+    State(state): State<AppState>,
+    Json(request): Json<ExecutionRecordRequest>,
+) -> ApiResult<(StatusCode, Json<ExecutionAcceptedResponse>)> {
+    let payload_bytes = serde_json::to_vec(&request.payload).map_or(0, |b| b.len());
+    if payload_bytes > MAX_EXECUTION_PAYLOAD_BYTES {
+        warn!(payload_bytes, "Rejected oversized execution record");
+        return Err(ApiError::with_code(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!(
+                "Execution payload is {} bytes, over the limit of {} bytes",
+                payload_bytes, MAX_EXECUTION_PAYLOAD_BYTES
+            ),
+            "PAYLOAD_TOO_LARGE",
+        )
+        .with_details(serde_json::json!({ "limit_bytes": MAX_EXECUTION_PAYLOAD_BYTES })));
+    }
+
+    let record = request.validate().map_err(|e| {
+        warn!("Rejected execution record: {}", e);
+        ApiError::bad_request(e)
+    })?;
+
+    let reservation = match state.execution_dedup.record(&record.execution_id).await {
+        // Released if persisting fails or the request is cancelled
+        DedupOutcome::Recorded => {
+            DedupReservation::new(state.execution_dedup.clone(), record.execution_id.clone())
+        }
+        DedupOutcome::InFlight => {
+            debug!(execution_id = %record.execution_id, "Execution record is still being persisted");
+            return Err(ApiError::with_code(
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!(
+                    "Execution record {} is still being persisted; retry later",
+                    record.execution_id
+                ),
+                "EXECUTION_IN_FLIGHT",
+            ));
+        }
+        DedupOutcome::Duplicate => {
+            debug!(execution_id = %record.execution_id, "Ignored duplicate execution record");
+            return Ok((
+                StatusCode::OK,
+                Json(ExecutionAcceptedResponse {
+                    status: "duplicate".to_string(),
+                    execution_id: record.execution_id,
+                }),
+            ));
+        }
+    };
+
+    if let Err(e) = state.execution_sink.persist(&request).await {
+        // Let the retry through instead of answering it as a duplicate
+        reservation.release().await;
+        error!(execution_id = %record.execution_id, error = %e, "Failed to persist execution record");
+        return Err(ApiError::internal_server_error(
+            "Failed to persist execution record",
+        ));
+    }
+    reservation.confirm().await;
+
+    info!(
+        execution_id = %record.execution_id,
+        source = %record.source,
+        event_type = ?record.event_type,
+        timestamp = %record.timestamp,
+        "Accepted execution record from data-core"
+    );
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ExecutionAcceptedResponse {
+            status: "accepted".to_string(),
+            execution_id: record.execution_id,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    #[test]
+    fn test_version_info_creation() {
+        let info = VersionInfo {
+            version: "0.1.0".to_string(),
+            api_version: "v1".to_string(),
+            build_timestamp: "2024-01-01".to_string(),
+        };
+
+        assert_eq!(info.version, "0.1.0");
+        assert_eq!(info.api_version, "v1");
+    }
+
+    #[tokio::test]
+    async fn test_list_span_types() {
+        let ctx = llm_registry_core::ExecutionContext {
+            execution_id: llm_registry_core::ExecutionId::new("exec-1"),
+            parent_span_id: llm_registry_core::SpanId::new(),
+            sampled: true,
+        };
+        let Json(envelope) = list_span_types(Extension(SpanCollector::new(&ctx)))
+            .await
+            .unwrap();
+
+        assert!(envelope
+            .data
+            .span_types
+            .contains(&"RegistrationService".to_string()));
+        assert_eq!(envelope.data.span_types.len(), AgentService::ALL.len());
+    }
+
+    /// App state whose integrity service reads from an in-memory content store
+    fn verify_state() -> (
+        AppState,
+        Arc<test_support::InMemoryRepository>,
+        Arc<llm_registry_service::InMemoryContentStore>,
+    ) {
+        use llm_registry_service::{InMemoryContentStore, ServiceRegistryBuilder};
+
+        let repository = Arc::new(test_support::InMemoryRepository::default());
+        let content = Arc::new(InMemoryContentStore::new());
+        let services = ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(Arc::new(test_support::InMemoryEventStore::default()))
+            .content_store(content.clone())
+            .build()
+            .unwrap();
+        (AppState::new(services), repository, content)
+    }
+
+    async fn verify(state: &AppState, id: AssetId) -> ApiResult<IntegrityReport> {
+        verify_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(id.to_string()),
+            Query(VerifyAssetParams::default()),
+        )
+        .await
+        .map(|Json(envelope)| envelope.data)
+    }
+
+    #[tokio::test]
+    async fn test_verify_asset_matching_hash() {
+        let (state, repository, content) = verify_state();
+        let mut asset = test_support::asset("model", "1.0.0");
+        asset.checksum =
+            llm_registry_service::integrity::utils::compute_sha256(b"weights").unwrap();
+        content.insert(&asset.storage, b"weights".to_vec());
+        let asset_id = asset.id;
+        repository.assets.lock().unwrap().push(asset);
+
+        let report = verify(&state, asset_id).await.unwrap();
+        assert!(report.verified);
+        assert_eq!(report.actual, report.expected);
+        assert_eq!(report.size_bytes, 7);
+
+        let events = state.observatory.buffered_events().await;
+        assert!(matches!(
+            events.as_slice(),
+            [GovernanceEvent::IntegrityVerified { asset_id: id, valid: true, .. }]
+                if *id == asset_id.to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_asset_reports_tampered_content() {
+        let (state, repository, content) = verify_state();
+        let mut asset = test_support::asset("model", "1.0.0");
+        asset.checksum =
+            llm_registry_service::integrity::utils::compute_sha256(b"weights").unwrap();
+        content.insert(&asset.storage, b"tampered".to_vec());
+        let asset_id = asset.id;
+        repository.assets.lock().unwrap().push(asset);
+
+        let report = verify(&state, asset_id).await.unwrap();
+        assert!(!report.verified);
+        assert_ne!(report.actual, report.expected);
+
+        let events = state.observatory.buffered_events().await;
+        assert!(matches!(
+            events.as_slice(),
+            [GovernanceEvent::IntegrityVerified { valid: false, .. }]
+        ));
+
+        // A disallowed algorithm falls back to the recorded one
+        let Json(envelope) = verify_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(asset_id.to_string()),
+            Query(VerifyAssetParams {
+                algorithm: Some("sha512".to_string()),
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            envelope.data.algorithm,
+            llm_registry_core::HashAlgorithm::SHA256
+        );
+        assert_eq!(envelope.data.warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_verify_missing_asset() {
+        let (state, _, _) = verify_state();
+
+        let err = verify(&state, AssetId::new()).await.unwrap_err();
+        assert_eq!(
+            axum::response::IntoResponse::into_response(err).status(),
+            StatusCode::NOT_FOUND
+        );
+        assert!(state.observatory.buffered_events().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_governance_events_clamps_limit() {
+        let (state, _, _) = test_support::app_state();
+        for i in 0..(MAX_GOVERNANCE_EVENTS_LIMIT + 10) {
+            state
+                .observatory
+                .trace_asset_update(&format!("asset-{}", i), vec![], "admin")
+                .await
+                .unwrap();
+        }
+        state.observatory.flush().await.unwrap();
+
+        for (requested, applied) in [
+            (None, DEFAULT_GOVERNANCE_EVENTS_LIMIT),
+            (Some(0), 1),
+            (Some(3), 3),
+            (Some(10_000), MAX_GOVERNANCE_EVENTS_LIMIT),
+        ] {
+            let Json(envelope) = list_governance_events(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                Query(GovernanceEventsParams { limit: requested }),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(envelope.data.limit, applied);
+            assert_eq!(envelope.data.events.len(), applied);
+        }
+    }
+
+    /// Names of assets listed for a tag filter over a seeded set
+    async fn list_names(state: &AppState, params: serde_json::Value) -> Vec<String> {
+        let params: SearchAssetsRequest = serde_json::from_value(params).unwrap();
+        let Json(envelope) = list_assets(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap();
+        envelope
+            .items
+            .into_iter()
+            .map(|a| a.into_inner().metadata.name)
+            .collect()
+    }
+
+    /// Deprecate an asset through the handler with a fixed reason
+    async fn deprecate(
+        state: &AppState,
+        id: AssetId,
+        superseded_by: Option<AssetId>,
+    ) -> ApiResult<Json<ExecutionEnvelope<Asset>>> {
+        deprecate_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            None,
+            Path(id.to_string()),
+            Json(DeprecateAssetRequest {
+                reason: "replaced by v2".to_string(),
+                superseded_by,
+            }),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_deprecate_asset() {
+        use llm_registry_service::registration::{
+            DEPRECATION_REASON_ANNOTATION, SUPERSEDED_BY_ANNOTATION,
+        };
+
+        let (state, repository, _) = test_support::app_state();
+        let old = test_support::asset("model-v1", "1.0.0");
+        let new = test_support::asset("model-v2", "2.0.0");
+        let (old_id, new_id) = (old.id, new.id);
+        repository.assets.lock().unwrap().extend([old, new]);
+
+        let Json(envelope) = deprecate(&state, old_id, Some(new_id)).await.unwrap();
+        let asset = envelope.data;
+        assert!(asset.is_deprecated());
+        assert!(asset.deprecated_at.is_some());
+        assert_eq!(
+            asset
+                .metadata
+                .annotations
+                .get(DEPRECATION_REASON_ANNOTATION)
+                .map(String::as_str),
+            Some("replaced by v2")
+        );
+        assert_eq!(
+            asset.metadata.annotations.get(SUPERSEDED_BY_ANNOTATION),
+            Some(&new_id.to_string())
+        );
+
+        let events = state.observatory.buffered_events().await;
+        assert!(matches!(
+            events.as_slice(),
+            [GovernanceEvent::AssetDeprecated { asset_id, deprecated_by, .. }]
+                if *asset_id == old_id.to_string() && deprecated_by == "anonymous"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_deprecated_assets_hidden_by_default() {
+        let (state, repository, _) = test_support::app_state();
+        let old = test_support::asset("model-v1", "1.0.0");
+        let old_id = old.id;
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([old, test_support::asset("model-v2", "2.0.0")]);
+        assert!(deprecate(&state, old_id, None).await.is_ok());
+
+        let sorted = serde_json::json!({ "sort_by": "name", "sort_order": "asc" });
+        assert_eq!(list_names(&state, sorted).await, ["model-v2"]);
+
+        let included = serde_json::json!({
+            "sort_by": "name",
+            "sort_order": "asc",
+            "include_deprecated": true,
+        });
+        assert_eq!(list_names(&state, included).await, ["model-v1", "model-v2"]);
+
+        // Still directly addressable
+        let Json(envelope) = get_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(old_id.to_string()),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .map(|(_, body)| body)
+        .unwrap();
+        assert!(envelope.data.is_deprecated());
+    }
+
+    #[tokio::test]
+    async fn test_deprecate_rejects_bad_successor() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("model-v1", "1.0.0");
+        let asset_id = asset.id;
+        let mut deleted = test_support::asset("model-v2", "2.0.0");
+        deleted.deleted_at = Some(chrono::Utc::now());
+        let deleted_id = deleted.id;
+        repository.assets.lock().unwrap().extend([asset, deleted]);
+
+        for successor in [AssetId::new(), asset_id, deleted_id] {
+            let err = deprecate(&state, asset_id, Some(successor))
+                .await
+                .unwrap_err();
+            assert_eq!(
+                axum::response::IntoResponse::into_response(err).status(),
+                StatusCode::BAD_REQUEST
+            );
+        }
+
+        let stored = repository.assets.lock().unwrap()[0].clone();
+        assert!(!stored.is_deprecated());
+        assert!(state.observatory.buffered_events().await.is_empty());
+    }
+
+    async fn list_tagged(tags: &[&str], tag_match: Option<&str>) -> Vec<String> {
+        let (state, repository, _) = test_support::app_state();
+        for (name, tags) in [
+            ("prod-llm", &["prod", "llm"][..]),
+            ("prod-only", &["prod"][..]),
+            ("llm-only", &["llm"][..]),
+            ("untagged", &[][..]),
+        ] {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.metadata.tags = tags.iter().map(|t| t.to_string()).collect();
+            repository.assets.lock().unwrap().push(asset);
+        }
+
+        let params: SearchAssetsRequest = serde_json::from_value(serde_json::json!({
+            "tags": tags,
+            "tag_match": tag_match,
+            "sort_by": "name",
+            "sort_order": "asc",
+        }))
+        .unwrap();
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap();
+        envelope
+            .items
+            .into_iter()
+            .map(|a| a.into_inner().metadata.name)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_tag_match_all() {
+        assert_eq!(
+            list_tagged(&["prod", "llm"], Some("all")).await,
+            ["prod-llm"]
+        );
+        // `all` is the default
+        assert_eq!(list_tagged(&["prod", "llm"], None).await, ["prod-llm"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_tag_match_any() {
+        assert_eq!(
+            list_tagged(&["prod", "llm"], Some("any")).await,
+            ["llm-only", "prod-llm", "prod-only"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_empty_tags_ignored() {
+        assert_eq!(list_tagged(&[], Some("any")).await.len(), 4);
+        assert_eq!(list_tagged(&[], Some("all")).await.len(), 4);
+    }
+
+    async fn list_labeled(label_selector: &str) -> ApiResult<Vec<String>> {
+        let (state, repository, _) = test_support::app_state();
+        for (name, labels) in [
+            ("gold-ml", &[("team", "ml-platform"), ("tier", "gold")][..]),
+            (
+                "silver-ml",
+                &[("team", "ml-platform"), ("tier", "silver")][..],
+            ),
+            ("gold-search", &[("team", "search"), ("tier", "gold")][..]),
+            ("unlabeled", &[][..]),
+        ] {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.metadata.labels = labels
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            repository.assets.lock().unwrap().push(asset);
+        }
+
+        let params = SearchAssetsRequest {
+            label_selector: Some(label_selector.to_string()),
+            sort_by: Some("name".to_string()),
+            sort_order: Some(llm_registry_service::SortOrder::Ascending),
+            ..Default::default()
+        };
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await?;
+        Ok(envelope
+            .items
+            .into_iter()
+            .map(|a| a.into_inner().metadata.name)
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_label_selector_exact_match() {
+        assert_eq!(
+            list_labeled("team=ml-platform").await.unwrap(),
+            ["gold-ml", "silver-ml"]
+        );
+        assert_eq!(
+            list_labeled("team=ml-platform,tier=gold").await.unwrap(),
+            ["gold-ml"]
+        );
+        assert_eq!(
+            list_labeled("team!=ml-platform").await.unwrap(),
+            ["gold-search", "unlabeled"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_label_selector_set_membership() {
+        assert_eq!(
+            list_labeled("tier in (gold,silver)").await.unwrap(),
+            ["gold-ml", "gold-search", "silver-ml"]
+        );
+        assert_eq!(
+            list_labeled("team=ml-platform,tier in (gold, bronze)")
+                .await
+                .unwrap(),
+            ["gold-ml"]
+        );
+        assert_eq!(
+            list_labeled("tier notin (gold)").await.unwrap(),
+            ["silver-ml", "unlabeled"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_invalid_label_selector_rejected() {
+        for selector in ["tier in (gold", "team=", "tier within (gold)"] {
+            let err = list_labeled(selector).await.unwrap_err();
+            assert_eq!(status_of(err), StatusCode::BAD_REQUEST, "{}", selector);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dependency_graph_dot_export() {
+        use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+        use llm_registry_core::execution::SpanId;
+        use tower::ServiceExt;
+
+        let (state, repository, _) = test_support::app_state();
+        let app = test_support::asset("app", "1.0.0");
+        let model = test_support::asset("model", "2.0.0");
+        let tokenizer = test_support::asset("tokenizer", "0.3.0");
+        let (a, m, t) = (app.id, model.id, tokenizer.id);
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([app, model, tokenizer]);
+        // app -> model -> tokenizer -> model (cycle)
+        repository
+            .dependencies
+            .lock()
+            .unwrap()
+            .extend([(a, m), (m, t), (t, m)]);
+
+        let response = crate::routes::build_router(state)
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/v1/assets/{}/dependencies.dot", a))
+                    .header(HEADER_EXECUTION_ID, "exec-dot")
+                    .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/vnd.graphviz"));
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let dot = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains(&format!("\"{}\" [label=\"app@1.0.0\"];", a)));
+        assert!(dot.contains(&format!("\"{}\" [label=\"tokenizer@0.3.0\"];", t)));
+        assert!(dot.contains(&format!("\"{}\" -> \"{}\";\n", a, m)));
+        assert!(dot.contains(&format!("\"{}\" -> \"{}\";\n", m, t)));
+        assert!(dot.contains(&format!("\"{}\" -> \"{}\" [style=dashed];\n", t, m)));
+    }
+
+    /// Names of assets matching a free-text query, plus the reported total
+    async fn list_q(q: &str) -> (Vec<String>, i64) {
+        let (state, repository, _) = test_support::app_state();
+        for (name, description) in [
+            ("Sentiment-Classifier", "Scores product reviews"),
+            (
+                "summarizer",
+                "Condenses long documents into SENTIMENT-neutral prose",
+            ),
+            ("translator", "Translates between languages"),
+        ] {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.metadata.description = Some(description.to_string());
+            repository.assets.lock().unwrap().push(asset);
+        }
+
+        let params = SearchAssetsRequest {
+            q: Some(q.to_string()),
+            sort_by: Some("name".to_string()),
+            sort_order: Some(llm_registry_service::SortOrder::Ascending),
+            ..Default::default()
+        };
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap();
+        let names = envelope
+            .items
+            .into_iter()
+            .map(|a| a.into_inner().metadata.name)
+            .collect();
+        (names, envelope.pagination.total)
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_q_matches_name() {
+        assert_eq!(list_q("CLASSIFIER").await.0, ["Sentiment-Classifier"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_q_matches_description() {
+        assert_eq!(list_q("  languages ").await.0, ["translator"]);
+        assert_eq!(
+            list_q("sentiment").await.0,
+            ["Sentiment-Classifier", "summarizer"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_q_without_match_is_empty() {
+        assert_eq!(list_q("diffusion").await, (vec![], 0));
+        // Blank queries are ignored
+        assert_eq!(list_q("   ").await.0.len(), 3);
+    }
+
+    async fn list_sorted(sort_by: &str, sort_order: &str) -> Vec<String> {
+        let (state, repository, _) = test_support::app_state();
+        for (name, version) in [("bravo", "1.0.0"), ("alpha", "2.0.0"), ("charlie", "1.5.0")] {
+            let mut asset = test_support::asset(name, version);
+            asset.metadata.size_bytes = Some(version.len() as u64 * 100 + name.len() as u64);
+            repository.assets.lock().unwrap().push(asset);
+        }
+
+        let params: SearchAssetsRequest = serde_json::from_value(serde_json::json!({
+            "sort_by": sort_by,
+            "sort_order": sort_order,
+        }))
+        .unwrap();
+
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap();
+
+        envelope
+            .items
+            .into_iter()
+            .map(|a| a.into_inner().metadata.name)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_sort_by_name() {
+        assert_eq!(
+            list_sorted("name", "asc").await,
+            ["alpha", "bravo", "charlie"]
+        );
+        assert_eq!(
+            list_sorted("name", "desc").await,
+            ["charlie", "bravo", "alpha"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_sort_by_version() {
+        assert_eq!(
+            list_sorted("version", "desc").await,
+            ["alpha", "charlie", "bravo"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_sort_by_timestamps() {
+        let (state, repository, _) = test_support::app_state();
+        let now = chrono::Utc::now();
+        for (offset, name) in [(2, "older"), (1, "newer")] {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.created_at = now - chrono::Duration::minutes(offset);
+            asset.updated_at = now - chrono::Duration::minutes(3 - offset);
+            repository.assets.lock().unwrap().push(asset);
+        }
+
+        for (sort_by, expected) in [
+            ("created_at", ["newer", "older"]),
+            ("updated_at", ["older", "newer"]),
+        ] {
+            let params = SearchAssetsRequest {
+                sort_by: Some(sort_by.to_string()),
+                ..Default::default()
+            };
+            let Json(envelope) = list_assets(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                Query(params),
+                Query(FieldsParams::default()),
+            )
+            .await
+            .unwrap();
+
+            let names: Vec<_> = envelope
+                .items
+                .iter()
+                .map(|a| a.metadata.name.as_str())
+                .collect();
+            assert_eq!(names, expected, "sort_by={}", sort_by);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_defaults_to_created_at_desc() {
+        let (state, repository, _) = test_support::app_state();
+
+        let _ = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(SearchAssetsRequest::default()),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap();
+
+        let query = repository.last_query.lock().unwrap().clone().unwrap();
+        assert_eq!(query.sort_by, llm_registry_db::SortField::CreatedAt);
+        assert_eq!(query.sort_order, llm_registry_db::SortOrder::Descending);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_report_telemetry_loss() {
+        let (state, _, _) = test_support::app_state();
+        let observatory = Arc::new(ObservatoryAdapter::default().with_max_buffered_events(0));
+        let state = state.with_observatory(observatory.clone());
+
+        let _ = observatory
+            .trace_asset_update("id-1", vec!["tags".to_string()], "tester")
+            .await;
+
+        let body = metrics(State(state)).await.unwrap();
+        assert!(body.contains("llm_registry_governance_events_dropped_total 1"));
+        assert!(body.contains("llm_registry_governance_failed_flushes_total 0"));
+        assert!(body.contains("llm_registry_governance_events_filtered_total 0"));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_label_flush_errors_by_kind() {
+        let (state, _, _) = test_support::app_state();
+        // Nothing listens on port 1, so every attempt fails to connect
+        let observatory = Arc::new(
+            ObservatoryAdapter::with_endpoint("test", "http://127.0.0.1:1".to_string())
+                .with_retry_backoff(Duration::ZERO),
+        );
+        let state = state.with_observatory(observatory.clone());
+
+        observatory
+            .trace_asset_update("id-1", vec!["tags".to_string()], "tester")
+            .await
+            .unwrap();
+        assert!(observatory.flush().await.is_err());
+
+        let body = metrics(State(state)).await.unwrap();
+        assert!(body.contains("llm_registry_governance_last_flush_error{kind=\"transport\"} 1"));
+    }
+
+    /// Page size served for `limit`, with `max_page_size` 5 and 8 stored assets
+    async fn served_limit(limit: i64) -> (i64, usize) {
+        let (state, repository, _) = test_support::app_state();
+        for i in 0..8 {
+            repository
+                .assets
+                .lock()
+                .unwrap()
+                .push(test_support::asset(&format!("model-{}", i), "1.0.0"));
+        }
+        let state = state.with_max_page_size(5);
+        let params = SearchAssetsRequest {
+            limit,
+            ..Default::default()
+        };
+
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap();
+        (envelope.pagination.limit, envelope.items.len())
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_clamps_limit_to_max_page_size() {
+        assert_eq!(served_limit(1_000_000).await, (5, 5));
+        assert_eq!(served_limit(3).await, (3, 3));
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_non_positive_limit_uses_default() {
+        // The default page size (50) is itself capped by the maximum
+        assert_eq!(served_limit(0).await, (5, 5));
+        assert_eq!(served_limit(-10).await, (5, 5));
+        assert_eq!(effective_limit(0, MAX_PAGE_SIZE), DEFAULT_PAGE_SIZE);
+        assert_eq!(effective_limit(-1, MAX_PAGE_SIZE), DEFAULT_PAGE_SIZE);
+        assert_eq!(effective_limit(1_000_000, MAX_PAGE_SIZE), 200);
+    }
+
+    #[tokio::test]
+    async fn test_list_assets_unknown_sort_field_rejected() {
+        let (state, _, _) = test_support::app_state();
+        let params = SearchAssetsRequest {
+            sort_by: Some("popularity".to_string()),
+            ..Default::default()
+        };
+
+        let err = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            axum::response::IntoResponse::into_response(err).status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    async fn update_with_if_match(
+        state: AppState,
+        asset: &Asset,
+        if_match: &str,
+    ) -> ApiResult<WithEtag<ExecutionEnvelope<llm_registry_service::UpdateAssetResponse>>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, if_match.parse().unwrap());
+        let request: UpdateAssetRequest = serde_json::from_value(serde_json::json!({
+            "asset_id": asset.id,
+            "description": "guarded",
+        }))
+        .unwrap();
+
+        update_asset(
+            State(state),
+            Extension(test_support::collector()),
+            Path(asset.id.to_string()),
+            headers,
+            Json(request),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_update_asset_with_matching_if_match() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("guarded", "1.0.0");
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let (etag, Json(envelope)) =
+            update_with_if_match(state, &asset, &format!("\"{}\"", asset.etag()))
+                .await
+                .unwrap();
+
+        assert_eq!(
+            envelope.data.asset.metadata.description.as_deref(),
+            Some("guarded")
+        );
+        assert_eq!(etag[0].1, format!("\"{}\"", envelope.data.asset.etag()));
+    }
+
+    #[tokio::test]
+    async fn test_update_asset_with_stale_if_match() {
+        use axum::response::IntoResponse;
+
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("guarded", "1.0.0");
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let err = update_with_if_match(state, &asset, "\"stale\"")
+            .await
+            .err()
+            .unwrap();
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["code"], "PRECONDITION_FAILED");
+        assert!(body["execution"]["spans"]
+            .as_array()
+            .is_some_and(|s| !s.is_empty()));
+
+        let stored = repository.assets.lock().unwrap()[0].clone();
+        assert!(stored.metadata.description.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_updates_with_same_if_match() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("guarded", "1.0.0");
+        repository.assets.lock().unwrap().push(asset.clone());
+        // Both writers read the asset before either writes
+        *repository.find_delay.lock().unwrap() = Duration::from_millis(50);
+
+        let if_match = format!("\"{}\"", asset.etag());
+        let (first, second) = tokio::join!(
+            update_with_if_match(state.clone(), &asset, &if_match),
+            update_with_if_match(state.clone(), &asset, &if_match),
+        );
+
+        let statuses: Vec<StatusCode> = [first.map(|_| ()), second.map(|_| ())]
+            .into_iter()
+            .map(|result| match result {
+                Ok(()) => StatusCode::OK,
+                Err(err) => axum::response::IntoResponse::into_response(err).status(),
+            })
+            .collect();
+        assert!(statuses.contains(&StatusCode::OK), "{:?}", statuses);
+        assert!(
+            statuses.contains(&StatusCode::PRECONDITION_FAILED),
+            "{:?}",
+            statuses
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_merge_patches_with_same_if_match() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("guarded", "1.0.0");
+        repository.assets.lock().unwrap().push(asset.clone());
+        *repository.find_delay.lock().unwrap() = Duration::from_millis(50);
+
+        let merge_patch = |description: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::IF_MATCH,
+                format!("\"{}\"", asset.etag()).parse().unwrap(),
+            );
+            patch_asset(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                Path(asset.id.to_string()),
+                headers,
+                AssetPatchBody::MergePatch(serde_json::json!({ "description": description })),
+            )
+        };
+        let (first, second) = tokio::join!(merge_patch("first"), merge_patch("second"));
+
+        assert!(first.is_ok() != second.is_ok());
+        let err = first.err().or(second.err()).unwrap();
+        assert_eq!(
+            axum::response::IntoResponse::into_response(err).status(),
+            StatusCode::PRECONDITION_FAILED
+        );
+    }
+
+    async fn patch(
+        state: AppState,
+        asset: &Asset,
+        patch: serde_json::Value,
+    ) -> llm_registry_service::UpdateAssetResponse {
+        let (_, Json(envelope)) = patch_asset(
+            State(state),
+            Extension(test_support::collector()),
+            Path(asset.id.to_string()),
+            HeaderMap::new(),
+            AssetPatchBody::Fields(Box::new(serde_json::from_value(patch).unwrap())),
+        )
+        .await
+        .unwrap();
+        envelope.data
+    }
+
+    fn tagged_asset() -> Asset {
+        let mut asset = test_support::asset("patched", "1.0.0");
+        asset.metadata.add_tag("nlp");
+        asset.metadata.add_tag("production");
+        asset
+            .add_dependency(llm_registry_core::AssetReference::by_id(AssetId::new()))
+            .unwrap();
+        asset
+    }
+
+    #[tokio::test]
+    async fn test_patch_description_leaves_tags_and_dependencies() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = tagged_asset();
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let response = patch(state, &asset, serde_json::json!({"description": "patched"})).await;
+
+        assert_eq!(response.updated_fields, vec!["description".to_string()]);
+        let stored = repository.assets.lock().unwrap()[0].clone();
+        assert_eq!(stored.metadata.description.as_deref(), Some("patched"));
+        assert_eq!(stored.metadata.tags, asset.metadata.tags);
+        assert_eq!(stored.dependencies, asset.dependencies);
+    }
+
+    #[tokio::test]
+    async fn test_patch_remove_missing_tag_is_noop() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = tagged_asset();
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let response = patch(
+            state,
+            &asset,
+            serde_json::json!({"remove_tags": ["missing"], "add_tags": ["new"]}),
+        )
+        .await;
+
+        assert_eq!(response.updated_fields, vec!["tags".to_string()]);
+        assert_eq!(
+            response.asset.metadata.tags,
+            vec![
+                "nlp".to_string(),
+                "production".to_string(),
+                "new".to_string()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patch_edits_labels_within_limits() {
+        let (state, repository, _) = test_support::app_state();
+        let mut asset = tagged_asset();
+        asset
+            .metadata
+            .labels
+            .insert("tier".to_string(), "silver".to_string());
+        asset
+            .metadata
+            .labels
+            .insert("team".to_string(), "nlp".to_string());
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let response = patch(
+            state.clone(),
+            &asset,
+            serde_json::json!({"add_labels": {"tier": "gold"}, "remove_labels": ["team", "missing"]}),
+        )
+        .await;
+
+        assert_eq!(response.updated_fields, vec!["labels".to_string()]);
+        assert_eq!(response.asset.metadata.labels.len(), 1);
+        assert_eq!(response.asset.metadata.labels["tier"], "gold");
+
+        let max_labels = ValidationConstraints::default().max_labels as usize;
+        let labels: std::collections::HashMap<String, String> = (0..=max_labels)
+            .map(|i| (format!("key-{}", i), "v".to_string()))
+            .collect();
+        for body in [
+            serde_json::json!({"add_labels": labels}),
+            serde_json::json!({"add_labels": {"bad key": "v"}}),
+        ] {
+            let err = patch_asset(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                Path(asset.id.to_string()),
+                HeaderMap::new(),
+                AssetPatchBody::Fields(Box::new(serde_json::from_value(body.clone()).unwrap())),
+            )
+            .await
+            .unwrap_err();
+            assert_eq!(
+                axum::response::IntoResponse::into_response(err).status(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "{}",
+                body
+            );
+        }
+        assert_eq!(
+            repository.assets.lock().unwrap()[0].metadata.labels.len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patch_accepts_legacy_update_body() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = tagged_asset();
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        // Bodies written for the old PATCH carried the asset id; the path wins
+        let body = serde_json::json!({
+            "asset_id": AssetId::new(),
+            "description": "legacy",
+            "add_tags": ["legacy"],
+            "remove_tags": [],
+        });
+        let response = patch(state, &asset, body).await;
+
+        assert_eq!(response.asset.id, asset.id);
+        assert_eq!(
+            response.asset.metadata.description.as_deref(),
+            Some("legacy")
+        );
+        assert!(response.asset.metadata.tags.contains(&"legacy".to_string()));
+    }
+
+    #[test]
+    fn test_patch_rejects_whole_list_fields() {
+        let result = serde_json::from_value::<AssetPatch>(serde_json::json!({"tags": ["a"]}));
+        assert!(result.is_err());
+    }
+
+    /// PATCH /v1/assets/{id} with an `application/merge-patch+json` body
+    async fn merge_patch(
+        state: AppState,
+        asset: &Asset,
+        patch: serde_json::Value,
+    ) -> (StatusCode, serde_json::Value) {
+        use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+        use llm_registry_core::execution::SpanId;
+        use tower::ServiceExt;
+
+        let response = crate::routes::build_router(state)
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("PATCH")
+                    .uri(format!("/v1/assets/{}", asset.id))
+                    .header(HEADER_EXECUTION_ID, uuid::Uuid::new_v4().to_string())
+                    .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+                    .header(header::CONTENT_TYPE, MERGE_PATCH_CONTENT_TYPE)
+                    .body(Body::from(patch.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    fn annotated_asset() -> Asset {
+        let mut asset = tagged_asset();
+        asset.metadata.description = Some("Sentiment model".to_string());
+        asset.metadata.license = Some("MIT".to_string());
+        asset.metadata.add_annotation("owner", "ml-platform");
+        asset.metadata.add_annotation("stage", "beta");
+        asset
+    }
+
+    #[tokio::test]
+    async fn test_merge_patch_null_deletes_key() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = annotated_asset();
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let (status, body) = merge_patch(state, &asset, serde_json::json!({"license": null})).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["data"]["updated_fields"],
+            serde_json::json!(["license"])
+        );
+        let stored = repository.assets.lock().unwrap()[0].clone();
+        assert_eq!(stored.metadata.license, None);
+        assert_eq!(stored.metadata.description, asset.metadata.description);
+        assert_eq!(stored.metadata.tags, asset.metadata.tags);
+    }
+
+    #[tokio::test]
+    async fn test_merge_patch_merges_nested_objects() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = annotated_asset();
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let (status, body) = merge_patch(
+            state,
+            &asset,
+            serde_json::json!({
+                "annotations": {"stage": "ga", "owner": null, "reviewer": "alice"},
+                "labels": {"tier": "gold"},
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body["data"]["updated_fields"],
+            serde_json::json!(["annotations", "labels"])
+        );
+        let stored = repository.assets.lock().unwrap()[0].clone();
+        let annotations: std::collections::BTreeMap<_, _> =
+            stored.metadata.annotations.into_iter().collect();
+        assert_eq!(
+            annotations,
+            [
+                ("reviewer".to_string(), "alice".to_string()),
+                ("stage".to_string(), "ga".to_string()),
+            ]
+            .into()
+        );
+        assert_eq!(stored.metadata.labels["tier"], "gold");
+        assert_eq!(stored.metadata.license, asset.metadata.license);
+    }
+
+    #[tokio::test]
+    async fn test_merge_patch_violating_constraints_rejected() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = annotated_asset();
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let max_tags = ValidationConstraints::default().max_tags as usize;
+        let tags: Vec<String> = (0..=max_tags).map(|i| format!("tag-{}", i)).collect();
+        for patch in [
+            serde_json::json!({"tags": tags}),
+            serde_json::json!({"description": null}),
+            serde_json::json!({"version": "2.0.0"}),
+            serde_json::json!({"owner": "ml-platform"}),
+            serde_json::json!({"labels": {"bad key": "gold"}}),
+        ] {
+            let (status, body) = merge_patch(state.clone(), &asset, patch.clone()).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", patch);
+            assert_eq!(body["code"], "VALIDATION_FAILED", "{}", patch);
+        }
+
+        let (status, _) =
+            merge_patch(state, &asset, serde_json::json!(["not", "an", "object"])).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let stored = repository.assets.lock().unwrap()[0].clone();
+        assert_eq!(stored.metadata, asset.metadata);
+    }
+
+    #[tokio::test]
+    async fn test_get_capabilities() {
+        let (state, _, _) = test_support::app_state();
+        let state = state.with_timestamp_format(TimestampFormat::UnixMillis);
+
+        let Json(envelope) = get_capabilities(State(state), Extension(test_support::collector()))
+            .await
+            .unwrap();
+        let capabilities = &envelope.data.capabilities;
+
+        let pagination = &capabilities["offset_pagination"];
+        assert!(pagination.enabled);
+        assert_eq!(pagination.parameters["max_page_size"], 200);
+        assert!(!capabilities["cursor_pagination"].enabled);
+        assert_eq!(
+            capabilities["checksums"].parameters["algorithms"],
+            serde_json::json!(["SHA256", "BLAKE3"])
+        );
+        assert_eq!(
+            capabilities["timestamp_formats"].parameters["default"],
+            "unix_millis"
+        );
+        assert!(capabilities["sorting"].parameters["fields"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("size_bytes")));
+        assert!(capabilities["soft_delete"].enabled);
+        assert_eq!(
+            capabilities["response_formats"].parameters["formats"],
+            serde_json::json!([
+                "application/json",
+                "application/msgpack",
+                "application/cbor"
+            ])
+        );
+        assert!(capabilities["msgpack"].enabled);
+        assert!(capabilities["cbor"].enabled);
+        assert_eq!(
+            capabilities["soft_delete"].parameters["restore_grace_days"],
+            30
+        );
+
+        // The configured grace window, not the default
+        let services = llm_registry_service::ServiceRegistryBuilder::new()
+            .repository(Arc::new(test_support::InMemoryRepository::default()))
+            .event_store(Arc::new(test_support::InMemoryEventStore::default()))
+            .restore_grace(chrono::Duration::days(7))
+            .build()
+            .unwrap();
+        let capabilities = super::capabilities(&AppState::new(services))
+            .await
+            .capabilities;
+        assert_eq!(
+            capabilities["soft_delete"].parameters["restore_grace_days"],
+            7
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_assets_streams_every_asset() {
+        use tower::ServiceExt;
+
+        let (state, repository, _) = test_support::app_state();
+        for i in 0..1234 {
+            repository
+                .assets
+                .lock()
+                .unwrap()
+                .push(test_support::asset(&format!("asset-{}", i), "1.0.0"));
+        }
+
+        // Served outside the execution boundary: no execution headers needed
+        let response = crate::routes::build_router(state)
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/v1/assets:export")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain"));
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let lines: Vec<&str> = std::str::from_utf8(&body).unwrap().lines().collect();
+        assert_eq!(lines.len(), 1234);
+
+        let names: std::collections::HashSet<String> = lines
+            .iter()
+            .map(|line| serde_json::from_str::<Asset>(line).unwrap().metadata.name)
+            .collect();
+        assert_eq!(names.len(), 1234);
+    }
+
+    #[tokio::test]
+    async fn test_export_includes_expired_and_deleted_assets_exactly_once() {
+        use llm_registry_service::adapters::config_manager::Environment;
+        use llm_registry_service::adapters::ConfigConsumer;
+        use llm_registry_service::{ConfigManagerAdapter, ServiceRegistryBuilder};
+        use tower::ServiceExt;
+
+        let config = ConfigManagerAdapter::new(Environment::Production);
+        config.refresh().await.unwrap();
+        let ttl = config.get_ttl_config().await.unwrap();
+        assert!(ttl.enforce);
+
+        let repository = Arc::new(test_support::InMemoryRepository::default());
+        let services = ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(Arc::new(test_support::InMemoryEventStore::default()))
+            .ttl_config(ttl.clone())
+            .build()
+            .unwrap();
+        let state = AppState::new(services);
+
+        // Equal creation times straddle the page boundary, so only the id
+        // tie-break keeps the cursor from skipping or repeating assets
+        let created_at = chrono::Utc::now() - chrono::Duration::minutes(1);
+        for i in 0..EXPORT_PAGE_SIZE + 10 {
+            let mut asset = test_support::asset(&format!("asset-{}", i), "1.0.0");
+            asset.created_at = created_at;
+            repository.assets.lock().unwrap().push(asset);
+        }
+        let mut archived = test_support::asset("archived", "1.0.0");
+        archived.status = llm_registry_core::AssetStatus::Archived;
+        archived.updated_at = chrono::Utc::now()
+            - chrono::Duration::from_std(ttl.archived_ttl).unwrap()
+            - chrono::Duration::days(1);
+        repository.assets.lock().unwrap().push(archived);
+        let mut deleted = test_support::asset("deleted", "1.0.0");
+        deleted.deleted_at = Some(chrono::Utc::now());
+        repository.assets.lock().unwrap().push(deleted);
+
+        let export = |uri: &'static str| {
+            let state = state.clone();
+            async move {
+                let response = crate::routes::build_router(state)
+                    .oneshot(
+                        axum::http::Request::builder()
+                            .uri(uri)
+                            .body(Body::empty())
+                            .unwrap(),
+                    )
+                    .await
+                    .unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap();
+                std::str::from_utf8(&body)
+                    .unwrap()
+                    .lines()
+                    .map(|line| serde_json::from_str::<Asset>(line).unwrap().metadata.name)
+                    .collect::<Vec<String>>()
+            }
+        };
+
+        let names = export("/v1/assets:export").await;
+        let unique: std::collections::HashSet<&String> = names.iter().collect();
+        assert_eq!(
+            (names.len(), unique.len()),
+            (
+                EXPORT_PAGE_SIZE as usize + 11,
+                EXPORT_PAGE_SIZE as usize + 11
+            )
+        );
+        assert!(unique.contains(&"archived".to_string()));
+        assert!(!unique.contains(&"deleted".to_string()));
+
+        let names = export("/v1/assets:export?include_deleted=true").await;
+        let unique: std::collections::HashSet<&String> = names.iter().collect();
+        assert_eq!(
+            (names.len(), unique.len()),
+            (
+                EXPORT_PAGE_SIZE as usize + 12,
+                EXPORT_PAGE_SIZE as usize + 12
+            )
+        );
+        assert!(unique.contains(&"deleted".to_string()));
+    }
+
+    async fn delete(state: &AppState, id: AssetId, hard: bool) -> ApiResult<StatusCode> {
+        let (status, _) = delete_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            None,
+            Path(id.to_string()),
+            Query(DeleteAssetParams { hard }),
+        )
+        .await?;
+        Ok(status)
+    }
+
+    async fn restore(state: &AppState, id: AssetId) -> ApiResult<Asset> {
+        let Json(envelope) = restore_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            None,
+            Path(id.to_string()),
+        )
+        .await?;
+        Ok(envelope.data)
+    }
+
+    fn status_of(err: ApiError) -> StatusCode {
+        axum::response::IntoResponse::into_response(err).status()
+    }
+
+    #[tokio::test]
+    async fn test_soft_delete_then_restore() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("model", "1.0.0");
+        let id = asset.id;
+        repository.assets.lock().unwrap().push(asset);
+
+        assert_eq!(delete(&state, id, false).await.unwrap(), StatusCode::OK);
+        assert!(repository.assets.lock().unwrap()[0].deleted_at.is_some());
+        assert!(list_names(&state, serde_json::json!({})).await.is_empty());
+        let err = get_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(id.to_string()),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(status_of(err), StatusCode::NOT_FOUND);
+        assert_eq!(
+            status_of(delete(&state, id, false).await.unwrap_err()),
+            StatusCode::NOT_FOUND
+        );
+
+        let restored = restore(&state, id).await.unwrap();
+        assert!(!restored.is_deleted());
+        assert_eq!(list_names(&state, serde_json::json!({})).await, ["model"]);
+        assert_eq!(
+            status_of(restore(&state, id).await.unwrap_err()),
+            StatusCode::BAD_REQUEST
+        );
+
+        let events = state.observatory.buffered_events().await;
+        assert!(matches!(
+            events.as_slice(),
+            [
+                GovernanceEvent::AssetDeleted { permanent: false, .. },
+                GovernanceEvent::AssetRestored { asset_id, .. },
+            ] if *asset_id == id.to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_restore_after_grace_window_is_refused() {
+        let (state, repository, _) = test_support::app_state();
+        let mut asset = test_support::asset("model", "1.0.0");
+        asset.deleted_at = Some(chrono::Utc::now() - chrono::Duration::days(31));
+        let id = asset.id;
+        repository.assets.lock().unwrap().push(asset);
+
+        assert_eq!(
+            status_of(restore(&state, id).await.unwrap_err()),
+            StatusCode::FORBIDDEN
+        );
+        assert!(repository.assets.lock().unwrap()[0].is_deleted());
+    }
+
+    #[tokio::test]
+    async fn test_hard_delete_cannot_restore() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("model", "1.0.0");
+        let id = asset.id;
+        repository.assets.lock().unwrap().push(asset);
+
+        assert_eq!(delete(&state, id, true).await.unwrap(), StatusCode::OK);
+        assert!(repository.assets.lock().unwrap().is_empty());
+        assert_eq!(
+            status_of(restore(&state, id).await.unwrap_err()),
+            StatusCode::NOT_FOUND
+        );
+
+        let events = state.observatory.buffered_events().await;
+        assert!(matches!(
+            events.as_slice(),
+            [GovernanceEvent::AssetDeleted {
+                permanent: true,
+                ..
+            }]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_mixed_ids() {
+        let (state, repository, _) = test_support::app_state();
+        let existing = test_support::asset("doomed", "1.0.0");
+        repository.assets.lock().unwrap().push(existing.clone());
+        let missing = AssetId::new();
+
+        let claims = crate::jwt::Claims::new("alice", "test", "test", 3600);
+        let Json(envelope) = batch_delete_assets(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Some(Extension(AuthUser::new(claims))),
+            Json(vec![
+                existing.id.to_string(),
+                missing.to_string(),
+                "not-an-id".to_string(),
+            ]),
+        )
+        .await
+        .unwrap();
+
+        let results = envelope.data;
+        assert_eq!(results.len(), 3);
+        assert!(results[0].deleted && results[0].error.is_none());
+        assert!(!results[1].deleted);
+        assert!(results[1].error.as_deref().unwrap().contains("not found"));
+        assert!(!results[2].deleted);
+        assert!(results[2]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("Invalid asset ID"));
+        assert!(repository.assets.lock().unwrap()[0].is_deleted());
+
+        // One agent span per attempted delete; malformed ids never reach the service
+        let agent_spans: Vec<_> = envelope
+            .execution
+            .spans
+            .iter()
+            .filter(|s| s.name == AgentService::Registration.as_str())
+            .collect();
+        assert_eq!(agent_spans.len(), 2);
+        assert_eq!(agent_spans[0].status, SpanStatus::Ok);
+        assert_eq!(agent_spans[1].status, SpanStatus::Failed);
+
+        // Only the successful delete is audited, with the caller as actor
+        let events = state.observatory.buffered_events().await;
+        assert!(matches!(
+            events.as_slice(),
+            [GovernanceEvent::AssetDeleted { asset_id, deleted_by, permanent: false }]
+                if *asset_id == existing.id.to_string() && deleted_by == "alice"
+        ));
+    }
+
+    fn fields(fields: Option<&str>) -> Query<FieldsParams> {
+        Query(FieldsParams {
+            fields: fields.map(str::to_string),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_asset_projects_fields() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("mobile-model", "2.1.0");
+        let asset_id = asset.id;
+        repository.assets.lock().unwrap().push(asset);
+
+        let (_, Json(envelope)) = get_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(asset_id.to_string()),
+            fields(Some("id,metadata.name,metadata.version")),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&envelope.data).unwrap(),
+            serde_json::json!({
+                "id": asset_id,
+                "metadata": {"name": "mobile-model", "version": "2.1.0"},
+            })
+        );
+
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(SearchAssetsRequest::default()),
+            fields(Some("id,status")),
+        )
+        .await
+        .unwrap();
+        let items = serde_json::to_value(&envelope.items).unwrap();
+        assert_eq!(items[0].as_object().unwrap().len(), 2);
+        assert_eq!(items[0]["status"], "active");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_projection_field_is_rejected() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("mobile-model", "2.1.0");
+        let asset_id = asset.id;
+        repository.assets.lock().unwrap().push(asset);
+
+        let err = get_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(asset_id.to_string()),
+            fields(Some("id,colour")),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            axum::response::IntoResponse::into_response(err).status(),
+            StatusCode::BAD_REQUEST
+        );
+
+        let err = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(SearchAssetsRequest::default()),
+            fields(Some("metadata.colour")),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            axum::response::IntoResponse::into_response(err).status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_asset_without_fields_returns_everything() {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("mobile-model", "2.1.0");
+        let asset_id = asset.id;
+        repository.assets.lock().unwrap().push(asset.clone());
+
+        let (_, Json(envelope)) = get_asset(
+            State(state),
+            Extension(test_support::collector()),
+            Path(asset_id.to_string()),
+            fields(None),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&envelope.data).unwrap(),
+            serde_json::to_value(&asset).unwrap()
+        );
+    }
+
+    async fn compare(
+        state: &AppState,
+        a: &llm_registry_core::Asset,
+        b: &llm_registry_core::Asset,
+    ) -> ApiResult<AssetComparison> {
+        let Json(envelope) = compare_asset_versions(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Query(CompareAssetsParams {
+                a: a.id.to_string(),
+                b: b.id.to_string(),
+            }),
+        )
+        .await?;
+        Ok(envelope.data)
+    }
+
+    #[tokio::test]
+    async fn test_compare_metadata_change() {
+        let (state, repository, _) = test_support::app_state();
+        let mut old = test_support::asset("model", "1.2.0");
+        old.metadata.description = Some("first cut".to_string());
+        old.metadata.tags = vec!["nlp".to_string()];
+        let mut new = test_support::asset("model", "1.3.0");
+        new.metadata.description = Some("retrained".to_string());
+        new.metadata.tags = vec!["nlp".to_string()];
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([old.clone(), new.clone()]);
+
+        let comparison = compare(&state, &old, &new).await.unwrap();
+        assert_eq!((comparison.a, comparison.b), (old.id, new.id));
+        assert_eq!(
+            comparison.metadata.changed.keys().collect::<Vec<_>>(),
+            ["description", "version"]
+        );
+        assert_eq!(comparison.metadata.changed["version"].to, "1.3.0");
+        assert!(comparison.metadata.added.is_empty() && comparison.metadata.removed.is_empty());
+        assert_eq!(comparison.tags, llm_registry_service::TagDiff::default());
+        assert!(comparison.dependencies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compare_tag_changes() {
+        let (state, repository, _) = test_support::app_state();
+        let mut old = test_support::asset("model", "1.2.0");
+        old.metadata.tags = vec!["nlp".to_string(), "beta".to_string()];
+        let mut new = test_support::asset("model", "1.2.0");
+        new.metadata.tags = vec!["nlp".to_string(), "stable".to_string()];
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([old.clone(), new.clone()]);
+
+        let comparison = compare(&state, &old, &new).await.unwrap();
+        assert_eq!(comparison.tags.added, ["stable"]);
+        assert_eq!(comparison.tags.removed, ["beta"]);
+        assert!(comparison.metadata.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_compare_missing_asset() {
+        let (state, repository, _) = test_support::app_state();
+        let old = test_support::asset("model", "1.2.0");
+        let missing = test_support::asset("model", "1.3.0");
+        repository.assets.lock().unwrap().push(old.clone());
+
+        let err = compare(&state, &old, &missing).await.unwrap_err();
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["details"]["missing"], "b");
+        assert!(body["error"]
+            .as_str()
+            .unwrap()
+            .contains(&missing.id.to_string()));
+    }
+
+    async fn resolve_latest(
+        state: &AppState,
+        name: &str,
+        params: ResolveLatestParams,
+    ) -> ApiResult<String> {
+        let Json(envelope) = resolve_latest_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(name.to_string()),
+            Query(params),
+        )
+        .await?;
+        Ok(envelope.data.metadata.version.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_latest_orders_by_semver() {
+        let (state, repository, _) = test_support::app_state();
+        repository.assets.lock().unwrap().extend([
+            test_support::asset("model", "1.9.0"),
+            test_support::asset("model", "1.10.0"),
+            test_support::asset("model", "2.0.0-rc.1"),
+            test_support::asset("other", "3.0.0"),
+        ]);
+
+        let latest = resolve_latest(&state, "model", Default::default())
+            .await
+            .unwrap();
+        assert_eq!(latest, "2.0.0-rc.1");
+
+        let err = resolve_latest(&state, "missing", Default::default())
+            .await
+            .unwrap_err();
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_latest_skips_deprecated() {
+        let (state, repository, _) = test_support::app_state();
+        let mut deprecated = test_support::asset("model", "1.3.0");
+        deprecated.status = llm_registry_core::AssetStatus::Deprecated;
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([test_support::asset("model", "1.2.0"), deprecated]);
+
+        let latest = resolve_latest(&state, "model", Default::default())
+            .await
+            .unwrap();
+        assert_eq!(latest, "1.2.0");
+
+        let params = ResolveLatestParams {
+            include_deprecated: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_latest(&state, "model", params).await.unwrap(),
+            "1.3.0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolve_latest_skips_soft_deleted() {
+        let (state, repository, _) = test_support::app_state();
+        let mut deleted = test_support::asset("model", "2.0.0");
+        deleted.deleted_at = Some(chrono::Utc::now());
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([test_support::asset("model", "1.0.0"), deleted]);
+
+        let latest = resolve_latest(&state, "model", Default::default())
+            .await
+            .unwrap();
+        assert_eq!(latest, "1.0.0");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_latest_with_constraint() {
+        let (state, repository, _) = test_support::app_state();
+        repository.assets.lock().unwrap().extend([
+            test_support::asset("model", "1.1.0"),
+            test_support::asset("model", "1.2.5"),
+            test_support::asset("model", "1.4.0"),
+            test_support::asset("model", "2.0.0"),
+        ]);
+
+        let constrained = |constraint: &str| ResolveLatestParams {
+            constraint: Some(constraint.to_string()),
+            ..Default::default()
+        };
+        let latest = resolve_latest(&state, "model", constrained("^1.2"))
+            .await
+            .unwrap();
+        assert_eq!(latest, "1.4.0");
+        let latest = resolve_latest(&state, "model", constrained("~1.2"))
+            .await
+            .unwrap();
+        assert_eq!(latest, "1.2.5");
+
+        let err = resolve_latest(&state, "model", constrained("^3"))
+            .await
+            .unwrap_err();
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let err = resolve_latest(&state, "model", constrained("not a range"))
+            .await
+            .unwrap_err();
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    async fn tags(state: &AppState, prefix: Option<&str>) -> Vec<(String, i64)> {
+        let params = TagsParams {
+            prefix: prefix.map(str::to_string),
+        };
+        let Json(envelope) = list_tags(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Query(params),
+        )
+        .await
+        .unwrap();
+        assert_eq!(envelope.execution.spans[1].name, "SearchService");
+        envelope
+            .data
+            .into_iter()
+            .map(|t| (t.tag, t.count))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_tags_with_counts() {
+        let (state, repository, _) = test_support::app_state();
+        let tagged = |name: &str, tags: &[&str]| {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.metadata.tags = tags.iter().map(|t| t.to_string()).collect();
+            asset
+        };
+        repository.assets.lock().unwrap().extend([
+            tagged("a", &["nlp", "production"]),
+            tagged("b", &["nlp", "vision"]),
+            tagged("c", &["nlp", "production", "beta"]),
+        ]);
+
+        let all = tags(&state, None).await;
+        assert_eq!(
+            all,
+            vec![
+                ("nlp".to_string(), 3),
+                ("production".to_string(), 2),
+                ("beta".to_string(), 1),
+                ("vision".to_string(), 1),
+            ]
+        );
+
+        assert_eq!(
+            tags(&state, Some("pro")).await,
+            vec![("production".to_string(), 2)]
+        );
+        assert!(tags(&state, Some("zzz")).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_tags_empty_registry() {
+        let (state, _, _) = test_support::app_state();
+        assert!(tags(&state, None).await.is_empty());
+    }
+
+    async fn stats(state: &AppState) -> RegistryMetrics {
+        let Json(envelope) = get_stats(State(state.clone()), Extension(test_support::collector()))
+            .await
+            .unwrap();
+        assert_eq!(envelope.execution.spans[1].name, "SearchService");
+        envelope.data
+    }
+
+    #[tokio::test]
+    async fn test_stats_counts_seeded_mix() {
+        let (state, repository, _) = test_support::app_state();
+        let seeded = |name: &str, asset_type: AssetType, size_bytes: Option<u64>| {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.asset_type = asset_type;
+            asset.metadata.size_bytes = size_bytes;
+            asset
+        };
+        let mut deprecated = seeded("d1", AssetType::Dataset, Some(300));
+        deprecated.status = llm_registry_core::AssetStatus::Deprecated;
+        let mut deleted = seeded("m3", AssetType::Model, Some(5_000));
+        deleted.deleted_at = Some(chrono::Utc::now());
+        repository.assets.lock().unwrap().extend([
+            seeded("m1", AssetType::Model, Some(1_000)),
+            seeded("m2", AssetType::Model, None),
+            seeded("p1", AssetType::Pipeline, Some(20)),
+            deprecated,
+            deleted,
+        ]);
+
+        let metrics = stats(&state).await;
+        assert_eq!(metrics.total_assets, 4);
+        assert_eq!(metrics.assets_by_type.len(), 3);
+        assert_eq!(metrics.assets_by_type["model"], 2);
+        assert_eq!(metrics.assets_by_type["dataset"], 1);
+        assert_eq!(metrics.assets_by_type["pipeline"], 1);
+        assert_eq!(metrics.assets_by_state["active"], 3);
+        assert_eq!(metrics.assets_by_state["deprecated"], 1);
+        assert_eq!(metrics.assets_by_state["deleted"], 1);
+        assert_eq!(metrics.storage_bytes, 1_320);
+
+        // Counts are cached briefly, so a new asset is not visible right away
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .push(seeded("m4", AssetType::Model, Some(1)));
+        assert_eq!(stats(&state).await.total_assets, 4);
+    }
+
+    #[tokio::test]
+    async fn test_search_type_facets_cover_filtered_set() {
+        use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+        use llm_registry_core::execution::SpanId;
+        use tower::ServiceExt;
+
+        let (state, repository, _) = test_support::app_state();
+        let seeded = |name: &str, asset_type: AssetType, tags: &[&str]| {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.asset_type = asset_type;
+            asset.metadata.tags = tags.iter().map(|t| t.to_string()).collect();
+            asset.metadata.description = Some(format!("{} asset", tags[0]));
+            asset
+        };
+        repository.assets.lock().unwrap().extend([
+            seeded("m1", AssetType::Model, &["nlp"]),
+            seeded("m2", AssetType::Model, &["nlp"]),
+            seeded("m3", AssetType::Model, &["vision"]),
+            seeded("d1", AssetType::Dataset, &["nlp"]),
+            seeded("p1", AssetType::Pipeline, &["nlp", "etl"]),
+        ]);
+
+        let app = crate::routes::build_router(state);
+        let search = |query: &str| {
+            app.clone().oneshot(
+                axum::http::Request::builder()
+                    .uri(format!("/v1/assets?{}", query))
+                    .header(HEADER_EXECUTION_ID, "exec-facets")
+                    .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+        };
+
+        let response = search("q=nlp&limit=1&facets=type,%20tag").await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["items"].as_array().unwrap().len(), 1);
+
+        let facets = &body["facets"];
+        let types = facets["type"].as_object().unwrap();
+        assert_eq!(types.len(), 3);
+        assert_eq!(types["model"], 2);
+        assert_eq!(types["dataset"], 1);
+        assert_eq!(types["pipeline"], 1);
+        assert_eq!(facets["tag"]["nlp"], 4);
+        assert_eq!(facets["tag"]["etl"], 1);
+        assert!(facets["tag"].get("vision").is_none());
+
+        let response = search("facets=color").await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_search_span_carries_query_timing() {
+        let (state, repository, _) = test_support::app_state();
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .push(test_support::asset("model", "1.0.0"));
+
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(SearchAssetsRequest::default()),
+            Query(FieldsParams::default()),
+        )
+        .await
+        .unwrap();
+
+        let search = envelope
+            .execution
+            .spans
+            .iter()
+            .find(|s| s.name == AgentService::Search.as_str())
+            .unwrap();
+        let timing = &search
+            .artifacts
+            .iter()
+            .find(|a| a.name == "query_timing")
+            .unwrap()
+            .data;
+        for key in ["validate_ms", "db_ms", "shape_ms"] {
+            assert!(timing[key].as_f64().unwrap() >= 0.0, "{} missing", key);
+        }
+    }
+
+    async fn search_by_hash(state: AppState, content_hash: &str) -> ApiResult<Vec<String>> {
+        let params = SearchAssetsRequest {
+            content_hash: Some(content_hash.to_string()),
+            ..Default::default()
+        };
+        let Json(envelope) = list_assets(
+            State(state),
+            Extension(test_support::collector()),
+            Query(params),
+            Query(FieldsParams::default()),
+        )
+        .await?;
+        let mut names: Vec<String> = envelope
+            .items
+            .into_iter()
+            .map(|a| a.into_inner().metadata.name)
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    #[tokio::test]
+    async fn test_search_by_content_hash() {
+        let (state, repository, _) = test_support::app_state();
+        for (name, hash) in [
+            ("first", format!("abcd1{}", "0".repeat(59))),
+            ("duplicate", format!("abcd1{}", "0".repeat(59))),
+            ("second", format!("abcd2{}", "0".repeat(59))),
+        ] {
+            let mut asset = test_support::asset(name, "1.0.0");
+            asset.checksum =
+                llm_registry_core::Checksum::new(llm_registry_core::HashAlgorithm::SHA256, hash)
+                    .unwrap();
+            repository.assets.lock().unwrap().push(asset);
+        }
+
+        // Full hash finds every asset with that content
+        let full = format!("ABCD1{}", "0".repeat(59));
+        assert_eq!(
+            search_by_hash(state.clone(), &full).await.unwrap(),
+            vec!["duplicate", "first"]
+        );
+
+        // Unambiguous short hash
+        assert_eq!(
+            search_by_hash(state.clone(), "abcd2").await.unwrap(),
+            vec!["second"]
+        );
+
+        // Ambiguous, too short and non-hex prefixes are rejected
+        for bad in ["abcd", "abc", "xyz12"] {
+            let err = search_by_hash(state.clone(), bad).await.unwrap_err();
+            assert_eq!(
+                axum::response::IntoResponse::into_response(err).status(),
+                StatusCode::BAD_REQUEST,
+                "{}",
+                bad
+            );
+        }
+    }
+
+    /// Build a ring of `len` assets and return its members and reported cycles
+    async fn dependency_ring(len: usize) -> (Vec<AssetId>, Vec<Vec<AssetId>>) {
+        let (state, repository, _) = test_support::app_state();
+        let ids: Vec<AssetId> = (0..len)
+            .map(|i| {
+                let asset = test_support::asset(&format!("node-{}", i), "1.0.0");
+                let id = asset.id;
+                repository.assets.lock().unwrap().push(asset);
+                id
+            })
+            .collect();
+        for i in 0..len {
+            repository
+                .dependencies
+                .lock()
+                .unwrap()
+                .push((ids[i], ids[(i + 1) % len]));
+        }
+
+        // Enter the ring at every member; each entry must report the same cycle
+        let mut cycles = Vec::new();
+        for root in &ids {
+            let Json(envelope) = get_dependencies(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                Path(root.to_string()),
+                Query(DependencyGraphParams { max_depth: None }),
+            )
+            .await
+            .unwrap();
+            assert_eq!(envelope.data.dependencies.len(), len);
+            assert_eq!(envelope.data.cycles.len(), 1);
+            cycles.extend(envelope.data.cycles);
+        }
+        cycles.dedup();
+        (ids, cycles)
+    }
+
+    fn assert_single_cycle(ids: &[AssetId], cycles: &[Vec<AssetId>]) {
+        assert_eq!(cycles.len(), 1, "{:?}", cycles);
+        let cycle = &cycles[0];
+        assert_eq!(cycle.len(), ids.len());
+        // Reported in dependency order, starting from any member
+        let start = ids.iter().position(|id| *id == cycle[0]).unwrap();
+        let mut expected = ids.to_vec();
+        expected.rotate_left(start);
+        assert_eq!(*cycle, expected);
+    }
+
+    #[tokio::test]
+    async fn test_dependency_graph_reports_two_node_cycle() {
+        let (ids, cycles) = dependency_ring(2).await;
+        assert_single_cycle(&ids, &cycles);
+    }
+
+    #[tokio::test]
+    async fn test_dependency_graph_reports_three_node_cycle() {
+        let (ids, cycles) = dependency_ring(3).await;
+        assert_single_cycle(&ids, &cycles);
+    }
+
+    #[tokio::test]
+    async fn test_wide_dependency_graph_is_truncated_at_node_cap() {
+        let repository = Arc::new(test_support::InMemoryRepository::default());
+        let services = llm_registry_service::ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(Arc::new(test_support::InMemoryEventStore::default()))
+            .max_graph_nodes(50)
+            .build()
+            .unwrap();
+        let state = AppState::new(services);
+
+        let root = test_support::asset("root", "1.0.0");
+        let root_id = root.id;
+        repository.assets.lock().unwrap().push(root);
+        for i in 0..200 {
+            let leaf = test_support::asset(&format!("leaf-{}", i), "1.0.0");
+            repository
+                .dependencies
+                .lock()
+                .unwrap()
+                .push((root_id, leaf.id));
+            repository.assets.lock().unwrap().push(leaf);
+        }
+
+        // The cap applies although the depth is unlimited
+        let Json(envelope) = get_dependencies(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(root_id.to_string()),
+            Query(DependencyGraphParams { max_depth: None }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(envelope.data.dependencies.len(), 50);
+        assert!(envelope.data.truncated);
+        assert_eq!(envelope.data.truncated_at_depth, Some(1));
+
+        let body = serde_json::to_value(&envelope.data).unwrap();
+        assert_eq!(body["truncated"], true);
+        assert_eq!(body["truncated_at_depth"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_direct_vs_transitive_dependents() {
+        let (state, repository, _) = test_support::app_state();
+        let ids: Vec<AssetId> = ["base", "left", "right", "top", "app"]
+            .iter()
+            .map(|name| {
+                let asset = test_support::asset(name, "1.0.0");
+                let id = asset.id;
+                repository.assets.lock().unwrap().push(asset);
+                id
+            })
+            .collect();
+        let (base, left, right, top, app) = (ids[0], ids[1], ids[2], ids[3], ids[4]);
+
+        // Diamond: left and right depend on base, top on both, app on top.
+        // base -> app closes a cycle that the traversal must not follow forever.
+        repository.dependencies.lock().unwrap().extend([
+            (left, base),
+            (right, base),
+            (top, left),
+            (top, right),
+            (app, top),
+            (base, app),
+        ]);
+
+        let fetch = |transitive| {
+            get_dependents(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                Path(base.to_string()),
+                Query(DependentsParams { transitive }),
+            )
+        };
+
+        let Json(envelope) = fetch(None).await.unwrap();
+        let DependentsResponse::Direct(direct) = envelope.data else {
+            panic!("expected direct dependents");
+        };
+        let mut direct: Vec<AssetId> = direct.into_iter().map(|a| a.id).collect();
+        direct.sort_by_key(|id| *id.as_ulid());
+        let mut expected = vec![left, right];
+        expected.sort_by_key(|id| *id.as_ulid());
+        assert_eq!(direct, expected);
+
+        let Json(envelope) = fetch(Some(true)).await.unwrap();
+        let DependentsResponse::Transitive(transitive) = envelope.data else {
+            panic!("expected transitive dependents");
+        };
+        let distances: std::collections::HashMap<AssetId, u32> = transitive
+            .iter()
+            .map(|d| (d.asset.id, d.distance))
+            .collect();
+        assert_eq!(transitive.len(), 4, "top is reached twice but listed once");
+        assert_eq!(
+            distances,
+            std::collections::HashMap::from([(left, 1), (right, 1), (top, 2), (app, 3)])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_soft_deleted_dependents_are_ignored() {
+        let (state, repository, _) = test_support::app_state();
+        let base = test_support::asset("base", "1.0.0");
+        let mut gone = test_support::asset("gone", "1.0.0");
+        gone.deleted_at = Some(chrono::Utc::now());
+        let (base_id, gone_id) = (base.id, gone.id);
+        repository.assets.lock().unwrap().extend([base, gone]);
+        repository
+            .dependencies
+            .lock()
+            .unwrap()
+            .push((gone_id, base_id));
+
+        let Json(envelope) = get_dependents(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(base_id.to_string()),
+            Query(DependentsParams { transitive: None }),
+        )
+        .await
+        .unwrap();
+        let DependentsResponse::Direct(direct) = envelope.data else {
+            panic!("expected direct dependents");
+        };
+        assert!(direct.is_empty());
+
+        // A soft-deleted dependent does not block deleting what it depended on
+        assert_eq!(
+            delete(&state, base_id, false).await.unwrap(),
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_soft_deleted_dependencies_leave_the_graph() {
+        let (state, repository, _) = test_support::app_state();
+        let root = test_support::asset("root", "1.0.0");
+        let live = test_support::asset("live", "1.0.0");
+        let mut gone = test_support::asset("gone", "1.0.0");
+        gone.deleted_at = Some(chrono::Utc::now());
+        let (root_id, live_id, gone_id) = (root.id, live.id, gone.id);
+        repository.assets.lock().unwrap().extend([root, live, gone]);
+        repository
+            .dependencies
+            .lock()
+            .unwrap()
+            .extend([(root_id, live_id), (root_id, gone_id)]);
+
+        let Json(envelope) = get_dependencies(
+            State(state),
+            Extension(test_support::collector()),
+            Path(root_id.to_string()),
+            Query(DependencyGraphParams { max_depth: None }),
+        )
+        .await
+        .unwrap();
+        let ids: Vec<AssetId> = envelope
+            .data
+            .dependencies
+            .iter()
+            .map(|d| d.asset_id)
+            .collect();
+        assert!(ids.contains(&live_id));
+        assert!(!ids.contains(&gone_id));
+    }
+
+    async fn health(state: AppState) -> (StatusCode, serde_json::Value) {
+        let response = IntoResponse::into_response(health_check(State(state)).await.unwrap());
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    async fn fresh_config_manager() -> Arc<ConfigManagerAdapter> {
+        use llm_registry_service::adapters::ConfigConsumer;
+
+        let config_manager = Arc::new(ConfigManagerAdapter::default());
+        config_manager.refresh().await.unwrap();
+        config_manager
+    }
+
+    #[tokio::test]
+    async fn test_health_all_components_healthy() {
+        let (state, _, _) = test_support::app_state();
+        let state = state
+            .with_config_manager(fresh_config_manager().await)
+            .with_schema_registry(Arc::new(llm_registry_service::SchemaRegistryAdapter::new()));
+
+        let (status, response) = health(state).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response["status"], "healthy");
+
+        let checks = &response["checks"];
+        for name in [
+            "database",
+            "observatory",
+            "config_manager",
+            "schema_registry",
+        ] {
+            assert_eq!(checks[name]["status"], "healthy", "{}", name);
+            assert!(checks[name]["latency_ms"].is_u64(), "{}", name);
+        }
+        assert_eq!(checks["observatory"]["metrics"]["pending_events"], 0);
+        assert_eq!(checks["observatory"]["metrics"]["state"], "healthy");
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_disabled_observatory_as_healthy() {
+        let (state, _, _) = test_support::app_state();
+        let observatory = ObservatoryAdapter::default().with_enabled(false);
+        let state = state.with_observatory(Arc::new(observatory));
+
+        let (status, response) = health(state).await;
+        assert_eq!(status, StatusCode::OK);
+        let observatory = &response["checks"]["observatory"];
+        assert_eq!(observatory["status"], "healthy");
+        assert_eq!(observatory["metrics"]["state"], "disabled");
+    }
+
+    #[tokio::test]
+    async fn test_health_stale_config_is_degraded() {
+        let (state, _, _) = test_support::app_state();
+        let state = state.with_config_manager(Arc::new(ConfigManagerAdapter::default()));
+
+        let (status, response) = health(state).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response["status"], "degraded");
+        assert_eq!(response["checks"]["config_manager"]["status"], "degraded");
+        assert_eq!(response["checks"]["database"]["status"], "healthy");
+    }
+
+    #[tokio::test]
+    async fn test_health_database_down_is_unhealthy() {
+        let (state, repository, _) = test_support::app_state();
+        repository
+            .unavailable
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+        let state = state.with_config_manager(fresh_config_manager().await);
+
+        let (status, response) = health(state).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response["status"], "unhealthy");
+        assert_eq!(response["checks"]["database"]["status"], "unhealthy");
+        assert_eq!(response["checks"]["config_manager"]["status"], "healthy");
+    }
+
+    async fn probe(state: AppState, path: &str) -> (StatusCode, serde_json::Value) {
+        use tower::ServiceExt;
+
+        let response = crate::routes::build_router(state)
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri(path)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_readiness_fails_with_database_while_liveness_holds() {
+        let (state, repository, _) = test_support::app_state();
+
+        let (status, response) = probe(state.clone(), "/health/ready").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response["checks"]["database"]["status"], "healthy");
+
+        repository
+            .unavailable
+            .store(true, std::sync::atomic::Ordering::Relaxed);
+
+        let (status, response) = probe(state.clone(), "/health/ready").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response["checks"]["database"]["status"], "unhealthy");
+
+        let (status, response) = probe(state.clone(), "/health/live").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response["status"], "healthy");
+        assert!(response.get("checks").is_none());
+
+        // The combined endpoint keeps reporting dependency health
+        let (status, _) = probe(state, "/health").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_health_reports_open_config_breaker() {
+        use llm_registry_service::adapters::ConfigConsumer;
+
+        // Nothing listens on port 9 (discard)
+        let endpoint = "http://127.0.0.1:9".to_string();
+        let config_manager = Arc::new(
+            ConfigManagerAdapter::with_endpoint(Environment::Staging, endpoint)
+                .with_circuit_breaker(1, std::time::Duration::from_secs(3600)),
+        );
+        config_manager.refresh().await.unwrap_err();
+
+        let (state, _, _) = test_support::app_state();
+        let (status, response) = health(state.with_config_manager(config_manager)).await;
+        assert_eq!(status, StatusCode::OK);
+        let check = &response["checks"]["config_manager"];
+        assert_eq!(check["status"], "degraded");
+        assert_eq!(check["metrics"]["breaker_state"], "open");
+        assert_eq!(check["metrics"]["consecutive_failures"], 1);
+    }
+
+    async fn get_config_json(state: AppState) -> (StatusCode, serde_json::Value) {
+        use tower::ServiceExt;
+
+        let response = crate::routes::build_router(state)
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/v1/config")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_config_source_reflects_remote_fetch() {
+        use llm_registry_service::adapters::ConfigConsumer;
+
+        // Minimal upstream config manager serving the staging config
+        let upstream = axum::Router::new().route(
+            "/configs/llm.registry/staging",
+            axum::routing::get(|| async { Json(RegistryConfig::default()) }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, upstream).await });
+
+        let config_manager = Arc::new(ConfigManagerAdapter::with_endpoint(
+            Environment::Staging,
+            endpoint,
+        ));
+        let (state, _, _) = test_support::app_state();
+        let state = state.with_config_manager(config_manager.clone());
+
+        let (status, body) = get_config_json(state.clone()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["source"], "defaults");
+        assert_eq!(body["data"]["environment"], "staging");
+        assert_eq!(body["data"]["namespace"], "llm.registry");
+        assert!(body["data"]["last_refresh"].is_null());
+
+        config_manager.refresh().await.unwrap();
+
+        let (status, body) = get_config_json(state).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["source"], "remote");
+        assert!(body["data"]["last_refresh"].is_string());
+        assert_eq!(body["data"]["config"]["environment"], "staging");
+    }
+
+    #[tokio::test]
+    async fn test_config_without_config_manager_is_unavailable() {
+        let (state, _, _) = test_support::app_state();
+
+        let (status, body) = get_config_json(state).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["code"], "CONFIG_UNAVAILABLE");
+    }
+
+    #[tokio::test]
+    async fn test_metrics_reflect_requests() {
+        use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+        use llm_registry_core::execution::SpanId;
+        use tower::ServiceExt;
+
+        let (state, _, _) = test_support::app_state();
+        let metrics = state.metrics.clone();
+        let app = crate::routes::build_router(state);
+
+        let get = |uri: &str| {
+            axum::http::Request::builder()
+                .uri(uri)
+                .header(HEADER_EXECUTION_ID, "exec-metrics")
+                .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+                .body(Body::empty())
+                .unwrap()
+        };
+        for uri in [
+            "/v1/assets",
+            "/v1/assets",
+            "/v1/assets/not-an-id",
+            "/health",
+        ] {
+            app.clone().oneshot(get(uri)).await.unwrap();
+        }
+
+        let response = app.oneshot(get("/metrics")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let scraped = String::from_utf8(body.to_vec()).unwrap();
+
+        for line in [
+            r#"llm_registry_http_requests_total{method="GET",route="/v1/assets",status="200"} 2"#,
+            r#"llm_registry_http_requests_total{method="GET",route="/health",status="200"} 1"#,
+            r#"llm_registry_http_request_errors_total{method="GET",route="/v1/assets/{id}",status="400"} 1"#,
+            r#"llm_registry_http_request_duration_seconds_count{method="GET",route="/v1/assets",status="200"} 2"#,
+            "llm_registry_search_queries_total 2",
+            "llm_registry_governance_events_dropped_total 0",
+        ] {
+            assert!(
+                scraped.contains(line),
+                "missing {:?} in:\n{}",
+                line,
+                scraped
+            );
+        }
+
+        // The observatory view is derived from the same counters
+        let registry_metrics = metrics.registry_metrics();
+        assert_eq!(registry_metrics.search_queries_hour, 2);
+        assert_eq!(registry_metrics.assets_registered_hour, 0);
+    }
+
+    #[tokio::test]
+    async fn test_v1_requests_export_spans_to_observatory() {
+        use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+        use llm_registry_core::execution::{SpanId, SpanType};
+        use tower::ServiceExt;
+
+        let (state, _, _) = test_support::app_state();
+        let observatory = state.observatory.clone();
+        let app = crate::routes::build_router(state);
+
+        let template = test_support::asset("exported-model", "1.0.0");
+        let body = serde_json::json!({
+            "asset_type": template.asset_type,
+            "name": template.metadata.name,
+            "version": template.metadata.version,
+            "storage": template.storage,
+            "checksum": template.checksum,
+        });
+        let parent_span_id = SpanId::new();
+        let response = app
+            .clone()
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/v1/assets")
+                    .header(HEADER_EXECUTION_ID, "exec-export")
+                    .header(HEADER_PARENT_SPAN_ID, parent_span_id.to_string())
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        // Infrastructure endpoints are outside the execution boundary
+        let response = app
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let executions = observatory.buffered_executions().await;
+        assert_eq!(executions.len(), 1);
+        let execution = &executions[0];
+        assert_eq!(execution.execution_id.as_str(), "exec-export");
+
+        let repo = &execution.spans[0];
+        assert_eq!(repo.span_type, SpanType::Repo);
+        assert_eq!(repo.parent_span_id, parent_span_id);
+        assert!(repo.ended_at.is_some());
+
+        let agent = &execution.spans[1];
+        assert_eq!(agent.span_type, SpanType::Agent);
+        assert_eq!(agent.name, AgentService::Registration.as_str());
+        assert_eq!(agent.parent_span_id, repo.span_id);
+    }
+
+    /// POST /v1/assets for a fresh asset named `name`, optionally idempotent
+    async fn post_asset(
+        app: &axum::Router,
+        name: &str,
+        idempotency_key: Option<&str>,
+    ) -> (StatusCode, serde_json::Value) {
+        use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+        use llm_registry_core::execution::SpanId;
+        use tower::ServiceExt;
+
+        let template = test_support::asset(name, "1.0.0");
+        let body = serde_json::json!({
+            "asset_type": template.asset_type,
+            "name": template.metadata.name,
+            "version": template.metadata.version,
+            "storage": template.storage,
+            "checksum": template.checksum,
+        });
+        // Every request runs under its own execution
+        let mut request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/v1/assets")
+            .header(HEADER_EXECUTION_ID, uuid::Uuid::new_v4().to_string())
+            .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(key) = idempotency_key {
+            request = request.header(HEADER_IDEMPOTENCY_KEY, key);
+        }
+        let response = app
+            .clone()
+            .oneshot(request.body(Body::from(body.to_string())).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    fn dry_run_request(name: &str) -> RegisterAssetRequest {
+        let template = test_support::asset("model", "1.0.0");
+        serde_json::from_value(serde_json::json!({
+            "asset_type": template.asset_type,
+            "name": name,
+            "version": template.metadata.version,
+            "storage": template.storage,
+            "checksum": template.checksum,
+            "dry_run": true,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_register_dry_run_persists_nothing() {
+        let (state, repository, events) = test_support::app_state();
+
+        let (status, Json(envelope)) = register_asset(
+            State(state),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(dry_run_request("dry-model")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(envelope.data.dry_run);
+        assert_eq!(envelope.data.asset.metadata.name, "dry-model");
+        assert!(repository.assets.lock().unwrap().is_empty());
+        // Policy evaluation is still recorded, registration is not
+        assert!(!events.events.lock().unwrap().iter().any(|e| matches!(
+            e.event_type,
+            llm_registry_core::EventType::AssetRegistered { .. }
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_register_dry_run_reports_validation_failures() {
+        let (state, repository, events) = test_support::app_state();
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .push(test_support::asset("taken", "1.0.0"));
+
+        for (name, expected) in [
+            ("", StatusCode::UNPROCESSABLE_ENTITY),
+            ("taken", StatusCode::CONFLICT),
+        ] {
+            let err = register_asset(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                None,
+                HeaderMap::new(),
+                Json(dry_run_request(name)),
+            )
+            .await
+            .unwrap_err();
+            assert_eq!(
+                axum::response::IntoResponse::into_response(err).status(),
+                expected
+            );
+        }
+
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+        assert!(!events.events.lock().unwrap().iter().any(|e| matches!(
+            e.event_type,
+            llm_registry_core::EventType::AssetRegistered { .. }
+        )));
+    }
+
+    use llm_registry_service::adapters::schema_registry::{
+        ConsumedSchema, SchemaAdapterError, SchemaResult, SchemaValidationResult,
+    };
+
+    /// Schema registry rejecting every document with `errors`
+    #[derive(Default)]
+    struct StubSchemaRegistry {
+        errors: Vec<String>,
+        validated: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl SchemaConsumer for StubSchemaRegistry {
+        async fn get_schema(&self, name: &str, _namespace: &str) -> SchemaResult<ConsumedSchema> {
+            Err(SchemaAdapterError::SchemaNotFound(name.to_string()))
+        }
+
+        async fn get_schema_version(
+            &self,
+            name: &str,
+            namespace: &str,
+            _version: &str,
+        ) -> SchemaResult<ConsumedSchema> {
+            self.get_schema(name, namespace).await
+        }
+
+        async fn validate_against_schema(
+            &self,
+            schema_name: &str,
+            _namespace: &str,
+            _data: &serde_json::Value,
+        ) -> SchemaResult<SchemaValidationResult> {
+            self.validated.lock().unwrap().push(schema_name.to_string());
+            Ok(SchemaValidationResult {
+                valid: self.errors.is_empty(),
+                schema_id: format!("{}-1", schema_name),
+                errors: self.errors.clone(),
+                warnings: vec![],
+                unvalidated: false,
+                fallback: None,
+            })
+        }
+
+        async fn list_schemas(&self, _namespace: &str) -> SchemaResult<Vec<String>> {
+            Ok(vec![])
+        }
+    }
+
+    fn model_request(name: &str) -> RegisterAssetRequest {
+        RegisterAssetRequest {
+            dry_run: None,
+            ..dry_run_request(name)
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_concurrent_duplicate_registration_conflicts() {
+        let (state, repository, _) = test_support::app_state();
+        *repository.create_delay.lock().unwrap() = Duration::from_millis(50);
+
+        let register = |state: AppState| {
+            tokio::spawn(async move {
+                register_asset(
+                    State(state),
+                    Extension(test_support::collector()),
+                    None,
+                    HeaderMap::new(),
+                    Json(model_request("racy-model")),
+                )
+                .await
+            })
+        };
+        let first = register(state.clone());
+        let second = register(state);
+        let results = [first.await.unwrap(), second.await.unwrap()];
+
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+
+        let err = results.into_iter().find_map(Result::err).unwrap();
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "ALREADY_EXISTS");
+        let registration = body["execution"]["spans"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["name"] == AgentService::Registration.as_str())
+            .unwrap();
+        assert_eq!(registration["status"], "failed");
+        assert!(registration["ended_at"].is_string());
+    }
 
     #[test]
-    fn test_version_info_creation() {
-        let info = VersionInfo {
-            version: "0.1.0".to_string(),
-            api_version: "v1".to_string(),
-            build_timestamp: "2024-01-01".to_string(),
+    fn test_register_request_rejects_invalid_version() {
+        let mut request = serde_json::to_value(model_request("model")).unwrap();
+        request["version"] = serde_json::json!("1.2");
+
+        let err = serde_json::from_value::<RegisterAssetRequest>(request).unwrap_err();
+        assert!(
+            err.to_string().contains("'1.2' is not a semantic version"),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_register_model_valid_against_schema() {
+        let (state, repository, _) = test_support::app_state();
+        let schema_registry = Arc::new(StubSchemaRegistry::default());
+        let state = state.with_schema_registry(schema_registry.clone());
+
+        let (status, Json(envelope)) = register_asset(
+            State(state),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(model_request("schema-model")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(
+            *schema_registry.validated.lock().unwrap(),
+            vec![MODEL_METADATA_SCHEMA.to_string()]
+        );
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+        let validation = envelope
+            .execution
+            .spans
+            .iter()
+            .find(|s| s.name == AgentService::Validation.as_str())
+            .unwrap();
+        assert_eq!(validation.status, SpanStatus::Ok);
+        assert_eq!(validation.artifacts[0].name, "schema_validation");
+    }
+
+    #[tokio::test]
+    async fn test_register_invalid_model_rejected_in_strict_mode() {
+        use llm_registry_service::adapters::config_manager::Environment;
+
+        let (state, repository, _) = test_support::app_state();
+        // Staging runs with strict validation
+        let config = ConfigManagerAdapter::new(Environment::Staging);
+        config.refresh().await.unwrap();
+        let state = state
+            .with_config_manager(Arc::new(config))
+            .with_schema_registry(Arc::new(StubSchemaRegistry {
+                errors: vec!["/parameters: expected integer".to_string()],
+                ..Default::default()
+            }));
+
+        let err = register_asset(
+            State(state),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(model_request("malformed-model")),
+        )
+        .await
+        .unwrap_err();
+
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "SCHEMA_VALIDATION_FAILED");
+        assert_eq!(
+            body["details"]["errors"][0],
+            "/parameters: expected integer"
+        );
+
+        let validation = body["execution"]["spans"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["name"] == AgentService::Validation.as_str())
+            .unwrap();
+        assert_eq!(validation["status"], "failed");
+        assert_eq!(validation["artifacts"][0]["name"], "schema_errors");
+        assert!(repository.assets.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_register_rejected_when_schema_registry_unavailable() {
+        let (state, repository, _) = test_support::app_state();
+        // No endpoint and a rejecting fallback: every lookup is Unavailable
+        let state = state.with_schema_registry(Arc::new(
+            llm_registry_service::SchemaRegistryAdapter::new()
+                .with_fallback(SchemaFallbackMode::Reject),
+        ));
+
+        let err = register_asset(
+            State(state),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(model_request("offline-model")),
+        )
+        .await
+        .unwrap_err();
+
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "SCHEMA_VALIDATION_UNAVAILABLE");
+        let validation = body["execution"]["spans"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["name"] == AgentService::Validation.as_str())
+            .unwrap();
+        assert_eq!(validation["status"], "failed");
+        assert!(repository.assets.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_register_proceeds_when_schema_registry_unavailable_and_permissive() {
+        let (state, repository, _) = test_support::app_state();
+        let state = state.with_schema_registry(Arc::new(
+            llm_registry_service::SchemaRegistryAdapter::new()
+                .with_fallback(SchemaFallbackMode::Permissive),
+        ));
+
+        let (status, Json(envelope)) = register_asset(
+            State(state),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(model_request("offline-model")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(envelope.data.warnings.iter().any(|w| w.contains("skipped")));
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_register_uses_configured_schema_fallback() {
+        let (state, repository, _) = test_support::app_state();
+        // The adapter would admit the asset, but the production config rejects
+        let config_manager = Arc::new(ConfigManagerAdapter::new(Environment::Production));
+        config_manager.refresh().await.unwrap();
+        let state = state
+            .with_schema_registry(Arc::new(
+                llm_registry_service::SchemaRegistryAdapter::new()
+                    .with_fallback(SchemaFallbackMode::Permissive),
+            ))
+            .with_config_manager(config_manager);
+
+        let err = register_asset(
+            State(state),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(model_request("offline-model")),
+        )
+        .await
+        .unwrap_err();
+
+        let response = axum::response::IntoResponse::into_response(err);
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(repository.assets.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_register_queue_fallback_marks_asset_unvalidated() {
+        use llm_registry_service::adapters::config_manager::RegistryConfig;
+
+        // Minimal upstream config manager serving a queueing fallback
+        let mut config = RegistryConfig {
+            environment: Environment::Staging,
+            ..Default::default()
         };
+        config.validation.schema_fallback = SchemaFallbackMode::Queue;
+        let upstream = axum::Router::new().route(
+            "/configs/llm.registry/staging",
+            axum::routing::get(move || async move { Json(config) }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, upstream).await });
 
-        assert_eq!(info.version, "0.1.0");
-        assert_eq!(info.api_version, "v1");
+        let config_manager = Arc::new(ConfigManagerAdapter::with_endpoint(
+            Environment::Staging,
+            endpoint,
+        ));
+        config_manager.refresh().await.unwrap();
+        let (state, repository, _) = test_support::app_state();
+        let state = state
+            .with_schema_registry(Arc::new(llm_registry_service::SchemaRegistryAdapter::new()))
+            .with_config_manager(config_manager);
+
+        let (status, Json(envelope)) = register_asset(
+            State(state),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(model_request("offline-model")),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(!envelope.data.warnings.is_empty());
+        let assets = repository.assets.lock().unwrap();
+        assert_eq!(
+            assets[0]
+                .metadata
+                .annotations
+                .get(SCHEMA_UNVALIDATED_ANNOTATION),
+            Some(&"true".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_registration_enforces_configured_limits_for_every_caller() {
+        use llm_registry_service::adapters::config_manager::{
+            AssetType as ConfigAssetType, RegistryConfig, TypeConstraints,
+        };
+
+        let mut config = RegistryConfig::default();
+        config.validation.max_metadata_size = 2048;
+        config.validation.per_type.insert(
+            ConfigAssetType::Model,
+            TypeConstraints {
+                max_tags: Some(1),
+                ..Default::default()
+            },
+        );
+        let repository = Arc::new(test_support::InMemoryRepository::default());
+        let services = llm_registry_service::ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(Arc::new(test_support::InMemoryEventStore::default()))
+            .config(Arc::new(llm_registry_service::InMemoryConfigConsumer::new(
+                config,
+            )))
+            .build()
+            .unwrap();
+        let state = AppState::new(services);
+
+        let tagged = |name: &str, asset_type: AssetType| RegisterAssetRequest {
+            asset_type,
+            tags: vec!["nlp".to_string(), "bert".to_string()],
+            ..model_request(name)
+        };
+
+        // Through the HTTP handler
+        let err = register_asset(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            None,
+            HeaderMap::new(),
+            Json(tagged("tagged-model", AssetType::Model)),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            axum::response::IntoResponse::into_response(err).status(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+
+        // Directly, as GraphQL and gRPC call it
+        let registration = state.services.registration();
+        let err = registration
+            .register_asset(tagged("tagged-model", AssetType::Model))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            llm_registry_service::ServiceError::ValidationFailed(_)
+        ));
+        let mut oversized = model_request("verbose-model");
+        oversized.description = Some("x".repeat(4096));
+        let err = registration.register_asset(oversized).await.unwrap_err();
+        assert!(err.to_string().contains("Metadata is"));
+
+        // Other types keep the global limits
+        registration
+            .register_asset(tagged("tagged-dataset", AssetType::Dataset))
+            .await
+            .unwrap();
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_first_call_registers() {
+        let (state, repository, _) = test_support::app_state();
+        let app = crate::routes::build_router(state);
+
+        let (status, body) = post_asset(&app, "idempotent-model", Some("key-1")).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(
+            body["data"]["asset"]["metadata"]["name"],
+            "idempotent-model"
+        );
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_duplicate_returns_cached_response() {
+        let (state, repository, _) = test_support::app_state();
+        let app = crate::routes::build_router(state);
+
+        let (_, first) = post_asset(&app, "idempotent-model", Some("key-1")).await;
+        let (status, replay) = post_asset(&app, "idempotent-model", Some("key-1")).await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(replay["data"]["asset"]["id"], first["data"]["asset"]["id"]);
+        assert_eq!(
+            replay["execution"]["execution_id"],
+            first["execution"]["execution_id"]
+        );
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_different_keys_register_separately() {
+        let (state, repository, _) = test_support::app_state();
+        let app = crate::routes::build_router(state);
+
+        let (_, first) = post_asset(&app, "idempotent-model", Some("key-1")).await;
+        let (status, second) = post_asset(&app, "other-model", Some("key-2")).await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_ne!(second["data"]["asset"]["id"], first["data"]["asset"]["id"]);
+        assert_eq!(repository.assets.lock().unwrap().len(), 2);
+
+        let (status, _) = post_asset(&app, "third-model", Some("   ")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_reused_with_other_body_rejected() {
+        let (state, repository, _) = test_support::app_state();
+        let app = crate::routes::build_router(state);
+
+        post_asset(&app, "idempotent-model", Some("key-1")).await;
+        let (status, body) = post_asset(&app, "other-model", Some("key-1")).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "IDEMPOTENCY_KEY_MISMATCH");
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_concurrent_retry_registers_once() {
+        let (state, repository, _) = test_support::app_state();
+        *repository.create_delay.lock().unwrap() = Duration::from_millis(50);
+        let app = crate::routes::build_router(state);
+
+        let ((first, _), (second, body)) =
+            tokio::join!(post_asset(&app, "idempotent-model", Some("key-1")), async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                post_asset(&app, "idempotent-model", Some("key-1")).await
+            });
+
+        assert_eq!(first, StatusCode::CREATED);
+        assert_eq!(second, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "IDEMPOTENCY_KEY_IN_USE");
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+
+        // Once the first request completes, a retry replays it
+        let (status, _) = post_asset(&app, "idempotent-model", Some("key-1")).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(repository.assets.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_keys_are_scoped_to_principal() {
+        let (state, repository, _) = test_support::app_state();
+        let mut headers = HeaderMap::new();
+        headers.insert(HEADER_IDEMPOTENCY_KEY, "key-1".parse().unwrap());
+
+        for (user, name) in [("alice", "alice-model"), ("bob", "bob-model")] {
+            let claims = crate::jwt::Claims::new(user, "test", "test", 3600);
+            let (status, Json(envelope)) = register_asset(
+                State(state.clone()),
+                Extension(test_support::collector()),
+                Some(Extension(AuthUser::new(claims))),
+                headers.clone(),
+                Json(model_request(name)),
+            )
+            .await
+            .unwrap();
+            assert_eq!(status, StatusCode::CREATED);
+            assert_eq!(envelope.data.asset.metadata.name, name);
+        }
+        assert_eq!(repository.assets.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_expired_assets_hidden_when_ttl_enforced() {
+        use llm_registry_service::adapters::config_manager::Environment;
+        use llm_registry_service::adapters::ConfigConsumer;
+        use llm_registry_service::{ConfigManagerAdapter, ServiceRegistryBuilder};
+
+        // Production enforces TTLs
+        let config = ConfigManagerAdapter::new(Environment::Production);
+        config.refresh().await.unwrap();
+        let ttl = config.get_ttl_config().await.unwrap();
+        assert!(ttl.enforce);
+
+        let repository = Arc::new(test_support::InMemoryRepository::default());
+        let services = ServiceRegistryBuilder::new()
+            .repository(repository.clone())
+            .event_store(Arc::new(test_support::InMemoryEventStore::default()))
+            .ttl_config(ttl.clone())
+            .build()
+            .unwrap();
+        let state = AppState::new(services);
+
+        // The expired asset is newer, so it sorts onto the first page
+        let mut fresh = test_support::asset("fresh", "1.0.0");
+        fresh.created_at -= chrono::Duration::minutes(1);
+        repository.assets.lock().unwrap().push(fresh);
+        let mut archived = test_support::asset("archived", "1.0.0");
+        archived.status = llm_registry_core::AssetStatus::Archived;
+        archived.updated_at = chrono::Utc::now()
+            - chrono::Duration::from_std(ttl.archived_ttl).unwrap()
+            - chrono::Duration::days(1);
+        let archived_id = archived.id;
+        repository.assets.lock().unwrap().push(archived);
+
+        let search = |include_expired: bool, limit: i64| {
+            let state = state.clone();
+            async move {
+                let params = SearchAssetsRequest {
+                    include_expired,
+                    limit,
+                    ..Default::default()
+                };
+                let Json(envelope) = list_assets(
+                    State(state),
+                    Extension(test_support::collector()),
+                    Query(params),
+                    Query(FieldsParams::default()),
+                )
+                .await
+                .unwrap();
+                let names: Vec<String> = envelope
+                    .items
+                    .into_iter()
+                    .map(|a| a.into_inner().metadata.name)
+                    .collect();
+                (names, envelope.pagination.total)
+            }
+        };
+
+        assert_eq!(search(false, 20).await, (vec!["fresh".to_string()], 1));
+        assert_eq!(search(false, 1).await, (vec!["fresh".to_string()], 1));
+        let (names, total) = search(true, 20).await;
+        assert_eq!((names.len(), total), (2, 2));
+
+        let expired = state.services.search().find_expired_assets().await.unwrap();
+        assert_eq!(expired, vec![archived_id]);
+    }
+
+    /// POST /v1/assets/{id}/dependencies, returning the error body on failure
+    async fn add_deps(
+        state: &AppState,
+        id: AssetId,
+        dependency_ids: Vec<AssetId>,
+    ) -> Result<Asset, (StatusCode, serde_json::Value)> {
+        match add_dependencies(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(id.to_string()),
+            Json(dependency_ids),
+        )
+        .await
+        {
+            Ok(Json(envelope)) => Ok(envelope.data),
+            Err(err) => {
+                let response = axum::response::IntoResponse::into_response(err);
+                let status = response.status();
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                    .await
+                    .unwrap();
+                Err((status, serde_json::from_slice(&body).unwrap()))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_add_dependencies() {
+        let (state, repository, _) = test_support::app_state();
+        let pipeline = test_support::asset("pipeline", "1.0.0");
+        let model = test_support::asset("model", "1.0.0");
+        let tokenizer = test_support::asset("tokenizer", "1.0.0");
+        let (p, m, t) = (pipeline.id, model.id, tokenizer.id);
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([pipeline, model, tokenizer]);
+
+        let asset = add_deps(&state, p, vec![m, t, m]).await.unwrap();
+        let deps: Vec<AssetId> = asset
+            .dependencies
+            .iter()
+            .filter_map(|d| d.as_id().copied())
+            .collect();
+        assert_eq!(deps, [m, t]);
+        assert_eq!(*repository.dependencies.lock().unwrap(), [(p, m), (p, t)]);
+
+        // Re-adding an existing dependency is a no-op
+        add_deps(&state, p, vec![t]).await.unwrap();
+        assert_eq!(repository.dependencies.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_add_dependencies_missing_target() {
+        let (state, repository, _) = test_support::app_state();
+        let pipeline = test_support::asset("pipeline", "1.0.0");
+        let model = test_support::asset("model", "1.0.0");
+        let (p, m, missing) = (pipeline.id, model.id, AssetId::new());
+        repository.assets.lock().unwrap().extend([pipeline, model]);
+
+        let (status, body) = add_deps(&state, p, vec![m, missing]).await.unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "DEPENDENCY_NOT_FOUND");
+        assert_eq!(body["details"]["dependency_id"], missing.to_string());
+        assert!(repository.dependencies.lock().unwrap().is_empty());
+        assert!(repository.assets.lock().unwrap()[0].dependencies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_add_dependencies_rejects_cycle() {
+        let (state, repository, _) = test_support::app_state();
+        let pipeline = test_support::asset("pipeline", "1.0.0");
+        let model = test_support::asset("model", "1.0.0");
+        let (p, m) = (pipeline.id, model.id);
+        repository.assets.lock().unwrap().extend([pipeline, model]);
+        add_deps(&state, m, vec![p]).await.unwrap();
+
+        let tokenizer = test_support::asset("tokenizer", "1.0.0");
+        let t = tokenizer.id;
+        repository.assets.lock().unwrap().push(tokenizer);
+
+        let (status, body) = add_deps(&state, p, vec![t, m]).await.unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "CIRCULAR_DEPENDENCY");
+        assert_eq!(body["details"]["dependency_id"], m.to_string());
+        // The valid target listed first was not added either
+        assert_eq!(*repository.dependencies.lock().unwrap(), [(m, p)]);
+    }
+
+    async fn remove_dep(state: &AppState, id: AssetId, dep_id: AssetId) -> ApiResult<StatusCode> {
+        remove_dependency(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(DependencyEdgePath {
+                id: id.to_string(),
+                dep_id: dep_id.to_string(),
+            }),
+        )
+        .await
+    }
+
+    async fn dependency_ids(state: &AppState, id: AssetId) -> Vec<AssetId> {
+        let Json(envelope) = get_dependencies(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(id.to_string()),
+            Query(DependencyGraphParams { max_depth: None }),
+        )
+        .await
+        .unwrap();
+        envelope
+            .data
+            .dependencies
+            .iter()
+            .map(|node| node.asset_id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_remove_dependency() {
+        let (state, repository, _) = test_support::app_state();
+        let pipeline = test_support::asset("pipeline", "1.0.0");
+        let model = test_support::asset("model", "1.0.0");
+        let tokenizer = test_support::asset("tokenizer", "1.0.0");
+        let (p, m, t) = (pipeline.id, model.id, tokenizer.id);
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([pipeline, model, tokenizer]);
+        add_deps(&state, p, vec![m, t]).await.unwrap();
+
+        // Populate the graph cache so removal has to invalidate it
+        assert!(dependency_ids(&state, p).await.contains(&m));
+
+        assert_eq!(
+            remove_dep(&state, p, m).await.unwrap(),
+            StatusCode::NO_CONTENT
+        );
+        let remaining = dependency_ids(&state, p).await;
+        assert!(!remaining.contains(&m));
+        assert!(remaining.contains(&t));
+        assert_eq!(*repository.dependencies.lock().unwrap(), [(p, t)]);
+
+        let Json(envelope) = get_dependents(
+            State(state.clone()),
+            Extension(test_support::collector()),
+            Path(m.to_string()),
+            Query(DependentsParams { transitive: None }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(envelope.data.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_remove_missing_dependency_is_not_found() {
+        let (state, repository, _) = test_support::app_state();
+        let pipeline = test_support::asset("pipeline", "1.0.0");
+        let model = test_support::asset("model", "1.0.0");
+        let (p, m) = (pipeline.id, model.id);
+        repository.assets.lock().unwrap().extend([pipeline, model]);
+
+        let err = remove_dep(&state, p, m).await.unwrap_err();
+        assert_eq!(status_of(err), StatusCode::NOT_FOUND);
+        let err = remove_dep(&state, AssetId::new(), m).await.unwrap_err();
+        assert_eq!(status_of(err), StatusCode::NOT_FOUND);
+    }
+
+    // Only the in-memory repository keeps a reverse index separate from its
+    // forward edges, so only it can drift; PostgreSQL serves both directions
+    // from asset_dependencies and always reports nothing added or removed.
+    #[tokio::test]
+    async fn test_reindex_dependents_repairs_drifted_index() {
+        let (state, repository, _) = test_support::app_state();
+        let mut pipeline = test_support::asset("pipeline", "1.0.0");
+        let model = test_support::asset("model", "1.0.0");
+        let tokenizer = test_support::asset("tokenizer", "1.0.0");
+        let (p, m, t) = (pipeline.id, model.id, tokenizer.id);
+
+        // Imported edges the index never saw, plus an indexed edge nobody declares
+        pipeline.dependencies = vec![
+            llm_registry_core::AssetReference::by_id(m),
+            llm_registry_core::AssetReference::by_id(t),
+        ];
+        repository
+            .assets
+            .lock()
+            .unwrap()
+            .extend([pipeline, model, tokenizer]);
+        repository.dependencies.lock().unwrap().push((t, m));
+
+        let reindex = || async {
+            let Json(envelope) =
+                reindex_dependents(State(state.clone()), Extension(test_support::collector()))
+                    .await
+                    .unwrap();
+            envelope.data
+        };
+        let dependents = |id: AssetId| {
+            let state = state.clone();
+            async move {
+                state
+                    .services
+                    .search()
+                    .get_reverse_dependencies(&id)
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|a| a.id)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let report = reindex().await;
+        assert_eq!(report.edges_processed, 2);
+        assert_eq!(report.edges_added, 2);
+        assert_eq!(report.edges_removed, 1);
+        assert_eq!(dependents(m).await, [p]);
+        assert_eq!(dependents(t).await, [p]);
+        assert!(dependents(p).await.is_empty());
+
+        // A second run finds nothing to repair
+        let report = reindex().await;
+        assert_eq!(report.edges_processed, 2);
+        assert_eq!(report.edges_added, 0);
+        assert_eq!(report.edges_removed, 0);
+        assert_eq!(dependents(m).await, [p]);
+    }
+
+    async fn ingest(record: serde_json::Value) -> (StatusCode, serde_json::Value) {
+        let (state, _, _) = test_support::app_state();
+        ingest_into(state, record).await
+    }
+
+    async fn ingest_into(
+        state: AppState,
+        record: serde_json::Value,
+    ) -> (StatusCode, serde_json::Value) {
+        use tower::ServiceExt;
+
+        let response = crate::routes::build_router(state)
+            .oneshot(
+                axum::http::Request::builder()
+                    .method("POST")
+                    .uri("/api/v1/executions")
+                    .header("content-type", "application/json")
+                    .body(Body::from(record.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn execution_record() -> serde_json::Value {
+        serde_json::json!({
+            "source": "data-core",
+            "event_type": "execution_completed",
+            "execution_id": "exec-42",
+            "timestamp": "2025-03-01T12:30:00Z",
+            "payload": { "inputs": ["model@1.0.0"] },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_accepts_valid_record() {
+        let (status, response) = ingest(execution_record()).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(response["status"], "accepted");
+        assert_eq!(response["execution_id"], "exec-42");
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_rejects_bad_timestamp() {
+        let mut record = execution_record();
+        record["timestamp"] = serde_json::json!("yesterday at noon");
+        let (status, _) = ingest(record).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_rejects_unknown_event_type() {
+        let mut record = execution_record();
+        record["event_type"] = serde_json::json!("execution_teleported");
+        let (status, _) = ingest(record).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_rejects_oversized_payload() {
+        let mut record = execution_record();
+        record["payload"] = serde_json::json!("x".repeat(MAX_EXECUTION_PAYLOAD_BYTES));
+        let (status, _) = ingest(record).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_answers_retry_as_duplicate() {
+        let (state, _, _) = test_support::app_state();
+
+        let (status, response) = ingest_into(state.clone(), execution_record()).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(response["status"], "accepted");
+
+        let (status, response) = ingest_into(state.clone(), execution_record()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response["status"], "duplicate");
+        assert_eq!(response["execution_id"], "exec-42");
+
+        let mut other = execution_record();
+        other["execution_id"] = serde_json::json!("exec-43");
+        let (status, _) = ingest_into(state, other).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_in_flight_retry_is_retryable() {
+        struct SlowSink;
+
+        #[async_trait::async_trait]
+        impl ExecutionRecordSink for SlowSink {
+            async fn persist(&self, _: &ExecutionRecordRequest) -> Result<(), String> {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(())
+            }
+        }
+
+        let (state, _, _) = test_support::app_state();
+        let state = state.with_execution_sink(Arc::new(SlowSink));
+
+        let (first, retry) = tokio::join!(ingest_into(state.clone(), execution_record()), async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            ingest_into(state.clone(), execution_record()).await
+        });
+        assert_eq!(first.0, StatusCode::ACCEPTED);
+        assert_eq!(retry.0, StatusCode::SERVICE_UNAVAILABLE);
+
+        // Once persisted, a retry is a duplicate
+        let (status, response) = ingest_into(state, execution_record()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response["status"], "duplicate");
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_cancelled_delivery_is_not_left_in_flight() {
+        /// Sink whose first persist never completes
+        #[derive(Default)]
+        struct HangingSink(std::sync::atomic::AtomicBool);
+
+        #[async_trait::async_trait]
+        impl ExecutionRecordSink for HangingSink {
+            async fn persist(&self, _: &ExecutionRecordRequest) -> Result<(), String> {
+                if !self.0.swap(true, std::sync::atomic::Ordering::SeqCst) {
+                    std::future::pending::<()>().await;
+                }
+                Ok(())
+            }
+        }
+
+        let (state, _, _) = test_support::app_state();
+        let state = state.with_execution_sink(Arc::new(HangingSink::default()));
+
+        // The client gives up on the first delivery mid-persist
+        let cancelled = tokio::time::timeout(
+            Duration::from_millis(20),
+            ingest_into(state.clone(), execution_record()),
+        )
+        .await;
+        assert!(cancelled.is_err());
+        tokio::task::yield_now().await;
+
+        let (status, response) = ingest_into(state, execution_record()).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert_eq!(response["status"], "accepted");
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_accepts_retry_after_dedup_expiry() {
+        let (state, _, _) = test_support::app_state();
+        let state =
+            state.with_execution_dedup(Arc::new(InMemoryExecutionDedupStore::new(Duration::ZERO)));
+
+        for _ in 0..2 {
+            let (status, response) = ingest_into(state.clone(), execution_record()).await;
+            assert_eq!(status, StatusCode::ACCEPTED);
+            assert_eq!(response["status"], "accepted");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_persists_record() {
+        let path = std::env::temp_dir()
+            .join(format!("execution-records-{}", uuid::Uuid::new_v4()))
+            .join("records.jsonl");
+        let sink = Arc::new(crate::FileExecutionRecordSink::new(&path));
+        let (state, _, _) = test_support::app_state();
+        let state = state.with_execution_sink(sink);
+
+        let (status, _) = ingest_into(state, execution_record()).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<serde_json::Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines, [execution_record()]);
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_receive_execution_sink_failure_is_server_error() {
+        // A directory cannot be opened for appending
+        let sink = Arc::new(crate::FileExecutionRecordSink::new(std::env::temp_dir()));
+        let (state, _, _) = test_support::app_state();
+        let state = state.with_execution_sink(sink);
+
+        // The failed delivery is not remembered, so the retry is attempted anew
+        for _ in 0..2 {
+            let (status, response) = ingest_into(state.clone(), execution_record()).await;
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+            assert_ne!(response["status"], "duplicate");
+        }
     }
 }
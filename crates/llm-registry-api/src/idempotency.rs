@@ -0,0 +1,433 @@
+//! Idempotent asset registration
+//!
+//! Clients may send an `Idempotency-Key` header with `POST /v1/assets`. Keys
+//! are scoped to the authenticated principal, so two callers never see each
+//! other's registrations. Before registering, the handler reserves the key
+//! together with a hash of the request body in an [`IdempotencyStore`]:
+//!
+//! - a repeat of a completed registration with the same body replays the
+//!   recorded response, including the original execution result
+//! - a repeat with a different body is rejected
+//! - a repeat while the first request is still running is rejected as
+//!   retryable, so a key can never register twice
+//!
+//! Failed registrations release the key, so a retry after an error is
+//! attempted anew. A store that is full rejects new keys rather than
+//! forgetting recorded ones, which would let a retry register twice.
+
+use llm_registry_core::ExecutionResult;
+use llm_registry_service::RegisterAssetResponse;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Header carrying the client-chosen idempotency key
+pub const HEADER_IDEMPOTENCY_KEY: &str = "idempotency-key";
+
+/// Longest accepted idempotency key
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// How long a recorded registration is replayed by default
+pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Most keys an [`InMemoryIdempotencyStore`] holds by default
+pub const DEFAULT_MAX_IDEMPOTENCY_ENTRIES: usize = 100_000;
+
+/// An idempotency key as chosen by one principal
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdempotencyKey {
+    /// Authenticated user ID; `None` for anonymous callers
+    pub principal: Option<String>,
+    /// Client-chosen key
+    pub key: String,
+}
+
+impl IdempotencyKey {
+    /// Key `key` as used by `principal`
+    pub fn new(principal: Option<&str>, key: impl Into<String>) -> Self {
+        Self {
+            principal: principal.map(str::to_string),
+            key: key.into(),
+        }
+    }
+}
+
+/// A registration recorded under an idempotency key
+#[derive(Debug, Clone)]
+pub struct IdempotentRegistration {
+    /// Response of the original registration
+    pub response: RegisterAssetResponse,
+    /// Execution result of the original registration
+    pub execution: ExecutionResult,
+}
+
+/// Outcome of reserving an idempotency key
+#[derive(Debug, Clone)]
+pub enum Reservation {
+    /// The key is reserved for this request; complete or release it
+    Reserved,
+    /// A request with the same body completed; replay its registration
+    Completed(Box<IdempotentRegistration>),
+    /// A request with the same key is still being processed
+    InFlight,
+    /// The key was used with a different request body
+    Mismatch,
+    /// The store cannot take another key until entries expire
+    Full,
+}
+
+/// Storage for idempotency keys
+pub trait IdempotencyStore: Send + Sync {
+    /// Atomically reserve `key` for a request whose body hashes to `body_hash`
+    ///
+    /// Only [`Reservation::Reserved`] lets the caller proceed; it must then
+    /// [`complete`](Self::complete) or [`release`](Self::release) the key.
+    fn reserve(&self, key: &IdempotencyKey, body_hash: &str) -> Reservation;
+
+    /// Record the registration of a reserved key
+    fn complete(&self, key: &IdempotencyKey, registration: IdempotentRegistration);
+
+    /// Drop the reservation of a key whose request failed
+    fn release(&self, key: &IdempotencyKey);
+}
+
+/// Hash of a request body, for detecting a key reused with another request
+///
+/// The body is hashed in its JSON form, whose object keys are sorted, so
+/// the hash does not depend on map iteration order.
+pub fn body_hash<T: Serialize>(body: &T) -> String {
+    let json = serde_json::to_value(body)
+        .map(|value| value.to_string())
+        .unwrap_or_default();
+    Sha256::digest(json.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// An entry of [`InMemoryIdempotencyStore`]
+struct IdempotencyEntry {
+    reserved_at: Instant,
+    body_hash: String,
+    registration: Option<IdempotentRegistration>,
+}
+
+/// Reserved keys, plus the order they expire in
+#[derive(Default)]
+struct IdempotencyEntries {
+    by_key: HashMap<IdempotencyKey, IdempotencyEntry>,
+    /// Keys by reservation time, oldest first; with a fixed TTL this is also
+    /// expiry order. Released or re-reserved keys leave stale items behind,
+    /// recognised by a reservation time that no longer matches.
+    expiry: VecDeque<(Instant, IdempotencyKey)>,
+}
+
+impl IdempotencyEntries {
+    /// Drop entries reserved `ttl` or longer ago
+    fn prune(&mut self, ttl: Duration) {
+        while let Some((reserved_at, _)) = self.expiry.front() {
+            if reserved_at.elapsed() < ttl {
+                break;
+            }
+            let (reserved_at, key) = self.expiry.pop_front().unwrap();
+            if self
+                .by_key
+                .get(&key)
+                .is_some_and(|entry| entry.reserved_at == reserved_at)
+            {
+                self.by_key.remove(&key);
+            }
+        }
+    }
+}
+
+/// Process-local [`IdempotencyStore`] with a fixed TTL
+///
+/// Reservations and completed registrations both expire after the TTL. At
+/// most `max_entries` keys are held; further keys are rejected as
+/// [`Reservation::Full`] until older ones expire.
+pub struct InMemoryIdempotencyStore {
+    ttl: Duration,
+    max_entries: usize,
+    entries: Mutex<IdempotencyEntries>,
+}
+
+impl InMemoryIdempotencyStore {
+    /// Create a store whose entries expire after `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            max_entries: DEFAULT_MAX_IDEMPOTENCY_ENTRIES,
+            entries: Mutex::new(IdempotencyEntries::default()),
+        }
+    }
+
+    /// Set the most keys the store holds at once
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+}
+
+impl Default for InMemoryIdempotencyStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDEMPOTENCY_TTL)
+    }
+}
+
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    fn reserve(&self, key: &IdempotencyKey, body_hash: &str) -> Reservation {
+        let mut entries = self.entries.lock().unwrap();
+        // Expired entries are dropped, oldest first, whenever a key is reserved
+        entries.prune(self.ttl);
+
+        match entries.by_key.get(key) {
+            Some(entry) if entry.body_hash != body_hash => Reservation::Mismatch,
+            Some(IdempotencyEntry {
+                registration: Some(registration),
+                ..
+            }) => Reservation::Completed(Box::new(registration.clone())),
+            Some(_) => Reservation::InFlight,
+            None if entries.by_key.len() >= self.max_entries => Reservation::Full,
+            None => {
+                let reserved_at = Instant::now();
+                entries.by_key.insert(
+                    key.clone(),
+                    IdempotencyEntry {
+                        reserved_at,
+                        body_hash: body_hash.to_string(),
+                        registration: None,
+                    },
+                );
+                entries.expiry.push_back((reserved_at, key.clone()));
+                Reservation::Reserved
+            }
+        }
+    }
+
+    fn complete(&self, key: &IdempotencyKey, registration: IdempotentRegistration) {
+        if let Some(entry) = self.entries.lock().unwrap().by_key.get_mut(key) {
+            entry.registration = Some(registration);
+        }
+    }
+
+    fn release(&self, key: &IdempotencyKey) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries
+            .by_key
+            .get(key)
+            .is_some_and(|entry| entry.registration.is_none())
+        {
+            entries.by_key.remove(key);
+        }
+    }
+}
+
+/// Releases a reserved key when dropped before it was completed
+///
+/// Covers requests that fail as well as handlers cancelled mid-flight, for
+/// example when the client disconnects.
+pub struct ReservationGuard {
+    store: Arc<dyn IdempotencyStore>,
+    key: Option<IdempotencyKey>,
+}
+
+impl ReservationGuard {
+    /// Guard the reservation of `key` in `store`
+    pub fn new(store: Arc<dyn IdempotencyStore>, key: IdempotencyKey) -> Self {
+        Self {
+            store,
+            key: Some(key),
+        }
+    }
+
+    /// Record the registration, keeping the key reserved for replays
+    pub fn complete(mut self, registration: IdempotentRegistration) {
+        if let Some(key) = self.key.take() {
+            self.store.complete(&key, registration);
+        }
+    }
+}
+
+impl Drop for ReservationGuard {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.store.release(&key);
+        }
+    }
+}
+
+/// Validate an `Idempotency-Key` header value
+pub fn validate_key(key: &str) -> Result<&str, String> {
+    let key = key.trim();
+    if key.is_empty() {
+        return Err("Idempotency-Key must not be empty".to_string());
+    }
+    if key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+        return Err(format!(
+            "Idempotency-Key must be at most {} characters",
+            MAX_IDEMPOTENCY_KEY_LEN
+        ));
+    }
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn registration() -> IdempotentRegistration {
+        IdempotentRegistration {
+            response: RegisterAssetResponse {
+                asset: test_support::asset("model", "1.0.0"),
+                warnings: vec![],
+                dry_run: false,
+            },
+            execution: test_support::collector().finalize(),
+        }
+    }
+
+    fn key(principal: Option<&str>) -> IdempotencyKey {
+        IdempotencyKey::new(principal, "key-1")
+    }
+
+    #[test]
+    fn test_entries_expire() {
+        let store = InMemoryIdempotencyStore::new(Duration::ZERO);
+        assert!(matches!(
+            store.reserve(&key(None), "a"),
+            Reservation::Reserved
+        ));
+        store.complete(&key(None), registration());
+        assert!(matches!(
+            store.reserve(&key(None), "a"),
+            Reservation::Reserved
+        ));
+
+        let store = InMemoryIdempotencyStore::default();
+        let recorded = registration();
+        assert!(matches!(
+            store.reserve(&key(None), "a"),
+            Reservation::Reserved
+        ));
+        store.complete(&key(None), recorded.clone());
+        match store.reserve(&key(None), "a") {
+            Reservation::Completed(replayed) => {
+                assert_eq!(replayed.response.asset.id, recorded.response.asset.id)
+            }
+            other => panic!("expected a replay, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_expired_entries_are_pruned_in_order() {
+        let store = InMemoryIdempotencyStore::new(Duration::from_millis(100));
+        store.reserve(&IdempotencyKey::new(None, "key-1"), "a");
+        store.reserve(&IdempotencyKey::new(None, "key-2"), "a");
+        // Re-reserved after release: the stale expiry item must not evict it
+        store.release(&IdempotencyKey::new(None, "key-1"));
+        std::thread::sleep(Duration::from_millis(60));
+        store.reserve(&IdempotencyKey::new(None, "key-1"), "a");
+        std::thread::sleep(Duration::from_millis(60));
+
+        store.reserve(&IdempotencyKey::new(None, "key-3"), "a");
+        {
+            let entries = store.entries.lock().unwrap();
+            let mut keys: Vec<&str> = entries.by_key.keys().map(|k| k.key.as_str()).collect();
+            keys.sort();
+            assert_eq!(keys, ["key-1", "key-3"]);
+            assert_eq!(entries.expiry.len(), 2);
+        }
+        assert!(matches!(
+            store.reserve(&IdempotencyKey::new(None, "key-1"), "a"),
+            Reservation::InFlight
+        ));
+    }
+
+    #[test]
+    fn test_full_store_rejects_new_keys() {
+        let store = InMemoryIdempotencyStore::default().with_max_entries(1);
+        assert!(matches!(
+            store.reserve(&key(Some("alice")), "a"),
+            Reservation::Reserved
+        ));
+        store.complete(&key(Some("alice")), registration());
+
+        // Recorded keys are kept and still replay
+        assert!(matches!(
+            store.reserve(&key(Some("bob")), "a"),
+            Reservation::Full
+        ));
+        assert!(matches!(
+            store.reserve(&key(Some("alice")), "a"),
+            Reservation::Completed(_)
+        ));
+    }
+
+    #[test]
+    fn test_reservation_is_exclusive_and_scoped_to_principal() {
+        let store = InMemoryIdempotencyStore::default();
+        assert!(matches!(
+            store.reserve(&key(Some("alice")), "a"),
+            Reservation::Reserved
+        ));
+        assert!(matches!(
+            store.reserve(&key(Some("alice")), "a"),
+            Reservation::InFlight
+        ));
+        assert!(matches!(
+            store.reserve(&key(Some("alice")), "b"),
+            Reservation::Mismatch
+        ));
+        assert!(matches!(
+            store.reserve(&key(Some("bob")), "b"),
+            Reservation::Reserved
+        ));
+        assert!(matches!(
+            store.reserve(&key(None), "c"),
+            Reservation::Reserved
+        ));
+
+        store.release(&key(Some("alice")));
+        assert!(matches!(
+            store.reserve(&key(Some("alice")), "b"),
+            Reservation::Reserved
+        ));
+    }
+
+    #[test]
+    fn test_guard_releases_unless_completed() {
+        let store: Arc<dyn IdempotencyStore> = Arc::new(InMemoryIdempotencyStore::default());
+        store.reserve(&key(None), "a");
+        drop(ReservationGuard::new(store.clone(), key(None)));
+        assert!(matches!(
+            store.reserve(&key(None), "a"),
+            Reservation::Reserved
+        ));
+
+        ReservationGuard::new(store.clone(), key(None)).complete(registration());
+        assert!(matches!(
+            store.reserve(&key(None), "a"),
+            Reservation::Completed(_)
+        ));
+    }
+
+    #[test]
+    fn test_body_hash_ignores_map_order() {
+        let keys: Vec<String> = (0..32).map(|i| format!("key-{}", i)).collect();
+        let forward: HashMap<&String, usize> = keys.iter().zip(0..32).collect();
+        let backward: HashMap<&String, usize> = keys.iter().zip(0..32).rev().collect();
+        assert_eq!(body_hash(&forward), body_hash(&backward));
+        assert_ne!(body_hash(&forward), body_hash(&serde_json::json!({"a": 1})));
+    }
+
+    #[test]
+    fn test_validate_key() {
+        assert_eq!(validate_key(" abc "), Ok("abc"));
+        assert!(validate_key("  ").is_err());
+        assert!(validate_key(&"k".repeat(MAX_IDEMPOTENCY_KEY_LEN + 1)).is_err());
+    }
+}
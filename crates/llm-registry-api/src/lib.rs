@@ -39,43 +39,77 @@
 
 pub mod auth;
 pub mod auth_handlers;
+pub mod body_limit;
 pub mod error;
+pub mod execution_dedup;
 pub mod execution_middleware;
+pub mod execution_sink;
+pub mod governance_middleware;
 pub mod graphql;
 pub mod grpc;
 pub mod handlers;
+pub mod idempotency;
 pub mod jwt;
 pub mod metrics_middleware;
 pub mod middleware;
+pub mod negotiation;
+pub mod projection;
 pub mod rate_limit;
 pub mod rbac;
 pub mod responses;
 pub mod routes;
+pub mod timeout;
+pub mod timestamp_middleware;
+
+#[cfg(test)]
+mod test_support;
 
 // Re-export main types for convenience
-pub use auth::{AuthState, AuthUser, optional_auth, require_auth, require_role};
+pub use auth::{optional_auth, require_auth, require_role, AuthState, AuthUser};
 pub use auth_handlers::{AuthHandlerState, LoginRequest, LoginResponse, RefreshTokenRequest};
 pub use error::{ApiError, ApiResult, ErrorResponse};
+pub use execution_dedup::{
+    DedupOutcome, DedupReservation, ExecutionDedupStore, InMemoryExecutionDedupStore,
+};
+pub use execution_middleware::{
+    require_execution_context, ExecutionContextLayer, ExecutionHeaderNames,
+};
+pub use execution_sink::{
+    ExecutionRecordSink, FileExecutionRecordSink, InMemoryExecutionRecordSink,
+};
+pub use governance_middleware::{require_governance_context, GovernanceConfig};
 pub use graphql::{
     build_schema, graphql_handler, graphql_playground, AppSchema, Mutation as GraphQLMutation,
     Query as GraphQLQuery,
 };
 pub use grpc::{build_grpc_server, serve_grpc, RegistryServiceImpl, RegistryServiceServer};
-pub use handlers::{AppState, ExecutionAcceptedResponse, ExecutionRecordRequest, VersionInfo};
+pub use handlers::{
+    AppState, ExecutionAcceptedResponse, ExecutionEventType, ExecutionRecordRequest, VersionInfo,
+};
+pub use idempotency::{
+    IdempotencyKey, IdempotencyStore, IdempotentRegistration, InMemoryIdempotencyStore, Reservation,
+};
 pub use jwt::{Claims, JwtConfig, JwtManager, TokenPair};
+pub use llm_registry_core::TimestampFormat;
+pub use metrics_middleware::{metrics_middleware, ApiMetrics};
 pub use middleware::{CorsConfig, MiddlewareConfig, UuidRequestIdGenerator};
+pub use negotiation::{negotiate_content, ResponseFormat};
 pub use rate_limit::{rate_limit, RateLimitConfig, RateLimiterState};
-pub use execution_middleware::require_execution_context;
 pub use rbac::{Permission, RbacPolicy, Role};
 pub use responses::{
     created, created_with_execution, deleted, deleted_with_execution, no_content, ok,
     ok_with_execution, ApiResponse, ComponentHealth, EmptyResponse, ExecutionEnvelope,
     HealthResponse, HealthStatus, PaginatedExecutionEnvelope, PaginatedResponse, ResponseMeta,
 };
-pub use routes::{build_router, build_router_with_auth, build_router_with_graphql, RouteConfig};
+pub use routes::{
+    build_router, build_router_with_auth, build_router_with_graphql, RouteConfig, ADMIN_ROLE,
+};
+pub use timeout::{enforce_timeout, RequestTimeouts, RouteClass};
+pub use timestamp_middleware::{render_timestamps, HEADER_TIMESTAMP_FORMAT};
 
 use axum::Router;
 use llm_registry_service::ServiceRegistry;
+use std::sync::Arc;
 
 /// Build a complete API server with middleware
 ///
@@ -137,7 +171,25 @@ pub fn build_api_server_with_config(
     services: ServiceRegistry,
     middleware_config: MiddlewareConfig,
 ) -> Router {
-    let state = AppState::new(services);
+    let mut state = AppState::new(services)
+        .with_governance(middleware_config.governance.clone())
+        .with_timestamp_format(middleware_config.timestamp_format)
+        .with_lenient_execution_context(middleware_config.lenient_execution_context)
+        .with_execution_headers(middleware_config.execution_headers.clone())
+        .with_max_body_size(middleware_config.max_body_size)
+        .with_max_page_size(middleware_config.max_page_size)
+        .with_request_timeouts(RequestTimeouts {
+            default: middleware_config
+                .request_timeout_seconds
+                .map(std::time::Duration::from_secs),
+            ..middleware_config.request_timeouts
+        });
+    if let Some(ref path) = middleware_config.execution_records_path {
+        state = state.with_execution_sink(Arc::new(FileExecutionRecordSink::new(path)));
+    }
+    if let Some(ref schema_registry) = middleware_config.schema_registry {
+        state = state.with_schema_registry(schema_registry.clone());
+    }
     let mut router = build_router(state);
 
     // Apply CORS if configured
@@ -1,50 +1,235 @@
 //! Metrics middleware for HTTP request tracking
 //!
 //! This module provides middleware for automatic collection of HTTP request metrics
-//! including request counts, durations, and status codes.
+//! including request counts, durations, and status codes, recorded into the
+//! Prometheus registry held by [`ApiMetrics`].
 
 use axum::{
     body::Body,
-    extract::MatchedPath,
+    extract::{MatchedPath, State},
     http::{Request, Response},
     middleware::Next,
 };
-use std::time::Instant;
+use llm_registry_service::adapters::observatory::RegistryMetrics;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::info;
 
+/// Route label for requests that did not match any route
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Latency buckets in seconds
+const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+/// Prometheus metrics for the API
+///
+/// Each instance owns its own [`Registry`], so separate routers (and tests)
+/// never share counters.
+pub struct ApiMetrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    request_errors: IntCounterVec,
+    request_duration: HistogramVec,
+    registrations: IntCounter,
+    registration_duration: Histogram,
+    searches: IntCounter,
+    search_duration: Histogram,
+}
+
+impl Default for ApiMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApiMetrics {
+    /// Create and register all API metrics
+    pub fn new() -> Self {
+        let registry = Registry::new();
+        let labels = &["method", "route", "status"];
+
+        let info = IntGaugeVec::new(
+            Opts::new("llm_registry_info", "Registry information"),
+            &["version"],
+        )
+        .expect("valid metric");
+        info.with_label_values(&[env!("CARGO_PKG_VERSION")]).set(1);
+
+        let requests = IntCounterVec::new(
+            Opts::new(
+                "llm_registry_http_requests_total",
+                "Total number of HTTP requests",
+            ),
+            labels,
+        )
+        .expect("valid metric");
+        let request_errors = IntCounterVec::new(
+            Opts::new(
+                "llm_registry_http_request_errors_total",
+                "HTTP requests answered with a 4xx or 5xx status",
+            ),
+            labels,
+        )
+        .expect("valid metric");
+        let request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "llm_registry_http_request_duration_seconds",
+                "HTTP request duration in seconds",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+            labels,
+        )
+        .expect("valid metric");
+        let registrations = IntCounter::new(
+            "llm_registry_asset_registrations_total",
+            "Assets registered successfully",
+        )
+        .expect("valid metric");
+        let registration_duration = Histogram::with_opts(
+            HistogramOpts::new(
+                "llm_registry_asset_registration_duration_seconds",
+                "Duration of successful asset registrations in seconds",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+        )
+        .expect("valid metric");
+        let searches = IntCounter::new(
+            "llm_registry_search_queries_total",
+            "Search queries served successfully",
+        )
+        .expect("valid metric");
+        let search_duration = Histogram::with_opts(
+            HistogramOpts::new(
+                "llm_registry_search_duration_seconds",
+                "Duration of successful search queries in seconds",
+            )
+            .buckets(LATENCY_BUCKETS.to_vec()),
+        )
+        .expect("valid metric");
+
+        for collector in [
+            Box::new(info) as Box<dyn prometheus::core::Collector>,
+            Box::new(requests.clone()),
+            Box::new(request_errors.clone()),
+            Box::new(request_duration.clone()),
+            Box::new(registrations.clone()),
+            Box::new(registration_duration.clone()),
+            Box::new(searches.clone()),
+            Box::new(search_duration.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("metric names are unique");
+        }
+
+        Self {
+            registry,
+            requests,
+            request_errors,
+            request_duration,
+            registrations,
+            registration_duration,
+            searches,
+            search_duration,
+        }
+    }
+
+    /// Record a completed HTTP request
+    pub fn record_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let status_label = status.to_string();
+        let labels = [method, route, status_label.as_str()];
+
+        self.requests.with_label_values(&labels).inc();
+        if status >= 400 {
+            self.request_errors.with_label_values(&labels).inc();
+        }
+        self.request_duration
+            .with_label_values(&labels)
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Record a successful asset registration
+    pub fn record_registration(&self, duration: Duration) {
+        self.registrations.inc();
+        self.registration_duration.observe(duration.as_secs_f64());
+    }
+
+    /// Record a successful search query
+    pub fn record_search(&self, duration: Duration) {
+        self.searches.inc();
+        self.search_duration.observe(duration.as_secs_f64());
+    }
+
+    /// Registration and search figures derived from the Prometheus counters
+    ///
+    /// Counts cover the lifetime of this instance; fields that are not
+    /// tracked here are left at their defaults.
+    pub fn registry_metrics(&self) -> RegistryMetrics {
+        RegistryMetrics {
+            assets_registered_hour: self.registrations.get(),
+            avg_registration_latency_ms: average_ms(&self.registration_duration),
+            search_queries_hour: self.searches.get(),
+            avg_search_latency_ms: average_ms(&self.search_duration),
+            ..Default::default()
+        }
+    }
+
+    /// Render the registry in the Prometheus text format
+    pub fn render(&self) -> Result<String, String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .map_err(|e| format!("Failed to encode metrics: {}", e))?;
+
+        String::from_utf8(buffer).map_err(|e| format!("Failed to convert metrics to string: {}", e))
+    }
+}
+
+fn average_ms(histogram: &Histogram) -> u64 {
+    match histogram.get_sample_count() {
+        0 => 0,
+        count => (histogram.get_sample_sum() * 1000.0 / count as f64).round() as u64,
+    }
+}
+
 /// Middleware for collecting HTTP request metrics
 ///
 /// Records:
-/// - Request count by method, path, and status
-/// - Request duration by method and path
-///
-/// Note: Actual metric recording is done by the server binary which has access
-/// to the Prometheus registry. This middleware just logs the information.
+/// - Request count by method, route, and status
+/// - Error count (4xx and 5xx) by method, route, and status
+/// - Request duration by method, route, and status
 pub async fn metrics_middleware(
+    State(metrics): State<Arc<ApiMetrics>>,
     req: Request<Body>,
     next: Next,
 ) -> Response<Body> {
     let start = Instant::now();
     let method = req.method().to_string();
 
-    // Try to get the matched path template (e.g., "/api/v1/assets/:id")
-    // If not available, use the URI path
-    let path = req
+    // Use the matched path template (e.g., "/v1/assets/{id}") so label
+    // cardinality stays bounded
+    let route = req
         .extensions()
         .get::<MatchedPath>()
         .map(|mp| mp.as_str().to_string())
-        .unwrap_or_else(|| req.uri().path().to_string());
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
 
     // Process the request
     let response = next.run(req).await;
 
     let duration = start.elapsed();
     let status = response.status().as_u16();
+    metrics.record_request(&method, &route, status, duration);
 
-    // Log metrics data (can be picked up by telemetry layer)
     info!(
         method = %method,
-        path = %path,
+        path = %route,
         status = status,
         duration_ms = duration.as_millis() as u64,
         "http_request_completed"
@@ -56,10 +241,7 @@ pub async fn metrics_middleware(
 /// Span creation for HTTP requests
 ///
 /// Creates a tracing span for each HTTP request with relevant context
-pub async fn create_request_span(
-    req: Request<Body>,
-    next: Next,
-) -> Response<Body> {
+pub async fn create_request_span(req: Request<Body>, next: Next) -> Response<Body> {
     let method = req.method().to_string();
     let uri = req.uri().to_string();
     let version = format!("{:?}", req.version());
@@ -111,18 +293,28 @@ mod tests {
 
     #[tokio::test]
     async fn test_metrics_middleware() {
-        let app = Router::new()
-            .route("/test", get(test_handler))
-            .layer(middleware::from_fn(metrics_middleware));
+        let metrics = Arc::new(ApiMetrics::new());
+        let app =
+            Router::new()
+                .route("/test", get(test_handler))
+                .layer(middleware::from_fn_with_state(
+                    metrics.clone(),
+                    metrics_middleware,
+                ));
 
-        let request = Request::builder()
-            .uri("/test")
-            .body(Body::empty())
-            .unwrap();
+        for uri in ["/test", "/test", "/missing"] {
+            let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
 
-        let response = app.oneshot(request).await.unwrap();
-
-        assert_eq!(response.status(), StatusCode::OK);
+        let rendered = metrics.render().unwrap();
+        assert!(rendered.contains(
+            r#"llm_registry_http_requests_total{method="GET",route="/test",status="200"} 2"#
+        ));
+        assert!(rendered.contains(
+            r#"llm_registry_http_request_errors_total{method="GET",route="unmatched",status="404"} 1"#
+        ));
+        assert!(!rendered.contains("/missing"));
     }
 
     #[tokio::test]
@@ -131,10 +323,7 @@ mod tests {
             .route("/test", get(test_handler))
             .layer(middleware::from_fn(create_request_span));
 
-        let request = Request::builder()
-            .uri("/test")
-            .body(Body::empty())
-            .unwrap();
+        let request = Request::builder().uri("/test").body(Body::empty()).unwrap();
 
         let response = app.oneshot(request).await.unwrap();
 
@@ -3,9 +3,12 @@
 //! This module provides middleware layers for request processing including
 //! logging, CORS, compression, and request ID generation.
 
-use axum::http::{HeaderValue, Method, Request};
+use axum::http::{HeaderName, HeaderValue, Method, Request};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 use tower_http::{
-    cors::{Any, CorsLayer},
+    cors::{AllowHeaders, AllowOrigin, CorsLayer},
     request_id::{MakeRequestId, RequestId},
     trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer},
     LatencyUnit,
@@ -13,6 +16,13 @@ use tower_http::{
 use tracing::Level;
 use uuid::Uuid;
 
+use crate::body_limit::default_max_body_size;
+use crate::execution_middleware::ExecutionHeaderNames;
+use crate::governance_middleware::GovernanceConfig;
+use crate::timeout::{RequestTimeouts, RouteClass};
+use llm_registry_core::TimestampFormat;
+use llm_registry_service::SchemaRegistryAdapter;
+
 /// Request ID generator using UUIDs
 #[derive(Clone, Default)]
 pub struct UuidRequestIdGenerator;
@@ -20,14 +30,14 @@ pub struct UuidRequestIdGenerator;
 impl MakeRequestId for UuidRequestIdGenerator {
     fn make_request_id<B>(&mut self, _request: &Request<B>) -> Option<RequestId> {
         let request_id = Uuid::new_v4().to_string();
-        Some(RequestId::new(
-            HeaderValue::from_str(&request_id).unwrap(),
-        ))
+        Some(RequestId::new(HeaderValue::from_str(&request_id).unwrap()))
     }
 }
 
 /// Build trace layer
-pub fn trace_layer() -> TraceLayer<tower_http::classify::SharedClassifier<tower_http::classify::ServerErrorsAsFailures>> {
+pub fn trace_layer(
+) -> TraceLayer<tower_http::classify::SharedClassifier<tower_http::classify::ServerErrorsAsFailures>>
+{
     TraceLayer::new_for_http()
         .make_span_with(
             DefaultMakeSpan::new()
@@ -42,38 +52,36 @@ pub fn trace_layer() -> TraceLayer<tower_http::classify::SharedClassifier<tower_
         )
 }
 
-/// Build CORS layer
+/// Build CORS layer allowing any origin
 pub fn cors_layer() -> CorsLayer {
-    CorsLayer::new()
-        // Allow requests from any origin
-        // In production, configure this based on environment
-        .allow_origin(Any)
-        // Allow common HTTP methods
-        .allow_methods([
-            Method::GET,
-            Method::POST,
-            Method::PUT,
-            Method::PATCH,
-            Method::DELETE,
-            Method::OPTIONS,
-        ])
-        // Allow common headers
-        .allow_headers(Any)
-        // Expose request ID header
-        .expose_headers([
-            axum::http::header::CONTENT_TYPE,
-            axum::http::header::HeaderName::from_static("x-request-id"),
-        ])
-        // Allow credentials
-        .allow_credentials(false)
+    CorsConfig::default().into_layer()
 }
 
+/// Methods allowed when no `allowed_methods` are configured
+const DEFAULT_CORS_METHODS: [Method; 6] = [
+    Method::GET,
+    Method::POST,
+    Method::PUT,
+    Method::PATCH,
+    Method::DELETE,
+    Method::OPTIONS,
+];
+
 /// CORS configuration options
 #[derive(Debug, Clone)]
 pub struct CorsConfig {
     /// Allowed origins (empty means any)
+    ///
+    /// Each entry is an exact origin (`https://admin.example.com`), `*` for
+    /// any origin, or a subdomain wildcard (`https://*.example.com`).
     pub allowed_origins: Vec<String>,
 
+    /// Allowed request methods (empty means GET, POST, PUT, PATCH, DELETE and OPTIONS)
+    pub allowed_methods: Vec<String>,
+
+    /// Allowed request headers (empty means any)
+    pub allowed_headers: Vec<String>,
+
     /// Whether to allow credentials
     pub allow_credentials: bool,
 
@@ -85,6 +93,8 @@ impl Default for CorsConfig {
     fn default() -> Self {
         Self {
             allowed_origins: vec![],
+            allowed_methods: vec![],
+            allowed_headers: vec![],
             allow_credentials: false,
             max_age_seconds: Some(3600),
         }
@@ -92,36 +102,87 @@ impl Default for CorsConfig {
 }
 
 impl CorsConfig {
+    /// Whether `origin` matches one of the allowed origins
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allowed_origins.is_empty()
+            || self
+                .allowed_origins
+                .iter()
+                .any(|pattern| origin_matches(pattern, origin))
+    }
+
+    /// Whether any origin is allowed
+    fn allows_any_origin(&self) -> bool {
+        self.allowed_origins.is_empty() || self.allowed_origins.iter().any(|o| o == "*")
+    }
+
+    /// Check that the configuration is safe to serve
+    ///
+    /// Credentials may only be allowed for an explicit list of origins;
+    /// otherwise every site could make authenticated cross-origin requests.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.allow_credentials && self.allows_any_origin() {
+            return Err(
+                "cors.allow_credentials requires an explicit cors.allowed_origins list".to_string(),
+            );
+        }
+        Ok(())
+    }
+
     /// Build CORS layer from config
+    ///
+    /// Browsers reject wildcard headers on credentialed requests, so with
+    /// credentials enabled the request's own headers are echoed back. A
+    /// configuration that fails [`CorsConfig::validate`] never allows
+    /// credentials.
     pub fn into_layer(self) -> CorsLayer {
+        let allow_credentials = match self.validate() {
+            Ok(()) => self.allow_credentials,
+            Err(reason) => {
+                tracing::warn!("{}; not allowing credentials", reason);
+                false
+            }
+        };
+
+        let allow_origin = if self.allows_any_origin() {
+            AllowOrigin::any()
+        } else {
+            let config = self.clone();
+            AllowOrigin::predicate(move |origin, _| {
+                origin.to_str().is_ok_and(|o| config.allows_origin(o))
+            })
+        };
+
+        let methods: Vec<Method> = if self.allowed_methods.is_empty() {
+            DEFAULT_CORS_METHODS.to_vec()
+        } else {
+            self.allowed_methods
+                .iter()
+                .filter_map(|m| Method::from_bytes(m.trim().to_ascii_uppercase().as_bytes()).ok())
+                .collect()
+        };
+
+        let allow_headers = if !self.allowed_headers.is_empty() {
+            AllowHeaders::list(
+                self.allowed_headers
+                    .iter()
+                    .filter_map(|h| HeaderName::from_bytes(h.trim().as_bytes()).ok()),
+            )
+        } else if allow_credentials {
+            AllowHeaders::mirror_request()
+        } else {
+            AllowHeaders::any()
+        };
+
         let mut layer = CorsLayer::new()
-            .allow_methods([
-                Method::GET,
-                Method::POST,
-                Method::PUT,
-                Method::PATCH,
-                Method::DELETE,
-                Method::OPTIONS,
-            ])
-            .allow_headers(Any)
+            .allow_origin(allow_origin)
+            .allow_methods(methods)
+            .allow_headers(allow_headers)
             .expose_headers([
                 axum::http::header::CONTENT_TYPE,
                 axum::http::header::HeaderName::from_static("x-request-id"),
             ])
-            .allow_credentials(self.allow_credentials);
-
-        // Configure origins
-        if self.allowed_origins.is_empty() {
-            layer = layer.allow_origin(Any);
-        } else {
-            // Parse origins
-            let origins: Vec<HeaderValue> = self
-                .allowed_origins
-                .iter()
-                .filter_map(|o| o.parse().ok())
-                .collect();
-            layer = layer.allow_origin(origins);
-        }
+            .allow_credentials(allow_credentials);
 
         // Configure max age
         if let Some(max_age) = self.max_age_seconds {
@@ -132,6 +193,26 @@ impl CorsConfig {
     }
 }
 
+/// Whether `origin` matches one allowed-origin entry
+///
+/// Scheme and host are case-insensitive, so both exact and wildcard entries
+/// compare lowercased values.
+fn origin_matches(pattern: &str, origin: &str) -> bool {
+    if pattern == "*" {
+        return true;
+    }
+    let pattern = pattern.to_ascii_lowercase();
+    let origin = origin.to_ascii_lowercase();
+    match pattern.split_once("://*.") {
+        Some((scheme, domain)) => origin
+            .strip_prefix(scheme)
+            .and_then(|rest| rest.strip_prefix("://"))
+            .and_then(|host| host.strip_suffix(domain))
+            .is_some_and(|subdomain| subdomain.len() > 1 && subdomain.ends_with('.')),
+        None => pattern == origin,
+    }
+}
+
 /// Middleware configuration
 #[derive(Debug, Clone)]
 pub struct MiddlewareConfig {
@@ -146,6 +227,33 @@ pub struct MiddlewareConfig {
 
     /// Request timeout in seconds
     pub request_timeout_seconds: Option<u64>,
+
+    /// Governance context requirements
+    pub governance: GovernanceConfig,
+
+    /// Wire format for response timestamps
+    pub timestamp_format: TimestampFormat,
+
+    /// Synthesize a missing execution context instead of rejecting `/v1` requests
+    pub lenient_execution_context: bool,
+
+    /// Headers the execution context is read from
+    pub execution_headers: ExecutionHeaderNames,
+
+    /// Largest accepted body, in bytes, on routes carrying asset metadata
+    pub max_body_size: usize,
+
+    /// Per-class overrides of `request_timeout_seconds`
+    pub request_timeouts: RequestTimeouts,
+
+    /// Largest page size served by asset search; larger limits are clamped
+    pub max_page_size: i64,
+
+    /// File accepted execution records are appended to; kept in memory if unset
+    pub execution_records_path: Option<PathBuf>,
+
+    /// Schema registry registrations are validated against; unchecked if unset
+    pub schema_registry: Option<Arc<SchemaRegistryAdapter>>,
 }
 
 impl Default for MiddlewareConfig {
@@ -155,6 +263,15 @@ impl Default for MiddlewareConfig {
             enable_compression: true,
             enable_tracing: true,
             request_timeout_seconds: Some(30),
+            governance: GovernanceConfig::default(),
+            timestamp_format: TimestampFormat::default(),
+            lenient_execution_context: false,
+            execution_headers: ExecutionHeaderNames::default(),
+            max_body_size: default_max_body_size(),
+            request_timeouts: RequestTimeouts::default(),
+            max_page_size: llm_registry_service::MAX_PAGE_SIZE,
+            execution_records_path: None,
+            schema_registry: None,
         }
     }
 }
@@ -188,6 +305,60 @@ impl MiddlewareConfig {
         self.request_timeout_seconds = Some(timeout_seconds);
         self
     }
+
+    /// Set governance context requirements
+    pub fn with_governance(mut self, governance: GovernanceConfig) -> Self {
+        self.governance = governance;
+        self
+    }
+
+    /// Set the wire format for response timestamps
+    pub fn with_timestamp_format(mut self, format: TimestampFormat) -> Self {
+        self.timestamp_format = format;
+        self
+    }
+
+    /// Accept `/v1` requests without an execution context
+    pub fn with_lenient_execution_context(mut self, lenient: bool) -> Self {
+        self.lenient_execution_context = lenient;
+        self
+    }
+
+    /// Read the execution context from custom header names
+    pub fn with_execution_headers(mut self, names: ExecutionHeaderNames) -> Self {
+        self.execution_headers = names;
+        self
+    }
+
+    /// Set the largest accepted body on routes carrying asset metadata
+    pub fn with_max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = bytes;
+        self
+    }
+
+    /// Set the largest page size served by asset search
+    pub fn with_max_page_size(mut self, max: i64) -> Self {
+        self.max_page_size = max;
+        self
+    }
+
+    /// Persist accepted execution records to a JSON Lines file
+    pub fn with_execution_records_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.execution_records_path = Some(path.into());
+        self
+    }
+
+    /// Validate model and pipeline registrations against a schema registry
+    pub fn with_schema_registry(mut self, schema_registry: Arc<SchemaRegistryAdapter>) -> Self {
+        self.schema_registry = Some(schema_registry);
+        self
+    }
+
+    /// Override the request timeout of one route class
+    pub fn with_route_timeout(mut self, class: RouteClass, timeout: Duration) -> Self {
+        self.request_timeouts = self.request_timeouts.with_class(class, timeout);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -213,6 +384,130 @@ mod tests {
         assert_eq!(config.max_age_seconds, Some(3600));
     }
 
+    #[test]
+    fn test_cors_origin_rules() {
+        let config = CorsConfig {
+            allowed_origins: vec![
+                "https://admin.example.com".to_string(),
+                "https://*.internal.example.com".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert!(config.allows_origin("https://admin.example.com"));
+        assert!(config.allows_origin("https://ops.internal.example.com"));
+        assert!(!config.allows_origin("https://internal.example.com"));
+        assert!(!config.allows_origin("https://evilinternal.example.com"));
+        assert!(!config.allows_origin("http://ops.internal.example.com"));
+        assert!(!config.allows_origin("https://other.example.com"));
+        assert!(CorsConfig::default().allows_origin("https://anywhere.test"));
+    }
+
+    #[test]
+    fn test_cors_origin_rules_ignore_case() {
+        let config = CorsConfig {
+            allowed_origins: vec![
+                "https://Admin.Example.com".to_string(),
+                "HTTPS://*.Internal.Example.com".to_string(),
+            ],
+            ..Default::default()
+        };
+        assert!(config.allows_origin("https://admin.example.com"));
+        assert!(config.allows_origin("https://OPS.internal.example.COM"));
+        assert!(!config.allows_origin("https://internal.example.com"));
+    }
+
+    async fn preflight(cors: CorsConfig, origin: &str) -> axum::response::Response {
+        use tower::ServiceExt;
+
+        let (state, _, _) = crate::test_support::app_state();
+        let request = Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/v1/assets")
+            .header("origin", origin)
+            .header("access-control-request-method", "POST")
+            .header(
+                "access-control-request-headers",
+                "content-type,x-execution-id",
+            )
+            .body(axum::body::Body::empty())
+            .unwrap();
+        crate::routes::build_router(state)
+            .layer(cors.into_layer())
+            .oneshot(request)
+            .await
+            .unwrap()
+    }
+
+    fn admin_ui_cors() -> CorsConfig {
+        CorsConfig {
+            allowed_origins: vec!["https://admin.example.com".to_string()],
+            allowed_methods: vec!["get".to_string(), "post".to_string()],
+            allowed_headers: vec!["Content-Type".to_string(), "X-Execution-Id".to_string()],
+            allow_credentials: true,
+            max_age_seconds: Some(600),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cors_preflight_allowed_origin() {
+        let response = preflight(admin_ui_cors(), "https://admin.example.com").await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let headers = response.headers();
+        assert_eq!(
+            headers["access-control-allow-origin"],
+            "https://admin.example.com"
+        );
+        assert_eq!(headers["access-control-allow-credentials"], "true");
+        assert_eq!(headers["access-control-allow-methods"], "GET,POST");
+        assert_eq!(
+            headers["access-control-allow-headers"],
+            "content-type,x-execution-id"
+        );
+        assert_eq!(headers["access-control-max-age"], "600");
+    }
+
+    #[tokio::test]
+    async fn test_cors_preflight_disallowed_origin() {
+        let response = preflight(admin_ui_cors(), "https://evil.example.com").await;
+        assert!(!response
+            .headers()
+            .contains_key("access-control-allow-origin"));
+    }
+
+    #[test]
+    fn test_cors_credentials_require_explicit_origins() {
+        let any_origin = CorsConfig {
+            allow_credentials: true,
+            ..Default::default()
+        };
+        assert!(any_origin.validate().is_err());
+
+        let wildcard = CorsConfig {
+            allowed_origins: vec!["*".to_string()],
+            allow_credentials: true,
+            ..Default::default()
+        };
+        assert!(wildcard.validate().is_err());
+
+        assert!(admin_ui_cors().validate().is_ok());
+        assert!(CorsConfig::default().validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_cors_credentials_with_any_origin_are_not_allowed() {
+        let cors = CorsConfig {
+            allow_credentials: true,
+            ..Default::default()
+        };
+        let response = preflight(cors, "http://localhost:5173").await;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert_eq!(response.headers()["access-control-allow-origin"], "*");
+        assert!(!response
+            .headers()
+            .contains_key("access-control-allow-credentials"));
+    }
+
     #[test]
     fn test_middleware_config_default() {
         let config = MiddlewareConfig::default();
@@ -0,0 +1,305 @@
+//! Response content negotiation
+//!
+//! Handlers always build JSON envelopes. For `/v1` requests whose `Accept`
+//! header prefers MessagePack or CBOR, the [`negotiate_content`] middleware
+//! re-encodes JSON response bodies in that format, so every
+//! [`ExecutionEnvelope`](crate::responses::ExecutionEnvelope) and error body
+//! is covered without handler changes. A missing header, `*/*` and
+//! `application/*` select JSON; an `Accept` header naming none of the
+//! supported types is answered with `406 Not Acceptable` before the handler
+//! runs. The export dump and DOT graph routes are left alone.
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use tracing::warn;
+
+use crate::error::ErrorResponse;
+
+/// Wire format of a `/v1` response body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// `application/json`
+    Json,
+    /// `application/msgpack`
+    MsgPack,
+    /// `application/cbor`
+    Cbor,
+}
+
+impl ResponseFormat {
+    /// Every supported format, the default first
+    pub const ALL: [Self; 3] = [Self::Json, Self::MsgPack, Self::Cbor];
+
+    /// Media types this format is served for
+    fn media_types(self) -> &'static [&'static str] {
+        match self {
+            Self::Json => &["application/json"],
+            Self::MsgPack => &[
+                "application/msgpack",
+                "application/x-msgpack",
+                "application/vnd.msgpack",
+            ],
+            Self::Cbor => &["application/cbor"],
+        }
+    }
+
+    /// `Content-Type` of responses in this format
+    pub fn content_type(self) -> &'static str {
+        self.media_types()[0]
+    }
+
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        if media_type == "*/*" || media_type == "application/*" {
+            return Some(Self::Json);
+        }
+        Self::ALL
+            .into_iter()
+            .find(|format| format.media_types().contains(&media_type))
+    }
+
+    /// Pick the format from an `Accept` header
+    ///
+    /// The highest `q` wins; ties go to the type listed first. Returns
+    /// `None` when no listed type with a non-zero `q` is supported.
+    pub fn negotiate(headers: &HeaderMap) -> Option<Self> {
+        let Some(accept) = headers.get(header::ACCEPT) else {
+            return Some(Self::Json);
+        };
+        let accept = accept.to_str().ok()?;
+        if accept.trim().is_empty() {
+            return Some(Self::Json);
+        }
+
+        let mut best: Option<(Self, f32)> = None;
+        for range in accept.split(',') {
+            let mut params = range.split(';');
+            let media_type = params
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase();
+            let quality = params
+                .filter_map(|param| param.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            let Some(format) = Self::from_media_type(&media_type) else {
+                continue;
+            };
+            let better = match best {
+                Some((_, q)) => quality > q,
+                None => true,
+            };
+            if quality > 0.0 && better {
+                best = Some((format, quality));
+            }
+        }
+        best.map(|(format, _)| format)
+    }
+
+    /// Re-encode a JSON document in this format
+    fn encode(self, value: &serde_json::Value) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Json => serde_json::to_vec(value).map_err(|e| e.to_string()),
+            Self::MsgPack => rmp_serde::to_vec_named(value).map_err(|e| e.to_string()),
+            Self::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::into_writer(value, &mut bytes).map_err(|e| e.to_string())?;
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+/// Middleware that serves `/v1` JSON responses in the format the client accepts
+pub async fn negotiate_content(request: Request, next: Next) -> Result<Response, Response> {
+    if !is_negotiated(request.uri().path()) {
+        return Ok(next.run(request).await);
+    }
+    let format =
+        ResponseFormat::negotiate(request.headers()).ok_or_else(not_acceptable_response)?;
+
+    let mut response = next.run(request).await;
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept"));
+    if format == ResponseFormat::Json || !is_json(&response) {
+        return Ok(response);
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!(error = %e, "Failed to buffer response body for content negotiation");
+            return Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+        }
+    };
+
+    let encoded = serde_json::from_slice::<serde_json::Value>(&bytes)
+        .map_err(|e| e.to_string())
+        .and_then(|value| format.encode(&value));
+    let body = match encoded {
+        Ok(encoded) => {
+            parts.headers.remove(header::CONTENT_LENGTH);
+            parts.headers.insert(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(format.content_type()),
+            );
+            Body::from(encoded)
+        }
+        Err(e) => {
+            warn!(error = %e, format = ?format, "Failed to re-encode response body");
+            Body::from(bytes)
+        }
+    };
+
+    Ok(Response::from_parts(parts, body))
+}
+
+/// `/v1` routes other than the export dump and DOT graphs, which have their own media types
+fn is_negotiated(path: &str) -> bool {
+    path.starts_with("/v1/") && !path.ends_with(":export") && !path.ends_with(".dot")
+}
+
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"))
+}
+
+fn not_acceptable_response() -> Response {
+    let body = ErrorResponse {
+        status: 406,
+        error: "None of the media types in Accept are supported; use application/json, \
+                application/msgpack or application/cbor"
+            .to_string(),
+        code: Some("NOT_ACCEPTABLE".to_string()),
+        details: None,
+        timestamp: chrono::Utc::now(),
+        execution: None,
+    };
+    (StatusCode::NOT_ACCEPTABLE, Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+    use crate::test_support;
+    use llm_registry_core::execution::SpanId;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn negotiate(accept: &str) -> Option<ResponseFormat> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::ACCEPT, HeaderValue::from_str(accept).unwrap());
+        ResponseFormat::negotiate(&headers)
+    }
+
+    #[test]
+    fn test_accept_header_selection() {
+        assert_eq!(
+            ResponseFormat::negotiate(&HeaderMap::new()),
+            Some(ResponseFormat::Json)
+        );
+        assert_eq!(negotiate("*/*"), Some(ResponseFormat::Json));
+        assert_eq!(
+            negotiate("application/x-msgpack"),
+            Some(ResponseFormat::MsgPack)
+        );
+        assert_eq!(
+            negotiate("application/json;q=0.5, application/cbor"),
+            Some(ResponseFormat::Cbor)
+        );
+        assert_eq!(
+            negotiate("text/html, application/msgpack;q=0.8, */*;q=0.1"),
+            Some(ResponseFormat::MsgPack)
+        );
+        assert_eq!(negotiate("text/html"), None);
+        assert_eq!(negotiate("application/cbor;q=0"), None);
+
+        assert!(is_negotiated("/v1/assets/abc"));
+        assert!(!is_negotiated("/v1/assets/abc/dependencies.dot"));
+        assert!(!is_negotiated("/health"));
+    }
+
+    /// GET /v1/assets/{id} with the given `Accept` header
+    async fn get_asset(accept: &str) -> (StatusCode, Option<String>, Vec<u8>, String) {
+        let (state, repository, _) = test_support::app_state();
+        let asset = test_support::asset("model", "1.0.0");
+        repository.assets.lock().unwrap().push(asset.clone());
+        let app = crate::routes::build_router(state);
+
+        let request = axum::http::Request::builder()
+            .uri(format!("/v1/assets/{}", asset.id))
+            .header(header::ACCEPT, accept)
+            .header(HEADER_EXECUTION_ID, uuid::Uuid::new_v4().to_string())
+            .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .map(|v| v.to_str().unwrap().to_string());
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, content_type, bytes.to_vec(), asset.id.to_string())
+    }
+
+    fn assert_envelope(body: &Value, asset_id: &str) {
+        assert_eq!(body["data"]["id"], asset_id);
+        assert_eq!(body["data"]["metadata"]["version"], "1.0.0");
+        assert_eq!(body["execution"]["spans"][0]["span_type"], "repo");
+        assert!(body["execution"]["spans"][0]["started_at"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_get_asset_as_json() {
+        for accept in ["application/json", "*/*"] {
+            let (status, content_type, bytes, id) = get_asset(accept).await;
+            assert_eq!(status, StatusCode::OK);
+            assert_eq!(content_type.as_deref(), Some("application/json"));
+            assert_envelope(&serde_json::from_slice(&bytes).unwrap(), &id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_asset_as_msgpack() {
+        let (status, content_type, bytes, id) = get_asset("application/msgpack").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type.as_deref(), Some("application/msgpack"));
+        assert_envelope(&rmp_serde::from_slice::<Value>(&bytes).unwrap(), &id);
+    }
+
+    #[tokio::test]
+    async fn test_get_asset_as_cbor() {
+        let (status, content_type, bytes, id) = get_asset("application/cbor").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type.as_deref(), Some("application/cbor"));
+        assert_envelope(
+            &ciborium::from_reader::<Value, _>(bytes.as_slice()).unwrap(),
+            &id,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_accept_is_not_acceptable() {
+        let (status, _, bytes, _) = get_asset("text/html").await;
+        assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+        let body: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "NOT_ACCEPTABLE");
+    }
+}
@@ -0,0 +1,200 @@
+//! Response field projection
+//!
+//! `GET /v1/assets/{id}` and `GET /v1/assets` accept a comma-separated
+//! `fields` query parameter naming the asset fields to return, for example
+//! `fields=id,metadata.name,metadata.version`. Top-level fields are named
+//! directly; fields of `metadata` use a `metadata.` prefix. Projection is
+//! applied while serializing, so handlers and services keep working with
+//! whole assets.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeSet;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Top-level asset fields that may be projected
+pub const ASSET_FIELDS: &[&str] = &[
+    "id",
+    "asset_type",
+    "metadata",
+    "status",
+    "storage",
+    "checksum",
+    "provenance",
+    "dependencies",
+    "created_at",
+    "updated_at",
+    "deprecated_at",
+    "deleted_at",
+];
+
+/// Asset metadata fields that may be projected as `metadata.<field>`
+pub const ASSET_METADATA_FIELDS: &[&str] = &[
+    "name",
+    "version",
+    "description",
+    "license",
+    "tags",
+    "annotations",
+    "labels",
+    "size_bytes",
+    "content_type",
+];
+
+/// Query parameters selecting the fields to return
+#[derive(Debug, Default, Deserialize)]
+pub struct FieldsParams {
+    /// Comma-separated field names; all fields when absent
+    pub fields: Option<String>,
+}
+
+impl FieldsParams {
+    /// Parse the requested projection, `None` when no fields were given
+    pub fn projection(&self) -> Result<Option<Arc<FieldProjection>>, String> {
+        self.fields
+            .as_deref()
+            .map(|fields| FieldProjection::parse(fields).map(Arc::new))
+            .transpose()
+    }
+}
+
+/// Set of asset fields to keep when serializing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldProjection {
+    fields: BTreeSet<String>,
+    metadata_fields: BTreeSet<String>,
+}
+
+impl FieldProjection {
+    /// Parse a comma-separated field list, rejecting unknown names
+    pub fn parse(fields: &str) -> Result<Self, String> {
+        let mut projection = Self {
+            fields: BTreeSet::new(),
+            metadata_fields: BTreeSet::new(),
+        };
+
+        for field in fields.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            match field.strip_prefix("metadata.") {
+                Some(sub) if ASSET_METADATA_FIELDS.contains(&sub) => {
+                    projection.metadata_fields.insert(sub.to_string());
+                }
+                None if ASSET_FIELDS.contains(&field) => {
+                    projection.fields.insert(field.to_string());
+                }
+                _ => return Err(format!("Unknown field: {}", field)),
+            }
+        }
+
+        if projection.fields.is_empty() && projection.metadata_fields.is_empty() {
+            return Err("fields must name at least one field".to_string());
+        }
+        Ok(projection)
+    }
+
+    /// Keep only the projected fields of a serialized asset
+    pub fn apply(&self, value: serde_json::Value) -> serde_json::Value {
+        let serde_json::Value::Object(mut object) = value else {
+            return value;
+        };
+
+        // A whole `metadata` request wins over individual metadata fields
+        let metadata = match object.remove("metadata") {
+            Some(metadata) if self.fields.contains("metadata") => Some(metadata),
+            Some(serde_json::Value::Object(mut metadata)) if !self.metadata_fields.is_empty() => {
+                metadata.retain(|key, _| self.metadata_fields.contains(key));
+                Some(serde_json::Value::Object(metadata))
+            }
+            _ => None,
+        };
+
+        object.retain(|key, _| self.fields.contains(key));
+        if let Some(metadata) = metadata {
+            object.insert("metadata".to_string(), metadata);
+        }
+        serde_json::Value::Object(object)
+    }
+}
+
+/// A value serialized through an optional [`FieldProjection`]
+///
+/// Dereferences to the wrapped value. Deserializing yields an unprojected
+/// wrapper.
+#[derive(Debug, Clone)]
+pub struct Projected<T> {
+    value: T,
+    projection: Option<Arc<FieldProjection>>,
+}
+
+impl<T> Projected<T> {
+    /// Wrap `value`, projecting it on serialization when `projection` is set
+    pub fn new(value: T, projection: Option<Arc<FieldProjection>>) -> Self {
+        Self { value, projection }
+    }
+
+    /// The wrapped value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Projected<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Serialize> Serialize for Projected<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.projection {
+            Some(ref projection) => {
+                let value = serde_json::to_value(&self.value).map_err(serde::ser::Error::custom)?;
+                projection.apply(value).serialize(serializer)
+            }
+            None => self.value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Projected<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(|value| Self::new(value, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    #[test]
+    fn test_known_fields_cover_serialized_asset() {
+        let mut asset = test_support::asset("model", "1.0.0");
+        asset.metadata.description = Some("desc".to_string());
+        asset
+            .metadata
+            .labels
+            .insert("tier".to_string(), "gold".to_string());
+        asset.deprecated_at = Some(chrono::Utc::now());
+        asset.deleted_at = Some(chrono::Utc::now());
+        let value = serde_json::to_value(&asset).unwrap();
+
+        for key in value.as_object().unwrap().keys() {
+            assert!(ASSET_FIELDS.contains(&key.as_str()), "{}", key);
+        }
+        for key in value["metadata"].as_object().unwrap().keys() {
+            assert!(ASSET_METADATA_FIELDS.contains(&key.as_str()), "{}", key);
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_fields() {
+        assert_eq!(
+            FieldProjection::parse("id,colour").unwrap_err(),
+            "Unknown field: colour"
+        );
+        assert!(FieldProjection::parse("metadata.colour").is_err());
+        assert!(FieldProjection::parse(" , ").is_err());
+    }
+}
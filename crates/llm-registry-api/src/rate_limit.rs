@@ -290,11 +290,9 @@ fn extract_identifier(request: &Request<Body>, config: &RateLimitConfig) -> Stri
 async fn check_rate_limit(limiter: &RateLimiterState, identifier: &str) -> bool {
     let mut storage = limiter.storage.write().await;
 
-    let bucket = storage
-        .entry(identifier.to_string())
-        .or_insert_with(|| {
-            TokenBucket::new(limiter.config.max_requests, limiter.config.window_secs)
-        });
+    let bucket = storage.entry(identifier.to_string()).or_insert_with(|| {
+        TokenBucket::new(limiter.config.max_requests, limiter.config.window_secs)
+    });
 
     bucket.try_consume(1.0)
 }
@@ -331,12 +329,13 @@ impl IntoResponse for RateLimitError {
                     status: 429,
                     error: "Rate limit exceeded".to_string(),
                     code: Some("RATE_LIMIT_EXCEEDED".to_string()),
+                    details: None,
                     timestamp: chrono::Utc::now(),
                     execution: None,
                 };
 
-                let mut response = (StatusCode::TOO_MANY_REQUESTS, axum::Json(error_response))
-                    .into_response();
+                let mut response =
+                    (StatusCode::TOO_MANY_REQUESTS, axum::Json(error_response)).into_response();
 
                 // Add Retry-After header
                 response.headers_mut().insert(
@@ -354,7 +353,11 @@ impl std::fmt::Display for RateLimitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             RateLimitError::LimitExceeded { retry_after } => {
-                write!(f, "Rate limit exceeded. Retry after {} seconds", retry_after)
+                write!(
+                    f,
+                    "Rate limit exceeded. Retry after {} seconds",
+                    retry_after
+                )
             }
         }
     }
@@ -385,10 +388,7 @@ mod tests {
         assert_eq!(config.max_requests, 200);
         assert_eq!(config.window_secs, 120);
         assert!(!config.by_ip);
-        assert_eq!(
-            config.identifier_header,
-            Some("X-API-Key".to_string())
-        );
+        assert_eq!(config.identifier_header, Some("X-API-Key".to_string()));
     }
 
     #[test]
@@ -260,25 +260,13 @@ impl RbacPolicy {
     }
 
     /// Check if a set of roles has ANY of the specified permissions
-    pub fn has_any_permission(
-        &mut self,
-        roles: &[String],
-        permissions: &[Permission],
-    ) -> bool {
-        permissions
-            .iter()
-            .any(|p| self.has_permission(roles, p))
+    pub fn has_any_permission(&mut self, roles: &[String], permissions: &[Permission]) -> bool {
+        permissions.iter().any(|p| self.has_permission(roles, p))
     }
 
     /// Check if a set of roles has ALL of the specified permissions
-    pub fn has_all_permissions(
-        &mut self,
-        roles: &[String],
-        permissions: &[Permission],
-    ) -> bool {
-        permissions
-            .iter()
-            .all(|p| self.has_permission(roles, p))
+    pub fn has_all_permissions(&mut self, roles: &[String], permissions: &[Permission]) -> bool {
+        permissions.iter().all(|p| self.has_permission(roles, p))
     }
 }
 
@@ -380,14 +368,8 @@ mod tests {
         let moderator_roles = vec!["moderator".to_string()];
 
         // Should have permissions from both moderator and viewer
-        assert!(policy.has_permission(
-            &moderator_roles,
-            &Permission::new("asset", "read")
-        ));
-        assert!(policy.has_permission(
-            &moderator_roles,
-            &Permission::new("asset", "delete")
-        ));
+        assert!(policy.has_permission(&moderator_roles, &Permission::new("asset", "read")));
+        assert!(policy.has_permission(&moderator_roles, &Permission::new("asset", "delete")));
     }
 
     #[test]
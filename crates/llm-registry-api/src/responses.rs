@@ -9,6 +9,7 @@ use axum::{
     Json,
 };
 use llm_registry_core::execution::ExecutionResult;
+use llm_registry_service::Facets;
 use serde::{Deserialize, Serialize};
 
 /// Standard success response wrapper
@@ -208,6 +209,10 @@ pub struct ComponentHealth {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 
+    /// Time taken by the check, in milliseconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+
     /// Optional metrics
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<std::collections::HashMap<String, serde_json::Value>>,
@@ -274,6 +279,7 @@ impl ComponentHealth {
         Self {
             status: HealthStatus::Healthy,
             message: None,
+            latency_ms: None,
             metrics: None,
         }
     }
@@ -283,6 +289,7 @@ impl ComponentHealth {
         Self {
             status: HealthStatus::Degraded,
             message: Some(message.into()),
+            latency_ms: None,
             metrics: None,
         }
     }
@@ -292,10 +299,17 @@ impl ComponentHealth {
         Self {
             status: HealthStatus::Unhealthy,
             message: Some(message.into()),
+            latency_ms: None,
             metrics: None,
         }
     }
 
+    /// Record how long the check took
+    pub fn with_latency(mut self, latency: std::time::Duration) -> Self {
+        self.latency_ms = Some(latency.as_millis() as u64);
+        self
+    }
+
     /// Add metrics
     pub fn with_metrics(
         mut self,
@@ -377,6 +391,9 @@ pub struct PaginatedExecutionEnvelope<T> {
     pub items: Vec<T>,
     /// Pagination metadata.
     pub pagination: PaginationMeta,
+    /// Facet counts over the full filtered set, when requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Facets>,
     /// Execution trace.
     pub execution: ExecutionResult,
 }
@@ -400,7 +417,10 @@ pub fn created_with_execution<T: Serialize>(
     data: T,
     execution: ExecutionResult,
 ) -> (StatusCode, Json<ExecutionEnvelope<T>>) {
-    (StatusCode::CREATED, Json(ExecutionEnvelope::new(data, execution)))
+    (
+        StatusCode::CREATED,
+        Json(ExecutionEnvelope::new(data, execution)),
+    )
 }
 
 /// Helper: deleted response with execution spans.
@@ -4,33 +4,64 @@
 
 use axum::{
     middleware,
-    routing::{delete, get, patch, post},
+    routing::{delete, get, patch, post, put},
     Router,
 };
 
 use crate::{
-    auth::{optional_auth, require_auth, AuthState},
+    auth::{optional_auth, require_auth, require_role, AuthState},
     auth_handlers::{generate_api_key, login, logout, me, refresh_token, AuthHandlerState},
-    execution_middleware::require_execution_context,
+    body_limit::limit_body_size,
+    execution_middleware::ExecutionContextLayer,
+    governance_middleware::require_governance_context,
     graphql::{build_schema, graphql_handler, graphql_playground},
     handlers::{
-        delete_asset, get_asset, get_dependencies, get_dependents, health_check, list_assets,
-        metrics, receive_execution, register_asset, update_asset, version_info, AppState,
+        add_dependencies, batch_delete_assets, compare_asset_versions, delete_asset,
+        deprecate_asset, export_assets, get_asset, get_capabilities, get_config, get_dependencies,
+        get_dependencies_dot, get_dependents, get_stats, health_check, list_assets,
+        list_governance_events, list_span_types, list_tags, liveness, metrics, patch_asset,
+        preview_retention, readiness, receive_execution, register_asset, register_asset_from_ref,
+        reindex_dependents, remove_dependency, resolve_latest_asset, restore_asset,
+        set_retention_override, update_asset, verify_asset, version_info, AppState,
     },
+    metrics_middleware::metrics_middleware,
+    negotiation::negotiate_content,
+    timeout::enforce_timeout,
+    timestamp_middleware::render_timestamps,
 };
 
+/// Role required for `/v1/admin` routes on authenticated routers
+pub const ADMIN_ROLE: &str = "admin";
+
 /// Build the API router with all routes
 pub fn build_router(state: AppState) -> Router {
+    let timestamp_format = state.timestamp_format;
+    let api_metrics = state.metrics.clone();
+
     Router::new()
         // Health and info endpoints
         .route("/health", get(health_check))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
         .route("/metrics", get(metrics))
         .route("/version", get(version_info))
+        // Bulk export (infrastructure dump, no execution-context middleware)
+        .route("/v1/assets:export", get(export_assets))
+        .route("/v1/config", get(get_config))
         // API v1 routes
-        .nest("/v1", build_v1_routes())
+        .nest("/v1", build_v1_routes(&state, None))
         // Data-core execution ingestion (no execution-context middleware)
         .route("/api/v1/executions", post(receive_execution))
         .with_state(state)
+        .layer(middleware::from_fn_with_state(
+            api_metrics,
+            metrics_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            timestamp_format,
+            render_timestamps,
+        ))
+        .layer(middleware::from_fn(negotiate_content))
 }
 
 /// Build the API router with authentication enabled
@@ -42,11 +73,20 @@ pub fn build_router_with_auth(
     auth_handler_state: AuthHandlerState,
     auth_state: AuthState,
 ) -> Router {
+    let timestamp_format = state.timestamp_format;
+    let api_metrics = state.metrics.clone();
+    // Access decisions are reported alongside the app's other governance events
+    let auth_state = auth_state.with_observatory(state.observatory.clone());
+
     // Build public routes
     let public_routes = Router::new()
         .route("/health", get(health_check))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
         .route("/metrics", get(metrics))
         .route("/version", get(version_info))
+        .route("/v1/assets:export", get(export_assets))
+        .route("/v1/config", get(get_config))
         .with_state(state.clone());
 
     // Build auth routes (public)
@@ -66,8 +106,8 @@ pub fn build_router_with_auth(
         ))
         .with_state(auth_handler_state);
 
-    // Build v1 routes (with optional authentication on some endpoints)
-    let v1_routes = build_v1_routes().with_state(state.clone());
+    // Build v1 routes (admin routes require the admin role)
+    let v1_routes = build_v1_routes(&state, Some(&auth_state)).with_state(state.clone());
 
     // Data-core execution ingestion (no execution-context middleware)
     let execution_routes = Router::new()
@@ -81,6 +121,15 @@ pub fn build_router_with_auth(
         .nest("/v1/auth", protected_auth_routes)
         .nest("/v1", v1_routes)
         .merge(execution_routes)
+        .layer(middleware::from_fn_with_state(
+            api_metrics,
+            metrics_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            timestamp_format,
+            render_timestamps,
+        ))
+        .layer(middleware::from_fn(negotiate_content))
 }
 
 /// Build the API router with GraphQL support
@@ -92,15 +141,24 @@ pub fn build_router_with_graphql(
     auth_handler_state: AuthHandlerState,
     auth_state: AuthState,
 ) -> Router {
+    let timestamp_format = state.timestamp_format;
+    let api_metrics = state.metrics.clone();
+    // Access decisions are reported alongside the app's other governance events
+    let auth_state = auth_state.with_observatory(state.observatory.clone());
+
     // Build GraphQL schema
     let schema = build_schema(state.services.clone());
 
     // Build public routes
     let public_routes = Router::new()
         .route("/health", get(health_check))
+        .route("/health/live", get(liveness))
+        .route("/health/ready", get(readiness))
         .route("/metrics", get(metrics))
         .route("/version", get(version_info))
         .route("/graphql/playground", get(graphql_playground))
+        .route("/v1/assets:export", get(export_assets))
+        .route("/v1/config", get(get_config))
         .with_state(state.clone());
 
     // Build GraphQL route with optional authentication
@@ -129,8 +187,8 @@ pub fn build_router_with_graphql(
         ))
         .with_state(auth_handler_state);
 
-    // Build v1 routes
-    let v1_routes = build_v1_routes().with_state(state.clone());
+    // Build v1 routes (admin routes require the admin role)
+    let v1_routes = build_v1_routes(&state, Some(&auth_state)).with_state(state.clone());
 
     // Data-core execution ingestion (no execution-context middleware)
     let execution_routes = Router::new()
@@ -145,26 +203,104 @@ pub fn build_router_with_graphql(
         .nest("/v1/auth", protected_auth_routes)
         .nest("/v1", v1_routes)
         .merge(execution_routes)
+        .layer(middleware::from_fn_with_state(
+            api_metrics,
+            metrics_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            timestamp_format,
+            render_timestamps,
+        ))
+        .layer(middleware::from_fn(negotiate_content))
 }
 
 /// Build v1 API routes
 ///
 /// All v1 routes require an execution context (X-Execution-Id and
-/// X-Parent-Span-Id headers) enforced by the execution middleware.
-fn build_v1_routes() -> Router<AppState> {
-    Router::new()
+/// X-Parent-Span-Id headers) enforced by the execution middleware, unless
+/// the state enables lenient mode.
+/// Mutating routes must also carry the configured governance context.
+/// With `auth`, a valid bearer token identifies the caller on every route
+/// (scoping idempotency keys, for one), and the admin routes require the
+/// `admin` role.
+fn build_v1_routes(state: &AppState, auth: Option<&AuthState>) -> Router<AppState> {
+    // Routes whose bodies carry asset metadata are size-limited
+    let body_limit = || middleware::from_fn_with_state(state.max_body_size, limit_body_size);
+
+    let mut admin_routes = Router::new()
+        .route("/admin/span-types", get(list_span_types))
+        .route("/admin/reindex-dependents", post(reindex_dependents));
+    if let Some(auth) = auth {
+        admin_routes = admin_routes.route_layer(middleware::from_fn_with_state(
+            (auth.clone(), vec![ADMIN_ROLE.to_string()]),
+            require_role,
+        ));
+    }
+
+    let routes = Router::new()
         // Asset management
-        .route("/assets", post(register_asset))
+        .route("/assets", post(register_asset).layer(body_limit()))
         .route("/assets", get(list_assets))
+        .route("/assets:fromRef", post(register_asset_from_ref))
+        .route("/assets:batchDelete", post(batch_delete_assets))
+        .route("/assets/compare", get(compare_asset_versions))
+        .route("/assets/by-name/{name}/latest", get(resolve_latest_asset))
+        .route("/tags", get(list_tags))
+        .route("/stats", get(get_stats))
         .route("/assets/{id}", get(get_asset))
-        .route("/assets/{id}", patch(update_asset))
+        .route("/assets/{id}", put(update_asset).layer(body_limit()))
+        .route("/assets/{id}", patch(patch_asset).layer(body_limit()))
         .route("/assets/{id}", delete(delete_asset))
+        .route("/assets/{id}/deprecate", post(deprecate_asset))
+        .route("/assets/{id}/restore", post(restore_asset))
+        .route("/assets/{id}/verify", post(verify_asset))
         // Dependencies
         .route("/assets/{id}/dependencies", get(get_dependencies))
+        .route(
+            "/assets/{id}/dependencies",
+            post(add_dependencies).layer(body_limit()),
+        )
+        .route(
+            "/assets/{id}/dependencies/{dep_id}",
+            delete(remove_dependency),
+        )
+        .route("/assets/{id}/dependencies.dot", get(get_dependencies_dot))
         .route("/assets/{id}/dependents", get(get_dependents))
+        // Retention
+        .route("/assets/{id}/retention", put(set_retention_override))
+        .route("/retention/{name}/preview", get(preview_retention))
+        // Capabilities
+        .route("/capabilities", get(get_capabilities))
+        .route("/governance/events", get(list_governance_events))
+        // Admin
+        .merge(admin_routes)
+        // Timeout middleware — innermost, so a timed-out handler still
+        // reports its execution graph.
+        .layer(middleware::from_fn_with_state(
+            state.request_timeouts,
+            enforce_timeout,
+        ))
+        // Governance context middleware — runs inside the execution context
+        // so rejections still report the execution graph.
+        .layer(middleware::from_fn_with_state(
+            state.governance.clone(),
+            require_governance_context,
+        ))
         // Execution context middleware — rejects requests without valid
-        // X-Execution-Id and X-Parent-Span-Id headers.
-        .layer(middleware::from_fn(require_execution_context))
+        // X-Execution-Id and X-Parent-Span-Id headers (strict mode) and
+        // exports the finalized span tree to the observatory.
+        .layer(
+            ExecutionContextLayer::new(state.lenient_execution_context)
+                .with_header_names(state.execution_headers.clone())
+                .with_observatory(state.observatory.clone()),
+        );
+
+    // Optional authentication — outermost, so the caller is known to every
+    // handler and inner middleware
+    match auth {
+        Some(auth) => routes.layer(middleware::from_fn_with_state(auth.clone(), optional_auth)),
+        None => routes,
+    }
 }
 
 /// Route configuration
@@ -208,6 +344,152 @@ impl RouteConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+    use crate::jwt::{Claims, JwtConfig, JwtManager};
+    use crate::test_support;
+    use axum::{
+        body::Body,
+        http::{header::AUTHORIZATION, Request, StatusCode},
+    };
+    use llm_registry_core::SpanId;
+    use tower::ServiceExt;
+
+    fn jwt_manager() -> JwtManager {
+        JwtManager::new(
+            JwtConfig::new("test-secret-key")
+                .with_issuer("test")
+                .with_audience("test"),
+        )
+        .unwrap()
+    }
+
+    /// Bearer token for `user123` holding `roles`
+    fn token(roles: &[&str]) -> String {
+        user_token("user123", roles)
+    }
+
+    fn user_token(user: &str, roles: &[&str]) -> String {
+        let claims = Claims::new(user, "test", "test", 3600)
+            .with_roles(roles.iter().map(|r| r.to_string()).collect());
+        jwt_manager().generate_token_with_claims(claims).unwrap()
+    }
+
+    /// POST to `uri` with a fresh execution context and optional token
+    fn post(uri: &str, token: Option<&str>) -> Request<Body> {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header(HEADER_EXECUTION_ID, uuid::Uuid::new_v4().to_string())
+            .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string());
+        if let Some(token) = token {
+            request = request.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_admin_routes_require_admin_role() {
+        let builders: [fn(AppState, AuthHandlerState, AuthState) -> Router; 2] =
+            [build_router_with_auth, build_router_with_graphql];
+        for build in builders {
+            let (state, _, _) = test_support::app_state();
+            let app = build(
+                state,
+                AuthHandlerState::new(jwt_manager()),
+                AuthState::new(jwt_manager()),
+            );
+            let reindex = |token: Option<String>| {
+                app.clone()
+                    .oneshot(post("/v1/admin/reindex-dependents", token.as_deref()))
+            };
+
+            assert_eq!(
+                reindex(None).await.unwrap().status(),
+                StatusCode::UNAUTHORIZED
+            );
+            assert_eq!(
+                reindex(Some(token(&["developer"]))).await.unwrap().status(),
+                StatusCode::FORBIDDEN
+            );
+            assert_eq!(
+                reindex(Some(token(&[ADMIN_ROLE]))).await.unwrap().status(),
+                StatusCode::OK
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auth_routers_emit_access_decisions() {
+        use llm_registry_service::adapters::GovernanceEvent;
+
+        let builders: [fn(AppState, AuthHandlerState, AuthState) -> Router; 2] =
+            [build_router_with_auth, build_router_with_graphql];
+        for build in builders {
+            let (state, _, _) = test_support::app_state();
+            let observatory = state.observatory.clone();
+            let app = build(
+                state,
+                AuthHandlerState::new(jwt_manager()),
+                AuthState::new(jwt_manager()),
+            );
+
+            let response = app.oneshot(post("/v1/auth/logout", None)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+            let denials = observatory
+                .buffered_events()
+                .await
+                .into_iter()
+                .filter(|e| matches!(e, GovernanceEvent::AccessDecision { allowed: false, .. }))
+                .count();
+            assert_eq!(denials, 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_auth_routers_scope_idempotency_keys_to_the_caller() {
+        let builders: [fn(AppState, AuthHandlerState, AuthState) -> Router; 2] =
+            [build_router_with_auth, build_router_with_graphql];
+        for build in builders {
+            let (state, repository, _) = test_support::app_state();
+            let app = build(
+                state,
+                AuthHandlerState::new(jwt_manager()),
+                AuthState::new(jwt_manager()),
+            );
+
+            // The same key with different bodies only conflicts within one caller
+            for (user, name, expected) in [
+                ("alice", "alice-model", StatusCode::CREATED),
+                ("bob", "bob-model", StatusCode::CREATED),
+                ("alice", "other-model", StatusCode::UNPROCESSABLE_ENTITY),
+            ] {
+                let body = serde_json::json!({
+                    "asset_type": "model",
+                    "name": name,
+                    "version": "1.0.0",
+                    "storage": {
+                        "backend": {"type": "s3", "bucket": "models", "region": "us-east-1"},
+                        "path": format!("{}.bin", name)
+                    },
+                    "checksum": {"algorithm": "SHA256", "value": "a".repeat(64)}
+                });
+                let request = Request::builder()
+                    .method("POST")
+                    .uri("/v1/assets")
+                    .header(HEADER_EXECUTION_ID, uuid::Uuid::new_v4().to_string())
+                    .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+                    .header(AUTHORIZATION, format!("Bearer {}", user_token(user, &[])))
+                    .header("Idempotency-Key", "key-1")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap();
+                let response = app.clone().oneshot(request).await.unwrap();
+                assert_eq!(response.status(), expected, "{} registering {}", user, name);
+            }
+            assert_eq!(repository.assets.lock().unwrap().len(), 2);
+        }
+    }
 
     #[test]
     fn test_route_config_default() {
@@ -218,9 +500,7 @@ mod tests {
 
     #[test]
     fn test_route_config_builder() {
-        let config = RouteConfig::new()
-            .with_base_path("/api")
-            .with_version("v2");
+        let config = RouteConfig::new().with_base_path("/api").with_version("v2");
 
         assert_eq!(config.base_path, "/api");
         assert_eq!(config.version, "v2");
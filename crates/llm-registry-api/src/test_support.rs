@@ -0,0 +1,433 @@
+//! In-memory backends for handler tests
+
+use async_trait::async_trait;
+use llm_registry_core::Version;
+use llm_registry_core::{
+    Asset, AssetId, AssetMetadata, AssetType, Checksum, ExecutionContext, ExecutionId,
+    HashAlgorithm, RegistryEvent, SpanCollector, SpanId, StorageBackend, StorageLocation,
+};
+use llm_registry_db::{
+    AssetRepository, DbError, DbResult, EventQuery, EventQueryResults, EventStore, SearchQuery,
+    SearchResults, SortField, SortOrder, TagMatch,
+};
+use llm_registry_service::ServiceRegistryBuilder;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::handlers::AppState;
+
+/// Asset repository backed by a vector; records the last search query
+#[derive(Default)]
+pub struct InMemoryRepository {
+    pub assets: Mutex<Vec<Asset>>,
+    pub last_query: Mutex<Option<SearchQuery>>,
+    /// Dependency edges as `(asset, dependency)` pairs
+    pub dependencies: Mutex<Vec<(AssetId, AssetId)>>,
+    /// Simulate a lost database connection for probe queries
+    pub unavailable: AtomicBool,
+    /// Simulate a slow database on searches
+    pub search_delay: Mutex<Duration>,
+    /// Simulate a slow database on inserts
+    pub create_delay: Mutex<Duration>,
+    /// Delay returning lookups by id, which are read before the delay
+    pub find_delay: Mutex<Duration>,
+}
+
+impl InMemoryRepository {
+    /// Live (not soft-deleted) assets with the given ids
+    fn assets_for(&self, ids: impl IntoIterator<Item = AssetId>) -> Vec<Asset> {
+        let assets = self.assets.lock().unwrap();
+        ids.into_iter()
+            .filter_map(|id| {
+                assets
+                    .iter()
+                    .find(|a| a.id == id && !a.is_deleted())
+                    .cloned()
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AssetRepository for InMemoryRepository {
+    async fn create(&self, asset: Asset) -> DbResult<Asset> {
+        let delay = *self.create_delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        self.assets.lock().unwrap().push(asset.clone());
+        Ok(asset)
+    }
+
+    async fn find_by_id(&self, id: &AssetId) -> DbResult<Option<Asset>> {
+        let found = self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|a| a.id == *id)
+            .cloned();
+        let delay = *self.find_delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        Ok(found)
+    }
+
+    async fn find_by_name_and_version(
+        &self,
+        name: &str,
+        version: &Version,
+    ) -> DbResult<Option<Asset>> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|a| a.metadata.name == name && a.metadata.version == *version)
+            .cloned())
+    }
+
+    async fn find_by_ids(&self, ids: &[AssetId]) -> DbResult<Vec<Asset>> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| ids.contains(&a.id))
+            .cloned()
+            .collect())
+    }
+
+    async fn search(&self, query: &SearchQuery) -> DbResult<SearchResults> {
+        *self.last_query.lock().unwrap() = Some(query.clone());
+        let delay = *self.search_delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+
+        let mut assets: Vec<Asset> = self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| !query.exclude_deprecated || a.deprecated_at.is_none())
+            .filter(|a| query.include_deleted || a.deleted_at.is_none())
+            .filter(|a| match query.content_hash_prefix {
+                Some(ref prefix) => a.checksum.value.starts_with(prefix.as_str()),
+                None => true,
+            })
+            .filter(|a| match query.q {
+                Some(ref q) => {
+                    let q = q.to_lowercase();
+                    a.metadata.name.to_lowercase().contains(&q)
+                        || a.metadata
+                            .description
+                            .as_ref()
+                            .is_some_and(|d| d.to_lowercase().contains(&q))
+                }
+                None => true,
+            })
+            .filter(|a| {
+                let has_tag = |tag: &String| a.metadata.tags.contains(tag);
+                match query.tag_match {
+                    _ if query.tags.is_empty() => true,
+                    TagMatch::All => query.tags.iter().all(has_tag),
+                    TagMatch::Any => query.tags.iter().any(has_tag),
+                }
+            })
+            .filter(|a| match query.label_selector {
+                Some(ref selector) => selector.matches(&a.metadata.labels),
+                None => true,
+            })
+            .filter(|a| match query.exclude_expired {
+                Some(ref cutoff) => !cutoff.is_expired(a),
+                None => true,
+            })
+            .filter(|a| match query.after {
+                Some((created_at, id)) => {
+                    (a.created_at, a.id.to_string()) > (created_at, id.to_string())
+                }
+                None => true,
+            })
+            .cloned()
+            .collect();
+        assets.sort_by(|a, b| {
+            let ordering = match query.sort_by {
+                SortField::CreatedAt => a.created_at.cmp(&b.created_at),
+                SortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+                SortField::Name => a.metadata.name.cmp(&b.metadata.name),
+                SortField::Version => a.metadata.version.cmp(&b.metadata.version),
+                SortField::SizeBytes => a.metadata.size_bytes.cmp(&b.metadata.size_bytes),
+            };
+            match query.sort_order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+            .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+        });
+
+        let total = assets.len() as i64;
+        let assets = assets
+            .into_iter()
+            .skip(query.offset as usize)
+            .take(query.limit as usize)
+            .collect();
+
+        Ok(SearchResults {
+            assets,
+            total,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn update(&self, asset: Asset) -> DbResult<Asset> {
+        let mut assets = self.assets.lock().unwrap();
+        if let Some(existing) = assets.iter_mut().find(|a| a.id == asset.id) {
+            *existing = asset.clone();
+        }
+        Ok(asset)
+    }
+
+    async fn update_if_unmodified(
+        &self,
+        asset: Asset,
+        expected_updated_at: chrono::DateTime<chrono::Utc>,
+    ) -> DbResult<Asset> {
+        let mut assets = self.assets.lock().unwrap();
+        let existing = assets
+            .iter_mut()
+            .find(|a| a.id == asset.id)
+            .ok_or_else(|| DbError::NotFound(asset.id.to_string()))?;
+        if existing.updated_at != expected_updated_at {
+            return Err(DbError::PreconditionFailed(asset.id.to_string()));
+        }
+        *existing = asset.clone();
+        Ok(asset)
+    }
+
+    async fn delete(&self, id: &AssetId) -> DbResult<()> {
+        self.assets.lock().unwrap().retain(|a| a.id != *id);
+        Ok(())
+    }
+
+    async fn list_versions(&self, name: &str) -> DbResult<Vec<Asset>> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.metadata.name == name && !a.is_deleted())
+            .cloned()
+            .collect())
+    }
+
+    async fn list_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        let edges = self.dependencies.lock().unwrap().clone();
+        Ok(self.assets_for(
+            edges
+                .into_iter()
+                .filter(|(from, _)| from == id)
+                .map(|(_, to)| to),
+        ))
+    }
+
+    async fn list_reverse_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>> {
+        let edges = self.dependencies.lock().unwrap().clone();
+        Ok(self.assets_for(
+            edges
+                .into_iter()
+                .filter(|(_, to)| to == id)
+                .map(|(from, _)| from),
+        ))
+    }
+
+    async fn add_tag(&self, _: &AssetId, _: &str) -> DbResult<()> {
+        Ok(())
+    }
+
+    async fn remove_tag(&self, _: &AssetId, _: &str) -> DbResult<()> {
+        Ok(())
+    }
+
+    async fn get_tags(&self, _: &AssetId) -> DbResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn list_all_tags(&self) -> DbResult<Vec<String>> {
+        if self.unavailable.load(Ordering::Relaxed) {
+            return Err(DbError::Connection("connection refused".to_string()));
+        }
+        Ok(vec![])
+    }
+
+    async fn add_dependency(
+        &self,
+        asset_id: &AssetId,
+        dependency_id: &AssetId,
+        _: Option<&str>,
+    ) -> DbResult<()> {
+        let mut edges = self.dependencies.lock().unwrap();
+        if !edges.contains(&(*asset_id, *dependency_id)) {
+            edges.push((*asset_id, *dependency_id));
+        }
+        // Mirror the relations a database repository loads with the asset
+        if let Some(asset) = self
+            .assets
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|a| a.id == *asset_id)
+        {
+            if !asset
+                .dependencies
+                .iter()
+                .any(|d| d.as_id() == Some(dependency_id))
+            {
+                asset
+                    .dependencies
+                    .push(llm_registry_core::AssetReference::by_id(*dependency_id));
+            }
+        }
+        Ok(())
+    }
+
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()> {
+        self.dependencies
+            .lock()
+            .unwrap()
+            .retain(|edge| *edge != (*asset_id, *dependency_id));
+        if let Some(asset) = self
+            .assets
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|a| a.id == *asset_id)
+        {
+            asset
+                .dependencies
+                .retain(|d| d.as_id() != Some(dependency_id));
+        }
+        Ok(())
+    }
+
+    async fn count_assets(&self) -> DbResult<i64> {
+        Ok(self.assets.lock().unwrap().len() as i64)
+    }
+
+    async fn count_by_type(&self, asset_type: &AssetType) -> DbResult<i64> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.asset_type == *asset_type)
+            .count() as i64)
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        if self.unavailable.load(Ordering::Relaxed) {
+            return Err(DbError::Connection("connection refused".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Event store that keeps appended events in memory
+#[derive(Default)]
+pub struct InMemoryEventStore {
+    pub events: Mutex<Vec<RegistryEvent>>,
+}
+
+#[async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, event: RegistryEvent) -> DbResult<RegistryEvent> {
+        self.events.lock().unwrap().push(event.clone());
+        Ok(event)
+    }
+
+    async fn append_batch(&self, events: Vec<RegistryEvent>) -> DbResult<Vec<RegistryEvent>> {
+        self.events.lock().unwrap().extend(events.iter().cloned());
+        Ok(events)
+    }
+
+    async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults> {
+        let events = self.events.lock().unwrap().clone();
+        Ok(EventQueryResults {
+            total: events.len() as i64,
+            events,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn get_asset_events(&self, _: &AssetId, _: i64) -> DbResult<Vec<RegistryEvent>> {
+        Ok(vec![])
+    }
+
+    async fn get_latest_event(&self, _: &AssetId) -> DbResult<Option<RegistryEvent>> {
+        Ok(None)
+    }
+
+    async fn count_events(&self) -> DbResult<i64> {
+        Ok(self.events.lock().unwrap().len() as i64)
+    }
+
+    async fn count_by_type(&self, _: &str) -> DbResult<i64> {
+        Ok(0)
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        Ok(())
+    }
+}
+
+/// App state wired to in-memory backends
+pub fn app_state() -> (AppState, Arc<InMemoryRepository>, Arc<InMemoryEventStore>) {
+    let repository = Arc::new(InMemoryRepository::default());
+    let event_store = Arc::new(InMemoryEventStore::default());
+
+    let services = ServiceRegistryBuilder::new()
+        .repository(repository.clone())
+        .event_store(event_store.clone())
+        .build()
+        .unwrap();
+
+    (AppState::new(services), repository, event_store)
+}
+
+/// Span collector for a fresh execution
+pub fn collector() -> SpanCollector {
+    SpanCollector::new(&ExecutionContext {
+        execution_id: ExecutionId::new("test-exec"),
+        parent_span_id: SpanId::new(),
+        sampled: true,
+    })
+}
+
+/// Minimal valid model asset
+pub fn asset(name: &str, version: &str) -> Asset {
+    let metadata = AssetMetadata::new(name, Version::parse(version).unwrap());
+    let storage = StorageLocation::new(
+        StorageBackend::S3 {
+            bucket: "test".to_string(),
+            region: "us-east-1".to_string(),
+            endpoint: None,
+        },
+        format!("{}.bin", name),
+        None,
+    )
+    .unwrap();
+    let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+
+    Asset::new(
+        AssetId::new(),
+        AssetType::Model,
+        metadata,
+        storage,
+        checksum,
+    )
+    .unwrap()
+}
@@ -0,0 +1,219 @@
+//! Per-request timeouts
+//!
+//! The [`enforce_timeout`] middleware cancels a `/v1` handler that runs past
+//! the deadline for its [`RouteClass`] and responds `504 Gateway Timeout`.
+//! The execution result is still finalized: the repo span fails with a
+//! `timeout_ms` attribute, and agent spans the handler left open are closed
+//! as failed.
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::Method,
+    middleware::Next,
+    response::Response,
+};
+use llm_registry_core::execution::SpanCollector;
+use std::time::Duration;
+use tracing::warn;
+
+use crate::error::ApiError;
+
+/// `/v1` routes served by the search service
+const SEARCH_ROUTES: &[&str] = &[
+    "/assets",
+    "/assets/compare",
+    "/assets/by-name/{name}/latest",
+    "/assets/{id}/dependencies",
+    "/assets/{id}/dependencies.dot",
+    "/assets/{id}/dependents",
+    "/tags",
+];
+
+/// Groups of routes sharing a timeout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteClass {
+    /// Lookups of a single resource
+    Read,
+    /// Queries over many assets
+    Search,
+    /// Requests that modify the registry
+    Write,
+}
+
+impl RouteClass {
+    /// Classify a request by method and matched route
+    pub fn of(method: &Method, route: &str) -> Self {
+        if *method != Method::GET && *method != Method::HEAD {
+            return Self::Write;
+        }
+        let route = route.strip_prefix("/v1").unwrap_or(route);
+        if SEARCH_ROUTES.contains(&route) {
+            Self::Search
+        } else {
+            Self::Read
+        }
+    }
+}
+
+/// Handler deadlines, per route class
+///
+/// A class without its own timeout uses `default`; with neither set,
+/// requests of that class never time out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestTimeouts {
+    /// Timeout for classes without an override
+    pub default: Option<Duration>,
+    /// Timeout for [`RouteClass::Read`] routes
+    pub read: Option<Duration>,
+    /// Timeout for [`RouteClass::Search`] routes
+    pub search: Option<Duration>,
+    /// Timeout for [`RouteClass::Write`] routes
+    pub write: Option<Duration>,
+}
+
+impl RequestTimeouts {
+    /// Apply `timeout` to every route class
+    pub fn uniform(timeout: Duration) -> Self {
+        Self {
+            default: Some(timeout),
+            ..Self::default()
+        }
+    }
+
+    /// Override the timeout of one route class
+    pub fn with_class(mut self, class: RouteClass, timeout: Duration) -> Self {
+        match class {
+            RouteClass::Read => self.read = Some(timeout),
+            RouteClass::Search => self.search = Some(timeout),
+            RouteClass::Write => self.write = Some(timeout),
+        }
+        self
+    }
+
+    /// The timeout applied to `class`, if any
+    pub fn for_class(&self, class: RouteClass) -> Option<Duration> {
+        let timeout = match class {
+            RouteClass::Read => self.read,
+            RouteClass::Search => self.search,
+            RouteClass::Write => self.write,
+        };
+        timeout.or(self.default)
+    }
+}
+
+/// Middleware that fails requests whose handler outlives its route's timeout
+pub async fn enforce_timeout(
+    State(timeouts): State<RequestTimeouts>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str())
+        .unwrap_or_else(|| request.uri().path());
+    let class = RouteClass::of(request.method(), route);
+    let Some(timeout) = timeouts.for_class(class) else {
+        return Ok(next.run(request).await);
+    };
+
+    let route = route.to_string();
+    let collector = request.extensions().get::<SpanCollector>().cloned();
+    match tokio::time::timeout(timeout, next.run(request)).await {
+        Ok(response) => Ok(response),
+        Err(_) => {
+            let timeout_ms = timeout.as_millis() as u64;
+            warn!(route = %route, timeout_ms, "Request timed out");
+
+            let err = ApiError::gateway_timeout(timeout_ms);
+            Err(match collector {
+                Some(collector) => {
+                    let _ = collector.set_attribute(
+                        collector.repo_span_id(),
+                        "timeout_ms",
+                        serde_json::json!(timeout_ms),
+                    );
+                    err.with_execution(collector.finalize_failed("Request timed out"))
+                }
+                None => err,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution_middleware::{HEADER_EXECUTION_ID, HEADER_PARENT_SPAN_ID};
+    use crate::test_support;
+    use axum::{body::Body, http::StatusCode};
+    use llm_registry_core::execution::SpanId;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_route_classes() {
+        assert_eq!(
+            RouteClass::of(&Method::GET, "/v1/assets"),
+            RouteClass::Search
+        );
+        assert_eq!(
+            RouteClass::of(&Method::GET, "/v1/assets/{id}/dependents"),
+            RouteClass::Search
+        );
+        assert_eq!(
+            RouteClass::of(&Method::GET, "/v1/assets/{id}"),
+            RouteClass::Read
+        );
+        assert_eq!(
+            RouteClass::of(&Method::POST, "/v1/assets"),
+            RouteClass::Write
+        );
+
+        let timeouts = RequestTimeouts::uniform(Duration::from_secs(30))
+            .with_class(RouteClass::Search, Duration::from_secs(5));
+        assert_eq!(
+            timeouts.for_class(RouteClass::Search),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            timeouts.for_class(RouteClass::Write),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(RequestTimeouts::default().for_class(RouteClass::Read), None);
+    }
+
+    #[tokio::test]
+    async fn test_slow_search_times_out_with_failed_spans() {
+        let (state, repository, _) = test_support::app_state();
+        *repository.search_delay.lock().unwrap() = Duration::from_secs(10);
+        let timeouts = RequestTimeouts::uniform(Duration::from_secs(10))
+            .with_class(RouteClass::Search, Duration::from_millis(50));
+        let app = crate::routes::build_router(state.with_request_timeouts(timeouts));
+
+        let request = axum::http::Request::builder()
+            .uri("/v1/assets")
+            .header(HEADER_EXECUTION_ID, uuid::Uuid::new_v4().to_string())
+            .header(HEADER_PARENT_SPAN_ID, SpanId::new().to_string())
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["code"], "GATEWAY_TIMEOUT");
+
+        let spans = body["execution"]["spans"].as_array().unwrap();
+        let repo = &spans[0];
+        assert_eq!(repo["span_type"], "repo");
+        assert_eq!(repo["status"], "failed");
+        assert_eq!(repo["attributes"]["timeout_ms"], 50);
+
+        let agent = &spans[1];
+        assert_eq!(agent["name"], "SearchService");
+        assert_eq!(agent["status"], "failed");
+        assert_eq!(agent["attributes"]["auto_closed"], true);
+    }
+}
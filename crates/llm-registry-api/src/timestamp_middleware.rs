@@ -0,0 +1,180 @@
+//! Timestamp wire format middleware
+//!
+//! Handlers always serialize timestamps as RFC 3339. When the server is
+//! configured for a numeric format (or a client asks for one with the
+//! `X-Timestamp-Format` header) this middleware rewrites the timestamp fields
+//! of JSON response bodies, so every response type uses the same format.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use llm_registry_core::TimestampFormat;
+use tracing::warn;
+
+use crate::error::ErrorResponse;
+
+/// Header a client can use to override the configured timestamp format.
+pub const HEADER_TIMESTAMP_FORMAT: &str = "x-timestamp-format";
+
+/// Middleware that renders response timestamps in the requested format.
+pub async fn render_timestamps(
+    State(default_format): State<TimestampFormat>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let format = match request.headers().get(HEADER_TIMESTAMP_FORMAT) {
+        Some(value) => value
+            .to_str()
+            .map_err(|e| e.to_string())
+            .and_then(str::parse::<TimestampFormat>)
+            .map_err(invalid_format_response)?,
+        None => default_format,
+    };
+
+    let response = next.run(request).await;
+    if format == TimestampFormat::Rfc3339 || !is_json(&response) {
+        return Ok(response);
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            warn!(error = %e, "Failed to buffer response body for timestamp rendering");
+            return Ok(StatusCode::INTERNAL_SERVER_ERROR.into_response());
+        }
+    };
+
+    let body = match serde_json::from_slice::<serde_json::Value>(&bytes) {
+        Ok(mut value) => {
+            format.convert(&mut value);
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Body::from(serde_json::to_vec(&value).unwrap_or_else(|_| bytes.to_vec()))
+        }
+        Err(_) => Body::from(bytes),
+    };
+
+    Ok(Response::from_parts(parts, body))
+}
+
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"))
+}
+
+fn invalid_format_response(message: String) -> Response {
+    let body = ErrorResponse {
+        status: 400,
+        error: message,
+        code: Some("INVALID_TIMESTAMP_FORMAT".to_string()),
+        details: None,
+        timestamp: chrono::Utc::now(),
+        execution: None,
+    };
+    (StatusCode::BAD_REQUEST, Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http, middleware, routing::get, Router};
+    use chrono::{DateTime, TimeZone, Utc};
+    use llm_registry_core::execution::{
+        ExecutionContext, ExecutionId, ExecutionSpan, SpanCollector, SpanId,
+    };
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn started_at() -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(1_704_067_200_123).unwrap()
+    }
+
+    async fn span() -> Json<ExecutionSpan> {
+        let collector = SpanCollector::new(&ExecutionContext {
+            execution_id: ExecutionId::new("exec"),
+            parent_span_id: SpanId::new(),
+            sampled: true,
+        });
+        let mut span = collector.finalize().spans.remove(0);
+        span.started_at = started_at();
+        span.ended_at = Some(started_at());
+        Json(span)
+    }
+
+    async fn fetch(default_format: TimestampFormat, header: Option<&str>) -> (StatusCode, Value) {
+        let app = Router::new()
+            .route("/span", get(span))
+            .layer(middleware::from_fn_with_state(
+                default_format,
+                render_timestamps,
+            ));
+
+        let mut builder = http::Request::builder().uri("/span");
+        if let Some(header) = header {
+            builder = builder.header(HEADER_TIMESTAMP_FORMAT, header);
+        }
+        let response = app
+            .oneshot(builder.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_rfc3339_round_trip() {
+        let (status, body) = fetch(TimestampFormat::Rfc3339, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let span: ExecutionSpan = serde_json::from_value(body).unwrap();
+        assert_eq!(span.started_at, started_at());
+        assert_eq!(span.ended_at, Some(started_at()));
+    }
+
+    #[tokio::test]
+    async fn test_unix_millis_round_trip() {
+        let (_, body) = fetch(TimestampFormat::UnixMillis, None).await;
+        assert_eq!(body["started_at"], Value::from(1_704_067_200_123_i64));
+        assert_eq!(
+            TimestampFormat::UnixMillis.parse(&body["ended_at"]),
+            Some(started_at())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unix_nanos_round_trip() {
+        let (_, body) = fetch(TimestampFormat::UnixNanos, None).await;
+        assert_eq!(
+            body["started_at"],
+            Value::from(1_704_067_200_123_000_000_i64)
+        );
+        assert_eq!(
+            TimestampFormat::UnixNanos.parse(&body["started_at"]),
+            Some(started_at())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_header_overrides_configured_format() {
+        let (_, body) = fetch(TimestampFormat::Rfc3339, Some("unix-millis")).await;
+        assert_eq!(body["started_at"], Value::from(1_704_067_200_123_i64));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_header_rejected() {
+        let (status, body) = fetch(TimestampFormat::Rfc3339, Some("epoch")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "INVALID_TIMESTAMP_FORMAT");
+    }
+}
@@ -5,9 +5,8 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use llm_registry_benchmarks::{
-    run_all_benchmarks, save_results, save_raw_results, load_results,
-    compare_results, generate_report, generate_comparison_report,
-    OutputFormat, DEFAULT_OUTPUT_DIR,
+    compare_results, generate_comparison_report, generate_report, load_results, run_all_benchmarks,
+    save_raw_results, save_results, OutputFormat, DEFAULT_OUTPUT_DIR,
 };
 use std::path::PathBuf;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -111,8 +110,7 @@ async fn main() -> Result<()> {
             // Generate markdown report if requested
             if markdown {
                 let report = generate_report(&results);
-                let report_path = PathBuf::from(DEFAULT_OUTPUT_DIR)
-                    .join("benchmark_report.md");
+                let report_path = PathBuf::from(DEFAULT_OUTPUT_DIR).join("benchmark_report.md");
                 std::fs::write(&report_path, report)?;
                 println!("Markdown report saved to: {}", report_path.display());
             }
@@ -198,10 +196,12 @@ async fn main() -> Result<()> {
                     &baseline.display().to_string(),
                     &current.display().to_string(),
                 );
-                let report_path = PathBuf::from(DEFAULT_OUTPUT_DIR)
-                    .join("comparison_report.md");
+                let report_path = PathBuf::from(DEFAULT_OUTPUT_DIR).join("comparison_report.md");
                 std::fs::write(&report_path, report)?;
-                println!("Markdown comparison report saved to: {}", report_path.display());
+                println!(
+                    "Markdown comparison report saved to: {}",
+                    report_path.display()
+                );
             }
         }
 
@@ -227,8 +227,14 @@ fn generate_summary(results: &[llm_registry_benchmarks::BenchmarkResult]) -> Str
     use llm_registry_benchmarks::BenchmarkStatus;
 
     let total = results.len();
-    let successful = results.iter().filter(|r| r.status == BenchmarkStatus::Success).count();
-    let failed = results.iter().filter(|r| r.status == BenchmarkStatus::Failed).count();
+    let successful = results
+        .iter()
+        .filter(|r| r.status == BenchmarkStatus::Success)
+        .count();
+    let failed = results
+        .iter()
+        .filter(|r| r.status == BenchmarkStatus::Failed)
+        .count();
 
     let mut summary = format!("=== Benchmark Summary ===\n\n");
     summary.push_str(&format!("Total:      {}\n", total));
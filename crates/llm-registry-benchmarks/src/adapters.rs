@@ -3,7 +3,7 @@
 //! This module provides adapter implementations of the BenchTarget trait
 //! for various Registry operations (CRUD, search, cache, etc.).
 
-use crate::{measure_async, BenchTarget, BenchmarkResult, result::BenchmarkMetrics};
+use crate::{measure_async, result::BenchmarkMetrics, BenchTarget, BenchmarkResult};
 use async_trait::async_trait;
 
 // Note: These adapters will need to be connected to actual Registry components
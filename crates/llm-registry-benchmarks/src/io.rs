@@ -29,13 +29,9 @@ pub enum OutputFormat {
 ///
 /// The filename will be automatically generated with a timestamp.
 /// Format: `benchmark_results_YYYYMMDD_HHMMSS.{ext}`
-pub fn save_results(
-    results: &[BenchmarkResult],
-    format: OutputFormat,
-) -> Result<PathBuf> {
+pub fn save_results(results: &[BenchmarkResult], format: OutputFormat) -> Result<PathBuf> {
     let output_dir = Path::new(DEFAULT_OUTPUT_DIR);
-    fs::create_dir_all(output_dir)
-        .context("Failed to create output directory")?;
+    fs::create_dir_all(output_dir).context("Failed to create output directory")?;
 
     let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
     let extension = match format {
@@ -48,16 +44,14 @@ pub fn save_results(
 
     match format {
         OutputFormat::Json => {
-            let json = serde_json::to_string(&results)
-                .context("Failed to serialize results to JSON")?;
-            fs::write(&filepath, json)
-                .context("Failed to write JSON file")?;
+            let json =
+                serde_json::to_string(&results).context("Failed to serialize results to JSON")?;
+            fs::write(&filepath, json).context("Failed to write JSON file")?;
         }
         OutputFormat::JsonPretty => {
             let json = serde_json::to_string_pretty(&results)
                 .context("Failed to serialize results to pretty JSON")?;
-            fs::write(&filepath, json)
-                .context("Failed to write JSON file")?;
+            fs::write(&filepath, json).context("Failed to write JSON file")?;
         }
         OutputFormat::Csv => {
             save_results_csv(results, &filepath)?;
@@ -72,8 +66,7 @@ pub fn save_results(
 /// Each benchmark result is saved as a separate JSON file for easier analysis.
 pub fn save_raw_results(results: &[BenchmarkResult]) -> Result<Vec<PathBuf>> {
     let raw_dir = Path::new(DEFAULT_RAW_DIR);
-    fs::create_dir_all(raw_dir)
-        .context("Failed to create raw output directory")?;
+    fs::create_dir_all(raw_dir).context("Failed to create raw output directory")?;
 
     let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
     let mut paths = Vec::new();
@@ -84,11 +77,10 @@ pub fn save_raw_results(results: &[BenchmarkResult]) -> Result<Vec<PathBuf>> {
         let filename = format!("{}_{}.json", sanitized_id, timestamp);
         let filepath = raw_dir.join(filename);
 
-        let json = serde_json::to_string_pretty(&result)
-            .context("Failed to serialize result to JSON")?;
+        let json =
+            serde_json::to_string_pretty(&result).context("Failed to serialize result to JSON")?;
 
-        fs::write(&filepath, json)
-            .context("Failed to write raw JSON file")?;
+        fs::write(&filepath, json).context("Failed to write raw JSON file")?;
 
         paths.push(filepath);
     }
@@ -98,11 +90,10 @@ pub fn save_raw_results(results: &[BenchmarkResult]) -> Result<Vec<PathBuf>> {
 
 /// Load benchmark results from a JSON file
 pub fn load_results(filepath: impl AsRef<Path>) -> Result<Vec<BenchmarkResult>> {
-    let content = fs::read_to_string(filepath.as_ref())
-        .context("Failed to read results file")?;
+    let content = fs::read_to_string(filepath.as_ref()).context("Failed to read results file")?;
 
-    let results: Vec<BenchmarkResult> = serde_json::from_str(&content)
-        .context("Failed to deserialize results from JSON")?;
+    let results: Vec<BenchmarkResult> =
+        serde_json::from_str(&content).context("Failed to deserialize results from JSON")?;
 
     Ok(results)
 }
@@ -111,8 +102,7 @@ pub fn load_results(filepath: impl AsRef<Path>) -> Result<Vec<BenchmarkResult>>
 fn save_results_csv(results: &[BenchmarkResult], filepath: &Path) -> Result<()> {
     use std::io::Write;
 
-    let mut file = fs::File::create(filepath)
-        .context("Failed to create CSV file")?;
+    let mut file = fs::File::create(filepath).context("Failed to create CSV file")?;
 
     // Write header
     writeln!(
@@ -128,10 +118,26 @@ fn save_results_csv(results: &[BenchmarkResult], filepath: &Path) -> Result<()>
             result.target_id,
             result.status,
             result.metrics.duration_ms,
-            result.metrics.throughput_ops_per_sec.map(|v| v.to_string()).unwrap_or_default(),
-            result.metrics.memory_bytes.map(|v| v.to_string()).unwrap_or_default(),
-            result.metrics.success_count.map(|v| v.to_string()).unwrap_or_default(),
-            result.metrics.error_count.map(|v| v.to_string()).unwrap_or_default(),
+            result
+                .metrics
+                .throughput_ops_per_sec
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            result
+                .metrics
+                .memory_bytes
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            result
+                .metrics
+                .success_count
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            result
+                .metrics
+                .error_count
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
             result.timestamp.to_rfc3339(),
             result.error.as_deref().unwrap_or("")
         )?;
@@ -176,17 +182,15 @@ pub fn compare_results(
 ) -> ComparisonSummary {
     use std::collections::HashMap;
 
-    let baseline_map: HashMap<_, _> = baseline
-        .iter()
-        .map(|r| (r.target_id.clone(), r))
-        .collect();
+    let baseline_map: HashMap<_, _> = baseline.iter().map(|r| (r.target_id.clone(), r)).collect();
 
     let mut comparisons = Vec::new();
 
     for result in current {
         if let Some(base) = baseline_map.get(&result.target_id) {
             let duration_change_pct = if base.metrics.duration_ms > 0.0 {
-                ((result.metrics.duration_ms - base.metrics.duration_ms) / base.metrics.duration_ms) * 100.0
+                ((result.metrics.duration_ms - base.metrics.duration_ms) / base.metrics.duration_ms)
+                    * 100.0
             } else {
                 0.0
             };
@@ -33,13 +33,12 @@ use async_trait::async_trait;
 use std::time::Instant;
 
 // Re-export public API
-pub use result::{BenchmarkMetadata, BenchmarkMetrics, BenchmarkResult, BenchmarkStatus};
 pub use io::{
-    save_results, save_raw_results, load_results, list_result_files,
-    compare_results, ComparisonSummary, BenchmarkComparison, OutputFormat,
-    DEFAULT_OUTPUT_DIR, DEFAULT_RAW_DIR,
+    compare_results, list_result_files, load_results, save_raw_results, save_results,
+    BenchmarkComparison, ComparisonSummary, OutputFormat, DEFAULT_OUTPUT_DIR, DEFAULT_RAW_DIR,
 };
-pub use markdown::{generate_report, generate_comparison_report, generate_pr_comment};
+pub use markdown::{generate_comparison_report, generate_pr_comment, generate_report};
+pub use result::{BenchmarkMetadata, BenchmarkMetrics, BenchmarkResult, BenchmarkStatus};
 
 /// Trait for implementing benchmark targets
 ///
@@ -3,8 +3,8 @@
 //! Provides functionality to generate human-readable markdown reports
 //! from benchmark results for documentation and CI/CD integration.
 
+use crate::io::{BenchmarkComparison, ComparisonSummary};
 use crate::result::{BenchmarkResult, BenchmarkStatus};
-use crate::io::{ComparisonSummary, BenchmarkComparison};
 use chrono::Utc;
 use std::fmt::Write;
 
@@ -76,11 +76,7 @@ pub fn generate_report(results: &[BenchmarkResult]) -> String {
             writeln!(
                 &mut report,
                 "| {} | {} | {:.2} | {} | {} |",
-                result.target_id,
-                status_icon,
-                result.metrics.duration_ms,
-                throughput,
-                memory
+                result.target_id, status_icon, result.metrics.duration_ms, throughput, memory
             )
             .unwrap();
         }
@@ -377,7 +373,7 @@ pub fn generate_pr_comment(summary: &ComparisonSummary) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::result::{BenchmarkMetrics, BenchmarkMetadata};
+    use crate::result::{BenchmarkMetadata, BenchmarkMetrics};
 
     fn create_test_result(id: &str, duration: f64, status: BenchmarkStatus) -> BenchmarkResult {
         BenchmarkResult {
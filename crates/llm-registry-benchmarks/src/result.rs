@@ -235,7 +235,11 @@ impl BenchmarkMetadata {
             rust_version: Some(env!("CARGO_PKG_RUST_VERSION").to_string()),
             target_triple: Some(env!("TARGET").to_string()),
             cpu_cores: Some(num_cpus::get()),
-            os_info: Some(format!("{} {}", std::env::consts::OS, std::env::consts::ARCH)),
+            os_info: Some(format!(
+                "{} {}",
+                std::env::consts::OS,
+                std::env::consts::ARCH
+            )),
             ..Default::default()
         }
     }
@@ -284,7 +288,10 @@ mod tests {
         assert_eq!(metrics.memory_bytes, Some(1024 * 1024));
         assert_eq!(metrics.success_count, Some(50));
         assert_eq!(metrics.error_count, Some(2));
-        assert_eq!(metrics.custom.as_ref().unwrap().get("latency_p99"), Some(&250.0));
+        assert_eq!(
+            metrics.custom.as_ref().unwrap().get("latency_p99"),
+            Some(&250.0)
+        );
     }
 
     #[test]
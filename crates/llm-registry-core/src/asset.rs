@@ -3,8 +3,8 @@
 //! This module defines the main Asset type and related structures for representing
 //! LLM artifacts in the registry.
 
+use crate::version::Version;
 use chrono::{DateTime, Utc};
-use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -14,7 +14,7 @@ use crate::dependency::AssetReference;
 use crate::error::{RegistryError, Result};
 use crate::provenance::Provenance;
 use crate::storage::StorageLocation;
-use crate::types::{Annotations, AssetId, AssetStatus, Tags};
+use crate::types::{Annotations, AssetId, AssetStatus, Labels, Tags};
 
 /// Types of assets that can be stored in the registry
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -113,6 +113,10 @@ pub struct AssetMetadata {
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub annotations: Annotations,
 
+    /// Structured key-value labels for selection and policy
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: Labels,
+
     /// File size in bytes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size_bytes: Option<u64>,
@@ -132,6 +136,7 @@ impl AssetMetadata {
             license: None,
             tags: Vec::new(),
             annotations: HashMap::new(),
+            labels: HashMap::new(),
             size_bytes: None,
             content_type: None,
         }
@@ -168,9 +173,10 @@ impl AssetMetadata {
             }
             // Basic MIME type validation
             if !ct.contains('/') {
-                return Err(RegistryError::ValidationError(
-                    format!("Invalid content type format: {}", ct),
-                ));
+                return Err(RegistryError::ValidationError(format!(
+                    "Invalid content type format: {}",
+                    ct
+                )));
             }
         }
 
@@ -196,6 +202,11 @@ impl AssetMetadata {
     pub fn get_annotation(&self, key: &str) -> Option<&String> {
         self.annotations.get(key)
     }
+
+    /// Get a label value
+    pub fn get_label(&self, key: &str) -> Option<&String> {
+        self.labels.get(key)
+    }
 }
 
 /// Builder for AssetMetadata
@@ -247,6 +258,18 @@ impl AssetMetadataBuilder {
         self
     }
 
+    /// Add a label
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.labels.insert(key.into(), value.into());
+        self
+    }
+
+    /// Add multiple labels
+    pub fn labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.metadata.labels.extend(labels);
+        self
+    }
+
     /// Set the size in bytes
     pub fn size_bytes(mut self, size: u64) -> Self {
         self.metadata.size_bytes = Some(size);
@@ -309,6 +332,11 @@ pub struct Asset {
     /// Optional timestamp when the asset was deprecated
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated_at: Option<DateTime<Utc>>,
+
+    /// Set when the asset is soft-deleted; such assets are hidden from queries
+    /// until restored or purged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Asset {
@@ -339,6 +367,7 @@ impl Asset {
             created_at: now,
             updated_at: now,
             deprecated_at: None,
+            deleted_at: None,
         })
     }
 
@@ -394,6 +423,11 @@ impl Asset {
         Ok(())
     }
 
+    /// Check if the asset is soft-deleted
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     /// Check if the asset is active
     pub fn is_active(&self) -> bool {
         self.status == AssetStatus::Active
@@ -413,6 +447,14 @@ impl Asset {
     pub fn full_name(&self) -> String {
         format!("{}@{}", self.metadata.name, self.metadata.version)
     }
+
+    /// Get the entity tag used for optimistic concurrency control
+    ///
+    /// Derived from `updated_at` at microsecond precision (what the database
+    /// stores), so it changes whenever the asset is updated.
+    pub fn etag(&self) -> String {
+        format!("{:x}", self.updated_at.timestamp_micros())
+    }
 }
 
 impl fmt::Display for Asset {
@@ -528,6 +570,7 @@ impl AssetBuilder {
             created_at: self.created_at,
             updated_at: self.created_at,
             deprecated_at,
+            deleted_at: None,
         })
     }
 
@@ -551,6 +594,7 @@ impl AssetBuilder {
             created_at: self.created_at,
             updated_at: self.created_at,
             deprecated_at,
+            deleted_at: None,
         }
     }
 }
@@ -628,9 +672,15 @@ mod tests {
         assert_eq!(metadata.license.as_deref(), Some("MIT"));
         assert_eq!(metadata.tags.len(), 2);
         assert!(metadata.has_tag("nlp"));
-        assert_eq!(metadata.get_annotation("framework"), Some(&"pytorch".to_string()));
+        assert_eq!(
+            metadata.get_annotation("framework"),
+            Some(&"pytorch".to_string())
+        );
         assert_eq!(metadata.size_bytes, Some(1024));
-        assert_eq!(metadata.content_type.as_deref(), Some("application/octet-stream"));
+        assert_eq!(
+            metadata.content_type.as_deref(),
+            Some("application/octet-stream")
+        );
     }
 
     #[test]
@@ -22,6 +22,13 @@ pub enum HashAlgorithm {
 }
 
 impl HashAlgorithm {
+    /// All supported algorithms
+    pub const ALL: [HashAlgorithm; 3] = [
+        HashAlgorithm::SHA256,
+        HashAlgorithm::SHA3_256,
+        HashAlgorithm::BLAKE3,
+    ];
+
     /// Get the expected length of the hash in bytes
     pub fn hash_length(&self) -> usize {
         match self {
@@ -174,13 +181,19 @@ mod tests {
     #[test]
     fn test_hash_algorithm_validation() {
         let valid_sha256 = "a".repeat(64);
-        assert!(HashAlgorithm::SHA256.validate_hash_format(&valid_sha256).is_ok());
+        assert!(HashAlgorithm::SHA256
+            .validate_hash_format(&valid_sha256)
+            .is_ok());
 
         let invalid_length = "a".repeat(63);
-        assert!(HashAlgorithm::SHA256.validate_hash_format(&invalid_length).is_err());
+        assert!(HashAlgorithm::SHA256
+            .validate_hash_format(&invalid_length)
+            .is_err());
 
         let invalid_chars = "g".repeat(64);
-        assert!(HashAlgorithm::SHA256.validate_hash_format(&invalid_chars).is_err());
+        assert!(HashAlgorithm::SHA256
+            .validate_hash_format(&invalid_chars)
+            .is_err());
     }
 
     #[test]
@@ -224,9 +237,6 @@ mod tests {
     fn test_checksum_display() {
         let hash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
         let checksum = Checksum::new(HashAlgorithm::SHA256, hash.to_string()).unwrap();
-        assert_eq!(
-            checksum.to_string(),
-            format!("SHA256:{}", hash)
-        );
+        assert_eq!(checksum.to_string(), format!("SHA256:{}", hash));
     }
 }
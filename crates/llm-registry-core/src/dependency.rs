@@ -67,7 +67,9 @@ impl AssetReference {
     /// Get the name and version if this is a name/version reference
     pub fn as_name_version(&self) -> Option<(&str, &str)> {
         match self {
-            AssetReference::ByNameVersion { name, version } => Some((name.as_str(), version.as_str())),
+            AssetReference::ByNameVersion { name, version } => {
+                Some((name.as_str(), version.as_str()))
+            }
             _ => None,
         }
     }
@@ -395,7 +397,9 @@ mod tests {
         let asset1 = AssetId::new();
         let asset2 = AssetId::new();
 
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
 
         let deps = graph.get_dependencies(&asset1).unwrap();
         assert_eq!(deps.len(), 1);
@@ -407,7 +411,9 @@ mod tests {
         let asset1 = AssetId::new();
         let asset2 = AssetId::new();
 
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
 
         let deps = graph.get_dependencies(&asset1).unwrap();
         assert_eq!(deps.len(), 1);
@@ -420,7 +426,9 @@ mod tests {
         let asset1 = AssetId::new();
         let asset2 = AssetId::new();
 
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
         assert!(graph.contains_asset(&asset1));
 
         graph.remove_asset(&asset1);
@@ -434,8 +442,12 @@ mod tests {
         let asset2 = AssetId::new();
 
         // Create a simple cycle: asset1 -> asset2 -> asset1
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
-        graph.add_dependency(asset2, AssetReference::by_id(asset1)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
+        graph
+            .add_dependency(asset2, AssetReference::by_id(asset1))
+            .unwrap();
 
         assert!(graph.detect_circular_dependencies().is_err());
     }
@@ -448,9 +460,15 @@ mod tests {
         let asset3 = AssetId::new();
 
         // Create a cycle: asset1 -> asset2 -> asset3 -> asset1
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
-        graph.add_dependency(asset2, AssetReference::by_id(asset3)).unwrap();
-        graph.add_dependency(asset3, AssetReference::by_id(asset1)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
+        graph
+            .add_dependency(asset2, AssetReference::by_id(asset3))
+            .unwrap();
+        graph
+            .add_dependency(asset3, AssetReference::by_id(asset1))
+            .unwrap();
 
         assert!(graph.detect_circular_dependencies().is_err());
     }
@@ -463,8 +481,12 @@ mod tests {
         let asset3 = AssetId::new();
 
         // Create a DAG: asset1 -> asset2, asset1 -> asset3
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
-        graph.add_dependency(asset1, AssetReference::by_id(asset3)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset3))
+            .unwrap();
 
         assert!(graph.detect_circular_dependencies().is_ok());
     }
@@ -477,8 +499,12 @@ mod tests {
         let asset3 = AssetId::new();
 
         // asset1 -> asset2 -> asset3
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
-        graph.add_dependency(asset2, AssetReference::by_id(asset3)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
+        graph
+            .add_dependency(asset2, AssetReference::by_id(asset3))
+            .unwrap();
 
         let all_deps = graph.get_all_dependencies(&asset1);
         assert_eq!(all_deps.len(), 2);
@@ -494,8 +520,12 @@ mod tests {
         let asset3 = AssetId::new();
 
         // asset1 -> asset3, asset2 -> asset3
-        graph.add_dependency(asset1, AssetReference::by_id(asset3)).unwrap();
-        graph.add_dependency(asset2, AssetReference::by_id(asset3)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset3))
+            .unwrap();
+        graph
+            .add_dependency(asset2, AssetReference::by_id(asset3))
+            .unwrap();
 
         let dependents = graph.get_dependents(&asset3);
         assert_eq!(dependents.len(), 2);
@@ -511,8 +541,12 @@ mod tests {
         let asset3 = AssetId::new();
 
         // asset1 -> asset2 -> asset3 (asset1 depends on asset2, asset2 depends on asset3)
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
-        graph.add_dependency(asset2, AssetReference::by_id(asset3)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
+        graph
+            .add_dependency(asset2, AssetReference::by_id(asset3))
+            .unwrap();
         graph.add_dependencies(asset3, vec![]).unwrap(); // asset3 has no dependencies
 
         let sorted = graph.topological_sort().unwrap();
@@ -526,8 +560,18 @@ mod tests {
         let pos3 = sorted.iter().position(|id| id == &asset3).unwrap();
 
         // Verify the order: asset3 < asset2 < asset1
-        assert!(pos3 < pos2, "asset3 (pos {}) should come before asset2 (pos {})", pos3, pos2);
-        assert!(pos2 < pos1, "asset2 (pos {}) should come before asset1 (pos {})", pos2, pos1);
+        assert!(
+            pos3 < pos2,
+            "asset3 (pos {}) should come before asset2 (pos {})",
+            pos3,
+            pos2
+        );
+        assert!(
+            pos2 < pos1,
+            "asset2 (pos {}) should come before asset1 (pos {})",
+            pos2,
+            pos1
+        );
     }
 
     #[test]
@@ -537,8 +581,12 @@ mod tests {
         let asset2 = AssetId::new();
 
         // Create a cycle
-        graph.add_dependency(asset1, AssetReference::by_id(asset2)).unwrap();
-        graph.add_dependency(asset2, AssetReference::by_id(asset1)).unwrap();
+        graph
+            .add_dependency(asset1, AssetReference::by_id(asset2))
+            .unwrap();
+        graph
+            .add_dependency(asset2, AssetReference::by_id(asset1))
+            .unwrap();
 
         assert!(graph.topological_sort().is_err());
     }
@@ -46,6 +46,16 @@ pub enum EventType {
         asset_version: String,
     },
 
+    /// A soft-deleted asset was restored
+    AssetRestored {
+        /// ID of the restored asset
+        asset_id: AssetId,
+        /// Name of the asset
+        asset_name: String,
+        /// Version of the asset
+        asset_version: String,
+    },
+
     /// Asset status changed
     AssetStatusChanged {
         /// ID of the asset
@@ -139,6 +149,7 @@ impl EventType {
             EventType::AssetRegistered { .. } => "asset_registered",
             EventType::AssetUpdated { .. } => "asset_updated",
             EventType::AssetDeleted { .. } => "asset_deleted",
+            EventType::AssetRestored { .. } => "asset_restored",
             EventType::AssetStatusChanged { .. } => "asset_status_changed",
             EventType::AssetDownloaded { .. } => "asset_downloaded",
             EventType::ChecksumVerified { .. } => "checksum_verified",
@@ -156,6 +167,7 @@ impl EventType {
             EventType::AssetRegistered { asset_id, .. }
             | EventType::AssetUpdated { asset_id, .. }
             | EventType::AssetDeleted { asset_id, .. }
+            | EventType::AssetRestored { asset_id, .. }
             | EventType::AssetStatusChanged { asset_id, .. }
             | EventType::AssetDownloaded { asset_id, .. }
             | EventType::ChecksumVerified { asset_id, .. }
@@ -437,7 +449,10 @@ mod tests {
         assert_eq!(event.correlation_id.as_deref(), Some("corr-123"));
         assert_eq!(event.actor.as_deref(), Some("user@example.com"));
         assert_eq!(event.source.as_deref(), Some("api-server"));
-        assert_eq!(event.get_context("request_id"), Some(&"req-456".to_string()));
+        assert_eq!(
+            event.get_context("request_id"),
+            Some(&"req-456".to_string())
+        );
     }
 
     #[test]
@@ -454,8 +469,14 @@ mod tests {
         event.add_context("download_size", "1024");
         event.add_context("download_duration_ms", "150");
 
-        assert_eq!(event.get_context("download_size"), Some(&"1024".to_string()));
-        assert_eq!(event.get_context("download_duration_ms"), Some(&"150".to_string()));
+        assert_eq!(
+            event.get_context("download_size"),
+            Some(&"1024".to_string())
+        );
+        assert_eq!(
+            event.get_context("download_duration_ms"),
+            Some(&"150".to_string())
+        );
     }
 
     #[test]
@@ -11,12 +11,22 @@
 //!       └─ Agent span (one per service invocation)
 //! ```
 
+use crate::export::SpanExportQueue;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
 use ulid::Ulid;
 
+/// Header carrying the execution-wide identifier between services
+pub const HEADER_EXECUTION_ID: &str = "x-execution-id";
+/// Header carrying the caller's span ID between services
+pub const HEADER_PARENT_SPAN_ID: &str = "x-parent-span-id";
+
+tokio::task_local! {
+    static CURRENT_COLLECTOR: SpanCollector;
+}
+
 // ============================================================================
 // Identifiers
 // ============================================================================
@@ -38,6 +48,11 @@ impl SpanId {
             .map(Self)
             .map_err(|e| format!("Invalid SpanId: {}", e))
     }
+
+    /// The id as a 128-bit integer.
+    pub(crate) fn as_u128(&self) -> u128 {
+        u128::from(self.0)
+    }
 }
 
 impl Default for SpanId {
@@ -73,6 +88,97 @@ impl std::fmt::Display for ExecutionId {
     }
 }
 
+/// Parsed W3C Trace Context `traceparent` header
+/// (`version-traceid-parentid-flags`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceParent {
+    /// Format version (`00` for the current specification).
+    pub version: u8,
+    /// 128-bit trace identifier.
+    pub trace_id: u128,
+    /// 64-bit identifier of the caller's span.
+    pub parent_id: u64,
+    /// Trace flags (bit 0 = sampled).
+    pub flags: u8,
+}
+
+impl TraceParent {
+    /// Parse a `traceparent` header value.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = value.trim().split('-').collect();
+        if fields.len() < 4 {
+            return Err("traceparent must have four '-' separated fields".to_string());
+        }
+
+        let version = parse_hex(fields[0], 2, "version")? as u8;
+        if version == 0xff {
+            return Err("traceparent version ff is invalid".to_string());
+        }
+        // Later versions may append fields; version 00 has exactly four
+        if version == 0 && fields.len() != 4 {
+            return Err("traceparent version 00 must have exactly four fields".to_string());
+        }
+
+        let trace_id = parse_hex(fields[1], 32, "trace-id")?;
+        let parent_id = parse_hex(fields[2], 16, "parent-id")? as u64;
+        let flags = parse_hex(fields[3], 2, "flags")? as u8;
+
+        if trace_id == 0 {
+            return Err("traceparent trace-id must not be all zeros".to_string());
+        }
+        if parent_id == 0 {
+            return Err("traceparent parent-id must not be all zeros".to_string());
+        }
+
+        Ok(Self {
+            version,
+            trace_id,
+            parent_id,
+            flags,
+        })
+    }
+
+    /// Execution ID carried by this trace: the trace-id as 32 hex digits.
+    pub fn execution_id(&self) -> ExecutionId {
+        ExecutionId::new(format!("{:032x}", self.trace_id))
+    }
+
+    /// Span ID of the caller's span.
+    pub fn parent_span_id(&self) -> SpanId {
+        SpanId(Ulid::from(self.parent_id as u128))
+    }
+
+    /// Whether the caller sampled this trace (flags bit 0).
+    pub fn sampled(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+}
+
+impl std::fmt::Display for TraceParent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02x}-{:032x}-{:016x}-{:02x}",
+            self.version, self.trace_id, self.parent_id, self.flags
+        )
+    }
+}
+
+/// Parse a fixed-width lowercase hex field.
+fn parse_hex(field: &str, width: usize, name: &str) -> Result<u128, String> {
+    let valid = field.len() == width
+        && field
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+    if !valid {
+        return Err(format!(
+            "traceparent {} must be {} lowercase hex digits",
+            name, width
+        ));
+    }
+    u128::from_str_radix(field, 16).map_err(|e| format!("Invalid traceparent {}: {}", name, e))
+}
+
 // ============================================================================
 // Execution context
 // ============================================================================
@@ -87,6 +193,14 @@ pub struct ExecutionContext {
     pub execution_id: ExecutionId,
     /// The parent span ID from the calling entity (the Core's span).
     pub parent_span_id: SpanId,
+    /// Whether the span tree should be exported to telemetry. The result is
+    /// still built and returned when `false`.
+    #[serde(default = "default_sampled")]
+    pub sampled: bool,
+}
+
+fn default_sampled() -> bool {
+    true
 }
 
 // ============================================================================
@@ -103,6 +217,52 @@ pub enum SpanType {
     Agent,
 }
 
+/// Services that emit agent-level spans.
+///
+/// The span name of an agent span is [`AgentService::as_str`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AgentService {
+    Registration,
+    SourceRegistration,
+    Search,
+    Validation,
+    Integrity,
+    Versioning,
+    Retention,
+}
+
+impl AgentService {
+    /// Every agent service, in a stable order.
+    pub const ALL: &'static [AgentService] = &[
+        AgentService::Registration,
+        AgentService::SourceRegistration,
+        AgentService::Search,
+        AgentService::Validation,
+        AgentService::Integrity,
+        AgentService::Versioning,
+        AgentService::Retention,
+    ];
+
+    /// Span name emitted for this service.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AgentService::Registration => "RegistrationService",
+            AgentService::SourceRegistration => "SourceRegistrationService",
+            AgentService::Search => "SearchService",
+            AgentService::Validation => "ValidationService",
+            AgentService::Integrity => "IntegrityService",
+            AgentService::Versioning => "VersioningService",
+            AgentService::Retention => "RetentionService",
+        }
+    }
+}
+
+impl std::fmt::Display for AgentService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Terminal status of a span.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -111,6 +271,55 @@ pub enum SpanStatus {
     Failed,
 }
 
+/// Span attribute carrying a failed span's [`FailureKind`]
+pub const ATTR_FAILURE_KIND: &str = "failure_kind";
+/// Span attribute carrying a human-readable failure message
+pub const ATTR_FAILURE_REASON: &str = "failure_reason";
+
+/// Category of a span failure, recorded in the `failure_kind` attribute.
+///
+/// Variants are ordered from least to most severe; the repo span reports
+/// the most severe kind among its failed agent spans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// The request was rejected as invalid
+    Validation,
+    /// A referenced resource does not exist
+    NotFound,
+    /// The request conflicts with the current state
+    Conflict,
+    /// An operation did not finish in time
+    Timeout,
+    /// An upstream service was unavailable or failed
+    Dependency,
+    /// Reading or writing persistent storage failed
+    Storage,
+    /// An unexpected internal error
+    Internal,
+}
+
+impl FailureKind {
+    /// Attribute value for this kind.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailureKind::Validation => "validation",
+            FailureKind::NotFound => "not_found",
+            FailureKind::Conflict => "conflict",
+            FailureKind::Timeout => "timeout",
+            FailureKind::Dependency => "dependency",
+            FailureKind::Storage => "storage",
+            FailureKind::Internal => "internal",
+        }
+    }
+}
+
+impl std::fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// An artifact produced by an agent and attached to its span.
 ///
 /// Artifacts MUST only be attached to agent-level spans, never to repo spans.
@@ -144,12 +353,85 @@ pub struct ExecutionSpan {
 }
 
 /// The finalized execution result included in every response.
+///
+/// `spans` is in begin order: the repo span first, then agent spans in the
+/// order they were begun, regardless of when they ended. Spans recorded on
+/// [`ChildCollector`]s are placed among them by `started_at`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionResult {
     pub execution_id: ExecutionId,
     pub spans: Vec<ExecutionSpan>,
 }
 
+impl ExecutionSpan {
+    /// The failure kind recorded on this span, if any.
+    pub fn failure_kind(&self) -> Option<FailureKind> {
+        self.attributes
+            .get(ATTR_FAILURE_KIND)
+            .and_then(|kind| serde_json::from_value(kind.clone()).ok())
+    }
+
+    /// Close the span as failed, recording `kind` and `message`.
+    fn fail(&mut self, kind: FailureKind, message: &str) {
+        self.ended_at = Some(Utc::now());
+        self.status = SpanStatus::Failed;
+        self.attributes.insert(
+            ATTR_FAILURE_KIND.to_string(),
+            serde_json::Value::from(kind.as_str()),
+        );
+        self.attributes.insert(
+            ATTR_FAILURE_REASON.to_string(),
+            serde_json::Value::from(message),
+        );
+    }
+}
+
+impl ExecutionResult {
+    /// The repo-level span at the root of the tree.
+    pub fn root(&self) -> Option<&ExecutionSpan> {
+        self.spans.iter().find(|s| s.span_type == SpanType::Repo)
+    }
+
+    /// Span with the given id.
+    pub fn span(&self, id: SpanId) -> Option<&ExecutionSpan> {
+        self.spans.iter().find(|s| s.span_id == id)
+    }
+
+    /// Direct children of the given span, in start order.
+    pub fn children(&self, id: SpanId) -> Vec<&ExecutionSpan> {
+        self.spans
+            .iter()
+            .filter(|s| s.parent_span_id == id && s.span_id != id)
+            .collect()
+    }
+
+    /// All spans ordered by `started_at`.
+    ///
+    /// The sort is stable, so spans started at the same instant keep their
+    /// order in `spans`. Useful for results that were not produced by a
+    /// [`SpanCollector`], such as trees assembled from several exports.
+    pub fn sorted_by_start(&self) -> Vec<&ExecutionSpan> {
+        let mut spans: Vec<&ExecutionSpan> = self.spans.iter().collect();
+        spans.sort_by_key(|s| s.started_at);
+        spans
+    }
+
+    /// Encode as MessagePack, keeping field names so omitted optional
+    /// fields decode the same way they do from JSON.
+    #[cfg(feature = "msgpack")]
+    pub fn to_msgpack(&self) -> crate::Result<Vec<u8>> {
+        rmp_serde::to_vec_named(self)
+            .map_err(|e| crate::RegistryError::SerializationError(e.to_string()))
+    }
+
+    /// Decode a result produced by [`Self::to_msgpack`].
+    #[cfg(feature = "msgpack")]
+    pub fn from_msgpack(bytes: &[u8]) -> crate::Result<Self> {
+        rmp_serde::from_slice(bytes)
+            .map_err(|e| crate::RegistryError::SerializationError(e.to_string()))
+    }
+}
+
 // ============================================================================
 // Span collector
 // ============================================================================
@@ -169,6 +451,11 @@ struct SpanCollectorInner {
     execution_id: ExecutionId,
     repo_span_id: SpanId,
     spans: Vec<ExecutionSpan>,
+    sampled: bool,
+    finalized: Option<ExecutionResult>,
+    export_queue: Option<SpanExportQueue>,
+    /// Span buffers of child collectors, merged at finalize
+    children: Vec<Arc<Mutex<Vec<ExecutionSpan>>>>,
 }
 
 impl SpanCollector {
@@ -191,18 +478,43 @@ impl SpanCollector {
                 execution_id: ctx.execution_id.clone(),
                 repo_span_id,
                 spans: vec![repo_span],
+                sampled: ctx.sampled,
+                finalized: None,
+                export_queue: None,
+                children: Vec::new(),
             })),
         }
     }
 
+    /// Queue the first finalized result on `queue` when sampled.
+    pub fn with_export_queue(self, queue: SpanExportQueue) -> Self {
+        self.lock().export_queue = Some(queue);
+        self
+    }
+
+    /// Lock the collector state, recovering it if a holder panicked.
+    ///
+    /// Span bookkeeping stays consistent across a panic, so a poisoned lock
+    /// must not fail every later call on a collector shared with others.
+    fn lock(&self) -> MutexGuard<'_, SpanCollectorInner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// Returns the repo-level span ID (used as parent for agent spans).
     pub fn repo_span_id(&self) -> SpanId {
-        self.inner.lock().unwrap().repo_span_id
+        self.lock().repo_span_id
+    }
+
+    /// Whether the execution was sampled for telemetry export.
+    pub fn sampled(&self) -> bool {
+        self.lock().sampled
     }
 
     /// Begin a new agent-level span. Returns its SpanId.
     pub fn begin_agent_span(&self, agent_name: &str) -> SpanId {
-        let mut inner = self.inner.lock().unwrap();
+        let mut inner = self.lock();
         let span_id = SpanId::new();
         let parent_id = inner.repo_span_id;
         inner.spans.push(ExecutionSpan {
@@ -219,21 +531,44 @@ impl SpanCollector {
         span_id
     }
 
+    /// Begin a new agent-level span closed when the returned scope drops.
+    ///
+    /// The span ends `Failed` unless [`SpanScope::set_status`] recorded
+    /// another status, so early returns close it as a failure.
+    pub fn begin_agent_span_scoped(&self, agent_name: &str) -> SpanScope {
+        SpanScope {
+            collector: self.clone(),
+            span_id: self.begin_agent_span(agent_name),
+            status: SpanStatus::Failed,
+        }
+    }
+
     /// Close an agent span with the given status.
     pub fn end_agent_span(&self, span_id: SpanId, status: SpanStatus) {
-        let mut inner = self.inner.lock().unwrap();
+        let mut inner = self.lock();
         if let Some(span) = inner.spans.iter_mut().find(|s| s.span_id == span_id) {
             span.ended_at = Some(Utc::now());
             span.status = status;
         }
     }
 
+    /// Close an agent span as failed, recording why.
+    ///
+    /// `kind` and `message` are stored in the span's `failure_kind` and
+    /// `failure_reason` attributes.
+    pub fn end_agent_span_with_reason(&self, span_id: SpanId, kind: FailureKind, message: &str) {
+        let mut inner = self.lock();
+        if let Some(span) = inner.spans.iter_mut().find(|s| s.span_id == span_id) {
+            span.fail(kind, message);
+        }
+    }
+
     /// Attach an artifact to an agent span.
     ///
     /// Returns an error if the target span is a repo span (artifacts MUST
     /// only be attached at the agent level).
     pub fn attach_artifact(&self, span_id: SpanId, artifact: SpanArtifact) -> Result<(), String> {
-        let mut inner = self.inner.lock().unwrap();
+        let mut inner = self.lock();
         let span = inner
             .spans
             .iter_mut()
@@ -246,23 +581,108 @@ impl SpanCollector {
         Ok(())
     }
 
+    /// Serialize `data` and attach it as a JSON artifact that must not be empty.
+    ///
+    /// Unlike [`attach_artifact`](Self::attach_artifact), a serialization
+    /// failure is returned instead of being stored as `null`, and a value
+    /// that serializes to `null` is rejected.
+    pub fn attach_artifact_checked<T: Serialize + ?Sized>(
+        &self,
+        span_id: SpanId,
+        name: &str,
+        data: &T,
+    ) -> Result<(), String> {
+        let data = serde_json::to_value(data)
+            .map_err(|e| format!("Failed to serialize artifact '{}': {}", name, e))?;
+        if data.is_null() {
+            return Err(format!("Artifact '{}' must not be null", name));
+        }
+        self.attach_artifact(
+            span_id,
+            SpanArtifact {
+                name: name.to_string(),
+                content_type: Some("application/json".to_string()),
+                data,
+            },
+        )
+    }
+
+    /// Set a single attribute on a repo or agent span.
+    ///
+    /// Returns an error if the span does not exist. An existing value for
+    /// `key` is overwritten.
+    pub fn set_attribute(
+        &self,
+        span_id: SpanId,
+        key: &str,
+        value: serde_json::Value,
+    ) -> Result<(), String> {
+        self.set_attributes(span_id, [(key.to_string(), value)])
+    }
+
+    /// Set several attributes on a repo or agent span at once.
+    ///
+    /// Returns an error if the span does not exist, in which case no
+    /// attribute is written.
+    pub fn set_attributes(
+        &self,
+        span_id: SpanId,
+        attributes: impl IntoIterator<Item = (String, serde_json::Value)>,
+    ) -> Result<(), String> {
+        let mut inner = self.lock();
+        let span = inner
+            .spans
+            .iter_mut()
+            .find(|s| s.span_id == span_id)
+            .ok_or_else(|| format!("Span not found: {}", span_id))?;
+        span.attributes.extend(attributes);
+        Ok(())
+    }
+
+    /// Create a child collector for a concurrent subtask.
+    ///
+    /// Its agent spans are children of the repo span. See [`ChildCollector`].
+    pub fn child(&self) -> ChildCollector {
+        let parent_span_id = self.repo_span_id();
+        self.child_of(parent_span_id)
+    }
+
+    /// Create a child collector whose agent spans are children of `parent`.
+    pub fn child_of(&self, parent: SpanId) -> ChildCollector {
+        let spans = Arc::new(Mutex::new(Vec::new()));
+        self.lock().children.push(Arc::clone(&spans));
+        ChildCollector {
+            parent_span_id: parent,
+            spans,
+        }
+    }
+
     /// Returns `true` if at least one agent-level span has been recorded.
     pub fn has_agent_spans(&self) -> bool {
-        let inner = self.inner.lock().unwrap();
+        let inner = self.lock();
         inner.spans.iter().any(|s| s.span_type == SpanType::Agent)
     }
 
     /// Finalize the collector: close the repo span, propagate failure status,
     /// and return the complete execution result.
     ///
-    /// If any agent span has status `Failed`, the repo span is also marked
-    /// `Failed`.
+    /// Spans are returned in begin order (see [`ExecutionResult`]); ending a
+    /// span never moves it.
+    ///
+    /// Spans buffered by [`ChildCollector`]s are merged in first, ordered by
+    /// `started_at`.
+    ///
+    /// Agent spans that were never ended are closed as `Failed` with an
+    /// `auto_closed` attribute. If any agent span has status `Failed`, the
+    /// repo span is also marked `Failed`, and the most severe
+    /// [`FailureKind`] among them is copied to its `failure_kind` attribute.
+    /// Spans whose parent is not in the tree are reported in a
+    /// `tree_integrity` attribute on the repo span.
     pub fn finalize(&self) -> ExecutionResult {
-        let mut inner = self.inner.lock().unwrap();
-        let any_failed = inner
-            .spans
-            .iter()
-            .any(|s| s.status == SpanStatus::Failed);
+        let mut inner = self.lock();
+        inner.merge_children();
+        inner.close_stalled_spans();
+        let any_failed = inner.spans.iter().any(|s| s.status == SpanStatus::Failed);
         // Close repo span
         if let Some(repo) = inner.spans.first_mut() {
             repo.ended_at = Some(Utc::now());
@@ -270,26 +690,305 @@ impl SpanCollector {
                 repo.status = SpanStatus::Failed;
             }
         }
-        ExecutionResult {
-            execution_id: inner.execution_id.clone(),
-            spans: inner.spans.clone(),
-        }
+        inner.propagate_failure_kind();
+        inner.store_finalized()
     }
 
     /// Finalize with an explicit failure status on the repo span.
     pub fn finalize_failed(&self, reason: &str) -> ExecutionResult {
-        let mut inner = self.inner.lock().unwrap();
+        let mut inner = self.lock();
+        inner.merge_children();
+        inner.close_stalled_spans();
         if let Some(repo) = inner.spans.first_mut() {
             repo.ended_at = Some(Utc::now());
             repo.status = SpanStatus::Failed;
             repo.attributes.insert(
-                "failure_reason".to_string(),
+                ATTR_FAILURE_REASON.to_string(),
                 serde_json::Value::String(reason.to_string()),
             );
         }
-        ExecutionResult {
-            execution_id: inner.execution_id.clone(),
-            spans: inner.spans.clone(),
+        inner.propagate_failure_kind();
+        inner.store_finalized()
+    }
+
+    /// The result of the most recent `finalize` / `finalize_failed` call,
+    /// or `None` if the collector has not been finalized yet.
+    pub fn finalized(&self) -> Option<ExecutionResult> {
+        self.lock().finalized.clone()
+    }
+
+    /// The execution this collector records.
+    pub fn execution_id(&self) -> ExecutionId {
+        self.lock().execution_id.clone()
+    }
+
+    /// The most recently begun agent span that has not ended yet.
+    pub fn current_agent_span(&self) -> Option<SpanId> {
+        let inner = self.lock();
+        inner
+            .spans
+            .iter()
+            .rev()
+            .find(|s| s.span_type == SpanType::Agent && s.ended_at.is_none())
+            .map(|s| s.span_id)
+    }
+
+    /// Headers continuing this execution in an outbound call.
+    ///
+    /// The parent span is the current agent span, or the repo span when no
+    /// agent span is open.
+    pub fn outbound_headers(&self) -> [(&'static str, String); 2] {
+        let parent = self
+            .current_agent_span()
+            .unwrap_or_else(|| self.repo_span_id());
+        [
+            (HEADER_EXECUTION_ID, self.execution_id().to_string()),
+            (HEADER_PARENT_SPAN_ID, parent.to_string()),
+        ]
+    }
+
+    /// Run a future with this collector as the current task's collector.
+    pub async fn scope<F: std::future::Future>(self, future: F) -> F::Output {
+        CURRENT_COLLECTOR.scope(self, future).await
+    }
+
+    /// Outbound headers of the current task's collector, if it runs inside
+    /// [`SpanCollector::scope`].
+    pub fn current_outbound_headers() -> Option<[(&'static str, String); 2]> {
+        CURRENT_COLLECTOR
+            .try_with(SpanCollector::outbound_headers)
+            .ok()
+    }
+}
+
+/// RAII guard for an agent span, returned by
+/// [`SpanCollector::begin_agent_span_scoped`].
+///
+/// Dropping the scope ends the span with the recorded status unless it was
+/// already ended. Drop it before finalizing the collector.
+#[derive(Debug)]
+pub struct SpanScope {
+    collector: SpanCollector,
+    span_id: SpanId,
+    status: SpanStatus,
+}
+
+impl SpanScope {
+    /// The guarded span's ID.
+    pub fn span_id(&self) -> SpanId {
+        self.span_id
+    }
+
+    /// Status the span is ended with when the scope drops.
+    pub fn set_status(&mut self, status: SpanStatus) {
+        self.status = status;
+    }
+
+    /// Attach an artifact to the guarded span.
+    pub fn attach_artifact(&self, artifact: SpanArtifact) -> Result<(), String> {
+        self.collector.attach_artifact(self.span_id, artifact)
+    }
+}
+
+impl Drop for SpanScope {
+    fn drop(&mut self) {
+        // A panic unwinding through the scope always fails the span
+        let status = if std::thread::panicking() {
+            SpanStatus::Failed
+        } else {
+            self.status
+        };
+        let mut inner = self.collector.lock();
+        if let Some(span) = inner
+            .spans
+            .iter_mut()
+            .find(|s| s.span_id == self.span_id && s.ended_at.is_none())
+        {
+            span.ended_at = Some(Utc::now());
+            span.status = status;
+        }
+    }
+}
+
+/// Span recorder for one concurrent subtask of a request.
+///
+/// Obtained from [`SpanCollector::child`]. Each child buffers its spans
+/// behind its own lock, so subtasks joined with `tokio::join!` do not
+/// contend on the request's collector. The buffered spans join the tree
+/// when the parent collector is finalized, ordered by `started_at`.
+#[derive(Debug, Clone)]
+pub struct ChildCollector {
+    parent_span_id: SpanId,
+    spans: Arc<Mutex<Vec<ExecutionSpan>>>,
+}
+
+impl ChildCollector {
+    fn lock(&self) -> MutexGuard<'_, Vec<ExecutionSpan>> {
+        self.spans
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Span this child's agent spans are parented to.
+    pub fn parent_span_id(&self) -> SpanId {
+        self.parent_span_id
+    }
+
+    /// Begin a new agent-level span. Returns its SpanId.
+    pub fn begin_agent_span(&self, agent_name: &str) -> SpanId {
+        let span_id = SpanId::new();
+        self.lock().push(ExecutionSpan {
+            span_id,
+            parent_span_id: self.parent_span_id,
+            span_type: SpanType::Agent,
+            name: agent_name.to_string(),
+            started_at: Utc::now(),
+            ended_at: None,
+            status: SpanStatus::Ok,
+            artifacts: vec![],
+            attributes: HashMap::new(),
+        });
+        span_id
+    }
+
+    /// Close an agent span begun on this child with the given status.
+    pub fn end_agent_span(&self, span_id: SpanId, status: SpanStatus) {
+        if let Some(span) = self.lock().iter_mut().find(|s| s.span_id == span_id) {
+            span.ended_at = Some(Utc::now());
+            span.status = status;
+        }
+    }
+
+    /// Close an agent span begun on this child as failed, recording why.
+    pub fn end_agent_span_with_reason(&self, span_id: SpanId, kind: FailureKind, message: &str) {
+        if let Some(span) = self.lock().iter_mut().find(|s| s.span_id == span_id) {
+            span.fail(kind, message);
+        }
+    }
+
+    /// Attach an artifact to an agent span begun on this child.
+    pub fn attach_artifact(&self, span_id: SpanId, artifact: SpanArtifact) -> Result<(), String> {
+        let mut spans = self.lock();
+        let span = spans
+            .iter_mut()
+            .find(|s| s.span_id == span_id)
+            .ok_or_else(|| format!("Span not found: {}", span_id))?;
+        span.artifacts.push(artifact);
+        Ok(())
+    }
+
+    /// Set a single attribute on an agent span begun on this child.
+    pub fn set_attribute(
+        &self,
+        span_id: SpanId,
+        key: &str,
+        value: serde_json::Value,
+    ) -> Result<(), String> {
+        let mut spans = self.lock();
+        let span = spans
+            .iter_mut()
+            .find(|s| s.span_id == span_id)
+            .ok_or_else(|| format!("Span not found: {}", span_id))?;
+        span.attributes.insert(key.to_string(), value);
+        Ok(())
+    }
+}
+
+impl SpanCollectorInner {
+    /// Move spans buffered by child collectors into the tree
+    ///
+    /// Spans after the repo span are re-sorted by `started_at`; the sort is
+    /// stable, so spans started at the same instant keep their order.
+    fn merge_children(&mut self) {
+        let mut merged = false;
+        for child in &self.children {
+            let mut spans = child
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            merged |= !spans.is_empty();
+            self.spans.append(&mut spans);
+        }
+        if merged {
+            self.spans[1..].sort_by_key(|s| s.started_at);
+        }
+    }
+
+    /// Copy the most severe agent failure kind to the repo span
+    fn propagate_failure_kind(&mut self) {
+        let worst = self
+            .spans
+            .iter()
+            .filter(|s| s.span_type != SpanType::Repo && s.status == SpanStatus::Failed)
+            .filter_map(ExecutionSpan::failure_kind)
+            .max();
+        if let (Some(kind), Some(repo)) = (worst, self.spans.first_mut()) {
+            repo.attributes.insert(
+                ATTR_FAILURE_KIND.to_string(),
+                serde_json::Value::from(kind.as_str()),
+            );
+        }
+    }
+
+    /// Snapshot the spans as the finalized result
+    fn store_finalized(&mut self) -> ExecutionResult {
+        self.check_tree_integrity();
+        let result = ExecutionResult {
+            execution_id: self.execution_id.clone(),
+            spans: self.spans.clone(),
+        };
+        if self.finalized.is_none() && self.sampled {
+            if let Some(ref queue) = self.export_queue {
+                queue.push(result.clone());
+            }
+        }
+        self.finalized = Some(result.clone());
+        result
+    }
+
+    /// Fail agent spans that were never ended
+    fn close_stalled_spans(&mut self) {
+        let now = Utc::now();
+        for span in self
+            .spans
+            .iter_mut()
+            .filter(|s| s.span_type != SpanType::Repo && s.ended_at.is_none())
+        {
+            tracing::warn!(
+                execution_id = %self.execution_id.as_str(),
+                span_id = %span.span_id,
+                span = %span.name,
+                "Span was never ended; closing it as failed"
+            );
+            span.ended_at = Some(now);
+            span.status = SpanStatus::Failed;
+            span.attributes
+                .insert("auto_closed".to_string(), serde_json::Value::Bool(true));
+        }
+    }
+
+    /// Flag non-repo spans whose parent is not part of the tree
+    fn check_tree_integrity(&mut self) {
+        let span_ids: HashSet<SpanId> = self.spans.iter().map(|s| s.span_id).collect();
+        let orphans: Vec<String> = self
+            .spans
+            .iter()
+            .filter(|s| s.span_type != SpanType::Repo && !span_ids.contains(&s.parent_span_id))
+            .map(|s| s.span_id.to_string())
+            .collect();
+        if orphans.is_empty() {
+            return;
+        }
+
+        tracing::warn!(
+            execution_id = %self.execution_id.as_str(),
+            orphans = ?orphans,
+            "Execution span tree contains spans without a parent"
+        );
+        if let Some(repo) = self.spans.first_mut() {
+            repo.attributes.insert(
+                "tree_integrity".to_string(),
+                serde_json::json!({ "orphaned_spans": orphans }),
+            );
         }
     }
 }
@@ -302,10 +1001,77 @@ impl SpanCollector {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_traceparent_round_trip() {
+        let header = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+        let parent = TraceParent::parse(header).unwrap();
+
+        assert_eq!(parent.version, 0);
+        assert_eq!(parent.parent_id, 0x00f0_67aa_0ba9_02b7);
+        assert_eq!(parent.flags, 1);
+        assert_eq!(
+            parent.execution_id().as_str(),
+            "4bf92f3577b34da6a3ce929d0e0e4736"
+        );
+        assert_eq!(parent.to_string(), header);
+        assert_eq!(
+            parent.parent_span_id().0,
+            Ulid::from(0x00f0_67aa_0ba9_02b7_u128)
+        );
+    }
+
+    #[test]
+    fn test_traceparent_rejects_malformed() {
+        for header in [
+            "",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7",
+            "00-4BF92F3577B34DA6A3CE929D0E0E4736-00f067aa0ba902b7-01",
+            "00-00000000000000000000000000000000-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01",
+            "ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra",
+            "00-4bf92f3577b34da6a3ce929d0e0e47-00f067aa0ba902b7-01",
+        ] {
+            assert!(TraceParent::parse(header).is_err(), "{:?}", header);
+        }
+
+        // Future versions may carry extra fields
+        assert!(TraceParent::parse(
+            "01-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01-extra"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_sampling_flag() {
+        let sampled =
+            TraceParent::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+        let unsampled =
+            TraceParent::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-00").unwrap();
+        assert!(sampled.sampled());
+        assert!(!unsampled.sampled());
+
+        // Contexts serialized before the flag existed are sampled
+        let ctx: ExecutionContext = serde_json::from_value(serde_json::json!({
+            "execution_id": "exec-1",
+            "parent_span_id": SpanId::new(),
+        }))
+        .unwrap();
+        assert!(ctx.sampled);
+
+        let collector = SpanCollector::new(&ExecutionContext {
+            sampled: false,
+            ..ctx
+        });
+        assert!(!collector.sampled());
+        assert_eq!(collector.finalize().spans.len(), 1);
+    }
+
     fn test_context() -> ExecutionContext {
         ExecutionContext {
             execution_id: ExecutionId::new("test-exec-001"),
             parent_span_id: SpanId::new(),
+            sampled: true,
         }
     }
 
@@ -336,6 +1102,40 @@ mod tests {
         assert!(result.spans[0].ended_at.is_some());
     }
 
+    #[tokio::test]
+    async fn test_outbound_headers_follow_current_agent_span() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        assert!(SpanCollector::current_outbound_headers().is_none());
+
+        let headers = |h: [(&'static str, String); 2]| -> (ExecutionId, SpanId) {
+            assert_eq!(h[0].0, HEADER_EXECUTION_ID);
+            assert_eq!(h[1].0, HEADER_PARENT_SPAN_ID);
+            (
+                ExecutionId::new(h[0].1.clone()),
+                SpanId::from_string(&h[1].1).unwrap(),
+            )
+        };
+
+        // Without an open agent span the repo span is the parent
+        let (execution_id, parent) = headers(collector.outbound_headers());
+        assert_eq!(execution_id, ctx.execution_id);
+        assert_eq!(parent, collector.repo_span_id());
+
+        let outer = collector.begin_agent_span("RegistrationService");
+        let inner = collector.begin_agent_span("ValidationService");
+        let scoped = collector
+            .clone()
+            .scope(async { SpanCollector::current_outbound_headers().unwrap() })
+            .await;
+        let (execution_id, parent) = headers(scoped);
+        assert_eq!(execution_id, ctx.execution_id);
+        assert_eq!(parent, inner);
+
+        collector.end_agent_span(inner, SpanStatus::Ok);
+        assert_eq!(headers(collector.outbound_headers()).1, outer);
+    }
+
     #[test]
     fn test_collector_agent_span_lifecycle() {
         let ctx = test_context();
@@ -372,6 +1172,22 @@ mod tests {
         assert_eq!(result.spans[0].status, SpanStatus::Failed);
     }
 
+    #[test]
+    fn test_finalized_result_is_retained() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        assert!(collector.finalized().is_none());
+
+        let agent_id = collector.begin_agent_span("RegistrationService");
+        collector.end_agent_span(agent_id, SpanStatus::Ok);
+        let result = collector.finalize();
+
+        let retained = collector.clone().finalized().unwrap();
+        assert_eq!(retained.execution_id, result.execution_id);
+        assert_eq!(retained.spans.len(), 2);
+        assert!(retained.spans[0].ended_at.is_some());
+    }
+
     #[test]
     fn test_attach_artifact_to_agent_span() {
         let ctx = test_context();
@@ -405,6 +1221,146 @@ mod tests {
         assert!(collector.attach_artifact(repo_id, artifact).is_err());
     }
 
+    #[test]
+    fn test_attach_artifact_checked_rejects_null() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        let agent_id = collector.begin_agent_span("SearchService");
+
+        let err = collector
+            .attach_artifact_checked(agent_id, "search_results", &Option::<u32>::None)
+            .unwrap_err();
+        assert!(err.contains("search_results"));
+
+        let err = collector
+            .attach_artifact_checked(agent_id, "search_results", &serde_json::Value::Null)
+            .unwrap_err();
+        assert!(err.contains("must not be null"));
+
+        collector
+            .attach_artifact_checked(agent_id, "search_results", &serde_json::json!({"count": 5}))
+            .unwrap();
+        let result = collector.finalize();
+        assert_eq!(result.spans[1].artifacts.len(), 1);
+        assert_eq!(
+            result.spans[1].artifacts[0].content_type.as_deref(),
+            Some("application/json")
+        );
+    }
+
+    #[test]
+    fn test_attach_artifact_checked_surfaces_serialization_error() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        let agent_id = collector.begin_agent_span("SearchService");
+
+        // JSON object keys must be strings
+        let data: HashMap<(u8, u8), u8> = HashMap::from([((1, 2), 3)]);
+        let err = collector
+            .attach_artifact_checked(agent_id, "comparison", &data)
+            .unwrap_err();
+        assert!(err.starts_with("Failed to serialize artifact 'comparison'"));
+        assert!(collector.finalize().spans[1].artifacts.is_empty());
+    }
+
+    #[test]
+    fn test_set_attributes_survive_finalize() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        let agent_id = collector.begin_agent_span("RegistrationService");
+
+        collector
+            .set_attribute(agent_id, "asset_name", serde_json::json!("gpt-x"))
+            .unwrap();
+        collector
+            .set_attributes(
+                agent_id,
+                [
+                    ("asset_count".to_string(), serde_json::json!(3)),
+                    ("asset_name".to_string(), serde_json::json!("gpt-y")),
+                ],
+            )
+            .unwrap();
+        collector
+            .set_attribute(
+                collector.repo_span_id(),
+                "tenant",
+                serde_json::json!("acme"),
+            )
+            .unwrap();
+        collector.end_agent_span(agent_id, SpanStatus::Ok);
+
+        let result = collector.finalize();
+        assert_eq!(result.spans[0].attributes["tenant"], "acme");
+        let agent = &result.spans[1];
+        assert_eq!(agent.attributes.len(), 2);
+        assert_eq!(agent.attributes["asset_name"], "gpt-y");
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["spans"][1]["attributes"]["asset_count"], 3);
+    }
+
+    #[test]
+    fn test_failure_kind_propagates_to_repo_span() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        let validation = collector.begin_agent_span("ValidationService");
+        collector.end_agent_span_with_reason(validation, FailureKind::Validation, "missing name");
+        let child = collector.child();
+        let registration = child.begin_agent_span("RegistrationService");
+        child.end_agent_span_with_reason(registration, FailureKind::Storage, "disk full");
+        let search = collector.begin_agent_span("SearchService");
+        collector.end_agent_span(search, SpanStatus::Failed);
+
+        let result = collector.finalize();
+        let repo = result.root().unwrap();
+        assert_eq!(repo.status, SpanStatus::Failed);
+        assert_eq!(repo.failure_kind(), Some(FailureKind::Storage));
+
+        let validation = result.span(validation).unwrap();
+        assert_eq!(validation.status, SpanStatus::Failed);
+        assert_eq!(validation.failure_kind(), Some(FailureKind::Validation));
+        assert_eq!(validation.attributes[ATTR_FAILURE_REASON], "missing name");
+        assert_eq!(result.span(search).unwrap().failure_kind(), None);
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["spans"][0]["attributes"]["failure_kind"], "storage");
+        let decoded: ExecutionResult = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            decoded.root().unwrap().failure_kind(),
+            Some(FailureKind::Storage)
+        );
+        assert_eq!(
+            serde_json::to_value(FailureKind::NotFound).unwrap(),
+            serde_json::json!(FailureKind::NotFound.as_str())
+        );
+    }
+
+    #[test]
+    fn test_untyped_failures_leave_repo_span_without_kind() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        let span = collector.begin_agent_span("SearchService");
+        collector.end_agent_span(span, SpanStatus::Failed);
+
+        let result = collector.finalize();
+        assert_eq!(result.root().unwrap().status, SpanStatus::Failed);
+        assert!(!result
+            .root()
+            .unwrap()
+            .attributes
+            .contains_key(ATTR_FAILURE_KIND));
+    }
+
+    #[test]
+    fn test_set_attribute_unknown_span_rejected() {
+        let collector = SpanCollector::new(&test_context());
+        let err = collector
+            .set_attribute(SpanId::new(), "key", serde_json::json!(true))
+            .unwrap_err();
+        assert!(err.contains("Span not found"));
+    }
+
     #[test]
     fn test_execution_result_serialization() {
         let ctx = test_context();
@@ -435,4 +1391,312 @@ mod tests {
         assert_eq!(result.spans[1].name, "ValidationService");
         assert_eq!(result.spans[2].name, "RegistrationService");
     }
+
+    #[test]
+    fn test_collector_survives_poisoned_lock() {
+        let collector = SpanCollector::new(&test_context());
+        let shared = collector.clone();
+        let panicked = std::thread::spawn(move || {
+            let _guard = shared.inner.lock().unwrap();
+            panic!("request handler panicked while holding the collector");
+        })
+        .join();
+        assert!(panicked.is_err());
+        assert!(collector.inner.is_poisoned());
+
+        let agent = collector.begin_agent_span("RegistrationService");
+        collector.end_agent_span(agent, SpanStatus::Ok);
+        let result = collector.finalize();
+        assert_eq!(result.spans.len(), 2);
+        assert_eq!(result.spans[1].status, SpanStatus::Ok);
+    }
+
+    #[test]
+    fn test_span_scope_ends_with_status_on_drop() {
+        let collector = SpanCollector::new(&test_context());
+        let span_id = {
+            let mut scope = collector.begin_agent_span_scoped("SearchService");
+            scope
+                .attach_artifact(SpanArtifact {
+                    name: "result".to_string(),
+                    content_type: None,
+                    data: serde_json::json!(1),
+                })
+                .unwrap();
+            scope.set_status(SpanStatus::Ok);
+            scope.span_id()
+        };
+
+        let result = collector.finalize();
+        let span = result.span(span_id).unwrap();
+        assert_eq!(span.status, SpanStatus::Ok);
+        assert!(span.ended_at.is_some());
+        assert_eq!(span.artifacts.len(), 1);
+        assert!(!span.attributes.contains_key("auto_closed"));
+    }
+
+    #[test]
+    fn test_span_scope_fails_span_on_early_return() {
+        fn lookup(collector: &SpanCollector, id: Option<u32>) -> Result<u32, String> {
+            let mut scope = collector.begin_agent_span_scoped("SearchService");
+            let found = id.ok_or_else(|| "missing".to_string())?;
+            scope.set_status(SpanStatus::Ok);
+            Ok(found)
+        }
+
+        let collector = SpanCollector::new(&test_context());
+        assert!(lookup(&collector, None).is_err());
+        assert_eq!(lookup(&collector, Some(7)), Ok(7));
+
+        let result = collector.finalize();
+        assert_eq!(result.spans[1].status, SpanStatus::Failed);
+        assert!(result.spans[1].ended_at.is_some());
+        assert_eq!(result.spans[2].status, SpanStatus::Ok);
+        assert_eq!(result.root().unwrap().status, SpanStatus::Failed);
+    }
+
+    #[test]
+    fn test_span_scope_fails_span_on_panic() {
+        let collector = SpanCollector::new(&test_context());
+        let shared = collector.clone();
+        let panicked = std::thread::spawn(move || {
+            let mut scope = shared.begin_agent_span_scoped("IntegrityService");
+            scope.set_status(SpanStatus::Ok);
+            panic!("integrity check panicked");
+        })
+        .join();
+        assert!(panicked.is_err());
+
+        let result = collector.finalize();
+        assert_eq!(result.spans[1].status, SpanStatus::Failed);
+        assert!(result.spans[1].ended_at.is_some());
+    }
+
+    #[test]
+    fn test_finalize_flags_orphaned_spans() {
+        let collector = SpanCollector::new(&test_context());
+        let agent = collector.begin_agent_span("ValidationService");
+        let orphan = SpanId::new();
+        {
+            let mut inner = collector.inner.lock().unwrap();
+            let mut span = inner.spans[1].clone();
+            span.span_id = orphan;
+            span.parent_span_id = SpanId::new();
+            inner.spans.push(span);
+        }
+
+        let result = collector.finalize();
+        let integrity = &result.root().unwrap().attributes["tree_integrity"];
+        assert_eq!(
+            integrity["orphaned_spans"],
+            serde_json::json!([orphan.to_string()])
+        );
+        assert!(!integrity.to_string().contains(&agent.to_string()));
+    }
+
+    #[test]
+    fn test_well_formed_tree_has_no_integrity_attribute() {
+        let collector = SpanCollector::new(&test_context());
+        let agent = collector.begin_agent_span("ValidationService");
+        collector.end_agent_span(agent, SpanStatus::Ok);
+
+        let result = collector.finalize_failed("boom");
+        assert!(!result
+            .root()
+            .unwrap()
+            .attributes
+            .contains_key("tree_integrity"));
+    }
+
+    #[test]
+    fn test_finalize_closes_stalled_span() {
+        let collector = SpanCollector::new(&test_context());
+        let stalled = collector.begin_agent_span("RegistrationService");
+
+        let result = collector.finalize();
+        let span = result.span(stalled).unwrap();
+        assert!(span.ended_at.is_some());
+        assert_eq!(span.status, SpanStatus::Failed);
+        assert_eq!(span.attributes["auto_closed"], serde_json::json!(true));
+        assert_eq!(result.root().unwrap().status, SpanStatus::Failed);
+    }
+
+    #[test]
+    fn test_finalize_closes_only_stalled_spans() {
+        let collector = SpanCollector::new(&test_context());
+        let done = collector.begin_agent_span("ValidationService");
+        collector.end_agent_span(done, SpanStatus::Ok);
+        let stalled = collector.begin_agent_span("RegistrationService");
+        let also_done = collector.begin_agent_span("IntegrityService");
+        collector.end_agent_span(also_done, SpanStatus::Ok);
+
+        let result = collector.finalize();
+        for id in [done, also_done] {
+            let span = result.span(id).unwrap();
+            assert_eq!(span.status, SpanStatus::Ok);
+            assert!(!span.attributes.contains_key("auto_closed"));
+        }
+        assert_eq!(result.span(stalled).unwrap().status, SpanStatus::Failed);
+        assert_eq!(result.root().unwrap().status, SpanStatus::Failed);
+        assert!(result.spans.iter().all(|s| s.ended_at.is_some()));
+    }
+
+    #[test]
+    fn test_spans_keep_begin_order() {
+        let pause = || std::thread::sleep(std::time::Duration::from_millis(2));
+        let collector = SpanCollector::new(&test_context());
+        let child = collector.child();
+
+        let a = collector.begin_agent_span("a");
+        pause();
+        let x = child.begin_agent_span("x");
+        pause();
+        let b = collector.begin_agent_span("b");
+        collector.end_agent_span(a, SpanStatus::Ok);
+        pause();
+        let c = collector.begin_agent_span("c");
+        collector.end_agent_span(c, SpanStatus::Ok);
+        child.end_agent_span(x, SpanStatus::Ok);
+        collector.end_agent_span(b, SpanStatus::Ok);
+
+        let result = collector.finalize();
+        let names = |spans: Vec<&ExecutionSpan>| -> Vec<String> {
+            spans.into_iter().map(|s| s.name.clone()).collect()
+        };
+        let expected = ["llm-registry", "a", "x", "b", "c"];
+        assert_eq!(names(result.spans.iter().collect()), expected);
+        assert_eq!(names(result.sorted_by_start()), expected);
+        assert_eq!(result.spans[2].span_id, x);
+
+        // Decoded results may arrive in any order
+        let mut shuffled = result.clone();
+        shuffled.spans.reverse();
+        assert_eq!(names(shuffled.sorted_by_start()), expected);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_child_collectors_record_concurrent_spans() {
+        let collector = SpanCollector::new(&test_context());
+        let fan_out = collector.begin_agent_span("search");
+
+        let tasks: Vec<_> = (0..8)
+            .map(|i| {
+                // Half the subtasks nest under the fan-out span
+                let child = if i % 2 == 0 {
+                    collector.child_of(fan_out)
+                } else {
+                    collector.child()
+                };
+                tokio::spawn(async move {
+                    for step in 0..3 {
+                        let span_id = child.begin_agent_span(&format!("task-{}-{}", i, step));
+                        tokio::task::yield_now().await;
+                        child.end_agent_span(span_id, SpanStatus::Ok);
+                    }
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+        collector.end_agent_span(fan_out, SpanStatus::Ok);
+
+        let result = collector.finalize();
+        let repo_id = collector.repo_span_id();
+        assert_eq!(result.spans.len(), 2 + 8 * 3);
+        assert_eq!(result.spans[0].span_id, repo_id);
+        assert_eq!(result.root().unwrap().status, SpanStatus::Ok);
+        assert!(!result
+            .root()
+            .unwrap()
+            .attributes
+            .contains_key("tree_integrity"));
+        assert!(result.spans[1..]
+            .windows(2)
+            .all(|pair| pair[0].started_at <= pair[1].started_at));
+
+        for i in 0..8 {
+            let expected_parent = if i % 2 == 0 { fan_out } else { repo_id };
+            for step in 0..3 {
+                let name = format!("task-{}-{}", i, step);
+                let span = result.spans.iter().find(|s| s.name == name).unwrap();
+                assert_eq!(span.parent_span_id, expected_parent, "{}", name);
+                assert_eq!(span.span_type, SpanType::Agent);
+                assert!(span.ended_at.is_some(), "{}", name);
+            }
+        }
+        assert_eq!(result.children(fan_out).len(), 4 * 3);
+
+        // Merged spans are not duplicated by a second finalize
+        assert_eq!(collector.finalize().spans.len(), result.spans.len());
+    }
+
+    #[test]
+    fn test_execution_result_lookup() {
+        let ctx = test_context();
+        let collector = SpanCollector::new(&ctx);
+        let a1 = collector.begin_agent_span("ValidationService");
+        collector.end_agent_span(a1, SpanStatus::Ok);
+        let a2 = collector.begin_agent_span("RegistrationService");
+        collector.end_agent_span(a2, SpanStatus::Ok);
+        let result = collector.finalize();
+
+        let root = result.root().unwrap();
+        assert_eq!(root.span_type, SpanType::Repo);
+        assert_eq!(root.parent_span_id, ctx.parent_span_id);
+
+        assert_eq!(result.span(a2).unwrap().name, "RegistrationService");
+        assert!(result.span(SpanId::new()).is_none());
+
+        let children: Vec<&str> = result
+            .children(root.span_id)
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(children, ["ValidationService", "RegistrationService"]);
+        assert!(result.children(a1).is_empty());
+        // The caller's parent span is outside the result
+        assert_eq!(result.children(ctx.parent_span_id).len(), 1);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[test]
+    fn test_msgpack_round_trip_matches_json() {
+        let collector = SpanCollector::new(&test_context());
+        let agent = collector.begin_agent_span("SearchService");
+        collector
+            .attach_artifact(
+                agent,
+                SpanArtifact {
+                    name: "query".to_string(),
+                    content_type: Some("application/json".to_string()),
+                    data: serde_json::json!({ "limit": 20, "tags": ["nlp"], "score": 0.5 }),
+                },
+            )
+            .unwrap();
+        collector
+            .set_attribute(agent, "rows", serde_json::json!(3))
+            .unwrap();
+        collector.end_agent_span(agent, SpanStatus::Ok);
+        let result = collector.finalize();
+
+        let bytes = result.to_msgpack().unwrap();
+        let decoded = ExecutionResult::from_msgpack(&bytes).unwrap();
+        assert_eq!(
+            serde_json::to_value(&decoded).unwrap(),
+            serde_json::to_value(&result).unwrap()
+        );
+        assert!(bytes.len() < serde_json::to_vec(&result).unwrap().len());
+
+        // Timestamps keep their sub-second precision
+        for (original, decoded) in result.spans.iter().zip(&decoded.spans) {
+            assert_eq!(decoded.started_at, original.started_at);
+            assert_eq!(decoded.ended_at, original.ended_at);
+        }
+
+        assert!(matches!(
+            ExecutionResult::from_msgpack(b"not msgpack"),
+            Err(crate::RegistryError::SerializationError(_))
+        ));
+    }
 }
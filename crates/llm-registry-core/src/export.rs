@@ -0,0 +1,327 @@
+//! Batched, non-blocking export of execution results
+//!
+//! A [`SpanExportQueue`] takes finalized [`ExecutionResult`]s off the request
+//! path: [`SpanExportQueue::push`] never blocks, and a background task hands
+//! queued results to a [`SpanExporter`] in batches. When the queue is full
+//! the oldest queued result is dropped and counted.
+
+use crate::execution::ExecutionResult;
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tokio::task::JoinHandle;
+
+/// Destination for batches of execution results
+#[async_trait]
+pub trait SpanExporter: Send + Sync + 'static {
+    /// Export one batch; failures are the exporter's to report
+    async fn export(&self, batch: Vec<ExecutionResult>);
+}
+
+/// Sizing of a [`SpanExportQueue`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpanExportQueueConfig {
+    /// Results held before the oldest is dropped
+    pub capacity: usize,
+    /// Maximum results handed to the exporter at once
+    pub batch_size: usize,
+}
+
+impl Default for SpanExportQueueConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1024,
+            batch_size: 64,
+        }
+    }
+}
+
+/// Export counters for a [`SpanExportQueue`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpanExportStats {
+    /// Results handed to the exporter
+    pub exported: u64,
+    /// Results dropped because the queue was full or shut down
+    pub dropped: u64,
+}
+
+struct QueueShared {
+    /// Only taken by the drain task while waiting for or collecting a batch,
+    /// and by `push` to evict the oldest result
+    receiver: tokio::sync::Mutex<mpsc::Receiver<ExecutionResult>>,
+    exported: AtomicU64,
+    dropped: AtomicU64,
+}
+
+/// Bounded buffer drained to a [`SpanExporter`] by a background task
+///
+/// Cheap to clone; clones share the queue.
+#[derive(Clone)]
+pub struct SpanExportQueue {
+    sender: Arc<Mutex<Option<mpsc::Sender<ExecutionResult>>>>,
+    shared: Arc<QueueShared>,
+    drain: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl std::fmt::Debug for SpanExportQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpanExportQueue")
+            .field("stats", &self.stats())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SpanExportQueue {
+    /// Create the queue and spawn its drain task on the current runtime
+    pub fn spawn(exporter: Arc<dyn SpanExporter>, config: SpanExportQueueConfig) -> Self {
+        let (sender, receiver) = mpsc::channel(config.capacity.max(1));
+        let shared = Arc::new(QueueShared {
+            receiver: tokio::sync::Mutex::new(receiver),
+            exported: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+        });
+        let drain = tokio::spawn(drain(
+            Arc::clone(&shared),
+            exporter,
+            config.batch_size.max(1),
+        ));
+
+        Self {
+            sender: Arc::new(Mutex::new(Some(sender))),
+            shared,
+            drain: Arc::new(Mutex::new(Some(drain))),
+        }
+    }
+
+    /// Queue a result for export without blocking
+    ///
+    /// A full queue drops its oldest result to make room. If the drain task
+    /// is collecting a batch at that moment, `result` itself is dropped.
+    /// Both are counted in [`SpanExportStats::dropped`].
+    pub fn push(&self, result: ExecutionResult) {
+        let sender = self
+            .sender
+            .lock()
+            .unwrap_or_else(|p| p.into_inner())
+            .clone();
+        let Some(sender) = sender else {
+            self.record_dropped();
+            return;
+        };
+
+        let result = match sender.try_send(result) {
+            Ok(()) => return,
+            Err(TrySendError::Closed(_)) => {
+                self.record_dropped();
+                return;
+            }
+            Err(TrySendError::Full(result)) => result,
+        };
+
+        // Evict the oldest result; the drain task holds the receiver only
+        // while it is emptying the queue anyway
+        if let Ok(mut receiver) = self.shared.receiver.try_lock() {
+            if receiver.try_recv().is_ok() {
+                self.record_dropped();
+            }
+        }
+        if sender.try_send(result).is_err() {
+            self.record_dropped();
+        }
+    }
+
+    /// Snapshot of the export counters
+    pub fn stats(&self) -> SpanExportStats {
+        SpanExportStats {
+            exported: self.shared.exported.load(Ordering::Relaxed),
+            dropped: self.shared.dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Stop accepting results, export everything queued, and wait for the
+    /// drain task to finish
+    ///
+    /// Results pushed afterwards are dropped. Calling it again is a no-op.
+    pub async fn flush_and_shutdown(&self) {
+        self.sender.lock().unwrap_or_else(|p| p.into_inner()).take();
+        let drain = self.drain.lock().unwrap_or_else(|p| p.into_inner()).take();
+        if let Some(drain) = drain {
+            if let Err(e) = drain.await {
+                tracing::warn!(error = %e, "Span export task failed");
+            }
+        }
+    }
+
+    fn record_dropped(&self) {
+        self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Hand queued results to `exporter` until every sender is gone
+async fn drain(shared: Arc<QueueShared>, exporter: Arc<dyn SpanExporter>, batch_size: usize) {
+    loop {
+        let batch = {
+            let mut receiver = shared.receiver.lock().await;
+            let Some(first) = receiver.recv().await else {
+                return;
+            };
+            let mut batch = vec![first];
+            while batch.len() < batch_size {
+                match receiver.try_recv() {
+                    Ok(result) => batch.push(result),
+                    Err(_) => break,
+                }
+            }
+            batch
+        };
+
+        let count = batch.len() as u64;
+        exporter.export(batch).await;
+        shared.exported.fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::{ExecutionContext, ExecutionId, SpanCollector, SpanId};
+    use tokio::sync::{Notify, Semaphore};
+
+    /// Records exported batches, optionally holding each export until released
+    #[derive(Default)]
+    struct RecordingExporter {
+        batches: Mutex<Vec<Vec<String>>>,
+        started: Notify,
+        release: Option<Semaphore>,
+    }
+
+    #[async_trait]
+    impl SpanExporter for RecordingExporter {
+        async fn export(&self, batch: Vec<ExecutionResult>) {
+            self.started.notify_one();
+            if let Some(ref release) = self.release {
+                release.acquire().await.unwrap().forget();
+            }
+            let ids = batch
+                .iter()
+                .map(|r| r.execution_id.as_str().to_string())
+                .collect();
+            self.batches.lock().unwrap().push(ids);
+        }
+    }
+
+    fn result(id: &str) -> ExecutionResult {
+        let ctx = ExecutionContext {
+            execution_id: ExecutionId::new(id),
+            parent_span_id: SpanId::new(),
+            sampled: true,
+        };
+        SpanCollector::new(&ctx).finalize()
+    }
+
+    #[tokio::test]
+    async fn test_results_are_exported_in_batches() {
+        let exporter = Arc::new(RecordingExporter::default());
+        let config = SpanExportQueueConfig {
+            capacity: 16,
+            batch_size: 2,
+        };
+        let queue = SpanExportQueue::spawn(exporter.clone(), config);
+
+        // Hold the receiver so everything queues up before draining starts
+        let guard = queue.shared.receiver.lock().await;
+        for id in ["a", "b", "c", "d", "e"] {
+            queue.push(result(id));
+        }
+        drop(guard);
+        queue.flush_and_shutdown().await;
+
+        let batches = exporter.batches.lock().unwrap().clone();
+        assert_eq!(batches, [vec!["a", "b"], vec!["c", "d"], vec!["e"]]);
+        assert_eq!(
+            queue.stats(),
+            SpanExportStats {
+                exported: 5,
+                dropped: 0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_full_queue_drops_oldest() {
+        let exporter = Arc::new(RecordingExporter {
+            release: Some(Semaphore::new(0)),
+            ..Default::default()
+        });
+        let config = SpanExportQueueConfig {
+            capacity: 2,
+            batch_size: 1,
+        };
+        let queue = SpanExportQueue::spawn(exporter.clone(), config);
+
+        // The drain task takes the first result and blocks exporting it
+        queue.push(result("first"));
+        exporter.started.notified().await;
+
+        for id in ["a", "b", "c", "d"] {
+            queue.push(result(id));
+        }
+        assert_eq!(queue.stats().dropped, 2);
+
+        exporter.release.as_ref().unwrap().add_permits(8);
+        queue.flush_and_shutdown().await;
+        assert_eq!(
+            exporter.batches.lock().unwrap().concat(),
+            ["first", "c", "d"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collector_queues_first_sampled_result() {
+        let exporter = Arc::new(RecordingExporter::default());
+        let queue = SpanExportQueue::spawn(exporter.clone(), SpanExportQueueConfig::default());
+
+        let sampled = SpanCollector::new(&ExecutionContext {
+            execution_id: ExecutionId::new("sampled"),
+            parent_span_id: SpanId::new(),
+            sampled: true,
+        })
+        .with_export_queue(queue.clone());
+        sampled.finalize();
+        sampled.finalize_failed("again");
+
+        let unsampled = SpanCollector::new(&ExecutionContext {
+            execution_id: ExecutionId::new("unsampled"),
+            parent_span_id: SpanId::new(),
+            sampled: false,
+        })
+        .with_export_queue(queue.clone());
+        unsampled.finalize();
+
+        queue.flush_and_shutdown().await;
+        assert_eq!(exporter.batches.lock().unwrap().concat(), ["sampled"]);
+    }
+
+    #[tokio::test]
+    async fn test_flush_and_shutdown_drains_and_rejects_later_pushes() {
+        let exporter = Arc::new(RecordingExporter::default());
+        let queue = SpanExportQueue::spawn(exporter.clone(), SpanExportQueueConfig::default());
+
+        for id in ["a", "b", "c"] {
+            queue.push(result(id));
+        }
+        queue.flush_and_shutdown().await;
+        assert_eq!(exporter.batches.lock().unwrap().concat(), ["a", "b", "c"]);
+
+        queue.push(result("late"));
+        queue.flush_and_shutdown().await;
+        assert_eq!(
+            queue.stats(),
+            SpanExportStats {
+                exported: 3,
+                dropped: 1
+            }
+        );
+    }
+}
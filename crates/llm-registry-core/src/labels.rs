@@ -0,0 +1,298 @@
+//! Label selectors
+//!
+//! Labels are structured `key=value` pairs on an asset, used for filtering
+//! and policy. A selector is a comma-separated list of requirements that
+//! must all hold:
+//!
+//! - `key=value` (or `key==value`): the label is present with that value
+//! - `key!=value`: the label is absent or has another value
+//! - `key in (a,b)`: the label is present with one of the values
+//! - `key notin (a,b)`: the label is absent or has none of the values
+//! - `key`: the label is present
+//! - `!key`: the label is absent
+
+use crate::types::Labels;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// How a requirement tests a label's value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "operator", content = "values", rename_all = "snake_case")]
+pub enum LabelOperator {
+    /// Present with exactly this value
+    Equals(String),
+    /// Absent or any other value
+    NotEquals(String),
+    /// Present with one of these values
+    In(Vec<String>),
+    /// Absent or none of these values
+    NotIn(Vec<String>),
+    /// Present with any value
+    Exists,
+    /// Absent
+    DoesNotExist,
+}
+
+/// A single requirement on one label key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelRequirement {
+    /// Label key
+    pub key: String,
+    /// Test applied to the key's value
+    #[serde(flatten)]
+    pub operator: LabelOperator,
+}
+
+impl LabelRequirement {
+    /// Whether `labels` satisfy this requirement
+    pub fn matches(&self, labels: &Labels) -> bool {
+        let value = labels.get(&self.key);
+        match self.operator {
+            LabelOperator::Equals(ref expected) => value == Some(expected),
+            LabelOperator::NotEquals(ref expected) => value != Some(expected),
+            LabelOperator::In(ref values) => value.is_some_and(|v| values.contains(v)),
+            LabelOperator::NotIn(ref values) => !value.is_some_and(|v| values.contains(v)),
+            LabelOperator::Exists => value.is_some(),
+            LabelOperator::DoesNotExist => value.is_none(),
+        }
+    }
+}
+
+impl fmt::Display for LabelRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.operator {
+            LabelOperator::Equals(ref value) => write!(f, "{}={}", self.key, value),
+            LabelOperator::NotEquals(ref value) => write!(f, "{}!={}", self.key, value),
+            LabelOperator::In(ref values) => write!(f, "{} in ({})", self.key, values.join(",")),
+            LabelOperator::NotIn(ref values) => {
+                write!(f, "{} notin ({})", self.key, values.join(","))
+            }
+            LabelOperator::Exists => f.write_str(&self.key),
+            LabelOperator::DoesNotExist => write!(f, "!{}", self.key),
+        }
+    }
+}
+
+/// Conjunction of label requirements
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelSelector {
+    /// Requirements that must all hold
+    pub requirements: Vec<LabelRequirement>,
+}
+
+impl LabelSelector {
+    /// Whether `labels` satisfy every requirement
+    pub fn matches(&self, labels: &Labels) -> bool {
+        self.requirements.iter().all(|r| r.matches(labels))
+    }
+
+    /// Whether the selector has no requirements and so matches everything
+    pub fn is_empty(&self) -> bool {
+        self.requirements.is_empty()
+    }
+}
+
+impl fmt::Display for LabelSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, requirement) in self.requirements.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", requirement)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for LabelSelector {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let requirements = split_requirements(s)?
+            .into_iter()
+            .map(parse_requirement)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(LabelSelector { requirements })
+    }
+}
+
+/// Split on commas outside parentheses
+fn split_requirements(s: &str) -> Result<Vec<&str>, String> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| format!("Unbalanced ')' in label selector '{}'", s))?;
+            }
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(format!("Unbalanced '(' in label selector '{}'", s));
+    }
+    parts.push(&s[start..]);
+
+    // A blank selector selects everything
+    if parts.len() == 1 && parts[0].trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(parts)
+}
+
+fn parse_requirement(raw: &str) -> Result<LabelRequirement, String> {
+    let requirement = raw.trim();
+    if requirement.is_empty() {
+        return Err("Empty requirement in label selector".to_string());
+    }
+
+    if let Some(open) = requirement.find('(') {
+        let (head, list) = requirement.split_at(open);
+        let mut words = head.split_whitespace();
+        let (Some(key), Some(op), None) = (words.next(), words.next(), words.next()) else {
+            return Err(format!("Invalid set requirement '{}'", requirement));
+        };
+        let values = list
+            .strip_prefix('(')
+            .and_then(|l| l.strip_suffix(')'))
+            .ok_or_else(|| format!("Invalid value list in '{}'", requirement))?
+            .split(',')
+            .map(|v| parse_token(v, "value"))
+            .collect::<Result<Vec<_>, _>>()?;
+        let operator = match op {
+            "in" => LabelOperator::In(values),
+            "notin" => LabelOperator::NotIn(values),
+            other => {
+                return Err(format!(
+                    "Unknown set operator '{}' in '{}' (expected in or notin)",
+                    other, requirement
+                ))
+            }
+        };
+        return Ok(LabelRequirement {
+            key: parse_token(key, "key")?,
+            operator,
+        });
+    }
+
+    let (key, operator) = if let Some((key, value)) = requirement.split_once("!=") {
+        (key, LabelOperator::NotEquals(parse_token(value, "value")?))
+    } else if let Some((key, value)) = requirement.split_once("==") {
+        (key, LabelOperator::Equals(parse_token(value, "value")?))
+    } else if let Some((key, value)) = requirement.split_once('=') {
+        (key, LabelOperator::Equals(parse_token(value, "value")?))
+    } else if let Some(key) = requirement.strip_prefix('!') {
+        (key, LabelOperator::DoesNotExist)
+    } else {
+        (requirement, LabelOperator::Exists)
+    };
+    Ok(LabelRequirement {
+        key: parse_token(key, "key")?,
+        operator,
+    })
+}
+
+/// First character of a label key or value that a selector cannot express
+pub fn reserved_char(token: &str) -> Option<char> {
+    token
+        .chars()
+        .find(|c| c.is_whitespace() || "!=(),".contains(*c))
+}
+
+/// A trimmed, non-empty key or value without selector syntax in it
+fn parse_token(raw: &str, what: &str) -> Result<String, String> {
+    let token = raw.trim();
+    if token.is_empty() {
+        return Err(format!("Empty label {} in label selector", what));
+    }
+    if let Some(c) = reserved_char(token) {
+        return Err(format!(
+            "Invalid character '{}' in label {} '{}'",
+            c, what, token
+        ));
+    }
+    Ok(token.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(pairs: &[(&str, &str)]) -> Labels {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_selector() {
+        let selector: LabelSelector = "team=ml-platform, tier in (gold, silver),!legacy"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            selector.requirements,
+            [
+                LabelRequirement {
+                    key: "team".to_string(),
+                    operator: LabelOperator::Equals("ml-platform".to_string()),
+                },
+                LabelRequirement {
+                    key: "tier".to_string(),
+                    operator: LabelOperator::In(vec!["gold".to_string(), "silver".to_string()]),
+                },
+                LabelRequirement {
+                    key: "legacy".to_string(),
+                    operator: LabelOperator::DoesNotExist,
+                },
+            ]
+        );
+        assert_eq!(
+            selector.to_string(),
+            "team=ml-platform,tier in (gold,silver),!legacy"
+        );
+        assert!("  ".parse::<LabelSelector>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_selector_errors() {
+        for invalid in [
+            "team=",
+            "=gold",
+            "team=a,,tier=b",
+            "tier in (gold",
+            "tier in gold)",
+            "tier within (gold)",
+            "tier in ()",
+            "team=ml platform",
+        ] {
+            assert!(invalid.parse::<LabelSelector>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_selector_matching() {
+        let asset = labels(&[("team", "ml-platform"), ("tier", "gold")]);
+        let matches = |selector: &str| selector.parse::<LabelSelector>().unwrap().matches(&asset);
+
+        assert!(matches("team=ml-platform"));
+        assert!(matches("team==ml-platform,tier in (gold,silver)"));
+        assert!(!matches("team=ml-platform,tier in (silver,bronze)"));
+        assert!(matches("tier notin (silver)"));
+        assert!(matches("region notin (eu)"));
+        assert!(matches("region!=eu"));
+        assert!(!matches("team!=ml-platform"));
+        assert!(matches("tier,!region"));
+        assert!(!matches("region"));
+        assert!(matches(""));
+    }
+}
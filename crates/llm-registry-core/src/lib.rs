@@ -10,9 +10,14 @@ pub mod dependency;
 pub mod error;
 pub mod event;
 pub mod execution;
+pub mod export;
+pub mod labels;
+pub mod otlp;
 pub mod provenance;
 pub mod storage;
+pub mod timestamp;
 pub mod types;
+pub mod version;
 
 // Re-exports for convenience
 pub use asset::{Asset, AssetMetadata, AssetType};
@@ -21,9 +26,14 @@ pub use dependency::{AssetReference, DependencyGraph};
 pub use error::{RegistryError, Result};
 pub use event::{EventType, RegistryEvent};
 pub use execution::{
-    ExecutionContext, ExecutionId, ExecutionResult, ExecutionSpan, SpanArtifact, SpanCollector,
-    SpanId, SpanStatus, SpanType,
+    AgentService, ChildCollector, ExecutionContext, ExecutionId, ExecutionResult, ExecutionSpan,
+    FailureKind, SpanArtifact, SpanCollector, SpanId, SpanScope, SpanStatus, SpanType, TraceParent,
+    ATTR_FAILURE_KIND, ATTR_FAILURE_REASON,
 };
+pub use export::{SpanExportQueue, SpanExportQueueConfig, SpanExportStats, SpanExporter};
+pub use labels::{LabelOperator, LabelRequirement, LabelSelector};
 pub use provenance::Provenance;
 pub use storage::{StorageBackend, StorageLocation};
-pub use types::{AssetId, AssetStatus, Tags, Annotations};
+pub use timestamp::TimestampFormat;
+pub use types::{Annotations, AssetId, AssetStatus, Labels, Tags};
+pub use version::Version;
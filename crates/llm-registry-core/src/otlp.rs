@@ -0,0 +1,254 @@
+//! OTLP/JSON export of execution span trees
+//!
+//! Converts an [`ExecutionResult`] into the `resourceSpans` document accepted
+//! by OpenTelemetry collectors on `/v1/traces`:
+//!
+//! - the trace id is the execution id when that is already 32 hex digits
+//!   (as produced from a `traceparent` header), otherwise a stable 128-bit
+//!   hash of it
+//! - span ids are the low 64 bits of each [`SpanId`]
+//! - the repo span is a server span, agent spans are internal spans
+//! - [`SpanStatus::Ok`] maps to `STATUS_CODE_OK`, [`SpanStatus::Failed`] to
+//!   `STATUS_CODE_ERROR`
+//! - span attributes are carried over; artifact names are listed in the
+//!   `llm_registry.artifacts` attribute
+
+use serde_json::{json, Value};
+
+use crate::execution::{ExecutionId, ExecutionResult, ExecutionSpan, SpanId, SpanStatus, SpanType};
+
+/// `service.name` reported on the exported resource.
+pub const SERVICE_NAME: &str = "llm-registry";
+
+/// OTLP `SPAN_KIND_INTERNAL`.
+const SPAN_KIND_INTERNAL: u8 = 1;
+/// OTLP `SPAN_KIND_SERVER`.
+const SPAN_KIND_SERVER: u8 = 2;
+/// OTLP `STATUS_CODE_OK`.
+const STATUS_CODE_OK: u8 = 1;
+/// OTLP `STATUS_CODE_ERROR`.
+const STATUS_CODE_ERROR: u8 = 2;
+
+/// Convert an execution result into an OTLP/JSON `resourceSpans` document.
+pub fn to_otlp_json(result: &ExecutionResult) -> Value {
+    let trace_id = trace_id(&result.execution_id);
+    let spans: Vec<Value> = result
+        .spans
+        .iter()
+        .map(|span| span_json(&trace_id, span))
+        .collect();
+
+    json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [
+                    key_value("service.name", &json!(SERVICE_NAME)),
+                    key_value("llm_registry.execution_id", &json!(result.execution_id.as_str())),
+                ]
+            },
+            "scopeSpans": [{
+                "scope": { "name": SERVICE_NAME },
+                "spans": spans,
+            }]
+        }]
+    })
+}
+
+/// 32-hex-digit OTLP trace id for an execution.
+pub fn trace_id(execution_id: &ExecutionId) -> String {
+    let id = execution_id.as_str();
+    let is_trace_id = id.len() == 32
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+        && id.bytes().any(|b| b != b'0');
+    if is_trace_id {
+        id.to_string()
+    } else {
+        format!("{:032x}", fnv1a_128(id.as_bytes()))
+    }
+}
+
+/// 16-hex-digit OTLP span id for a span.
+pub fn span_id(id: SpanId) -> String {
+    format!("{:016x}", id.as_u128() as u64)
+}
+
+fn span_json(trace_id: &str, span: &ExecutionSpan) -> Value {
+    let (kind, span_type) = match span.span_type {
+        SpanType::Repo => (SPAN_KIND_SERVER, "repo"),
+        SpanType::Agent => (SPAN_KIND_INTERNAL, "agent"),
+    };
+
+    let mut attributes = vec![key_value("llm_registry.span_type", &json!(span_type))];
+    let mut keys: Vec<&String> = span.attributes.keys().collect();
+    keys.sort();
+    attributes.extend(
+        keys.into_iter()
+            .map(|key| key_value(key, &span.attributes[key])),
+    );
+    if !span.artifacts.is_empty() {
+        let names: Vec<&str> = span.artifacts.iter().map(|a| a.name.as_str()).collect();
+        attributes.push(key_value("llm_registry.artifacts", &json!(names)));
+    }
+
+    let mut status = match span.status {
+        SpanStatus::Ok => json!({ "code": STATUS_CODE_OK }),
+        SpanStatus::Failed => json!({ "code": STATUS_CODE_ERROR }),
+    };
+    if let Some(Value::String(reason)) = span.attributes.get("failure_reason") {
+        status["message"] = json!(reason);
+    }
+
+    let mut otlp = json!({
+        "traceId": trace_id,
+        "spanId": span_id(span.span_id),
+        "parentSpanId": span_id(span.parent_span_id),
+        "name": span.name,
+        "kind": kind,
+        "startTimeUnixNano": unix_nanos(span.started_at),
+        "attributes": attributes,
+        "status": status,
+    });
+    if let Some(ended_at) = span.ended_at {
+        otlp["endTimeUnixNano"] = json!(unix_nanos(ended_at));
+    }
+    otlp
+}
+
+/// Timestamps are encoded as decimal strings, as OTLP/JSON does for 64-bit integers.
+fn unix_nanos(at: chrono::DateTime<chrono::Utc>) -> String {
+    at.timestamp_nanos_opt().unwrap_or_default().to_string()
+}
+
+fn key_value(key: &str, value: &Value) -> Value {
+    json!({ "key": key, "value": any_value(value) })
+}
+
+/// Map a JSON value onto an OTLP `AnyValue`.
+fn any_value(value: &Value) -> Value {
+    match value {
+        Value::Null => json!({}),
+        Value::Bool(b) => json!({ "boolValue": b }),
+        Value::Number(n) => match n.as_i64() {
+            Some(i) => json!({ "intValue": i.to_string() }),
+            None => json!({ "doubleValue": n.as_f64() }),
+        },
+        Value::String(s) => json!({ "stringValue": s }),
+        Value::Array(items) => json!({
+            "arrayValue": { "values": items.iter().map(any_value).collect::<Vec<_>>() }
+        }),
+        Value::Object(fields) => json!({
+            "kvlistValue": {
+                "values": fields.iter().map(|(k, v)| key_value(k, v)).collect::<Vec<_>>()
+            }
+        }),
+    }
+}
+
+/// 128-bit FNV-1a; stable across builds, unlike `std`'s hasher.
+fn fnv1a_128(bytes: &[u8]) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    bytes.iter().fold(OFFSET_BASIS, |hash, &b| {
+        (hash ^ u128::from(b)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::{ExecutionContext, SpanArtifact, SpanCollector};
+
+    fn result() -> ExecutionResult {
+        let collector = SpanCollector::new(&ExecutionContext {
+            execution_id: ExecutionId::new("exec-otlp"),
+            parent_span_id: SpanId::new(),
+            sampled: true,
+        });
+        let ok = collector.begin_agent_span("ValidationService");
+        collector
+            .attach_artifact(
+                ok,
+                SpanArtifact {
+                    name: "validation_report".to_string(),
+                    content_type: None,
+                    data: json!({ "valid": true }),
+                },
+            )
+            .unwrap();
+        collector.end_agent_span(ok, SpanStatus::Ok);
+        let failed = collector.begin_agent_span("RegistrationService");
+        collector.end_agent_span(failed, SpanStatus::Failed);
+        collector.finalize()
+    }
+
+    fn spans(otlp: &Value) -> &Vec<Value> {
+        otlp["resourceSpans"][0]["scopeSpans"][0]["spans"]
+            .as_array()
+            .unwrap()
+    }
+
+    fn attribute<'a>(span: &'a Value, key: &str) -> Option<&'a Value> {
+        span["attributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|kv| kv["key"] == key)
+            .map(|kv| &kv["value"])
+    }
+
+    #[test]
+    fn test_span_tree_is_exported() {
+        let result = result();
+        let otlp = to_otlp_json(&result);
+        let spans = spans(&otlp);
+
+        assert_eq!(spans.len(), 3);
+        let trace_id = spans[0]["traceId"].as_str().unwrap();
+        assert_eq!(trace_id.len(), 32);
+        assert!(spans.iter().all(|s| s["traceId"] == trace_id));
+        assert_eq!(spans[0]["kind"], SPAN_KIND_SERVER);
+        assert_eq!(spans[1]["kind"], SPAN_KIND_INTERNAL);
+        assert_eq!(spans[1]["parentSpanId"], spans[0]["spanId"]);
+        assert_eq!(spans[1]["spanId"].as_str().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn test_status_mapping() {
+        let otlp = to_otlp_json(&result());
+        let spans = spans(&otlp);
+
+        assert_eq!(spans[1]["status"]["code"], STATUS_CODE_OK);
+        assert_eq!(spans[2]["status"]["code"], STATUS_CODE_ERROR);
+        // Failure propagates to the repo span
+        assert_eq!(spans[0]["status"]["code"], STATUS_CODE_ERROR);
+    }
+
+    #[test]
+    fn test_artifact_names_become_attributes() {
+        let otlp = to_otlp_json(&result());
+        let spans = spans(&otlp);
+
+        assert_eq!(
+            attribute(&spans[1], "llm_registry.artifacts").unwrap(),
+            &json!({ "arrayValue": { "values": [{ "stringValue": "validation_report" }] } })
+        );
+        assert!(attribute(&spans[2], "llm_registry.artifacts").is_none());
+        assert_eq!(
+            attribute(&spans[0], "llm_registry.span_type").unwrap(),
+            &json!({ "stringValue": "repo" })
+        );
+    }
+
+    #[test]
+    fn test_trace_id_derivation() {
+        let hex = "4bf92f3577b34da6a3ce929d0e0e4736";
+        assert_eq!(trace_id(&ExecutionId::new(hex)), hex);
+
+        let derived = trace_id(&ExecutionId::new("exec-1"));
+        assert_eq!(derived.len(), 32);
+        assert_eq!(derived, trace_id(&ExecutionId::new("exec-1")));
+        assert_ne!(derived, trace_id(&ExecutionId::new("exec-2")));
+    }
+}
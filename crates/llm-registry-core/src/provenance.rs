@@ -71,7 +71,8 @@ impl Provenance {
             if !repo.starts_with("http://")
                 && !repo.starts_with("https://")
                 && !repo.starts_with("git@")
-                && !repo.starts_with("ssh://") {
+                && !repo.starts_with("ssh://")
+            {
                 return Err(RegistryError::ValidationError(
                     "Source repository must be a valid URL or SSH connection string".to_string(),
                 ));
@@ -87,9 +88,10 @@ impl Provenance {
             }
             let len = hash.len();
             if len != 40 && len != 64 {
-                return Err(RegistryError::ValidationError(
-                    format!("Commit hash must be 40 (SHA-1) or 64 (SHA-256) characters, got {}", len),
-                ));
+                return Err(RegistryError::ValidationError(format!(
+                    "Commit hash must be 40 (SHA-1) or 64 (SHA-256) characters, got {}",
+                    len
+                )));
             }
             if !hash.chars().all(|c| c.is_ascii_hexdigit()) {
                 return Err(RegistryError::ValidationError(
@@ -210,7 +212,9 @@ impl ProvenanceBuilder {
 
     /// Add build metadata
     pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
-        self.provenance.build_metadata.insert(key.into(), value.into());
+        self.provenance
+            .build_metadata
+            .insert(key.into(), value.into());
         self
     }
 
@@ -262,8 +266,14 @@ mod tests {
             .build()
             .unwrap();
 
-        assert_eq!(prov.source_repo.as_deref(), Some("https://github.com/example/repo"));
-        assert_eq!(prov.commit_hash.as_deref(), Some("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3"));
+        assert_eq!(
+            prov.source_repo.as_deref(),
+            Some("https://github.com/example/repo")
+        );
+        assert_eq!(
+            prov.commit_hash.as_deref(),
+            Some("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3")
+        );
         assert_eq!(prov.build_id.as_deref(), Some("build-123"));
         assert_eq!(prov.author.as_deref(), Some("Alice"));
     }
@@ -278,27 +288,20 @@ mod tests {
         ];
 
         for url in valid_urls {
-            let prov = Provenance::builder()
-                .source_repo(url)
-                .build()
-                .unwrap();
+            let prov = Provenance::builder().source_repo(url).build().unwrap();
             assert_eq!(prov.source_repo.as_deref(), Some(url));
         }
     }
 
     #[test]
     fn test_provenance_validation_invalid_url() {
-        let result = Provenance::builder()
-            .source_repo("not-a-url")
-            .build();
+        let result = Provenance::builder().source_repo("not-a-url").build();
         assert!(result.is_err());
     }
 
     #[test]
     fn test_provenance_validation_empty_repo() {
-        let result = Provenance::builder()
-            .source_repo("")
-            .build();
+        let result = Provenance::builder().source_repo("").build();
         assert!(result.is_err());
     }
 
@@ -308,7 +311,10 @@ mod tests {
             .commit_hash("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3")
             .build()
             .unwrap();
-        assert_eq!(prov.commit_hash.as_deref(), Some("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3"));
+        assert_eq!(
+            prov.commit_hash.as_deref(),
+            Some("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3")
+        );
     }
 
     #[test]
@@ -317,14 +323,15 @@ mod tests {
             .commit_hash("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3a94a8fe5ccb19ba61c4c0873")
             .build()
             .unwrap();
-        assert_eq!(prov.commit_hash.as_deref(), Some("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3a94a8fe5ccb19ba61c4c0873"));
+        assert_eq!(
+            prov.commit_hash.as_deref(),
+            Some("a94a8fe5ccb19ba61c4c0873d391e987982fbbd3a94a8fe5ccb19ba61c4c0873")
+        );
     }
 
     #[test]
     fn test_provenance_validation_invalid_commit_length() {
-        let result = Provenance::builder()
-            .commit_hash("abc123")
-            .build();
+        let result = Provenance::builder().commit_hash("abc123").build();
         assert!(result.is_err());
     }
 
@@ -342,8 +349,14 @@ mod tests {
         prov.add_metadata("python_version".to_string(), "3.11".to_string());
         prov.add_metadata("torch_version".to_string(), "2.0.0".to_string());
 
-        assert_eq!(prov.get_metadata("python_version"), Some(&"3.11".to_string()));
-        assert_eq!(prov.get_metadata("torch_version"), Some(&"2.0.0".to_string()));
+        assert_eq!(
+            prov.get_metadata("python_version"),
+            Some(&"3.11".to_string())
+        );
+        assert_eq!(
+            prov.get_metadata("torch_version"),
+            Some(&"2.0.0".to_string())
+        );
         assert_eq!(prov.build_metadata.len(), 2);
     }
 
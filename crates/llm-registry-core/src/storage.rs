@@ -63,7 +63,11 @@ impl StorageBackend {
     /// Validate the storage backend configuration
     pub fn validate(&self) -> Result<()> {
         match self {
-            StorageBackend::S3 { bucket, region, endpoint } => {
+            StorageBackend::S3 {
+                bucket,
+                region,
+                endpoint,
+            } => {
                 if bucket.is_empty() {
                     return Err(RegistryError::ValidationError(
                         "S3 bucket name cannot be empty".to_string(),
@@ -100,7 +104,10 @@ impl StorageBackend {
                 }
                 Ok(())
             }
-            StorageBackend::AzureBlob { account_name, container } => {
+            StorageBackend::AzureBlob {
+                account_name,
+                container,
+            } => {
                 if account_name.is_empty() {
                     return Err(RegistryError::ValidationError(
                         "Azure account name cannot be empty".to_string(),
@@ -236,8 +243,14 @@ impl StorageLocation {
             StorageBackend::GCS { bucket, .. } => {
                 format!("gs://{}/{}", bucket, self.path)
             }
-            StorageBackend::AzureBlob { account_name, container } => {
-                format!("https://{}.blob.core.windows.net/{}/{}", account_name, container, self.path)
+            StorageBackend::AzureBlob {
+                account_name,
+                container,
+            } => {
+                format!(
+                    "https://{}.blob.core.windows.net/{}/{}",
+                    account_name, container, self.path
+                )
             }
             StorageBackend::MinIO { bucket, endpoint } => {
                 format!("{}/{}/{}", endpoint, bucket, self.path)
@@ -336,11 +349,8 @@ mod tests {
             region: "us-east-1".to_string(),
             endpoint: None,
         };
-        let location = StorageLocation::new(
-            backend,
-            "models/gpt-2/model.bin".to_string(),
-            None,
-        ).unwrap();
+        let location =
+            StorageLocation::new(backend, "models/gpt-2/model.bin".to_string(), None).unwrap();
         assert_eq!(location.path, "models/gpt-2/model.bin");
     }
 
@@ -361,12 +371,12 @@ mod tests {
             region: "us-east-1".to_string(),
             endpoint: None,
         };
-        let location = StorageLocation::new(
-            backend,
-            "models/gpt-2/model.bin".to_string(),
-            None,
-        ).unwrap();
-        assert_eq!(location.generate_uri(), "s3://my-bucket/models/gpt-2/model.bin");
+        let location =
+            StorageLocation::new(backend, "models/gpt-2/model.bin".to_string(), None).unwrap();
+        assert_eq!(
+            location.generate_uri(),
+            "s3://my-bucket/models/gpt-2/model.bin"
+        );
     }
 
     #[test]
@@ -375,12 +385,12 @@ mod tests {
             bucket: "my-bucket".to_string(),
             project_id: "my-project".to_string(),
         };
-        let location = StorageLocation::new(
-            backend,
-            "models/bert/model.bin".to_string(),
-            None,
-        ).unwrap();
-        assert_eq!(location.generate_uri(), "gs://my-bucket/models/bert/model.bin");
+        let location =
+            StorageLocation::new(backend, "models/bert/model.bin".to_string(), None).unwrap();
+        assert_eq!(
+            location.generate_uri(),
+            "gs://my-bucket/models/bert/model.bin"
+        );
     }
 
     #[test]
@@ -388,12 +398,11 @@ mod tests {
         let backend = StorageBackend::FileSystem {
             base_path: "/var/lib/registry".to_string(),
         };
-        let location = StorageLocation::new(
-            backend,
-            "models/model.bin".to_string(),
-            None,
-        ).unwrap();
-        assert_eq!(location.generate_uri(), "file:///var/lib/registry/models/model.bin");
+        let location = StorageLocation::new(backend, "models/model.bin".to_string(), None).unwrap();
+        assert_eq!(
+            location.generate_uri(),
+            "file:///var/lib/registry/models/model.bin"
+        );
     }
 
     #[test]
@@ -408,7 +417,8 @@ mod tests {
             backend,
             "models/model.bin".to_string(),
             Some(custom_uri.clone()),
-        ).unwrap();
+        )
+        .unwrap();
         assert_eq!(location.get_uri(), custom_uri);
     }
 
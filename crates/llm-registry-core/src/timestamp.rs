@@ -0,0 +1,203 @@
+//! Wire formats for timestamps
+//!
+//! Timestamps are always stored as `DateTime<Utc>` and serialize as RFC 3339
+//! strings. Consumers that want numeric epochs can have serialized documents
+//! rewritten with [`TimestampFormat::convert`].
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+/// Field names that carry timestamps in serialized registry types
+pub const TIMESTAMP_FIELDS: &[&str] = &[
+    "timestamp",
+    "created_at",
+    "updated_at",
+    "deprecated_at",
+    "deleted_at",
+    "started_at",
+    "ended_at",
+    "start_time",
+    "end_time",
+];
+
+/// Object fields holding free-form user data that is never rewritten
+const OPAQUE_FIELDS: &[&str] = &["annotations", "attributes", "context"];
+
+/// Wire format for timestamps
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampFormat {
+    /// RFC 3339 string, e.g. `2024-01-01T00:00:00Z`
+    #[default]
+    Rfc3339,
+    /// Milliseconds since the Unix epoch
+    UnixMillis,
+    /// Nanoseconds since the Unix epoch
+    UnixNanos,
+}
+
+impl TimestampFormat {
+    /// Name of the format as accepted by [`FromStr`]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimestampFormat::Rfc3339 => "rfc3339",
+            TimestampFormat::UnixMillis => "unix_millis",
+            TimestampFormat::UnixNanos => "unix_nanos",
+        }
+    }
+
+    /// Encode a timestamp in this format
+    pub fn format(&self, timestamp: &DateTime<Utc>) -> Value {
+        match self {
+            TimestampFormat::Rfc3339 => Value::String(timestamp.to_rfc3339()),
+            TimestampFormat::UnixMillis => Value::from(timestamp.timestamp_millis()),
+            TimestampFormat::UnixNanos => timestamp
+                .timestamp_nanos_opt()
+                .map(Value::from)
+                .unwrap_or(Value::Null),
+        }
+    }
+
+    /// Decode a timestamp encoded in this format
+    ///
+    /// RFC 3339 strings are accepted regardless of the format.
+    pub fn parse(&self, value: &Value) -> Option<DateTime<Utc>> {
+        match value {
+            Value::String(s) => DateTime::parse_from_rfc3339(s)
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc)),
+            Value::Number(n) => {
+                let n = n.as_i64()?;
+                match self {
+                    TimestampFormat::UnixNanos => Some(Utc.timestamp_nanos(n)),
+                    TimestampFormat::Rfc3339 | TimestampFormat::UnixMillis => {
+                        Utc.timestamp_millis_opt(n).single()
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Rewrite every RFC 3339 timestamp field in a serialized document
+    pub fn convert(&self, value: &mut Value) {
+        if *self == TimestampFormat::Rfc3339 {
+            return;
+        }
+
+        match value {
+            Value::Object(map) => {
+                for (key, field) in map.iter_mut() {
+                    if TIMESTAMP_FIELDS.contains(&key.as_str()) {
+                        if let Some(timestamp) = TimestampFormat::Rfc3339.parse(field) {
+                            *field = self.format(&timestamp);
+                            continue;
+                        }
+                    }
+                    if !OPAQUE_FIELDS.contains(&key.as_str()) {
+                        self.convert(field);
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.convert(item)),
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Display for TimestampFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TimestampFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('-', "_").as_str() {
+            "rfc3339" => Ok(TimestampFormat::Rfc3339),
+            "unix_millis" => Ok(TimestampFormat::UnixMillis),
+            "unix_nanos" => Ok(TimestampFormat::UnixNanos),
+            other => Err(format!(
+                "Unknown timestamp format '{}' (expected rfc3339, unix_millis or unix_nanos)",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> DateTime<Utc> {
+        Utc.timestamp_nanos(1_704_067_200_123_456_789)
+    }
+
+    #[test]
+    fn test_round_trip_rfc3339() {
+        let format = TimestampFormat::Rfc3339;
+        let encoded = format.format(&sample());
+        assert!(encoded.is_string());
+        assert_eq!(format.parse(&encoded), Some(sample()));
+    }
+
+    #[test]
+    fn test_round_trip_unix_millis() {
+        let format = TimestampFormat::UnixMillis;
+        let encoded = format.format(&sample());
+        assert_eq!(encoded, Value::from(1_704_067_200_123_i64));
+        assert_eq!(
+            format.parse(&encoded),
+            Some(Utc.timestamp_millis_opt(1_704_067_200_123).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_round_trip_unix_nanos() {
+        let format = TimestampFormat::UnixNanos;
+        let encoded = format.format(&sample());
+        assert_eq!(encoded, Value::from(1_704_067_200_123_456_789_i64));
+        assert_eq!(format.parse(&encoded), Some(sample()));
+    }
+
+    #[test]
+    fn test_convert_rewrites_nested_fields_only() {
+        let mut doc = serde_json::json!({
+            "data": {
+                "created_at": sample().to_rfc3339(),
+                "annotations": {"created_at": "2020-01-01T00:00:00Z"},
+                "name": "2020-01-01T00:00:00Z",
+            },
+            "execution": {"spans": [{"started_at": sample().to_rfc3339(), "ended_at": null}]},
+        });
+
+        TimestampFormat::UnixMillis.convert(&mut doc);
+
+        assert_eq!(
+            doc["data"]["created_at"],
+            Value::from(1_704_067_200_123_i64)
+        );
+        assert_eq!(
+            doc["data"]["annotations"]["created_at"],
+            "2020-01-01T00:00:00Z"
+        );
+        assert_eq!(doc["data"]["name"], "2020-01-01T00:00:00Z");
+        assert_eq!(
+            doc["execution"]["spans"][0]["started_at"],
+            Value::from(1_704_067_200_123_i64)
+        );
+        assert!(doc["execution"]["spans"][0]["ended_at"].is_null());
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("unix-millis".parse(), Ok(TimestampFormat::UnixMillis));
+        assert_eq!("RFC3339".parse(), Ok(TimestampFormat::Rfc3339));
+        assert!("epoch".parse::<TimestampFormat>().is_err());
+    }
+}
@@ -111,6 +111,9 @@ pub type Tags = Vec<String>;
 /// Type alias for annotations (key-value metadata)
 pub type Annotations = HashMap<String, String>;
 
+/// Type alias for labels (structured key-value pairs used for selection)
+pub type Labels = HashMap<String, String>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,128 @@
+//! Asset version type
+//!
+//! [`Version`] wraps a [`semver::Version`] so asset versions always order by
+//! semantic version precedence rather than as strings: `1.10.0` is newer
+//! than `1.9.0`, and a pre-release such as `2.0.0-rc.1` precedes `2.0.0`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use crate::error::{RegistryError, Result};
+
+/// Semantic version of an asset
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Version(semver::Version);
+
+impl Version {
+    /// Create a release version
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self(semver::Version::new(major, minor, patch))
+    }
+
+    /// Parse a semantic version such as `1.2.3` or `2.0.0-rc.1`
+    pub fn parse(version: &str) -> Result<Self> {
+        semver::Version::parse(version.trim())
+            .map(Self)
+            .map_err(|e| {
+                RegistryError::InvalidVersion(format!(
+                    "'{}' is not a semantic version (expected MAJOR.MINOR.PATCH): {}",
+                    version, e
+                ))
+            })
+    }
+
+    /// The underlying semver version
+    pub fn as_semver(&self) -> &semver::Version {
+        &self.0
+    }
+}
+
+impl Deref for Version {
+    type Target = semver::Version;
+
+    fn deref(&self) -> &semver::Version {
+        &self.0
+    }
+}
+
+impl From<semver::Version> for Version {
+    fn from(version: semver::Version) -> Self {
+        Self(version)
+    }
+}
+
+impl From<Version> for semver::Version {
+    fn from(version: Version) -> Self {
+        version.0
+    }
+}
+
+impl PartialEq<semver::Version> for Version {
+    fn eq(&self, other: &semver::Version) -> bool {
+        self.0 == *other
+    }
+}
+
+impl FromStr for Version {
+    type Err = RegistryError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let version = String::deserialize(deserializer)?;
+        Self::parse(&version).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(version: &str) -> Version {
+        Version::parse(version).unwrap()
+    }
+
+    #[test]
+    fn test_orders_by_semver_precedence() {
+        assert!(v("1.10.0") > v("1.9.0"));
+        assert!(v("2.0.0-rc.1") < v("2.0.0"));
+        assert!(v("2.0.0-alpha") < v("2.0.0-beta"));
+
+        let mut versions = [v("1.10.0"), v("1.9.0"), v("1.10.0-rc.1"), v("0.9.12")];
+        versions.sort();
+        let sorted: Vec<String> = versions.iter().map(Version::to_string).collect();
+        assert_eq!(sorted, ["0.9.12", "1.9.0", "1.10.0-rc.1", "1.10.0"]);
+    }
+
+    #[test]
+    fn test_invalid_version_error_names_input() {
+        let err = Version::parse("1.2").unwrap_err();
+        assert!(matches!(err, RegistryError::InvalidVersion(_)));
+        assert!(err.to_string().contains("'1.2' is not a semantic version"));
+
+        let err = serde_json::from_str::<Version>("\"latest\"").unwrap_err();
+        assert!(err.to_string().contains("'latest'"));
+        assert_eq!(
+            serde_json::to_string(&v("1.0.0-rc.1")).unwrap(),
+            "\"1.0.0-rc.1\""
+        );
+    }
+}
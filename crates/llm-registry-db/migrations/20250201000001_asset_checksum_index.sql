@@ -0,0 +1,6 @@
+-- Index checksum values for content hash search
+-- Migration: 20250201000001_asset_checksum_index
+
+-- text_pattern_ops lets short-hash prefix matches (LIKE 'abc%') use the index
+-- regardless of the database collation
+CREATE INDEX idx_assets_checksum_value ON assets(checksum_value text_pattern_ops);
@@ -0,0 +1,7 @@
+-- Soft delete for assets
+-- Migration: 20250301000001_asset_soft_delete
+
+-- Soft-deleted assets keep their row (and name/version) until purged
+ALTER TABLE assets ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX idx_assets_deleted ON assets(deleted_at) WHERE deleted_at IS NULL;
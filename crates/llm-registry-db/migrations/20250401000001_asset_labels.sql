@@ -0,0 +1,7 @@
+-- Structured key=value labels on assets
+-- Migration: 20250401000001_asset_labels
+
+ALTER TABLE assets ADD COLUMN labels JSONB NOT NULL DEFAULT '{}'::jsonb;
+
+-- Containment (labels @> '{"team": "ml-platform"}') and key-existence lookups
+CREATE INDEX idx_assets_labels ON assets USING GIN (labels);
@@ -0,0 +1,6 @@
+-- Let the application own assets.updated_at
+-- Migration: 20250501000001_asset_updated_at_app_managed
+
+-- The trigger replaced updated_at with NOW() on every update, so the value
+-- written (and handed to clients as an ETag) never matched the stored row
+DROP TRIGGER IF EXISTS update_assets_updated_at ON assets;
@@ -92,7 +92,10 @@ pub struct RedisCache {
 impl RedisCache {
     /// Create a new Redis cache client
     pub async fn new(config: CacheConfig) -> DbResult<Self> {
-        info!("Connecting to Redis at {}", mask_redis_url(&config.redis_url));
+        info!(
+            "Connecting to Redis at {}",
+            mask_redis_url(&config.redis_url)
+        );
 
         let client = Client::open(config.redis_url.clone())
             .map_err(|e| DbError::Configuration(format!("Invalid Redis URL: {}", e)))?;
@@ -133,18 +136,16 @@ impl RedisCache {
 
         let mut conn = self.connection.clone();
 
-        let data: Option<Vec<u8>> = conn
-            .get(key)
-            .await
-            .map_err(|e| {
-                warn!("Cache GET error for key {}: {}", key, e);
-                DbError::Cache(format!("Failed to get from cache: {}", e))
-            })?;
+        let data: Option<Vec<u8>> = conn.get(key).await.map_err(|e| {
+            warn!("Cache GET error for key {}: {}", key, e);
+            DbError::Cache(format!("Failed to get from cache: {}", e))
+        })?;
 
         match data {
             Some(bytes) => {
-                let value: T = serde_json::from_slice(&bytes)
-                    .map_err(|e| DbError::Serialization(format!("Failed to deserialize cached value: {}", e)))?;
+                let value: T = serde_json::from_slice(&bytes).map_err(|e| {
+                    DbError::Serialization(format!("Failed to deserialize cached value: {}", e))
+                })?;
 
                 debug!("Cache HIT: {}", key);
                 Ok(Some(value))
@@ -184,12 +185,10 @@ impl RedisCache {
 
         let mut conn = self.connection.clone();
 
-        conn.del::<_, ()>(key)
-            .await
-            .map_err(|e| {
-                error!("Cache DELETE error for key {}: {}", key, e);
-                DbError::Cache(format!("Failed to delete from cache: {}", e))
-            })?;
+        conn.del::<_, ()>(key).await.map_err(|e| {
+            error!("Cache DELETE error for key {}: {}", key, e);
+            DbError::Cache(format!("Failed to delete from cache: {}", e))
+        })?;
 
         Ok(())
     }
@@ -225,7 +224,10 @@ impl RedisCache {
             .await
             .map_err(|e| DbError::Cache(format!("Failed to delete keys: {}", e)))?;
 
-        info!("Invalidated {} cache keys matching pattern: {}", count, pattern);
+        info!(
+            "Invalidated {} cache keys matching pattern: {}",
+            count, pattern
+        );
         Ok(count)
     }
 
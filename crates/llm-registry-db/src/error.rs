@@ -39,6 +39,10 @@ pub enum DbError {
     #[error("Asset already exists: {0}")]
     AlreadyExists(String),
 
+    /// Asset was modified since the version an update was based on
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
     /// Constraint violation
     #[error("Constraint violation: {0}")]
     ConstraintViolation(String),
@@ -106,7 +110,10 @@ impl DbError {
 
     /// Check if this error is a duplicate/already exists error
     pub fn is_already_exists(&self) -> bool {
-        matches!(self, DbError::AlreadyExists(_) | DbError::UniqueViolation(_))
+        matches!(
+            self,
+            DbError::AlreadyExists(_) | DbError::UniqueViolation(_)
+        )
     }
 
     /// Check if this is a transient error that could be retried
@@ -168,13 +175,9 @@ impl From<sqlx::Error> for DbError {
                 DbError::InvalidData(format!("Column not found: {}", col))
             }
 
-            sqlx::Error::Decode(msg) => {
-                DbError::Serialization(format!("Decode error: {}", msg))
-            }
+            sqlx::Error::Decode(msg) => DbError::Serialization(format!("Decode error: {}", msg)),
 
-            sqlx::Error::Migrate(migrate_err) => {
-                DbError::Migration(format!("{}", migrate_err))
-            }
+            sqlx::Error::Migrate(migrate_err) => DbError::Migration(format!("{}", migrate_err)),
 
             _ => DbError::Internal(format!("{}", err)),
         }
@@ -233,6 +236,9 @@ mod tests {
         assert_eq!(err.to_string(), "Asset not found: asset-123");
 
         let err = DbError::UniqueViolation("duplicate key".to_string());
-        assert_eq!(err.to_string(), "Unique constraint violation: duplicate key");
+        assert_eq!(
+            err.to_string(),
+            "Unique constraint violation: duplicate key"
+        );
     }
 }
@@ -157,7 +157,11 @@ pub trait EventStore: Send + Sync {
     ///
     /// # Returns
     /// * Vector of events for the asset, ordered by timestamp descending
-    async fn get_asset_events(&self, asset_id: &AssetId, limit: i64) -> DbResult<Vec<RegistryEvent>>;
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>>;
 
     /// Get the latest event for an asset
     ///
@@ -354,7 +358,11 @@ impl EventStore for PostgresEventStore {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn get_asset_events(&self, asset_id: &AssetId, limit: i64) -> DbResult<Vec<RegistryEvent>> {
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>> {
         debug!("Getting events for asset");
 
         let rows = sqlx::query(
@@ -413,10 +421,11 @@ impl EventStore for PostgresEventStore {
     }
 
     async fn count_by_type(&self, event_type: &str) -> DbResult<i64> {
-        let row = sqlx::query("SELECT COUNT(*) as count FROM registry_events WHERE event_type = $1")
-            .bind(event_type)
-            .fetch_one(&self.pool)
-            .await?;
+        let row =
+            sqlx::query("SELECT COUNT(*) as count FROM registry_events WHERE event_type = $1")
+                .bind(event_type)
+                .fetch_one(&self.pool)
+                .await?;
 
         Ok(row.get("count"))
     }
@@ -472,8 +481,8 @@ fn row_to_event(row: PgRow) -> DbResult<RegistryEvent> {
     let actor: Option<String> = row.get("actor");
 
     let metadata_json: JsonValue = row.get("metadata");
-    let context = serde_json::from_value(metadata_json)
-        .unwrap_or_else(|_| std::collections::HashMap::new());
+    let context =
+        serde_json::from_value(metadata_json).unwrap_or_else(|_| std::collections::HashMap::new());
 
     Ok(RegistryEvent {
         event_type,
@@ -62,7 +62,10 @@ pub use pool::{
     PoolStats,
 };
 pub use postgres::PostgresAssetRepository;
-pub use repository::{AssetRepository, SearchQuery, SearchResults, SortField, SortOrder};
+pub use repository::{
+    AssetRepository, AssetStats, DependentsReindex, ExpiryCutoff, FacetField, SearchQuery,
+    SearchResults, SortField, SortOrder, TagMatch,
+};
 
 // Re-export sqlx types that users may need
 pub use sqlx::postgres::PgPool;
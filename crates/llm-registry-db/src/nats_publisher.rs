@@ -86,10 +86,7 @@ pub struct NatsEventPublisher {
 impl NatsEventPublisher {
     /// Create a new NATS event publisher
     pub async fn new(config: NatsPublisherConfig) -> DbResult<Self> {
-        info!(
-            "Connecting to NATS server at {}",
-            config.server_url
-        );
+        info!("Connecting to NATS server at {}", config.server_url);
 
         let connect_options = ConnectOptions::new()
             .name(&config.client_name)
@@ -108,10 +105,7 @@ impl NatsEventPublisher {
 
         info!("Successfully connected to NATS");
 
-        Ok(Self {
-            client,
-            config,
-        })
+        Ok(Self { client, config })
     }
 
     /// Publish an event to NATS
@@ -244,6 +238,7 @@ fn event_type_to_subject(event_type: &EventType) -> &'static str {
         EventType::AssetRegistered { .. } => "asset.registered",
         EventType::AssetUpdated { .. } => "asset.updated",
         EventType::AssetDeleted { .. } => "asset.deleted",
+        EventType::AssetRestored { .. } => "asset.restored",
         EventType::AssetStatusChanged { .. } => "asset.status_changed",
         EventType::AssetDownloaded { .. } => "asset.downloaded",
         EventType::ChecksumVerified { .. } => "checksum.verified",
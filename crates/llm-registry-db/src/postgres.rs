@@ -5,20 +5,23 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use llm_registry_core::Version;
 use llm_registry_core::{
-    Asset, AssetId, AssetMetadata, AssetStatus, AssetType, Checksum, HashAlgorithm, Provenance,
-    StorageBackend, StorageLocation,
+    Asset, AssetId, AssetMetadata, AssetStatus, AssetType, Checksum, HashAlgorithm, LabelOperator,
+    LabelSelector, Provenance, StorageBackend, StorageLocation,
 };
-use semver::Version;
 use serde_json::Value as JsonValue;
-use sqlx::postgres::PgRow;
+use sqlx::postgres::{PgArguments, PgRow};
 use sqlx::{PgPool, Row};
 use std::collections::HashMap;
 use std::str::FromStr;
 use tracing::{debug, instrument};
 
 use crate::error::{DbError, DbResult};
-use crate::repository::{AssetRepository, SearchQuery, SearchResults, SortField, SortOrder};
+use crate::repository::{
+    AssetRepository, AssetStats, DependentsReindex, FacetField, SearchQuery, SearchResults,
+    SortField, SortOrder, TagMatch,
+};
 
 /// PostgreSQL implementation of AssetRepository
 #[derive(Debug, Clone)]
@@ -41,14 +44,14 @@ impl PostgresAssetRepository {
 #[async_trait]
 impl AssetRepository for PostgresAssetRepository {
     #[instrument(skip(self, asset), fields(asset_id = %asset.id, asset_name = %asset.metadata.name))]
-    async fn create(&self, asset: Asset) -> DbResult<Asset> {
+    async fn create(&self, mut asset: Asset) -> DbResult<Asset> {
         debug!("Creating asset in database");
 
         // Start a transaction
         let mut tx = self.pool.begin().await?;
 
         // Insert main asset record
-        sqlx::query(
+        let row = sqlx::query(
             r#"
             INSERT INTO assets (
                 id, name, version, asset_type, status,
@@ -57,7 +60,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, deleted_at, metadata, labels
             ) VALUES (
                 $1, $2, $3, $4, $5,
                 $6, $7, $8, $9,
@@ -65,8 +68,9 @@ impl AssetRepository for PostgresAssetRepository {
                 $12, $13, $14,
                 $15, $16, $17,
                 $18, $19, $20, $21,
-                $22, $23, $24, $25
+                $22, $23, $24, $25, $26, $27
             )
+            RETURNING created_at, updated_at
             "#,
         )
         .bind(&asset.id.to_string())
@@ -75,8 +79,18 @@ impl AssetRepository for PostgresAssetRepository {
         .bind(&asset.asset_type.to_string())
         .bind(&asset.status.to_string())
         .bind(&asset.storage.backend.to_string())
-        .bind(asset.storage.uri.as_ref().unwrap_or(&asset.storage.get_uri()))
-        .bind(if asset.storage.path.is_empty() { None } else { Some(&asset.storage.path) })
+        .bind(
+            asset
+                .storage
+                .uri
+                .as_ref()
+                .unwrap_or(&asset.storage.get_uri()),
+        )
+        .bind(if asset.storage.path.is_empty() {
+            None
+        } else {
+            Some(&asset.storage.path)
+        })
         .bind(asset.metadata.size_bytes.map(|s| s as i64))
         .bind(&asset.checksum.algorithm.to_string())
         .bind(&asset.checksum.value)
@@ -87,15 +101,43 @@ impl AssetRepository for PostgresAssetRepository {
         .bind(&asset.metadata.license)
         .bind(&asset.metadata.content_type)
         .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.source_repo.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.commit_hash.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.build_id.as_deref()))
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.source_repo.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.commit_hash.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.build_id.as_deref()),
+        )
         .bind(&asset.created_at)
         .bind(&asset.updated_at)
         .bind(&asset.deprecated_at)
+        .bind(asset.deleted_at)
         .bind(serde_json::to_value(&asset.metadata.annotations)?)
-        .execute(&mut *tx)
-        .await?;
+        .bind(serde_json::to_value(&asset.metadata.labels)?)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match DbError::from(e) {
+            // UNIQUE(name, version): a concurrent registration won the race
+            DbError::UniqueViolation(_) => DbError::AlreadyExists(format!(
+                "{}@{}",
+                asset.metadata.name, asset.metadata.version
+            )),
+            other => other,
+        })?;
+        // Stored at microsecond precision; return what was stored
+        asset.created_at = row.try_get("created_at")?;
+        asset.updated_at = row.try_get("updated_at")?;
 
         // Insert tags
         for tag in &asset.metadata.tags {
@@ -115,7 +157,9 @@ impl AssetRepository for PostgresAssetRepository {
         // Insert dependencies
         for dep in &asset.dependencies {
             let dep_id = dep.as_id().ok_or_else(|| {
-                DbError::InvalidData("Dependency must be resolved to ID before persisting".to_string())
+                DbError::InvalidData(
+                    "Dependency must be resolved to ID before persisting".to_string(),
+                )
             })?;
 
             sqlx::query(
@@ -152,7 +196,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, deleted_at, metadata, labels
             FROM assets
             WHERE id = $1
             "#,
@@ -188,7 +232,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, deleted_at, metadata, labels
             FROM assets
             WHERE name = $1 AND version = $2
             "#,
@@ -227,7 +271,7 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, deleted_at, metadata, labels
             FROM assets
             WHERE id = ANY($1)
             "#,
@@ -260,114 +304,52 @@ impl AssetRepository for PostgresAssetRepository {
                 a.signature_algorithm, a.signature_value, a.signature_key_id,
                 a.description, a.license, a.content_type,
                 a.author, a.source_repo, a.commit_hash, a.build_id,
-                a.created_at, a.updated_at, a.deprecated_at, a.metadata
+                a.created_at, a.updated_at, a.deprecated_at, a.deleted_at, a.metadata, a.labels
             FROM assets a
             WHERE 1=1
             "#,
         );
 
-        let mut conditions = Vec::new();
-        let mut bind_values: Vec<String> = Vec::new();
-        let mut param_num = 1;
-
-        // Text search
-        if let Some(ref text) = query.text {
-            conditions.push(format!(
-                "(a.name ILIKE ${} OR a.description ILIKE ${})",
-                param_num, param_num + 1
-            ));
-            bind_values.push(format!("%{}%", text));
-            bind_values.push(format!("%{}%", text));
-            param_num += 2;
-        }
-
-        // Asset type filter
-        if !query.asset_types.is_empty() {
-            conditions.push(format!("a.asset_type = ANY(${})", param_num));
-            // This is a placeholder - we'll use a different approach for ANY
-            param_num += 1;
-        }
-
-        // Author filter
-        if let Some(ref author) = query.author {
-            conditions.push(format!("a.author = ${}", param_num));
-            bind_values.push(author.clone());
-            param_num += 1;
-        }
-
-        // Storage backend filter
-        if let Some(ref backend) = query.storage_backend {
-            conditions.push(format!("a.storage_backend = ${}", param_num));
-            bind_values.push(backend.clone());
-            param_num += 1;
-        }
-
-        // Deprecated filter
-        if query.exclude_deprecated {
-            conditions.push("a.deprecated_at IS NULL".to_string());
-        }
-
-        // Tag filter - must have all specified tags
-        if !query.tags.is_empty() {
-            let tag_condition = format!(
-                "a.id IN (
-                    SELECT asset_id FROM asset_tags
-                    WHERE tag = ANY(${}::text[])
-                    GROUP BY asset_id
-                    HAVING COUNT(DISTINCT tag) = {}
-                )",
-                param_num,
-                query.tags.len()
-            );
-            conditions.push(tag_condition);
-            #[allow(unused_assignments)]
-            {
-                param_num += 1;
-            }
-        }
-
-        // Add conditions to query
-        if !conditions.is_empty() {
-            sql.push_str(" AND ");
-            sql.push_str(&conditions.join(" AND "));
-        }
+        let filter = SearchFilter::new(query);
+        sql.push_str(&filter.where_clause());
 
         // Add ORDER BY
-        let sort_field = match query.sort_by {
-            SortField::CreatedAt => "a.created_at",
-            SortField::UpdatedAt => "a.updated_at",
-            SortField::Name => "a.name",
-            SortField::Version => "a.version",
-            SortField::SizeBytes => "a.size_bytes",
+        let sort_keys: &[&str] = match query.sort_by {
+            SortField::CreatedAt => &["a.created_at"],
+            SortField::UpdatedAt => &["a.updated_at"],
+            SortField::Name => &["a.name"],
+            // Semver precedence: numeric MAJOR.MINOR.PATCH, then a release
+            // after its pre-releases, then pre-release tags as text
+            SortField::Version => &[
+                "string_to_array(regexp_replace(a.version, '[-+].*$', ''), '.')::bigint[]",
+                "(split_part(a.version, '+', 1) NOT LIKE '%-%')",
+                "a.version",
+            ],
+            SortField::SizeBytes => &["a.size_bytes"],
         };
 
         let sort_order = match query.sort_order {
             SortOrder::Ascending => "ASC",
             SortOrder::Descending => "DESC",
         };
+        let sort_field = sort_keys.join(&format!(" {}, ", sort_order));
+
+        // Rank name matches above description-only matches for free-text queries
+        let relevance = match filter.q_param {
+            Some(param) => format!("(a.name ILIKE ${}) DESC, ", param),
+            None => String::new(),
+        };
 
-        sql.push_str(&format!(" ORDER BY {} {}", sort_field, sort_order));
+        // Tie-break on id so pagination is stable across equal sort keys
+        sql.push_str(&format!(
+            " ORDER BY {}{} {}, a.id ASC",
+            relevance, sort_field, sort_order
+        ));
 
         // Add LIMIT and OFFSET
         sql.push_str(&format!(" LIMIT {} OFFSET {}", query.limit, query.offset));
 
-        // For simplicity, we'll use a simpler approach - rebuild with sqlx query builder
-        // In production, you'd want to use a query builder or macro for this
-        let mut final_query = sqlx::query(&sql);
-
-        // Bind parameters in order
-        for value in &bind_values {
-            final_query = final_query.bind(value);
-        }
-
-        let types: Vec<String> = query.asset_types.iter().map(|t| t.to_string()).collect();
-        if !query.asset_types.is_empty() {
-            final_query = final_query.bind(&types);
-        }
-
-        if !query.tags.is_empty() {
-            final_query = final_query.bind(&query.tags);
-        }
+        let final_query = filter.bind(sqlx::query(&sql));
 
         let rows = final_query.fetch_all(&self.pool).await?;
 
@@ -391,93 +373,16 @@ impl AssetRepository for PostgresAssetRepository {
 
     #[instrument(skip(self, asset), fields(asset_id = %asset.id))]
     async fn update(&self, asset: Asset) -> DbResult<Asset> {
-        debug!("Updating asset");
-
-        let mut tx = self.pool.begin().await?;
-
-        let result = sqlx::query(
-            r#"
-            UPDATE assets SET
-                name = $2,
-                version = $3,
-                asset_type = $4,
-                status = $5,
-                storage_backend = $6,
-                storage_uri = $7,
-                storage_path = $8,
-                size_bytes = $9,
-                checksum_algorithm = $10,
-                checksum_value = $11,
-                signature_algorithm = $12,
-                signature_value = $13,
-                signature_key_id = $14,
-                description = $15,
-                license = $16,
-                content_type = $17,
-                author = $18,
-                source_repo = $19,
-                commit_hash = $20,
-                build_id = $21,
-                deprecated_at = $22,
-                metadata = $23,
-                updated_at = $24
-            WHERE id = $1
-            "#,
-        )
-        .bind(&asset.id.to_string())
-        .bind(&asset.metadata.name)
-        .bind(&asset.metadata.version.to_string())
-        .bind(&asset.asset_type.to_string())
-        .bind(&asset.status.to_string())
-        .bind(&asset.storage.backend.to_string())
-        .bind(asset.storage.uri.as_ref().unwrap_or(&asset.storage.get_uri()))
-        .bind(if asset.storage.path.is_empty() { None } else { Some(&asset.storage.path) })
-        .bind(asset.metadata.size_bytes.map(|s| s as i64))
-        .bind(&asset.checksum.algorithm.to_string())
-        .bind(&asset.checksum.value)
-        .bind(None::<&str>)
-        .bind(None::<&str>)
-        .bind(None::<&str>)
-        .bind(&asset.metadata.description)
-        .bind(&asset.metadata.license)
-        .bind(&asset.metadata.content_type)
-        .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.source_repo.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.commit_hash.as_deref()))
-        .bind(asset.provenance.as_ref().and_then(|p| p.build_id.as_deref()))
-        .bind(&asset.deprecated_at)
-        .bind(serde_json::to_value(&asset.metadata.annotations)?)
-        .bind(Utc::now())
-        .execute(&mut *tx)
-        .await?;
-
-        if result.rows_affected() == 0 {
-            return Err(DbError::NotFound(format!("Asset {} not found", asset.id)));
-        }
-
-        // Update tags - delete and re-insert for simplicity
-        sqlx::query("DELETE FROM asset_tags WHERE asset_id = $1")
-            .bind(&asset.id.to_string())
-            .execute(&mut *tx)
-            .await?;
-
-        for tag in &asset.metadata.tags {
-            sqlx::query(
-                r#"
-                INSERT INTO asset_tags (asset_id, tag)
-                VALUES ($1, $2)
-                "#,
-            )
-            .bind(&asset.id.to_string())
-            .bind(tag)
-            .execute(&mut *tx)
-            .await?;
-        }
-
-        tx.commit().await?;
+        self.write_update(asset, None).await
+    }
 
-        debug!("Asset updated successfully");
-        Ok(asset)
+    #[instrument(skip(self, asset), fields(asset_id = %asset.id))]
+    async fn update_if_unmodified(
+        &self,
+        asset: Asset,
+        expected_updated_at: DateTime<Utc>,
+    ) -> DbResult<Asset> {
+        self.write_update(asset, Some(expected_updated_at)).await
     }
 
     #[instrument(skip(self), fields(asset_id = %id))]
@@ -510,9 +415,9 @@ impl AssetRepository for PostgresAssetRepository {
                 signature_algorithm, signature_value, signature_key_id,
                 description, license, content_type,
                 author, source_repo, commit_hash, build_id,
-                created_at, updated_at, deprecated_at, metadata
+                created_at, updated_at, deprecated_at, deleted_at, metadata, labels
             FROM assets
-            WHERE name = $1
+            WHERE name = $1 AND deleted_at IS NULL
             ORDER BY created_at DESC
             "#,
         )
@@ -543,10 +448,10 @@ impl AssetRepository for PostgresAssetRepository {
                 a.signature_algorithm, a.signature_value, a.signature_key_id,
                 a.description, a.license, a.content_type,
                 a.author, a.source_repo, a.commit_hash, a.build_id,
-                a.created_at, a.updated_at, a.deprecated_at, a.metadata
+                a.created_at, a.updated_at, a.deprecated_at, a.deleted_at, a.metadata, a.labels
             FROM assets a
             INNER JOIN asset_dependencies d ON a.id = d.dependency_id
-            WHERE d.asset_id = $1
+            WHERE d.asset_id = $1 AND a.deleted_at IS NULL
             "#,
         )
         .bind(&id.to_string())
@@ -576,10 +481,10 @@ impl AssetRepository for PostgresAssetRepository {
                 a.signature_algorithm, a.signature_value, a.signature_key_id,
                 a.description, a.license, a.content_type,
                 a.author, a.source_repo, a.commit_hash, a.build_id,
-                a.created_at, a.updated_at, a.deprecated_at, a.metadata
+                a.created_at, a.updated_at, a.deprecated_at, a.deleted_at, a.metadata, a.labels
             FROM assets a
             INNER JOIN asset_dependencies d ON a.id = d.asset_id
-            WHERE d.dependency_id = $1
+            WHERE d.dependency_id = $1 AND a.deleted_at IS NULL
             "#,
         )
         .bind(&id.to_string())
@@ -637,10 +542,7 @@ impl AssetRepository for PostgresAssetRepository {
             .fetch_all(&self.pool)
             .await?;
 
-        let tags = rows
-            .iter()
-            .map(|row| row.get::<String, _>("tag"))
-            .collect();
+        let tags = rows.iter().map(|row| row.get::<String, _>("tag")).collect();
 
         Ok(tags)
     }
@@ -653,12 +555,77 @@ impl AssetRepository for PostgresAssetRepository {
             .fetch_all(&self.pool)
             .await?;
 
-        let tags = rows
+        let tags = rows.iter().map(|row| row.get::<String, _>("tag")).collect();
+
+        Ok(tags)
+    }
+
+    #[instrument(skip(self))]
+    async fn count_tags(&self, prefix: Option<&str>) -> DbResult<Vec<(String, i64)>> {
+        debug!("Counting tags");
+
+        let rows = sqlx::query(
+            "SELECT t.tag, COUNT(*) AS count FROM asset_tags t \
+             JOIN assets a ON a.id = t.asset_id \
+             WHERE a.deleted_at IS NULL AND ($1::text IS NULL OR t.tag LIKE $1) \
+             GROUP BY t.tag ORDER BY count DESC, t.tag",
+        )
+        .bind(prefix.map(|p| format!("{}%", escape_like(p))))
+        .fetch_all(&self.pool)
+        .await?;
+
+        let counts = rows
             .iter()
-            .map(|row| row.get::<String, _>("tag"))
+            .map(|row| (row.get::<String, _>("tag"), row.get::<i64, _>("count")))
             .collect();
 
-        Ok(tags)
+        Ok(counts)
+    }
+
+    #[instrument(skip(self, query))]
+    async fn count_facet(
+        &self,
+        query: &SearchQuery,
+        field: FacetField,
+    ) -> DbResult<Vec<(String, i64)>> {
+        debug!(?field, "Counting facet values");
+
+        let (value, join) = match field {
+            FacetField::AssetType => ("a.asset_type", ""),
+            FacetField::Tag => ("t.tag", " JOIN asset_tags t ON t.asset_id = a.id"),
+        };
+        let filter = SearchFilter::new(query);
+        let sql = format!(
+            "SELECT {0} AS value, COUNT(*) AS count FROM assets a{1} WHERE 1=1{2} GROUP BY {0}",
+            value,
+            join,
+            filter.where_clause()
+        );
+
+        let rows = filter.bind(sqlx::query(&sql)).fetch_all(&self.pool).await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| (row.get::<String, _>("value"), row.get::<i64, _>("count")))
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_checksums_by_prefix(&self, prefix: &str, limit: i64) -> DbResult<Vec<String>> {
+        debug!("Resolving checksum prefix");
+
+        let rows = sqlx::query(
+            "SELECT DISTINCT checksum_value FROM assets WHERE checksum_value LIKE $1 LIMIT $2",
+        )
+        .bind(format!("{}%", escape_like(prefix)))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| row.get::<String, _>("checksum_value"))
+            .collect())
     }
 
     #[instrument(skip(self))]
@@ -695,12 +662,35 @@ impl AssetRepository for PostgresAssetRepository {
         Ok(())
     }
 
-    #[instrument(skip(self))]
-    async fn remove_dependency(
+    #[instrument(skip(self, dependency_ids), fields(count = dependency_ids.len()))]
+    async fn add_dependencies(
         &self,
         asset_id: &AssetId,
-        dependency_id: &AssetId,
+        dependency_ids: &[AssetId],
     ) -> DbResult<()> {
+        debug!("Adding dependency relationships");
+
+        let mut tx = self.pool.begin().await?;
+        for dependency_id in dependency_ids {
+            sqlx::query(
+                r#"
+                INSERT INTO asset_dependencies (asset_id, dependency_id)
+                VALUES ($1, $2)
+                ON CONFLICT (asset_id, dependency_id) DO NOTHING
+                "#,
+            )
+            .bind(asset_id.to_string())
+            .bind(dependency_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()> {
         debug!("Removing dependency relationship");
 
         sqlx::query("DELETE FROM asset_dependencies WHERE asset_id = $1 AND dependency_id = $2")
@@ -712,6 +702,24 @@ impl AssetRepository for PostgresAssetRepository {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn rebuild_dependents_index(&self) -> DbResult<DependentsReindex> {
+        debug!("Counting dependency edges");
+
+        // Forward and reverse lookups both read asset_dependencies, so the
+        // reverse view cannot drift from the declared edges and there is
+        // nothing to add or remove; only the edges are counted.
+        let row = sqlx::query("SELECT COUNT(*) as count FROM asset_dependencies")
+            .fetch_one(&self.pool)
+            .await?;
+
+        let processed: i64 = row.get("count");
+        Ok(DependentsReindex {
+            edges_processed: processed as u64,
+            ..DependentsReindex::default()
+        })
+    }
+
     #[instrument(skip(self))]
     async fn count_assets(&self) -> DbResult<i64> {
         let row = sqlx::query("SELECT COUNT(*) as count FROM assets")
@@ -721,6 +729,30 @@ impl AssetRepository for PostgresAssetRepository {
         Ok(row.get("count"))
     }
 
+    #[instrument(skip(self))]
+    async fn asset_stats(&self) -> DbResult<AssetStats> {
+        let rows = sqlx::query(
+            "SELECT asset_type, status, deleted_at IS NOT NULL AS deleted, \
+             COUNT(*) AS count, COALESCE(SUM(size_bytes), 0)::BIGINT AS size_bytes \
+             FROM assets GROUP BY 1, 2, 3",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut stats = AssetStats::default();
+        for row in &rows {
+            stats.add(
+                row.get("asset_type"),
+                row.get("status"),
+                row.get("deleted"),
+                row.get::<i64, _>("count") as u64,
+                row.get::<i64, _>("size_bytes") as u64,
+            );
+        }
+
+        Ok(stats)
+    }
+
     #[instrument(skip(self))]
     async fn count_by_type(&self, asset_type: &AssetType) -> DbResult<i64> {
         let row = sqlx::query("SELECT COUNT(*) as count FROM assets WHERE asset_type = $1")
@@ -742,6 +774,146 @@ impl AssetRepository for PostgresAssetRepository {
 }
 
 impl PostgresAssetRepository {
+    /// Update an asset, optionally only if its `updated_at` is unchanged
+    async fn write_update(
+        &self,
+        mut asset: Asset,
+        expected_updated_at: Option<DateTime<Utc>>,
+    ) -> DbResult<Asset> {
+        debug!("Updating asset");
+
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE assets SET
+                name = $2,
+                version = $3,
+                asset_type = $4,
+                status = $5,
+                storage_backend = $6,
+                storage_uri = $7,
+                storage_path = $8,
+                size_bytes = $9,
+                checksum_algorithm = $10,
+                checksum_value = $11,
+                signature_algorithm = $12,
+                signature_value = $13,
+                signature_key_id = $14,
+                description = $15,
+                license = $16,
+                content_type = $17,
+                author = $18,
+                source_repo = $19,
+                commit_hash = $20,
+                build_id = $21,
+                deprecated_at = $22,
+                metadata = $23,
+                updated_at = $24,
+                deleted_at = $25,
+                labels = $26
+            WHERE id = $1 AND ($27::timestamptz IS NULL OR updated_at = $27)
+            RETURNING updated_at
+            "#,
+        )
+        .bind(&asset.id.to_string())
+        .bind(&asset.metadata.name)
+        .bind(&asset.metadata.version.to_string())
+        .bind(&asset.asset_type.to_string())
+        .bind(&asset.status.to_string())
+        .bind(&asset.storage.backend.to_string())
+        .bind(
+            asset
+                .storage
+                .uri
+                .as_ref()
+                .unwrap_or(&asset.storage.get_uri()),
+        )
+        .bind(if asset.storage.path.is_empty() {
+            None
+        } else {
+            Some(&asset.storage.path)
+        })
+        .bind(asset.metadata.size_bytes.map(|s| s as i64))
+        .bind(&asset.checksum.algorithm.to_string())
+        .bind(&asset.checksum.value)
+        .bind(None::<&str>)
+        .bind(None::<&str>)
+        .bind(None::<&str>)
+        .bind(&asset.metadata.description)
+        .bind(&asset.metadata.license)
+        .bind(&asset.metadata.content_type)
+        .bind(asset.provenance.as_ref().and_then(|p| p.author.as_deref()))
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.source_repo.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.commit_hash.as_deref()),
+        )
+        .bind(
+            asset
+                .provenance
+                .as_ref()
+                .and_then(|p| p.build_id.as_deref()),
+        )
+        .bind(&asset.deprecated_at)
+        .bind(serde_json::to_value(&asset.metadata.annotations)?)
+        .bind(asset.updated_at)
+        .bind(asset.deleted_at)
+        .bind(serde_json::to_value(&asset.metadata.labels)?)
+        .bind(expected_updated_at)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        // Timestamps are stored at microsecond precision, so hand back what
+        // was stored; an ETag built from it must match the next If-Match
+        let Some(row) = result else {
+            let exists: bool =
+                sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM assets WHERE id = $1)")
+                    .bind(asset.id.to_string())
+                    .fetch_one(&mut *tx)
+                    .await?;
+            if exists && expected_updated_at.is_some() {
+                return Err(DbError::PreconditionFailed(format!(
+                    "Asset {} was modified concurrently",
+                    asset.id
+                )));
+            }
+            return Err(DbError::NotFound(format!("Asset {} not found", asset.id)));
+        };
+        asset.updated_at = row.try_get("updated_at")?;
+
+        // Update tags - delete and re-insert for simplicity
+        sqlx::query("DELETE FROM asset_tags WHERE asset_id = $1")
+            .bind(&asset.id.to_string())
+            .execute(&mut *tx)
+            .await?;
+
+        for tag in &asset.metadata.tags {
+            sqlx::query(
+                r#"
+                INSERT INTO asset_tags (asset_id, tag)
+                VALUES ($1, $2)
+                "#,
+            )
+            .bind(&asset.id.to_string())
+            .bind(tag)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        debug!("Asset updated successfully");
+        Ok(asset)
+    }
+
     /// Load tags and dependencies for an asset
     async fn load_asset_relations(&self, mut asset: Asset) -> DbResult<Asset> {
         // Load tags
@@ -749,12 +921,11 @@ impl PostgresAssetRepository {
         asset.metadata.tags = tags;
 
         // Load dependency references
-        let dep_rows = sqlx::query(
-            "SELECT dependency_id FROM asset_dependencies WHERE asset_id = $1"
-        )
-        .bind(&asset.id.to_string())
-        .fetch_all(&self.pool)
-        .await?;
+        let dep_rows =
+            sqlx::query("SELECT dependency_id FROM asset_dependencies WHERE asset_id = $1")
+                .bind(&asset.id.to_string())
+                .fetch_all(&self.pool)
+                .await?;
 
         asset.dependencies = dep_rows
             .iter()
@@ -808,18 +979,304 @@ impl PostgresAssetRepository {
             sql.push_str(" AND a.deprecated_at IS NULL");
         }
 
+        if !query.include_deleted {
+            sql.push_str(" AND a.deleted_at IS NULL");
+        }
+
+        let mut param_num = 1;
+        if query.q.is_some() {
+            sql.push_str(&format!(
+                " AND (a.name ILIKE ${0} OR a.description ILIKE ${0})",
+                param_num
+            ));
+            param_num += 1;
+        }
+
+        if query.content_hash_prefix.is_some() {
+            sql.push_str(&format!(" AND a.checksum_value LIKE ${}", param_num));
+            param_num += 1;
+        }
+
+        let mut label_values = Vec::new();
+        if let Some(ref selector) = query.label_selector {
+            let (label_conditions, values) = label_conditions(selector, param_num);
+            for condition in label_conditions {
+                sql.push_str(" AND ");
+                sql.push_str(&condition);
+            }
+            label_values = values;
+        }
+
         if !query.asset_types.is_empty() {
             let types: Vec<String> = query.asset_types.iter().map(|t| t.to_string()).collect();
             let placeholders: Vec<String> = types.iter().map(|t| format!("'{}'", t)).collect();
-            sql.push_str(&format!(" AND a.asset_type IN ({})", placeholders.join(", ")));
+            sql.push_str(&format!(
+                " AND a.asset_type IN ({})",
+                placeholders.join(", ")
+            ));
+        }
+
+        let mut count_query = sqlx::query(&sql);
+        if let Some(ref q) = query.q {
+            count_query = count_query.bind(format!("%{}%", escape_like(q)));
+        }
+        if let Some(ref prefix) = query.content_hash_prefix {
+            count_query = count_query.bind(format!("{}%", escape_like(prefix)));
+        }
+        for value in &label_values {
+            count_query = count_query.bind(value);
         }
 
-        let row = sqlx::query(&sql).fetch_one(&self.pool).await?;
+        let row = count_query.fetch_one(&self.pool).await?;
 
         Ok(row.get("count"))
     }
 }
 
+/// Value bound to a search filter placeholder
+enum FilterBind {
+    Text(String),
+    TextArray(Vec<String>),
+    Timestamp(DateTime<Utc>),
+}
+
+/// WHERE conditions of a search query, shared by every query over its matches
+///
+/// Placeholders are numbered from `$1` in the order values are bound.
+struct SearchFilter {
+    conditions: Vec<String>,
+    binds: Vec<FilterBind>,
+    /// Placeholder of the free-text pattern, for relevance ordering
+    q_param: Option<usize>,
+}
+
+impl SearchFilter {
+    fn new(query: &SearchQuery) -> Self {
+        let mut filter = Self {
+            conditions: Vec::new(),
+            binds: Vec::new(),
+            q_param: None,
+        };
+
+        // Text search
+        if let Some(ref text) = query.text {
+            let param = filter.push(FilterBind::Text(format!("%{}%", text)));
+            filter.conditions.push(format!(
+                "(a.name ILIKE ${0} OR a.description ILIKE ${0})",
+                param
+            ));
+        }
+
+        // Free-text query, matched literally
+        if let Some(ref q) = query.q {
+            let param = filter.push(FilterBind::Text(format!("%{}%", escape_like(q))));
+            filter.conditions.push(format!(
+                "(a.name ILIKE ${0} OR a.description ILIKE ${0})",
+                param
+            ));
+            filter.q_param = Some(param);
+        }
+
+        // Content hash prefix filter
+        if let Some(ref prefix) = query.content_hash_prefix {
+            let param = filter.push(FilterBind::Text(format!("{}%", escape_like(prefix))));
+            filter
+                .conditions
+                .push(format!("a.checksum_value LIKE ${}", param));
+        }
+
+        // Label selector
+        if let Some(ref selector) = query.label_selector {
+            let (label_conditions, label_values) =
+                label_conditions(selector, filter.binds.len() + 1);
+            filter.conditions.extend(label_conditions);
+            filter
+                .binds
+                .extend(label_values.into_iter().map(FilterBind::Text));
+        }
+
+        // Asset type filter
+        if !query.asset_types.is_empty() {
+            let types = query.asset_types.iter().map(|t| t.to_string()).collect();
+            let param = filter.push(FilterBind::TextArray(types));
+            filter
+                .conditions
+                .push(format!("a.asset_type = ANY(${}::text[])", param));
+        }
+
+        // Author filter
+        if let Some(ref author) = query.author {
+            let param = filter.push(FilterBind::Text(author.clone()));
+            filter.conditions.push(format!("a.author = ${}", param));
+        }
+
+        // Storage backend filter
+        if let Some(ref backend) = query.storage_backend {
+            let param = filter.push(FilterBind::Text(backend.clone()));
+            filter
+                .conditions
+                .push(format!("a.storage_backend = ${}", param));
+        }
+
+        // Deprecated filter
+        if query.exclude_deprecated {
+            filter
+                .conditions
+                .push("a.deprecated_at IS NULL".to_string());
+        }
+
+        // Soft-deleted filter
+        if !query.include_deleted {
+            filter.conditions.push("a.deleted_at IS NULL".to_string());
+        }
+
+        // Expiry filter - each state has its own cutoff, pinned assets never expire
+        if let Some(ref cutoff) = query.exclude_expired {
+            let mut expired = Vec::new();
+            if let Some(at) = cutoff.created {
+                let param = filter.push(FilterBind::Timestamp(at));
+                expired.push(format!(
+                    "(a.status IN ('active', 'non_compliant') AND a.created_at <= ${})",
+                    param
+                ));
+            }
+            if let Some(at) = cutoff.deprecated {
+                let param = filter.push(FilterBind::Timestamp(at));
+                expired.push(format!(
+                    "(a.status = 'deprecated' AND COALESCE(a.deprecated_at, a.updated_at) <= ${})",
+                    param
+                ));
+            }
+            if let Some(at) = cutoff.archived {
+                let param = filter.push(FilterBind::Timestamp(at));
+                expired.push(format!(
+                    "(a.status = 'archived' AND a.updated_at <= ${})",
+                    param
+                ));
+            }
+            if !expired.is_empty() {
+                let param = filter.push(FilterBind::Text(cutoff.pin_annotation.clone()));
+                filter.conditions.push(format!(
+                    "(NOT ({}) OR LOWER(COALESCE(a.metadata->>${}, '')) = 'true')",
+                    expired.join(" OR "),
+                    param
+                ));
+            }
+        }
+
+        // Keyset cursor - ids are compared as stored, like the id tie-break
+        if let Some((created_at, ref id)) = query.after {
+            let at = filter.push(FilterBind::Timestamp(created_at));
+            let id = filter.push(FilterBind::Text(id.to_string()));
+            filter
+                .conditions
+                .push(format!("(a.created_at, a.id) > (${}, ${})", at, id));
+        }
+
+        // Tag filter - all specified tags, or any of them
+        if !query.tags.is_empty() {
+            let param = filter.push(FilterBind::TextArray(query.tags.clone()));
+            filter.conditions.push(match query.tag_match {
+                TagMatch::All => format!(
+                    "a.id IN (
+                        SELECT asset_id FROM asset_tags
+                        WHERE tag = ANY(${}::text[])
+                        GROUP BY asset_id
+                        HAVING COUNT(DISTINCT tag) = {}
+                    )",
+                    param,
+                    query.tags.len()
+                ),
+                TagMatch::Any => format!(
+                    "a.id IN (
+                        SELECT asset_id FROM asset_tags
+                        WHERE tag = ANY(${}::text[])
+                    )",
+                    param
+                ),
+            });
+        }
+
+        filter
+    }
+
+    /// Add a bind value, returning its placeholder number
+    fn push(&mut self, value: FilterBind) -> usize {
+        self.binds.push(value);
+        self.binds.len()
+    }
+
+    /// The conditions as a suffix for `WHERE 1=1`
+    fn where_clause(&self) -> String {
+        self.conditions
+            .iter()
+            .map(|condition| format!(" AND {}", condition))
+            .collect()
+    }
+
+    /// Bind the filter values to `query` in placeholder order
+    fn bind<'q>(
+        &'q self,
+        mut query: sqlx::query::Query<'q, sqlx::Postgres, PgArguments>,
+    ) -> sqlx::query::Query<'q, sqlx::Postgres, PgArguments> {
+        for value in &self.binds {
+            query = match value {
+                FilterBind::Text(text) => query.bind(text),
+                FilterBind::TextArray(values) => query.bind(values),
+                FilterBind::Timestamp(at) => query.bind(at),
+            };
+        }
+        query
+    }
+}
+
+/// SQL conditions for a label selector, with their bind values in order
+///
+/// Placeholders are numbered from `first_param`.
+fn label_conditions(selector: &LabelSelector, first_param: usize) -> (Vec<String>, Vec<String>) {
+    let mut conditions = Vec::new();
+    let mut values = Vec::new();
+    let mut param = |value: &str| {
+        values.push(value.to_string());
+        format!("${}", first_param + values.len() - 1)
+    };
+
+    for requirement in &selector.requirements {
+        let key = param(&requirement.key);
+        let condition = match requirement.operator {
+            LabelOperator::Equals(ref value) => format!("a.labels ->> {} = {}", key, param(value)),
+            LabelOperator::NotEquals(ref value) => {
+                format!("(a.labels ->> {}) IS DISTINCT FROM {}", key, param(value))
+            }
+            LabelOperator::In(ref set) | LabelOperator::NotIn(ref set) => {
+                let list: Vec<String> = set.iter().map(|value| param(value)).collect();
+                if matches!(requirement.operator, LabelOperator::In(_)) {
+                    format!("a.labels ->> {} IN ({})", key, list.join(", "))
+                } else {
+                    format!(
+                        "(a.labels ->> {0} IS NULL OR a.labels ->> {0} NOT IN ({1}))",
+                        key,
+                        list.join(", ")
+                    )
+                }
+            }
+            LabelOperator::Exists => format!("a.labels ? {}", key),
+            LabelOperator::DoesNotExist => format!("NOT (a.labels ? {})", key),
+        };
+        conditions.push(condition);
+    }
+
+    (conditions, values)
+}
+
+/// Escape `LIKE` metacharacters so a value matches literally
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 /// Convert a database row to an Asset
 fn row_to_asset(row: PgRow) -> DbResult<Asset> {
     let id_str: String = row.get("id");
@@ -827,8 +1284,7 @@ fn row_to_asset(row: PgRow) -> DbResult<Asset> {
         .map_err(|e| DbError::InvalidData(format!("Invalid asset ID: {}", e)))?;
 
     let version_str: String = row.get("version");
-    let version = Version::parse(&version_str)
-        .map_err(|e| DbError::InvalidData(format!("Invalid version: {}", e)))?;
+    let version = Version::parse(&version_str).map_err(|e| DbError::InvalidData(e.to_string()))?;
 
     let asset_type_str: String = row.get("asset_type");
     let asset_type = parse_asset_type(&asset_type_str)?;
@@ -847,12 +1303,16 @@ fn row_to_asset(row: PgRow) -> DbResult<Asset> {
     let checksum_value: String = row.get("checksum_value");
 
     let metadata_json: JsonValue = row.get("metadata");
-    let annotations: HashMap<String, String> = serde_json::from_value(metadata_json)
-        .unwrap_or_default();
+    let annotations: HashMap<String, String> =
+        serde_json::from_value(metadata_json).unwrap_or_default();
+    let labels_json: JsonValue = row.get("labels");
+    let labels: HashMap<String, String> = serde_json::from_value(labels_json)
+        .map_err(|e| DbError::InvalidData(format!("Invalid labels: {}", e)))?;
 
     let created_at: DateTime<Utc> = row.get("created_at");
     let updated_at: DateTime<Utc> = row.get("updated_at");
     let deprecated_at: Option<DateTime<Utc>> = row.get("deprecated_at");
+    let deleted_at: Option<DateTime<Utc>> = row.get("deleted_at");
 
     let size_bytes: Option<i64> = row.get("size_bytes");
 
@@ -883,6 +1343,7 @@ fn row_to_asset(row: PgRow) -> DbResult<Asset> {
         license: row.get("license"),
         tags: Vec::new(), // Loaded separately
         annotations,
+        labels,
         size_bytes: size_bytes.map(|s| s as u64),
         content_type: row.get("content_type"),
     };
@@ -910,6 +1371,7 @@ fn row_to_asset(row: PgRow) -> DbResult<Asset> {
         created_at,
         updated_at,
         deprecated_at,
+        deleted_at,
     })
 }
 
@@ -965,3 +1427,67 @@ fn parse_hash_algorithm(s: &str) -> DbResult<HashAlgorithm> {
     HashAlgorithm::from_str(s)
         .map_err(|e| DbError::InvalidData(format!("Invalid hash algorithm: {}", e)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pool::{create_pool, PoolConfig};
+    use llm_registry_core::{AssetMetadata, Checksum, StorageBackend, StorageLocation};
+
+    /// Repository on the migrated database at `DATABASE_URL`
+    async fn repository() -> PostgresAssetRepository {
+        let url = std::env::var(crate::DEFAULT_DATABASE_URL_ENV)
+            .expect("DATABASE_URL must name a Postgres database");
+        PostgresAssetRepository::new(create_pool(&PoolConfig::new(url)).await.unwrap())
+    }
+
+    fn asset() -> Asset {
+        let id = AssetId::new();
+        let storage = StorageLocation::new(
+            StorageBackend::S3 {
+                bucket: "test".to_string(),
+                region: "us-east-1".to_string(),
+                endpoint: None,
+            },
+            format!("{}.bin", id),
+            None,
+        )
+        .unwrap();
+        let metadata = AssetMetadata::new(format!("etag-{}", id), Version::new(1, 0, 0));
+        let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+        Asset::new(id, AssetType::Model, metadata, storage, checksum).unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database at DATABASE_URL"]
+    async fn test_conditional_updates_chain_on_returned_version() {
+        let repository = repository().await;
+        let created = repository.create(asset()).await.unwrap();
+        let stored = repository.find_by_id(&created.id).await.unwrap().unwrap();
+        assert_eq!(created.updated_at, stored.updated_at);
+
+        // Each update is based on the version the previous one returned
+        let mut previous = created.clone();
+        for description in ["first", "second"] {
+            let mut next = previous.clone();
+            next.metadata.description = Some(description.to_string());
+            next.updated_at = Utc::now();
+            previous = repository
+                .update_if_unmodified(next, previous.updated_at)
+                .await
+                .unwrap();
+            let stored = repository.find_by_id(&created.id).await.unwrap().unwrap();
+            assert_eq!(previous.updated_at, stored.updated_at);
+        }
+
+        let mut stale = created.clone();
+        stale.updated_at = Utc::now();
+        let err = repository
+            .update_if_unmodified(stale, created.updated_at)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbError::PreconditionFailed(_)), "{:?}", err);
+
+        repository.delete(&created.id).await.unwrap();
+    }
+}
@@ -4,10 +4,13 @@
 //! allowing for different implementations (PostgreSQL, SQLite, in-memory, etc.).
 
 use async_trait::async_trait;
-use llm_registry_core::{Asset, AssetId, AssetType};
-use semver::Version;
+use chrono::{DateTime, Utc};
+use llm_registry_core::Version;
+use llm_registry_core::{Asset, AssetId, AssetStatus, AssetType, LabelSelector};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
 
-use crate::error::DbResult;
+use crate::error::{DbError, DbResult};
 
 /// Query parameters for searching assets
 #[derive(Debug, Clone, Default)]
@@ -15,21 +18,44 @@ pub struct SearchQuery {
     /// Text search across name, description, and annotations
     pub text: Option<String>,
 
+    /// Free-text query matched case-insensitively against name and
+    /// description; backends that can rank results put name matches first
+    pub q: Option<String>,
+
     /// Filter by asset types
     pub asset_types: Vec<AssetType>,
 
-    /// Filter by tags (AND logic - asset must have all tags)
+    /// Filter by tags; see `tag_match` for how multiple tags combine
     pub tags: Vec<String>,
 
+    /// Whether an asset must carry all of `tags` or at least one
+    pub tag_match: TagMatch,
+
+    /// Filter by labels; every requirement of the selector must hold
+    pub label_selector: Option<LabelSelector>,
+
     /// Filter by author
     pub author: Option<String>,
 
     /// Filter by storage backend
     pub storage_backend: Option<String>,
 
+    /// Filter by content hash (lowercase hex prefix of the checksum value)
+    pub content_hash_prefix: Option<String>,
+
     /// Only include non-deprecated assets
     pub exclude_deprecated: bool,
 
+    /// Also include soft-deleted assets
+    pub include_deleted: bool,
+
+    /// Exclude assets past their time-to-live
+    pub exclude_expired: Option<ExpiryCutoff>,
+
+    /// Only include assets whose `(created_at, id)` key is after this one;
+    /// a keyset cursor for scans sorted by ascending creation time
+    pub after: Option<(DateTime<Utc>, AssetId)>,
+
     /// Maximum number of results to return
     pub limit: i64,
 
@@ -62,6 +88,12 @@ impl SearchQuery {
         self
     }
 
+    /// Set free-text query
+    pub fn q(mut self, q: impl Into<String>) -> Self {
+        self.q = Some(q.into());
+        self
+    }
+
     /// Add an asset type filter
     pub fn asset_type(mut self, asset_type: AssetType) -> Self {
         self.asset_types.push(asset_type);
@@ -74,6 +106,18 @@ impl SearchQuery {
         self
     }
 
+    /// Set how multiple tag filters combine
+    pub fn tag_match(mut self, tag_match: TagMatch) -> Self {
+        self.tag_match = tag_match;
+        self
+    }
+
+    /// Set label selector filter
+    pub fn label_selector(mut self, selector: LabelSelector) -> Self {
+        self.label_selector = Some(selector);
+        self
+    }
+
     /// Set author filter
     pub fn author(mut self, author: impl Into<String>) -> Self {
         self.author = Some(author.into());
@@ -86,12 +130,37 @@ impl SearchQuery {
         self
     }
 
+    /// Set content hash prefix filter
+    pub fn content_hash_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.content_hash_prefix = Some(prefix.into());
+        self
+    }
+
     /// Include or exclude deprecated assets
     pub fn exclude_deprecated(mut self, exclude: bool) -> Self {
         self.exclude_deprecated = exclude;
         self
     }
 
+    /// Include or exclude soft-deleted assets
+    pub fn include_deleted(mut self, include: bool) -> Self {
+        self.include_deleted = include;
+        self
+    }
+
+    /// Exclude assets that are expired under `cutoff`
+    pub fn exclude_expired(mut self, cutoff: ExpiryCutoff) -> Self {
+        self.exclude_expired = Some(cutoff);
+        self
+    }
+
+    /// Only include assets created after `created_at`, or at that instant
+    /// with an id after `id`
+    pub fn after(mut self, created_at: DateTime<Utc>, id: AssetId) -> Self {
+        self.after = Some((created_at, id));
+        self
+    }
+
     /// Set pagination limit
     pub fn limit(mut self, limit: i64) -> Self {
         self.limit = limit;
@@ -117,6 +186,48 @@ impl SearchQuery {
     }
 }
 
+/// Point in time each asset state must have been entered by to be expired
+///
+/// An asset is expired once it has been in its current state since at or
+/// before the cutoff for that state; a `None` cutoff never expires. Assets
+/// carrying `pin_annotation` set to `true` never expire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiryCutoff {
+    /// Cutoff for the creation time of active and non-compliant assets
+    pub created: Option<DateTime<Utc>>,
+    /// Cutoff for the deprecation time of deprecated assets, falling back to
+    /// the last update when no deprecation time was recorded
+    pub deprecated: Option<DateTime<Utc>>,
+    /// Cutoff for the last update of archived assets
+    pub archived: Option<DateTime<Utc>>,
+    /// Annotation pinning an asset against expiry
+    pub pin_annotation: String,
+}
+
+impl ExpiryCutoff {
+    /// Whether the asset is expired under this cutoff
+    pub fn is_expired(&self, asset: &Asset) -> bool {
+        let pinned = asset
+            .metadata
+            .annotations
+            .get(&self.pin_annotation)
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+        if pinned {
+            return false;
+        }
+
+        let (since, cutoff) = match asset.status {
+            AssetStatus::Active | AssetStatus::NonCompliant => (asset.created_at, self.created),
+            AssetStatus::Deprecated => (
+                asset.deprecated_at.unwrap_or(asset.updated_at),
+                self.deprecated,
+            ),
+            AssetStatus::Archived => (asset.updated_at, self.archived),
+        };
+        cutoff.is_some_and(|cutoff| since <= cutoff)
+    }
+}
+
 /// Fields that can be used for sorting
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortField {
@@ -138,6 +249,25 @@ impl Default for SortField {
     }
 }
 
+/// How multiple tag filters combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagMatch {
+    /// Asset must have every tag
+    #[default]
+    All,
+    /// Asset must have at least one of the tags
+    Any,
+}
+
+/// Asset field whose values a facet count groups by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FacetField {
+    /// Group by asset type
+    AssetType,
+    /// Group by tag; an asset counts once for each of its tags
+    Tag,
+}
+
 /// Sort order
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SortOrder {
@@ -181,6 +311,58 @@ impl SearchResults {
     }
 }
 
+/// Outcome of rebuilding the dependents index
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DependentsReindex {
+    /// Forward edges examined
+    pub edges_processed: u64,
+    /// Edges missing from the index and added
+    pub edges_added: u64,
+    /// Indexed edges with no matching forward edge, removed
+    pub edges_removed: u64,
+    /// Cached dependency graphs dropped by the service layer; repositories
+    /// leave this at zero
+    pub cached_graphs_cleared: u64,
+}
+
+/// Aggregate asset counts and storage usage
+///
+/// Soft-deleted assets are only counted in `deleted`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AssetStats {
+    /// Assets that are not soft-deleted
+    pub total_assets: u64,
+    /// Assets by type
+    pub by_type: BTreeMap<String, u64>,
+    /// Assets by lifecycle status
+    pub by_status: BTreeMap<String, u64>,
+    /// Soft-deleted assets awaiting purge
+    pub deleted: u64,
+    /// Sum of `size_bytes` over assets that record a size
+    pub storage_bytes: u64,
+}
+
+impl AssetStats {
+    /// Count `count` assets of one type and status, `size_bytes` in total
+    pub fn add(
+        &mut self,
+        asset_type: &str,
+        status: &str,
+        deleted: bool,
+        count: u64,
+        size_bytes: u64,
+    ) {
+        if deleted {
+            self.deleted += count;
+            return;
+        }
+        self.total_assets += count;
+        *self.by_type.entry(asset_type.to_string()).or_default() += count;
+        *self.by_status.entry(status.to_string()).or_default() += count;
+        self.storage_bytes += size_bytes;
+    }
+}
+
 /// Repository trait for asset persistence operations
 ///
 /// This trait defines the interface for all asset database operations.
@@ -243,6 +425,31 @@ pub trait AssetRepository: Send + Sync {
     /// * Search results with matching assets and pagination metadata
     async fn search(&self, query: &SearchQuery) -> DbResult<SearchResults>;
 
+    /// Find distinct checksum values starting with a prefix
+    ///
+    /// Used to resolve short content hashes; callers typically pass a small
+    /// `limit` (e.g. 2) just to detect ambiguous prefixes.
+    ///
+    /// # Arguments
+    /// * `prefix` - Lowercase hex prefix of the checksum value
+    /// * `limit` - Maximum number of distinct values to return
+    async fn find_checksums_by_prefix(&self, prefix: &str, limit: i64) -> DbResult<Vec<String>> {
+        let query = SearchQuery::new()
+            .content_hash_prefix(prefix)
+            .exclude_deprecated(false)
+            .limit(i64::MAX);
+        let mut checksums: Vec<String> = Vec::new();
+        for asset in self.search(&query).await?.assets {
+            if !checksums.contains(&asset.checksum.value) {
+                checksums.push(asset.checksum.value);
+            }
+            if checksums.len() as i64 >= limit {
+                break;
+            }
+        }
+        Ok(checksums)
+    }
+
     /// Update an existing asset
     ///
     /// # Arguments
@@ -254,6 +461,39 @@ pub trait AssetRepository: Send + Sync {
     /// * `Err(DbError)` - For other database errors
     async fn update(&self, asset: Asset) -> DbResult<Asset>;
 
+    /// Update an asset only if it is unchanged since it was read
+    ///
+    /// The stored asset must still carry `expected_updated_at`; backends shared
+    /// between writers must check and write atomically. The default checks
+    /// and then writes, which only suits single-writer backends.
+    ///
+    /// # Arguments
+    /// * `asset` - The asset with updated fields (must have existing ID)
+    /// * `expected_updated_at` - `updated_at` of the version the update is based on
+    ///
+    /// # Returns
+    /// * `Ok(Asset)` - The updated asset
+    /// * `Err(DbError::NotFound)` - If the asset doesn't exist
+    /// * `Err(DbError::PreconditionFailed)` - If the asset was modified since
+    /// * `Err(DbError)` - For other database errors
+    async fn update_if_unmodified(
+        &self,
+        asset: Asset,
+        expected_updated_at: DateTime<Utc>,
+    ) -> DbResult<Asset> {
+        let current = self
+            .find_by_id(&asset.id)
+            .await?
+            .ok_or_else(|| DbError::NotFound(format!("Asset {} not found", asset.id)))?;
+        if current.updated_at != expected_updated_at {
+            return Err(DbError::PreconditionFailed(format!(
+                "Asset {} was modified concurrently",
+                asset.id
+            )));
+        }
+        self.update(asset).await
+    }
+
     /// Delete an asset by ID
     ///
     /// # Arguments
@@ -265,7 +505,7 @@ pub trait AssetRepository: Send + Sync {
     /// * `Err(DbError)` - For other database errors
     async fn delete(&self, id: &AssetId) -> DbResult<()>;
 
-    /// List all versions of an asset by name
+    /// List all versions of an asset by name, excluding soft-deleted versions
     ///
     /// # Arguments
     /// * `name` - The asset name
@@ -274,7 +514,7 @@ pub trait AssetRepository: Send + Sync {
     /// * Vector of assets with the given name, sorted by version descending
     async fn list_versions(&self, name: &str) -> DbResult<Vec<Asset>>;
 
-    /// Get all direct dependencies of an asset
+    /// Get all direct dependencies of an asset, excluding soft-deleted assets
     ///
     /// # Arguments
     /// * `id` - The asset ID
@@ -283,7 +523,8 @@ pub trait AssetRepository: Send + Sync {
     /// * Vector of assets that this asset depends on
     async fn list_dependencies(&self, id: &AssetId) -> DbResult<Vec<Asset>>;
 
-    /// Get all assets that depend on this asset (reverse dependencies)
+    /// Get all assets that depend on this asset (reverse dependencies),
+    /// excluding soft-deleted assets
     ///
     /// # Arguments
     /// * `id` - The asset ID
@@ -321,6 +562,59 @@ pub trait AssetRepository: Send + Sync {
     /// * Vector of all unique tags across all assets
     async fn list_all_tags(&self) -> DbResult<Vec<String>>;
 
+    /// Count the assets carrying each tag
+    ///
+    /// # Arguments
+    /// * `prefix` - Only count tags starting with this prefix
+    ///
+    /// # Returns
+    /// * `(tag, count)` pairs, most used first and ties ordered by tag
+    async fn count_tags(&self, prefix: Option<&str>) -> DbResult<Vec<(String, i64)>> {
+        let query = SearchQuery::new().exclude_deprecated(false).limit(i64::MAX);
+        let mut counts: Vec<(String, i64)> = Vec::new();
+        for asset in self.search(&query).await?.assets {
+            for tag in asset.metadata.tags {
+                if prefix.is_some_and(|p| !tag.starts_with(p)) {
+                    continue;
+                }
+                match counts.iter_mut().find(|(t, _)| *t == tag) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((tag, 1)),
+                }
+            }
+        }
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Ok(counts)
+    }
+
+    /// Count the assets matching `query` for each value of `field`
+    ///
+    /// Pagination and sorting in `query` are ignored.
+    ///
+    /// # Returns
+    /// * `(value, count)` pairs in no particular order
+    async fn count_facet(
+        &self,
+        query: &SearchQuery,
+        field: FacetField,
+    ) -> DbResult<Vec<(String, i64)>> {
+        let all = query.clone().offset(0).limit(i64::MAX);
+        let mut counts: BTreeMap<String, i64> = BTreeMap::new();
+        for asset in self.search(&all).await?.assets {
+            match field {
+                FacetField::AssetType => {
+                    *counts.entry(asset.asset_type.to_string()).or_default() += 1
+                }
+                FacetField::Tag => {
+                    for tag in asset.metadata.tags {
+                        *counts.entry(tag).or_default() += 1;
+                    }
+                }
+            }
+        }
+        Ok(counts.into_iter().collect())
+    }
+
     /// Add a dependency relationship between assets
     ///
     /// # Arguments
@@ -334,16 +628,85 @@ pub trait AssetRepository: Send + Sync {
         version_constraint: Option<&str>,
     ) -> DbResult<()>;
 
+    /// Add several dependency relationships as one unit
+    ///
+    /// Either every edge is recorded or none is: the default implementation
+    /// removes the edges it already added when a later one fails.
+    ///
+    /// # Arguments
+    /// * `asset_id` - The asset that has the dependencies
+    /// * `dependency_ids` - The assets being depended upon
+    async fn add_dependencies(
+        &self,
+        asset_id: &AssetId,
+        dependency_ids: &[AssetId],
+    ) -> DbResult<()> {
+        for (added, dependency_id) in dependency_ids.iter().enumerate() {
+            if let Err(e) = self.add_dependency(asset_id, dependency_id, None).await {
+                for rollback in &dependency_ids[..added] {
+                    let _ = self.remove_dependency(asset_id, rollback).await;
+                }
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     /// Remove a dependency relationship
     ///
     /// # Arguments
     /// * `asset_id` - The asset that has the dependency
     /// * `dependency_id` - The dependency to remove
-    async fn remove_dependency(
-        &self,
-        asset_id: &AssetId,
-        dependency_id: &AssetId,
-    ) -> DbResult<()>;
+    async fn remove_dependency(&self, asset_id: &AssetId, dependency_id: &AssetId) -> DbResult<()>;
+
+    /// Rebuild the dependents index from each asset's forward edges
+    ///
+    /// Adds edges an asset declares but `list_reverse_dependencies` does not
+    /// report, and removes reported edges the dependent no longer declares.
+    /// Forward edges to unknown assets are left alone. Running it again on a
+    /// consistent repository changes nothing.
+    async fn rebuild_dependents_index(&self) -> DbResult<DependentsReindex> {
+        let query = SearchQuery::new()
+            .exclude_deprecated(false)
+            .include_deleted(true)
+            .limit(i64::MAX);
+        let assets = self.search(&query).await?.assets;
+        let forward: HashSet<(AssetId, AssetId)> = assets
+            .iter()
+            .flat_map(|a| {
+                a.dependencies
+                    .iter()
+                    .filter_map(|d| d.as_id().map(|id| (a.id, *id)))
+            })
+            .collect();
+
+        let mut report = DependentsReindex::default();
+        let mut indexed = HashSet::new();
+        for asset in &assets {
+            for dependent in self.list_reverse_dependencies(&asset.id).await? {
+                let edge = (dependent.id, asset.id);
+                if forward.contains(&edge) {
+                    indexed.insert(edge);
+                } else {
+                    self.remove_dependency(&dependent.id, &asset.id).await?;
+                    report.edges_removed += 1;
+                }
+            }
+        }
+
+        let known: HashSet<AssetId> = assets.iter().map(|a| a.id).collect();
+        for (asset_id, dependency_id) in &forward {
+            if !known.contains(dependency_id) {
+                continue;
+            }
+            report.edges_processed += 1;
+            if !indexed.contains(&(*asset_id, *dependency_id)) {
+                self.add_dependency(asset_id, dependency_id, None).await?;
+                report.edges_added += 1;
+            }
+        }
+        Ok(report)
+    }
 
     /// Count total assets in the repository
     ///
@@ -351,6 +714,28 @@ pub trait AssetRepository: Send + Sync {
     /// * Total number of assets
     async fn count_assets(&self) -> DbResult<i64>;
 
+    /// Aggregate asset counts by type and status, and storage usage
+    ///
+    /// The default implementation loads every asset; database-backed
+    /// repositories should aggregate in the query instead.
+    async fn asset_stats(&self) -> DbResult<AssetStats> {
+        let query = SearchQuery::new()
+            .exclude_deprecated(false)
+            .include_deleted(true)
+            .limit(i64::MAX);
+        let mut stats = AssetStats::default();
+        for asset in self.search(&query).await?.assets {
+            stats.add(
+                &asset.asset_type.to_string(),
+                &asset.status.to_string(),
+                asset.deleted_at.is_some(),
+                1,
+                asset.metadata.size_bytes.unwrap_or(0),
+            );
+        }
+        Ok(stats)
+    }
+
     /// Count assets by type
     ///
     /// # Arguments
@@ -7,8 +7,12 @@
 //! - Command-line arguments
 
 use config::{Config, ConfigError, Environment, File};
+use llm_registry_api::{ExecutionHeaderNames, GovernanceConfig, TimestampFormat};
+use llm_registry_service::adapters::config_manager::TtlConfig;
+use llm_registry_service::adapters::schema_registry::SchemaFallbackMode;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Server configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +33,58 @@ pub struct ServerConfig {
     /// CORS settings
     #[serde(default)]
     pub cors: CorsConfig,
+
+    /// Governance context requirements for mutating requests
+    #[serde(default)]
+    pub governance: GovernanceConfig,
+
+    /// Wire format for timestamps in responses (`rfc3339`, `unix_millis`, `unix_nanos`)
+    #[serde(default)]
+    pub timestamp_format: TimestampFormat,
+
+    /// Accept `/v1` requests without execution context headers, synthesizing
+    /// an execution ID and root span instead of rejecting them
+    #[serde(default)]
+    pub lenient_execution_context: bool,
+
+    /// Header names carrying the execution context
+    #[serde(default)]
+    pub execution_headers: ExecutionHeaderNames,
+
+    /// Asset time-to-live settings
+    #[serde(default)]
+    pub ttl: TtlSettings,
+
+    /// Where stored asset content is read from for integrity verification
+    #[serde(default)]
+    pub content: ContentConfig,
+
+    /// Upstream schema registry checked on registration
+    #[serde(default)]
+    pub schema_registry: SchemaRegistrySettings,
+}
+
+/// Stored content configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContentConfig {
+    /// Directory holding `FileSystem` storage locations; content
+    /// verification is unavailable when unset
+    #[serde(default)]
+    pub root: Option<PathBuf>,
+}
+
+/// Schema registry configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SchemaRegistrySettings {
+    /// Base URL of the schema registry; registrations are not checked
+    /// against canonical schemas when unset
+    #[serde(default)]
+    pub endpoint: Option<String>,
+
+    /// Behavior when the schema registry is unreachable (`permissive`,
+    /// `reject`, `queue`)
+    #[serde(default)]
+    pub fallback: SchemaFallbackMode,
 }
 
 /// HTTP server configuration
@@ -46,6 +102,18 @@ pub struct HttpServerConfig {
     #[serde(default = "default_timeout")]
     pub timeout_seconds: u64,
 
+    /// Timeout in seconds for search routes, overriding `timeout_seconds`
+    #[serde(default)]
+    pub search_timeout_seconds: Option<u64>,
+
+    /// Largest page size served by asset search; larger limits are clamped
+    #[serde(default = "default_max_page_size")]
+    pub max_page_size: i64,
+
+    /// JSON Lines file accepted execution records are appended to
+    #[serde(default = "default_execution_records_path")]
+    pub execution_records_path: PathBuf,
+
     /// Enable graceful shutdown
     #[serde(default = "default_true")]
     pub graceful_shutdown: bool,
@@ -67,6 +135,14 @@ fn default_timeout() -> u64 {
     30
 }
 
+fn default_max_page_size() -> i64 {
+    llm_registry_service::MAX_PAGE_SIZE
+}
+
+fn default_execution_records_path() -> PathBuf {
+    PathBuf::from("data/execution-records.jsonl")
+}
+
 fn default_true() -> bool {
     true
 }
@@ -81,6 +157,9 @@ impl Default for HttpServerConfig {
             host: default_host(),
             port: default_port(),
             timeout_seconds: default_timeout(),
+            search_timeout_seconds: None,
+            max_page_size: default_max_page_size(),
+            execution_records_path: default_execution_records_path(),
             graceful_shutdown: default_true(),
             shutdown_timeout_seconds: default_shutdown_timeout(),
         }
@@ -225,10 +304,18 @@ impl Default for LoggingConfig {
 /// CORS configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CorsConfig {
-    /// Allowed origins (empty means all)
+    /// Allowed origins (empty means all); `https://*.example.com` matches any subdomain
     #[serde(default)]
     pub allowed_origins: Vec<String>,
 
+    /// Allowed request methods (empty means the standard REST methods)
+    #[serde(default)]
+    pub allowed_methods: Vec<String>,
+
+    /// Allowed request headers (empty means all)
+    #[serde(default)]
+    pub allowed_headers: Vec<String>,
+
     /// Allow credentials
     #[serde(default)]
     pub allow_credentials: bool,
@@ -246,12 +333,81 @@ impl Default for CorsConfig {
     fn default() -> Self {
         Self {
             allowed_origins: vec![],
+            allowed_methods: vec![],
+            allowed_headers: vec![],
             allow_credentials: false,
             max_age_seconds: default_cors_max_age(),
         }
     }
 }
 
+impl From<CorsConfig> for llm_registry_api::CorsConfig {
+    fn from(config: CorsConfig) -> Self {
+        Self {
+            allowed_origins: config.allowed_origins,
+            allowed_methods: config.allowed_methods,
+            allowed_headers: config.allowed_headers,
+            allow_credentials: config.allow_credentials,
+            max_age_seconds: Some(config.max_age_seconds),
+        }
+    }
+}
+
+/// Asset time-to-live configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TtlSettings {
+    /// Hide assets past their TTL from search and latest-version resolution
+    #[serde(default)]
+    pub enforce: bool,
+
+    /// TTL of active assets, from creation
+    #[serde(default = "default_ttl_seconds")]
+    pub default_ttl_seconds: u64,
+
+    /// TTL of deprecated assets, from deprecation
+    #[serde(default = "default_deprecated_ttl_seconds")]
+    pub deprecated_ttl_seconds: u64,
+
+    /// TTL of archived assets, from archival
+    #[serde(default = "default_archived_ttl_seconds")]
+    pub archived_ttl_seconds: u64,
+}
+
+fn default_ttl_seconds() -> u64 {
+    TtlConfig::default().default_ttl.as_secs()
+}
+
+fn default_deprecated_ttl_seconds() -> u64 {
+    TtlConfig::default().deprecated_ttl.as_secs()
+}
+
+fn default_archived_ttl_seconds() -> u64 {
+    TtlConfig::default().archived_ttl.as_secs()
+}
+
+impl Default for TtlSettings {
+    fn default() -> Self {
+        Self {
+            enforce: false,
+            default_ttl_seconds: default_ttl_seconds(),
+            deprecated_ttl_seconds: default_deprecated_ttl_seconds(),
+            archived_ttl_seconds: default_archived_ttl_seconds(),
+        }
+    }
+}
+
+impl From<TtlSettings> for TtlConfig {
+    fn from(settings: TtlSettings) -> Self {
+        Self {
+            default_ttl: Duration::from_secs(settings.default_ttl_seconds),
+            deprecated_ttl: Duration::from_secs(settings.deprecated_ttl_seconds),
+            archived_ttl: Duration::from_secs(settings.archived_ttl_seconds),
+            enforce: settings.enforce,
+            ..TtlConfig::default()
+        }
+    }
+}
+
 impl ServerConfig {
     /// Load configuration from files and environment
     ///
@@ -275,7 +431,9 @@ impl ServerConfig {
             // Start with default config
             .add_source(File::from(config_dir.join("default.toml")).required(false))
             // Add environment-specific config
-            .add_source(File::from(config_dir.join(format!("{}.toml", environment))).required(false))
+            .add_source(
+                File::from(config_dir.join(format!("{}.toml", environment))).required(false),
+            )
             // Add environment variables with prefix LLM_REGISTRY
             // e.g., LLM_REGISTRY_SERVER__PORT=8080
             .add_source(
@@ -289,12 +447,28 @@ impl ServerConfig {
     }
 
     /// Load configuration with defaults if files don't exist
-    pub fn load_or_default(config_dir: impl Into<PathBuf>, environment: &str) -> Self {
-        Self::load(config_dir, environment).unwrap_or_else(|e| {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the loaded configuration fails [`ServerConfig::validate`]
+    pub fn load_or_default(
+        config_dir: impl Into<PathBuf>,
+        environment: &str,
+    ) -> Result<Self, ConfigError> {
+        let config = Self::load(config_dir, environment).unwrap_or_else(|e| {
             eprintln!("Warning: Failed to load configuration: {}", e);
             eprintln!("Using default configuration");
             Self::default()
-        })
+        });
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Reject settings that are unsafe to serve
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        llm_registry_api::CorsConfig::from(self.cors.clone())
+            .validate()
+            .map_err(ConfigError::Message)
     }
 
     /// Get database connection string
@@ -316,6 +490,13 @@ impl Default for ServerConfig {
             database: DatabaseConfig::default(),
             logging: LoggingConfig::default(),
             cors: CorsConfig::default(),
+            governance: GovernanceConfig::default(),
+            timestamp_format: TimestampFormat::default(),
+            lenient_execution_context: false,
+            execution_headers: ExecutionHeaderNames::default(),
+            ttl: TtlSettings::default(),
+            content: ContentConfig::default(),
+            schema_registry: SchemaRegistrySettings::default(),
         }
     }
 }
@@ -373,6 +554,68 @@ mod tests {
         assert!(config.include_timestamps);
     }
 
+    #[test]
+    fn test_load_rejects_credentials_for_any_origin() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::copy(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../config/default.toml"),
+            dir.path().join("default.toml"),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("staging.toml"),
+            "[cors]\nallowed_origins = []\nallow_credentials = true\n",
+        )
+        .unwrap();
+
+        assert!(ServerConfig::load_or_default(dir.path(), "staging").is_err());
+
+        std::fs::write(
+            dir.path().join("staging.toml"),
+            "[cors]\nallowed_origins = [\"http://localhost:5173\"]\nallow_credentials = true\n",
+        )
+        .unwrap();
+        let config = ServerConfig::load_or_default(dir.path(), "staging").unwrap();
+        assert!(config.cors.allow_credentials);
+    }
+
+    #[test]
+    fn test_load_ttl_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::copy(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../../config/default.toml"),
+            dir.path().join("default.toml"),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("staging.toml"),
+            "[ttl]\nenforce = true\narchived_ttl_seconds = 86400\n",
+        )
+        .unwrap();
+
+        let ttl: TtlConfig = ServerConfig::load_or_default(dir.path(), "staging")
+            .unwrap()
+            .ttl
+            .into();
+        assert!(ttl.enforce);
+        assert_eq!(ttl.archived_ttl, Duration::from_secs(86400));
+        assert_eq!(ttl.deprecated_ttl, TtlConfig::default().deprecated_ttl);
+    }
+
+    #[test]
+    fn test_shipped_configs_are_valid() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../../config");
+        for environment in ["development", "production"] {
+            assert!(
+                ServerConfig::load(dir, environment)
+                    .and_then(|config| config.validate())
+                    .is_ok(),
+                "{} config should load",
+                environment
+            );
+        }
+    }
+
     #[test]
     fn test_get_environment_default() {
         // Clear env var for test
@@ -10,9 +10,9 @@ mod tracing_setup;
 
 use anyhow::{Context, Result};
 use clap::Parser;
-use llm_registry_api::build_api_server;
+use llm_registry_api::{build_api_server_with_config, MiddlewareConfig, RouteClass};
 use llm_registry_db::{create_pool, PoolConfig, PostgresAssetRepository, PostgresEventStore};
-use llm_registry_service::ServiceRegistry;
+use llm_registry_service::{FilesystemContentStore, SchemaRegistryAdapter, ServiceRegistryBuilder};
 use sqlx::PgPool;
 use std::net::SocketAddr;
 use std::sync::Arc;
@@ -60,7 +60,8 @@ async fn main() -> Result<()> {
     let args = Args::parse();
 
     // Load configuration
-    let mut config = ServerConfig::load_or_default(&args.config_dir, &args.environment);
+    let mut config = ServerConfig::load_or_default(&args.config_dir, &args.environment)
+        .context("Invalid configuration")?;
 
     // Override with command-line arguments
     if let Some(host) = args.host {
@@ -101,10 +102,48 @@ async fn main() -> Result<()> {
     let event_store = Arc::new(PostgresEventStore::new(pool.clone()));
 
     // Create service registry (wrapped in Arc for sharing between servers)
-    let services = Arc::new(ServiceRegistry::new(asset_repository, event_store));
+    let mut services = ServiceRegistryBuilder::new()
+        .repository(asset_repository)
+        .event_store(event_store)
+        .ttl_config(config.ttl.clone().into());
+    if let Some(ref root) = config.content.root {
+        info!("Content root: {}", root.display());
+        services = services.content_store(Arc::new(FilesystemContentStore::new(root)));
+    }
+    let services = Arc::new(
+        services
+            .build()
+            .map_err(anyhow::Error::msg)
+            .context("Failed to build services")?,
+    );
 
     // Build API server
-    let app = build_api_server((*services).clone());
+    let mut middleware_config = MiddlewareConfig::new()
+        .with_cors(config.cors.clone().into())
+        .with_governance(config.governance.clone())
+        .with_timestamp_format(config.timestamp_format)
+        .with_lenient_execution_context(config.lenient_execution_context)
+        .with_execution_headers(config.execution_headers.clone())
+        .with_timeout(config.server.timeout_seconds)
+        .with_max_page_size(config.server.max_page_size)
+        .with_execution_records_path(&config.server.execution_records_path);
+    if let Some(seconds) = config.server.search_timeout_seconds {
+        middleware_config =
+            middleware_config.with_route_timeout(RouteClass::Search, Duration::from_secs(seconds));
+    }
+    if let Some(ref endpoint) = config.schema_registry.endpoint {
+        info!("Schema registry: {}", endpoint);
+        let schema_registry = Arc::new(
+            SchemaRegistryAdapter::with_endpoint(endpoint.clone())
+                .with_fallback(config.schema_registry.fallback),
+        );
+        // Preload the schemas registration needs, so the first registration
+        // does not wait on the fetch; startup does not wait on it either
+        let warming = Arc::clone(&schema_registry);
+        tokio::spawn(async move { warming.warm_critical_schemas().await });
+        middleware_config = middleware_config.with_schema_registry(schema_registry);
+    }
+    let app = build_api_server_with_config((*services).clone(), middleware_config);
 
     // Parse HTTP bind address
     let http_addr: SocketAddr = config
@@ -182,13 +221,19 @@ async fn setup_database_with_retries(config: &ServerConfig) -> Result<PgPool> {
     let mut delay = Duration::from_secs(2);
 
     for attempt in 1..=MAX_RETRIES {
-        info!("Connecting to database (attempt {}/{})", attempt, MAX_RETRIES);
+        info!(
+            "Connecting to database (attempt {}/{})",
+            attempt, MAX_RETRIES
+        );
 
         match setup_database(config).await {
             Ok(pool) => return Ok(pool),
             Err(e) => {
                 if attempt == MAX_RETRIES {
-                    error!("Database connection failed after {} attempts: {:#}", MAX_RETRIES, e);
+                    error!(
+                        "Database connection failed after {} attempts: {:#}",
+                        MAX_RETRIES, e
+                    );
                     return Err(e);
                 }
                 warn!(
@@ -254,7 +299,10 @@ async fn shutdown_signal(timeout_seconds: u64) {
     }
 
     // Give the server time to finish processing requests
-    info!("Waiting up to {} seconds for graceful shutdown", timeout_seconds);
+    info!(
+        "Waiting up to {} seconds for graceful shutdown",
+        timeout_seconds
+    );
 }
 
 /// Mask sensitive parts of database URL for logging
@@ -5,8 +5,8 @@
 
 use once_cell::sync::Lazy;
 use prometheus::{
-    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, HistogramVec,
-    IntCounterVec, IntGaugeVec, Registry, TextEncoder, Encoder,
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, Encoder,
+    HistogramVec, IntCounterVec, IntGaugeVec, Registry, TextEncoder,
 };
 use std::time::Instant;
 
@@ -263,9 +263,7 @@ pub fn update_db_pool_metrics(pool_name: &str, active: usize, idle: usize) {
 
 /// Update total assets metric
 pub fn update_assets_total(status: &str, count: i64) {
-    ASSETS_TOTAL
-        .with_label_values(&[status])
-        .set(count);
+    ASSETS_TOTAL.with_label_values(&[status]).set(count);
 }
 
 #[cfg(test)]
@@ -108,8 +108,8 @@ pub fn init() {
 /// ```
 pub fn init_with_config(config: TelemetryConfig) {
     // Build environment filter
-    let env_filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| EnvFilter::new(&config.log_level));
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&config.log_level));
 
     if config.json_format {
         // JSON formatting for production
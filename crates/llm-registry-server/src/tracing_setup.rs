@@ -134,8 +134,7 @@ pub fn init_tracing(config: OtelConfig) -> Result<(), TraceError> {
     let telemetry_layer = tracing_opentelemetry::layer().with_tracer(tracer);
 
     // Create env filter
-    let env_filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| EnvFilter::new("info"));
+    let env_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
 
     // Initialize subscriber with OpenTelemetry layer
     tracing_subscriber::registry()
@@ -144,7 +143,7 @@ pub fn init_tracing(config: OtelConfig) -> Result<(), TraceError> {
         .with(
             tracing_subscriber::fmt::layer()
                 .with_target(true)
-                .with_thread_ids(true)
+                .with_thread_ids(true),
         )
         .try_init()
         .map_err(|e| TraceError::Other(Box::new(e)))?;
@@ -219,13 +218,11 @@ mod tests {
 
     #[test]
     fn test_sampling_ratio_clamping() {
-        let config = OtelConfig::new("test")
-            .with_sampling_ratio(1.5); // Should clamp to 1.0
+        let config = OtelConfig::new("test").with_sampling_ratio(1.5); // Should clamp to 1.0
 
         assert_eq!(config.sampling_ratio, 1.0);
 
-        let config2 = OtelConfig::new("test")
-            .with_sampling_ratio(-0.5); // Should clamp to 0.0
+        let config2 = OtelConfig::new("test").with_sampling_ratio(-0.5); // Should clamp to 0.0
 
         assert_eq!(config2.sampling_ratio, 0.0);
     }
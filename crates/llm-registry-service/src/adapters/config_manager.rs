@@ -5,16 +5,21 @@
 //! registry indexing or metadata management logic.
 
 use async_trait::async_trait;
+use llm_registry_core::AssetMetadata;
 use serde::{Deserialize, Serialize};
 #[allow(unused_imports)]
-use std::collections::HashMap;
-use std::sync::Arc;
-use std::time::Duration;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tracing::{debug, instrument, warn};
 
+use super::observatory::ObservatoryAdapter;
+use super::schema_registry::SchemaFallbackMode;
+
 /// Errors from config manager consumption
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum ConfigAdapterError {
     #[error("Configuration not found: {0}")]
     NotFound(String),
@@ -39,6 +44,17 @@ pub enum Environment {
     Production,
 }
 
+impl Environment {
+    /// Lowercase environment name, as serialized
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Environment::Development => "development",
+            Environment::Staging => "staging",
+            Environment::Production => "production",
+        }
+    }
+}
+
 /// Registry policy consumed from config manager
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegistryPolicy {
@@ -74,8 +90,8 @@ impl Default for TtlConfig {
         Self {
             default_ttl: Duration::from_secs(365 * 24 * 60 * 60), // 1 year
             deprecated_ttl: Duration::from_secs(90 * 24 * 60 * 60), // 90 days
-            archived_ttl: Duration::from_secs(30 * 24 * 60 * 60),  // 30 days
-            cache_ttl: Duration::from_secs(3600),                   // 1 hour
+            archived_ttl: Duration::from_secs(30 * 24 * 60 * 60), // 30 days
+            cache_ttl: Duration::from_secs(3600),                 // 1 hour
             enforce: false,
         }
     }
@@ -108,6 +124,75 @@ impl Default for RetentionRules {
     }
 }
 
+/// Asset type as named in configuration (mirrors upstream)
+///
+/// Serialized as its plain name (`"Model"`, `"TestSuite"`, ...). Names are
+/// case-sensitive: anything that is not an exact match for a known type,
+/// including `"model"` or a typo like `"Modle"`, becomes [`AssetType::Other`]
+/// and only matches an identically spelled entry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum AssetType {
+    Model,
+    Pipeline,
+    TestSuite,
+    Policy,
+    Dataset,
+    Other(String),
+}
+
+impl AssetType {
+    /// Configuration name of the type
+    pub fn as_str(&self) -> &str {
+        match self {
+            AssetType::Model => "Model",
+            AssetType::Pipeline => "Pipeline",
+            AssetType::TestSuite => "TestSuite",
+            AssetType::Policy => "Policy",
+            AssetType::Dataset => "Dataset",
+            AssetType::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for AssetType {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "Model" => AssetType::Model,
+            "Pipeline" => AssetType::Pipeline,
+            "TestSuite" => AssetType::TestSuite,
+            "Policy" => AssetType::Policy,
+            "Dataset" => AssetType::Dataset,
+            _ => AssetType::Other(name),
+        }
+    }
+}
+
+impl From<AssetType> for String {
+    fn from(ty: AssetType) -> Self {
+        ty.as_str().to_string()
+    }
+}
+
+impl From<&llm_registry_core::AssetType> for AssetType {
+    fn from(ty: &llm_registry_core::AssetType) -> Self {
+        match ty {
+            llm_registry_core::AssetType::Model => AssetType::Model,
+            llm_registry_core::AssetType::Pipeline => AssetType::Pipeline,
+            llm_registry_core::AssetType::TestSuite => AssetType::TestSuite,
+            llm_registry_core::AssetType::Policy => AssetType::Policy,
+            llm_registry_core::AssetType::Dataset => AssetType::Dataset,
+            llm_registry_core::AssetType::Custom(name) => AssetType::Other(name.clone()),
+        }
+    }
+}
+
+impl std::fmt::Display for AssetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Validation constraints for registry operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ValidationConstraints {
@@ -117,14 +202,51 @@ pub struct ValidationConstraints {
     pub max_metadata_size: u64,
     /// Maximum number of tags per asset
     pub max_tags: u32,
+    /// Maximum number of labels per asset
+    #[serde(default = "default_max_labels")]
+    pub max_labels: u32,
     /// Maximum number of dependencies per asset
     pub max_dependencies: u32,
     /// Required metadata fields
     pub required_fields: Vec<String>,
     /// Allowed asset types
-    pub allowed_asset_types: Vec<String>,
+    pub allowed_asset_types: Vec<AssetType>,
     /// Whether to enforce strict validation
     pub strict_mode: bool,
+    /// Schema validation behavior when the schema registry is unavailable
+    #[serde(default)]
+    pub schema_fallback: SchemaFallbackMode,
+    /// Hash algorithms integrity verification may use
+    #[serde(default = "default_allowed_algorithms")]
+    pub allowed_algorithms: Vec<String>,
+    /// Limits overriding the ones above for specific asset types
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub per_type: HashMap<AssetType, TypeConstraints>,
+}
+
+/// Limits for one asset type; unset limits fall back to the global ones
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeConstraints {
+    /// Maximum asset size in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_asset_size: Option<u64>,
+    /// Maximum metadata size in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_metadata_size: Option<u64>,
+    /// Maximum number of tags per asset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tags: Option<u32>,
+    /// Maximum number of labels per asset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_labels: Option<u32>,
+}
+
+fn default_max_labels() -> u32 {
+    32
+}
+
+fn default_allowed_algorithms() -> Vec<String> {
+    vec!["sha256".to_string(), "blake3".to_string()]
 }
 
 impl Default for ValidationConstraints {
@@ -133,6 +255,7 @@ impl Default for ValidationConstraints {
             max_asset_size: 10 * 1024 * 1024 * 1024, // 10 GB
             max_metadata_size: 1024 * 1024,          // 1 MB
             max_tags: 50,
+            max_labels: default_max_labels(),
             max_dependencies: 100,
             required_fields: vec![
                 "name".to_string(),
@@ -140,17 +263,96 @@ impl Default for ValidationConstraints {
                 "description".to_string(),
             ],
             allowed_asset_types: vec![
-                "Model".to_string(),
-                "Pipeline".to_string(),
-                "TestSuite".to_string(),
-                "Policy".to_string(),
-                "Dataset".to_string(),
+                AssetType::Model,
+                AssetType::Pipeline,
+                AssetType::TestSuite,
+                AssetType::Policy,
+                AssetType::Dataset,
             ],
             strict_mode: false,
+            schema_fallback: SchemaFallbackMode::default(),
+            allowed_algorithms: default_allowed_algorithms(),
+            per_type: HashMap::new(),
         }
     }
 }
 
+impl ValidationConstraints {
+    /// Whether assets of this type may be registered
+    pub fn is_type_allowed(&self, ty: &AssetType) -> bool {
+        self.allowed_asset_types.contains(ty)
+    }
+
+    /// Constraints in effect for assets of `ty`, with its overrides applied
+    pub fn for_type(&self, ty: &AssetType) -> ValidationConstraints {
+        let mut effective = self.clone();
+        if let Some(limits) = self.per_type.get(ty) {
+            effective.max_asset_size = limits.max_asset_size.unwrap_or(self.max_asset_size);
+            effective.max_metadata_size =
+                limits.max_metadata_size.unwrap_or(self.max_metadata_size);
+            effective.max_tags = limits.max_tags.unwrap_or(self.max_tags);
+            effective.max_labels = limits.max_labels.unwrap_or(self.max_labels);
+        }
+        effective
+    }
+
+    /// Check tag and label counts and the declared asset size
+    pub fn check_limits(
+        &self,
+        tags: usize,
+        labels: usize,
+        size_bytes: Option<u64>,
+    ) -> Result<(), String> {
+        if tags > self.max_tags as usize {
+            return Err(format!(
+                "Asset has {} tags; at most {} are allowed",
+                tags, self.max_tags
+            ));
+        }
+        if labels > self.max_labels as usize {
+            return Err(format!(
+                "Asset has {} labels; at most {} are allowed",
+                labels, self.max_labels
+            ));
+        }
+        if let Some(size) = size_bytes.filter(|size| *size > self.max_asset_size) {
+            return Err(format!(
+                "Asset is {} bytes; at most {} are allowed",
+                size, self.max_asset_size
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check metadata against the tag, label and size limits
+    pub fn check_metadata(&self, metadata: &AssetMetadata) -> Result<(), String> {
+        self.check_limits(
+            metadata.tags.len(),
+            metadata.labels.len(),
+            metadata.size_bytes,
+        )?;
+        let size = serde_json::to_vec(metadata).map_or(0, |bytes| bytes.len() as u64);
+        if size > self.max_metadata_size {
+            return Err(format!(
+                "Metadata is {} bytes; at most {} are allowed",
+                size, self.max_metadata_size
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether integrity verification may use this algorithm
+    ///
+    /// Names compare case-insensitively, with `_` and `-` treated alike.
+    pub fn is_algorithm_allowed(&self, algorithm: &str) -> bool {
+        let normalize = |name: &str| name.to_ascii_lowercase().replace('_', "-");
+        let algorithm = normalize(algorithm);
+        self.allowed_algorithms
+            .iter()
+            .any(|allowed| normalize(allowed) == algorithm)
+    }
+}
+
 /// Combined registry configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegistryConfig {
@@ -178,6 +380,162 @@ impl Default for RegistryConfig {
     }
 }
 
+impl RegistryConfig {
+    /// Layer `overlay` on top of `base`
+    ///
+    /// The overlay wins for every scalar section (environment, TTL,
+    /// retention and validation). Policies are merged by name: an overlay
+    /// policy replaces the base policy of the same name in place, and
+    /// policies new to the overlay are appended in overlay order.
+    pub fn merge(base: RegistryConfig, overlay: RegistryConfig) -> RegistryConfig {
+        let mut policies = base.policies;
+        for policy in overlay.policies {
+            match policies.iter_mut().find(|p| p.name == policy.name) {
+                Some(existing) => *existing = policy,
+                None => policies.push(policy),
+            }
+        }
+
+        RegistryConfig {
+            environment: overlay.environment,
+            ttl: overlay.ttl,
+            retention: overlay.retention,
+            validation: overlay.validation,
+            policies,
+        }
+    }
+}
+
+/// Where the active configuration came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigSource {
+    /// Built-in defaults with environment overrides
+    #[default]
+    Defaults,
+    /// Fetched from the upstream config manager
+    Remote,
+}
+
+/// Consecutive fetch failures that open the circuit breaker by default
+const DEFAULT_BREAKER_THRESHOLD: u32 = 5;
+
+/// How long the circuit breaker stays open by default
+const DEFAULT_BREAKER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// How long a remote config fetch may take before it counts as a failure
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// State of the circuit breaker around remote fetches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerState {
+    /// Fetches go upstream
+    #[default]
+    Closed,
+    /// Fetches are skipped until the cooldown ends
+    Open,
+    /// The cooldown has ended; the next fetch probes upstream
+    HalfOpen,
+}
+
+impl BreakerState {
+    /// Get the string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BreakerState::Closed => "closed",
+            BreakerState::Open => "open",
+            BreakerState::HalfOpen => "half_open",
+        }
+    }
+}
+
+/// Snapshot of the remote fetch circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BreakerStatus {
+    /// Current state
+    pub state: BreakerState,
+    /// Remote fetches that failed in a row
+    pub consecutive_failures: u32,
+}
+
+/// Circuit breaker guarding remote fetches
+#[derive(Debug)]
+struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cooldown,
+            consecutive_failures: 0,
+            opened_at: None,
+            probing: false,
+        }
+    }
+
+    fn state(&self) -> BreakerState {
+        match self.opened_at {
+            None => BreakerState::Closed,
+            Some(opened_at) if self.probing || opened_at.elapsed() >= self.cooldown => {
+                BreakerState::HalfOpen
+            }
+            Some(_) => BreakerState::Open,
+        }
+    }
+
+    /// Whether a fetch may go upstream; claims the probe when half-open
+    fn try_acquire(&mut self) -> bool {
+        match self.opened_at {
+            None => true,
+            Some(opened_at) if !self.probing && opened_at.elapsed() >= self.cooldown => {
+                self.probing = true;
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+        self.probing = false;
+    }
+
+    /// A failed probe reopens the breaker for another cooldown
+    fn record_failure(&mut self) {
+        self.consecutive_failures += 1;
+        if self.probing || self.consecutive_failures >= self.threshold {
+            self.opened_at = Some(Instant::now());
+        }
+        self.probing = false;
+    }
+}
+
+/// Clears a claimed probe if the fetch is dropped before its outcome is
+/// recorded, so a cancelled probe cannot hold the breaker half-open
+struct ProbeGuard<'a>(Option<&'a Mutex<CircuitBreaker>>);
+
+impl ProbeGuard<'_> {
+    fn disarm(mut self) {
+        self.0 = None;
+    }
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(breaker) = self.0.take() {
+            breaker.lock().unwrap().probing = false;
+        }
+    }
+}
+
 /// Trait for config manager consumption
 #[async_trait]
 pub trait ConfigConsumer: Send + Sync {
@@ -214,8 +572,21 @@ pub struct ConfigManagerAdapter {
     namespace: String,
     /// Remote endpoint (if configured)
     endpoint: Option<String>,
+    /// HTTP client for remote fetches
+    client: reqwest::Client,
     /// Last refresh timestamp
     last_refresh: Arc<tokio::sync::RwLock<Option<chrono::DateTime<chrono::Utc>>>>,
+    /// Origin of the cached configuration
+    source: Arc<tokio::sync::RwLock<ConfigSource>>,
+    /// Receives `ConfigChanged` events (if configured)
+    observatory: Option<Arc<ObservatoryAdapter>>,
+    /// Skips remote fetches while upstream keeps failing
+    breaker: Mutex<CircuitBreaker>,
+    /// Held while [`Self::get_config_fresh`] refreshes, so only one caller
+    /// does; holds the error the latest of those refreshes failed with
+    refresh_guard: tokio::sync::Mutex<Option<ConfigAdapterError>>,
+    /// Refreshes completed by [`Self::get_config_fresh`]
+    refresh_attempts: AtomicU64,
 }
 
 impl ConfigManagerAdapter {
@@ -229,14 +600,26 @@ impl ConfigManagerAdapter {
             })),
             namespace: "llm.registry".to_string(),
             endpoint: None,
+            client: reqwest::Client::builder()
+                .timeout(FETCH_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
             last_refresh: Arc::new(tokio::sync::RwLock::new(None)),
+            source: Arc::new(tokio::sync::RwLock::new(ConfigSource::Defaults)),
+            observatory: None,
+            breaker: Mutex::new(CircuitBreaker::new(
+                DEFAULT_BREAKER_THRESHOLD,
+                DEFAULT_BREAKER_COOLDOWN,
+            )),
+            refresh_guard: tokio::sync::Mutex::new(None),
+            refresh_attempts: AtomicU64::new(0),
         }
     }
 
     /// Create adapter with remote endpoint
     pub fn with_endpoint(environment: Environment, endpoint: String) -> Self {
         let mut adapter = Self::new(environment);
-        adapter.endpoint = Some(endpoint);
+        adapter.endpoint = Some(endpoint.trim_end_matches('/').to_string());
         adapter
     }
 
@@ -246,11 +629,118 @@ impl ConfigManagerAdapter {
         self
     }
 
+    /// Emit a `ConfigChanged` governance event when a refresh changes the config
+    pub fn with_observatory(mut self, observatory: Arc<ObservatoryAdapter>) -> Self {
+        self.observatory = Some(observatory);
+        self
+    }
+
+    /// Open the circuit breaker after `threshold` consecutive failed fetches,
+    /// probing upstream again once `cooldown` has passed
+    pub fn with_circuit_breaker(self, threshold: u32, cooldown: Duration) -> Self {
+        *self.breaker.lock().unwrap() = CircuitBreaker::new(threshold, cooldown);
+        self
+    }
+
+    /// State of the remote fetch circuit breaker
+    pub fn breaker_status(&self) -> BreakerStatus {
+        let breaker = self.breaker.lock().unwrap();
+        BreakerStatus {
+            state: breaker.state(),
+            consecutive_failures: breaker.consecutive_failures,
+        }
+    }
+
     /// Get the current environment
     pub fn environment(&self) -> Environment {
         self.environment
     }
 
+    /// Get the configuration namespace
+    pub fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    /// When the configuration was last refreshed, if ever
+    pub async fn last_refresh(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        *self.last_refresh.read().await
+    }
+
+    /// Where the cached configuration came from
+    pub async fn source(&self) -> ConfigSource {
+        *self.source.read().await
+    }
+
+    /// Fetch upstream through the circuit breaker
+    ///
+    /// Only `Unavailable` errors count as failures; any other answer shows the
+    /// config manager is reachable.
+    async fn fetch_guarded(&self, endpoint: &str) -> ConfigResult<RegistryConfig> {
+        let probe = {
+            let mut breaker = self.breaker.lock().unwrap();
+            if !breaker.try_acquire() {
+                debug!(namespace = %self.namespace, "Circuit breaker open - skipping remote fetch");
+                return Err(ConfigAdapterError::Unavailable(
+                    "circuit breaker open; keeping cached configuration".to_string(),
+                ));
+            }
+            ProbeGuard(breaker.probing.then_some(&self.breaker))
+        };
+
+        let result = self.fetch_remote(endpoint).await;
+        probe.disarm();
+        let mut breaker = self.breaker.lock().unwrap();
+        match result {
+            Err(ConfigAdapterError::Unavailable(ref message)) => {
+                breaker.record_failure();
+                if breaker.state() == BreakerState::Open {
+                    warn!(
+                        failures = breaker.consecutive_failures,
+                        error = %message,
+                        "Config manager circuit breaker open"
+                    );
+                }
+            }
+            _ => breaker.record_success(),
+        }
+        result
+    }
+
+    /// Fetch the configuration for this namespace and environment upstream
+    async fn fetch_remote(&self, endpoint: &str) -> ConfigResult<RegistryConfig> {
+        let url = format!(
+            "{}/configs/{}/{}",
+            endpoint,
+            self.namespace,
+            self.environment.as_str()
+        );
+        let response = super::with_execution_headers(self.client.get(&url))
+            .send()
+            .await
+            .map_err(|e| ConfigAdapterError::Unavailable(format!("GET {} failed: {}", url, e)))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(ConfigAdapterError::NotFound(url));
+        }
+        if !response.status().is_success() {
+            return Err(ConfigAdapterError::Unavailable(format!(
+                "GET {} returned {}",
+                url,
+                response.status()
+            )));
+        }
+
+        let mut config: RegistryConfig = response.json().await.map_err(|e| {
+            ConfigAdapterError::InvalidFormat(format!(
+                "Invalid config response from {}: {}",
+                url, e
+            ))
+        })?;
+        // The adapter's environment is authoritative
+        config.environment = self.environment;
+        Ok(config)
+    }
+
     /// Check if configuration is stale and needs refresh
     #[instrument(skip(self))]
     pub async fn is_stale(&self, max_age: Duration) -> bool {
@@ -264,6 +754,34 @@ impl ConfigManagerAdapter {
         }
     }
 
+    /// Get the configuration, refreshing it first if older than `max_age`
+    ///
+    /// Concurrent callers that find the configuration stale wait for a single
+    /// refresh rather than each starting their own, and share its outcome. A
+    /// failed refresh is returned as an error instead of serving the stale
+    /// configuration.
+    /// [`ConfigConsumer::get_config`] remains the non-checking fast path.
+    #[instrument(skip(self))]
+    pub async fn get_config_fresh(&self, max_age: Duration) -> ConfigResult<RegistryConfig> {
+        if self.is_stale(max_age).await {
+            let attempts = self.refresh_attempts.load(Ordering::Acquire);
+            let mut last_error = self.refresh_guard.lock().await;
+            if self.refresh_attempts.load(Ordering::Acquire) != attempts {
+                // Another caller refreshed while this one waited
+                if let Some(ref e) = *last_error {
+                    return Err(e.clone());
+                }
+            } else if self.is_stale(max_age).await {
+                debug!(namespace = %self.namespace, "Configuration stale - refreshing on read");
+                let result = self.refresh().await;
+                *last_error = result.as_ref().err().cloned();
+                self.refresh_attempts.fetch_add(1, Ordering::Release);
+                result?;
+            }
+        }
+        self.get_config().await
+    }
+
     /// Apply environment-specific overrides
     #[instrument(skip(self, base_config))]
     async fn apply_environment_overrides(&self, mut base_config: RegistryConfig) -> RegistryConfig {
@@ -271,6 +789,8 @@ impl ConfigManagerAdapter {
             Environment::Production => {
                 // Stricter settings for production
                 base_config.validation.strict_mode = true;
+                base_config.validation.schema_fallback =
+                    SchemaFallbackMode::for_environment(Environment::Production);
                 base_config.ttl.enforce = true;
                 base_config.retention.keep_one_active = true;
             }
@@ -283,7 +803,8 @@ impl ConfigManagerAdapter {
                 // Relaxed settings for development
                 base_config.validation.strict_mode = false;
                 base_config.ttl.enforce = false;
-                base_config.validation.max_asset_size = 100 * 1024 * 1024 * 1024; // 100 GB for dev
+                base_config.validation.max_asset_size = 100 * 1024 * 1024 * 1024;
+                // 100 GB for dev
             }
         }
 
@@ -337,28 +858,34 @@ impl ConfigConsumer for ConfigManagerAdapter {
 
     #[instrument(skip(self))]
     async fn refresh(&self) -> ConfigResult<()> {
-        // In production, this would fetch from the upstream config manager
-        // For Phase 2B, we apply environment overrides to defaults
-
-        if self.endpoint.is_some() {
-            warn!(
-                namespace = %self.namespace,
-                "Config manager remote fetch not yet connected - using defaults with overrides"
-            );
-        }
-
-        let base_config = RegistryConfig {
+        // Layers compose base -> environment -> namespace. A failed or
+        // short-circuited remote fetch leaves the cached configuration in place
+        let base = RegistryConfig {
             environment: self.environment,
             ..Default::default()
         };
+        let environment_layer = self.apply_environment_overrides(base.clone()).await;
+        let config = RegistryConfig::merge(base, environment_layer);
 
-        let config = self.apply_environment_overrides(base_config).await;
+        let (config, source) = match self.endpoint {
+            Some(ref endpoint) => {
+                let namespace_layer = self.fetch_guarded(endpoint).await?;
+                (
+                    RegistryConfig::merge(config, namespace_layer),
+                    ConfigSource::Remote,
+                )
+            }
+            None => (config, ConfigSource::Defaults),
+        };
 
-        {
+        let changed_paths = {
             let mut cached = self.config.write().await;
+            let changed_paths = changed_paths(&cached, &config);
             *cached = config;
-        }
+            changed_paths
+        };
 
+        *self.source.write().await = source;
         {
             let mut last_refresh = self.last_refresh.write().await;
             *last_refresh = Some(chrono::Utc::now());
@@ -367,13 +894,134 @@ impl ConfigConsumer for ConfigManagerAdapter {
         debug!(
             environment = ?self.environment,
             namespace = %self.namespace,
+            source = ?source,
+            changed = changed_paths.len(),
             "Configuration refreshed"
         );
 
+        if !changed_paths.is_empty() {
+            if let Some(ref observatory) = self.observatory {
+                if let Err(e) = observatory
+                    .trace_config_change(&self.namespace, self.environment.as_str(), changed_paths)
+                    .await
+                {
+                    warn!(error = %e, "Failed to emit config change event");
+                }
+            }
+        }
+
         Ok(())
     }
 }
 
+/// [`ConfigConsumer`] serving a programmable configuration without a remote
+///
+/// [`Self::set_config`] stages a configuration the way an upstream change
+/// would; it is served once [`ConfigConsumer::refresh`] runs.
+pub struct InMemoryConfigConsumer {
+    /// Configuration the next refresh picks up
+    upstream: Mutex<RegistryConfig>,
+    /// Configuration currently served
+    config: Mutex<RegistryConfig>,
+    refreshes: AtomicU64,
+}
+
+impl InMemoryConfigConsumer {
+    /// Create a consumer serving `config`
+    pub fn new(config: RegistryConfig) -> Self {
+        Self {
+            upstream: Mutex::new(config.clone()),
+            config: Mutex::new(config),
+            refreshes: AtomicU64::new(0),
+        }
+    }
+
+    /// Stage `config`; it is served after the next refresh
+    pub fn set_config(&self, config: RegistryConfig) {
+        *self.upstream.lock().unwrap() = config;
+    }
+
+    /// Number of completed refreshes
+    pub fn refresh_count(&self) -> u64 {
+        self.refreshes.load(Ordering::Relaxed)
+    }
+
+    fn current(&self) -> RegistryConfig {
+        self.config.lock().unwrap().clone()
+    }
+}
+
+impl Default for InMemoryConfigConsumer {
+    fn default() -> Self {
+        Self::new(RegistryConfig::default())
+    }
+}
+
+#[async_trait]
+impl ConfigConsumer for InMemoryConfigConsumer {
+    async fn get_config(&self) -> ConfigResult<RegistryConfig> {
+        Ok(self.current())
+    }
+
+    async fn get_ttl_config(&self) -> ConfigResult<TtlConfig> {
+        Ok(self.current().ttl)
+    }
+
+    async fn get_retention_rules(&self) -> ConfigResult<RetentionRules> {
+        Ok(self.current().retention)
+    }
+
+    async fn get_validation_constraints(&self) -> ConfigResult<ValidationConstraints> {
+        Ok(self.current().validation)
+    }
+
+    async fn get_policies(&self) -> ConfigResult<Vec<RegistryPolicy>> {
+        Ok(self.current().policies)
+    }
+
+    async fn refresh(&self) -> ConfigResult<()> {
+        let upstream = self.upstream.lock().unwrap().clone();
+        *self.config.lock().unwrap() = upstream;
+        self.refreshes.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// Settings that differ between two configurations
+///
+/// Paths name a top-level section and one of its fields
+/// (`validation.strict_mode`); sections without fields, such as
+/// `policies`, are compared as a whole.
+fn changed_paths(old: &RegistryConfig, new: &RegistryConfig) -> Vec<String> {
+    let old = serde_json::to_value(old).unwrap_or_default();
+    let new = serde_json::to_value(new).unwrap_or_default();
+    let mut changed = Vec::new();
+
+    for section in object_keys(&old, &new) {
+        let (old_section, new_section) = (&old[section.as_str()], &new[section.as_str()]);
+        if old_section.is_object() && new_section.is_object() {
+            for field in object_keys(old_section, new_section) {
+                if old_section[field.as_str()] != new_section[field.as_str()] {
+                    changed.push(format!("{}.{}", section, field));
+                }
+            }
+        } else if old_section != new_section {
+            changed.push(section);
+        }
+    }
+
+    changed
+}
+
+/// Sorted union of the keys of two JSON objects
+fn object_keys(a: &serde_json::Value, b: &serde_json::Value) -> BTreeSet<String> {
+    [a, b]
+        .into_iter()
+        .filter_map(|value| value.as_object())
+        .flat_map(|object| object.keys().cloned())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -394,6 +1042,52 @@ mod tests {
         assert!(config.validation.strict_mode);
     }
 
+    #[tokio::test]
+    async fn test_in_memory_consumer_applies_changes_on_refresh() {
+        let consumer = InMemoryConfigConsumer::default();
+        assert!(
+            !consumer
+                .get_validation_constraints()
+                .await
+                .unwrap()
+                .strict_mode
+        );
+
+        let mut config = consumer.get_config().await.unwrap();
+        config.validation.strict_mode = true;
+        config.ttl.cache_ttl = Duration::from_secs(5);
+        config.policies.push(policy("require-license", 10));
+        consumer.set_config(config);
+
+        // Staged changes are not served until a refresh
+        assert!(
+            !consumer
+                .get_validation_constraints()
+                .await
+                .unwrap()
+                .strict_mode
+        );
+        assert_eq!(consumer.refresh_count(), 0);
+
+        consumer.refresh().await.unwrap();
+        assert_eq!(consumer.refresh_count(), 1);
+        assert!(
+            consumer
+                .get_validation_constraints()
+                .await
+                .unwrap()
+                .strict_mode
+        );
+        assert_eq!(
+            consumer.get_ttl_config().await.unwrap().cache_ttl,
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            consumer.get_policies().await.unwrap()[0].name,
+            "require-license"
+        );
+    }
+
     #[tokio::test]
     async fn test_ttl_defaults() {
         let adapter = ConfigManagerAdapter::default();
@@ -420,7 +1114,368 @@ mod tests {
 
         let constraints = adapter.get_validation_constraints().await.unwrap();
         assert!(constraints.required_fields.contains(&"name".to_string()));
-        assert!(constraints.allowed_asset_types.contains(&"Model".to_string()));
+        assert!(constraints.is_type_allowed(&AssetType::Model));
+        assert_eq!(constraints.schema_fallback, SchemaFallbackMode::Permissive);
+    }
+
+    #[test]
+    fn test_per_type_constraints_override_global_limits() {
+        let constraints: ValidationConstraints = serde_json::from_value(serde_json::json!({
+            "max_asset_size": 1_000_000,
+            "max_metadata_size": 4096,
+            "max_tags": 10,
+            "max_dependencies": 5,
+            "required_fields": [],
+            "allowed_asset_types": ["Model", "Policy"],
+            "strict_mode": false,
+            "per_type": {
+                "Policy": { "max_asset_size": 1024, "max_tags": 2 }
+            }
+        }))
+        .unwrap();
+
+        let policy = constraints.for_type(&AssetType::Policy);
+        assert_eq!(policy.max_asset_size, 1024);
+        assert_eq!(policy.max_tags, 2);
+        // Limits the override leaves unset fall back to the global ones
+        assert_eq!(policy.max_metadata_size, 4096);
+        assert_eq!(policy.max_labels, default_max_labels());
+        assert!(policy.check_limits(2, 0, Some(1024)).is_ok());
+        assert!(policy.check_limits(3, 0, None).is_err());
+        assert!(policy.check_limits(0, 0, Some(2048)).is_err());
+
+        let model = constraints.for_type(&(&llm_registry_core::AssetType::Model).into());
+        assert_eq!(model.max_asset_size, 1_000_000);
+        assert_eq!(model.max_tags, 10);
+        assert!(model.check_limits(3, 0, Some(2048)).is_ok());
+        assert!(model.check_limits(0, 0, Some(1_000_001)).is_err());
+
+        let roundtrip: ValidationConstraints =
+            serde_json::from_value(serde_json::to_value(&constraints).unwrap()).unwrap();
+        assert_eq!(roundtrip.per_type, constraints.per_type);
+        assert!(ValidationConstraints::default()
+            .for_type(&AssetType::Policy)
+            .per_type
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_from_remote_endpoint() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut remote = RegistryConfig::default();
+        remote.validation.max_tags = 7;
+        Mock::given(method("GET"))
+            .and(path("/configs/llm.registry/staging"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&remote))
+            .mount(&server)
+            .await;
+
+        let adapter = ConfigManagerAdapter::with_endpoint(Environment::Staging, server.uri());
+        assert_eq!(adapter.source().await, ConfigSource::Defaults);
+        assert!(adapter.last_refresh().await.is_none());
+
+        adapter.refresh().await.unwrap();
+        assert_eq!(adapter.source().await, ConfigSource::Remote);
+        assert!(adapter.last_refresh().await.is_some());
+        let config = adapter.get_config().await.unwrap();
+        assert_eq!(config.validation.max_tags, 7);
+        assert_eq!(config.environment, Environment::Staging);
+    }
+
+    #[tokio::test]
+    async fn test_failed_remote_refresh_keeps_cached_config() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        let adapter = ConfigManagerAdapter::with_endpoint(Environment::Staging, server.uri());
+        let err = adapter.refresh().await.unwrap_err();
+        assert!(matches!(err, ConfigAdapterError::Unavailable(_)));
+        assert_eq!(adapter.source().await, ConfigSource::Defaults);
+        assert!(adapter.last_refresh().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_probe_does_not_hold_breaker() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(60)))
+            .mount(&server)
+            .await;
+
+        let adapter = ConfigManagerAdapter::with_endpoint(Environment::Staging, server.uri())
+            .with_circuit_breaker(1, Duration::ZERO);
+        adapter.refresh().await.unwrap_err();
+
+        // The probe is abandoned mid-flight
+        let probe = tokio::time::timeout(Duration::from_millis(50), adapter.refresh()).await;
+        assert!(probe.is_err());
+        assert!(adapter.breaker.lock().unwrap().try_acquire());
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_and_short_circuits() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let adapter = ConfigManagerAdapter::with_endpoint(Environment::Staging, server.uri())
+            .with_circuit_breaker(2, Duration::from_secs(3600));
+        adapter.refresh().await.unwrap_err();
+        assert_eq!(adapter.breaker_status().state, BreakerState::Closed);
+        adapter.refresh().await.unwrap_err();
+        assert_eq!(
+            adapter.breaker_status(),
+            BreakerStatus {
+                state: BreakerState::Open,
+                consecutive_failures: 2
+            }
+        );
+
+        // Open: no request reaches upstream and the cache is untouched
+        let err = adapter.refresh().await.unwrap_err();
+        assert!(err.to_string().contains("circuit breaker open"));
+        assert_eq!(adapter.source().await, ConfigSource::Defaults);
+    }
+
+    #[tokio::test]
+    async fn test_breaker_recovers_after_half_open_probe() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(RegistryConfig::default()))
+            .mount(&server)
+            .await;
+
+        let adapter = ConfigManagerAdapter::with_endpoint(Environment::Staging, server.uri())
+            .with_circuit_breaker(1, Duration::from_millis(20));
+        adapter.refresh().await.unwrap_err();
+        assert_eq!(adapter.breaker_status().state, BreakerState::Open);
+
+        // A failed probe reopens the breaker
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(adapter.breaker_status().state, BreakerState::HalfOpen);
+        adapter.refresh().await.unwrap_err();
+        assert_eq!(adapter.breaker_status().state, BreakerState::Open);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        adapter.refresh().await.unwrap();
+        assert_eq!(
+            adapter.breaker_status(),
+            BreakerStatus {
+                state: BreakerState::Closed,
+                consecutive_failures: 0
+            }
+        );
+        assert_eq!(adapter.source().await, ConfigSource::Remote);
+    }
+
+    #[test]
+    fn test_asset_types_deserialize_from_strings() {
+        let constraints: ValidationConstraints = serde_json::from_value(serde_json::json!({
+            "max_asset_size": 1024,
+            "max_metadata_size": 1024,
+            "max_tags": 10,
+            "max_dependencies": 10,
+            "required_fields": [],
+            "allowed_asset_types": ["Model", "TestSuite", "Modle", "prompt"],
+            "strict_mode": false,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            constraints.allowed_asset_types,
+            vec![
+                AssetType::Model,
+                AssetType::TestSuite,
+                AssetType::Other("Modle".to_string()),
+                AssetType::Other("prompt".to_string()),
+            ]
+        );
+        assert!(constraints.is_type_allowed(&AssetType::Model));
+        assert!(constraints.is_type_allowed(&AssetType::Other("prompt".to_string())));
+        assert!(!constraints.is_type_allowed(&AssetType::Dataset));
+
+        // Round-trips as plain strings
+        let json = serde_json::to_value(&constraints).unwrap();
+        assert_eq!(json["allowed_asset_types"][1], "TestSuite");
+    }
+
+    #[test]
+    fn test_asset_type_names_are_case_sensitive() {
+        assert_eq!(
+            AssetType::from("model".to_string()),
+            AssetType::Other("model".to_string())
+        );
+        assert_eq!(AssetType::from("Model".to_string()), AssetType::Model);
+
+        let constraints = ValidationConstraints::default();
+        assert!(!constraints.is_type_allowed(&AssetType::from("model".to_string())));
+        assert!(!constraints.is_type_allowed(&AssetType::from("Modle".to_string())));
+    }
+
+    #[test]
+    fn test_asset_type_from_core() {
+        let constraints = ValidationConstraints::default();
+        assert!(constraints.is_type_allowed(&(&llm_registry_core::AssetType::TestSuite).into()));
+
+        let custom = llm_registry_core::AssetType::custom("prompt").unwrap();
+        assert_eq!(
+            AssetType::from(&custom),
+            AssetType::Other("prompt".to_string())
+        );
+        assert!(!constraints.is_type_allowed(&AssetType::from(&custom)));
+    }
+
+    #[tokio::test]
+    async fn test_production_schema_fallback_rejects() {
+        let adapter = ConfigManagerAdapter::new(Environment::Production);
+        adapter.refresh().await.unwrap();
+
+        let constraints = adapter.get_validation_constraints().await.unwrap();
+        assert_eq!(constraints.schema_fallback, SchemaFallbackMode::Reject);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_emits_config_changed_only_on_change() {
+        use crate::adapters::observatory::GovernanceEvent;
+
+        let observatory = Arc::new(ObservatoryAdapter::default());
+        let adapter = ConfigManagerAdapter::new(Environment::Production)
+            .with_namespace("llm.registry.test".to_string())
+            .with_observatory(observatory.clone());
+
+        adapter.refresh().await.unwrap();
+        let events = observatory.buffered_events().await;
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            GovernanceEvent::ConfigChanged {
+                namespace,
+                environment,
+                changed_paths,
+            } => {
+                assert_eq!(namespace, "llm.registry.test");
+                assert_eq!(environment, "production");
+                assert_eq!(
+                    changed_paths,
+                    &[
+                        "ttl.enforce",
+                        "validation.schema_fallback",
+                        "validation.strict_mode",
+                    ]
+                );
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+
+        // Nothing changed upstream, so a second refresh is silent
+        adapter.refresh().await.unwrap();
+        assert_eq!(observatory.pending_events().await, 1);
+    }
+
+    #[test]
+    fn test_changed_paths() {
+        let old = RegistryConfig::default();
+        assert!(changed_paths(&old, &old.clone()).is_empty());
+
+        let mut new = old.clone();
+        new.retention.max_versions = 10;
+        new.policies.push(RegistryPolicy {
+            name: "policy".to_string(),
+            namespace: "llm.registry".to_string(),
+            enabled: true,
+            rules: serde_json::json!({}),
+            priority: 0,
+        });
+        assert_eq!(
+            changed_paths(&old, &new),
+            ["policies", "retention.max_versions"]
+        );
+    }
+
+    fn policy(name: &str, priority: u32) -> RegistryPolicy {
+        RegistryPolicy {
+            name: name.to_string(),
+            namespace: "llm.registry".to_string(),
+            enabled: true,
+            rules: serde_json::json!({}),
+            priority,
+        }
+    }
+
+    #[test]
+    fn test_merge_overlay_wins_for_scalars() {
+        let base = RegistryConfig::default();
+        let mut overlay = base.clone();
+        overlay.environment = Environment::Production;
+        overlay.validation.strict_mode = true;
+        overlay.retention.max_versions = 10;
+
+        let merged = RegistryConfig::merge(base, overlay);
+        assert_eq!(merged.environment, Environment::Production);
+        assert!(merged.validation.strict_mode);
+        assert_eq!(merged.retention.max_versions, 10);
+    }
+
+    #[test]
+    fn test_merge_replaces_policies_by_name() {
+        let base = RegistryConfig {
+            policies: vec![policy("retention", 1), policy("signing", 2)],
+            ..Default::default()
+        };
+        let overlay = RegistryConfig {
+            policies: vec![policy("retention", 9)],
+            ..Default::default()
+        };
+
+        let merged = RegistryConfig::merge(base, overlay);
+        let policies: Vec<_> = merged
+            .policies
+            .iter()
+            .map(|p| (p.name.as_str(), p.priority))
+            .collect();
+        assert_eq!(policies, [("retention", 9), ("signing", 2)]);
+    }
+
+    #[test]
+    fn test_merge_appends_new_policies() {
+        let base = RegistryConfig {
+            policies: vec![policy("retention", 1)],
+            ..Default::default()
+        };
+        let overlay = RegistryConfig {
+            policies: vec![policy("signing", 2), policy("quota", 3)],
+            ..Default::default()
+        };
+
+        let merged = RegistryConfig::merge(base, overlay);
+        let names: Vec<_> = merged.policies.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["retention", "signing", "quota"]);
     }
 
     #[tokio::test]
@@ -435,4 +1490,90 @@ mod tests {
         // Should not be stale immediately after refresh
         assert!(!adapter.is_stale(Duration::from_secs(60)).await);
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_stale_read_refreshes_once_under_concurrency() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let mut remote = RegistryConfig::default();
+        remote.validation.max_tags = 9;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(&remote)
+                    .set_delay(Duration::from_millis(100)),
+            )
+            .mount(&server)
+            .await;
+
+        let adapter = Arc::new(ConfigManagerAdapter::with_endpoint(
+            Environment::Staging,
+            server.uri(),
+        ));
+        let reads: Vec<_> = (0..8)
+            .map(|_| {
+                let adapter = adapter.clone();
+                tokio::spawn(async move { adapter.get_config_fresh(Duration::from_secs(60)).await })
+            })
+            .collect();
+        for read in reads {
+            assert_eq!(read.await.unwrap().unwrap().validation.max_tags, 9);
+        }
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+        // A fresh configuration is served without another fetch
+        adapter
+            .get_config_fresh(Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_stale_reads_share_failed_refresh() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(503).set_delay(Duration::from_millis(100)))
+            .mount(&server)
+            .await;
+
+        let adapter = Arc::new(ConfigManagerAdapter::with_endpoint(
+            Environment::Staging,
+            server.uri(),
+        ));
+        let reads: Vec<_> = (0..8)
+            .map(|_| {
+                let adapter = adapter.clone();
+                tokio::spawn(async move { adapter.get_config_fresh(Duration::from_secs(60)).await })
+            })
+            .collect();
+        for read in reads {
+            assert!(matches!(
+                read.await.unwrap(),
+                Err(ConfigAdapterError::Unavailable(_))
+            ));
+        }
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_stale_read_surfaces_failed_refresh() {
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(wiremock::matchers::method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let adapter = ConfigManagerAdapter::with_endpoint(Environment::Staging, server.uri());
+        assert!(adapter.get_config().await.is_ok());
+        assert!(matches!(
+            adapter.get_config_fresh(Duration::from_secs(60)).await,
+            Err(ConfigAdapterError::Unavailable(_))
+        ));
+    }
 }
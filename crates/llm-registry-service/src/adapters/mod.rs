@@ -4,14 +4,39 @@
 //! - Schema Registry: Canonical schema definitions for model metadata and pipeline descriptors
 //! - Config Manager: Configuration-driven registry policies, TTLs, and validation constraints
 //! - Observatory: Telemetry signals, governance events, and registry health traces
+//! - Webhooks: Signed fan-out of governance events to registered URLs
 //!
 //! These adapters are additive and do not modify existing registry logic.
 
-pub mod schema_registry;
 pub mod config_manager;
 pub mod observatory;
+pub mod schema_registry;
+pub mod webhook;
+
+/// Attach the current execution's headers, if any, to an outbound request
+///
+/// Requests issued inside [`SpanCollector::scope`](llm_registry_core::SpanCollector::scope)
+/// carry `X-Execution-Id` and `X-Parent-Span-Id` naming the open agent span,
+/// so the upstream service can continue the trace.
+pub(crate) fn with_execution_headers(
+    mut request: reqwest::RequestBuilder,
+) -> reqwest::RequestBuilder {
+    if let Some(headers) = llm_registry_core::SpanCollector::current_outbound_headers() {
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+    }
+    request
+}
 
 // Re-export adapter types for convenience
-pub use schema_registry::SchemaRegistryAdapter;
-pub use config_manager::ConfigManagerAdapter;
-pub use observatory::ObservatoryAdapter;
+pub use config_manager::{
+    BreakerState, BreakerStatus, ConfigConsumer, ConfigManagerAdapter, InMemoryConfigConsumer,
+};
+pub use observatory::{
+    AuditRecord, EventFilter, GovernanceEvent, ObservatoryAdapter, TelemetryState, TelemetryStats,
+};
+pub use schema_registry::{
+    InMemorySchemaConsumer, SchemaConsumer, SchemaFallbackMode, SchemaRegistryAdapter,
+};
+pub use webhook::{Webhook, WebhookSink, WebhookStats};
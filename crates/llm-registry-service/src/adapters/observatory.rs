@@ -5,18 +5,29 @@
 //! without modifying existing registry indexing or metadata management logic.
 
 use async_trait::async_trait;
+use llm_registry_core::execution::ExecutionResult;
+use llm_registry_core::SpanExporter;
+use llm_registry_db::AssetStats;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use thiserror::Error;
 use tracing::{debug, info, instrument, warn};
 
+use super::webhook::WebhookSink;
+
 /// Errors from observatory operations
 #[derive(Error, Debug)]
 pub enum ObservatoryError {
-    #[error("Failed to emit telemetry: {0}")]
-    EmitFailed(String),
+    /// `status` is the HTTP status returned by the observatory, if it answered
+    #[error("Failed to emit telemetry: {message}")]
+    EmitFailed {
+        status: Option<u16>,
+        message: String,
+    },
     #[error("Observatory unavailable: {0}")]
     Unavailable(String),
     #[error("Invalid span data: {0}")]
@@ -25,9 +36,54 @@ pub enum ObservatoryError {
     TraceNotFound(String),
 }
 
+impl ObservatoryError {
+    /// Emission failure that did not involve an HTTP response
+    pub fn emit_failed(message: impl Into<String>) -> Self {
+        ObservatoryError::EmitFailed {
+            status: None,
+            message: message.into(),
+        }
+    }
+
+    /// HTTP status returned by the observatory, if any
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ObservatoryError::EmitFailed { status, .. } => *status,
+            _ => None,
+        }
+    }
+
+    /// Coarse error category, bounded for use as a metric label
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ObservatoryError::EmitFailed { status: None, .. } => "transport",
+            ObservatoryError::EmitFailed {
+                status: Some(status),
+                ..
+            } if *status >= 500 => "server_error",
+            ObservatoryError::EmitFailed { .. } => "rejected",
+            ObservatoryError::Unavailable(_) => "unavailable",
+            ObservatoryError::InvalidSpan(_) => "invalid_span",
+            ObservatoryError::TraceNotFound(_) => "trace_not_found",
+        }
+    }
+}
+
 /// Result type for observatory operations
 pub type ObservatoryResult<T> = Result<T, ObservatoryError>;
 
+/// Default maximum number of events held in the flush buffer
+const DEFAULT_MAX_BUFFERED_EVENTS: usize = 10_000;
+
+/// Default number of events sent per flush request
+const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
+/// Attempts made to deliver a batch before it is dropped
+const FLUSH_MAX_ATTEMPTS: u32 = 3;
+
+/// Default number of consecutive failed flushes after which telemetry is degraded
+const DEFAULT_DEGRADED_AFTER_FAILURES: u64 = 3;
+
 /// Span status (mirrors upstream)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -65,6 +121,14 @@ pub enum GovernanceEvent {
     AssetDeleted {
         asset_id: String,
         deleted_by: String,
+        /// Whether the asset was purged rather than soft-deleted
+        #[serde(default)]
+        permanent: bool,
+    },
+    /// Soft-deleted asset was restored
+    AssetRestored {
+        asset_id: String,
+        restored_by: String,
     },
     /// Policy was validated
     PolicyValidated {
@@ -86,6 +150,77 @@ pub enum GovernanceEvent {
         action: String,
         allowed: bool,
     },
+    /// Active configuration changed on refresh
+    ConfigChanged {
+        namespace: String,
+        environment: String,
+        changed_paths: Vec<String>,
+    },
+}
+
+/// A governance event numbered in the order the adapter accepted it
+///
+/// One line of the archive written by [`ObservatoryAdapter::export_audit`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Position in the adapter's event stream, starting at 1
+    pub sequence: u64,
+    /// The event, flattened alongside `sequence`
+    #[serde(flatten)]
+    pub event: GovernanceEvent,
+}
+
+impl GovernanceEvent {
+    /// Event type discriminant, as serialized in the `type` field
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            GovernanceEvent::AssetRegistered { .. } => "asset_registered",
+            GovernanceEvent::AssetUpdated { .. } => "asset_updated",
+            GovernanceEvent::AssetDeprecated { .. } => "asset_deprecated",
+            GovernanceEvent::AssetDeleted { .. } => "asset_deleted",
+            GovernanceEvent::AssetRestored { .. } => "asset_restored",
+            GovernanceEvent::PolicyValidated { .. } => "policy_validated",
+            GovernanceEvent::IntegrityVerified { .. } => "integrity_verified",
+            GovernanceEvent::AccessDecision { .. } => "access_decision",
+            GovernanceEvent::ConfigChanged { .. } => "config_changed",
+        }
+    }
+}
+
+/// Which governance event types are forwarded to the observatory
+///
+/// Types are named by their [`GovernanceEvent::event_type`] discriminant.
+/// The observatory forwards access denials regardless of the filter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum EventFilter {
+    /// Forward every event
+    #[default]
+    AllowAll,
+    /// Forward only the listed types
+    Allow(HashSet<String>),
+    /// Forward everything except the listed types
+    Deny(HashSet<String>),
+}
+
+impl EventFilter {
+    /// Forward only the listed event types
+    pub fn allow(types: &[&str]) -> Self {
+        EventFilter::Allow(types.iter().map(|t| t.to_string()).collect())
+    }
+
+    /// Forward everything except the listed event types
+    pub fn deny(types: &[&str]) -> Self {
+        EventFilter::Deny(types.iter().map(|t| t.to_string()).collect())
+    }
+
+    /// Whether the event passes the filter
+    pub fn permits(&self, event: &GovernanceEvent) -> bool {
+        match self {
+            EventFilter::AllowAll => true,
+            EventFilter::Allow(types) => types.contains(event.event_type()),
+            EventFilter::Deny(types) => !types.contains(event.event_type()),
+        }
+    }
 }
 
 /// Registry health status
@@ -165,13 +300,92 @@ pub struct RegistryMetrics {
     pub validation_pass_rate: f64,
     /// Cache hit rate (0.0 - 1.0)
     pub cache_hit_rate: f64,
+    /// Assets by type
+    #[serde(default)]
+    pub assets_by_type: BTreeMap<String, u64>,
+    /// Assets by lifecycle state; soft-deleted assets are counted as `deleted`
+    #[serde(default)]
+    pub assets_by_state: BTreeMap<String, u64>,
+    /// Total size of the stored assets in bytes
+    #[serde(default)]
+    pub storage_bytes: u64,
+}
+
+impl RegistryMetrics {
+    /// Fill in the asset counts and storage usage from `stats`
+    pub fn with_asset_stats(mut self, stats: &AssetStats) -> Self {
+        self.total_assets = stats.total_assets;
+        self.assets_by_type = stats.by_type.clone();
+        self.assets_by_state = stats.by_status.clone();
+        self.assets_by_state
+            .insert("deleted".to_string(), stats.deleted);
+        self.storage_bytes = stats.storage_bytes;
+        self
+    }
+}
+
+/// Counters for governance events lost before reaching the observatory
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelemetryStats {
+    /// Events discarded due to buffer overflow or permanently failed flushes
+    pub dropped_events: u64,
+    /// Flushes that failed after all retry attempts
+    pub failed_flushes: u64,
+    /// Events discarded by the configured [`EventFilter`]
+    #[serde(default)]
+    pub filtered_events: u64,
+    /// Error of the most recent failed flush
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_flush_error: Option<String>,
+    /// [`ObservatoryError::kind`] of the most recent failed flush
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_flush_error_kind: Option<String>,
+}
+
+/// Whether telemetry is reaching the observatory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TelemetryState {
+    /// Turned off by configuration; events are intentionally not sent
+    Disabled,
+    /// Flushes are succeeding, or have failed fewer times in a row than
+    /// the degradation threshold
+    Healthy,
+    /// Flushes have failed repeatedly; events are being lost
+    Degraded,
+}
+
+impl TelemetryState {
+    /// Get the state name as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TelemetryState::Disabled => "disabled",
+            TelemetryState::Healthy => "healthy",
+            TelemetryState::Degraded => "degraded",
+        }
+    }
+}
+
+/// Shared loss counters behind [`TelemetryStats`]
+#[derive(Debug, Default)]
+struct TelemetryCounters {
+    dropped_events: AtomicU64,
+    failed_flushes: AtomicU64,
+    filtered_events: AtomicU64,
+    consecutive_failed_flushes: AtomicU64,
+    last_flush_error: Mutex<Option<String>>,
+    last_flush_error_kind: Mutex<Option<&'static str>>,
 }
 
 /// Trait for observatory telemetry operations
 #[async_trait]
 pub trait TelemetryEmitter: Send + Sync {
     /// Start a new trace span
-    async fn start_span(&self, name: &str, attributes: HashMap<String, serde_json::Value>) -> ObservatoryResult<RegistrySpan>;
+    async fn start_span(
+        &self,
+        name: &str,
+        attributes: HashMap<String, serde_json::Value>,
+    ) -> ObservatoryResult<RegistrySpan>;
 
     /// End a span with status
     async fn end_span(&self, span: &mut RegistrySpan, status: SpanStatus) -> ObservatoryResult<()>;
@@ -198,13 +412,48 @@ pub struct ObservatoryAdapter {
     /// Remote endpoint (if configured)
     endpoint: Option<String>,
     /// Buffer for batching events
-    event_buffer: Arc<tokio::sync::RwLock<Vec<GovernanceEvent>>>,
+    event_buffer: Arc<tokio::sync::RwLock<Vec<AuditRecord>>>,
+    /// Span trees of completed executions
+    execution_buffer: Arc<tokio::sync::RwLock<Vec<ExecutionResult>>>,
     /// Buffer flush interval
     flush_interval: Duration,
     /// Whether telemetry is enabled
     enabled: bool,
+    /// HTTP client for remote flushes
+    client: reqwest::Client,
+    /// Maximum number of buffered events before new events are dropped
+    max_buffered_events: usize,
+    /// Events sent per flush request; also the auto-flush threshold
+    max_batch_size: usize,
+    /// Delay between flush attempts (multiplied by the attempt number)
+    retry_backoff: Duration,
+    /// Consecutive failed flushes that make [`Self::state`] report degraded
+    degraded_after: u64,
+    /// Loss counters
+    counters: Arc<TelemetryCounters>,
+    /// Most recently flushed events, oldest first
+    recent_events: Arc<Mutex<VecDeque<AuditRecord>>>,
+    /// Number of flushed events kept in `recent_events` (0 disables)
+    recent_capacity: usize,
+    /// Event types forwarded to the observatory
+    event_filter: EventFilter,
+    /// Webhooks that also receive governance events
+    webhooks: Option<Arc<WebhookSink>>,
+    /// Sequence number of the last buffered event
+    last_sequence: AtomicU64,
+    /// Buffered events not yet written by `export_audit`, oldest first
+    audit_queue: Mutex<VecDeque<AuditRecord>>,
+    /// Number of events kept in `audit_queue` (0 disables)
+    audit_capacity: usize,
+    /// Callbacks told the event buffer depth after every push and flush
+    depth_gauges: Mutex<Vec<DepthGauge>>,
+    /// Whether a background flush started by `record_access_decision` is running
+    background_flush: AtomicBool,
 }
 
+/// Callback receiving the governance event buffer depth
+type DepthGauge = Arc<dyn Fn(usize) + Send + Sync>;
+
 impl ObservatoryAdapter {
     /// Create a new observatory adapter
     pub fn new(service_name: &str) -> Self {
@@ -212,15 +461,31 @@ impl ObservatoryAdapter {
             service_name: service_name.to_string(),
             endpoint: None,
             event_buffer: Arc::new(tokio::sync::RwLock::new(Vec::new())),
+            execution_buffer: Arc::new(tokio::sync::RwLock::new(Vec::new())),
             flush_interval: Duration::from_secs(10),
             enabled: true,
+            client: reqwest::Client::new(),
+            max_buffered_events: DEFAULT_MAX_BUFFERED_EVENTS,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            retry_backoff: Duration::from_millis(200),
+            degraded_after: DEFAULT_DEGRADED_AFTER_FAILURES,
+            counters: Arc::new(TelemetryCounters::default()),
+            recent_events: Arc::new(Mutex::new(VecDeque::new())),
+            recent_capacity: 0,
+            event_filter: EventFilter::default(),
+            webhooks: None,
+            last_sequence: AtomicU64::new(0),
+            audit_queue: Mutex::new(VecDeque::new()),
+            audit_capacity: 0,
+            depth_gauges: Mutex::new(Vec::new()),
+            background_flush: AtomicBool::new(false),
         }
     }
 
     /// Create adapter with remote endpoint
     pub fn with_endpoint(service_name: &str, endpoint: String) -> Self {
         let mut adapter = Self::new(service_name);
-        adapter.endpoint = Some(endpoint);
+        adapter.endpoint = Some(endpoint.trim_end_matches('/').to_string());
         adapter
     }
 
@@ -236,11 +501,126 @@ impl ObservatoryAdapter {
         self
     }
 
+    /// Set the maximum number of buffered events
+    pub fn with_max_buffered_events(mut self, max: usize) -> Self {
+        self.max_buffered_events = max;
+        self
+    }
+
+    /// Set the number of events sent per flush request (at least 1)
+    pub fn with_batch_size(mut self, size: usize) -> Self {
+        self.max_batch_size = size.max(1);
+        self
+    }
+
+    /// Keep the last `capacity` flushed events for [`Self::recent_events`]
+    pub fn with_recent_events(mut self, capacity: usize) -> Self {
+        self.recent_capacity = capacity;
+        self
+    }
+
+    /// Queue up to `capacity` events for [`Self::export_audit`]
+    ///
+    /// When more events are waiting, the oldest are dropped.
+    pub fn with_audit_export(mut self, capacity: usize) -> Self {
+        self.audit_capacity = capacity;
+        self
+    }
+
+    /// Only buffer governance events that pass `filter`
+    pub fn with_event_filter(mut self, filter: EventFilter) -> Self {
+        self.event_filter = filter;
+        self
+    }
+
+    /// Fan governance events out to `sink` on every flush
+    ///
+    /// Webhooks apply their own filters, so they also see events excluded
+    /// by [`Self::with_event_filter`].
+    pub fn with_webhooks(mut self, sink: Arc<WebhookSink>) -> Self {
+        self.webhooks = Some(sink);
+        self
+    }
+
+    /// Set the delay between flush attempts
+    pub fn with_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Report degraded after `failures` consecutive failed flushes (at least 1)
+    pub fn with_degraded_after(mut self, failures: u64) -> Self {
+        self.degraded_after = failures.max(1);
+        self
+    }
+
     /// Check if telemetry is enabled
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
 
+    /// Whether telemetry is off, flowing, or failing
+    ///
+    /// Tells an intentionally disabled adapter apart from one whose flushes
+    /// keep failing; a successful flush restores [`TelemetryState::Healthy`].
+    pub fn state(&self) -> TelemetryState {
+        let failures = self
+            .counters
+            .consecutive_failed_flushes
+            .load(Ordering::Relaxed);
+        if !self.enabled {
+            TelemetryState::Disabled
+        } else if failures >= self.degraded_after {
+            TelemetryState::Degraded
+        } else {
+            TelemetryState::Healthy
+        }
+    }
+
+    /// Snapshot of dropped-event and failed-flush counters
+    pub fn telemetry_stats(&self) -> TelemetryStats {
+        TelemetryStats {
+            dropped_events: self.counters.dropped_events.load(Ordering::Relaxed),
+            failed_flushes: self.counters.failed_flushes.load(Ordering::Relaxed),
+            filtered_events: self.counters.filtered_events.load(Ordering::Relaxed),
+            last_flush_error: self.counters.last_flush_error.lock().unwrap().clone(),
+            last_flush_error_kind: self
+                .counters
+                .last_flush_error_kind
+                .lock()
+                .unwrap()
+                .map(str::to_string),
+        }
+    }
+
+    /// Health of the observatory pipeline
+    ///
+    /// Unhealthy while the most recent flush has failed permanently.
+    pub fn health_component(&self) -> ComponentHealth {
+        let healthy = self
+            .counters
+            .consecutive_failed_flushes
+            .load(Ordering::Relaxed)
+            == 0;
+        ComponentHealth {
+            name: "observatory".to_string(),
+            healthy,
+            latency_ms: 0,
+            error: if healthy {
+                None
+            } else {
+                self.counters.last_flush_error.lock().unwrap().clone()
+            },
+        }
+    }
+
+    /// Count events that were lost
+    fn record_dropped(&self, count: usize) {
+        self.counters
+            .dropped_events
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
     /// Generate a new span ID
     fn generate_span_id() -> String {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -264,7 +644,7 @@ impl ObservatoryAdapter {
     /// Emit a trace for asset registration
     #[instrument(skip(self))]
     pub async fn trace_asset_registration(
-        &self,
+        self: &Arc<Self>,
         asset_id: &str,
         asset_name: &str,
         asset_version: &str,
@@ -281,13 +661,13 @@ impl ObservatoryAdapter {
             registered_by: registered_by.to_string(),
         };
 
-        self.emit_governance_event(event).await
+        self.emit_in_background(event).await
     }
 
     /// Emit a trace for asset update
     #[instrument(skip(self, changes))]
     pub async fn trace_asset_update(
-        &self,
+        self: &Arc<Self>,
         asset_id: &str,
         changes: Vec<String>,
         updated_by: &str,
@@ -302,13 +682,74 @@ impl ObservatoryAdapter {
             updated_by: updated_by.to_string(),
         };
 
-        self.emit_governance_event(event).await
+        self.emit_in_background(event).await
+    }
+
+    /// Emit a trace for asset deprecation
+    #[instrument(skip(self, reason))]
+    pub async fn trace_asset_deprecation(
+        self: &Arc<Self>,
+        asset_id: &str,
+        reason: &str,
+        deprecated_by: &str,
+    ) -> ObservatoryResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let event = GovernanceEvent::AssetDeprecated {
+            asset_id: asset_id.to_string(),
+            reason: reason.to_string(),
+            deprecated_by: deprecated_by.to_string(),
+        };
+
+        self.emit_in_background(event).await
+    }
+
+    /// Emit a trace for asset deletion
+    #[instrument(skip(self))]
+    pub async fn trace_asset_deletion(
+        self: &Arc<Self>,
+        asset_id: &str,
+        deleted_by: &str,
+        permanent: bool,
+    ) -> ObservatoryResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let event = GovernanceEvent::AssetDeleted {
+            asset_id: asset_id.to_string(),
+            deleted_by: deleted_by.to_string(),
+            permanent,
+        };
+
+        self.emit_in_background(event).await
+    }
+
+    /// Emit a trace for restoring a soft-deleted asset
+    #[instrument(skip(self))]
+    pub async fn trace_asset_restoration(
+        self: &Arc<Self>,
+        asset_id: &str,
+        restored_by: &str,
+    ) -> ObservatoryResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let event = GovernanceEvent::AssetRestored {
+            asset_id: asset_id.to_string(),
+            restored_by: restored_by.to_string(),
+        };
+
+        self.emit_in_background(event).await
     }
 
     /// Emit a trace for policy validation
     #[instrument(skip(self, violations))]
     pub async fn trace_policy_validation(
-        &self,
+        self: &Arc<Self>,
         asset_id: &str,
         policy_name: &str,
         passed: bool,
@@ -325,13 +766,13 @@ impl ObservatoryAdapter {
             violations,
         };
 
-        self.emit_governance_event(event).await
+        self.emit_in_background(event).await
     }
 
     /// Emit a trace for integrity verification
     #[instrument(skip(self))]
     pub async fn trace_integrity_verification(
-        &self,
+        self: &Arc<Self>,
         asset_id: &str,
         algorithm: &str,
         valid: bool,
@@ -346,7 +787,209 @@ impl ObservatoryAdapter {
             valid,
         };
 
-        self.emit_governance_event(event).await
+        self.emit_in_background(event).await
+    }
+
+    /// Emit a trace for an access decision
+    ///
+    /// Denials bypass the event filter, so they are recorded even where
+    /// `access_decision` events are otherwise filtered out.
+    #[instrument(skip(self))]
+    pub async fn trace_access_decision(
+        self: &Arc<Self>,
+        principal: &str,
+        resource: &str,
+        action: &str,
+        allowed: bool,
+    ) -> ObservatoryResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let event = GovernanceEvent::AccessDecision {
+            principal: principal.to_string(),
+            resource: resource.to_string(),
+            action: action.to_string(),
+            allowed,
+        };
+
+        self.emit_in_background(event).await
+    }
+
+    /// Record an access decision without flushing inline
+    ///
+    /// Same as [`Self::trace_access_decision`].
+    pub async fn record_access_decision(
+        self: &Arc<Self>,
+        principal: &str,
+        resource: &str,
+        action: &str,
+        allowed: bool,
+    ) -> ObservatoryResult<()> {
+        self.trace_access_decision(principal, resource, action, allowed)
+            .await
+    }
+
+    /// Buffer a governance event without flushing inline
+    ///
+    /// Used on the request path: once a full batch is buffered, a single
+    /// background task flushes it instead of the caller.
+    pub async fn emit_in_background(
+        self: &Arc<Self>,
+        event: GovernanceEvent,
+    ) -> ObservatoryResult<()> {
+        let Some(depth) = self.buffer_governance_event(event).await? else {
+            return Ok(());
+        };
+        if depth >= self.max_batch_size && !self.background_flush.swap(true, Ordering::AcqRel) {
+            let adapter = Arc::clone(self);
+            tokio::spawn(async move {
+                if let Err(e) = adapter.flush().await {
+                    warn!(error = %e, "Background governance flush failed");
+                }
+                adapter.background_flush.store(false, Ordering::Release);
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Emit a trace for a configuration change
+    #[instrument(skip(self, changed_paths))]
+    pub async fn trace_config_change(
+        self: &Arc<Self>,
+        namespace: &str,
+        environment: &str,
+        changed_paths: Vec<String>,
+    ) -> ObservatoryResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let event = GovernanceEvent::ConfigChanged {
+            namespace: namespace.to_string(),
+            environment: environment.to_string(),
+            changed_paths,
+        };
+
+        self.emit_in_background(event).await
+    }
+
+    /// Buffer the span tree of a completed execution
+    ///
+    /// Shares the `max_buffered_events` limit; a full buffer drops the
+    /// execution and counts it in [`TelemetryStats`].
+    pub async fn record_execution(&self, result: ExecutionResult) -> ObservatoryResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let mut buffer = self.execution_buffer.write().await;
+        if buffer.len() >= self.max_buffered_events {
+            drop(buffer);
+            self.record_dropped(1);
+            warn!(execution_id = %result.execution_id, "Execution buffer full - spans dropped");
+            return Err(ObservatoryError::emit_failed("execution buffer full"));
+        }
+
+        debug!(
+            execution_id = %result.execution_id,
+            span_count = result.spans.len(),
+            "Buffered execution spans"
+        );
+        buffer.push(result);
+        Ok(())
+    }
+
+    /// Executions buffered for export
+    pub async fn buffered_executions(&self) -> Vec<ExecutionResult> {
+        self.execution_buffer.read().await.clone()
+    }
+
+    /// Governance events buffered for the next flush
+    pub async fn buffered_events(&self) -> Vec<GovernanceEvent> {
+        self.event_buffer
+            .read()
+            .await
+            .iter()
+            .map(|record| record.event.clone())
+            .collect()
+    }
+
+    /// Call `gauge` with the pending event count whenever it changes
+    ///
+    /// The callback runs on every buffered event and after every flush,
+    /// so a metrics exporter can track [`Self::pending_events`] without
+    /// polling. It must not block.
+    pub fn register_depth_gauge(&self, gauge: impl Fn(usize) + Send + Sync + 'static) {
+        self.depth_gauges.lock().unwrap().push(Arc::new(gauge));
+    }
+
+    /// Filter and buffer a governance event without flushing
+    ///
+    /// Returns the buffer depth, or `None` when the event was not buffered.
+    async fn buffer_governance_event(
+        &self,
+        event: GovernanceEvent,
+    ) -> ObservatoryResult<Option<usize>> {
+        if !self.enabled {
+            return Ok(None);
+        }
+
+        if let Some(ref webhooks) = self.webhooks {
+            webhooks.enqueue(&event);
+        }
+
+        // Access denials are always kept for the audit trail
+        let denial = matches!(
+            event,
+            GovernanceEvent::AccessDecision { allowed: false, .. }
+        );
+        if !denial && !self.event_filter.permits(&event) {
+            self.counters
+                .filtered_events
+                .fetch_add(1, Ordering::Relaxed);
+            debug!(event_type = event.event_type(), "Governance event filtered");
+            return Ok(None);
+        }
+
+        // Buffer the event
+        let depth = {
+            let mut buffer = self.event_buffer.write().await;
+            if buffer.len() >= self.max_buffered_events {
+                drop(buffer);
+                self.record_dropped(1);
+                warn!("Governance event buffer full - event dropped");
+                return Err(ObservatoryError::emit_failed("event buffer full"));
+            }
+            let sequence = self.last_sequence.fetch_add(1, Ordering::Relaxed) + 1;
+            let record = AuditRecord {
+                sequence,
+                event: event.clone(),
+            };
+            if self.audit_capacity > 0 {
+                let mut queue = self.audit_queue.lock().unwrap();
+                if queue.len() >= self.audit_capacity {
+                    queue.pop_front();
+                }
+                queue.push_back(record.clone());
+            }
+            buffer.push(record);
+            buffer.len()
+        };
+        self.report_depth(depth);
+
+        debug!(event = ?event, "Buffered governance event");
+
+        Ok(Some(depth))
+    }
+
+    fn report_depth(&self, depth: usize) {
+        // Clone out so a callback may register further gauges
+        let gauges = self.depth_gauges.lock().unwrap().clone();
+        for gauge in gauges {
+            gauge(depth);
+        }
     }
 
     /// Get pending events count
@@ -356,9 +999,22 @@ impl ObservatoryAdapter {
     }
 
     /// Flush pending events
+    ///
+    /// With an endpoint configured, events are POSTed to
+    /// `{endpoint}/v1/governance/events` in batches of at most
+    /// `max_batch_size`. A batch that still fails after all attempts is
+    /// dropped and counted in [`TelemetryStats`]; the batches after it are
+    /// returned to the buffer for the next flush.
+    ///
+    /// Webhook delivery runs in a background task, at most one at a time, and
+    /// never affects the result.
     #[instrument(skip(self))]
     pub async fn flush(&self) -> ObservatoryResult<()> {
-        let events: Vec<GovernanceEvent> = {
+        if let Some(ref webhooks) = self.webhooks {
+            webhooks.spawn_flush();
+        }
+
+        let events: Vec<AuditRecord> = {
             let mut buffer = self.event_buffer.write().await;
             std::mem::take(&mut *buffer)
         };
@@ -366,22 +1022,175 @@ impl ObservatoryAdapter {
         if events.is_empty() {
             return Ok(());
         }
+        self.report_depth(0);
+
+        match self.endpoint {
+            Some(ref endpoint) => {
+                let mut sent = 0;
+                for batch in events.chunks(self.max_batch_size) {
+                    if let Err(e) = self.send_with_retry(endpoint, batch).await {
+                        self.counters.failed_flushes.fetch_add(1, Ordering::Relaxed);
+                        self.counters
+                            .consecutive_failed_flushes
+                            .fetch_add(1, Ordering::Relaxed);
+                        *self.counters.last_flush_error.lock().unwrap() = Some(e.to_string());
+                        *self.counters.last_flush_error_kind.lock().unwrap() = Some(e.kind());
+                        self.record_dropped(batch.len());
+
+                        // Unsent batches go back ahead of anything emitted meanwhile
+                        let remainder = &events[sent + batch.len()..];
+                        if !remainder.is_empty() {
+                            let mut buffer = self.event_buffer.write().await;
+                            buffer.splice(0..0, remainder.iter().cloned());
+                            let depth = buffer.len();
+                            drop(buffer);
+                            self.report_depth(depth);
+                        }
+
+                        warn!(
+                            event_count = batch.len(),
+                            requeued = remainder.len(),
+                            error = %e,
+                            "Governance event flush failed permanently - events dropped"
+                        );
+                        return Err(e);
+                    }
+                    self.retain_recent(batch);
+                    sent += batch.len();
+                }
+                self.counters
+                    .consecutive_failed_flushes
+                    .store(0, Ordering::Relaxed);
+            }
+            None => {
+                for record in &events {
+                    info!(event = ?record.event, "Governance event emitted");
+                }
+                self.retain_recent(&events);
+            }
+        }
 
-        if self.endpoint.is_some() {
-            // In production, batch send to observatory
-            warn!(
-                event_count = events.len(),
-                "Observatory remote flush not yet connected - events logged locally"
-            );
+        debug!(event_count = events.len(), "Flushed governance events");
+
+        Ok(())
+    }
+
+    /// Flush the remaining events and consume the adapter
+    ///
+    /// Call this at shutdown: dropping an adapter with buffered events
+    /// loses them, since `Drop` cannot wait for a flush.
+    pub async fn shutdown(self) -> ObservatoryResult<()> {
+        self.flush().await
+    }
+
+    /// Up to `limit` recently flushed events, newest first
+    ///
+    /// Empty unless enabled with [`Self::with_recent_events`]. Events still
+    /// waiting in the flush buffer are not included.
+    pub fn recent_events(&self, limit: usize) -> Vec<GovernanceEvent> {
+        let recent = self.recent_events.lock().unwrap();
+        recent
+            .iter()
+            .rev()
+            .take(limit)
+            .map(|record| record.event.clone())
+            .collect()
+    }
+
+    /// Append events not yet exported to `writer` as newline-delimited JSON
+    ///
+    /// Empty unless enabled with [`Self::with_audit_export`]. Each line is an
+    /// [`AuditRecord`]; sequence numbers are assigned when an event is
+    /// buffered and never reset, so consecutive exports continue where the
+    /// previous one stopped whether or not the events were flushed in
+    /// between. Events dropped from a full export queue show up as a gap in
+    /// the sequence. The queue is drained before writing, so emitting is
+    /// never blocked on `writer`; records that fail to write go back to the
+    /// front of the queue and are retried by the next export. Returns the
+    /// number of records written.
+    pub fn export_audit(&self, mut writer: impl Write) -> io::Result<usize> {
+        let pending: Vec<AuditRecord> = self.audit_queue.lock().unwrap().drain(..).collect();
+
+        let mut written = 0;
+        for record in &pending {
+            let result = serde_json::to_vec(record)
+                .map_err(io::Error::from)
+                .and_then(|mut line| {
+                    line.push(b'\n');
+                    writer.write_all(&line)
+                });
+            if let Err(e) = result {
+                self.requeue_audit(&pending[written..]);
+                return Err(e);
+            }
+            written += 1;
         }
+        writer.flush()?;
 
-        for event in &events {
-            info!(event = ?event, "Governance event emitted");
+        Ok(written)
+    }
+
+    /// Put unwritten records back in front of events queued since, dropping
+    /// the oldest past the queue capacity
+    fn requeue_audit(&self, records: &[AuditRecord]) {
+        let mut queue = self.audit_queue.lock().unwrap();
+        for record in records.iter().rev() {
+            queue.push_front(record.clone());
         }
+        while queue.len() > self.audit_capacity {
+            queue.pop_front();
+        }
+    }
 
-        debug!(event_count = events.len(), "Flushed governance events");
+    /// Record flushed events in the recent-events ring buffer
+    fn retain_recent(&self, events: &[AuditRecord]) {
+        if self.recent_capacity == 0 {
+            return;
+        }
 
-        Ok(())
+        let mut recent = self.recent_events.lock().unwrap();
+        let skip = events.len().saturating_sub(self.recent_capacity);
+        recent.extend(events[skip..].iter().cloned());
+        while recent.len() > self.recent_capacity {
+            recent.pop_front();
+        }
+    }
+
+    /// POST a batch of events, retrying failed attempts
+    ///
+    /// Only connection errors and 5xx responses are retried; a 4xx means the
+    /// batch itself was rejected and is returned immediately.
+    async fn send_with_retry(
+        &self,
+        endpoint: &str,
+        records: &[AuditRecord],
+    ) -> ObservatoryResult<()> {
+        let url = format!("{}/v1/governance/events", endpoint);
+        let events: Vec<&GovernanceEvent> = records.iter().map(|record| &record.event).collect();
+        let mut attempt = 1;
+
+        loop {
+            let error = match self.client.post(&url).json(&events).send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => ObservatoryError::EmitFailed {
+                    status: Some(response.status().as_u16()),
+                    message: format!("POST {} returned {}", url, response.status()),
+                },
+                Err(e) => ObservatoryError::EmitFailed {
+                    status: None,
+                    message: format!("POST {} failed: {}", url, e),
+                },
+            };
+
+            debug!(attempt, error = %error, "Governance event flush attempt failed");
+            let retryable = error.status().map_or(true, |status| status >= 500);
+            if !retryable || attempt >= FLUSH_MAX_ATTEMPTS {
+                return Err(error);
+            }
+
+            tokio::time::sleep(self.retry_backoff * attempt).await;
+            attempt += 1;
+        }
     }
 
     /// Create a health status for registry components
@@ -401,7 +1210,11 @@ impl ObservatoryAdapter {
                 name: "database".to_string(),
                 healthy: database_healthy,
                 latency_ms: database_latency_ms,
-                error: if database_healthy { None } else { Some("Database connection failed".to_string()) },
+                error: if database_healthy {
+                    None
+                } else {
+                    Some("Database connection failed".to_string())
+                },
             },
         );
 
@@ -411,7 +1224,11 @@ impl ObservatoryAdapter {
                 name: "cache".to_string(),
                 healthy: cache_healthy,
                 latency_ms: cache_latency_ms,
-                error: if cache_healthy { None } else { Some("Cache connection failed".to_string()) },
+                error: if cache_healthy {
+                    None
+                } else {
+                    Some("Cache connection failed".to_string())
+                },
             },
         );
 
@@ -421,7 +1238,11 @@ impl ObservatoryAdapter {
                 name: "search".to_string(),
                 healthy: search_healthy,
                 latency_ms: search_latency_ms,
-                error: if search_healthy { None } else { Some("Search service unavailable".to_string()) },
+                error: if search_healthy {
+                    None
+                } else {
+                    Some("Search service unavailable".to_string())
+                },
             },
         );
 
@@ -435,12 +1256,43 @@ impl ObservatoryAdapter {
     }
 }
 
+impl Drop for ObservatoryAdapter {
+    fn drop(&mut self) {
+        // No flush can be holding the lock while the adapter is dropped
+        let lost = self
+            .event_buffer
+            .try_read()
+            .map(|buffer| buffer.len())
+            .unwrap_or(0);
+        if lost > 0 {
+            warn!(
+                lost_events = lost,
+                "Observatory adapter dropped with {} buffered events; call shutdown() to flush them",
+                lost
+            );
+        }
+    }
+}
+
 impl Default for ObservatoryAdapter {
     fn default() -> Self {
         Self::new("llm-registry")
     }
 }
 
+/// Drains a [`SpanExportQueue`](llm_registry_core::SpanExportQueue) into the
+/// execution buffer
+#[async_trait]
+impl SpanExporter for ObservatoryAdapter {
+    async fn export(&self, batch: Vec<ExecutionResult>) {
+        for result in batch {
+            if let Err(e) = self.record_execution(result).await {
+                warn!(error = %e, "Failed to export execution spans");
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl TelemetryEmitter for ObservatoryAdapter {
     #[instrument(skip(self, attributes))]
@@ -491,23 +1343,17 @@ impl TelemetryEmitter for ObservatoryAdapter {
         Ok(())
     }
 
+    /// Buffer an event, flushing inline once a full batch is buffered
+    ///
+    /// Request-path callers use [`ObservatoryAdapter::emit_in_background`]
+    /// instead, so a slow observatory never delays a response.
     #[instrument(skip(self, event))]
     async fn emit_governance_event(&self, event: GovernanceEvent) -> ObservatoryResult<()> {
-        if !self.enabled {
-            return Ok(());
-        }
-
-        // Buffer the event
-        {
-            let mut buffer = self.event_buffer.write().await;
-            buffer.push(event.clone());
-        }
-
-        debug!(event = ?event, "Buffered governance event");
-
-        // Auto-flush if buffer is large
-        if self.pending_events().await >= 100 {
-            self.flush().await?;
+        // Auto-flush once a full batch is buffered
+        if let Some(depth) = self.buffer_governance_event(event).await? {
+            if depth >= self.max_batch_size {
+                self.flush().await?;
+            }
         }
 
         Ok(())
@@ -605,7 +1451,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_trace_asset_registration() {
-        let adapter = ObservatoryAdapter::default();
+        let adapter = Arc::new(ObservatoryAdapter::default());
 
         adapter
             .trace_asset_registration("id-123", "my-model", "1.0.0", "user@example.com")
@@ -615,9 +1461,32 @@ mod tests {
         assert_eq!(adapter.pending_events().await, 1);
     }
 
+    #[tokio::test]
+    async fn test_full_batch_flushes_in_background() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_delay(Duration::from_secs(60)))
+            .mount(&server)
+            .await;
+
+        let adapter =
+            Arc::new(ObservatoryAdapter::with_endpoint("test", server.uri()).with_batch_size(1));
+        // The caller returns while the flush waits on the observatory
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            adapter.trace_asset_registration("id-123", "my-model", "1.0.0", "user@example.com"),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    }
+
     #[tokio::test]
     async fn test_disabled_adapter() {
-        let adapter = ObservatoryAdapter::default().with_enabled(false);
+        let adapter = Arc::new(ObservatoryAdapter::default().with_enabled(false));
 
         adapter
             .trace_asset_registration("id-123", "my-model", "1.0.0", "user@example.com")
@@ -626,14 +1495,673 @@ mod tests {
 
         // No events should be buffered when disabled
         assert_eq!(adapter.pending_events().await, 0);
+        assert_eq!(adapter.state(), TelemetryState::Disabled);
+    }
+
+    #[tokio::test]
+    async fn test_record_execution_respects_buffer_limit() {
+        use llm_registry_core::execution::{ExecutionContext, ExecutionId, SpanCollector, SpanId};
+
+        let adapter = ObservatoryAdapter::default().with_max_buffered_events(1);
+        let collector = SpanCollector::new(&ExecutionContext {
+            execution_id: ExecutionId::new("exec-1"),
+            parent_span_id: SpanId::new(),
+            sampled: true,
+        });
+        let result = collector.finalize();
+
+        adapter.record_execution(result.clone()).await.unwrap();
+        assert!(adapter.record_execution(result).await.is_err());
+
+        let buffered = adapter.buffered_executions().await;
+        assert_eq!(buffered.len(), 1);
+        assert_eq!(buffered[0].execution_id, ExecutionId::new("exec-1"));
+        assert_eq!(adapter.telemetry_stats().dropped_events, 1);
+    }
+
+    fn registered_event() -> GovernanceEvent {
+        GovernanceEvent::AssetRegistered {
+            asset_id: "test-123".to_string(),
+            asset_name: "test-model".to_string(),
+            asset_version: "1.0.0".to_string(),
+            registered_by: "test-user".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_buffer_overflow_counts_dropped_events() {
+        let adapter = ObservatoryAdapter::default().with_max_buffered_events(1);
+
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        assert!(adapter
+            .emit_governance_event(registered_event())
+            .await
+            .is_err());
+
+        assert_eq!(adapter.pending_events().await, 1);
+        assert_eq!(adapter.telemetry_stats().dropped_events, 1);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_buffer() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri());
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        assert_eq!(adapter.pending_events().await, 2);
+
+        adapter.shutdown().await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(
+            requests[0]
+                .body_json::<Vec<GovernanceEvent>>()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drop_without_shutdown_warns_about_lost_events() {
+        #[derive(Clone, Default)]
+        struct Captured(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for Captured {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let adapter = ObservatoryAdapter::default();
+        for _ in 0..3 {
+            adapter
+                .emit_governance_event(registered_event())
+                .await
+                .unwrap();
+        }
+
+        let captured = Captured::default();
+        let writer = captured.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+        tracing::subscriber::with_default(subscriber, || drop(adapter));
+
+        let logs = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("WARN"), "{}", logs);
+        assert!(logs.contains("lost_events=3"), "{}", logs);
+    }
+
+    #[tokio::test]
+    async fn test_depth_gauge_observes_auto_flush() {
+        let adapter = ObservatoryAdapter::default().with_batch_size(3);
+        let depths = Arc::new(Mutex::new(Vec::new()));
+        let observed = Arc::clone(&depths);
+        adapter.register_depth_gauge(move |depth| observed.lock().unwrap().push(depth));
+
+        for _ in 0..4 {
+            adapter
+                .emit_governance_event(registered_event())
+                .await
+                .unwrap();
+        }
+
+        // Reaching the threshold of 3 flushes the buffer back to empty
+        assert_eq!(*depths.lock().unwrap(), [1, 2, 3, 0, 1]);
+        assert_eq!(adapter.pending_events().await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_access_decisions_flush_in_background() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_delay(Duration::from_millis(300)))
+            .mount(&server)
+            .await;
+
+        let adapter =
+            Arc::new(ObservatoryAdapter::with_endpoint("test", server.uri()).with_batch_size(1));
+        let recorded = tokio::time::timeout(Duration::from_millis(200), async {
+            for _ in 0..3 {
+                adapter
+                    .record_access_decision("user", "/v1/assets", "GET", true)
+                    .await
+                    .unwrap();
+            }
+        })
+        .await;
+        assert!(recorded.is_ok(), "access decisions waited on the flush");
+
+        // Only one background flush runs at a time
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_flush_is_recorded() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/governance/events"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(u64::from(FLUSH_MAX_ATTEMPTS))
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri())
+            .with_retry_backoff(Duration::from_millis(1));
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+
+        assert!(adapter.flush().await.is_err());
+
+        let stats = adapter.telemetry_stats();
+        assert_eq!(stats.failed_flushes, 1);
+        assert_eq!(stats.dropped_events, 2);
+        assert!(stats.last_flush_error.unwrap().contains("503"));
+        assert_eq!(stats.last_flush_error_kind.as_deref(), Some("server_error"));
+        assert!(!adapter.health_component().healthy);
+    }
+
+    #[tokio::test]
+    async fn test_client_error_is_not_retried() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri())
+            .with_retry_backoff(Duration::from_millis(1));
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+
+        let error = adapter.flush().await.unwrap_err();
+        assert_eq!(error.status(), Some(400));
+        assert_eq!(adapter.telemetry_stats().dropped_events, 1);
+    }
+
+    #[tokio::test]
+    async fn test_server_error_is_retried() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri())
+            .with_retry_backoff(Duration::from_millis(1));
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+
+        adapter.flush().await.unwrap();
+        assert_eq!(adapter.telemetry_stats(), TelemetryStats::default());
+    }
+
+    #[tokio::test]
+    async fn test_flush_sends_events_in_batches() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri()).with_batch_size(100);
+        adapter.event_buffer.write().await.extend(
+            std::iter::repeat_with(registered_event)
+                .take(250)
+                .enumerate()
+                .map(|(i, event)| AuditRecord {
+                    sequence: i as u64 + 1,
+                    event,
+                }),
+        );
+        adapter.flush().await.unwrap();
+
+        let batch_sizes: Vec<usize> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| request.body_json::<Vec<GovernanceEvent>>().unwrap().len())
+            .collect();
+        assert_eq!(batch_sizes, [100, 100, 50]);
+        assert_eq!(adapter.pending_events().await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_requeues_remainder() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri()).with_batch_size(100);
+        adapter.event_buffer.write().await.extend(
+            std::iter::repeat_with(registered_event)
+                .take(250)
+                .enumerate()
+                .map(|(i, event)| AuditRecord {
+                    sequence: i as u64 + 1,
+                    event,
+                }),
+        );
+
+        assert!(adapter.flush().await.is_err());
+        assert_eq!(adapter.telemetry_stats().dropped_events, 100);
+        assert_eq!(adapter.pending_events().await, 50);
+    }
+
+    #[tokio::test]
+    async fn test_recent_events_are_bounded() {
+        let adapter = Arc::new(ObservatoryAdapter::default().with_recent_events(3));
+
+        for i in 0..5 {
+            adapter
+                .trace_asset_update(&format!("asset-{}", i), vec![], "test-user")
+                .await
+                .unwrap();
+        }
+        assert!(adapter.recent_events(10).is_empty());
+
+        adapter.flush().await.unwrap();
+        let recent: Vec<String> = adapter
+            .recent_events(10)
+            .into_iter()
+            .map(|event| match event {
+                GovernanceEvent::AssetUpdated { asset_id, .. } => asset_id,
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect();
+        assert_eq!(recent, ["asset-4", "asset-3", "asset-2"]);
+        assert_eq!(adapter.recent_events(1).len(), 1);
+        assert_eq!(adapter.pending_events().await, 0);
+
+        // Disabled by default
+        let adapter = ObservatoryAdapter::default();
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        adapter.flush().await.unwrap();
+        assert!(adapter.recent_events(10).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_audit_export_sequence_is_contiguous() {
+        fn export(adapter: &ObservatoryAdapter) -> Vec<AuditRecord> {
+            let mut archive = Vec::new();
+            let written = adapter.export_audit(&mut archive).unwrap();
+            let records: Vec<AuditRecord> = archive
+                .split(|b| *b == b'\n')
+                .filter(|line| !line.is_empty())
+                .map(|line| serde_json::from_slice(line).unwrap())
+                .collect();
+            assert_eq!(records.len(), written);
+            records
+        }
+
+        // No recent-events ring: exports must not depend on it
+        let adapter = ObservatoryAdapter::default().with_audit_export(100);
+        for _ in 0..3 {
+            adapter
+                .emit_governance_event(registered_event())
+                .await
+                .unwrap();
+        }
+        adapter.flush().await.unwrap();
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+
+        // Flushed and still-buffered events alike
+        let first = export(&adapter);
+        assert_eq!(
+            first.iter().map(|r| r.sequence).collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(first[0].event.event_type(), "asset_registered");
+
+        adapter.flush().await.unwrap();
+        for _ in 0..2 {
+            adapter
+                .emit_governance_event(registered_event())
+                .await
+                .unwrap();
+        }
+        adapter.flush().await.unwrap();
+        let second = export(&adapter);
+        assert_eq!(
+            second.iter().map(|r| r.sequence).collect::<Vec<_>>(),
+            [5, 6]
+        );
+        assert!(export(&adapter).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_audit_export_queue_is_bounded() {
+        let adapter = ObservatoryAdapter::default().with_audit_export(2);
+        for _ in 0..3 {
+            adapter
+                .emit_governance_event(registered_event())
+                .await
+                .unwrap();
+        }
+
+        let mut archive = Vec::new();
+        assert_eq!(adapter.export_audit(&mut archive).unwrap(), 2);
+        let sequences: Vec<u64> = archive
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_slice::<AuditRecord>(line)
+                    .unwrap()
+                    .sequence
+            })
+            .collect();
+        assert_eq!(sequences, [2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_audit_export_requeues_unwritten_records() {
+        /// Accepts `remaining` writes, then fails
+        struct FailingWriter {
+            remaining: usize,
+            written: Vec<u8>,
+        }
+
+        impl Write for FailingWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.remaining == 0 {
+                    return Err(io::Error::other("disk full"));
+                }
+                self.remaining -= 1;
+                self.written.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let adapter = ObservatoryAdapter::default().with_audit_export(100);
+        for _ in 0..3 {
+            adapter
+                .emit_governance_event(registered_event())
+                .await
+                .unwrap();
+        }
+
+        let mut failing = FailingWriter {
+            remaining: 1,
+            written: Vec::new(),
+        };
+        assert!(adapter.export_audit(&mut failing).is_err());
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+
+        let mut archive = failing.written;
+        assert_eq!(adapter.export_audit(&mut archive).unwrap(), 3);
+        let sequences: Vec<u64> = archive
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                serde_json::from_slice::<AuditRecord>(line)
+                    .unwrap()
+                    .sequence
+            })
+            .collect();
+        assert_eq!(sequences, [1, 2, 3, 4]);
+    }
+
+    fn access_event() -> GovernanceEvent {
+        GovernanceEvent::AccessDecision {
+            principal: "user".to_string(),
+            resource: "asset".to_string(),
+            action: "read".to_string(),
+            allowed: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_denied_event_type_is_not_buffered() {
+        let adapter = Arc::new(
+            ObservatoryAdapter::default()
+                .with_event_filter(EventFilter::deny(&["access_decision"])),
+        );
+
+        adapter.emit_governance_event(access_event()).await.unwrap();
+        assert_eq!(adapter.pending_events().await, 0);
+
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        let buffered = adapter.buffered_events().await;
+        assert_eq!(buffered.len(), 1);
+        assert_eq!(buffered[0].event_type(), "asset_registered");
+
+        let stats = adapter.telemetry_stats();
+        assert_eq!(stats.filtered_events, 1);
+        assert_eq!(stats.dropped_events, 0);
+
+        // Denials are kept even though the type is filtered
+        adapter
+            .trace_access_decision("user", "/v1/assets", "DELETE", false)
+            .await
+            .unwrap();
+        let buffered = adapter.buffered_events().await;
+        assert_eq!(buffered.len(), 2);
+        assert!(matches!(
+            buffered[1],
+            GovernanceEvent::AccessDecision { allowed: false, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_allow_filter_buffers_only_listed_types() {
+        let adapter = ObservatoryAdapter::default()
+            .with_event_filter(EventFilter::allow(&["access_decision"]));
+
+        adapter.emit_governance_event(access_event()).await.unwrap();
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+
+        let buffered = adapter.buffered_events().await;
+        assert_eq!(buffered.len(), 1);
+        assert_eq!(buffered[0].event_type(), "access_decision");
+        assert_eq!(adapter.telemetry_stats().filtered_events, 1);
+    }
+
+    #[test]
+    fn test_event_type_matches_serialized_tag() {
+        let value = serde_json::to_value(access_event()).unwrap();
+        assert_eq!(value["type"], access_event().event_type());
+    }
+
+    #[tokio::test]
+    async fn test_webhooks_receive_events_when_observatory_fails() {
+        use crate::adapters::webhook::Webhook;
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/v1/governance/events"))
+            .respond_with(ResponseTemplate::new(400))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+
+        let sink = Arc::new(WebhookSink::new());
+        sink.register(Webhook::new(format!("{}/hook", server.uri()), "s3cret"));
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri())
+            .with_event_filter(EventFilter::deny(&["access_decision"]))
+            .with_webhooks(Arc::clone(&sink));
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        adapter.emit_governance_event(access_event()).await.unwrap();
+
+        assert!(adapter.flush().await.is_err());
+        for _ in 0..100 {
+            if sink.stats().delivered == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(sink.stats().delivered, 2);
+    }
+
+    #[tokio::test]
+    async fn test_repeated_flush_failures_degrade_state() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2 * u64::from(FLUSH_MAX_ATTEMPTS))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri())
+            .with_retry_backoff(Duration::from_millis(1))
+            .with_degraded_after(2);
+        assert_eq!(adapter.state(), TelemetryState::Healthy);
+
+        // One failure is tolerated, the second in a row degrades
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        assert!(adapter.flush().await.is_err());
+        assert_eq!(adapter.state(), TelemetryState::Healthy);
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        assert!(adapter.flush().await.is_err());
+        assert_eq!(adapter.state(), TelemetryState::Degraded);
+
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        adapter.flush().await.unwrap();
+        assert_eq!(adapter.state(), TelemetryState::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_successful_flush_restores_health() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .mount(&server)
+            .await;
+
+        let adapter = ObservatoryAdapter::with_endpoint("test", server.uri());
+        adapter
+            .emit_governance_event(registered_event())
+            .await
+            .unwrap();
+        adapter.flush().await.unwrap();
+
+        assert_eq!(adapter.telemetry_stats(), TelemetryStats::default());
+        assert!(adapter.health_component().healthy);
     }
 
     #[tokio::test]
     async fn test_health_status_creation() {
         let status = ObservatoryAdapter::create_health_status(
-            true, 5,   // database
-            true, 2,   // cache
-            false, 0,  // search (unhealthy)
+            true, 5, // database
+            true, 2, // cache
+            false, 0, // search (unhealthy)
         );
 
         assert!(!status.healthy); // Overall unhealthy due to search
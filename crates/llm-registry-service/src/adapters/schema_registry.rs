@@ -4,17 +4,69 @@
 //! Provides schema validation for model metadata and pipeline descriptors without
 //! modifying existing registry indexing or metadata management logic.
 
+use super::config_manager::Environment;
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 #[allow(dead_code)]
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
-use tracing::{debug, instrument, warn};
+use tokio::sync::OnceCell;
+use tracing::{debug, info, instrument, warn};
+
+/// Maximum number of concurrent fetches while warming the cache
+const WARM_CACHE_CONCURRENCY: usize = 8;
+
+/// Default lifetime of a cached schema
+const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(3600);
+
+/// How long a remote schema fetch may take before the registry counts as
+/// unavailable
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Characters escaped in upstream URL path segments: all but RFC 3986
+/// unreserved characters
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Default TTL jitter, as a fraction of the TTL in either direction
+const DEFAULT_CACHE_TTL_JITTER: f64 = 0.1;
+
+/// Namespace holding the registry's canonical schemas
+pub const DEFAULT_SCHEMA_NAMESPACE: &str = "llm.registry";
+
+/// Default schema namespace for an environment
+///
+/// Production uses [`DEFAULT_SCHEMA_NAMESPACE`]; other environments keep
+/// their schemas under `llm.registry.<environment>`.
+pub fn namespace_for_environment(environment: Environment) -> String {
+    match environment {
+        Environment::Production => DEFAULT_SCHEMA_NAMESPACE.to_string(),
+        other => format!("{}.{}", DEFAULT_SCHEMA_NAMESPACE, other.as_str()),
+    }
+}
+
+/// Canonical schema for model metadata
+pub const MODEL_METADATA_SCHEMA: &str = "ModelMetadata";
+
+/// Canonical schema for pipeline descriptors
+pub const PIPELINE_DESCRIPTOR_SCHEMA: &str = "PipelineDescriptor";
+
+/// Annotation marking an asset admitted without schema validation, set to
+/// `true` when the queueing fallback let it through an outage
+pub const SCHEMA_UNVALIDATED_ANNOTATION: &str = "registry.schema.unvalidated";
 
 /// Errors from schema registry consumption
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum SchemaAdapterError {
     #[error("Schema not found: {0}")]
     SchemaNotFound(String),
@@ -60,6 +112,38 @@ pub struct ConsumedSchema {
     pub is_active: bool,
 }
 
+/// Behavior of schema validation when the schema registry is unavailable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaFallbackMode {
+    /// Treat data as valid and attach a warning
+    #[default]
+    Permissive,
+    /// Fail closed with [`SchemaAdapterError::Unavailable`]
+    Reject,
+    /// Accept the data but flag it as unvalidated for later re-validation
+    Queue,
+}
+
+impl SchemaFallbackMode {
+    /// Default fallback for an environment (fail closed in production)
+    pub fn for_environment(environment: Environment) -> Self {
+        match environment {
+            Environment::Production => SchemaFallbackMode::Reject,
+            Environment::Staging | Environment::Development => SchemaFallbackMode::Permissive,
+        }
+    }
+
+    /// Lowercase name of the mode
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SchemaFallbackMode::Permissive => "permissive",
+            SchemaFallbackMode::Reject => "reject",
+            SchemaFallbackMode::Queue => "queue",
+        }
+    }
+}
+
 /// Schema validation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchemaValidationResult {
@@ -71,6 +155,71 @@ pub struct SchemaValidationResult {
     pub errors: Vec<String>,
     /// Validation warnings
     pub warnings: Vec<String>,
+    /// Data was accepted without validation and must be re-validated later
+    #[serde(default)]
+    pub unvalidated: bool,
+    /// Fallback applied because the schema registry was unavailable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<SchemaFallbackMode>,
+}
+
+/// Result of comparing two versions of a schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompatibilityReport {
+    /// Whether data valid under the old schema remains valid under the new one
+    pub compatible: bool,
+    /// Changes that break existing data
+    pub breaking_changes: Vec<String>,
+    /// Changes that only extend the schema
+    pub additive_changes: Vec<String>,
+}
+
+impl SchemaValidationResult {
+    /// Create a failed result with a single error
+    fn invalid(schema_id: &str, error: String) -> Self {
+        Self {
+            valid: false,
+            schema_id: schema_id.to_string(),
+            errors: vec![error],
+            warnings: vec![],
+            unvalidated: false,
+            fallback: None,
+        }
+    }
+
+    /// Create a result for a format that cannot be validated yet
+    fn unsupported(schema_id: &str, format: SerializationFormat) -> Self {
+        Self {
+            valid: false,
+            schema_id: schema_id.to_string(),
+            errors: vec![],
+            warnings: vec![format!(
+                "Validation for {:?} schemas is not supported - data was not validated",
+                format
+            )],
+            unvalidated: false,
+            fallback: None,
+        }
+    }
+
+    /// Create the result for a registry outage under a non-rejecting fallback
+    fn fallback(mode: SchemaFallbackMode) -> Self {
+        let warning = match mode {
+            SchemaFallbackMode::Queue => {
+                "Schema registry unavailable - data queued for re-validation"
+            }
+            _ => "Schema registry unavailable - validation skipped",
+        };
+
+        Self {
+            valid: true,
+            schema_id: "unavailable".to_string(),
+            errors: vec![],
+            warnings: vec![warning.to_string()],
+            unvalidated: mode == SchemaFallbackMode::Queue,
+            fallback: Some(mode),
+        }
+    }
 }
 
 /// Trait for schema registry consumption
@@ -92,13 +241,59 @@ pub trait SchemaConsumer: Send + Sync {
         &self,
         schema_name: &str,
         namespace: &str,
-        data: &serde_json::Value, // Note: used for actual validation when upstream is connected
+        data: &serde_json::Value,
     ) -> SchemaResult<SchemaValidationResult>;
 
+    /// Validate data against a schema, applying `fallback` instead of the
+    /// consumer's own when the schema registry is unavailable
+    async fn validate_with_fallback(
+        &self,
+        schema_name: &str,
+        namespace: &str,
+        data: &serde_json::Value,
+        fallback: SchemaFallbackMode,
+    ) -> SchemaResult<SchemaValidationResult> {
+        match self
+            .validate_against_schema(schema_name, namespace, data)
+            .await
+        {
+            Err(SchemaAdapterError::Unavailable(_)) if fallback != SchemaFallbackMode::Reject => {
+                Ok(SchemaValidationResult::fallback(fallback))
+            }
+            result => result,
+        }
+    }
+
     /// List available schemas for a namespace
     async fn list_schemas(&self, namespace: &str) -> SchemaResult<Vec<String>>;
+
+    /// Namespace holding the canonical registry schemas
+    fn default_namespace(&self) -> &str {
+        DEFAULT_SCHEMA_NAMESPACE
+    }
+
+    /// Behavior when the schema registry is unavailable
+    fn fallback(&self) -> SchemaFallbackMode {
+        SchemaFallbackMode::default()
+    }
 }
 
+/// A cached schema and when it stops being served
+#[derive(Debug, Clone)]
+struct CachedSchema {
+    schema: ConsumedSchema,
+    expires_at: Option<Instant>,
+}
+
+impl CachedSchema {
+    fn is_fresh(&self) -> bool {
+        self.expires_at.map_or(true, |at| Instant::now() < at)
+    }
+}
+
+/// Upstream fetch shared by concurrent cache misses for one key
+type InFlightFetch = Arc<OnceCell<SchemaResult<ConsumedSchema>>>;
+
 /// Schema Registry Adapter for consuming canonical schema definitions
 ///
 /// This adapter provides a thin integration layer for consuming schema
@@ -106,12 +301,23 @@ pub trait SchemaConsumer: Send + Sync {
 /// registry logic or public APIs.
 pub struct SchemaRegistryAdapter {
     /// Base URL for schema registry (if remote)
-    #[allow(dead_code)]
     endpoint: Option<String>,
+    /// HTTP client for remote fetches
+    client: reqwest::Client,
     /// Cached schemas for performance
-    cache: Arc<tokio::sync::RwLock<HashMap<String, ConsumedSchema>>>,
+    cache: Arc<tokio::sync::RwLock<HashMap<String, CachedSchema>>>,
+    /// Lifetime of cached schemas (`None` never expires)
+    cache_ttl: Option<Duration>,
+    /// Random TTL spread, as a fraction of `cache_ttl` in either direction
+    cache_ttl_jitter: f64,
+    /// Fetches in progress, keyed by cache key
+    in_flight: Mutex<HashMap<String, InFlightFetch>>,
     /// Default namespace for model metadata schemas
     default_namespace: String,
+    /// Whether `default_namespace` was set explicitly rather than derived
+    namespace_overridden: bool,
+    /// Behavior when the registry is unavailable
+    fallback: SchemaFallbackMode,
 }
 
 impl SchemaRegistryAdapter {
@@ -119,23 +325,60 @@ impl SchemaRegistryAdapter {
     pub fn new() -> Self {
         Self {
             endpoint: None,
+            client: reqwest::Client::builder()
+                .timeout(FETCH_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
             cache: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
-            default_namespace: "llm.registry".to_string(),
+            cache_ttl: Some(DEFAULT_CACHE_TTL),
+            cache_ttl_jitter: DEFAULT_CACHE_TTL_JITTER,
+            in_flight: Mutex::new(HashMap::new()),
+            default_namespace: DEFAULT_SCHEMA_NAMESPACE.to_string(),
+            namespace_overridden: false,
+            fallback: SchemaFallbackMode::default(),
         }
     }
 
     /// Create adapter with remote endpoint
     pub fn with_endpoint(endpoint: String) -> Self {
-        Self {
-            endpoint: Some(endpoint),
-            cache: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
-            default_namespace: "llm.registry".to_string(),
-        }
+        let mut adapter = Self::new();
+        adapter.endpoint = Some(endpoint.trim_end_matches('/').to_string());
+        adapter
+    }
+
+    /// Set how long fetched schemas are cached (`None` never expires)
+    pub fn with_cache_ttl(mut self, ttl: Option<Duration>) -> Self {
+        self.cache_ttl = ttl;
+        self
     }
 
-    /// Set the default namespace
+    /// Spread each entry's TTL randomly by up to `fraction` of the TTL in
+    /// either direction, so schemas fetched together do not expire together
+    pub fn with_cache_ttl_jitter(mut self, fraction: f64) -> Self {
+        self.cache_ttl_jitter = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the default namespace, overriding any environment default
     pub fn with_namespace(mut self, namespace: String) -> Self {
         self.default_namespace = namespace;
+        self.namespace_overridden = true;
+        self
+    }
+
+    /// Derive the default namespace from the environment
+    ///
+    /// Has no effect once [`with_namespace`](Self::with_namespace) was called.
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        if !self.namespace_overridden {
+            self.default_namespace = namespace_for_environment(environment);
+        }
+        self
+    }
+
+    /// Set the behavior when the registry is unavailable
+    pub fn with_fallback(mut self, fallback: SchemaFallbackMode) -> Self {
+        self.fallback = fallback;
         self
     }
 
@@ -147,16 +390,48 @@ impl SchemaRegistryAdapter {
         }
     }
 
+    /// TTL for a new cache entry, with jitter applied
+    fn entry_ttl(&self) -> Option<Duration> {
+        self.cache_ttl.map(|ttl| {
+            if self.cache_ttl_jitter == 0.0 {
+                return ttl;
+            }
+            let factor = rand::thread_rng()
+                .gen_range(1.0 - self.cache_ttl_jitter..=1.0 + self.cache_ttl_jitter);
+            ttl.mul_f64(factor)
+        })
+    }
+
+    /// Cache `schema` under `key`
+    async fn insert_cached(&self, key: String, schema: ConsumedSchema) {
+        let expires_at = self.entry_ttl().map(|ttl| Instant::now() + ttl);
+        self.cache
+            .write()
+            .await
+            .insert(key, CachedSchema { schema, expires_at });
+    }
+
+    /// Unexpired cached schema for `key`
+    async fn cached(&self, key: &str) -> Option<ConsumedSchema> {
+        let cache = self.cache.read().await;
+        cache
+            .get(key)
+            .filter(|entry| entry.is_fresh())
+            .map(|entry| entry.schema.clone())
+    }
+
     /// Get the model metadata schema for validation
     #[instrument(skip(self))]
     pub async fn get_model_metadata_schema(&self) -> SchemaResult<ConsumedSchema> {
-        self.get_schema("ModelMetadata", &self.default_namespace).await
+        self.get_schema(MODEL_METADATA_SCHEMA, &self.default_namespace)
+            .await
     }
 
     /// Get the pipeline descriptor schema for validation
     #[instrument(skip(self))]
     pub async fn get_pipeline_descriptor_schema(&self) -> SchemaResult<ConsumedSchema> {
-        self.get_schema("PipelineDescriptor", &self.default_namespace).await
+        self.get_schema(PIPELINE_DESCRIPTOR_SCHEMA, &self.default_namespace)
+            .await
     }
 
     /// Validate model metadata against canonical schema
@@ -165,7 +440,7 @@ impl SchemaRegistryAdapter {
         &self,
         metadata: &serde_json::Value,
     ) -> SchemaResult<SchemaValidationResult> {
-        self.validate_against_schema("ModelMetadata", &self.default_namespace, metadata)
+        self.validate_against_schema(MODEL_METADATA_SCHEMA, &self.default_namespace, metadata)
             .await
     }
 
@@ -175,8 +450,12 @@ impl SchemaRegistryAdapter {
         &self,
         descriptor: &serde_json::Value,
     ) -> SchemaResult<SchemaValidationResult> {
-        self.validate_against_schema("PipelineDescriptor", &self.default_namespace, descriptor)
-            .await
+        self.validate_against_schema(
+            PIPELINE_DESCRIPTOR_SCHEMA,
+            &self.default_namespace,
+            descriptor,
+        )
+        .await
     }
 
     /// Clear cached schemas
@@ -185,6 +464,259 @@ impl SchemaRegistryAdapter {
         cache.clear();
         debug!("Schema cache cleared");
     }
+
+    /// Number of cached schemas
+    pub async fn cache_len(&self) -> usize {
+        self.cache.read().await.len()
+    }
+
+    /// Schemas needed on the registration path, as `(name, namespace)` pairs
+    pub fn critical_schemas(&self) -> Vec<(&str, &str)> {
+        vec![
+            (MODEL_METADATA_SCHEMA, self.default_namespace.as_str()),
+            (PIPELINE_DESCRIPTOR_SCHEMA, self.default_namespace.as_str()),
+        ]
+    }
+
+    /// Fetch and cache the given `(name, namespace)` schemas concurrently
+    ///
+    /// Individual failures are logged and skipped. Returns how many schemas
+    /// were cached.
+    #[instrument(skip(self, names), fields(count = names.len()))]
+    pub async fn warm_cache(&self, names: &[(&str, &str)]) -> SchemaResult<usize> {
+        // Owned names keep the future `Send` for any lifetime, so it can be spawned
+        let owned: Vec<(String, String)> = names
+            .iter()
+            .map(|&(name, namespace)| (name.to_string(), namespace.to_string()))
+            .collect();
+        let loaded = stream::iter(owned)
+            .map(|(name, namespace)| async move {
+                match self.get_schema(&name, &namespace).await {
+                    Ok(_) => true,
+                    Err(e) => {
+                        warn!(
+                            schema_name = %name,
+                            namespace = %namespace,
+                            error = %e,
+                            "Failed to preload schema"
+                        );
+                        false
+                    }
+                }
+            })
+            .buffer_unordered(WARM_CACHE_CONCURRENCY)
+            .filter(|loaded| futures::future::ready(*loaded))
+            .count()
+            .await;
+
+        info!(loaded, requested = names.len(), "Schema cache warmed");
+        Ok(loaded)
+    }
+
+    /// Preload the model metadata and pipeline descriptor schemas
+    pub async fn warm_critical_schemas(&self) -> SchemaResult<usize> {
+        let names = self.critical_schemas();
+        self.warm_cache(&names).await
+    }
+
+    /// Fetch a schema upstream, sharing one request among concurrent misses
+    ///
+    /// Callers that miss the cache for the same key while a fetch is running
+    /// wait for it and receive its result instead of fetching again.
+    async fn fetch_shared(&self, key: String, path: String) -> SchemaResult<ConsumedSchema> {
+        let fetch = Arc::clone(
+            self.in_flight
+                .lock()
+                .unwrap()
+                .entry(key.clone())
+                .or_default(),
+        );
+
+        let result = fetch
+            .get_or_init(|| async {
+                // A fetch that completed just before this one was registered
+                match self.cached(&key).await {
+                    Some(schema) => Ok(schema),
+                    None => self.fetch_remote(key.clone(), path).await,
+                }
+            })
+            .await
+            .clone();
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &fetch))
+        {
+            in_flight.remove(&key);
+        }
+        result
+    }
+
+    /// Fetch a schema from the upstream registry and cache it under `key`
+    async fn fetch_remote(&self, key: String, path: String) -> SchemaResult<ConsumedSchema> {
+        let Some(ref endpoint) = self.endpoint else {
+            return Err(SchemaAdapterError::Unavailable(
+                "Schema registry connection not configured".to_string(),
+            ));
+        };
+
+        let url = format!("{}/{}", endpoint, path);
+        let response = super::with_execution_headers(self.client.get(&url))
+            .send()
+            .await
+            .map_err(|e| SchemaAdapterError::Unavailable(format!("GET {} failed: {}", url, e)))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(SchemaAdapterError::SchemaNotFound(path));
+        }
+        if !response.status().is_success() {
+            return Err(SchemaAdapterError::Unavailable(format!(
+                "GET {} returned {}",
+                url,
+                response.status()
+            )));
+        }
+
+        let schema: ConsumedSchema = response.json().await.map_err(|e| {
+            SchemaAdapterError::Unavailable(format!("Invalid schema response from {}: {}", url, e))
+        })?;
+
+        self.insert_cached(key, schema.clone()).await;
+        debug!(schema_id = %schema.id, "Schema fetched from upstream registry");
+
+        Ok(schema)
+    }
+
+    /// Check whether `new` is backward-compatible with `old`
+    ///
+    /// Only JSON Schema definitions (in JSON or YAML) can be compared; a
+    /// change of serialization format is always incompatible.
+    #[instrument(skip(self, old, new), fields(old = %old.version, new = %new.version))]
+    pub fn check_compatibility(
+        &self,
+        old: &ConsumedSchema,
+        new: &ConsumedSchema,
+    ) -> SchemaResult<CompatibilityReport> {
+        if old.format != new.format {
+            return Ok(CompatibilityReport {
+                compatible: false,
+                breaking_changes: vec![format!(
+                    "format changed from {:?} to {:?}",
+                    old.format, new.format
+                )],
+                additive_changes: vec![],
+            });
+        }
+
+        let old_definition = Self::parse_definition(old)?;
+        let new_definition = Self::parse_definition(new)?;
+
+        let mut report = CompatibilityReport {
+            compatible: true,
+            breaking_changes: vec![],
+            additive_changes: vec![],
+        };
+        compare_json_schemas(&old_definition, &new_definition, "$", &mut report);
+        report.compatible = report.breaking_changes.is_empty();
+
+        debug!(
+            breaking = report.breaking_changes.len(),
+            additive = report.additive_changes.len(),
+            "Schema compatibility checked"
+        );
+
+        Ok(report)
+    }
+
+    /// Parse a JSON or YAML schema definition
+    fn parse_definition(schema: &ConsumedSchema) -> SchemaResult<serde_json::Value> {
+        match schema.format {
+            SerializationFormat::Json => serde_json::from_str(&schema.content).map_err(|e| {
+                SchemaAdapterError::ValidationFailed(format!("Invalid JSON schema content: {}", e))
+            }),
+            SerializationFormat::Yaml => serde_yaml::from_str(&schema.content).map_err(|e| {
+                SchemaAdapterError::ValidationFailed(format!("Invalid YAML schema content: {}", e))
+            }),
+            format => Err(SchemaAdapterError::ValidationFailed(format!(
+                "Structural schemas are not supported for {:?}",
+                format
+            ))),
+        }
+    }
+
+    /// Validate data according to the schema's serialization format
+    fn validate_with_format(
+        schema: &ConsumedSchema,
+        data: &serde_json::Value,
+    ) -> SchemaValidationResult {
+        match schema.format {
+            SerializationFormat::Json | SerializationFormat::Yaml => {
+                match Self::parse_definition(schema) {
+                    Ok(definition) => Self::validate_structural(schema, &definition, data),
+                    Err(e) => SchemaValidationResult::invalid(&schema.id, e.to_string()),
+                }
+            }
+            SerializationFormat::Avro => Self::validate_avro(schema, data),
+            SerializationFormat::Protobuf => {
+                SchemaValidationResult::unsupported(&schema.id, SerializationFormat::Protobuf)
+            }
+        }
+    }
+
+    /// Validate data against a JSON Schema style definition
+    fn validate_structural(
+        schema: &ConsumedSchema,
+        definition: &serde_json::Value,
+        data: &serde_json::Value,
+    ) -> SchemaValidationResult {
+        let mut errors = Vec::new();
+        check_json_schema(definition, data, "$", &mut errors);
+
+        SchemaValidationResult {
+            valid: errors.is_empty(),
+            schema_id: schema.id.clone(),
+            errors,
+            warnings: vec![],
+            unvalidated: false,
+            fallback: None,
+        }
+    }
+
+    /// Validate a datum against a parsed Avro schema
+    #[cfg(feature = "avro")]
+    fn validate_avro(schema: &ConsumedSchema, data: &serde_json::Value) -> SchemaValidationResult {
+        let parsed = match apache_avro::Schema::parse_str(&schema.content) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                return SchemaValidationResult::invalid(
+                    &schema.id,
+                    format!("Invalid Avro schema content: {}", e),
+                )
+            }
+        };
+
+        match apache_avro::types::Value::from(data.clone()).resolve(&parsed) {
+            Ok(_) => SchemaValidationResult {
+                valid: true,
+                schema_id: schema.id.clone(),
+                errors: vec![],
+                warnings: vec![],
+                unvalidated: false,
+                fallback: None,
+            },
+            Err(e) => SchemaValidationResult::invalid(
+                &schema.id,
+                format!("Datum does not match Avro schema: {}", e),
+            ),
+        }
+    }
+
+    /// Avro support is compiled out without the `avro` feature
+    #[cfg(not(feature = "avro"))]
+    fn validate_avro(schema: &ConsumedSchema, _data: &serde_json::Value) -> SchemaValidationResult {
+        SchemaValidationResult::unsupported(&schema.id, SerializationFormat::Avro)
+    }
 }
 
 impl Default for SchemaRegistryAdapter {
@@ -193,6 +725,34 @@ impl Default for SchemaRegistryAdapter {
     }
 }
 
+impl fmt::Debug for SchemaRegistryAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaRegistryAdapter")
+            .field("endpoint", &self.endpoint)
+            .field("default_namespace", &self.default_namespace)
+            .field("fallback", &self.fallback)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Relative URL path of `segments`, each percent-encoded
+///
+/// Empty and dot segments are refused, since URL parsing would collapse them
+/// even when encoded.
+fn schema_path(segments: &[&str]) -> SchemaResult<String> {
+    if segments
+        .iter()
+        .any(|segment| matches!(*segment, "" | "." | ".."))
+    {
+        return Err(SchemaAdapterError::SchemaNotFound(segments.join("/")));
+    }
+    Ok(segments
+        .iter()
+        .map(|segment| utf8_percent_encode(segment, PATH_SEGMENT).to_string())
+        .collect::<Vec<_>>()
+        .join("/"))
+}
+
 #[async_trait]
 impl SchemaConsumer for SchemaRegistryAdapter {
     #[instrument(skip(self))]
@@ -200,20 +760,21 @@ impl SchemaConsumer for SchemaRegistryAdapter {
         let key = Self::cache_key(name, namespace, None);
 
         // Check cache first
-        {
-            let cache = self.cache.read().await;
-            if let Some(schema) = cache.get(&key) {
-                debug!(schema_name = %name, "Schema found in cache");
-                return Ok(schema.clone());
-            }
+        if let Some(schema) = self.cached(&key).await {
+            debug!(schema_name = %name, "Schema found in cache");
+            return Ok(schema);
+        }
+
+        if self.endpoint.is_some() {
+            return self
+                .fetch_shared(key, schema_path(&["schemas", namespace, name])?)
+                .await;
         }
 
-        // In production, this would fetch from the upstream schema registry
-        // For Phase 2B, we provide a stub that indicates the integration point
         warn!(
             schema_name = %name,
             namespace = %namespace,
-            "Schema registry fetch not yet connected - returning placeholder"
+            "Schema registry endpoint not configured"
         );
 
         Err(SchemaAdapterError::Unavailable(
@@ -231,19 +792,25 @@ impl SchemaConsumer for SchemaRegistryAdapter {
         let key = Self::cache_key(name, namespace, Some(version));
 
         // Check cache first
-        {
-            let cache = self.cache.read().await;
-            if let Some(schema) = cache.get(&key) {
-                debug!(schema_name = %name, version = %version, "Versioned schema found in cache");
-                return Ok(schema.clone());
-            }
+        if let Some(schema) = self.cached(&key).await {
+            debug!(schema_name = %name, version = %version, "Versioned schema found in cache");
+            return Ok(schema);
+        }
+
+        if self.endpoint.is_some() {
+            return self
+                .fetch_shared(
+                    key,
+                    schema_path(&["schemas", namespace, name, "versions", version])?,
+                )
+                .await;
         }
 
         warn!(
             schema_name = %name,
             namespace = %namespace,
             version = %version,
-            "Schema registry version fetch not yet connected"
+            "Schema registry endpoint not configured"
         );
 
         Err(SchemaAdapterError::Unavailable(
@@ -251,65 +818,72 @@ impl SchemaConsumer for SchemaRegistryAdapter {
         ))
     }
 
-    #[instrument(skip(self, _data))]
     async fn validate_against_schema(
         &self,
         schema_name: &str,
         namespace: &str,
-        #[allow(unused_variables)]
-        _data: &serde_json::Value,
+        data: &serde_json::Value,
+    ) -> SchemaResult<SchemaValidationResult> {
+        self.validate_with_fallback(schema_name, namespace, data, self.fallback)
+            .await
+    }
+
+    #[instrument(skip(self, data), fields(schema_fallback = tracing::field::Empty))]
+    async fn validate_with_fallback(
+        &self,
+        schema_name: &str,
+        namespace: &str,
+        data: &serde_json::Value,
+        fallback: SchemaFallbackMode,
     ) -> SchemaResult<SchemaValidationResult> {
         // Attempt to get the schema
         let schema_result = self.get_schema(schema_name, namespace).await;
 
         match schema_result {
             Ok(schema) => {
-                // In production, perform actual JSON Schema validation
-                // For Phase 2B, return success to indicate integration point works
                 debug!(
                     schema_name = %schema_name,
                     schema_id = %schema.id,
+                    format = ?schema.format,
                     "Validation performed against schema"
                 );
 
-                Ok(SchemaValidationResult {
-                    valid: true,
-                    schema_id: schema.id,
-                    errors: vec![],
-                    warnings: vec![
-                        "Schema validation is in stub mode - connect to upstream for full validation".to_string()
-                    ],
-                })
+                Ok(Self::validate_with_format(&schema, data))
             }
-            Err(SchemaAdapterError::Unavailable(_)) => {
-                // Return a soft validation result when registry is unavailable
-                debug!(
+            Err(SchemaAdapterError::Unavailable(reason)) => {
+                tracing::Span::current().record("schema_fallback", fallback.as_str());
+                warn!(
                     schema_name = %schema_name,
-                    "Schema registry unavailable - returning permissive validation"
+                    fallback = fallback.as_str(),
+                    "Schema registry unavailable - applying fallback"
                 );
 
-                Ok(SchemaValidationResult {
-                    valid: true,
-                    schema_id: "unavailable".to_string(),
-                    errors: vec![],
-                    warnings: vec![
-                        "Schema registry unavailable - validation skipped".to_string()
-                    ],
-                })
+                match fallback {
+                    SchemaFallbackMode::Reject => Err(SchemaAdapterError::Unavailable(reason)),
+                    mode => Ok(SchemaValidationResult::fallback(mode)),
+                }
             }
             Err(e) => Err(e),
         }
     }
 
+    fn default_namespace(&self) -> &str {
+        &self.default_namespace
+    }
+
+    fn fallback(&self) -> SchemaFallbackMode {
+        self.fallback
+    }
+
     #[instrument(skip(self))]
     async fn list_schemas(&self, namespace: &str) -> SchemaResult<Vec<String>> {
         debug!(namespace = %namespace, "Listing schemas for namespace");
 
         // Return known schema types for the registry namespace
-        if namespace == self.default_namespace || namespace == "llm.registry" {
+        if namespace == self.default_namespace {
             Ok(vec![
-                "ModelMetadata".to_string(),
-                "PipelineDescriptor".to_string(),
+                MODEL_METADATA_SCHEMA.to_string(),
+                PIPELINE_DESCRIPTOR_SCHEMA.to_string(),
                 "AssetManifest".to_string(),
                 "DependencyGraph".to_string(),
             ])
@@ -319,6 +893,287 @@ impl SchemaConsumer for SchemaRegistryAdapter {
     }
 }
 
+/// [`SchemaConsumer`] serving a fixed set of schemas without a remote
+///
+/// Schemas are keyed by name and served for their own `namespace` and
+/// `version`. Validation runs the same checks as [`SchemaRegistryAdapter`],
+/// so tests and air-gapped deployments get real results.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySchemaConsumer {
+    schemas: HashMap<String, ConsumedSchema>,
+    default_namespace: String,
+}
+
+impl InMemorySchemaConsumer {
+    /// Create a consumer serving `schemas`, keyed by schema name
+    pub fn new(schemas: HashMap<String, ConsumedSchema>) -> Self {
+        Self {
+            schemas,
+            default_namespace: DEFAULT_SCHEMA_NAMESPACE.to_string(),
+        }
+    }
+
+    /// Set the namespace reported by [`SchemaConsumer::default_namespace`]
+    pub fn with_default_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.default_namespace = namespace.into();
+        self
+    }
+
+    /// Add or replace a schema under its name
+    pub fn insert(&mut self, schema: ConsumedSchema) {
+        self.schemas.insert(schema.name.clone(), schema);
+    }
+
+    fn lookup(&self, name: &str, namespace: &str) -> SchemaResult<&ConsumedSchema> {
+        self.schemas
+            .get(name)
+            .filter(|schema| schema.namespace == namespace)
+            .ok_or_else(|| SchemaAdapterError::SchemaNotFound(format!("{}/{}", namespace, name)))
+    }
+}
+
+impl From<HashMap<String, ConsumedSchema>> for InMemorySchemaConsumer {
+    fn from(schemas: HashMap<String, ConsumedSchema>) -> Self {
+        Self::new(schemas)
+    }
+}
+
+#[async_trait]
+impl SchemaConsumer for InMemorySchemaConsumer {
+    async fn get_schema(&self, name: &str, namespace: &str) -> SchemaResult<ConsumedSchema> {
+        self.lookup(name, namespace).cloned()
+    }
+
+    async fn get_schema_version(
+        &self,
+        name: &str,
+        namespace: &str,
+        version: &str,
+    ) -> SchemaResult<ConsumedSchema> {
+        self.lookup(name, namespace)
+            .ok()
+            .filter(|schema| schema.version == version)
+            .cloned()
+            .ok_or_else(|| {
+                SchemaAdapterError::SchemaNotFound(format!("{}/{}@{}", namespace, name, version))
+            })
+    }
+
+    async fn validate_against_schema(
+        &self,
+        schema_name: &str,
+        namespace: &str,
+        data: &serde_json::Value,
+    ) -> SchemaResult<SchemaValidationResult> {
+        let schema = self.lookup(schema_name, namespace)?;
+        Ok(SchemaRegistryAdapter::validate_with_format(schema, data))
+    }
+
+    async fn list_schemas(&self, namespace: &str) -> SchemaResult<Vec<String>> {
+        let mut names: Vec<String> = self
+            .schemas
+            .values()
+            .filter(|schema| schema.namespace == namespace)
+            .map(|schema| schema.name.clone())
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    fn default_namespace(&self) -> &str {
+        &self.default_namespace
+    }
+}
+
+/// Check a value against the supported subset of JSON Schema keywords
+/// (`type`, `enum`, `required`, `properties`, `items`)
+fn check_json_schema(
+    definition: &serde_json::Value,
+    data: &serde_json::Value,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    use serde_json::Value;
+
+    let Value::Object(definition) = definition else {
+        return;
+    };
+
+    if let Some(expected) = definition.get("type") {
+        let allowed: Vec<&str> = match expected {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        let matches = allowed.iter().any(|t| match *t {
+            "object" => data.is_object(),
+            "array" => data.is_array(),
+            "string" => data.is_string(),
+            "number" => data.is_number(),
+            "integer" => data.is_i64() || data.is_u64(),
+            "boolean" => data.is_boolean(),
+            "null" => data.is_null(),
+            _ => true,
+        });
+        if !allowed.is_empty() && !matches {
+            errors.push(format!("{}: expected type {}", path, allowed.join(" | ")));
+            return;
+        }
+    }
+
+    if let Some(Value::Array(options)) = definition.get("enum") {
+        if !options.contains(data) {
+            errors.push(format!(
+                "{}: value is not one of the allowed enum values",
+                path
+            ));
+        }
+    }
+
+    if let Value::Object(object) = data {
+        if let Some(Value::Array(required)) = definition.get("required") {
+            for field in required.iter().filter_map(Value::as_str) {
+                if !object.contains_key(field) {
+                    errors.push(format!("{}: missing required property '{}'", path, field));
+                }
+            }
+        }
+        if let Some(Value::Object(properties)) = definition.get("properties") {
+            for (name, property) in properties {
+                if let Some(value) = object.get(name) {
+                    check_json_schema(property, value, &format!("{}.{}", path, name), errors);
+                }
+            }
+        }
+    }
+
+    if let (Value::Array(items), Some(item_schema)) = (data, definition.get("items")) {
+        for (index, item) in items.iter().enumerate() {
+            check_json_schema(item_schema, item, &format!("{}[{}]", path, index), errors);
+        }
+    }
+}
+
+/// Types accepted by a JSON Schema definition, if it constrains the type
+fn schema_types(definition: &serde_json::Value) -> Option<Vec<String>> {
+    match definition.get("type")? {
+        serde_json::Value::String(t) => Some(vec![t.clone()]),
+        serde_json::Value::Array(types) => Some(
+            types
+                .iter()
+                .filter_map(|t| t.as_str().map(str::to_string))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Required property names of a JSON Schema object definition
+fn required_fields(definition: &serde_json::Value) -> Vec<&str> {
+    definition
+        .get("required")
+        .and_then(|r| r.as_array())
+        .map(|r| r.iter().filter_map(|f| f.as_str()).collect())
+        .unwrap_or_default()
+}
+
+/// Compare two JSON Schema definitions, recording breaking and additive changes
+fn compare_json_schemas(
+    old: &serde_json::Value,
+    new: &serde_json::Value,
+    path: &str,
+    report: &mut CompatibilityReport,
+) {
+    let accepts = |types: &[String], t: &str| {
+        types
+            .iter()
+            .any(|n| n == t || (n == "number" && t == "integer"))
+    };
+
+    match (schema_types(old), schema_types(new)) {
+        (Some(old_types), Some(new_types)) => {
+            let narrowed: Vec<&str> = old_types
+                .iter()
+                .filter(|t| !accepts(&new_types, t))
+                .map(String::as_str)
+                .collect();
+            if !narrowed.is_empty() {
+                report.breaking_changes.push(format!(
+                    "{}: type narrowed, no longer accepts {}",
+                    path,
+                    narrowed.join(" | ")
+                ));
+            }
+            let widened: Vec<&str> = new_types
+                .iter()
+                .filter(|t| !accepts(&old_types, t))
+                .map(String::as_str)
+                .collect();
+            if !widened.is_empty() {
+                report.additive_changes.push(format!(
+                    "{}: type widened to accept {}",
+                    path,
+                    widened.join(" | ")
+                ));
+            }
+        }
+        (None, Some(new_types)) => report.breaking_changes.push(format!(
+            "{}: type narrowed to {}",
+            path,
+            new_types.join(" | ")
+        )),
+        (Some(_), None) => report
+            .additive_changes
+            .push(format!("{}: type constraint removed", path)),
+        (None, None) => {}
+    }
+
+    let old_required = required_fields(old);
+    let new_required = required_fields(new);
+    let empty = serde_json::Map::new();
+    let old_properties = old
+        .get("properties")
+        .and_then(|p| p.as_object())
+        .unwrap_or(&empty);
+    let new_properties = new
+        .get("properties")
+        .and_then(|p| p.as_object())
+        .unwrap_or(&empty);
+
+    for field in &old_required {
+        if !new_properties.contains_key(*field) && !new_required.contains(field) {
+            report
+                .breaking_changes
+                .push(format!("{}.{}: required field removed", path, field));
+        }
+    }
+
+    for field in &new_required {
+        if !old_required.contains(field) {
+            report
+                .breaking_changes
+                .push(format!("{}.{}: field became required", path, field));
+        }
+    }
+
+    for (name, new_property) in new_properties {
+        let field_path = format!("{}.{}", path, name);
+        match old_properties.get(name) {
+            Some(old_property) => {
+                compare_json_schemas(old_property, new_property, &field_path, report)
+            }
+            None if !new_required.contains(&name.as_str()) => report
+                .additive_changes
+                .push(format!("{}: optional field added", field_path)),
+            None => {}
+        }
+    }
+
+    if let (Some(old_items), Some(new_items)) = (old.get("items"), new.get("items")) {
+        compare_json_schemas(old_items, new_items, &format!("{}[]", path), report);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,6 +1192,47 @@ mod tests {
         assert!(schemas.contains(&"PipelineDescriptor".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_environment_namespaces() {
+        let staging = SchemaRegistryAdapter::new().with_environment(Environment::Staging);
+        assert_eq!(
+            SchemaConsumer::default_namespace(&staging),
+            "llm.registry.staging"
+        );
+        assert!(staging
+            .list_schemas("llm.registry.staging")
+            .await
+            .unwrap()
+            .contains(&MODEL_METADATA_SCHEMA.to_string()));
+        assert!(staging
+            .list_schemas("llm.registry")
+            .await
+            .unwrap()
+            .is_empty());
+
+        let production = SchemaRegistryAdapter::new().with_environment(Environment::Production);
+        assert_eq!(production.default_namespace, DEFAULT_SCHEMA_NAMESPACE);
+    }
+
+    #[tokio::test]
+    async fn test_explicit_namespace_overrides_environment() {
+        for adapter in [
+            SchemaRegistryAdapter::new()
+                .with_namespace("acme.models".to_string())
+                .with_environment(Environment::Staging),
+            SchemaRegistryAdapter::new()
+                .with_environment(Environment::Staging)
+                .with_namespace("acme.models".to_string()),
+        ] {
+            assert_eq!(adapter.default_namespace, "acme.models");
+            assert!(!adapter
+                .list_schemas("acme.models")
+                .await
+                .unwrap()
+                .is_empty());
+        }
+    }
+
     #[tokio::test]
     async fn test_cache_key_generation() {
         let key = SchemaRegistryAdapter::cache_key("Test", "ns", None);
@@ -345,4 +1241,439 @@ mod tests {
         let versioned_key = SchemaRegistryAdapter::cache_key("Test", "ns", Some("1.0.0"));
         assert_eq!(versioned_key, "ns.Test@1.0.0");
     }
+
+    async fn adapter_with_schema(
+        format: SerializationFormat,
+        content: &str,
+    ) -> SchemaRegistryAdapter {
+        let adapter = SchemaRegistryAdapter::new();
+        adapter
+            .insert_cached(
+                SchemaRegistryAdapter::cache_key("ModelMetadata", "llm.registry", None),
+                ConsumedSchema {
+                    id: "model-metadata-v1".to_string(),
+                    name: "ModelMetadata".to_string(),
+                    namespace: "llm.registry".to_string(),
+                    version: "1.0.0".to_string(),
+                    format,
+                    content: content.to_string(),
+                    content_hash: String::new(),
+                    is_active: true,
+                },
+            )
+            .await;
+        adapter
+    }
+
+    #[cfg(feature = "avro")]
+    const AVRO_RECORD: &str = r#"{
+        "type": "record",
+        "name": "ModelMetadata",
+        "fields": [
+            {"name": "name", "type": "string"},
+            {"name": "parameters", "type": "long"}
+        ]
+    }"#;
+
+    #[cfg(feature = "avro")]
+    #[tokio::test]
+    async fn test_avro_record_validation_passes() {
+        let adapter = adapter_with_schema(SerializationFormat::Avro, AVRO_RECORD).await;
+        let result = adapter
+            .validate_model_metadata(&serde_json::json!({"name": "gpt", "parameters": 7000}))
+            .await
+            .unwrap();
+
+        assert!(result.valid, "unexpected errors: {:?}", result.errors);
+        assert_eq!(result.schema_id, "model-metadata-v1");
+    }
+
+    #[cfg(feature = "avro")]
+    #[tokio::test]
+    async fn test_avro_record_type_mismatch_fails() {
+        let adapter = adapter_with_schema(SerializationFormat::Avro, AVRO_RECORD).await;
+        let result = adapter
+            .validate_model_metadata(&serde_json::json!({"name": "gpt", "parameters": "many"}))
+            .await
+            .unwrap();
+
+        assert!(!result.valid);
+        assert_eq!(result.errors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_yaml_schema_validation() {
+        let yaml = "type: object\nrequired: [name]\nproperties:\n  name:\n    type: string\n";
+        let adapter = adapter_with_schema(SerializationFormat::Yaml, yaml).await;
+
+        let ok = adapter
+            .validate_model_metadata(&serde_json::json!({"name": "gpt"}))
+            .await
+            .unwrap();
+        assert!(ok.valid);
+
+        let bad = adapter
+            .validate_model_metadata(&serde_json::json!({"name": 42}))
+            .await
+            .unwrap();
+        assert!(!bad.valid);
+        assert!(bad.errors[0].contains("$.name"));
+    }
+
+    fn json_schema(version: &str, content: serde_json::Value) -> ConsumedSchema {
+        ConsumedSchema {
+            id: format!("model-metadata-{}", version),
+            name: "ModelMetadata".to_string(),
+            namespace: "llm.registry".to_string(),
+            version: version.to_string(),
+            format: SerializationFormat::Json,
+            content: content.to_string(),
+            content_hash: String::new(),
+            is_active: true,
+        }
+    }
+
+    fn in_memory_consumer() -> InMemorySchemaConsumer {
+        let schema = json_schema(
+            "1.0.0",
+            serde_json::json!({
+                "type": "object",
+                "required": ["name", "version"],
+                "properties": {"name": {"type": "string"}, "version": {"type": "string"}}
+            }),
+        );
+        InMemorySchemaConsumer::new(HashMap::from([(schema.name.clone(), schema)]))
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_consumer_validates_documents() {
+        let consumer = in_memory_consumer();
+
+        let good = consumer
+            .validate_against_schema(
+                MODEL_METADATA_SCHEMA,
+                DEFAULT_SCHEMA_NAMESPACE,
+                &serde_json::json!({"name": "gpt", "version": "1.0.0"}),
+            )
+            .await
+            .unwrap();
+        assert!(good.valid);
+        assert_eq!(good.schema_id, "model-metadata-1.0.0");
+
+        let bad = consumer
+            .validate_against_schema(
+                MODEL_METADATA_SCHEMA,
+                DEFAULT_SCHEMA_NAMESPACE,
+                &serde_json::json!({"name": 42}),
+            )
+            .await
+            .unwrap();
+        assert!(!bad.valid);
+        assert!(bad.errors.iter().any(|e| e.contains("$.name")));
+        assert!(bad.errors.iter().any(|e| e.contains("version")));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_consumer_lookups() {
+        let consumer = in_memory_consumer();
+
+        assert_eq!(
+            consumer
+                .list_schemas(DEFAULT_SCHEMA_NAMESPACE)
+                .await
+                .unwrap(),
+            [MODEL_METADATA_SCHEMA]
+        );
+        assert!(consumer.list_schemas("other").await.unwrap().is_empty());
+        assert!(consumer
+            .get_schema_version(MODEL_METADATA_SCHEMA, DEFAULT_SCHEMA_NAMESPACE, "1.0.0")
+            .await
+            .is_ok());
+        assert!(matches!(
+            consumer
+                .get_schema_version(MODEL_METADATA_SCHEMA, DEFAULT_SCHEMA_NAMESPACE, "2.0.0")
+                .await,
+            Err(SchemaAdapterError::SchemaNotFound(_))
+        ));
+        assert!(matches!(
+            consumer.get_schema(MODEL_METADATA_SCHEMA, "other").await,
+            Err(SchemaAdapterError::SchemaNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_compatibility_optional_field_added() {
+        let adapter = SchemaRegistryAdapter::new();
+        let old = json_schema(
+            "1.0.0",
+            serde_json::json!({
+                "type": "object",
+                "required": ["name"],
+                "properties": {"name": {"type": "string"}}
+            }),
+        );
+        let new = json_schema(
+            "1.1.0",
+            serde_json::json!({
+                "type": "object",
+                "required": ["name"],
+                "properties": {"name": {"type": "string"}, "license": {"type": "string"}}
+            }),
+        );
+
+        let report = adapter.check_compatibility(&old, &new).unwrap();
+        assert!(report.compatible);
+        assert!(report.breaking_changes.is_empty());
+        assert_eq!(
+            report.additive_changes,
+            vec!["$.license: optional field added"]
+        );
+    }
+
+    #[test]
+    fn test_compatibility_required_field_removed() {
+        let adapter = SchemaRegistryAdapter::new();
+        let old = json_schema(
+            "1.0.0",
+            serde_json::json!({
+                "type": "object",
+                "required": ["name", "version"],
+                "properties": {"name": {"type": "string"}, "version": {"type": "string"}}
+            }),
+        );
+        let new = json_schema(
+            "2.0.0",
+            serde_json::json!({
+                "type": "object",
+                "required": ["name"],
+                "properties": {"name": {"type": "string"}}
+            }),
+        );
+
+        let report = adapter.check_compatibility(&old, &new).unwrap();
+        assert!(!report.compatible);
+        assert_eq!(
+            report.breaking_changes,
+            vec!["$.version: required field removed"]
+        );
+    }
+
+    #[test]
+    fn test_compatibility_type_narrowed_and_format_mismatch() {
+        let adapter = SchemaRegistryAdapter::new();
+        let old = json_schema(
+            "1.0.0",
+            serde_json::json!({"properties": {"size": {"type": "number"}}}),
+        );
+        let new = json_schema(
+            "2.0.0",
+            serde_json::json!({"properties": {"size": {"type": "integer"}}}),
+        );
+
+        let report = adapter.check_compatibility(&old, &new).unwrap();
+        assert!(!report.compatible);
+        assert!(report.breaking_changes[0].starts_with("$.size: type narrowed"));
+
+        let mut avro = new.clone();
+        avro.format = SerializationFormat::Avro;
+        let report = adapter.check_compatibility(&old, &avro).unwrap();
+        assert!(!report.compatible);
+        assert!(report.breaking_changes[0].contains("format changed"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_escapes_path_segments() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/schemas/llm.registry/..%2Fadmin%3Fx%3D1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json_schema("1.0.0", serde_json::json!({"type": "object"}))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let adapter = SchemaRegistryAdapter::with_endpoint(server.uri());
+        adapter
+            .get_schema("../admin?x=1", "llm.registry")
+            .await
+            .unwrap();
+        assert!(matches!(
+            adapter.get_schema("..", "llm.registry").await,
+            Err(SchemaAdapterError::SchemaNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_warm_cache_against_upstream() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for name in ["ModelMetadata", "PipelineDescriptor"] {
+            Mock::given(method("GET"))
+                .and(path(format!("/schemas/llm.registry/{}", name)))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json_schema("1.0.0", serde_json::json!({"type": "object"}))),
+                )
+                .mount(&server)
+                .await;
+        }
+
+        let adapter = SchemaRegistryAdapter::with_endpoint(server.uri());
+        assert_eq!(adapter.cache_len().await, 0);
+
+        let loaded = adapter
+            .warm_cache(&[
+                ("ModelMetadata", "llm.registry"),
+                ("PipelineDescriptor", "llm.registry"),
+                ("Missing", "llm.registry"),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(loaded, 2);
+        assert_eq!(adapter.cache_len().await, 2);
+        assert!(adapter.get_model_metadata_schema().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_remote_fetch_propagates_execution_context() {
+        use llm_registry_core::execution::{
+            ExecutionContext, ExecutionId, SpanCollector, SpanId, HEADER_EXECUTION_ID,
+            HEADER_PARENT_SPAN_ID,
+        };
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/schemas/llm.registry/ModelMetadata"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json_schema("1.0.0", serde_json::json!({"type": "object"}))),
+            )
+            .mount(&server)
+            .await;
+
+        let collector = SpanCollector::new(&ExecutionContext {
+            execution_id: ExecutionId::new("exec-outbound"),
+            parent_span_id: SpanId::new(),
+            sampled: true,
+        });
+        let span_id = collector.begin_agent_span("ValidationService");
+
+        let adapter = SchemaRegistryAdapter::with_endpoint(server.uri());
+        collector
+            .clone()
+            .scope(adapter.get_model_metadata_schema())
+            .await
+            .unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let headers = &requests[0].headers;
+        assert_eq!(headers[HEADER_EXECUTION_ID], "exec-outbound");
+        assert_eq!(headers[HEADER_PARENT_SPAN_ID], span_id.to_string().as_str());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_misses_share_one_fetch() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/schemas/llm.registry/ModelMetadata"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json_schema("1.0.0", serde_json::json!({"type": "object"})))
+                    .set_delay(Duration::from_millis(50)),
+            )
+            .mount(&server)
+            .await;
+
+        let adapter = Arc::new(
+            SchemaRegistryAdapter::with_endpoint(server.uri())
+                .with_cache_ttl(Some(Duration::from_millis(20)))
+                .with_cache_ttl_jitter(0.0),
+        );
+        adapter.get_model_metadata_schema().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        let calls: Vec<_> = (0..32)
+            .map(|_| {
+                let adapter = Arc::clone(&adapter);
+                tokio::spawn(async move { adapter.get_model_metadata_schema().await })
+            })
+            .collect();
+        for call in calls {
+            call.await.unwrap().unwrap();
+        }
+
+        // One request to fill the cache, one for the expired entry
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+        assert!(adapter.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_entry_ttl_jitter_stays_in_bounds() {
+        let adapter = SchemaRegistryAdapter::new()
+            .with_cache_ttl(Some(Duration::from_secs(100)))
+            .with_cache_ttl_jitter(0.1);
+        let ttls: Vec<Duration> = (0..50).map(|_| adapter.entry_ttl().unwrap()).collect();
+
+        assert!(ttls
+            .iter()
+            .all(|ttl| (Duration::from_secs(90)..=Duration::from_secs(110)).contains(ttl)));
+        assert!(ttls.iter().any(|ttl| *ttl != ttls[0]));
+        assert_eq!(
+            adapter.with_cache_ttl_jitter(0.0).entry_ttl(),
+            Some(Duration::from_secs(100))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fallback_modes_when_unavailable() {
+        let data = serde_json::json!({"name": "model"});
+
+        let permissive = SchemaRegistryAdapter::new()
+            .validate_against_schema("ModelMetadata", "llm.registry", &data)
+            .await
+            .unwrap();
+        assert!(permissive.valid);
+        assert!(!permissive.unvalidated);
+        assert_eq!(permissive.fallback, Some(SchemaFallbackMode::Permissive));
+
+        let queued = SchemaRegistryAdapter::new()
+            .with_fallback(SchemaFallbackMode::Queue)
+            .validate_against_schema("ModelMetadata", "llm.registry", &data)
+            .await
+            .unwrap();
+        assert!(queued.valid);
+        assert!(queued.unvalidated);
+
+        let rejected = SchemaRegistryAdapter::new()
+            .with_fallback(SchemaFallbackMode::for_environment(Environment::Production))
+            .validate_against_schema("ModelMetadata", "llm.registry", &data)
+            .await;
+        assert!(matches!(rejected, Err(SchemaAdapterError::Unavailable(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_format_is_not_a_pass() {
+        let adapter =
+            adapter_with_schema(SerializationFormat::Protobuf, "syntax = \"proto3\";").await;
+        let result = adapter
+            .validate_model_metadata(&serde_json::json!({}))
+            .await
+            .unwrap();
+
+        assert!(!result.valid);
+        assert!(result.warnings[0].contains("not supported"));
+    }
 }
@@ -0,0 +1,484 @@
+//! Webhook fan-out for governance events
+//!
+//! A [`WebhookSink`] holds registered webhook URLs, each with its own secret
+//! and [`EventFilter`]. Events queued on the sink are POSTed one at a time to
+//! every webhook whose filter permits them, with the JSON body signed as
+//! `sha256=<hex HMAC-SHA256>` in the [`SIGNATURE_HEADER`] header. Delivery
+//! keeps its own queue and retry policy, so a failing webhook never affects
+//! the observatory flush and vice versa. Flushes run one at a time, so each
+//! webhook receives its events in the order they were queued.
+
+use super::observatory::{EventFilter, GovernanceEvent};
+use futures::future::join_all;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Header carrying the body signature
+pub const SIGNATURE_HEADER: &str = "x-registry-signature";
+
+/// Header carrying the event type
+pub const EVENT_TYPE_HEADER: &str = "x-registry-event";
+
+/// Default attempts made to deliver an event to one webhook
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Default time one delivery attempt may take
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum number of events waiting for delivery before new events are dropped
+const MAX_PENDING_EVENTS: usize = 10_000;
+
+/// Sign `body` with `secret`, formatted for [`SIGNATURE_HEADER`]
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// A registered webhook
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    /// URL events are POSTed to
+    pub url: String,
+    /// Event types delivered to this webhook
+    pub filter: EventFilter,
+    secret: String,
+}
+
+impl Webhook {
+    /// Webhook receiving every event, signed with `secret`
+    pub fn new(url: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            filter: EventFilter::default(),
+            secret: secret.into(),
+        }
+    }
+
+    /// Only deliver events that pass `filter`
+    pub fn with_filter(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+/// Delivery counters for a [`WebhookSink`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WebhookStats {
+    /// Events delivered successfully, counted per webhook
+    pub delivered: u64,
+    /// Deliveries that failed after all attempts
+    pub failed: u64,
+    /// Events dropped because the pending queue was full
+    pub dropped_events: u64,
+}
+
+#[derive(Default)]
+struct WebhookCounters {
+    delivered: AtomicU64,
+    failed: AtomicU64,
+    dropped_events: AtomicU64,
+}
+
+/// Signed, filtered webhook delivery of governance events
+pub struct WebhookSink {
+    client: reqwest::Client,
+    webhooks: Mutex<Vec<Webhook>>,
+    pending: Mutex<Vec<GovernanceEvent>>,
+    max_attempts: u32,
+    retry_backoff: Duration,
+    request_timeout: Duration,
+    counters: WebhookCounters,
+    /// Held for the whole of a flush, so flushes never overlap
+    delivering: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Default for WebhookSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebhookSink {
+    /// Create a sink with no webhooks
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            webhooks: Mutex::new(Vec::new()),
+            pending: Mutex::new(Vec::new()),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_backoff: Duration::from_millis(500),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            counters: WebhookCounters::default(),
+            delivering: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Set the attempts made per delivery (at least 1)
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Set the delay between attempts (multiplied by the attempt number)
+    pub fn with_retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Set how long one delivery attempt may take before it is retried
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Register a webhook
+    pub fn register(&self, webhook: Webhook) {
+        self.webhooks.lock().unwrap().push(webhook);
+    }
+
+    /// Registered webhooks
+    pub fn webhooks(&self) -> Vec<Webhook> {
+        self.webhooks.lock().unwrap().clone()
+    }
+
+    /// Snapshot of delivery counters
+    pub fn stats(&self) -> WebhookStats {
+        WebhookStats {
+            delivered: self.counters.delivered.load(Ordering::Relaxed),
+            failed: self.counters.failed.load(Ordering::Relaxed),
+            dropped_events: self.counters.dropped_events.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Queue an event for the next [`Self::flush`]
+    ///
+    /// Events no registered webhook accepts are not queued.
+    pub fn enqueue(&self, event: &GovernanceEvent) {
+        let wanted = self
+            .webhooks
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|webhook| webhook.filter.permits(event));
+        if !wanted {
+            return;
+        }
+
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() >= MAX_PENDING_EVENTS {
+            self.counters.dropped_events.fetch_add(1, Ordering::Relaxed);
+            warn!("Webhook queue full - event dropped");
+            return;
+        }
+        pending.push(event.clone());
+    }
+
+    /// Deliver all queued events, after any flush already in progress
+    pub async fn flush(&self) {
+        let _delivering = self.delivering.lock().await;
+        self.deliver_pending().await;
+    }
+
+    /// Flush in a background task, unless a flush is already in progress
+    ///
+    /// Events queued meanwhile wait for the next flush, so a hung webhook
+    /// holds at most one task.
+    pub fn spawn_flush(self: &Arc<Self>) {
+        let Ok(delivering) = Arc::clone(&self.delivering).try_lock_owned() else {
+            debug!("Webhook flush already in progress");
+            return;
+        };
+        let sink = Arc::clone(self);
+        tokio::spawn(async move {
+            sink.deliver_pending().await;
+            drop(delivering);
+        });
+    }
+
+    /// Deliver queued events; the caller holds `delivering`
+    async fn deliver_pending(&self) {
+        let events = std::mem::take(&mut *self.pending.lock().unwrap());
+        if !events.is_empty() {
+            self.deliver(&events).await;
+        }
+    }
+
+    /// Deliver `events` to every webhook whose filter permits them
+    ///
+    /// Webhooks are served concurrently; each receives its events in order.
+    pub async fn deliver(&self, events: &[GovernanceEvent]) {
+        let webhooks = self.webhooks();
+        join_all(webhooks.iter().map(|webhook| async move {
+            for event in events.iter().filter(|event| webhook.filter.permits(event)) {
+                match self.send_with_retry(webhook, event).await {
+                    Ok(()) => {
+                        self.counters.delivered.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(error) => {
+                        self.counters.failed.fetch_add(1, Ordering::Relaxed);
+                        warn!(
+                            url = %webhook.url,
+                            event_type = event.event_type(),
+                            error = %error,
+                            "Webhook delivery failed permanently"
+                        );
+                    }
+                }
+            }
+        }))
+        .await;
+    }
+
+    /// POST one signed event, retrying connection errors and 5xx responses
+    async fn send_with_retry(
+        &self,
+        webhook: &Webhook,
+        event: &GovernanceEvent,
+    ) -> Result<(), String> {
+        let body = serde_json::to_vec(event).map_err(|e| e.to_string())?;
+        let signature = sign(webhook.secret.as_bytes(), &body);
+        let mut attempt = 1;
+
+        loop {
+            let request = self
+                .client
+                .post(&webhook.url)
+                .timeout(self.request_timeout)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(SIGNATURE_HEADER, &signature)
+                .header(EVENT_TYPE_HEADER, event.event_type())
+                .body(body.clone());
+
+            let (error, retryable) = match request.send().await {
+                Ok(response) if response.status().is_success() => return Ok(()),
+                Ok(response) => (
+                    format!("POST {} returned {}", webhook.url, response.status()),
+                    response.status().is_server_error(),
+                ),
+                Err(e) => (format!("POST {} failed: {}", webhook.url, e), true),
+            };
+
+            debug!(attempt, error = %error, "Webhook delivery attempt failed");
+            if !retryable || attempt >= self.max_attempts {
+                return Err(error);
+            }
+
+            tokio::time::sleep(self.retry_backoff * attempt).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn registered_event() -> GovernanceEvent {
+        GovernanceEvent::AssetRegistered {
+            asset_id: "asset-1".to_string(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+            registered_by: "tester".to_string(),
+        }
+    }
+
+    fn deleted_event() -> GovernanceEvent {
+        GovernanceEvent::AssetDeleted {
+            asset_id: "asset-1".to_string(),
+            deleted_by: "tester".to_string(),
+            permanent: false,
+        }
+    }
+
+    #[test]
+    fn test_sign_matches_rfc4231_vector() {
+        assert_eq!(
+            sign(b"Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delivery_is_signed_with_webhook_secret() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let sink = WebhookSink::new();
+        sink.register(Webhook::new(format!("{}/hook", server.uri()), "s3cret"));
+        sink.enqueue(&registered_event());
+        sink.flush().await;
+
+        let requests = server.received_requests().await.unwrap();
+        let request = &requests[0];
+        assert_eq!(
+            request.headers[SIGNATURE_HEADER].to_str().unwrap(),
+            sign(b"s3cret", &request.body)
+        );
+        assert_eq!(request.headers[EVENT_TYPE_HEADER], "asset_registered");
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(body["type"], "asset_registered");
+        assert_eq!(sink.stats().delivered, 1);
+    }
+
+    #[tokio::test]
+    async fn test_filters_apply_per_webhook() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/all"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/deletions"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let sink = WebhookSink::new();
+        sink.register(Webhook::new(format!("{}/all", server.uri()), "a"));
+        sink.register(
+            Webhook::new(format!("{}/deletions", server.uri()), "b")
+                .with_filter(EventFilter::allow(&["asset_deleted"])),
+        );
+        sink.enqueue(&registered_event());
+        sink.enqueue(&deleted_event());
+        sink.flush().await;
+
+        let requests = server.received_requests().await.unwrap();
+        let deletion = requests
+            .iter()
+            .find(|r| r.url.path() == "/deletions")
+            .unwrap();
+        assert_eq!(deletion.headers[EVENT_TYPE_HEADER], "asset_deleted");
+        assert_eq!(
+            deletion.headers[SIGNATURE_HEADER].to_str().unwrap(),
+            sign(b"b", &deletion.body)
+        );
+        assert_eq!(sink.stats().delivered, 3);
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_are_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let sink = WebhookSink::new().with_retry_backoff(Duration::from_millis(1));
+        sink.register(Webhook::new(server.uri(), "s3cret"));
+        sink.deliver(&[registered_event()]).await;
+
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+        assert_eq!(
+            sink.stats(),
+            WebhookStats {
+                delivered: 1,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hung_webhook_times_out() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_secs(60)))
+            .mount(&server)
+            .await;
+
+        let sink = WebhookSink::new()
+            .with_max_attempts(1)
+            .with_request_timeout(Duration::from_millis(50));
+        sink.register(Webhook::new(server.uri(), "s3cret"));
+        sink.enqueue(&registered_event());
+        tokio::time::timeout(Duration::from_secs(5), sink.flush())
+            .await
+            .unwrap();
+
+        assert_eq!(sink.stats().failed, 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_flushes_keep_event_order() {
+        let server = MockServer::start().await;
+        // The first event needs a retry, which a concurrent flush must not overtake
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let sink = Arc::new(WebhookSink::new().with_retry_backoff(Duration::from_millis(100)));
+        sink.register(Webhook::new(server.uri(), "s3cret"));
+        sink.enqueue(&registered_event());
+        tokio::join!(sink.flush(), async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            sink.enqueue(&deleted_event());
+            sink.flush().await;
+        });
+
+        let events: Vec<_> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|request| {
+                request.headers[EVENT_TYPE_HEADER]
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            events,
+            ["asset_registered", "asset_registered", "asset_deleted"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawn_flush_skips_while_flushing() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(100)))
+            .mount(&server)
+            .await;
+
+        let sink = Arc::new(WebhookSink::new());
+        sink.register(Webhook::new(server.uri(), "s3cret"));
+        sink.enqueue(&registered_event());
+        sink.spawn_flush();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        // Left queued for the next flush rather than delivered by a second task
+        sink.enqueue(&deleted_event());
+        sink.spawn_flush();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+
+        sink.flush().await;
+        assert_eq!(sink.stats().delivered, 2);
+    }
+}
@@ -0,0 +1,205 @@
+//! Stored asset content
+//!
+//! The registry records where an asset's bytes live but does not hold them
+//! itself. A [`ContentStore`] reads those bytes back from the asset's storage
+//! location so integrity can be re-verified on demand. Content is streamed,
+//! so verifying a large model never holds it in memory.
+
+use async_trait::async_trait;
+use llm_registry_core::{StorageBackend, StorageLocation};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use std::pin::Pin;
+use std::sync::RwLock;
+use tokio::io::AsyncRead;
+
+use crate::error::{ServiceError, ServiceResult};
+
+/// Stream of stored asset content
+pub type ContentReader = Pin<Box<dyn AsyncRead + Send>>;
+
+/// Trait for reading asset content from storage
+#[async_trait]
+pub trait ContentStore: Send + Sync {
+    /// Open the content stored at `location`, or `None` if nothing is stored there
+    async fn open(&self, location: &StorageLocation) -> ServiceResult<Option<ContentReader>>;
+}
+
+/// Process-local [`ContentStore`] keyed by storage URI
+#[derive(Default)]
+pub struct InMemoryContentStore {
+    contents: RwLock<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryContentStore {
+    /// Create an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `content` at `location`, replacing anything already there
+    pub fn insert(&self, location: &StorageLocation, content: impl Into<Vec<u8>>) {
+        self.contents
+            .write()
+            .unwrap()
+            .insert(location.get_uri(), content.into());
+    }
+}
+
+#[async_trait]
+impl ContentStore for InMemoryContentStore {
+    async fn open(&self, location: &StorageLocation) -> ServiceResult<Option<ContentReader>> {
+        let content = self
+            .contents
+            .read()
+            .unwrap()
+            .get(&location.get_uri())
+            .cloned();
+        Ok(content.map(|bytes| Box::pin(std::io::Cursor::new(bytes)) as ContentReader))
+    }
+}
+
+/// [`ContentStore`] reading `FileSystem` storage locations from local disk
+///
+/// Only files under `root` are read; a location whose base path and path
+/// resolve outside it is refused, so registering an asset cannot be used to
+/// probe arbitrary files on the server.
+pub struct FilesystemContentStore {
+    root: PathBuf,
+}
+
+impl FilesystemContentStore {
+    /// Serve files under `root`
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The file a location refers to, if it lies under the root
+    async fn resolve(&self, base_path: &str, path: &str) -> ServiceResult<Option<PathBuf>> {
+        let root = tokio::fs::canonicalize(&self.root).await.map_err(|e| {
+            ServiceError::Unavailable(format!(
+                "Content root {} is not accessible: {}",
+                self.root.display(),
+                e
+            ))
+        })?;
+
+        // Refuse escapes before touching the file system, so whether a file
+        // exists outside the root is never observable
+        let file = Path::new(base_path).join(path.trim_start_matches('/'));
+        let escapes = file.components().any(|c| c == Component::ParentDir)
+            || (file.is_absolute() && !file.starts_with(&self.root) && !file.starts_with(&root));
+        if escapes {
+            return Err(outside_root(base_path, path));
+        }
+
+        let file = match tokio::fs::canonicalize(root.join(file)).await {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(ServiceError::Internal(format!(
+                    "Failed to resolve content: {}",
+                    e
+                )))
+            }
+        };
+        // A symlink under the root may still point outside it
+        if !file.starts_with(&root) {
+            return Err(outside_root(base_path, path));
+        }
+        Ok(Some(file))
+    }
+}
+
+fn outside_root(base_path: &str, path: &str) -> ServiceError {
+    ServiceError::NotPermitted(format!(
+        "Storage location {}/{} is outside the content root",
+        base_path.trim_end_matches('/'),
+        path.trim_start_matches('/')
+    ))
+}
+
+#[async_trait]
+impl ContentStore for FilesystemContentStore {
+    async fn open(&self, location: &StorageLocation) -> ServiceResult<Option<ContentReader>> {
+        let StorageBackend::FileSystem { ref base_path } = location.backend else {
+            return Err(ServiceError::Unavailable(format!(
+                "No content store is configured for {} storage",
+                location.backend
+            )));
+        };
+
+        let Some(file) = self.resolve(base_path, &location.path).await? else {
+            return Ok(None);
+        };
+        match tokio::fs::File::open(&file).await {
+            Ok(file) => Ok(Some(Box::pin(file))),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(ServiceError::Internal(format!(
+                "Failed to open content: {}",
+                e
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    fn location(base_path: &str, path: &str) -> StorageLocation {
+        StorageLocation::new(
+            StorageBackend::FileSystem {
+                base_path: base_path.to_string(),
+            },
+            path.to_string(),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_filesystem_store_reads_under_root_only() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("models")).unwrap();
+        std::fs::write(root.path().join("models/model.bin"), b"weights").unwrap();
+        let outside = tempfile::NamedTempFile::new().unwrap();
+        let store = FilesystemContentStore::new(root.path());
+
+        let mut content = Vec::new();
+        store
+            .open(&location("models", "model.bin"))
+            .await
+            .unwrap()
+            .unwrap()
+            .read_to_end(&mut content)
+            .await
+            .unwrap();
+        assert_eq!(content, b"weights");
+
+        assert!(store
+            .open(&location("models", "missing.bin"))
+            .await
+            .unwrap()
+            .is_none());
+        let escape = outside.path().to_str().unwrap();
+        assert!(matches!(
+            store.open(&location("/", escape)).await,
+            Err(ServiceError::NotPermitted(_))
+        ));
+        assert!(matches!(
+            store
+                .open(&location("models", "../../../../etc/hostname"))
+                .await,
+            Err(ServiceError::NotPermitted(_))
+        ));
+        assert!(matches!(
+            store
+                .open(&location("models", "../../../../no/such/file"))
+                .await,
+            Err(ServiceError::NotPermitted(_))
+        ));
+    }
+}
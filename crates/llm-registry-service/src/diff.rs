@@ -0,0 +1,258 @@
+//! Structured diffs between JSON documents and assets
+//!
+//! [`diff_json`] walks two JSON values and reports added, removed, and
+//! changed leaves by dotted path. [`compare_assets`] builds on it to compare
+//! two versions of an asset. [`merge_patch`] applies an RFC 7386 JSON Merge
+//! Patch to a document.
+
+use llm_registry_core::{Asset, AssetId, AssetReference};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A value that differs between two documents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValueChange {
+    /// Value in the old document
+    pub from: Value,
+    /// Value in the new document
+    pub to: Value,
+}
+
+/// Differences between two JSON documents, keyed by dotted path
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JsonDiff {
+    /// Paths only present in the new document
+    pub added: BTreeMap<String, Value>,
+    /// Paths only present in the old document
+    pub removed: BTreeMap<String, Value>,
+    /// Paths present in both with different values
+    pub changed: BTreeMap<String, ValueChange>,
+}
+
+impl JsonDiff {
+    /// Whether the documents are equal
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Diff two JSON documents
+///
+/// Objects are compared key by key; any other value, including arrays, is
+/// compared as a whole. A top-level change of non-objects is reported
+/// under the empty path.
+pub fn diff_json(old: &Value, new: &Value) -> JsonDiff {
+    let mut diff = JsonDiff::default();
+    diff_at("", old, new, &mut diff);
+    diff
+}
+
+fn diff_at(path: &str, old: &Value, new: &Value, diff: &mut JsonDiff) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match (old.get(key), new.get(key)) {
+                    (Some(old), Some(new)) => diff_at(&child, old, new, diff),
+                    (None, Some(new)) => {
+                        diff.added.insert(child, new.clone());
+                    }
+                    (Some(old), None) => {
+                        diff.removed.insert(child, old.clone());
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+        (old, new) if old != new => {
+            diff.changed.insert(
+                path.to_string(),
+                ValueChange {
+                    from: old.clone(),
+                    to: new.clone(),
+                },
+            );
+        }
+        _ => {}
+    }
+}
+
+/// Tags added and removed between two assets
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TagDiff {
+    /// Tags only on the new asset
+    pub added: Vec<String>,
+    /// Tags only on the old asset
+    pub removed: Vec<String>,
+}
+
+/// Differences between two assets
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssetComparison {
+    /// The asset compared from
+    pub a: AssetId,
+    /// The asset compared to
+    pub b: AssetId,
+    /// Metadata differences, excluding tags
+    pub metadata: JsonDiff,
+    /// Tag differences
+    pub tags: TagDiff,
+    /// Dependency differences, keyed by asset ID or name
+    pub dependencies: JsonDiff,
+}
+
+/// Compare asset `a` with asset `b`
+pub fn compare_assets(a: &Asset, b: &Asset) -> AssetComparison {
+    let metadata = |asset: &Asset| {
+        let mut value = serde_json::to_value(&asset.metadata).unwrap_or_default();
+        if let Value::Object(ref mut object) = value {
+            object.remove("tags");
+        }
+        value
+    };
+
+    let tags_a: BTreeSet<&String> = a.metadata.tags.iter().collect();
+    let tags_b: BTreeSet<&String> = b.metadata.tags.iter().collect();
+
+    AssetComparison {
+        a: a.id,
+        b: b.id,
+        metadata: diff_json(&metadata(a), &metadata(b)),
+        tags: TagDiff {
+            added: tags_b.difference(&tags_a).map(|t| t.to_string()).collect(),
+            removed: tags_a.difference(&tags_b).map(|t| t.to_string()).collect(),
+        },
+        dependencies: diff_json(&dependency_map(a), &dependency_map(b)),
+    }
+}
+
+/// Apply an RFC 7386 JSON Merge Patch to `target`
+///
+/// Object members of `patch` are merged recursively and `null` members
+/// delete the key; any other patch replaces the target wholesale.
+pub fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(members) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(object) = target else {
+        unreachable!("target was just made an object");
+    };
+    for (key, value) in members {
+        if value.is_null() {
+            object.remove(key);
+        } else {
+            merge_patch(object.entry(key.as_str()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// Dependencies as an object keyed by referenced ID or name
+///
+/// Name references map to their version, so a version bump shows as a change.
+fn dependency_map(asset: &Asset) -> Value {
+    let map: Map<String, Value> = asset
+        .dependencies
+        .iter()
+        .map(|dependency| match dependency {
+            AssetReference::ById { id } => (id.to_string(), Value::Bool(true)),
+            AssetReference::ByNameVersion { name, version } => {
+                (name.clone(), Value::String(version.clone()))
+            }
+        })
+        .collect();
+    Value::Object(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_json_reports_nested_paths() {
+        let old = json!({"name": "m", "limits": {"cpu": 1, "gpu": 0}, "gone": true});
+        let new = json!({"name": "m", "limits": {"cpu": 2, "memory": "4Gi"}, "new": [1]});
+
+        let diff = diff_json(&old, &new);
+        assert_eq!(
+            diff.changed["limits.cpu"],
+            ValueChange {
+                from: json!(1),
+                to: json!(2)
+            }
+        );
+        assert_eq!(
+            diff.added.keys().collect::<Vec<_>>(),
+            ["limits.memory", "new"]
+        );
+        assert_eq!(
+            diff.removed.keys().collect::<Vec<_>>(),
+            ["gone", "limits.gpu"]
+        );
+        assert!(diff_json(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_json_non_objects() {
+        let diff = diff_json(&json!([1, 2]), &json!([2, 1]));
+        assert_eq!(diff.changed.keys().collect::<Vec<_>>(), [""]);
+    }
+
+    #[test]
+    fn test_merge_patch_rfc7386_examples() {
+        for (target, patch, expected) in [
+            (json!({"a": "b"}), json!({"a": "c"}), json!({"a": "c"})),
+            (
+                json!({"a": "b"}),
+                json!({"b": "c"}),
+                json!({"a": "b", "b": "c"}),
+            ),
+            (
+                json!({"a": "b", "b": "c"}),
+                json!({"a": null}),
+                json!({"b": "c"}),
+            ),
+            (json!({"a": ["b"]}), json!({"a": "c"}), json!({"a": "c"})),
+            (
+                json!({"a": {"b": "c"}}),
+                json!({"a": {"b": "d", "c": null}}),
+                json!({"a": {"b": "d"}}),
+            ),
+            (
+                json!({"a": [{"b": "c"}]}),
+                json!({"a": [1]}),
+                json!({"a": [1]}),
+            ),
+            (
+                json!({"e": null}),
+                json!({"a": 1}),
+                json!({"e": null, "a": 1}),
+            ),
+            (
+                json!([1, 2]),
+                json!({"a": "b", "c": null}),
+                json!({"a": "b"}),
+            ),
+            (
+                json!({}),
+                json!({"a": {"bb": {"ccc": null}}}),
+                json!({"a": {"bb": {}}}),
+            ),
+            (json!({"a": "foo"}), json!(null), json!(null)),
+        ] {
+            let mut document = target.clone();
+            merge_patch(&mut document, &patch);
+            assert_eq!(document, expected, "{} + {}", target, patch);
+        }
+    }
+}
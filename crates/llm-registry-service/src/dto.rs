@@ -4,14 +4,16 @@
 //! separating internal domain models from external interfaces.
 
 use chrono::{DateTime, Utc};
+use llm_registry_core::Version;
 use llm_registry_core::{
-    Asset, AssetId, AssetReference, AssetStatus, AssetType, Checksum,
-    HashAlgorithm, Provenance, StorageLocation,
+    Asset, AssetId, AssetReference, AssetStatus, AssetType, Checksum, HashAlgorithm, LabelSelector,
+    Provenance, StorageLocation,
 };
-use semver::Version;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::retention::RetentionOverride;
+
 // ============================================================================
 // Registration DTOs
 // ============================================================================
@@ -44,6 +46,10 @@ pub struct RegisterAssetRequest {
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub annotations: HashMap<String, String>,
 
+    /// Structured `key=value` labels for selection and policy
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+
     /// Storage location
     pub storage: StorageLocation,
 
@@ -65,6 +71,14 @@ pub struct RegisterAssetRequest {
     /// Content type / MIME type
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
+
+    /// Per-asset override of the global retention rules
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retention_override: Option<RetentionOverride>,
+
+    /// Validate the request without registering the asset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
 }
 
 /// Response from registering an asset
@@ -76,6 +90,107 @@ pub struct RegisterAssetResponse {
     /// Any warnings generated during registration
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
+
+    /// Set when the request was a dry run and nothing was persisted
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+}
+
+/// External location holding an asset manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SourceReference {
+    /// Manifest file tracked in a Git repository
+    Git {
+        /// Clone URL of the repository
+        url: String,
+        /// Branch or tag to resolve when no commit is pinned
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rev: Option<String>,
+        /// Commit SHA to pin the fetch to
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        commit: Option<String>,
+        /// Path of the manifest within the repository
+        #[serde(default = "default_manifest_path")]
+        path: String,
+    },
+    /// Manifest pushed as an OCI artifact
+    Oci {
+        /// Registry host (e.g. `ghcr.io`)
+        registry: String,
+        /// Repository within the registry
+        repository: String,
+        /// Tag to resolve when no digest is pinned
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tag: Option<String>,
+        /// Manifest digest to pin the fetch to (`sha256:...`)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        digest: Option<String>,
+    },
+}
+
+fn default_manifest_path() -> String {
+    "asset.yaml".to_string()
+}
+
+impl SourceReference {
+    /// Short name of the reference kind
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SourceReference::Git { .. } => "git",
+            SourceReference::Oci { .. } => "oci",
+        }
+    }
+}
+
+impl std::fmt::Display for SourceReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceReference::Git {
+                url,
+                rev,
+                commit,
+                path,
+            } => {
+                let at = commit.as_deref().or(rev.as_deref()).unwrap_or("HEAD");
+                write!(f, "{}@{}:{}", url, at, path)
+            }
+            SourceReference::Oci {
+                registry,
+                repository,
+                tag,
+                digest,
+            } => match (digest, tag) {
+                (Some(digest), _) => write!(f, "{}/{}@{}", registry, repository, digest),
+                (None, Some(tag)) => write!(f, "{}/{}:{}", registry, repository, tag),
+                (None, None) => write!(f, "{}/{}:latest", registry, repository),
+            },
+        }
+    }
+}
+
+/// Request to register an asset from a Git or OCI reference
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterFromRefRequest {
+    /// Where to fetch the asset manifest from
+    pub reference: SourceReference,
+}
+
+/// Response from registering an asset from a reference
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterFromRefResponse {
+    /// The registered asset
+    pub asset: Asset,
+
+    /// The reference the manifest was fetched from
+    pub reference: SourceReference,
+
+    /// Commit SHA or manifest digest the reference resolved to
+    pub resolved_digest: String,
+
+    /// Any warnings generated during registration
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 // ============================================================================
@@ -83,20 +198,33 @@ pub struct RegisterAssetResponse {
 // ============================================================================
 
 /// Search query parameters
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchAssetsRequest {
     /// Text search across name, description, and annotations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 
+    /// Free-text query: case-insensitive substring match on name or
+    /// description, combined with the other filters; blank is ignored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+
     /// Filter by asset types
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub asset_types: Vec<AssetType>,
 
-    /// Filter by tags (asset must have all tags)
+    /// Filter by tags; an empty list applies no tag filter
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
 
+    /// Whether an asset must have `all` of `tags` (the default) or `any` of them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag_match: Option<TagMatch>,
+
+    /// Filter by labels, e.g. `team=ml-platform,tier in (gold,silver)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_selector: Option<String>,
+
     /// Filter by author
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
@@ -105,10 +233,19 @@ pub struct SearchAssetsRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub storage_backend: Option<String>,
 
+    /// Filter by content hash: a full checksum or an unambiguous hex prefix
+    /// of at least [`MIN_CONTENT_HASH_PREFIX`] characters
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+
     /// Only include non-deprecated assets
     #[serde(default = "default_exclude_deprecated")]
     pub exclude_deprecated: bool,
 
+    /// Include deprecated assets; overrides `exclude_deprecated`
+    #[serde(default)]
+    pub include_deprecated: bool,
+
     /// Maximum number of results
     #[serde(default = "default_limit")]
     pub limit: i64,
@@ -117,21 +254,97 @@ pub struct SearchAssetsRequest {
     #[serde(default)]
     pub offset: i64,
 
-    /// Sort field
-    #[serde(default)]
-    pub sort_by: SortField,
+    /// Sort field (`name`, `version`, `created_at`, `updated_at`, `size_bytes`);
+    /// defaults to `created_at`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<String>,
+
+    /// Sort order; defaults to `desc`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
 
-    /// Sort order
+    /// Include assets past their TTL when expiry is enforced
     #[serde(default)]
-    pub sort_order: SortOrder,
+    pub include_expired: bool,
+
+    /// Comma-separated facets to count over the full filtered set: `type` and/or `tag`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<String>,
+}
+
+impl Default for SearchAssetsRequest {
+    fn default() -> Self {
+        Self {
+            text: None,
+            q: None,
+            asset_types: vec![],
+            tags: vec![],
+            tag_match: None,
+            label_selector: None,
+            author: None,
+            storage_backend: None,
+            content_hash: None,
+            exclude_deprecated: default_exclude_deprecated(),
+            include_deprecated: false,
+            limit: default_limit(),
+            offset: 0,
+            sort_by: None,
+            sort_order: None,
+            include_expired: false,
+            facets: None,
+        }
+    }
+}
+
+impl SearchAssetsRequest {
+    /// Resolve the requested sort field, rejecting unknown fields
+    pub fn sort_field(&self) -> Result<SortField, String> {
+        match self.sort_by.as_deref() {
+            None => Ok(SortField::default()),
+            Some(field) => field.parse(),
+        }
+    }
+
+    /// Parse the requested label selector; a blank selector applies no filter
+    pub fn label_selector(&self) -> Result<Option<LabelSelector>, String> {
+        match self.label_selector.as_deref() {
+            None => Ok(None),
+            Some(selector) => {
+                let selector: LabelSelector = selector.parse()?;
+                Ok(Some(selector).filter(|s| !s.is_empty()))
+            }
+        }
+    }
+
+    /// The requested facet names, or `None` when no facets were requested
+    pub fn facet_names(&self) -> Option<Vec<String>> {
+        let names: Vec<String> = self
+            .facets
+            .as_deref()?
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+        Some(names).filter(|names| !names.is_empty())
+    }
 }
 
 fn default_exclude_deprecated() -> bool {
     true
 }
 
+/// Shortest content hash prefix accepted by search
+pub const MIN_CONTENT_HASH_PREFIX: usize = 4;
+
+/// Page size used when a search does not specify a limit
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Default cap on the page size a search may request
+pub const MAX_PAGE_SIZE: i64 = 200;
+
 fn default_limit() -> i64 {
-    50
+    DEFAULT_PAGE_SIZE
 }
 
 /// Fields to sort by
@@ -145,17 +358,69 @@ pub enum SortField {
     SizeBytes,
 }
 
+impl SortField {
+    /// All sortable fields
+    pub const ALL: [SortField; 5] = [
+        SortField::CreatedAt,
+        SortField::UpdatedAt,
+        SortField::Name,
+        SortField::Version,
+        SortField::SizeBytes,
+    ];
+
+    /// Query-string name of the field
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortField::CreatedAt => "created_at",
+            SortField::UpdatedAt => "updated_at",
+            SortField::Name => "name",
+            SortField::Version => "version",
+            SortField::SizeBytes => "size_bytes",
+        }
+    }
+}
+
+impl std::str::FromStr for SortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created_at" => Ok(SortField::CreatedAt),
+            "updated_at" => Ok(SortField::UpdatedAt),
+            "name" => Ok(SortField::Name),
+            "version" => Ok(SortField::Version),
+            "size_bytes" => Ok(SortField::SizeBytes),
+            other => Err(format!(
+                "Unknown sort field '{}' (expected one of: name, version, created_at, updated_at, size_bytes)",
+                other
+            )),
+        }
+    }
+}
+
 impl Default for SortField {
     fn default() -> Self {
         SortField::CreatedAt
     }
 }
 
+/// How multiple tag filters combine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatch {
+    /// Asset must have every tag
+    #[default]
+    All,
+    /// Asset must have at least one of the tags
+    Any,
+}
+
 /// Sort order
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
 pub enum SortOrder {
+    #[serde(rename = "asc", alias = "ascending")]
     Ascending,
+    #[serde(rename = "desc", alias = "descending")]
     Descending,
 }
 
@@ -182,8 +447,18 @@ pub struct SearchAssetsResponse {
 
     /// Whether there are more results
     pub has_more: bool,
+
+    /// Requested facet counts, keyed by facet then value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<Facets>,
 }
 
+/// Facet counts: facet name (`type`, `tag`) to value to number of matching assets
+pub type Facets = HashMap<String, HashMap<String, i64>>;
+
+/// Facet names accepted in [`SearchAssetsRequest::facets`]
+pub const SEARCH_FACETS: &[&str] = &["type", "tag"];
+
 // ============================================================================
 // Validation DTOs
 // ============================================================================
@@ -257,6 +532,32 @@ pub struct VerifyIntegrityRequest {
     pub computed_checksum: Option<Checksum>,
 }
 
+/// Result of re-hashing an asset's stored content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    /// Asset that was verified
+    pub asset_id: AssetId,
+
+    /// Algorithm the content was hashed with
+    pub algorithm: HashAlgorithm,
+
+    /// Checksum recorded at registration
+    pub expected: String,
+
+    /// Checksum of the content as currently stored
+    pub actual: String,
+
+    /// Whether the stored content still matches the recorded checksum
+    pub verified: bool,
+
+    /// Size of the stored content in bytes
+    pub size_bytes: u64,
+
+    /// Warnings raised while selecting the algorithm
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ValidationWarning>,
+}
+
 /// Integrity verification result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntegrityVerificationResult {
@@ -322,6 +623,35 @@ pub struct ListVersionsResponse {
     pub latest: Option<Version>,
 }
 
+/// A tag and the number of assets carrying it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagCount {
+    /// Tag name
+    pub tag: String,
+
+    /// Number of assets with this tag
+    pub count: i64,
+}
+
+/// Request to resolve the newest version of an asset by name
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResolveLatestRequest {
+    /// Asset name
+    pub name: String,
+
+    /// Semver range the version must satisfy, such as `^1.2`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+
+    /// Also consider deprecated versions
+    #[serde(default)]
+    pub include_deprecated: bool,
+
+    /// Also consider versions past their TTL
+    #[serde(default)]
+    pub include_expired: bool,
+}
+
 /// Information about a specific version
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionInfo {
@@ -395,8 +725,16 @@ pub struct DependencyGraphResponse {
     /// All dependencies (direct and transitive)
     pub dependencies: Vec<DependencyNode>,
 
-    /// Whether the graph was truncated due to max_depth
+    /// Whether the graph was truncated due to max_depth or the node cap
     pub truncated: bool,
+
+    /// Shallowest depth with assets left out by the node cap
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncated_at_depth: Option<i32>,
+
+    /// Dependency cycles found during traversal, each listed as its node path
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cycles: Vec<Vec<AssetId>>,
 }
 
 /// Node in dependency graph
@@ -419,6 +757,16 @@ pub struct DependencyNode {
     pub dependencies: Vec<AssetId>,
 }
 
+/// Asset that depends on another asset, directly or transitively
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitiveDependent {
+    /// Dependent asset
+    pub asset: Asset,
+
+    /// Shortest number of dependency hops to the target (1 = direct dependent)
+    pub distance: u32,
+}
+
 // ============================================================================
 // Update DTOs
 // ============================================================================
@@ -453,9 +801,105 @@ pub struct UpdateAssetRequest {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub remove_annotations: Vec<String>,
 
+    /// Labels to add/update
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub add_labels: HashMap<String, String>,
+
+    /// Label keys to remove
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove_labels: Vec<String>,
+
     /// New status
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<AssetStatus>,
+
+    /// Entity tag the asset must currently have for the update to apply
+    /// (see [`Asset::etag`]); `*` matches any version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<String>,
+}
+
+/// Sparse partial update of an asset
+///
+/// Only fields that are present are applied; tags, annotations and labels are edited
+/// through add/remove operations rather than replaced wholesale.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AssetPatch {
+    /// Accepted for compatibility with [`UpdateAssetRequest`] bodies, which
+    /// PATCH took before; the asset id always comes from the path
+    #[serde(default, skip_serializing)]
+    pub asset_id: Option<AssetId>,
+
+    /// New description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// New license
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+
+    /// Tags to add
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub add_tags: Option<Vec<String>>,
+
+    /// Tags to remove (absent tags are ignored)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remove_tags: Option<Vec<String>>,
+
+    /// Annotations to add/update
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub add_annotations: Option<HashMap<String, String>>,
+
+    /// Annotation keys to remove (absent keys are ignored)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remove_annotations: Option<Vec<String>>,
+
+    /// Labels to add/update
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub add_labels: Option<HashMap<String, String>>,
+
+    /// Label keys to remove (absent keys are ignored)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remove_labels: Option<Vec<String>>,
+
+    /// New status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AssetStatus>,
+
+    /// Entity tag the asset must currently have for the patch to apply
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_version: Option<String>,
+}
+
+impl AssetPatch {
+    /// Convert into an update request for the given asset
+    pub fn into_update_request(self, asset_id: AssetId) -> UpdateAssetRequest {
+        UpdateAssetRequest {
+            asset_id,
+            description: self.description,
+            license: self.license,
+            add_tags: self.add_tags.unwrap_or_default(),
+            remove_tags: self.remove_tags.unwrap_or_default(),
+            add_annotations: self.add_annotations.unwrap_or_default(),
+            remove_annotations: self.remove_annotations.unwrap_or_default(),
+            add_labels: self.add_labels.unwrap_or_default(),
+            remove_labels: self.remove_labels.unwrap_or_default(),
+            status: self.status,
+            expected_version: self.expected_version,
+        }
+    }
+}
+
+/// Request to deprecate an asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeprecateAssetRequest {
+    /// Why the asset is deprecated
+    pub reason: String,
+
+    /// Asset that replaces the deprecated one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub superseded_by: Option<AssetId>,
 }
 
 /// Response from updating an asset
@@ -478,8 +922,22 @@ mod tests {
         assert_eq!(req.limit, 50);
         assert_eq!(req.offset, 0);
         assert!(req.exclude_deprecated);
-        assert_eq!(req.sort_by, SortField::CreatedAt);
-        assert_eq!(req.sort_order, SortOrder::Descending);
+        assert_eq!(req.sort_field(), Ok(SortField::CreatedAt));
+        assert_eq!(req.sort_order.unwrap_or_default(), SortOrder::Descending);
+    }
+
+    #[test]
+    fn test_search_request_sort_parsing() {
+        let req: SearchAssetsRequest =
+            serde_json::from_str(r#"{"sort_by": "name", "sort_order": "asc"}"#).unwrap();
+        assert_eq!(req.sort_field(), Ok(SortField::Name));
+        assert_eq!(req.sort_order, Some(SortOrder::Ascending));
+
+        let req = SearchAssetsRequest {
+            sort_by: Some("popularity".to_string()),
+            ..Default::default()
+        };
+        assert!(req.sort_field().is_err());
     }
 
     #[test]
@@ -2,8 +2,13 @@
 //!
 //! This module defines error types specific to the service layer,
 //! mapping domain and database errors to service-level errors.
+//! [`RegistryError`] flattens service and adapter errors behind a stable
+//! error code and HTTP status.
 
-use llm_registry_core::RegistryError;
+use crate::adapters::config_manager::ConfigAdapterError;
+use crate::adapters::observatory::ObservatoryError;
+use crate::adapters::schema_registry::SchemaAdapterError;
+use llm_registry_core::RegistryError as DomainError;
 use llm_registry_db::DbError;
 use thiserror::Error;
 
@@ -48,6 +53,10 @@ pub enum ServiceError {
         message: String,
     },
 
+    /// The caller's expected version does not match the stored asset
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
     /// Invalid input
     #[error("Invalid input: {0}")]
     InvalidInput(String),
@@ -56,6 +65,14 @@ pub enum ServiceError {
     #[error("Operation not permitted: {0}")]
     NotPermitted(String),
 
+    /// Fetching from an external source (Git, OCI) failed
+    #[error("Source unavailable: {0}")]
+    SourceUnavailable(String),
+
+    /// A required upstream dependency is unavailable
+    #[error("Service unavailable: {0}")]
+    Unavailable(String),
+
     /// Database error
     #[error("Database error: {0}")]
     Database(String),
@@ -65,28 +82,28 @@ pub enum ServiceError {
     Internal(String),
 }
 
-impl From<RegistryError> for ServiceError {
-    fn from(err: RegistryError) -> Self {
+impl From<DomainError> for ServiceError {
+    fn from(err: DomainError) -> Self {
         match err {
-            RegistryError::AssetNotFound(msg) => ServiceError::NotFound(msg),
-            RegistryError::DuplicateAsset { name, version } => {
+            DomainError::AssetNotFound(msg) => ServiceError::NotFound(msg),
+            DomainError::DuplicateAsset { name, version } => {
                 ServiceError::AlreadyExists { name, version }
             }
-            RegistryError::ChecksumMismatch { expected, actual } => {
+            DomainError::ChecksumMismatch { expected, actual } => {
                 ServiceError::ChecksumVerificationFailed(format!(
                     "expected {}, got {}",
                     expected, actual
                 ))
             }
-            RegistryError::CircularDependency(msg) => ServiceError::CircularDependency(msg),
-            RegistryError::InvalidDependency(msg) => ServiceError::DependencyNotFound(msg),
-            RegistryError::PolicyValidationFailed(msg) => ServiceError::PolicyValidationFailed {
+            DomainError::CircularDependency(msg) => ServiceError::CircularDependency(msg),
+            DomainError::InvalidDependency(msg) => ServiceError::DependencyNotFound(msg),
+            DomainError::PolicyValidationFailed(msg) => ServiceError::PolicyValidationFailed {
                 policy_name: "unknown".to_string(),
                 message: msg,
             },
-            RegistryError::InvalidVersion(msg) => ServiceError::ValidationFailed(msg),
-            RegistryError::ValidationError(msg) => ServiceError::ValidationFailed(msg),
-            RegistryError::DatabaseError(msg) => ServiceError::Database(msg),
+            DomainError::InvalidVersion(msg) => ServiceError::ValidationFailed(msg),
+            DomainError::ValidationError(msg) => ServiceError::ValidationFailed(msg),
+            DomainError::DatabaseError(msg) => ServiceError::Database(msg),
             _ => ServiceError::Internal(err.to_string()),
         }
     }
@@ -111,6 +128,7 @@ impl From<DbError> for ServiceError {
                     }
                 }
             }
+            DbError::PreconditionFailed(msg) => ServiceError::PreconditionFailed(msg),
             DbError::ConstraintViolation(msg) => ServiceError::ValidationFailed(msg),
             DbError::ForeignKeyViolation(msg) => ServiceError::ValidationFailed(msg),
             DbError::UniqueViolation(msg) => ServiceError::ValidationFailed(msg),
@@ -132,6 +150,133 @@ impl From<DbError> for ServiceError {
     }
 }
 
+impl From<SchemaAdapterError> for ServiceError {
+    fn from(err: SchemaAdapterError) -> Self {
+        match err {
+            SchemaAdapterError::SchemaNotFound(msg) => ServiceError::NotFound(msg),
+            SchemaAdapterError::ValidationFailed(msg) => ServiceError::ValidationFailed(msg),
+            SchemaAdapterError::Unavailable(msg) => {
+                ServiceError::Unavailable(format!("schema registry: {}", msg))
+            }
+            SchemaAdapterError::IncompatibleVersion(msg) => ServiceError::ValidationFailed(msg),
+        }
+    }
+}
+
+/// Any error a registry operation can surface, service or adapter
+#[derive(Error, Debug)]
+pub enum RegistryError {
+    /// Service-layer error
+    #[error(transparent)]
+    Service(#[from] ServiceError),
+
+    /// Schema registry adapter error
+    #[error(transparent)]
+    Schema(#[from] SchemaAdapterError),
+
+    /// Config manager adapter error
+    #[error(transparent)]
+    Config(#[from] ConfigAdapterError),
+
+    /// Observatory adapter error
+    #[error(transparent)]
+    Observatory(#[from] ObservatoryError),
+}
+
+impl RegistryError {
+    /// Stable machine-readable error code
+    pub fn code(&self) -> &'static str {
+        match self {
+            RegistryError::Service(err) => match err {
+                ServiceError::NotFound(_) => "NOT_FOUND",
+                ServiceError::AlreadyExists { .. } => "ALREADY_EXISTS",
+                ServiceError::ValidationFailed(_) => "VALIDATION_FAILED",
+                ServiceError::ChecksumVerificationFailed(_) => "CHECKSUM_MISMATCH",
+                ServiceError::CircularDependency(_) => "CIRCULAR_DEPENDENCY",
+                ServiceError::DependencyNotFound(_) => "DEPENDENCY_NOT_FOUND",
+                ServiceError::VersionConflict(_) => "VERSION_CONFLICT",
+                ServiceError::PolicyValidationFailed { .. } => "POLICY_VALIDATION_FAILED",
+                ServiceError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+                ServiceError::InvalidInput(_) => "INVALID_INPUT",
+                ServiceError::NotPermitted(_) => "NOT_PERMITTED",
+                ServiceError::SourceUnavailable(_) => "SOURCE_UNAVAILABLE",
+                ServiceError::Unavailable(_) => "SERVICE_UNAVAILABLE",
+                ServiceError::Database(_) => "DATABASE_ERROR",
+                ServiceError::Internal(_) => "INTERNAL_ERROR",
+            },
+            RegistryError::Schema(err) => match err {
+                SchemaAdapterError::SchemaNotFound(_) => "SCHEMA_NOT_FOUND",
+                SchemaAdapterError::ValidationFailed(_) => "SCHEMA_VALIDATION_FAILED",
+                SchemaAdapterError::Unavailable(_) => "SCHEMA_REGISTRY_UNAVAILABLE",
+                SchemaAdapterError::IncompatibleVersion(_) => "SCHEMA_INCOMPATIBLE",
+            },
+            RegistryError::Config(err) => match err {
+                ConfigAdapterError::NotFound(_) => "CONFIG_NOT_FOUND",
+                ConfigAdapterError::ValidationFailed(_) => "CONFIG_VALIDATION_FAILED",
+                ConfigAdapterError::Unavailable(_) => "CONFIG_UNAVAILABLE",
+                ConfigAdapterError::InvalidFormat(_) => "CONFIG_INVALID_FORMAT",
+            },
+            RegistryError::Observatory(err) => match err {
+                ObservatoryError::EmitFailed { .. } => "TELEMETRY_EMIT_FAILED",
+                ObservatoryError::Unavailable(_) => "OBSERVATORY_UNAVAILABLE",
+                ObservatoryError::InvalidSpan(_) => "INVALID_SPAN",
+                ObservatoryError::TraceNotFound(_) => "TRACE_NOT_FOUND",
+            },
+        }
+    }
+
+    /// HTTP status code the error maps to
+    pub fn status(&self) -> u16 {
+        match self {
+            RegistryError::Service(err) => match err {
+                ServiceError::NotFound(_) => 404,
+                ServiceError::AlreadyExists { .. } | ServiceError::VersionConflict(_) => 409,
+                ServiceError::ValidationFailed(_)
+                | ServiceError::ChecksumVerificationFailed(_)
+                | ServiceError::CircularDependency(_)
+                | ServiceError::DependencyNotFound(_)
+                | ServiceError::PolicyValidationFailed { .. } => 422,
+                ServiceError::PreconditionFailed(_) => 412,
+                ServiceError::InvalidInput(_) => 400,
+                ServiceError::NotPermitted(_) => 403,
+                ServiceError::SourceUnavailable(_) => 502,
+                ServiceError::Unavailable(_) => 503,
+                ServiceError::Database(_) | ServiceError::Internal(_) => 500,
+            },
+            RegistryError::Schema(err) => match err {
+                SchemaAdapterError::SchemaNotFound(_) => 404,
+                SchemaAdapterError::ValidationFailed(_)
+                | SchemaAdapterError::IncompatibleVersion(_) => 422,
+                SchemaAdapterError::Unavailable(_) => 503,
+            },
+            RegistryError::Config(err) => match err {
+                ConfigAdapterError::NotFound(_) => 404,
+                ConfigAdapterError::ValidationFailed(_) => 422,
+                ConfigAdapterError::Unavailable(_) => 503,
+                ConfigAdapterError::InvalidFormat(_) => 500,
+            },
+            RegistryError::Observatory(err) => match err {
+                ObservatoryError::EmitFailed { .. } => 502,
+                ObservatoryError::Unavailable(_) => 503,
+                ObservatoryError::InvalidSpan(_) => 400,
+                ObservatoryError::TraceNotFound(_) => 404,
+            },
+        }
+    }
+}
+
+impl From<DomainError> for RegistryError {
+    fn from(err: DomainError) -> Self {
+        RegistryError::Service(err.into())
+    }
+}
+
+impl From<DbError> for RegistryError {
+    fn from(err: DbError) -> Self {
+        RegistryError::Service(err.into())
+    }
+}
+
 impl From<semver::Error> for ServiceError {
     fn from(err: semver::Error) -> Self {
         ServiceError::ValidationFailed(format!("Invalid version: {}", err))
@@ -150,7 +295,7 @@ mod tests {
 
     #[test]
     fn test_service_error_from_registry_error() {
-        let registry_err = RegistryError::AssetNotFound("test-asset".to_string());
+        let registry_err = DomainError::AssetNotFound("test-asset".to_string());
         let service_err: ServiceError = registry_err.into();
         assert!(matches!(service_err, ServiceError::NotFound(_)));
     }
@@ -162,6 +307,71 @@ mod tests {
         assert!(matches!(service_err, ServiceError::NotFound(_)));
     }
 
+    #[test]
+    fn test_registry_error_maps_adapter_errors() {
+        let cases: Vec<(RegistryError, u16, &str)> = vec![
+            (
+                SchemaAdapterError::SchemaNotFound("ModelMetadata".into()).into(),
+                404,
+                "SCHEMA_NOT_FOUND",
+            ),
+            (
+                SchemaAdapterError::ValidationFailed("bad".into()).into(),
+                422,
+                "SCHEMA_VALIDATION_FAILED",
+            ),
+            (
+                SchemaAdapterError::Unavailable("down".into()).into(),
+                503,
+                "SCHEMA_REGISTRY_UNAVAILABLE",
+            ),
+            (
+                ConfigAdapterError::NotFound("ttl".into()).into(),
+                404,
+                "CONFIG_NOT_FOUND",
+            ),
+            (
+                ConfigAdapterError::Unavailable("down".into()).into(),
+                503,
+                "CONFIG_UNAVAILABLE",
+            ),
+            (
+                ConfigAdapterError::InvalidFormat("yaml".into()).into(),
+                500,
+                "CONFIG_INVALID_FORMAT",
+            ),
+            (
+                ObservatoryError::EmitFailed {
+                    status: Some(500),
+                    message: "boom".into(),
+                }
+                .into(),
+                502,
+                "TELEMETRY_EMIT_FAILED",
+            ),
+            (
+                ObservatoryError::InvalidSpan("no id".into()).into(),
+                400,
+                "INVALID_SPAN",
+            ),
+            (
+                ObservatoryError::TraceNotFound("t-1".into()).into(),
+                404,
+                "TRACE_NOT_FOUND",
+            ),
+            (DbError::NotFound("asset".into()).into(), 404, "NOT_FOUND"),
+            (
+                ServiceError::PreconditionFailed("stale".into()).into(),
+                412,
+                "PRECONDITION_FAILED",
+            ),
+        ];
+
+        for (err, status, code) in cases {
+            assert_eq!((err.status(), err.code()), (status, code), "{}", err);
+        }
+    }
+
     #[test]
     fn test_service_error_display() {
         let err = ServiceError::ValidationFailed("Invalid name".to_string());
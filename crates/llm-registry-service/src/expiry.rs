@@ -0,0 +1,179 @@
+//! TTL-based asset expiry
+//!
+//! [`TtlConfig`] assigns each asset state a time-to-live, measured from the
+//! moment the asset entered that state:
+//!
+//! - active and non-compliant assets: `default_ttl` from creation
+//! - deprecated assets: `deprecated_ttl` from deprecation
+//! - archived assets: `archived_ttl` from the last update (the status change)
+//!
+//! Pinned versions (see [`RetentionOverride`]) never expire.
+
+use chrono::{DateTime, Utc};
+use llm_registry_core::{Asset, AssetStatus};
+use llm_registry_db::ExpiryCutoff;
+use std::time::Duration;
+
+use crate::adapters::config_manager::TtlConfig;
+use crate::retention::{RetentionOverride, RETENTION_PIN_ANNOTATION};
+
+/// When the asset's TTL runs out, or `None` if it never expires
+pub fn expires_at(asset: &Asset, ttl: &TtlConfig) -> Option<DateTime<Utc>> {
+    if RetentionOverride::of(asset).is_some_and(|o| o.pin) {
+        return None;
+    }
+
+    let (since, ttl) = match asset.status {
+        AssetStatus::Active | AssetStatus::NonCompliant => (asset.created_at, ttl.default_ttl),
+        AssetStatus::Deprecated => (
+            asset.deprecated_at.unwrap_or(asset.updated_at),
+            ttl.deprecated_ttl,
+        ),
+        AssetStatus::Archived => (asset.updated_at, ttl.archived_ttl),
+    };
+
+    chrono::Duration::from_std(ttl)
+        .ok()
+        .and_then(|ttl| since.checked_add_signed(ttl))
+}
+
+/// Cutoffs under which an asset is past its TTL at `now`, for pushing
+/// expiry into a [`SearchQuery`](llm_registry_db::SearchQuery)
+pub fn cutoff(ttl: &TtlConfig, now: DateTime<Utc>) -> ExpiryCutoff {
+    let before = |ttl: Duration| {
+        chrono::Duration::from_std(ttl)
+            .ok()
+            .and_then(|ttl| now.checked_sub_signed(ttl))
+    };
+    ExpiryCutoff {
+        created: before(ttl.default_ttl),
+        deprecated: before(ttl.deprecated_ttl),
+        archived: before(ttl.archived_ttl),
+        pin_annotation: RETENTION_PIN_ANNOTATION.to_string(),
+    }
+}
+
+/// Whether the asset is past its TTL at `now`
+pub fn is_expired(asset: &Asset, ttl: &TtlConfig, now: DateTime<Utc>) -> bool {
+    cutoff(ttl, now).is_expired(asset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_registry_core::Version;
+    use llm_registry_core::{
+        AssetId, AssetMetadata, AssetType, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
+    };
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn ttl() -> TtlConfig {
+        TtlConfig {
+            default_ttl: Duration::from_secs(100 * DAY as u64),
+            deprecated_ttl: Duration::from_secs(30 * DAY as u64),
+            archived_ttl: Duration::from_secs(10 * DAY as u64),
+            cache_ttl: Duration::from_secs(3600),
+            enforce: true,
+        }
+    }
+
+    /// An asset that entered `status` `days_ago` days ago
+    fn asset(status: AssetStatus, days_ago: i64) -> Asset {
+        let metadata = AssetMetadata::new("model", Version::new(1, 0, 0));
+        let storage = StorageLocation::new(
+            StorageBackend::S3 {
+                bucket: "test".to_string(),
+                region: "us-east-1".to_string(),
+                endpoint: None,
+            },
+            "model.bin".to_string(),
+            None,
+        )
+        .unwrap();
+        let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+        let mut asset = Asset::new(
+            AssetId::new(),
+            AssetType::Model,
+            metadata,
+            storage,
+            checksum,
+        )
+        .unwrap();
+
+        let entered = Utc::now() - chrono::Duration::days(days_ago);
+        asset.status = status;
+        asset.created_at = entered - chrono::Duration::days(1000);
+        asset.updated_at = entered;
+        if status == AssetStatus::Deprecated {
+            asset.deprecated_at = Some(entered);
+        }
+        if matches!(status, AssetStatus::Active | AssetStatus::NonCompliant) {
+            asset.created_at = entered;
+        }
+        asset
+    }
+
+    #[test]
+    fn test_each_state_crosses_its_ttl() {
+        let now = Utc::now();
+        for (status, ttl_days) in [
+            (AssetStatus::Active, 100),
+            (AssetStatus::NonCompliant, 100),
+            (AssetStatus::Deprecated, 30),
+            (AssetStatus::Archived, 10),
+        ] {
+            assert!(
+                !is_expired(&asset(status, ttl_days - 1), &ttl(), now),
+                "{:?} expired before its TTL",
+                status
+            );
+            assert!(
+                is_expired(&asset(status, ttl_days + 1), &ttl(), now),
+                "{:?} not expired after its TTL",
+                status
+            );
+        }
+    }
+
+    #[test]
+    fn test_deprecated_without_timestamp_uses_last_update() {
+        let mut deprecated = asset(AssetStatus::Deprecated, 31);
+        deprecated.deprecated_at = None;
+        assert!(is_expired(&deprecated, &ttl(), Utc::now()));
+
+        deprecated.updated_at = Utc::now();
+        assert!(!is_expired(&deprecated, &ttl(), Utc::now()));
+    }
+
+    #[test]
+    fn test_cutoff_agrees_with_expiry_time() {
+        let now = Utc::now();
+        let cutoff = cutoff(&ttl(), now);
+        for days_ago in [1, 29, 31, 99, 101] {
+            for status in [
+                AssetStatus::Active,
+                AssetStatus::Deprecated,
+                AssetStatus::Archived,
+            ] {
+                let asset = asset(status, days_ago);
+                assert_eq!(
+                    cutoff.is_expired(&asset),
+                    expires_at(&asset, &ttl()).is_some_and(|at| now >= at),
+                    "{:?} entered {} days ago",
+                    status,
+                    days_ago
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pinned_asset_never_expires() {
+        let mut pinned = asset(AssetStatus::Archived, 365);
+        RetentionOverride::pinned().apply_to(&mut pinned.metadata.annotations);
+
+        assert_eq!(expires_at(&pinned, &ttl()), None);
+        assert!(!is_expired(&pinned, &ttl(), Utc::now()));
+    }
+}
@@ -0,0 +1,176 @@
+//! Governance context propagation
+//!
+//! Compliance regimes often require extra context (ticket ids, change-request
+//! ids, ...) on every audit event. The API layer collects those values from the
+//! request and runs the service call inside [`GovernanceContext::scope`]; any
+//! event appended through a [`GovernanceEventStore`] during that call is
+//! stamped with them.
+
+use async_trait::async_trait;
+use llm_registry_core::{AssetId, RegistryEvent};
+use llm_registry_db::{DbResult, EventQuery, EventQueryResults, EventStore};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+tokio::task_local! {
+    static CURRENT: GovernanceContext;
+}
+
+/// Context key/value pairs that accompany a mutating operation
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GovernanceContext(HashMap<String, String>);
+
+impl GovernanceContext {
+    /// Create an empty context
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a context value
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    /// Get a context value
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Whether the context has no values
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the context values
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter()
+    }
+
+    /// Run a future with this context as the current governance context
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+
+    /// Get the governance context of the current task, if any
+    pub fn current() -> Option<GovernanceContext> {
+        CURRENT.try_with(Clone::clone).ok()
+    }
+
+    /// Stamp the context of the current task onto an event
+    ///
+    /// Existing event context keys are not overwritten.
+    pub fn stamp_current(event: &mut RegistryEvent) {
+        let _ = CURRENT.try_with(|context| {
+            for (key, value) in context.iter() {
+                event
+                    .context
+                    .entry(key.clone())
+                    .or_insert_with(|| value.clone());
+            }
+        });
+    }
+}
+
+impl FromIterator<(String, String)> for GovernanceContext {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+/// Event store decorator that stamps the current governance context onto
+/// appended events
+pub struct GovernanceEventStore {
+    inner: Arc<dyn EventStore>,
+}
+
+impl GovernanceEventStore {
+    /// Wrap an event store
+    pub fn new(inner: Arc<dyn EventStore>) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl EventStore for GovernanceEventStore {
+    async fn append(&self, mut event: RegistryEvent) -> DbResult<RegistryEvent> {
+        GovernanceContext::stamp_current(&mut event);
+        self.inner.append(event).await
+    }
+
+    async fn append_batch(&self, mut events: Vec<RegistryEvent>) -> DbResult<Vec<RegistryEvent>> {
+        events.iter_mut().for_each(GovernanceContext::stamp_current);
+        self.inner.append_batch(events).await
+    }
+
+    async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults> {
+        self.inner.query(query).await
+    }
+
+    async fn get_asset_events(
+        &self,
+        asset_id: &AssetId,
+        limit: i64,
+    ) -> DbResult<Vec<RegistryEvent>> {
+        self.inner.get_asset_events(asset_id, limit).await
+    }
+
+    async fn get_latest_event(&self, asset_id: &AssetId) -> DbResult<Option<RegistryEvent>> {
+        self.inner.get_latest_event(asset_id).await
+    }
+
+    async fn count_events(&self) -> DbResult<i64> {
+        self.inner.count_events().await
+    }
+
+    async fn count_by_type(&self, event_type: &str) -> DbResult<i64> {
+        self.inner.count_by_type(event_type).await
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        self.inner.health_check().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use llm_registry_core::EventType;
+
+    fn event() -> RegistryEvent {
+        RegistryEvent::new(EventType::AssetDeleted {
+            asset_id: AssetId::new(),
+            asset_name: "model".to_string(),
+            asset_version: "1.0.0".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_stamp_inside_scope() {
+        let context: GovernanceContext = [("ticket-id".to_string(), "OPS-42".to_string())]
+            .into_iter()
+            .collect();
+
+        let stamped = context
+            .scope(async {
+                let mut event = event();
+                GovernanceContext::stamp_current(&mut event);
+                event
+            })
+            .await;
+
+        assert_eq!(
+            stamped.context.get("ticket-id").map(String::as_str),
+            Some("OPS-42")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stamp_outside_scope_is_noop() {
+        let mut event = event();
+        GovernanceContext::stamp_current(&mut event);
+        assert!(event.context.is_empty());
+        assert!(GovernanceContext::current().is_none());
+    }
+}
@@ -0,0 +1,283 @@
+//! Dependency graph cache
+//!
+//! Computed [`DependencyGraphResponse`]s are cached per request and indexed by
+//! every asset they contain, so a change to any asset (however deep in the
+//! graph) invalidates each cached graph it is a member of.
+
+use llm_registry_core::AssetId;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::dto::{DependencyGraphResponse, GetDependencyGraphRequest};
+
+/// Default maximum number of cached graphs
+pub const DEFAULT_GRAPH_CACHE_CAPACITY: usize = 1024;
+
+/// Cache key covering every parameter that shapes a dependency graph
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphCacheKey {
+    /// Root asset
+    pub asset_id: AssetId,
+    /// Maximum traversal depth
+    pub max_depth: i32,
+}
+
+impl From<&GetDependencyGraphRequest> for GraphCacheKey {
+    fn from(request: &GetDependencyGraphRequest) -> Self {
+        Self {
+            asset_id: request.asset_id,
+            max_depth: request.max_depth,
+        }
+    }
+}
+
+/// Hit/miss counters for the graph cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GraphCacheStats {
+    /// Lookups served from the cache
+    pub hits: u64,
+    /// Lookups that had to build the graph
+    pub misses: u64,
+    /// Graphs currently cached
+    pub entries: usize,
+}
+
+#[derive(Default)]
+struct GraphCacheInner {
+    graphs: HashMap<GraphCacheKey, DependencyGraphResponse>,
+    /// Asset id -> cached graphs containing that asset
+    members: HashMap<AssetId, HashSet<GraphCacheKey>>,
+}
+
+impl GraphCacheInner {
+    fn remove(&mut self, key: &GraphCacheKey) {
+        let Some(graph) = self.graphs.remove(key) else {
+            return;
+        };
+        for asset_id in graph_members(&graph) {
+            if let Some(keys) = self.members.get_mut(&asset_id) {
+                keys.remove(key);
+                if keys.is_empty() {
+                    self.members.remove(&asset_id);
+                }
+            }
+        }
+    }
+}
+
+/// In-process cache of computed dependency graphs
+pub struct DependencyGraphCache {
+    inner: Mutex<GraphCacheInner>,
+    capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl Default for DependencyGraphCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_GRAPH_CACHE_CAPACITY)
+    }
+}
+
+impl DependencyGraphCache {
+    /// Create a cache holding at most `capacity` graphs
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(GraphCacheInner::default()),
+            capacity,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Look up a cached graph, counting the hit or miss
+    pub fn get(&self, key: &GraphCacheKey) -> Option<DependencyGraphResponse> {
+        let cached = self.inner.lock().unwrap().graphs.get(key).cloned();
+        let counter = if cached.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        cached
+    }
+
+    /// Cache a computed graph and index it by all of its member assets
+    pub fn insert(&self, key: GraphCacheKey, graph: DependencyGraphResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.remove(&key);
+
+        if inner.graphs.len() >= self.capacity {
+            if let Some(evicted) = inner.graphs.keys().next().cloned() {
+                inner.remove(&evicted);
+            }
+        }
+
+        for asset_id in graph_members(&graph) {
+            inner
+                .members
+                .entry(asset_id)
+                .or_default()
+                .insert(key.clone());
+        }
+        inner.graphs.insert(key, graph);
+    }
+
+    /// Drop every cached graph containing the given asset
+    ///
+    /// Returns the number of graphs invalidated.
+    pub fn invalidate(&self, asset_id: &AssetId) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        let keys = inner.members.remove(asset_id).unwrap_or_default();
+        for key in &keys {
+            inner.remove(key);
+        }
+        keys.len()
+    }
+
+    /// Drop all cached graphs
+    ///
+    /// Returns the number of graphs dropped.
+    pub fn clear(&self) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        let cleared = inner.graphs.len();
+        inner.graphs.clear();
+        inner.members.clear();
+        cleared
+    }
+
+    /// Current hit/miss counters
+    pub fn stats(&self) -> GraphCacheStats {
+        GraphCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.inner.lock().unwrap().graphs.len(),
+        }
+    }
+}
+
+/// Every asset whose state is reflected in a graph
+fn graph_members(graph: &DependencyGraphResponse) -> HashSet<AssetId> {
+    std::iter::once(graph.root)
+        .chain(graph.dependencies.iter().map(|node| node.asset_id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::DependencyNode;
+    use llm_registry_core::Version;
+
+    fn node(asset_id: AssetId, depth: i32, dependencies: Vec<AssetId>) -> DependencyNode {
+        DependencyNode {
+            asset_id,
+            name: asset_id.to_string(),
+            version: Version::new(1, 0, 0),
+            depth,
+            dependencies,
+        }
+    }
+
+    fn key(asset_id: AssetId) -> GraphCacheKey {
+        GraphCacheKey {
+            asset_id,
+            max_depth: -1,
+        }
+    }
+
+    #[test]
+    fn test_hit_and_miss_counted() {
+        let cache = DependencyGraphCache::default();
+        let root = AssetId::new();
+        let graph = DependencyGraphResponse {
+            root,
+            dependencies: vec![node(root, 0, vec![])],
+            truncated: false,
+            truncated_at_depth: None,
+            cycles: vec![],
+        };
+
+        assert!(cache.get(&key(root)).is_none());
+        cache.insert(key(root), graph);
+        assert!(cache.get(&key(root)).is_some());
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_invalidation_propagates_up_the_graph() {
+        let cache = DependencyGraphCache::default();
+        let (a, b, c, other) = (
+            AssetId::new(),
+            AssetId::new(),
+            AssetId::new(),
+            AssetId::new(),
+        );
+
+        // a -> b -> c, plus an unrelated graph
+        cache.insert(
+            key(a),
+            DependencyGraphResponse {
+                root: a,
+                dependencies: vec![node(a, 0, vec![b]), node(b, 1, vec![c]), node(c, 2, vec![])],
+                truncated: false,
+                truncated_at_depth: None,
+                cycles: vec![],
+            },
+        );
+        cache.insert(
+            key(b),
+            DependencyGraphResponse {
+                root: b,
+                dependencies: vec![node(b, 0, vec![c]), node(c, 1, vec![])],
+                truncated: false,
+                truncated_at_depth: None,
+                cycles: vec![],
+            },
+        );
+        cache.insert(
+            key(other),
+            DependencyGraphResponse {
+                root: other,
+                dependencies: vec![node(other, 0, vec![])],
+                truncated: false,
+                truncated_at_depth: None,
+                cycles: vec![],
+            },
+        );
+
+        assert_eq!(cache.invalidate(&c), 2);
+        assert!(cache.get(&key(a)).is_none());
+        assert!(cache.get(&key(b)).is_none());
+        assert!(cache.get(&key(other)).is_some());
+
+        // Membership of the dropped graphs is cleaned up as well
+        assert_eq!(cache.invalidate(&a), 0);
+    }
+
+    #[test]
+    fn test_capacity_bounds_entries() {
+        let cache = DependencyGraphCache::new(1);
+        for _ in 0..3 {
+            let root = AssetId::new();
+            cache.insert(
+                key(root),
+                DependencyGraphResponse {
+                    root,
+                    dependencies: vec![],
+                    truncated: false,
+                    truncated_at_depth: None,
+                    cycles: vec![],
+                },
+            );
+        }
+        assert_eq!(cache.stats().entries, 1);
+    }
+}
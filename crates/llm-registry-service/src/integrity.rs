@@ -5,36 +5,77 @@
 
 use async_trait::async_trait;
 use llm_registry_core::{Asset, AssetId, Checksum, EventType, HashAlgorithm, RegistryEvent};
-use llm_registry_db::{AssetRepository, EventStore};
+use llm_registry_db::{AssetRepository, EventStore, SearchQuery, SortField, SortOrder};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tracing::{debug, instrument, warn};
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tracing::{debug, info, instrument, warn};
 
+use crate::adapters::config_manager::ValidationConstraints;
+use crate::adapters::ObservatoryAdapter;
+use crate::content::ContentStore;
 use crate::dto::{
-    ComputeChecksumRequest, ComputeChecksumResponse, IntegrityVerificationResult,
-    VerifyIntegrityRequest,
+    ComputeChecksumRequest, ComputeChecksumResponse, IntegrityReport, IntegrityVerificationResult,
+    ValidationWarning, VerifyIntegrityRequest,
 };
 use crate::error::{ServiceError, ServiceResult};
 
+/// Page size used when scanning the registry for assets to verify
+const SWEEP_PAGE_SIZE: i64 = 500;
+
+/// Assets an [`IntegritySweeper`] verifies per second by default
+pub const DEFAULT_SWEEP_ASSETS_PER_SECOND: u32 = 10;
+
+/// Most error messages and failure reports a [`SweepReport`] keeps
+pub const MAX_SWEEP_REPORT_ENTRIES: usize = 100;
+
+/// Bytes read from the content store at a time while hashing
+const CONTENT_CHUNK_SIZE: usize = 64 * 1024;
+
 /// Trait for integrity verification operations
 #[async_trait]
 pub trait IntegrityService: Send + Sync {
     /// Compute checksum for provided data
-    async fn compute_checksum(&self, request: ComputeChecksumRequest) -> ServiceResult<ComputeChecksumResponse>;
+    async fn compute_checksum(
+        &self,
+        request: ComputeChecksumRequest,
+    ) -> ServiceResult<ComputeChecksumResponse>;
 
     /// Verify asset integrity against stored checksum
-    async fn verify_integrity(&self, request: VerifyIntegrityRequest) -> ServiceResult<IntegrityVerificationResult>;
+    async fn verify_integrity(
+        &self,
+        request: VerifyIntegrityRequest,
+    ) -> ServiceResult<IntegrityVerificationResult>;
 
     /// Verify checksum matches expected value
-    async fn verify_checksum(&self, asset_id: &AssetId, computed: &Checksum) -> ServiceResult<bool>;
+    async fn verify_checksum(&self, asset_id: &AssetId, computed: &Checksum)
+        -> ServiceResult<bool>;
 
     /// Recompute and update asset checksum
-    async fn update_checksum(&self, asset_id: &AssetId, new_checksum: Checksum) -> ServiceResult<Asset>;
+    async fn update_checksum(
+        &self,
+        asset_id: &AssetId,
+        new_checksum: Checksum,
+    ) -> ServiceResult<Asset>;
+
+    /// Re-hash the asset's stored content and compare it to the recorded checksum
+    ///
+    /// `algorithm` defaults to the one the checksum was recorded with and must
+    /// be permitted by `constraints`.
+    async fn verify_content(
+        &self,
+        asset_id: &AssetId,
+        algorithm: Option<&str>,
+        constraints: &ValidationConstraints,
+    ) -> ServiceResult<IntegrityReport>;
 }
 
 /// Default implementation of IntegrityService
 pub struct DefaultIntegrityService {
     repository: Arc<dyn AssetRepository>,
     event_store: Arc<dyn EventStore>,
+    content_store: Option<Arc<dyn ContentStore>>,
 }
 
 impl DefaultIntegrityService {
@@ -43,6 +84,33 @@ impl DefaultIntegrityService {
         Self {
             repository,
             event_store,
+            content_store: None,
+        }
+    }
+
+    /// Read asset content from `store` when verifying stored content
+    pub fn with_content_store(mut self, store: Arc<dyn ContentStore>) -> Self {
+        self.content_store = Some(store);
+        self
+    }
+
+    /// Hash a content stream chunk by chunk, returning the hash and the size
+    async fn hash_stream(
+        mut content: crate::content::ContentReader,
+        algorithm: HashAlgorithm,
+    ) -> ServiceResult<(String, u64)> {
+        let mut hasher = StreamHasher::new(algorithm);
+        let mut buffer = vec![0; CONTENT_CHUNK_SIZE];
+        let mut size = 0;
+        loop {
+            let read = content.read(&mut buffer).await.map_err(|e| {
+                ServiceError::Internal(format!("Failed to read stored content: {}", e))
+            })?;
+            if read == 0 {
+                return Ok((hasher.finalize(), size));
+            }
+            hasher.update(&buffer[..read]);
+            size += read as u64;
         }
     }
 
@@ -69,15 +137,54 @@ impl DefaultIntegrityService {
     }
 }
 
+/// Incremental hasher for any supported algorithm
+enum StreamHasher {
+    Sha256(sha2::Sha256),
+    Sha3_256(Box<sha3::Sha3_256>),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl StreamHasher {
+    fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::SHA256 => Self::Sha256(sha2::Digest::new()),
+            HashAlgorithm::SHA3_256 => Self::Sha3_256(Box::new(sha3::Digest::new())),
+            HashAlgorithm::BLAKE3 => Self::Blake3(Box::default()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Sha256(hasher) => sha2::Digest::update(hasher, data),
+            Self::Sha3_256(hasher) => sha3::Digest::update(hasher.as_mut(), data),
+            Self::Blake3(hasher) => {
+                hasher.update(data);
+            }
+        }
+    }
+
+    fn finalize(self) -> String {
+        match self {
+            Self::Sha256(hasher) => format!("{:x}", sha2::Digest::finalize(hasher)),
+            Self::Sha3_256(hasher) => format!("{:x}", sha3::Digest::finalize(*hasher)),
+            Self::Blake3(hasher) => hasher.finalize().to_hex().to_string(),
+        }
+    }
+}
+
 #[async_trait]
 impl IntegrityService for DefaultIntegrityService {
     #[instrument(skip(self, request))]
-    async fn compute_checksum(&self, request: ComputeChecksumRequest) -> ServiceResult<ComputeChecksumResponse> {
+    async fn compute_checksum(
+        &self,
+        request: ComputeChecksumRequest,
+    ) -> ServiceResult<ComputeChecksumResponse> {
         debug!("Computing checksum with algorithm: {:?}", request.algorithm);
 
         // Decode base64 data
         use base64::Engine;
-        let data = base64::engine::general_purpose::STANDARD.decode(&request.data)
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(&request.data)
             .map_err(|e| ServiceError::InvalidInput(format!("Invalid base64 data: {}", e)))?;
 
         // Compute hash
@@ -91,7 +198,10 @@ impl IntegrityService for DefaultIntegrityService {
     }
 
     #[instrument(skip(self, request), fields(asset_id = %request.asset_id))]
-    async fn verify_integrity(&self, request: VerifyIntegrityRequest) -> ServiceResult<IntegrityVerificationResult> {
+    async fn verify_integrity(
+        &self,
+        request: VerifyIntegrityRequest,
+    ) -> ServiceResult<IntegrityVerificationResult> {
         debug!("Verifying integrity for asset");
 
         // Fetch the asset
@@ -160,7 +270,11 @@ impl IntegrityService for DefaultIntegrityService {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn verify_checksum(&self, asset_id: &AssetId, computed: &Checksum) -> ServiceResult<bool> {
+    async fn verify_checksum(
+        &self,
+        asset_id: &AssetId,
+        computed: &Checksum,
+    ) -> ServiceResult<bool> {
         debug!("Verifying checksum");
 
         let asset = self
@@ -194,7 +308,11 @@ impl IntegrityService for DefaultIntegrityService {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn update_checksum(&self, asset_id: &AssetId, new_checksum: Checksum) -> ServiceResult<Asset> {
+    async fn update_checksum(
+        &self,
+        asset_id: &AssetId,
+        new_checksum: Checksum,
+    ) -> ServiceResult<Asset> {
         debug!("Updating asset checksum");
 
         // Fetch the asset
@@ -224,6 +342,293 @@ impl IntegrityService for DefaultIntegrityService {
 
         Ok(updated)
     }
+
+    #[instrument(skip(self, constraints), fields(asset_id = %asset_id))]
+    async fn verify_content(
+        &self,
+        asset_id: &AssetId,
+        algorithm: Option<&str>,
+        constraints: &ValidationConstraints,
+    ) -> ServiceResult<IntegrityReport> {
+        debug!("Verifying stored content");
+
+        let asset = self
+            .repository
+            .find_by_id(asset_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
+
+        let recorded = asset.checksum.algorithm();
+        let (requested, warning) = select_algorithm(algorithm, recorded, constraints)?;
+        if requested != recorded {
+            return Err(ServiceError::InvalidInput(format!(
+                "Asset checksum was recorded with {}, not {}",
+                recorded, requested
+            )));
+        }
+
+        let store = self.content_store.as_ref().ok_or_else(|| {
+            ServiceError::Unavailable("No content store is configured".to_string())
+        })?;
+        let content = store.open(&asset.storage).await?.ok_or_else(|| {
+            ServiceError::NotFound(format!("No stored content at {}", asset.storage))
+        })?;
+
+        let (actual, size_bytes) = Self::hash_stream(content, recorded).await?;
+        let verified = asset.checksum.verify_hash(&actual);
+
+        let event = RegistryEvent::new(if verified {
+            EventType::ChecksumVerified {
+                asset_id: *asset_id,
+                success: true,
+                algorithm: recorded.to_string(),
+            }
+        } else {
+            EventType::ChecksumFailed {
+                asset_id: *asset_id,
+                expected: asset.checksum.value().to_string(),
+                actual: actual.clone(),
+            }
+        });
+
+        if let Err(e) = self.event_store.append(event).await {
+            warn!("Failed to emit checksum event: {}", e);
+        }
+
+        Ok(IntegrityReport {
+            asset_id: *asset_id,
+            algorithm: recorded,
+            expected: asset.checksum.value().to_string(),
+            actual,
+            verified,
+            size_bytes,
+            warnings: warning.into_iter().collect(),
+        })
+    }
+}
+
+/// Resolve the algorithm to verify with, enforcing the allowed algorithms
+///
+/// A disallowed algorithm is rejected in strict mode; otherwise the
+/// recorded algorithm is used instead and a warning is returned alongside.
+/// A recorded algorithm that is itself disallowed is never computed, in
+/// either mode.
+fn select_algorithm(
+    requested: Option<&str>,
+    recorded: HashAlgorithm,
+    constraints: &ValidationConstraints,
+) -> ServiceResult<(HashAlgorithm, Option<ValidationWarning>)> {
+    let name = requested.map_or_else(|| recorded.to_string(), str::to_string);
+
+    if constraints.is_algorithm_allowed(&name) {
+        let algorithm = name.parse().map_err(|_| {
+            ServiceError::InvalidInput(format!("Unsupported hash algorithm: {}", name))
+        })?;
+        return Ok((algorithm, None));
+    }
+
+    if constraints.strict_mode {
+        return Err(ServiceError::ValidationFailed(format!(
+            "Hash algorithm {} is not allowed",
+            name
+        )));
+    }
+    if !constraints.is_algorithm_allowed(&recorded.to_string()) {
+        warn!(
+            "Recorded hash algorithm {} is not allowed, not verifying",
+            recorded
+        );
+        return Err(ServiceError::ValidationFailed(format!(
+            "Hash algorithm {} is not allowed and the checksum was recorded with it; not verified",
+            recorded
+        )));
+    }
+
+    warn!(
+        "Hash algorithm {} is not allowed, falling back to {}",
+        name, recorded
+    );
+    let warning = ValidationWarning {
+        field: "algorithm".to_string(),
+        message: format!(
+            "Hash algorithm {} is not allowed; verified with {}",
+            name, recorded
+        ),
+    };
+    Ok((recorded, Some(warning)))
+}
+
+/// Outcome of one [`IntegritySweeper`] run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SweepReport {
+    /// Assets whose stored content matched the recorded checksum
+    pub verified: usize,
+    /// Assets whose stored content no longer matches
+    pub failed: usize,
+    /// Assets that could not be verified, e.g. because no content is stored
+    pub skipped: usize,
+    /// Reports for the first [`MAX_SWEEP_REPORT_ENTRIES`] assets that failed
+    /// verification
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<IntegrityReport>,
+    /// Why the first [`MAX_SWEEP_REPORT_ENTRIES`] assets were skipped, or why
+    /// the run stopped early
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+    /// Failure reports and errors left out once the lists were full
+    #[serde(default)]
+    pub truncated: usize,
+}
+
+impl SweepReport {
+    fn push_error(&mut self, error: String) {
+        if self.errors.len() < MAX_SWEEP_REPORT_ENTRIES {
+            self.errors.push(error);
+        } else {
+            self.truncated += 1;
+        }
+    }
+
+    fn push_failure(&mut self, failure: IntegrityReport) {
+        if self.failures.len() < MAX_SWEEP_REPORT_ENTRIES {
+            self.failures.push(failure);
+        } else {
+            self.truncated += 1;
+        }
+    }
+}
+
+/// Re-verifies the stored content of every asset in the registry
+///
+/// Each completed check is emitted to the observatory as an
+/// `integrity_verified` governance event, as on-demand verification does.
+/// Verification is paced to a configurable number of assets per second so a
+/// sweep does not saturate the content store.
+pub struct IntegritySweeper {
+    repository: Arc<dyn AssetRepository>,
+    integrity: Arc<dyn IntegrityService>,
+    observatory: Arc<ObservatoryAdapter>,
+    constraints: ValidationConstraints,
+    assets_per_second: u32,
+}
+
+impl IntegritySweeper {
+    /// Create a sweeper verifying at the default rate
+    pub fn new(
+        repository: Arc<dyn AssetRepository>,
+        integrity: Arc<dyn IntegrityService>,
+        observatory: Arc<ObservatoryAdapter>,
+    ) -> Self {
+        Self {
+            repository,
+            integrity,
+            observatory,
+            constraints: ValidationConstraints::default(),
+            assets_per_second: DEFAULT_SWEEP_ASSETS_PER_SECOND,
+        }
+    }
+
+    /// Limit verification to `assets_per_second`; `0` disables the limit
+    pub fn with_rate_limit(mut self, assets_per_second: u32) -> Self {
+        self.assets_per_second = assets_per_second;
+        self
+    }
+
+    /// Set the constraints the hash algorithm is checked against
+    pub fn with_constraints(mut self, constraints: ValidationConstraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Verify every asset once, including deprecated ones
+    #[instrument(skip(self), fields(assets_per_second = self.assets_per_second))]
+    pub async fn run_once(&self) -> SweepReport {
+        let mut report = SweepReport::default();
+        let mut pacer = (self.assets_per_second > 0).then(|| {
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(1) / self.assets_per_second);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            interval
+        });
+
+        let mut offset = 0;
+        loop {
+            let query = SearchQuery::new()
+                .exclude_deprecated(false)
+                .sort_by(SortField::CreatedAt)
+                .sort_order(SortOrder::Ascending)
+                .limit(SWEEP_PAGE_SIZE)
+                .offset(offset);
+            let page = match self.repository.search(&query).await {
+                Ok(results) => results.assets,
+                Err(e) => {
+                    warn!(error = %e, "Integrity sweep aborted");
+                    report.push_error(format!("Failed to list assets: {}", e));
+                    break;
+                }
+            };
+
+            for asset in &page {
+                if let Some(pacer) = pacer.as_mut() {
+                    pacer.tick().await;
+                }
+                self.verify(asset, &mut report).await;
+            }
+
+            offset += page.len() as i64;
+            if (page.len() as i64) < SWEEP_PAGE_SIZE {
+                break;
+            }
+        }
+
+        info!(
+            verified = report.verified,
+            failed = report.failed,
+            skipped = report.skipped,
+            "Integrity sweep finished"
+        );
+
+        report
+    }
+
+    async fn verify(&self, asset: &Asset, report: &mut SweepReport) {
+        let result = self
+            .integrity
+            .verify_content(&asset.id, None, &self.constraints)
+            .await;
+        let checked = match result {
+            Ok(checked) => checked,
+            Err(e) => {
+                debug!(asset_id = %asset.id, error = %e, "Skipping asset");
+                report.skipped += 1;
+                report.push_error(format!(
+                    "Skipped {}@{}: {}",
+                    asset.metadata.name, asset.metadata.version, e
+                ));
+                return;
+            }
+        };
+
+        if let Err(e) = self
+            .observatory
+            .trace_integrity_verification(
+                &checked.asset_id.to_string(),
+                &checked.algorithm.to_string(),
+                checked.verified,
+            )
+            .await
+        {
+            warn!("Failed to emit integrity verification event: {}", e);
+        }
+
+        if checked.verified {
+            report.verified += 1;
+        } else {
+            warn!(asset_id = %asset.id, "Stored content does not match its checksum");
+            report.failed += 1;
+            report.push_failure(checked);
+        }
+    }
 }
 
 /// Utility functions for computing checksums
@@ -261,6 +666,10 @@ pub mod utils {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::GovernanceEvent;
+    use crate::content::InMemoryContentStore;
+    use crate::test_support::{InMemoryRepository, NullEventStore};
+    use llm_registry_core::{AssetMetadata, AssetType, StorageBackend, StorageLocation, Version};
 
     #[test]
     fn test_hash_sha256() {
@@ -284,6 +693,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_select_allowed_algorithm() {
+        let constraints = ValidationConstraints::default();
+
+        for recorded in [HashAlgorithm::SHA256, HashAlgorithm::BLAKE3] {
+            let (algorithm, warning) = select_algorithm(None, recorded, &constraints).unwrap();
+            assert_eq!(algorithm, recorded);
+            assert!(warning.is_none());
+        }
+
+        // Allowed but not implemented by the registry
+        let constraints = ValidationConstraints {
+            allowed_algorithms: vec!["sha512".to_string()],
+            ..ValidationConstraints::default()
+        };
+        let err =
+            select_algorithm(Some("sha512"), HashAlgorithm::SHA256, &constraints).unwrap_err();
+        assert!(matches!(err, ServiceError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_select_disallowed_algorithm_strict() {
+        let constraints = ValidationConstraints {
+            strict_mode: true,
+            ..ValidationConstraints::default()
+        };
+
+        let err = select_algorithm(Some("md5"), HashAlgorithm::SHA256, &constraints).unwrap_err();
+        assert!(matches!(err, ServiceError::ValidationFailed(_)));
+
+        // The recorded algorithm is subject to the same check
+        let err = select_algorithm(None, HashAlgorithm::SHA3_256, &constraints).unwrap_err();
+        assert!(matches!(err, ServiceError::ValidationFailed(_)));
+    }
+
+    #[test]
+    fn test_select_disallowed_algorithm_lenient() {
+        let constraints = ValidationConstraints::default();
+
+        // Falls back to the algorithm the checksum was recorded with
+        let (algorithm, warning) =
+            select_algorithm(Some("md5"), HashAlgorithm::BLAKE3, &constraints).unwrap();
+        assert_eq!(algorithm, HashAlgorithm::BLAKE3);
+        let warning = warning.unwrap();
+        assert_eq!(warning.field, "algorithm");
+        assert!(warning.message.contains("BLAKE3"));
+
+        // A disallowed recorded algorithm is not a fallback
+        for requested in [None, Some("md5"), Some("sha3-256")] {
+            let err =
+                select_algorithm(requested, HashAlgorithm::SHA3_256, &constraints).unwrap_err();
+            assert!(
+                matches!(err, ServiceError::ValidationFailed(_)),
+                "{:?}",
+                requested
+            );
+        }
+    }
+
     #[test]
     fn test_compute_sha256_util() {
         let data = b"test data";
@@ -301,4 +769,149 @@ mod tests {
         let wrong_data = b"wrong data";
         assert!(!utils::verify_data(wrong_data, &checksum));
     }
+
+    /// An asset whose recorded checksum covers `content`
+    fn asset(name: &str, content: &[u8]) -> Asset {
+        let storage = StorageLocation::new(
+            StorageBackend::S3 {
+                bucket: "test".to_string(),
+                region: "us-east-1".to_string(),
+                endpoint: None,
+            },
+            format!("{}.bin", name),
+            None,
+        )
+        .unwrap();
+        let metadata = AssetMetadata::new(name, Version::new(1, 0, 0));
+        let checksum = utils::compute_sha256(content).unwrap();
+        Asset::new(
+            AssetId::new(),
+            AssetType::Model,
+            metadata,
+            storage,
+            checksum,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sweep_reports_tampered_asset() {
+        let content = Arc::new(InMemoryContentStore::new());
+        let mut assets = Vec::new();
+        for name in ["alpha", "beta", "gamma"] {
+            let asset = asset(name, name.as_bytes());
+            content.insert(&asset.storage, name.as_bytes());
+            assets.push(asset);
+        }
+        let tampered = asset("delta", b"delta");
+        content.insert(&tampered.storage, b"tampered".to_vec());
+        assets.push(tampered.clone());
+        // Registered, but its content was never uploaded
+        assets.push(asset("epsilon", b"epsilon"));
+
+        let repository = Arc::new(InMemoryRepository::with_assets(assets));
+        let integrity = Arc::new(
+            DefaultIntegrityService::new(repository.clone(), Arc::new(NullEventStore))
+                .with_content_store(content),
+        );
+        let observatory = Arc::new(ObservatoryAdapter::new("test"));
+        let sweeper =
+            IntegritySweeper::new(repository, integrity, observatory.clone()).with_rate_limit(50);
+
+        let started = std::time::Instant::now();
+        let report = sweeper.run_once().await;
+
+        // Five assets at 50 per second are spaced at least 20ms apart
+        assert!(started.elapsed() >= Duration::from_millis(80));
+        assert_eq!(report.verified, 3);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].asset_id, tampered.id);
+        assert!(report.errors[0].contains("epsilon"));
+
+        let events = observatory.buffered_events().await;
+        assert_eq!(events.len(), 4);
+        let invalid: Vec<&GovernanceEvent> = events
+            .iter()
+            .filter(|e| matches!(e, GovernanceEvent::IntegrityVerified { valid: false, .. }))
+            .collect();
+        assert!(matches!(
+            invalid.as_slice(),
+            [GovernanceEvent::IntegrityVerified { asset_id, .. }]
+                if *asset_id == tampered.id.to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sweep_report_lists_are_capped() {
+        let assets = (0..MAX_SWEEP_REPORT_ENTRIES + 5)
+            .map(|i| asset(&format!("missing-{}", i), b"content"))
+            .collect();
+        let repository = Arc::new(InMemoryRepository::with_assets(assets));
+        let integrity = Arc::new(
+            DefaultIntegrityService::new(repository.clone(), Arc::new(NullEventStore))
+                .with_content_store(Arc::new(InMemoryContentStore::new())),
+        );
+        let sweeper = IntegritySweeper::new(
+            repository,
+            integrity,
+            Arc::new(ObservatoryAdapter::new("test")),
+        )
+        .with_rate_limit(0);
+
+        let report = sweeper.run_once().await;
+
+        assert_eq!(report.skipped, MAX_SWEEP_REPORT_ENTRIES + 5);
+        assert_eq!(report.errors.len(), MAX_SWEEP_REPORT_ENTRIES);
+        assert_eq!(report.truncated, 5);
+    }
+
+    #[tokio::test]
+    async fn test_verify_content_streams_from_filesystem() {
+        use crate::content::FilesystemContentStore;
+
+        let root = tempfile::tempdir().unwrap();
+        // Several read chunks' worth of content
+        let content: Vec<u8> = (0..3 * CONTENT_CHUNK_SIZE + 17).map(|i| i as u8).collect();
+        std::fs::write(root.path().join("model.bin"), &content).unwrap();
+
+        let mut assets = Vec::new();
+        for checksum in [
+            utils::compute_sha256(&content),
+            utils::compute_blake3(&content),
+        ] {
+            let storage = StorageLocation::new(
+                StorageBackend::FileSystem {
+                    base_path: ".".to_string(),
+                },
+                "model.bin".to_string(),
+                None,
+            )
+            .unwrap();
+            let metadata = AssetMetadata::new("model", Version::new(1, 0, 0));
+            assets.push(
+                Asset::new(
+                    AssetId::new(),
+                    AssetType::Model,
+                    metadata,
+                    storage,
+                    checksum.unwrap(),
+                )
+                .unwrap(),
+            );
+        }
+        let repository = Arc::new(InMemoryRepository::with_assets(assets.clone()));
+        let integrity = DefaultIntegrityService::new(repository, Arc::new(NullEventStore))
+            .with_content_store(Arc::new(FilesystemContentStore::new(root.path())));
+
+        for asset in &assets {
+            let report = integrity
+                .verify_content(&asset.id, None, &ValidationConstraints::default())
+                .await
+                .unwrap();
+            assert!(report.verified, "{}", report.algorithm);
+            assert_eq!(report.size_bytes, content.len() as u64);
+        }
+    }
 }
@@ -11,7 +11,11 @@
 //! - **SearchService**: Search and query operations
 //! - **ValidationService**: Schema and policy validation
 //! - **IntegrityService**: Checksum computation and verification
+//! - **IntegritySweeper**: Periodic re-verification of all stored content
 //! - **VersioningService**: Version management and conflict detection
+//! - **SourceRegistrationService**: Registration from Git/OCI manifest references
+//! - **RetentionService**: Per-asset retention overrides and prune previews
+//! - **RetentionEnforcer**: Periodic pruning driven by the global retention rules
 //!
 //! # Example
 //!
@@ -57,33 +61,60 @@
 //! # }
 //! ```
 
+pub mod content;
+pub mod diff;
 pub mod dto;
 pub mod error;
+pub mod expiry;
+pub mod governance;
+pub mod graph_cache;
 pub mod integrity;
 pub mod registration;
+pub mod retention;
 pub mod search;
+pub mod source;
 pub mod validation;
 pub mod versioning;
 
 // LLM-Dev-Ops upstream integration adapters (Phase 2B)
 pub mod adapters;
 
+#[cfg(test)]
+mod test_support;
+
 // Re-export main types for convenience
+pub use content::{ContentReader, ContentStore, FilesystemContentStore, InMemoryContentStore};
+pub use diff::{
+    compare_assets, diff_json, merge_patch, AssetComparison, JsonDiff, TagDiff, ValueChange,
+};
 pub use dto::*;
-pub use error::{ServiceError, ServiceResult};
+pub use error::{RegistryError, ServiceError, ServiceResult};
+pub use governance::{GovernanceContext, GovernanceEventStore};
+pub use graph_cache::{DependencyGraphCache, GraphCacheKey, GraphCacheStats};
 
 // Re-export service traits and implementations
-pub use integrity::{DefaultIntegrityService, IntegrityService};
+pub use integrity::{DefaultIntegrityService, IntegrityService, IntegritySweeper, SweepReport};
+pub use llm_registry_db::{AssetStats, DependentsReindex};
 pub use registration::{DefaultRegistrationService, RegistrationService};
+pub use retention::{
+    DefaultRetentionService, RetentionEnforcer, RetentionOverride, RetentionReport,
+    RetentionService,
+};
 pub use search::{DefaultSearchService, SearchService};
+pub use source::{
+    DefaultSourceFetcher, DefaultSourceRegistrationService, SourceFetcher,
+    SourceRegistrationService,
+};
 pub use validation::{DefaultValidationService, ValidationService};
 pub use versioning::{DefaultVersioningService, VersioningService};
 
 // Re-export upstream adapters for convenience
 pub use adapters::{
-    ConfigManagerAdapter, ObservatoryAdapter, SchemaRegistryAdapter,
+    ConfigManagerAdapter, InMemoryConfigConsumer, InMemorySchemaConsumer, ObservatoryAdapter,
+    SchemaRegistryAdapter,
 };
 
+use adapters::config_manager::{ConfigConsumer, TtlConfig};
 use llm_registry_db::{AssetRepository, EventStore};
 use std::sync::Arc;
 
@@ -103,6 +134,10 @@ pub struct ServiceRegistry {
     pub integrity: Arc<dyn IntegrityService>,
     /// Versioning service
     pub versioning: Arc<dyn VersioningService>,
+    /// Source reference registration service
+    pub source_registration: Arc<dyn SourceRegistrationService>,
+    /// Retention service
+    pub retention: Arc<dyn RetentionService>,
 }
 
 impl ServiceRegistry {
@@ -126,10 +161,10 @@ impl ServiceRegistry {
     /// let services = ServiceRegistry::new(repository, event_store);
     /// # }
     /// ```
-    pub fn new(
-        repository: Arc<dyn AssetRepository>,
-        event_store: Arc<dyn EventStore>,
-    ) -> Self {
+    pub fn new(repository: Arc<dyn AssetRepository>, event_store: Arc<dyn EventStore>) -> Self {
+        // Stamp request governance context onto every emitted event
+        let event_store: Arc<dyn EventStore> = Arc::new(GovernanceEventStore::new(event_store));
+
         // Create shared service instances
         let validation = Arc::new(DefaultValidationService::new(
             repository.clone(),
@@ -146,22 +181,39 @@ impl ServiceRegistry {
             event_store.clone(),
         ));
 
-        let search = Arc::new(DefaultSearchService::new(repository.clone()));
+        // Dependency graphs are cached by search and invalidated by registration
+        let graph_cache = Arc::new(DependencyGraphCache::default());
 
-        let registration = Arc::new(DefaultRegistrationService::new(
-            repository.clone(),
-            event_store.clone(),
-            validation.clone(),
-            integrity.clone(),
-            versioning.clone(),
+        let search = Arc::new(
+            DefaultSearchService::new(repository.clone()).with_graph_cache(graph_cache.clone()),
+        );
+
+        let registration = Arc::new(
+            DefaultRegistrationService::new(
+                repository.clone(),
+                event_store.clone(),
+                validation.clone(),
+                integrity.clone(),
+                versioning.clone(),
+            )
+            .with_graph_cache(graph_cache),
+        );
+
+        let source_registration = Arc::new(DefaultSourceRegistrationService::new(
+            registration.clone(),
+            Arc::new(DefaultSourceFetcher::default()),
         ));
 
+        let retention = Arc::new(DefaultRetentionService::new(repository, event_store));
+
         Self {
             registration,
             search,
             validation,
             integrity,
             versioning,
+            source_registration,
+            retention,
         }
     }
 
@@ -175,13 +227,21 @@ impl ServiceRegistry {
         validation: Arc<dyn ValidationService>,
         integrity: Arc<dyn IntegrityService>,
         versioning: Arc<dyn VersioningService>,
+        retention: Arc<dyn RetentionService>,
     ) -> Self {
+        let source_registration = Arc::new(DefaultSourceRegistrationService::new(
+            registration.clone(),
+            Arc::new(DefaultSourceFetcher::default()),
+        ));
+
         Self {
             registration,
             search,
             validation,
             integrity,
             versioning,
+            source_registration,
+            retention,
         }
     }
 
@@ -209,6 +269,16 @@ impl ServiceRegistry {
     pub fn versioning(&self) -> &Arc<dyn VersioningService> {
         &self.versioning
     }
+
+    /// Get the source reference registration service
+    pub fn source_registration(&self) -> &Arc<dyn SourceRegistrationService> {
+        &self.source_registration
+    }
+
+    /// Get the retention service
+    pub fn retention(&self) -> &Arc<dyn RetentionService> {
+        &self.retention
+    }
 }
 
 /// Builder for ServiceRegistry with custom configuration
@@ -220,6 +290,13 @@ pub struct ServiceRegistryBuilder {
     versioning: Option<Arc<dyn VersioningService>>,
     search: Option<Arc<dyn SearchService>>,
     registration: Option<Arc<dyn RegistrationService>>,
+    retention: Option<Arc<dyn RetentionService>>,
+    source_fetcher: Option<Arc<dyn SourceFetcher>>,
+    content_store: Option<Arc<dyn ContentStore>>,
+    ttl: Option<TtlConfig>,
+    max_graph_nodes: Option<usize>,
+    config: Option<Arc<dyn ConfigConsumer>>,
+    restore_grace: Option<chrono::Duration>,
 }
 
 impl ServiceRegistryBuilder {
@@ -233,6 +310,13 @@ impl ServiceRegistryBuilder {
             versioning: None,
             search: None,
             registration: None,
+            retention: None,
+            source_fetcher: None,
+            content_store: None,
+            ttl: None,
+            max_graph_nodes: None,
+            config: None,
+            restore_grace: None,
         }
     }
 
@@ -278,6 +362,50 @@ impl ServiceRegistryBuilder {
         self
     }
 
+    /// Set a custom retention service
+    pub fn retention_service(mut self, service: Arc<dyn RetentionService>) -> Self {
+        self.retention = Some(service);
+        self
+    }
+
+    /// Set a custom fetcher for Git/OCI source references
+    pub fn source_fetcher(mut self, fetcher: Arc<dyn SourceFetcher>) -> Self {
+        self.source_fetcher = Some(fetcher);
+        self
+    }
+
+    /// Set the store the default integrity service reads asset content from
+    pub fn content_store(mut self, store: Arc<dyn ContentStore>) -> Self {
+        self.content_store = Some(store);
+        self
+    }
+
+    /// Set the asset TTLs used by the default search service
+    pub fn ttl_config(mut self, ttl: TtlConfig) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Set the dependency graph node cap used by the default search service
+    pub fn max_graph_nodes(mut self, max: usize) -> Self {
+        self.max_graph_nodes = Some(max);
+        self
+    }
+
+    /// Set the config source whose validation constraints and retention rules
+    /// the default registration and retention services apply
+    pub fn config(mut self, config: Arc<dyn ConfigConsumer>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Set how long the default registration service keeps soft-deleted
+    /// assets restorable
+    pub fn restore_grace(mut self, grace: chrono::Duration) -> Self {
+        self.restore_grace = Some(grace);
+        self
+    }
+
     /// Build the service registry
     ///
     /// This will create default implementations for any services not explicitly set.
@@ -289,6 +417,7 @@ impl ServiceRegistryBuilder {
     pub fn build(self) -> Result<ServiceRegistry, String> {
         let repository = self.repository.ok_or("Repository is required")?;
         let event_store = self.event_store.ok_or("Event store is required")?;
+        let event_store: Arc<dyn EventStore> = Arc::new(GovernanceEventStore::new(event_store));
 
         // Create or use provided services
         let validation = self.validation.unwrap_or_else(|| {
@@ -298,11 +427,13 @@ impl ServiceRegistryBuilder {
             ))
         });
 
+        let content_store = self.content_store;
         let integrity = self.integrity.unwrap_or_else(|| {
-            Arc::new(DefaultIntegrityService::new(
-                repository.clone(),
-                event_store.clone(),
-            ))
+            let service = DefaultIntegrityService::new(repository.clone(), event_store.clone());
+            match content_store {
+                Some(store) => Arc::new(service.with_content_store(store)),
+                None => Arc::new(service),
+            }
         });
 
         let versioning = self.versioning.unwrap_or_else(|| {
@@ -312,18 +443,63 @@ impl ServiceRegistryBuilder {
             ))
         });
 
-        let search = self
-            .search
-            .unwrap_or_else(|| Arc::new(DefaultSearchService::new(repository.clone())));
+        // Graph caching is only safe when the default registration service
+        // is there to invalidate what the default search service caches
+        let graph_cache = (self.search.is_none() && self.registration.is_none())
+            .then(|| Arc::new(DependencyGraphCache::default()));
+
+        let ttl = self.ttl;
+        let max_graph_nodes = self.max_graph_nodes;
+        let search = self.search.unwrap_or_else(|| {
+            let mut service = DefaultSearchService::new(repository.clone());
+            if let Some(ttl) = ttl {
+                service = service.with_ttl_config(ttl);
+            }
+            if let Some(max) = max_graph_nodes {
+                service = service.with_max_graph_nodes(max);
+            }
+            match graph_cache.clone() {
+                Some(cache) => Arc::new(service.with_graph_cache(cache)),
+                None => Arc::new(service),
+            }
+        });
 
+        let config = self.config;
+        let restore_grace = self.restore_grace;
         let registration = self.registration.unwrap_or_else(|| {
-            Arc::new(DefaultRegistrationService::new(
+            let mut service = DefaultRegistrationService::new(
                 repository.clone(),
                 event_store.clone(),
                 validation.clone(),
                 integrity.clone(),
                 versioning.clone(),
-            ))
+            );
+            if let Some(config) = config.clone() {
+                service = service.with_config(config);
+            }
+            if let Some(grace) = restore_grace {
+                service = service.with_restore_grace(grace);
+            }
+            match graph_cache {
+                Some(cache) => Arc::new(service.with_graph_cache(cache)),
+                None => Arc::new(service),
+            }
+        });
+
+        let source_fetcher = self
+            .source_fetcher
+            .unwrap_or_else(|| Arc::new(DefaultSourceFetcher::default()));
+        let source_registration = Arc::new(DefaultSourceRegistrationService::new(
+            registration.clone(),
+            source_fetcher,
+        ));
+
+        let retention = self.retention.unwrap_or_else(|| {
+            let service = DefaultRetentionService::new(repository.clone(), event_store.clone());
+            match config {
+                Some(config) => Arc::new(service.with_config(config)),
+                None => Arc::new(service),
+            }
         });
 
         Ok(ServiceRegistry {
@@ -332,6 +508,8 @@ impl ServiceRegistryBuilder {
             validation,
             integrity,
             versioning,
+            source_registration,
+            retention,
         })
     }
 }
@@ -5,38 +5,187 @@
 
 use async_trait::async_trait;
 use llm_registry_core::{
-    Asset, AssetId, AssetMetadata, DependencyGraph, EventType, RegistryEvent,
+    Asset, AssetId, AssetMetadata, AssetStatus, DependencyGraph, EventType, RegistryEvent,
 };
-use llm_registry_db::{AssetRepository, EventStore};
-use std::sync::Arc;
+use llm_registry_db::{AssetRepository, DependentsReindex, EventStore};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tokio::sync::OwnedMutexGuard;
 use tracing::{debug, info, instrument, warn};
 
+use crate::adapters::config_manager::{ConfigConsumer, ValidationConstraints};
+use crate::diff::merge_patch;
 use crate::dto::{
-    RegisterAssetRequest, RegisterAssetResponse, UpdateAssetRequest, UpdateAssetResponse,
-    ValidateAssetRequest, ValidationResult,
+    DeprecateAssetRequest, RegisterAssetRequest, RegisterAssetResponse, UpdateAssetRequest,
+    UpdateAssetResponse, ValidateAssetRequest, ValidationResult,
 };
 use crate::error::{ServiceError, ServiceResult};
+use crate::graph_cache::DependencyGraphCache;
 use crate::integrity::IntegrityService;
-use crate::validation::ValidationService;
+use crate::validation::{label_errors, ValidationService};
 use crate::versioning::VersioningService;
 
+/// Annotation recording why an asset was deprecated
+pub const DEPRECATION_REASON_ANNOTATION: &str = "registry.deprecation.reason";
+
+/// Annotation recording the asset that supersedes a deprecated one
+pub const SUPERSEDED_BY_ANNOTATION: &str = "registry.deprecation.superseded_by";
+
+/// Default period during which a soft-deleted asset can be restored
+pub const DEFAULT_RESTORE_GRACE_DAYS: i64 = 30;
+
 /// Trait for asset registration operations
 #[async_trait]
 pub trait RegistrationService: Send + Sync {
     /// Register a new asset with full validation
-    async fn register_asset(&self, request: RegisterAssetRequest) -> ServiceResult<RegisterAssetResponse>;
+    async fn register_asset(
+        &self,
+        request: RegisterAssetRequest,
+    ) -> ServiceResult<RegisterAssetResponse>;
 
     /// Update an existing asset
-    async fn update_asset(&self, request: UpdateAssetRequest) -> ServiceResult<UpdateAssetResponse>;
+    async fn update_asset(&self, request: UpdateAssetRequest)
+        -> ServiceResult<UpdateAssetResponse>;
+
+    /// Apply an RFC 7386 JSON Merge Patch to an asset's metadata
+    ///
+    /// `name` and `version` identify the asset and cannot be patched. The
+    /// patched metadata must satisfy `constraints`, and required fields
+    /// cannot be deleted.
+    async fn merge_patch_metadata(
+        &self,
+        asset_id: &AssetId,
+        patch: &serde_json::Value,
+        expected_version: Option<&str>,
+        constraints: &ValidationConstraints,
+    ) -> ServiceResult<UpdateAssetResponse>;
 
-    /// Delete an asset
+    /// Soft-delete an asset; it stays restorable for the restore grace window
     async fn delete_asset(&self, asset_id: &AssetId) -> ServiceResult<()>;
 
+    /// Permanently delete an asset, whether or not it was soft-deleted
+    async fn purge_asset(&self, asset_id: &AssetId) -> ServiceResult<()>;
+
+    /// Undo a soft delete within the restore grace window
+    async fn restore_asset(&self, asset_id: &AssetId) -> ServiceResult<Asset>;
+
+    /// How long a soft-deleted asset stays restorable
+    fn restore_grace(&self) -> chrono::Duration;
+
+    /// Mark an asset deprecated while keeping it queryable
+    async fn deprecate_asset(
+        &self,
+        asset_id: &AssetId,
+        request: DeprecateAssetRequest,
+    ) -> ServiceResult<Asset>;
+
+    /// Add dependencies on existing assets, all or none
+    ///
+    /// Fails with the first target that is missing or would close a cycle.
+    async fn add_dependencies(
+        &self,
+        asset_id: &AssetId,
+        dependency_ids: &[AssetId],
+    ) -> ServiceResult<Asset>;
+
+    /// Drop a single dependency edge; `NotFound` if the edge does not exist
+    async fn remove_dependency(
+        &self,
+        asset_id: &AssetId,
+        dependency_id: &AssetId,
+    ) -> ServiceResult<()>;
+
+    /// Rebuild the dependents index from the assets' forward edges
+    ///
+    /// Idempotent; meant for repairing drift after imports that bypassed
+    /// registration. Also drops every cached dependency graph.
+    async fn reindex_dependents(&self) -> ServiceResult<DependentsReindex>;
+
     /// Validate dependencies before registration
-    async fn validate_dependencies(&self, dependencies: &[llm_registry_core::AssetReference]) -> ServiceResult<ValidationResult>;
+    async fn validate_dependencies(
+        &self,
+        dependencies: &[llm_registry_core::AssetReference],
+    ) -> ServiceResult<ValidationResult>;
 
     /// Check for circular dependencies
-    async fn check_circular_dependencies(&self, asset_id: &AssetId, dependencies: &[llm_registry_core::AssetReference]) -> ServiceResult<()>;
+    async fn check_circular_dependencies(
+        &self,
+        asset_id: &AssetId,
+        dependencies: &[llm_registry_core::AssetReference],
+    ) -> ServiceResult<()>;
+}
+
+/// Reject a stale update whose expected entity tag no longer matches
+///
+/// `*` matches any version.
+fn check_expected_version(asset: &Asset, expected: Option<&str>) -> ServiceResult<()> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+    let current = asset.etag();
+    if expected != "*" && expected != current {
+        return Err(ServiceError::PreconditionFailed(format!(
+            "asset {} is at version {}, expected {}",
+            asset.id, current, expected
+        )));
+    }
+    Ok(())
+}
+
+/// Reject labels that cannot be expressed in a label selector
+fn check_labels(metadata: &AssetMetadata) -> ServiceResult<()> {
+    match label_errors(&metadata.labels).into_iter().next() {
+        Some(error) => Err(ServiceError::ValidationFailed(format!(
+            "{}: {}",
+            error.field, error.message
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Async locks keyed by `name@version`, dropped once nobody holds or awaits them
+///
+/// Serializes the duplicate check and insert of concurrent registrations of
+/// the same asset version within this process.
+#[derive(Default)]
+struct RegistrationLocks {
+    locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl RegistrationLocks {
+    async fn acquire(&self, key: String) -> RegistrationGuard<'_> {
+        let lock = self
+            .locks
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+        RegistrationGuard {
+            locks: self,
+            key,
+            _guard: lock.lock_owned().await,
+        }
+    }
+}
+
+struct RegistrationGuard<'a> {
+    locks: &'a RegistrationLocks,
+    key: String,
+    _guard: OwnedMutexGuard<()>,
+}
+
+impl Drop for RegistrationGuard<'_> {
+    fn drop(&mut self) {
+        let mut locks = self.locks.locks.lock().unwrap();
+        // Only the map and this guard still reference the lock
+        if locks
+            .get(&self.key)
+            .is_some_and(|lock| Arc::strong_count(lock) == 2)
+        {
+            locks.remove(&self.key);
+        }
+    }
 }
 
 /// Default implementation of RegistrationService
@@ -46,6 +195,10 @@ pub struct DefaultRegistrationService {
     validation_service: Arc<dyn ValidationService>,
     integrity_service: Arc<dyn IntegrityService>,
     versioning_service: Arc<dyn VersioningService>,
+    graph_cache: Option<Arc<DependencyGraphCache>>,
+    config: Option<Arc<dyn ConfigConsumer>>,
+    restore_grace: chrono::Duration,
+    registration_locks: RegistrationLocks,
 }
 
 impl DefaultRegistrationService {
@@ -63,6 +216,88 @@ impl DefaultRegistrationService {
             validation_service,
             integrity_service,
             versioning_service,
+            graph_cache: None,
+            config: None,
+            restore_grace: chrono::Duration::days(DEFAULT_RESTORE_GRACE_DAYS),
+            registration_locks: RegistrationLocks::default(),
+        }
+    }
+
+    /// Set how long soft-deleted assets can be restored
+    pub fn with_restore_grace(mut self, grace: chrono::Duration) -> Self {
+        self.restore_grace = grace;
+        self
+    }
+
+    /// Fetch an asset that exists and is not soft-deleted
+    async fn find_live(&self, asset_id: &AssetId) -> ServiceResult<Asset> {
+        self.repository
+            .find_by_id(asset_id)
+            .await?
+            .filter(|asset| !asset.is_deleted())
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))
+    }
+
+    /// Persist an update checked against an expected entity tag
+    ///
+    /// With a concrete expected version the write only lands if the asset
+    /// still carries the `updated_at` it was read with, so two concurrent
+    /// writers cannot both pass the same precondition.
+    async fn persist_update(
+        &self,
+        asset: Asset,
+        expected_version: Option<&str>,
+        read_at: chrono::DateTime<chrono::Utc>,
+    ) -> ServiceResult<Asset> {
+        let updated = match expected_version {
+            Some(expected) if expected != "*" => {
+                self.repository.update_if_unmodified(asset, read_at).await?
+            }
+            _ => self.repository.update(asset).await?,
+        };
+        Ok(updated)
+    }
+
+    /// Refuse to delete an asset other assets depend on
+    async fn ensure_no_dependents(&self, asset_id: &AssetId) -> ServiceResult<()> {
+        let dependents = self.repository.list_reverse_dependencies(asset_id).await?;
+        if !dependents.is_empty() {
+            return Err(ServiceError::NotPermitted(format!(
+                "Cannot delete asset: {} other assets depend on it",
+                dependents.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Invalidate cached dependency graphs when assets change
+    pub fn with_graph_cache(mut self, cache: Arc<DependencyGraphCache>) -> Self {
+        self.graph_cache = Some(cache);
+        self
+    }
+
+    /// Enforce the validation constraints served by `config` on registration
+    pub fn with_config(mut self, config: Arc<dyn ConfigConsumer>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Validation constraints from the config source, or the defaults without one
+    async fn validation_constraints(&self) -> ValidationConstraints {
+        match self.config {
+            Some(ref config) => config
+                .get_validation_constraints()
+                .await
+                .unwrap_or_default(),
+            None => ValidationConstraints::default(),
+        }
+    }
+
+    /// Drop cached dependency graphs containing the given asset
+    fn invalidate_graphs(&self, asset_id: &AssetId) {
+        if let Some(ref cache) = self.graph_cache {
+            let invalidated = cache.invalidate(asset_id);
+            debug!(%asset_id, invalidated, "Invalidated cached dependency graphs");
         }
     }
 
@@ -79,7 +314,12 @@ impl DefaultRegistrationService {
         }
 
         builder = builder.tags(request.tags.clone());
-        builder = builder.annotations(request.annotations.clone());
+        let mut annotations = request.annotations.clone();
+        if let Some(ref retention_override) = request.retention_override {
+            retention_override.apply_to(&mut annotations);
+        }
+        builder = builder.annotations(annotations);
+        builder = builder.labels(request.labels.clone());
 
         if let Some(size) = request.size_bytes {
             builder = builder.size_bytes(size);
@@ -89,9 +329,9 @@ impl DefaultRegistrationService {
             builder = builder.content_type(ct.clone());
         }
 
-        builder.build().map_err(|e| {
-            ServiceError::ValidationFailed(format!("Invalid metadata: {}", e))
-        })
+        builder
+            .build()
+            .map_err(|e| ServiceError::ValidationFailed(format!("Invalid metadata: {}", e)))
     }
 
     /// Emit asset registered event
@@ -134,6 +374,19 @@ impl DefaultRegistrationService {
         }
     }
 
+    /// Emit asset restored event
+    async fn emit_restored_event(&self, asset: &Asset) {
+        let event = RegistryEvent::new(EventType::AssetRestored {
+            asset_id: asset.id,
+            asset_name: asset.metadata.name.clone(),
+            asset_version: asset.metadata.version.to_string(),
+        });
+
+        if let Err(e) = self.event_store.append(event).await {
+            warn!("Failed to emit asset restored event: {}", e);
+        }
+    }
+
     /// Validate asset before registration
     async fn validate_for_registration(&self, asset: &Asset) -> ServiceResult<Vec<String>> {
         let mut warnings = Vec::new();
@@ -145,7 +398,10 @@ impl DefaultRegistrationService {
             policies: vec![],
         };
 
-        let validation_result = self.validation_service.validate_asset(validation_request).await?;
+        let validation_result = self
+            .validation_service
+            .validate_asset(validation_request)
+            .await?;
 
         if !validation_result.valid {
             return Err(ServiceError::ValidationFailed(format!(
@@ -163,8 +419,16 @@ impl DefaultRegistrationService {
     }
 
     /// Check if asset already exists
-    async fn check_duplicate(&self, name: &str, version: &semver::Version) -> ServiceResult<()> {
-        if let Some(_existing) = self.repository.find_by_name_and_version(name, version).await? {
+    async fn check_duplicate(
+        &self,
+        name: &str,
+        version: &llm_registry_core::Version,
+    ) -> ServiceResult<()> {
+        if let Some(_existing) = self
+            .repository
+            .find_by_name_and_version(name, version)
+            .await?
+        {
             return Err(ServiceError::AlreadyExists {
                 name: name.to_string(),
                 version: version.to_string(),
@@ -177,19 +441,31 @@ impl DefaultRegistrationService {
 #[async_trait]
 impl RegistrationService for DefaultRegistrationService {
     #[instrument(skip(self, request), fields(name = %request.name, version = %request.version))]
-    async fn register_asset(&self, request: RegisterAssetRequest) -> ServiceResult<RegisterAssetResponse> {
+    async fn register_asset(
+        &self,
+        request: RegisterAssetRequest,
+    ) -> ServiceResult<RegisterAssetResponse> {
         info!("Registering asset: {}@{}", request.name, request.version);
 
+        // Hold the name@version lock until the asset is persisted so a
+        // concurrent registration sees it in its duplicate check
+        let _registration_guard = self
+            .registration_locks
+            .acquire(format!("{}@{}", request.name, request.version))
+            .await;
+
         // Check for duplicate
-        self.check_duplicate(&request.name, &request.version).await?;
+        self.check_duplicate(&request.name, &request.version)
+            .await?;
 
         // Build metadata
         let metadata = self.build_metadata(&request)?;
 
         // Validate asset type
-        request.asset_type.validate().map_err(|e| {
-            ServiceError::ValidationFailed(format!("Invalid asset type: {}", e))
-        })?;
+        request
+            .asset_type
+            .validate()
+            .map_err(|e| ServiceError::ValidationFailed(format!("Invalid asset type: {}", e)))?;
 
         // Build the asset
         let mut asset_builder = Asset::builder(
@@ -205,19 +481,41 @@ impl RegistrationService for DefaultRegistrationService {
 
         asset_builder = asset_builder.dependencies(request.dependencies.clone());
 
-        let asset = asset_builder.build().map_err(|e| {
-            ServiceError::ValidationFailed(format!("Failed to build asset: {}", e))
-        })?;
+        let asset = asset_builder
+            .build()
+            .map_err(|e| ServiceError::ValidationFailed(format!("Failed to build asset: {}", e)))?;
+
+        // Enforce the configured limits for this asset type
+        self.validation_constraints()
+            .await
+            .for_type(&(&asset.asset_type).into())
+            .check_metadata(&asset.metadata)
+            .map_err(ServiceError::ValidationFailed)?;
 
         // Validate dependencies
         if !asset.dependencies.is_empty() {
             self.validate_dependencies(&asset.dependencies).await?;
-            self.check_circular_dependencies(&asset.id, &asset.dependencies).await?;
+            self.check_circular_dependencies(&asset.id, &asset.dependencies)
+                .await?;
         }
 
         // Full validation
         let warnings = self.validate_for_registration(&asset).await?;
 
+        // A dry run stops once validation has passed: nothing is persisted
+        // and no events are emitted
+        if request.dry_run == Some(true) {
+            info!(
+                "Dry run passed validation: {}@{}",
+                request.name, request.version
+            );
+            return Ok(RegisterAssetResponse {
+                asset,
+                warnings,
+                dry_run: true,
+            });
+        }
+
         // Persist the asset
         let created = self.repository.create(asset).await?;
 
@@ -252,19 +550,23 @@ impl RegistrationService for DefaultRegistrationService {
         Ok(RegisterAssetResponse {
             asset: created,
             warnings,
+            dry_run: false,
         })
     }
 
     #[instrument(skip(self, request), fields(asset_id = %request.asset_id))]
-    async fn update_asset(&self, request: UpdateAssetRequest) -> ServiceResult<UpdateAssetResponse> {
+    async fn update_asset(
+        &self,
+        request: UpdateAssetRequest,
+    ) -> ServiceResult<UpdateAssetResponse> {
         debug!("Updating asset: {}", request.asset_id);
 
         // Fetch existing asset
-        let mut asset = self
-            .repository
-            .find_by_id(&request.asset_id)
-            .await?
-            .ok_or_else(|| ServiceError::NotFound(request.asset_id.to_string()))?;
+        let mut asset = self.find_live(&request.asset_id).await?;
+
+        // Reject stale updates
+        check_expected_version(&asset, request.expected_version.as_deref())?;
+        let read_at = asset.updated_at;
 
         let mut updated_fields = Vec::new();
 
@@ -288,10 +590,12 @@ impl RegistrationService for DefaultRegistrationService {
             }
         }
 
-        // Remove tags
+        // Remove tags (absent tags are a no-op)
         for tag in request.remove_tags {
-            asset.metadata.tags.retain(|t| t != &tag);
-            updated_fields.push("tags".to_string());
+            if asset.metadata.has_tag(&tag) {
+                asset.metadata.tags.retain(|t| t != &tag);
+                updated_fields.push("tags".to_string());
+            }
         }
 
         // Add/update annotations
@@ -300,12 +604,28 @@ impl RegistrationService for DefaultRegistrationService {
             updated_fields.push("annotations".to_string());
         }
 
-        // Remove annotations
+        // Remove annotations (absent keys are a no-op)
         for key in request.remove_annotations {
-            asset.metadata.annotations.remove(&key);
-            updated_fields.push("annotations".to_string());
+            if asset.metadata.annotations.remove(&key).is_some() {
+                updated_fields.push("annotations".to_string());
+            }
+        }
+
+        // Add/update labels
+        for (key, value) in request.add_labels {
+            asset.metadata.labels.insert(key, value);
+            updated_fields.push("labels".to_string());
         }
 
+        // Remove labels (absent keys are a no-op)
+        for key in request.remove_labels {
+            if asset.metadata.labels.remove(&key).is_some() {
+                updated_fields.push("labels".to_string());
+            }
+        }
+
+        updated_fields.dedup();
+
         // Update status
         if let Some(status) = request.status {
             asset.set_status(status);
@@ -319,12 +639,124 @@ impl RegistrationService for DefaultRegistrationService {
         asset.validate().map_err(|e| {
             ServiceError::ValidationFailed(format!("Updated asset is invalid: {}", e))
         })?;
+        check_labels(&asset.metadata)?;
+        self.validation_constraints()
+            .await
+            .for_type(&(&asset.asset_type).into())
+            .check_metadata(&asset.metadata)
+            .map_err(ServiceError::ValidationFailed)?;
 
         // Persist the update
-        let updated = self.repository.update(asset).await?;
+        let updated = self
+            .persist_update(asset, request.expected_version.as_deref(), read_at)
+            .await?;
+        self.invalidate_graphs(&updated.id);
 
         // Emit update event
-        self.emit_updated_event(&updated, updated_fields.clone()).await;
+        self.emit_updated_event(&updated, updated_fields.clone())
+            .await;
+
+        Ok(UpdateAssetResponse {
+            asset: updated,
+            updated_fields,
+        })
+    }
+
+    #[instrument(skip(self, patch, constraints), fields(asset_id = %asset_id))]
+    async fn merge_patch_metadata(
+        &self,
+        asset_id: &AssetId,
+        patch: &serde_json::Value,
+        expected_version: Option<&str>,
+        constraints: &ValidationConstraints,
+    ) -> ServiceResult<UpdateAssetResponse> {
+        debug!("Merge-patching asset metadata");
+
+        if !patch.is_object() {
+            return Err(ServiceError::InvalidInput(
+                "A metadata merge patch must be a JSON object".to_string(),
+            ));
+        }
+
+        let mut asset = self.find_live(asset_id).await?;
+        check_expected_version(&asset, expected_version)?;
+        let read_at = asset.updated_at;
+        let constraints = constraints.for_type(&(&asset.asset_type).into());
+
+        let before = serde_json::to_value(&asset.metadata)
+            .map_err(|e| ServiceError::Internal(format!("Failed to serialize metadata: {}", e)))?;
+        let mut after = before.clone();
+        merge_patch(&mut after, patch);
+
+        let metadata: AssetMetadata = serde_json::from_value(after.clone()).map_err(|e| {
+            ServiceError::ValidationFailed(format!("Patched metadata is invalid: {}", e))
+        })?;
+        if metadata.name != asset.metadata.name || metadata.version != asset.metadata.version {
+            return Err(ServiceError::ValidationFailed(
+                "Asset name and version cannot be patched".to_string(),
+            ));
+        }
+
+        // Fields the metadata cannot hold would otherwise be dropped silently
+        let normalized = serde_json::to_value(&metadata).unwrap_or_default();
+        let dropped = |key: &&String| {
+            after.get(key.as_str()).is_some_and(|value| {
+                let empty = value.as_array().is_some_and(Vec::is_empty)
+                    || value.as_object().is_some_and(serde_json::Map::is_empty);
+                !empty && normalized.get(key.as_str()).is_none()
+            })
+        };
+        if let Some(unknown) = patch
+            .as_object()
+            .into_iter()
+            .flat_map(|p| p.keys())
+            .find(dropped)
+        {
+            return Err(ServiceError::ValidationFailed(format!(
+                "Unknown metadata field: {}",
+                unknown
+            )));
+        }
+
+        if let Some(field) = constraints
+            .required_fields
+            .iter()
+            .find(|field| before.get(field).is_some() && after.get(field).is_none())
+        {
+            return Err(ServiceError::ValidationFailed(format!(
+                "Required field {} cannot be removed",
+                field
+            )));
+        }
+        constraints
+            .check_metadata(&metadata)
+            .map_err(ServiceError::ValidationFailed)?;
+        check_labels(&metadata)?;
+
+        let fields: BTreeSet<&String> = before
+            .as_object()
+            .into_iter()
+            .chain(after.as_object())
+            .flat_map(serde_json::Map::keys)
+            .collect();
+        let updated_fields: Vec<String> = fields
+            .into_iter()
+            .filter(|field| before.get(field) != after.get(field))
+            .cloned()
+            .collect();
+
+        asset.metadata = metadata;
+        asset.updated_at = chrono::Utc::now();
+        asset.validate().map_err(|e| {
+            ServiceError::ValidationFailed(format!("Updated asset is invalid: {}", e))
+        })?;
+
+        let updated = self
+            .persist_update(asset, expected_version, read_at)
+            .await?;
+        self.invalidate_graphs(&updated.id);
+        self.emit_updated_event(&updated, updated_fields.clone())
+            .await;
 
         Ok(UpdateAssetResponse {
             asset: updated,
@@ -334,7 +766,27 @@ impl RegistrationService for DefaultRegistrationService {
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
     async fn delete_asset(&self, asset_id: &AssetId) -> ServiceResult<()> {
-        debug!("Deleting asset: {}", asset_id);
+        debug!("Soft-deleting asset: {}", asset_id);
+
+        let mut asset = self.find_live(asset_id).await?;
+        self.ensure_no_dependents(asset_id).await?;
+
+        let now = chrono::Utc::now();
+        asset.deleted_at = Some(now);
+        asset.updated_at = now;
+        let deleted = self.repository.update(asset).await?;
+        self.invalidate_graphs(asset_id);
+
+        self.emit_deleted_event(&deleted).await;
+
+        info!("Asset soft-deleted: {}", asset_id);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn purge_asset(&self, asset_id: &AssetId) -> ServiceResult<()> {
+        debug!("Purging asset: {}", asset_id);
 
         // Fetch the asset first for event emission
         let asset = self
@@ -343,28 +795,248 @@ impl RegistrationService for DefaultRegistrationService {
             .await?
             .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
 
-        // Check if any assets depend on this one
-        let dependents = self.repository.list_reverse_dependencies(asset_id).await?;
-        if !dependents.is_empty() {
+        self.ensure_no_dependents(asset_id).await?;
+
+        self.repository.delete(asset_id).await?;
+        self.invalidate_graphs(asset_id);
+
+        self.emit_deleted_event(&asset).await;
+
+        info!("Asset purged: {}", asset_id);
+
+        Ok(())
+    }
+
+    fn restore_grace(&self) -> chrono::Duration {
+        self.restore_grace
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn restore_asset(&self, asset_id: &AssetId) -> ServiceResult<Asset> {
+        debug!("Restoring asset: {}", asset_id);
+
+        let mut asset = self
+            .repository
+            .find_by_id(asset_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
+
+        let Some(deleted_at) = asset.deleted_at else {
+            return Err(ServiceError::InvalidInput(format!(
+                "Asset {} is not deleted",
+                asset_id
+            )));
+        };
+        let now = chrono::Utc::now();
+        if now - deleted_at > self.restore_grace {
             return Err(ServiceError::NotPermitted(format!(
-                "Cannot delete asset: {} other assets depend on it",
-                dependents.len()
+                "Asset {} was deleted more than {} days ago and can no longer be restored",
+                asset_id,
+                self.restore_grace.num_days()
             )));
         }
 
-        // Delete from repository
-        self.repository.delete(asset_id).await?;
+        asset.deleted_at = None;
+        asset.updated_at = now;
+        let restored = self.repository.update(asset).await?;
+        self.invalidate_graphs(asset_id);
 
-        // Emit deletion event
-        self.emit_deleted_event(&asset).await;
+        self.emit_restored_event(&restored).await;
+
+        info!("Asset restored: {}", asset_id);
+
+        Ok(restored)
+    }
+
+    #[instrument(skip(self, request), fields(asset_id = %asset_id))]
+    async fn deprecate_asset(
+        &self,
+        asset_id: &AssetId,
+        request: DeprecateAssetRequest,
+    ) -> ServiceResult<Asset> {
+        debug!("Deprecating asset: {}", asset_id);
+
+        let reason = request.reason.trim();
+        if reason.is_empty() {
+            return Err(ServiceError::InvalidInput(
+                "Deprecation reason must not be empty".to_string(),
+            ));
+        }
+
+        let mut asset = self.find_live(asset_id).await?;
+
+        if let Some(successor) = request.superseded_by {
+            if successor == *asset_id {
+                return Err(ServiceError::InvalidInput(
+                    "An asset cannot supersede itself".to_string(),
+                ));
+            }
+            match self.find_live(&successor).await {
+                Ok(_) => {}
+                Err(ServiceError::NotFound(_)) => {
+                    return Err(ServiceError::InvalidInput(format!(
+                        "superseded_by asset {} does not exist",
+                        successor
+                    )));
+                }
+                Err(e) => return Err(e),
+            }
+            asset
+                .metadata
+                .add_annotation(SUPERSEDED_BY_ANNOTATION, successor.to_string());
+        } else {
+            asset.metadata.annotations.remove(SUPERSEDED_BY_ANNOTATION);
+        }
+        asset
+            .metadata
+            .add_annotation(DEPRECATION_REASON_ANNOTATION, reason);
+
+        let old_status = asset.status;
+        asset.set_status(AssetStatus::Deprecated);
+        asset.updated_at = chrono::Utc::now();
+
+        let updated = self.repository.update(asset).await?;
+        self.invalidate_graphs(&updated.id);
+
+        let event = RegistryEvent::new(EventType::AssetStatusChanged {
+            asset_id: updated.id,
+            asset_name: updated.metadata.name.clone(),
+            old_status,
+            new_status: AssetStatus::Deprecated,
+        });
+        if let Err(e) = self.event_store.append(event).await {
+            warn!("Failed to emit asset status changed event: {}", e);
+        }
+
+        info!("Asset deprecated: {}", asset_id);
+
+        Ok(updated)
+    }
+
+    #[instrument(skip(self, dependency_ids), fields(asset_id = %asset_id, count = dependency_ids.len()))]
+    async fn add_dependencies(
+        &self,
+        asset_id: &AssetId,
+        dependency_ids: &[AssetId],
+    ) -> ServiceResult<Asset> {
+        debug!("Adding dependencies to asset: {}", asset_id);
+
+        let asset = self.find_live(asset_id).await?;
 
-        info!("Asset deleted successfully: {}", asset_id);
+        // Validate every target before recording any edge
+        let mut added: Vec<AssetId> = Vec::new();
+        for dep_id in dependency_ids {
+            let existing = asset.dependencies.iter().any(|d| d.as_id() == Some(dep_id));
+            if existing || added.contains(dep_id) {
+                continue;
+            }
+
+            match self.find_live(dep_id).await {
+                Ok(_) => {}
+                Err(ServiceError::NotFound(_)) => {
+                    return Err(ServiceError::DependencyNotFound(dep_id.to_string()));
+                }
+                Err(e) => return Err(e),
+            }
+
+            let reference = [llm_registry_core::AssetReference::by_id(*dep_id)];
+            match self.check_circular_dependencies(asset_id, &reference).await {
+                Ok(()) => {}
+                Err(ServiceError::CircularDependency(cycle)) => {
+                    debug!(dependency_id = %dep_id, "Rejected dependency: {}", cycle);
+                    return Err(ServiceError::CircularDependency(dep_id.to_string()));
+                }
+                Err(e) => return Err(e),
+            }
+
+            added.push(*dep_id);
+        }
+
+        if !added.is_empty() {
+            self.repository.add_dependencies(asset_id, &added).await?;
+            self.invalidate_graphs(asset_id);
+
+            for dep_id in &added {
+                let event = RegistryEvent::new(EventType::DependencyAdded {
+                    asset_id: *asset_id,
+                    dependency_id: Some(*dep_id),
+                    dependency_name: None,
+                });
+                if let Err(e) = self.event_store.append(event).await {
+                    warn!("Failed to emit dependency added event: {}", e);
+                }
+            }
+            info!("Added {} dependencies to asset {}", added.len(), asset_id);
+        }
+
+        self.find_live(asset_id).await
+    }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id, dependency_id = %dependency_id))]
+    async fn remove_dependency(
+        &self,
+        asset_id: &AssetId,
+        dependency_id: &AssetId,
+    ) -> ServiceResult<()> {
+        debug!(
+            "Removing dependency {} from asset {}",
+            dependency_id, asset_id
+        );
+
+        let asset = self.find_live(asset_id).await?;
+        if !asset
+            .dependencies
+            .iter()
+            .any(|d| d.as_id() == Some(dependency_id))
+        {
+            return Err(ServiceError::NotFound(format!(
+                "Asset {} does not depend on {}",
+                asset_id, dependency_id
+            )));
+        }
+
+        self.repository
+            .remove_dependency(asset_id, dependency_id)
+            .await?;
+        // Graphs of either end may list the edge
+        self.invalidate_graphs(asset_id);
+        self.invalidate_graphs(dependency_id);
+
+        self.emit_updated_event(&asset, vec!["dependencies".to_string()])
+            .await;
+
+        info!(
+            "Removed dependency {} from asset {}",
+            dependency_id, asset_id
+        );
 
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    async fn reindex_dependents(&self) -> ServiceResult<DependentsReindex> {
+        let mut report = self.repository.rebuild_dependents_index().await?;
+        // Cached graphs may predate edges written outside this process (bulk
+        // imports, other replicas), which no repository repair can detect
+        if let Some(ref cache) = self.graph_cache {
+            report.cached_graphs_cleared = cache.clear() as u64;
+        }
+
+        info!(
+            edges_processed = report.edges_processed,
+            edges_added = report.edges_added,
+            edges_removed = report.edges_removed,
+            cached_graphs_cleared = report.cached_graphs_cleared,
+            "Rebuilt dependents index"
+        );
+        Ok(report)
+    }
+
     #[instrument(skip(self, dependencies), fields(dep_count = dependencies.len()))]
-    async fn validate_dependencies(&self, dependencies: &[llm_registry_core::AssetReference]) -> ServiceResult<ValidationResult> {
+    async fn validate_dependencies(
+        &self,
+        dependencies: &[llm_registry_core::AssetReference],
+    ) -> ServiceResult<ValidationResult> {
         debug!("Validating dependencies");
 
         let mut errors = Vec::new();
@@ -428,16 +1100,32 @@ impl RegistrationService for DefaultRegistrationService {
             ServiceError::Internal(format!("Failed to build dependency graph: {}", e))
         })?;
 
-        // For each dependency, fetch and add its dependencies
-        for dep in dependencies {
-            if let Some(dep_id) = dep.as_id() {
-                if let Ok(Some(dep_asset)) = self.repository.find_by_id(dep_id).await {
-                    graph
-                        .add_dependencies(*dep_id, dep_asset.dependencies.clone())
-                        .map_err(|e| {
-                            ServiceError::Internal(format!("Failed to add dependencies to graph: {}", e))
-                        })?;
-                }
+        // Add everything reachable from the dependencies, so a cycle back to
+        // this asset is found however long it is
+        let mut pending: Vec<AssetId> = dependencies
+            .iter()
+            .filter_map(|d| d.as_id().copied())
+            .collect();
+        let mut loaded = HashSet::new();
+        while let Some(dep_id) = pending.pop() {
+            if dep_id == *asset_id || !loaded.insert(dep_id) {
+                continue;
+            }
+            if let Ok(Some(dep_asset)) = self.repository.find_by_id(&dep_id).await {
+                pending.extend(
+                    dep_asset
+                        .dependencies
+                        .iter()
+                        .filter_map(|d| d.as_id().copied()),
+                );
+                graph
+                    .add_dependencies(dep_id, dep_asset.dependencies)
+                    .map_err(|e| {
+                        ServiceError::Internal(format!(
+                            "Failed to add dependencies to graph: {}",
+                            e
+                        ))
+                    })?;
             }
         }
 
@@ -449,9 +1137,11 @@ impl RegistrationService for DefaultRegistrationService {
                 .filter_map(|d| d.as_id().copied())
                 .collect();
 
-            let event = RegistryEvent::new(EventType::CircularDependencyDetected {
+            let mut event = RegistryEvent::new(EventType::CircularDependencyDetected {
                 cycle_asset_ids: dep_ids,
             });
+            // The spawned task runs outside the request's governance scope
+            crate::governance::GovernanceContext::stamp_current(&mut event);
 
             // Try to emit event (ignore errors)
             let event_store = self.event_store.clone();
@@ -470,8 +1160,8 @@ impl RegistrationService for DefaultRegistrationService {
 #[cfg(all(test, feature = "incomplete_tests"))]
 mod tests {
     use super::*;
+    use llm_registry_core::Version;
     use llm_registry_core::{AssetType, Checksum, HashAlgorithm, StorageBackend, StorageLocation};
-    use semver::Version;
 
     fn create_test_request() -> RegisterAssetRequest {
         RegisterAssetRequest {
@@ -482,6 +1172,7 @@ mod tests {
             license: Some("MIT".to_string()),
             tags: vec!["test".to_string()],
             annotations: Default::default(),
+            labels: Default::default(),
             storage: StorageLocation::new(
                 StorageBackend::S3 {
                     bucket: "test".to_string(),
@@ -497,6 +1188,8 @@ mod tests {
             dependencies: vec![],
             size_bytes: Some(1024),
             content_type: Some("application/octet-stream".to_string()),
+            retention_override: None,
+            dry_run: None,
         }
     }
 
@@ -534,7 +1227,11 @@ mod tests {
         async fn find_by_id(&self, _id: &str) -> DbResult<Option<Asset>> {
             Ok(None)
         }
-        async fn find_by_name_and_version(&self, _name: &str, _version: &Version) -> DbResult<Option<Asset>> {
+        async fn find_by_name_and_version(
+            &self,
+            _name: &str,
+            _version: &Version,
+        ) -> DbResult<Option<Asset>> {
             Ok(None)
         }
         async fn list(&self, _offset: i64, _limit: i64) -> DbResult<Vec<Asset>> {
@@ -553,17 +1250,30 @@ mod tests {
         async fn append(&self, _event: &Event) -> DbResult<()> {
             Ok(())
         }
-        async fn get_by_asset_id(&self, _asset_id: &str, _offset: i64, _limit: i64) -> DbResult<Vec<Event>> {
+        async fn get_by_asset_id(
+            &self,
+            _asset_id: &str,
+            _offset: i64,
+            _limit: i64,
+        ) -> DbResult<Vec<Event>> {
             Ok(vec![])
         }
-        async fn get_by_type(&self, _event_type: &str, _offset: i64, _limit: i64) -> DbResult<Vec<Event>> {
+        async fn get_by_type(
+            &self,
+            _event_type: &str,
+            _offset: i64,
+            _limit: i64,
+        ) -> DbResult<Vec<Event>> {
             Ok(vec![])
         }
     }
 
     #[async_trait]
     impl ValidationService for MockValidationService {
-        async fn validate_registration(&self, _request: &RegisterAssetRequest) -> ServiceResult<()> {
+        async fn validate_registration(
+            &self,
+            _request: &RegisterAssetRequest,
+        ) -> ServiceResult<()> {
             Ok(())
         }
     }
@@ -577,7 +1287,11 @@ mod tests {
 
     #[async_trait]
     impl VersioningService for MockVersioningService {
-        async fn validate_version(&self, _name: &str, _version: &Version) -> ServiceResult<()> {
+        async fn validate_version(
+            &self,
+            _name: &str,
+            _version: &llm_registry_core::Version,
+        ) -> ServiceResult<()> {
             Ok(())
         }
         async fn get_latest_version(&self, _name: &str) -> ServiceResult<Option<Version>> {
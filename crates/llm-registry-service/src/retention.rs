@@ -0,0 +1,729 @@
+//! Retention planning
+//!
+//! Global [`RetentionRules`] decide which versions of an asset are pruned
+//! automatically. Individual versions can override them with a
+//! [`RetentionOverride`], persisted as `registry.retention.*` annotations so it
+//! travels with the asset. Pinned versions are never pruned.
+//!
+//! [`RetentionEnforcer`] applies the rules to every asset in the registry.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use llm_registry_core::{Asset, AssetId, EventType, RegistryEvent};
+use llm_registry_db::{AssetRepository, EventStore, SearchQuery, SortField, SortOrder};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
+
+use crate::adapters::config_manager::{ConfigConsumer, RetentionRules};
+use crate::error::{ServiceError, ServiceResult};
+use crate::registration::RegistrationService;
+
+/// Page size used when scanning the registry for asset names
+const ENFORCER_PAGE_SIZE: i64 = 500;
+
+/// Annotation marking a version as pinned
+pub const RETENTION_PIN_ANNOTATION: &str = "registry.retention.pin";
+
+/// Annotation carrying a per-asset minimum version count
+pub const RETENTION_MIN_VERSIONS_ANNOTATION: &str = "registry.retention.min_versions";
+
+/// Per-asset override of the global retention rules
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionOverride {
+    /// Exclude this version from all automatic pruning and TTL deletion
+    #[serde(default)]
+    pub pin: bool,
+
+    /// Minimum versions of this asset to retain (raises the global minimum)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_versions: Option<u32>,
+}
+
+impl RetentionOverride {
+    /// Pin a version
+    pub fn pinned() -> Self {
+        Self {
+            pin: true,
+            min_versions: None,
+        }
+    }
+
+    /// Whether the override changes nothing
+    pub fn is_empty(&self) -> bool {
+        !self.pin && self.min_versions.is_none()
+    }
+
+    /// Read an override from asset annotations
+    pub fn from_annotations(annotations: &HashMap<String, String>) -> Option<Self> {
+        let pin = annotations
+            .get(RETENTION_PIN_ANNOTATION)
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"));
+        let min_versions = annotations
+            .get(RETENTION_MIN_VERSIONS_ANNOTATION)
+            .and_then(|v| v.parse().ok());
+
+        let retention_override = Self { pin, min_versions };
+        (!retention_override.is_empty()).then_some(retention_override)
+    }
+
+    /// Read the override of an asset, if any
+    pub fn of(asset: &Asset) -> Option<Self> {
+        Self::from_annotations(&asset.metadata.annotations)
+    }
+
+    /// Write the override into asset annotations, replacing any previous one
+    pub fn apply_to(&self, annotations: &mut HashMap<String, String>) {
+        annotations.remove(RETENTION_PIN_ANNOTATION);
+        annotations.remove(RETENTION_MIN_VERSIONS_ANNOTATION);
+
+        if self.pin {
+            annotations.insert(RETENTION_PIN_ANNOTATION.to_string(), "true".to_string());
+        }
+        if let Some(min_versions) = self.min_versions {
+            annotations.insert(
+                RETENTION_MIN_VERSIONS_ANNOTATION.to_string(),
+                min_versions.to_string(),
+            );
+        }
+    }
+}
+
+/// Why a version would be pruned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PruneReason {
+    /// Older than the newest `max_versions` versions
+    ExceedsMaxVersions,
+    /// Deprecated for longer than `delete_deprecated_after`
+    DeprecatedExpired,
+}
+
+/// A version selected for pruning
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PruneCandidate {
+    /// Asset ID
+    pub asset_id: AssetId,
+    /// Asset version
+    pub version: String,
+    /// Why the version would be pruned
+    pub reason: PruneReason,
+}
+
+/// An override in effect on one version
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionOverrideEntry {
+    /// Asset ID
+    pub asset_id: AssetId,
+    /// Asset version
+    pub version: String,
+    /// The override
+    pub retention_override: RetentionOverride,
+}
+
+/// What automatic pruning would do to the versions of an asset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionPreview {
+    /// Asset name
+    pub name: String,
+    /// Minimum versions retained after applying overrides
+    pub effective_min_versions: u32,
+    /// Versions that would be pruned
+    pub candidates: Vec<PruneCandidate>,
+    /// Overrides that were honored
+    pub overrides: Vec<RetentionOverrideEntry>,
+}
+
+/// Decide which versions of a single asset would be pruned
+///
+/// `versions` are all versions sharing one asset name, in any order.
+pub fn plan_retention(
+    name: &str,
+    versions: &[Asset],
+    rules: &RetentionRules,
+    now: DateTime<Utc>,
+) -> RetentionPreview {
+    let mut versions: Vec<&Asset> = versions.iter().collect();
+    versions.sort_by(|a, b| b.metadata.version.cmp(&a.metadata.version));
+
+    let overrides: Vec<RetentionOverrideEntry> = versions
+        .iter()
+        .filter_map(|asset| {
+            RetentionOverride::of(asset).map(|retention_override| RetentionOverrideEntry {
+                asset_id: asset.id,
+                version: asset.metadata.version.to_string(),
+                retention_override,
+            })
+        })
+        .collect();
+
+    let effective_min_versions = overrides
+        .iter()
+        .filter_map(|entry| entry.retention_override.min_versions)
+        .fold(rules.min_versions, u32::max);
+    let keep_newest = rules.max_versions.max(effective_min_versions) as usize;
+    let retain_all_for = chrono::Duration::from_std(rules.retain_all_for).unwrap_or_default();
+    let delete_deprecated_after =
+        chrono::Duration::from_std(rules.delete_deprecated_after).unwrap_or_default();
+
+    let mut candidates = Vec::new();
+    for (position, asset) in versions.iter().enumerate() {
+        let pinned = RetentionOverride::of(asset).is_some_and(|o| o.pin);
+        if pinned
+            || position < effective_min_versions as usize
+            || now - asset.created_at < retain_all_for
+        {
+            continue;
+        }
+
+        let reason = if asset
+            .deprecated_at
+            .is_some_and(|at| now - at >= delete_deprecated_after)
+        {
+            PruneReason::DeprecatedExpired
+        } else if position >= keep_newest {
+            PruneReason::ExceedsMaxVersions
+        } else {
+            continue;
+        };
+
+        candidates.push(PruneCandidate {
+            asset_id: asset.id,
+            version: asset.metadata.version.to_string(),
+            reason,
+        });
+    }
+
+    // Never prune the last active version
+    if rules.keep_one_active {
+        let active_survives = versions
+            .iter()
+            .any(|a| a.is_active() && !candidates.iter().any(|c| c.asset_id == a.id));
+        if !active_survives {
+            if let Some(newest_active) = versions.iter().find(|a| a.is_active()) {
+                candidates.retain(|c| c.asset_id != newest_active.id);
+            }
+        }
+    }
+
+    RetentionPreview {
+        name: name.to_string(),
+        effective_min_versions,
+        candidates,
+        overrides,
+    }
+}
+
+/// Trait for retention management operations
+#[async_trait]
+pub trait RetentionService: Send + Sync {
+    /// Set or clear the retention override of an asset version
+    async fn set_retention_override(
+        &self,
+        asset_id: &AssetId,
+        retention_override: RetentionOverride,
+    ) -> ServiceResult<Asset>;
+
+    /// Preview what automatic pruning would do to the versions of an asset
+    async fn preview_retention(&self, name: &str) -> ServiceResult<RetentionPreview>;
+}
+
+/// Default implementation of RetentionService
+pub struct DefaultRetentionService {
+    repository: Arc<dyn AssetRepository>,
+    event_store: Arc<dyn EventStore>,
+    rules: RetentionRules,
+    config: Option<Arc<dyn ConfigConsumer>>,
+}
+
+impl DefaultRetentionService {
+    /// Create a new retention service with the default rules
+    pub fn new(repository: Arc<dyn AssetRepository>, event_store: Arc<dyn EventStore>) -> Self {
+        Self {
+            repository,
+            event_store,
+            rules: RetentionRules::default(),
+            config: None,
+        }
+    }
+
+    /// Set the global retention rules
+    pub fn with_rules(mut self, rules: RetentionRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Apply the retention rules served by `config` instead of the fixed ones
+    pub fn with_config(mut self, config: Arc<dyn ConfigConsumer>) -> Self {
+        self.config = Some(config);
+        self
+    }
+}
+
+#[async_trait]
+impl RetentionService for DefaultRetentionService {
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn set_retention_override(
+        &self,
+        asset_id: &AssetId,
+        retention_override: RetentionOverride,
+    ) -> ServiceResult<Asset> {
+        let mut asset = self
+            .repository
+            .find_by_id(asset_id)
+            .await?
+            .ok_or_else(|| ServiceError::NotFound(asset_id.to_string()))?;
+
+        retention_override.apply_to(&mut asset.metadata.annotations);
+        asset.updated_at = Utc::now();
+
+        let updated = self.repository.update(asset).await?;
+
+        let event = RegistryEvent::new(EventType::AssetUpdated {
+            asset_id: updated.id,
+            asset_name: updated.metadata.name.clone(),
+            updated_fields: vec!["retention_override".to_string()],
+        });
+        if let Err(e) = self.event_store.append(event).await {
+            warn!("Failed to emit asset updated event: {}", e);
+        }
+
+        debug!(pin = retention_override.pin, "Retention override updated");
+
+        Ok(updated)
+    }
+
+    #[instrument(skip(self))]
+    async fn preview_retention(&self, name: &str) -> ServiceResult<RetentionPreview> {
+        let versions = self.repository.list_versions(name).await?;
+        if versions.is_empty() {
+            return Err(ServiceError::NotFound(name.to_string()));
+        }
+
+        let rules = current_rules(self.config.as_deref(), &self.rules).await;
+        Ok(plan_retention(name, &versions, &rules, Utc::now()))
+    }
+}
+
+/// Rules from the config source, or `fallback` without one or when it fails
+async fn current_rules(
+    config: Option<&dyn ConfigConsumer>,
+    fallback: &RetentionRules,
+) -> RetentionRules {
+    match config {
+        Some(config) => match config.get_retention_rules().await {
+            Ok(rules) => rules,
+            Err(e) => {
+                warn!("Failed to read retention rules, using fallback: {}", e);
+                fallback.clone()
+            }
+        },
+        None => fallback.clone(),
+    }
+}
+
+/// Outcome of one [`RetentionEnforcer`] run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionReport {
+    /// Whether deletions were only reported
+    pub dry_run: bool,
+    /// Asset names evaluated
+    pub assets_scanned: usize,
+    /// Versions deleted, or that would be deleted in a dry run
+    pub deleted: Vec<PruneCandidate>,
+    /// Versions or assets that could not be processed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+/// Deletes versions pruned by the global [`RetentionRules`]
+///
+/// Deletions go through the [`RegistrationService`], so versions other
+/// assets still depend on are kept and reported in
+/// [`RetentionReport::errors`].
+pub struct RetentionEnforcer {
+    repository: Arc<dyn AssetRepository>,
+    registration: Arc<dyn RegistrationService>,
+    rules: RetentionRules,
+    config: Option<Arc<dyn ConfigConsumer>>,
+    dry_run: bool,
+}
+
+impl RetentionEnforcer {
+    /// Create an enforcer with the default rules
+    pub fn new(
+        repository: Arc<dyn AssetRepository>,
+        registration: Arc<dyn RegistrationService>,
+    ) -> Self {
+        Self {
+            repository,
+            registration,
+            rules: RetentionRules::default(),
+            config: None,
+            dry_run: false,
+        }
+    }
+
+    /// Set the global retention rules
+    pub fn with_rules(mut self, rules: RetentionRules) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Apply the retention rules served by `config` instead of the fixed ones
+    pub fn with_config(mut self, config: Arc<dyn ConfigConsumer>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Only report what would be deleted
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Apply the retention rules to every asset once
+    #[instrument(skip(self), fields(dry_run = self.dry_run))]
+    pub async fn run_once(&self) -> RetentionReport {
+        let mut report = RetentionReport {
+            dry_run: self.dry_run,
+            ..Default::default()
+        };
+
+        let names = match self.asset_names().await {
+            Ok(names) => names,
+            Err(e) => {
+                warn!(error = %e, "Retention run aborted");
+                report.errors.push(format!("Failed to list assets: {}", e));
+                return report;
+            }
+        };
+
+        let rules = current_rules(self.config.as_deref(), &self.rules).await;
+        let now = Utc::now();
+        for name in names {
+            report.assets_scanned += 1;
+
+            let versions = match self.repository.list_versions(&name).await {
+                Ok(versions) => versions,
+                Err(e) => {
+                    report
+                        .errors
+                        .push(format!("Failed to list versions of {}: {}", name, e));
+                    continue;
+                }
+            };
+
+            for candidate in plan_retention(&name, &versions, &rules, now).candidates {
+                if !self.dry_run {
+                    if let Err(e) = self.registration.purge_asset(&candidate.asset_id).await {
+                        report.errors.push(format!(
+                            "Failed to delete {}@{}: {}",
+                            name, candidate.version, e
+                        ));
+                        continue;
+                    }
+                }
+                report.deleted.push(candidate);
+            }
+        }
+
+        info!(
+            assets_scanned = report.assets_scanned,
+            deleted = report.deleted.len(),
+            errors = report.errors.len(),
+            "Retention run finished"
+        );
+
+        report
+    }
+
+    /// Distinct names of all assets, including deprecated ones
+    async fn asset_names(&self) -> ServiceResult<BTreeSet<String>> {
+        let mut names = BTreeSet::new();
+        let mut offset = 0;
+
+        loop {
+            let query = SearchQuery::new()
+                .exclude_deprecated(false)
+                .sort_by(SortField::CreatedAt)
+                .sort_order(SortOrder::Ascending)
+                .limit(ENFORCER_PAGE_SIZE)
+                .offset(offset);
+            let page = self.repository.search(&query).await?.assets;
+
+            offset += page.len() as i64;
+            let done = (page.len() as i64) < ENFORCER_PAGE_SIZE;
+            names.extend(page.into_iter().map(|asset| asset.metadata.name));
+            if done {
+                return Ok(names);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{InMemoryRepository, NullEventStore};
+    use llm_registry_core::Version;
+    use llm_registry_core::{
+        AssetMetadata, AssetStatus, AssetType, Checksum, HashAlgorithm, StorageBackend,
+        StorageLocation,
+    };
+
+    fn version(v: &str, days_old: i64) -> Asset {
+        let metadata = AssetMetadata::new("model", Version::parse(v).unwrap());
+        let storage = StorageLocation::new(
+            StorageBackend::S3 {
+                bucket: "test".to_string(),
+                region: "us-east-1".to_string(),
+                endpoint: None,
+            },
+            "model.bin".to_string(),
+            None,
+        )
+        .unwrap();
+        let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+
+        let mut asset = Asset::new(
+            AssetId::new(),
+            AssetType::Model,
+            metadata,
+            storage,
+            checksum,
+        )
+        .unwrap();
+        asset.created_at = Utc::now() - chrono::Duration::days(days_old);
+        asset
+    }
+
+    fn rules(min_versions: u32, max_versions: u32) -> RetentionRules {
+        RetentionRules {
+            min_versions,
+            max_versions,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_override_annotation_roundtrip() {
+        let mut annotations = HashMap::new();
+        let retention_override = RetentionOverride {
+            pin: true,
+            min_versions: Some(5),
+        };
+
+        retention_override.apply_to(&mut annotations);
+        assert_eq!(
+            RetentionOverride::from_annotations(&annotations),
+            Some(retention_override)
+        );
+
+        RetentionOverride::default().apply_to(&mut annotations);
+        assert!(annotations.is_empty());
+    }
+
+    #[test]
+    fn test_exceeding_max_versions_pruned() {
+        let versions = vec![
+            version("1.0.0", 90),
+            version("1.1.0", 80),
+            version("1.2.0", 70),
+        ];
+
+        let preview = plan_retention("model", &versions, &rules(1, 2), Utc::now());
+
+        assert_eq!(preview.candidates.len(), 1);
+        assert_eq!(preview.candidates[0].version, "1.0.0");
+        assert_eq!(
+            preview.candidates[0].reason,
+            PruneReason::ExceedsMaxVersions
+        );
+    }
+
+    #[test]
+    fn test_max_versions_keeps_newest_by_semver() {
+        let versions = vec![
+            version("1.10.0", 70),
+            version("1.9.0", 90),
+            version("1.10.0-rc.1", 80),
+        ];
+
+        let preview = plan_retention("model", &versions, &rules(1, 1), Utc::now());
+
+        let pruned: Vec<&str> = preview
+            .candidates
+            .iter()
+            .map(|c| c.version.as_str())
+            .collect();
+        assert_eq!(pruned, ["1.10.0-rc.1", "1.9.0"]);
+    }
+
+    #[test]
+    fn test_pinned_version_never_pruned() {
+        let mut flagship = version("1.0.0", 90);
+        RetentionOverride::pinned().apply_to(&mut flagship.metadata.annotations);
+        let versions = vec![flagship, version("1.1.0", 80), version("1.2.0", 70)];
+
+        let preview = plan_retention("model", &versions, &rules(1, 1), Utc::now());
+
+        assert_eq!(preview.candidates.len(), 1);
+        assert_eq!(preview.candidates[0].version, "1.1.0");
+        assert_eq!(preview.overrides.len(), 1);
+        assert_eq!(preview.overrides[0].version, "1.0.0");
+    }
+
+    #[test]
+    fn test_override_min_versions_raises_floor() {
+        let mut latest = version("1.2.0", 70);
+        RetentionOverride {
+            pin: false,
+            min_versions: Some(3),
+        }
+        .apply_to(&mut latest.metadata.annotations);
+        let versions = vec![version("1.0.0", 90), version("1.1.0", 80), latest];
+
+        let preview = plan_retention("model", &versions, &rules(1, 1), Utc::now());
+
+        assert_eq!(preview.effective_min_versions, 3);
+        assert!(preview.candidates.is_empty());
+    }
+
+    #[test]
+    fn test_recent_versions_retained() {
+        let versions = vec![version("1.0.0", 1), version("1.1.0", 0)];
+
+        let preview = plan_retention("model", &versions, &rules(0, 1), Utc::now());
+
+        assert!(preview.candidates.is_empty());
+    }
+
+    fn deprecated(v: &str, days_old: i64, deprecated_days_ago: i64) -> Asset {
+        let mut asset = version(v, days_old);
+        asset.status = AssetStatus::Deprecated;
+        asset.deprecated_at = Some(Utc::now() - chrono::Duration::days(deprecated_days_ago));
+        asset
+    }
+
+    async fn run(
+        assets: Vec<Asset>,
+        rules: RetentionRules,
+        dry_run: bool,
+    ) -> (RetentionReport, Vec<String>) {
+        let repository = Arc::new(InMemoryRepository::with_assets(assets));
+        let services = crate::ServiceRegistry::new(repository.clone(), Arc::new(NullEventStore));
+        let enforcer = RetentionEnforcer::new(repository.clone(), services.registration().clone())
+            .with_rules(rules)
+            .with_dry_run(dry_run);
+
+        let report = enforcer.run_once().await;
+        let mut remaining: Vec<String> = repository
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|a| a.metadata.version.to_string())
+            .collect();
+        remaining.sort();
+        (report, remaining)
+    }
+
+    fn versions_of(report: &RetentionReport) -> Vec<&str> {
+        let mut versions: Vec<&str> = report.deleted.iter().map(|c| c.version.as_str()).collect();
+        versions.sort();
+        versions
+    }
+
+    #[tokio::test]
+    async fn test_enforcer_trims_to_max_versions() {
+        let assets = vec![
+            version("1.0.0", 90),
+            version("1.1.0", 80),
+            version("1.2.0", 70),
+            version("1.3.0", 60),
+            version("1.4.0", 50),
+        ];
+
+        let (report, remaining) = run(assets, rules(1, 2), false).await;
+
+        assert_eq!(report.assets_scanned, 1);
+        assert_eq!(versions_of(&report), vec!["1.0.0", "1.1.0", "1.2.0"]);
+        assert!(report
+            .deleted
+            .iter()
+            .all(|c| c.reason == PruneReason::ExceedsMaxVersions));
+        assert!(report.errors.is_empty());
+        assert_eq!(remaining, vec!["1.3.0", "1.4.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_enforcer_keeps_one_active_version() {
+        // Every version is a candidate, but 1.0.0 is the only active one
+        let assets = vec![
+            version("1.0.0", 400),
+            deprecated("1.1.0", 300, 200),
+            deprecated("1.2.0", 250, 190),
+        ];
+
+        let (report, remaining) = run(assets, rules(0, 1), false).await;
+
+        assert_eq!(versions_of(&report), vec!["1.1.0", "1.2.0"]);
+        assert!(report
+            .deleted
+            .iter()
+            .all(|c| c.reason == PruneReason::DeprecatedExpired));
+        assert_eq!(remaining, vec!["1.0.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_enforcer_dry_run_deletes_nothing() {
+        let assets = vec![
+            version("1.0.0", 90),
+            version("1.1.0", 80),
+            version("1.2.0", 70),
+        ];
+
+        let (report, remaining) = run(assets, rules(1, 1), true).await;
+
+        assert!(report.dry_run);
+        assert_eq!(versions_of(&report), vec!["1.0.0", "1.1.0"]);
+        assert_eq!(remaining, vec!["1.0.0", "1.1.0", "1.2.0"]);
+    }
+
+    #[tokio::test]
+    async fn test_preview_uses_configured_rules() {
+        use crate::adapters::config_manager::{InMemoryConfigConsumer, RegistryConfig};
+
+        let repository = Arc::new(InMemoryRepository::with_assets(vec![
+            version("1.0.0", 90),
+            version("1.1.0", 80),
+            version("1.2.0", 70),
+        ]));
+        let config = Arc::new(InMemoryConfigConsumer::new(RegistryConfig {
+            retention: rules(1, 1),
+            ..Default::default()
+        }));
+        let services = crate::ServiceRegistryBuilder::new()
+            .repository(repository)
+            .event_store(Arc::new(NullEventStore))
+            .config(config)
+            .build()
+            .unwrap();
+
+        let preview = services
+            .retention()
+            .preview_retention("model")
+            .await
+            .unwrap();
+
+        let mut pruned: Vec<String> = preview
+            .candidates
+            .iter()
+            .map(|c| c.version.clone())
+            .collect();
+        pruned.sort();
+        assert_eq!(pruned, vec!["1.0.0", "1.1.0"]);
+    }
+}
@@ -4,36 +4,65 @@
 //! including tag filtering, text search, and dependency graph queries.
 
 use async_trait::async_trait;
-use llm_registry_core::{Asset, AssetId, AssetType};
-use llm_registry_db::{AssetRepository, SearchQuery, SortField as DbSortField, SortOrder as DbSortOrder};
-use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
-use tracing::{debug, instrument};
+use llm_registry_core::{Asset, AssetId, AssetStatus, AssetType};
+use llm_registry_db::{
+    AssetRepository, AssetStats, FacetField, SearchQuery, SortField as DbSortField,
+    SortOrder as DbSortOrder, TagMatch as DbTagMatch,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, instrument, warn};
 
+use crate::adapters::config_manager::TtlConfig;
 use crate::dto::{
-    DependencyGraphResponse, DependencyNode, GetDependencyGraphRequest, SearchAssetsRequest,
-    SearchAssetsResponse, SortField, SortOrder,
+    DependencyGraphResponse, DependencyNode, Facets, GetDependencyGraphRequest,
+    ResolveLatestRequest, SearchAssetsRequest, SearchAssetsResponse, SortField, SortOrder,
+    TagCount, TagMatch, TransitiveDependent, MAX_PAGE_SIZE, MIN_CONTENT_HASH_PREFIX, SEARCH_FACETS,
 };
 use crate::error::{ServiceError, ServiceResult};
+use crate::expiry;
+use crate::graph_cache::{DependencyGraphCache, GraphCacheKey};
+
+/// Page size used when scanning the registry for expired assets
+const EXPIRY_SCAN_PAGE_SIZE: i64 = 500;
 
 /// Trait for search and query operations
 #[async_trait]
 pub trait SearchService: Send + Sync {
     /// Search for assets with filters
-    async fn search_assets(&self, request: SearchAssetsRequest) -> ServiceResult<SearchAssetsResponse>;
+    async fn search_assets(
+        &self,
+        request: SearchAssetsRequest,
+    ) -> ServiceResult<SearchAssetsResponse>;
 
     /// Get asset by ID
     async fn get_asset(&self, asset_id: &AssetId) -> ServiceResult<Option<Asset>>;
 
     /// Get asset by name and version
-    async fn get_asset_by_name_version(&self, name: &str, version: &str) -> ServiceResult<Option<Asset>>;
+    async fn get_asset_by_name_version(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> ServiceResult<Option<Asset>>;
 
     /// Get dependency graph for an asset
-    async fn get_dependency_graph(&self, request: GetDependencyGraphRequest) -> ServiceResult<DependencyGraphResponse>;
+    async fn get_dependency_graph(
+        &self,
+        request: GetDependencyGraphRequest,
+    ) -> ServiceResult<DependencyGraphResponse>;
 
     /// Get all tags in the registry
     async fn list_all_tags(&self) -> ServiceResult<Vec<String>>;
 
+    /// Get tags with the number of assets carrying each, most used first
+    async fn list_tag_counts(&self, prefix: Option<String>) -> ServiceResult<Vec<TagCount>>;
+
+    /// Get asset counts by type and status, and aggregate storage usage
+    ///
+    /// The figures may be up to the service's stats cache TTL old.
+    async fn registry_stats(&self) -> ServiceResult<AssetStats>;
+
     /// Search assets by tags (assets must have all specified tags)
     async fn search_by_tags(&self, tags: Vec<String>) -> ServiceResult<Vec<Asset>>;
 
@@ -42,17 +71,86 @@ pub trait SearchService: Send + Sync {
 
     /// Get reverse dependencies (assets that depend on this asset)
     async fn get_reverse_dependencies(&self, asset_id: &AssetId) -> ServiceResult<Vec<Asset>>;
+
+    /// Get every asset that depends on this asset, directly or transitively,
+    /// ordered by shortest hop distance
+    async fn get_transitive_dependents(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<Vec<TransitiveDependent>>;
+
+    /// Get the IDs of all assets past their TTL
+    async fn find_expired_assets(&self) -> ServiceResult<Vec<AssetId>>;
+
+    /// Get a page of every asset in ascending `(created_at, id)` order,
+    /// starting after the `after` key
+    ///
+    /// Deprecated and expired assets are always included; soft-deleted ones
+    /// only when `include_deleted` is set.
+    async fn export_page(
+        &self,
+        after: Option<(chrono::DateTime<chrono::Utc>, AssetId)>,
+        include_deleted: bool,
+        limit: i64,
+    ) -> ServiceResult<Vec<Asset>>;
+
+    /// Get the highest active version of an asset by semver precedence
+    async fn resolve_latest(&self, request: ResolveLatestRequest) -> ServiceResult<Option<Asset>>;
 }
 
+/// Default cap on the number of nodes in a dependency graph response
+pub const DEFAULT_MAX_GRAPH_NODES: usize = 10_000;
+
+/// How long registry stats are served from cache by default
+pub const DEFAULT_STATS_CACHE_TTL: Duration = Duration::from_secs(10);
+
 /// Default implementation of SearchService
 pub struct DefaultSearchService {
     repository: Arc<dyn AssetRepository>,
+    graph_cache: Option<Arc<DependencyGraphCache>>,
+    ttl: TtlConfig,
+    max_graph_nodes: usize,
+    stats_ttl: Duration,
+    stats_cache: Mutex<Option<(Instant, AssetStats)>>,
 }
 
 impl DefaultSearchService {
     /// Create a new search service
     pub fn new(repository: Arc<dyn AssetRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            graph_cache: None,
+            ttl: TtlConfig::default(),
+            max_graph_nodes: DEFAULT_MAX_GRAPH_NODES,
+            stats_ttl: DEFAULT_STATS_CACHE_TTL,
+            stats_cache: Mutex::new(None),
+        }
+    }
+
+    /// Serve dependency graphs from a shared cache
+    pub fn with_graph_cache(mut self, cache: Arc<DependencyGraphCache>) -> Self {
+        self.graph_cache = Some(cache);
+        self
+    }
+
+    /// Set the asset TTLs; with `enforce` set, expired assets are hidden
+    /// from search unless the request opts in with `include_expired`
+    pub fn with_ttl_config(mut self, ttl: TtlConfig) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Stop dependency graph traversal after `max` nodes, whatever the
+    /// requested depth (at least 1, the root)
+    pub fn with_max_graph_nodes(mut self, max: usize) -> Self {
+        self.max_graph_nodes = max.max(1);
+        self
+    }
+
+    /// Serve registry stats from cache for `ttl`; zero disables caching
+    pub fn with_stats_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.stats_ttl = ttl;
+        self
     }
 
     /// Convert DTO sort field to DB sort field
@@ -74,82 +172,219 @@ impl DefaultSearchService {
         }
     }
 
+    /// Convert DTO tag match to DB tag match
+    fn convert_tag_match(&self, tag_match: TagMatch) -> DbTagMatch {
+        match tag_match {
+            TagMatch::All => DbTagMatch::All,
+            TagMatch::Any => DbTagMatch::Any,
+        }
+    }
+
+    /// Count facet values over every asset matching `query`, ignoring pagination
+    async fn count_facets(&self, query: &SearchQuery, names: &[String]) -> ServiceResult<Facets> {
+        let fields = names
+            .iter()
+            .map(|name| match name.as_str() {
+                "type" => Ok((name, FacetField::AssetType)),
+                "tag" => Ok((name, FacetField::Tag)),
+                _ => Err(ServiceError::InvalidInput(format!(
+                    "Unknown facet '{}'; expected one of: {}",
+                    name,
+                    SEARCH_FACETS.join(", ")
+                ))),
+            })
+            .collect::<ServiceResult<Vec<_>>>()?;
+
+        let mut facets = Facets::new();
+        for (name, field) in fields {
+            let counts = self.repository.count_facet(query, field).await?;
+            facets.insert(name.clone(), counts.into_iter().collect());
+        }
+        Ok(facets)
+    }
+
+    /// Normalize a content hash filter and reject ambiguous short hashes
+    ///
+    /// A prefix is ambiguous when it matches more than one distinct checksum;
+    /// several assets sharing the same checksum (duplicate uploads) is fine.
+    async fn resolve_content_hash(&self, hash: &str) -> ServiceResult<String> {
+        let prefix = hash.trim().to_ascii_lowercase();
+
+        if prefix.len() < MIN_CONTENT_HASH_PREFIX {
+            return Err(ServiceError::InvalidInput(format!(
+                "Content hash must be at least {} characters",
+                MIN_CONTENT_HASH_PREFIX
+            )));
+        }
+        if !prefix.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ServiceError::InvalidInput(
+                "Content hash must be hexadecimal".to_string(),
+            ));
+        }
+
+        let matches = self.repository.find_checksums_by_prefix(&prefix, 2).await?;
+        if matches.len() > 1 {
+            return Err(ServiceError::InvalidInput(format!(
+                "Ambiguous content hash prefix '{}' matches multiple hashes",
+                prefix
+            )));
+        }
+
+        Ok(prefix)
+    }
+
     /// Build dependency graph recursively
     fn build_dependency_graph_recursive<'a>(
         &'a self,
         asset_id: &'a AssetId,
         max_depth: i32,
         current_depth: i32,
-        visited: &'a mut HashSet<AssetId>,
-        nodes: &'a mut HashMap<AssetId, DependencyNode>,
+        traversal: &'a mut GraphTraversal,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ServiceResult<()>> + 'a + Send>> {
         Box::pin(async move {
-        // Check depth limit
-        if max_depth >= 0 && current_depth >= max_depth {
-            return Ok(());
-        }
-
-        // Avoid cycles
-        if visited.contains(asset_id) {
-            return Ok(());
-        }
-        visited.insert(*asset_id);
+            // Check depth limit
+            if max_depth >= 0 && current_depth >= max_depth {
+                return Ok(());
+            }
+
+            // Reaching an asset that is still on the current path closes a cycle
+            if let Some(start) = traversal.path.iter().position(|id| id == asset_id) {
+                let cycle = traversal.path[start..].to_vec();
+                traversal.record_cycle(cycle);
+                return Ok(());
+            }
+
+            // Each asset is expanded only once
+            if traversal.visited.contains(asset_id) {
+                return Ok(());
+            }
+
+            // Bound the response size even when the depth is unlimited
+            if traversal.nodes.len() >= traversal.max_nodes {
+                traversal.truncated_at_depth = Some(match traversal.truncated_at_depth {
+                    Some(depth) => depth.min(current_depth),
+                    None => current_depth,
+                });
+                return Ok(());
+            }
+            traversal.visited.insert(*asset_id);
+
+            // Get the asset
+            let asset = match self.repository.find_by_id(asset_id).await? {
+                Some(a) => a,
+                None => return Ok(()), // Skip if asset not found
+            };
+
+            // Get dependencies
+            let deps = self.repository.list_dependencies(asset_id).await?;
+            let dep_ids: Vec<AssetId> = deps.iter().map(|d| d.id).collect();
+
+            // Create node
+            let node = DependencyNode {
+                asset_id: *asset_id,
+                name: asset.metadata.name.clone(),
+                version: asset.metadata.version.clone(),
+                depth: current_depth,
+                dependencies: dep_ids.clone(),
+            };
+            traversal.nodes.insert(*asset_id, node);
+
+            // Recursively process dependencies
+            traversal.path.push(*asset_id);
+            for dep in deps {
+                self.build_dependency_graph_recursive(
+                    &dep.id,
+                    max_depth,
+                    current_depth + 1,
+                    traversal,
+                )
+                .await?;
+            }
+            traversal.path.pop();
+
+            Ok(())
+        })
+    }
+}
 
-        // Get the asset
-        let asset = match self.repository.find_by_id(asset_id).await? {
-            Some(a) => a,
-            None => return Ok(()), // Skip if asset not found
-        };
+/// State accumulated while walking a dependency graph
+struct GraphTraversal {
+    visited: HashSet<AssetId>,
+    nodes: HashMap<AssetId, DependencyNode>,
+    /// Assets on the path from the root to the asset being expanded
+    path: Vec<AssetId>,
+    cycles: Vec<Vec<AssetId>>,
+    /// Node cap; assets reached once it is hit are left out
+    max_nodes: usize,
+    /// Shallowest depth at which an asset was left out by the node cap
+    truncated_at_depth: Option<i32>,
+}
 
-        // Get dependencies
-        let deps = self.repository.list_dependencies(asset_id).await?;
-        let dep_ids: Vec<AssetId> = deps.iter().map(|d| d.id).collect();
-
-        // Create node
-        let node = DependencyNode {
-            asset_id: *asset_id,
-            name: asset.metadata.name.clone(),
-            version: asset.metadata.version.clone(),
-            depth: current_depth,
-            dependencies: dep_ids.clone(),
-        };
-        nodes.insert(*asset_id, node);
-
-        // Recursively process dependencies
-        for dep in deps {
-            self.build_dependency_graph_recursive(
-                &dep.id,
-                max_depth,
-                current_depth + 1,
-                visited,
-                nodes,
-            )
-            .await?;
+impl GraphTraversal {
+    fn new(max_nodes: usize) -> Self {
+        Self {
+            visited: HashSet::new(),
+            nodes: HashMap::new(),
+            path: Vec::new(),
+            cycles: Vec::new(),
+            max_nodes,
+            truncated_at_depth: None,
         }
+    }
 
-        Ok(())
-        })
+    /// Record a cycle, rotated to start at its smallest asset ID so that the
+    /// same cycle reached from different entry points is reported once
+    fn record_cycle(&mut self, mut cycle: Vec<AssetId>) {
+        let start = cycle
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, id)| *id.as_ulid())
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        cycle.rotate_left(start);
+
+        if !self.cycles.contains(&cycle) {
+            self.cycles.push(cycle);
+        }
     }
 }
 
 #[async_trait]
 impl SearchService for DefaultSearchService {
     #[instrument(skip(self, request))]
-    async fn search_assets(&self, request: SearchAssetsRequest) -> ServiceResult<SearchAssetsResponse> {
+    async fn search_assets(
+        &self,
+        request: SearchAssetsRequest,
+    ) -> ServiceResult<SearchAssetsResponse> {
         debug!("Searching assets with query");
 
+        let sort_field = request.sort_field().map_err(ServiceError::InvalidInput)?;
+        let label_selector = request
+            .label_selector()
+            .map_err(ServiceError::InvalidInput)?;
+        let facet_names = request.facet_names();
+
         // Convert DTO request to DB query
         let mut query = SearchQuery::new()
             .limit(request.limit)
             .offset(request.offset)
-            .sort_by(self.convert_sort_field(request.sort_by))
-            .sort_order(self.convert_sort_order(request.sort_order))
-            .exclude_deprecated(request.exclude_deprecated);
+            .sort_by(self.convert_sort_field(sort_field))
+            .sort_order(self.convert_sort_order(request.sort_order.unwrap_or_default()))
+            .exclude_deprecated(request.exclude_deprecated && !request.include_deprecated);
 
         if let Some(text) = request.text {
             query = query.text(text);
         }
 
+        if let Some(q) = request
+            .q
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+        {
+            query = query.q(q);
+        }
+
         for asset_type in request.asset_types {
             query = query.asset_type(asset_type);
         }
@@ -157,6 +392,11 @@ impl SearchService for DefaultSearchService {
         for tag in request.tags {
             query = query.tag(tag);
         }
+        query = query.tag_match(self.convert_tag_match(request.tag_match.unwrap_or_default()));
+
+        if let Some(selector) = label_selector {
+            query = query.label_selector(selector);
+        }
 
         if let Some(author) = request.author {
             query = query.author(author);
@@ -166,6 +406,19 @@ impl SearchService for DefaultSearchService {
             query = query.storage_backend(backend);
         }
 
+        if let Some(ref hash) = request.content_hash {
+            query = query.content_hash_prefix(self.resolve_content_hash(hash).await?);
+        }
+
+        if self.ttl.enforce && !request.include_expired {
+            query = query.exclude_expired(expiry::cutoff(&self.ttl, chrono::Utc::now()));
+        }
+
+        let facets = match facet_names {
+            Some(ref names) => Some(self.count_facets(&query, names).await?),
+            None => None,
+        };
+
         // Execute search
         let results = self.repository.search(&query).await?;
         let has_more = results.has_more();
@@ -176,62 +429,100 @@ impl SearchService for DefaultSearchService {
             offset: results.offset,
             limit: results.limit,
             has_more,
+            facets,
         })
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
     async fn get_asset(&self, asset_id: &AssetId) -> ServiceResult<Option<Asset>> {
         debug!("Getting asset by ID");
-        self.repository
-            .find_by_id(asset_id)
-            .await
-            .map_err(Into::into)
+        let asset = self.repository.find_by_id(asset_id).await?;
+        Ok(asset.filter(|asset| !asset.is_deleted()))
     }
 
     #[instrument(skip(self), fields(name = %name, version = %version))]
-    async fn get_asset_by_name_version(&self, name: &str, version: &str) -> ServiceResult<Option<Asset>> {
+    async fn get_asset_by_name_version(
+        &self,
+        name: &str,
+        version: &str,
+    ) -> ServiceResult<Option<Asset>> {
         debug!("Getting asset by name and version");
 
-        let semver = semver::Version::parse(version)
-            .map_err(|e| ServiceError::ValidationFailed(format!("Invalid version: {}", e)))?;
+        let semver = llm_registry_core::Version::parse(version)
+            .map_err(|e| ServiceError::ValidationFailed(e.to_string()))?;
 
-        self.repository
+        let asset = self
+            .repository
             .find_by_name_and_version(name, &semver)
-            .await
-            .map_err(Into::into)
+            .await?;
+        Ok(asset.filter(|asset| !asset.is_deleted()))
     }
 
-    #[instrument(skip(self, request), fields(asset_id = %request.asset_id, max_depth = request.max_depth))]
-    async fn get_dependency_graph(&self, request: GetDependencyGraphRequest) -> ServiceResult<DependencyGraphResponse> {
+    #[instrument(
+        skip(self, request),
+        fields(asset_id = %request.asset_id, max_depth = request.max_depth, graph_cache = tracing::field::Empty)
+    )]
+    async fn get_dependency_graph(
+        &self,
+        request: GetDependencyGraphRequest,
+    ) -> ServiceResult<DependencyGraphResponse> {
+        let cache_key = GraphCacheKey::from(&request);
+        if let Some(ref cache) = self.graph_cache {
+            if let Some(graph) = cache.get(&cache_key) {
+                tracing::Span::current().record("graph_cache", "hit");
+                return Ok(graph);
+            }
+            tracing::Span::current().record("graph_cache", "miss");
+        }
+
         debug!("Building dependency graph");
 
-        let mut visited = HashSet::new();
-        let mut nodes = HashMap::new();
+        let mut traversal = GraphTraversal::new(self.max_graph_nodes);
 
         self.build_dependency_graph_recursive(
             &request.asset_id,
             request.max_depth,
             0,
-            &mut visited,
-            &mut nodes,
+            &mut traversal,
         )
         .await?;
+        let GraphTraversal {
+            nodes,
+            cycles,
+            truncated_at_depth,
+            ..
+        } = traversal;
+        if let Some(depth) = truncated_at_depth {
+            warn!(
+                asset_id = %request.asset_id,
+                max_nodes = self.max_graph_nodes,
+                depth,
+                "Dependency graph truncated at node cap"
+            );
+        }
 
         // Check if truncated
-        let truncated = if request.max_depth >= 0 {
-            // If max_depth is set, we might have truncated
-            nodes.values().any(|n| n.depth == request.max_depth - 1 && !n.dependencies.is_empty())
-        } else {
-            false
-        };
+        let truncated = truncated_at_depth.is_some()
+            || (request.max_depth >= 0
+                && nodes
+                    .values()
+                    .any(|n| n.depth == request.max_depth - 1 && !n.dependencies.is_empty()));
 
         let dependencies: Vec<DependencyNode> = nodes.into_values().collect();
 
-        Ok(DependencyGraphResponse {
+        let graph = DependencyGraphResponse {
             root: request.asset_id,
             dependencies,
             truncated,
-        })
+            truncated_at_depth,
+            cycles,
+        };
+
+        if let Some(ref cache) = self.graph_cache {
+            cache.insert(cache_key, graph.clone());
+        }
+
+        Ok(graph)
     }
 
     #[instrument(skip(self))]
@@ -240,6 +531,30 @@ impl SearchService for DefaultSearchService {
         self.repository.list_all_tags().await.map_err(Into::into)
     }
 
+    #[instrument(skip(self))]
+    async fn list_tag_counts(&self, prefix: Option<String>) -> ServiceResult<Vec<TagCount>> {
+        debug!("Counting tags");
+        let counts = self.repository.count_tags(prefix.as_deref()).await?;
+        Ok(counts
+            .into_iter()
+            .map(|(tag, count)| TagCount { tag, count })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn registry_stats(&self) -> ServiceResult<AssetStats> {
+        if let Some((computed_at, ref stats)) = *self.stats_cache.lock().unwrap() {
+            if computed_at.elapsed() < self.stats_ttl {
+                return Ok(stats.clone());
+            }
+        }
+
+        debug!("Aggregating registry stats");
+        let stats = self.repository.asset_stats().await?;
+        *self.stats_cache.lock().unwrap() = Some((Instant::now(), stats.clone()));
+        Ok(stats)
+    }
+
     #[instrument(skip(self, tags), fields(tag_count = tags.len()))]
     async fn search_by_tags(&self, tags: Vec<String>) -> ServiceResult<Vec<Asset>> {
         debug!("Searching by tags");
@@ -274,6 +589,127 @@ impl SearchService for DefaultSearchService {
             .await
             .map_err(Into::into)
     }
+
+    #[instrument(skip(self), fields(asset_id = %asset_id))]
+    async fn get_transitive_dependents(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<Vec<TransitiveDependent>> {
+        debug!("Getting transitive dependents");
+
+        // Breadth-first over reverse edges, so the first visit is the shortest path;
+        // the visited set (seeded with the target) keeps cycles from looping
+        let mut visited = HashSet::from([*asset_id]);
+        let mut queue = VecDeque::from([(*asset_id, 0u32)]);
+        let mut dependents = Vec::new();
+
+        while let Some((current, distance)) = queue.pop_front() {
+            for asset in self.repository.list_reverse_dependencies(&current).await? {
+                if visited.insert(asset.id) {
+                    queue.push_back((asset.id, distance + 1));
+                    dependents.push(TransitiveDependent {
+                        asset,
+                        distance: distance + 1,
+                    });
+                }
+            }
+        }
+
+        Ok(dependents)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_expired_assets(&self) -> ServiceResult<Vec<AssetId>> {
+        debug!("Scanning for expired assets");
+
+        let now = chrono::Utc::now();
+        let mut expired = Vec::new();
+        let mut offset = 0;
+
+        loop {
+            let query = SearchQuery::new()
+                .exclude_deprecated(false)
+                .sort_by(DbSortField::CreatedAt)
+                .sort_order(DbSortOrder::Ascending)
+                .limit(EXPIRY_SCAN_PAGE_SIZE)
+                .offset(offset);
+            let page = self.repository.search(&query).await?.assets;
+
+            offset += page.len() as i64;
+            let done = (page.len() as i64) < EXPIRY_SCAN_PAGE_SIZE;
+            expired.extend(
+                page.iter()
+                    .filter(|asset| expiry::is_expired(asset, &self.ttl, now))
+                    .map(|asset| asset.id),
+            );
+            if done {
+                return Ok(expired);
+            }
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn export_page(
+        &self,
+        after: Option<(chrono::DateTime<chrono::Utc>, AssetId)>,
+        include_deleted: bool,
+        limit: i64,
+    ) -> ServiceResult<Vec<Asset>> {
+        let mut query = SearchQuery::new()
+            .exclude_deprecated(false)
+            .include_deleted(include_deleted)
+            .sort_by(DbSortField::CreatedAt)
+            .sort_order(DbSortOrder::Ascending)
+            .limit(limit);
+        if let Some((created_at, id)) = after {
+            query = query.after(created_at, id);
+        }
+
+        Ok(self.repository.search(&query).await?.assets)
+    }
+
+    #[instrument(skip(self, request), fields(name = %request.name))]
+    async fn resolve_latest(&self, request: ResolveLatestRequest) -> ServiceResult<Option<Asset>> {
+        debug!("Resolving latest version");
+
+        let constraint = request
+            .constraint
+            .as_deref()
+            .map(|constraint| {
+                semver::VersionReq::parse(constraint).map_err(|e| {
+                    ServiceError::InvalidInput(format!(
+                        "Invalid version constraint '{}': {}",
+                        constraint, e
+                    ))
+                })
+            })
+            .transpose()?;
+
+        let now = chrono::Utc::now();
+        let latest = self
+            .repository
+            .list_versions(&request.name)
+            .await?
+            .into_iter()
+            .filter(|asset| !asset.is_deleted())
+            .filter(|asset| match asset.status {
+                AssetStatus::Active => true,
+                AssetStatus::Deprecated => request.include_deprecated,
+                AssetStatus::Archived | AssetStatus::NonCompliant => false,
+            })
+            .filter(|asset| match constraint {
+                Some(ref req) => req.matches(&asset.metadata.version),
+                None => true,
+            })
+            .filter(|asset| {
+                !self.ttl.enforce
+                    || request.include_expired
+                    || !expiry::is_expired(asset, &self.ttl, now)
+            })
+            .max_by(|a, b| a.metadata.version.cmp(&b.metadata.version));
+
+        Ok(latest)
+    }
 }
 
 /// Utility functions for search operations
@@ -291,10 +727,7 @@ pub mod utils {
             return Err(ServiceError::InvalidInput("Empty search query".to_string()));
         }
 
-        let terms: Vec<String> = query
-            .split_whitespace()
-            .map(|s| s.to_lowercase())
-            .collect();
+        let terms: Vec<String> = query.split_whitespace().map(|s| s.to_lowercase()).collect();
 
         Ok(terms)
     }
@@ -312,15 +745,22 @@ pub mod utils {
     pub fn default_search_request() -> SearchAssetsRequest {
         SearchAssetsRequest {
             text: None,
+            q: None,
             asset_types: vec![],
             tags: vec![],
+            tag_match: None,
+            label_selector: None,
             author: None,
             storage_backend: None,
+            content_hash: None,
             exclude_deprecated: true,
+            include_deprecated: false,
             limit: 50,
             offset: 0,
-            sort_by: SortField::CreatedAt,
-            sort_order: SortOrder::Descending,
+            sort_by: None,
+            sort_order: None,
+            include_expired: false,
+            facets: None,
         }
     }
 
@@ -331,10 +771,11 @@ pub mod utils {
                 "Limit must be positive".to_string(),
             ));
         }
-        if limit > 1000 {
-            return Err(ServiceError::InvalidInput(
-                "Limit cannot exceed 1000".to_string(),
-            ));
+        if limit > MAX_PAGE_SIZE {
+            return Err(ServiceError::InvalidInput(format!(
+                "Limit cannot exceed {}",
+                MAX_PAGE_SIZE
+            )));
         }
         if offset < 0 {
             return Err(ServiceError::InvalidInput(
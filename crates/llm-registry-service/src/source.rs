@@ -0,0 +1,1190 @@
+//! Source reference registration
+//!
+//! This module registers assets from manifests stored outside the registry,
+//! either in a Git repository or as an OCI artifact. The manifest is fetched,
+//! mapped into a [`RegisterAssetRequest`], and passed through the regular
+//! registration pipeline so it receives the same validation.
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+use tracing::{debug, info, instrument};
+
+use crate::dto::{
+    RegisterAssetRequest, RegisterFromRefRequest, RegisterFromRefResponse, SourceReference,
+};
+use crate::error::{ServiceError, ServiceResult};
+use crate::registration::RegistrationService;
+
+/// Media type identifying the asset manifest layer of an OCI artifact
+pub const ASSET_MANIFEST_MEDIA_TYPE: &str = "application/vnd.llm-registry.asset.manifest.v1+yaml";
+
+/// Largest OCI image manifest or asset manifest blob read from a registry
+pub const MAX_MANIFEST_BYTES: usize = 4 * 1024 * 1024;
+
+/// Most redirects followed while pulling from an OCI registry
+const MAX_OCI_REDIRECTS: usize = 5;
+
+/// Most bytes of git stderr kept for error messages
+const MAX_GIT_STDERR_BYTES: u64 = 64 * 1024;
+
+/// Annotation recording the kind of source an asset was registered from
+pub const SOURCE_KIND_ANNOTATION: &str = "registry.source.kind";
+
+/// Annotation recording the source reference an asset was registered from
+pub const SOURCE_REF_ANNOTATION: &str = "registry.source.ref";
+
+/// Annotation recording the resolved commit or digest of the source
+pub const SOURCE_DIGEST_ANNOTATION: &str = "registry.source.digest";
+
+/// Manifest fetched from a source reference
+#[derive(Debug, Clone)]
+pub struct FetchedManifest {
+    /// Commit SHA or manifest digest the reference resolved to
+    pub resolved_digest: String,
+    /// Raw manifest content (YAML or JSON)
+    pub content: Vec<u8>,
+}
+
+/// Trait for fetching asset manifests from external sources
+#[async_trait]
+pub trait SourceFetcher: Send + Sync {
+    /// Fetch the manifest a reference points to
+    async fn fetch(&self, reference: &SourceReference) -> ServiceResult<FetchedManifest>;
+}
+
+/// Trait for registering assets from source references
+#[async_trait]
+pub trait SourceRegistrationService: Send + Sync {
+    /// Fetch, map, validate, and register an asset from a reference
+    async fn register_from_ref(
+        &self,
+        request: RegisterFromRefRequest,
+    ) -> ServiceResult<RegisterFromRefResponse>;
+}
+
+// ============================================================================
+// Git
+// ============================================================================
+
+/// Fetches manifests from Git repositories using the `git` CLI
+#[derive(Debug, Clone)]
+pub struct GitSourceFetcher {
+    git_binary: PathBuf,
+    work_dir: PathBuf,
+}
+
+impl GitSourceFetcher {
+    /// Create a new Git fetcher using `git` from `PATH`
+    pub fn new() -> Self {
+        Self {
+            git_binary: PathBuf::from("git"),
+            work_dir: std::env::temp_dir(),
+        }
+    }
+
+    /// Set the directory used for temporary checkouts
+    pub fn with_work_dir(mut self, work_dir: impl Into<PathBuf>) -> Self {
+        self.work_dir = work_dir.into();
+        self
+    }
+
+    /// Run a git command in `dir` and return its stdout
+    ///
+    /// At most [`MAX_MANIFEST_BYTES`] of stdout are read; git is killed if
+    /// it writes more.
+    async fn git(&self, dir: &Path, args: &[&str]) -> ServiceResult<Vec<u8>> {
+        let failed = |e: std::io::Error| {
+            ServiceError::SourceUnavailable(format!("Failed to run git: {}", e))
+        };
+        let mut child = Command::new(&self.git_binary)
+            .arg("-C")
+            .arg(dir)
+            // Only ever talk HTTPS, including on submodules, and never follow
+            // redirects to hosts that were not checked
+            .args([
+                "-c",
+                "protocol.allow=never",
+                "-c",
+                "protocol.https.allow=always",
+            ])
+            .args(["-c", "http.followRedirects=false"])
+            .args(args)
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(failed)?;
+
+        // Drain stderr alongside stdout so a full pipe cannot stall git
+        let mut stderr = child.stderr.take().expect("stderr is piped");
+        let stderr = tokio::spawn(async move {
+            let mut kept = Vec::new();
+            let _ = (&mut stderr)
+                .take(MAX_GIT_STDERR_BYTES)
+                .read_to_end(&mut kept)
+                .await;
+            let _ = tokio::io::copy(&mut stderr, &mut tokio::io::sink()).await;
+            kept
+        });
+
+        let mut stdout = Vec::new();
+        child
+            .stdout
+            .take()
+            .expect("stdout is piped")
+            .take(MAX_MANIFEST_BYTES as u64 + 1)
+            .read_to_end(&mut stdout)
+            .await
+            .map_err(failed)?;
+        if stdout.len() > MAX_MANIFEST_BYTES {
+            return Err(ServiceError::ValidationFailed(format!(
+                "git {} returned more than {} bytes",
+                args.first().unwrap_or(&""),
+                MAX_MANIFEST_BYTES
+            )));
+        }
+
+        let status = child.wait().await.map_err(failed)?;
+        if !status.success() {
+            let stderr = stderr.await.unwrap_or_default();
+            return Err(ServiceError::SourceUnavailable(format!(
+                "git {} failed: {}",
+                args.first().unwrap_or(&""),
+                String::from_utf8_lossy(&stderr).trim()
+            )));
+        }
+
+        Ok(stdout)
+    }
+
+    async fn fetch_into(
+        &self,
+        dir: &Path,
+        url: &str,
+        rev: &str,
+        commit: Option<&str>,
+        path: &str,
+    ) -> ServiceResult<FetchedManifest> {
+        self.git(dir, &["init", "--quiet"]).await?;
+        self.git(dir, &["fetch", "--quiet", "--depth", "1", "--", url, rev])
+            .await?;
+
+        let resolved = self.git(dir, &["rev-parse", "FETCH_HEAD"]).await?;
+        let resolved = String::from_utf8_lossy(&resolved).trim().to_string();
+
+        if let Some(commit) = commit {
+            if !resolved.eq_ignore_ascii_case(commit) {
+                return Err(ServiceError::ChecksumVerificationFailed(format!(
+                    "pinned commit {} resolved to {}",
+                    commit, resolved
+                )));
+            }
+        }
+
+        let content = self
+            .git(dir, &["show", &format!("FETCH_HEAD:{}", path)])
+            .await?;
+
+        Ok(FetchedManifest {
+            resolved_digest: resolved,
+            content,
+        })
+    }
+}
+
+impl Default for GitSourceFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SourceFetcher for GitSourceFetcher {
+    #[instrument(skip(self))]
+    async fn fetch(&self, reference: &SourceReference) -> ServiceResult<FetchedManifest> {
+        let SourceReference::Git {
+            url,
+            rev,
+            commit,
+            path,
+        } = reference
+        else {
+            return Err(ServiceError::InvalidInput(format!(
+                "Git fetcher cannot handle {} references",
+                reference.kind()
+            )));
+        };
+
+        let target = commit.as_deref().or(rev.as_deref()).unwrap_or("HEAD");
+        let host = validate_git_reference(url, target, path)?;
+        ensure_public_host(host).await?;
+
+        let dir = self.work_dir.join(format!(
+            "llm-registry-src-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        tokio::fs::create_dir_all(&dir).await.map_err(|e| {
+            ServiceError::Internal(format!("Failed to create checkout directory: {}", e))
+        })?;
+
+        debug!(url = %url, rev = %target, "Fetching manifest from git");
+        let result = self
+            .fetch_into(&dir, url, target, commit.as_deref(), path)
+            .await;
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+        result
+    }
+}
+
+/// Reject Git reference values that could be read as options or reach
+/// non-HTTPS or internal endpoints, returning the URL's host
+fn validate_git_reference<'a>(url: &'a str, rev: &str, path: &str) -> ServiceResult<&'a str> {
+    let has_control = |value: &str| value.chars().any(|c| c.is_control() || c.is_whitespace());
+
+    let rest = url
+        .get(..8)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("https://"))
+        .map(|_| &url[8..])
+        .ok_or_else(|| ServiceError::InvalidInput(format!("Git url must use https: '{}'", url)))?;
+    if has_control(url) {
+        return Err(ServiceError::InvalidInput(format!(
+            "Invalid git url: '{}'",
+            url
+        )));
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority_host(authority.rsplit('@').next().unwrap_or_default());
+    if is_internal_host(host) {
+        return Err(ServiceError::InvalidInput(format!(
+            "Git url host is not allowed: '{}'",
+            host
+        )));
+    }
+
+    if rev.is_empty() || rev.starts_with('-') || has_control(rev) {
+        return Err(ServiceError::InvalidInput(format!(
+            "Invalid git revision: '{}'",
+            rev
+        )));
+    }
+    if path.starts_with('-') || path.split('/').any(|segment| segment == "..") {
+        return Err(ServiceError::InvalidInput(format!(
+            "Invalid manifest path: '{}'",
+            path
+        )));
+    }
+
+    Ok(host)
+}
+
+/// The host of a `host[:port]` authority, without IPv6 brackets
+fn authority_host(authority: &str) -> &str {
+    match authority.strip_prefix('[') {
+        Some(bracketed) => bracketed.split(']').next().unwrap_or_default(),
+        None => authority.split(':').next().unwrap_or_default(),
+    }
+}
+
+/// Whether a host names the local machine or a private network
+///
+/// Only the literal host is checked; [`ensure_public_host`] also checks
+/// the addresses it resolves to.
+fn is_internal_host(host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.is_empty() || host == "localhost" || host.ends_with(".localhost") {
+        return true;
+    }
+
+    host.parse::<IpAddr>().is_ok_and(is_internal_ip)
+}
+
+/// Whether an address is loopback, private, link-local, or unspecified
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+        }
+        IpAddr::V6(ip) => {
+            let segments = ip.segments();
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || (segments[0] & 0xfe00) == 0xfc00
+                || (segments[0] & 0xffc0) == 0xfe80
+                || ip
+                    .to_ipv4_mapped()
+                    .is_some_and(|v4| is_internal_ip(IpAddr::V4(v4)))
+        }
+    }
+}
+
+/// Resolve `host` and reject it if any address it resolves to is internal
+///
+/// This also catches names pointing at private networks and the decimal,
+/// hex, and shortened IPv4 forms the resolver accepts.
+async fn ensure_public_host(host: &str) -> ServiceResult<()> {
+    let addresses = tokio::net::lookup_host((host, 443)).await.map_err(|e| {
+        ServiceError::SourceUnavailable(format!("Failed to resolve '{}': {}", host, e))
+    })?;
+
+    for address in addresses {
+        if is_internal_ip(address.ip()) {
+            return Err(ServiceError::InvalidInput(format!(
+                "Host '{}' resolves to an internal address",
+                host
+            )));
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// OCI
+// ============================================================================
+
+/// Fetches manifests from OCI distribution registries
+#[derive(Debug, Clone)]
+pub struct OciSourceFetcher {
+    client: reqwest::Client,
+    bearer_token: Option<String>,
+    plain_http: bool,
+}
+
+impl OciSourceFetcher {
+    /// Create a new OCI fetcher for anonymous HTTPS pulls
+    ///
+    /// Registries may redirect blob pulls to a CDN, so redirects are
+    /// followed, but each hop is re-checked: internal hosts and downgrades
+    /// from HTTPS to HTTP end the pull.
+    pub fn new() -> Self {
+        let redirects = reqwest::redirect::Policy::custom(|attempt| {
+            let downgrade = attempt.url().scheme() != "https"
+                && attempt.previous().iter().any(|url| url.scheme() == "https");
+            let host = attempt.url().host_str().map(|host| {
+                host.trim_start_matches('[')
+                    .trim_end_matches(']')
+                    .to_string()
+            });
+            if attempt.previous().len() > MAX_OCI_REDIRECTS {
+                attempt.error("too many redirects")
+            } else if downgrade {
+                attempt.error("redirect downgrades to plain HTTP")
+            } else if host.map_or(true, |host| is_internal_host(&host)) {
+                attempt.error("redirect to an internal host")
+            } else {
+                attempt.follow()
+            }
+        });
+
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .redirect(redirects)
+                .build()
+                .unwrap_or_default(),
+            bearer_token: None,
+            plain_http: false,
+        }
+    }
+
+    /// Authenticate pulls with a bearer token
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    /// Use plain HTTP instead of HTTPS
+    ///
+    /// Internal registry hosts are refused either way.
+    pub fn with_plain_http(mut self, plain_http: bool) -> Self {
+        self.plain_http = plain_http;
+        self
+    }
+
+    /// GET `url`, reading at most [`MAX_MANIFEST_BYTES`] of the body
+    async fn get(&self, url: &str, accept: &str) -> ServiceResult<Vec<u8>> {
+        let mut request = self.client.get(url).header(reqwest::header::ACCEPT, accept);
+        if let Some(ref token) = self.bearer_token {
+            request = request.bearer_auth(token);
+        }
+
+        let mut response = request
+            .send()
+            .await
+            .map_err(|e| ServiceError::SourceUnavailable(format!("GET {} failed: {}", url, e)))?;
+
+        if !response.status().is_success() {
+            return Err(ServiceError::SourceUnavailable(format!(
+                "GET {} returned {}",
+                url,
+                response.status()
+            )));
+        }
+
+        let too_large = || {
+            ServiceError::ValidationFailed(format!(
+                "GET {} returned more than {} bytes",
+                url, MAX_MANIFEST_BYTES
+            ))
+        };
+        if response.content_length().unwrap_or(0) > MAX_MANIFEST_BYTES as u64 {
+            return Err(too_large());
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| ServiceError::SourceUnavailable(format!("GET {} failed: {}", url, e)))?
+        {
+            if body.len() + chunk.len() > MAX_MANIFEST_BYTES {
+                return Err(too_large());
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+}
+
+impl Default for OciSourceFetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reject OCI reference values that reach internal endpoints or fall
+/// outside the distribution spec's grammar, so they cannot alter the
+/// request path
+fn validate_oci_reference(
+    registry: &str,
+    repository: &str,
+    tag: Option<&str>,
+    digest: Option<&str>,
+) -> ServiceResult<()> {
+    let registry_valid = !registry.is_empty()
+        && registry
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':' | '[' | ']'));
+    if !registry_valid {
+        return Err(ServiceError::InvalidInput(format!(
+            "Invalid OCI registry: '{}'",
+            registry
+        )));
+    }
+    let host = authority_host(registry);
+    if is_internal_host(host) {
+        return Err(ServiceError::InvalidInput(format!(
+            "OCI registry host is not allowed: '{}'",
+            host
+        )));
+    }
+
+    if !repository.split('/').all(is_oci_path_component) {
+        return Err(ServiceError::InvalidInput(format!(
+            "Invalid OCI repository: '{}'",
+            repository
+        )));
+    }
+
+    if let Some(tag) = tag {
+        let tag_valid = tag.len() <= 128
+            && tag
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+        if !tag_valid {
+            return Err(ServiceError::InvalidInput(format!(
+                "Invalid OCI tag: '{}'",
+                tag
+            )));
+        }
+    }
+
+    if let Some(digest) = digest {
+        if !is_sha256_digest(digest) {
+            return Err(ServiceError::InvalidInput(format!(
+                "Invalid OCI digest: '{}'",
+                digest
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a repository path component matches
+/// `[a-z0-9]+((\.|_|__|-+)[a-z0-9]+)*`
+fn is_oci_path_component(component: &str) -> bool {
+    let is_separator = |c: char| matches!(c, '.' | '_' | '-');
+    if !component.starts_with(|c: char| c.is_ascii_lowercase() || c.is_ascii_digit()) {
+        return false;
+    }
+
+    let mut separator = String::new();
+    for c in component.chars() {
+        if is_separator(c) {
+            separator.push(c);
+            continue;
+        }
+        if !(c.is_ascii_lowercase() || c.is_ascii_digit()) {
+            return false;
+        }
+        let valid = matches!(separator.as_str(), "" | "." | "_" | "__")
+            || separator.chars().all(|s| s == '-');
+        if !valid {
+            return false;
+        }
+        separator.clear();
+    }
+    separator.is_empty()
+}
+
+/// Whether `digest` is a `sha256:` digest with 64 hex characters
+fn is_sha256_digest(digest: &str) -> bool {
+    digest
+        .strip_prefix("sha256:")
+        .is_some_and(|hex| hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Compute the OCI digest (`sha256:<hex>`) of content
+fn sha256_digest(content: &[u8]) -> String {
+    format!("sha256:{:x}", Sha256::digest(content))
+}
+
+/// Verify content against an expected OCI digest
+fn verify_digest(expected: &str, content: &[u8]) -> ServiceResult<String> {
+    let actual = sha256_digest(content);
+    if !expected.eq_ignore_ascii_case(&actual) {
+        return Err(ServiceError::ChecksumVerificationFailed(format!(
+            "expected {}, got {}",
+            expected, actual
+        )));
+    }
+    Ok(actual)
+}
+
+/// Find the digest of the asset manifest blob within an OCI image manifest
+fn find_manifest_layer(image_manifest: &serde_json::Value) -> Option<String> {
+    let is_asset_manifest = |descriptor: &&serde_json::Value| {
+        descriptor.get("mediaType").and_then(|m| m.as_str()) == Some(ASSET_MANIFEST_MEDIA_TYPE)
+    };
+
+    image_manifest
+        .get("layers")
+        .and_then(|layers| layers.as_array())
+        .and_then(|layers| layers.iter().find(is_asset_manifest))
+        .or_else(|| image_manifest.get("config").filter(is_asset_manifest))
+        .and_then(|descriptor| descriptor.get("digest"))
+        .and_then(|digest| digest.as_str())
+        .map(str::to_string)
+}
+
+#[async_trait]
+impl SourceFetcher for OciSourceFetcher {
+    #[instrument(skip(self))]
+    async fn fetch(&self, reference: &SourceReference) -> ServiceResult<FetchedManifest> {
+        let SourceReference::Oci {
+            registry,
+            repository,
+            tag,
+            digest,
+        } = reference
+        else {
+            return Err(ServiceError::InvalidInput(format!(
+                "OCI fetcher cannot handle {} references",
+                reference.kind()
+            )));
+        };
+
+        validate_oci_reference(registry, repository, tag.as_deref(), digest.as_deref())?;
+        ensure_public_host(authority_host(registry)).await?;
+
+        let scheme = if self.plain_http { "http" } else { "https" };
+        let base = format!("{}://{}/v2/{}", scheme, registry, repository);
+        let target = digest.as_deref().or(tag.as_deref()).unwrap_or("latest");
+
+        debug!(reference = %reference, "Fetching OCI manifest");
+        let manifest_bytes = self
+            .get(
+                &format!("{}/manifests/{}", base, target),
+                "application/vnd.oci.image.manifest.v1+json",
+            )
+            .await?;
+
+        let resolved_digest = match digest {
+            Some(expected) => verify_digest(expected, &manifest_bytes)?,
+            None => sha256_digest(&manifest_bytes),
+        };
+
+        let image_manifest: serde_json::Value = serde_json::from_slice(&manifest_bytes)
+            .map_err(|e| ServiceError::ValidationFailed(format!("Invalid OCI manifest: {}", e)))?;
+        let layer_digest = find_manifest_layer(&image_manifest).ok_or_else(|| {
+            ServiceError::ValidationFailed(format!(
+                "OCI artifact has no layer of type {}",
+                ASSET_MANIFEST_MEDIA_TYPE
+            ))
+        })?;
+        if !is_sha256_digest(&layer_digest) {
+            return Err(ServiceError::ValidationFailed(format!(
+                "Invalid asset manifest layer digest: '{}'",
+                layer_digest
+            )));
+        }
+
+        let content = self
+            .get(
+                &format!("{}/blobs/{}", base, layer_digest),
+                ASSET_MANIFEST_MEDIA_TYPE,
+            )
+            .await?;
+        verify_digest(&layer_digest, &content)?;
+
+        Ok(FetchedManifest {
+            resolved_digest,
+            content,
+        })
+    }
+}
+
+// ============================================================================
+// Dispatch and registration
+// ============================================================================
+
+/// Fetcher that dispatches to the Git or OCI fetcher by reference kind
+#[derive(Debug, Clone, Default)]
+pub struct DefaultSourceFetcher {
+    git: GitSourceFetcher,
+    oci: OciSourceFetcher,
+}
+
+impl DefaultSourceFetcher {
+    /// Create a new dispatching fetcher
+    pub fn new(git: GitSourceFetcher, oci: OciSourceFetcher) -> Self {
+        Self { git, oci }
+    }
+}
+
+#[async_trait]
+impl SourceFetcher for DefaultSourceFetcher {
+    async fn fetch(&self, reference: &SourceReference) -> ServiceResult<FetchedManifest> {
+        match reference {
+            SourceReference::Git { .. } => self.git.fetch(reference).await,
+            SourceReference::Oci { .. } => self.oci.fetch(reference).await,
+        }
+    }
+}
+
+/// Default implementation of SourceRegistrationService
+pub struct DefaultSourceRegistrationService {
+    registration: Arc<dyn RegistrationService>,
+    fetcher: Arc<dyn SourceFetcher>,
+}
+
+impl DefaultSourceRegistrationService {
+    /// Create a new source registration service
+    pub fn new(
+        registration: Arc<dyn RegistrationService>,
+        fetcher: Arc<dyn SourceFetcher>,
+    ) -> Self {
+        Self {
+            registration,
+            fetcher,
+        }
+    }
+
+    /// Reject references that cannot be fetched reproducibly
+    fn validate_reference(reference: &SourceReference) -> ServiceResult<()> {
+        match reference {
+            SourceReference::Git {
+                url,
+                rev,
+                commit,
+                path,
+            } => {
+                if url.trim().is_empty() {
+                    return Err(ServiceError::InvalidInput(
+                        "Git url is required".to_string(),
+                    ));
+                }
+                let target = commit.as_deref().or(rev.as_deref()).unwrap_or("HEAD");
+                validate_git_reference(url, target, path)?;
+                if path.trim().is_empty() || path.starts_with('/') {
+                    return Err(ServiceError::InvalidInput(format!(
+                        "Invalid manifest path: '{}'",
+                        path
+                    )));
+                }
+                if let Some(commit) = commit {
+                    // Servers only fetch full object ids, and only those pin reproducibly
+                    let full = commit.len() == 40 || commit.len() == 64;
+                    if !full || !commit.chars().all(|c| c.is_ascii_hexdigit()) {
+                        return Err(ServiceError::InvalidInput(format!(
+                            "Commit must be a full 40 or 64 character SHA: '{}'",
+                            commit
+                        )));
+                    }
+                }
+            }
+            SourceReference::Oci {
+                registry,
+                repository,
+                tag,
+                digest,
+            } => {
+                if registry.trim().is_empty() || repository.trim().is_empty() {
+                    return Err(ServiceError::InvalidInput(
+                        "OCI registry and repository are required".to_string(),
+                    ));
+                }
+                validate_oci_reference(registry, repository, tag.as_deref(), digest.as_deref())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Map a fetched manifest into a registration request
+    fn map_manifest(
+        reference: &SourceReference,
+        manifest: &FetchedManifest,
+    ) -> ServiceResult<RegisterAssetRequest> {
+        let mut request: RegisterAssetRequest =
+            serde_yaml::from_slice(&manifest.content).map_err(|e| {
+                ServiceError::ValidationFailed(format!("Invalid asset manifest: {}", e))
+            })?;
+
+        request.annotations.insert(
+            SOURCE_KIND_ANNOTATION.to_string(),
+            reference.kind().to_string(),
+        );
+        request
+            .annotations
+            .insert(SOURCE_REF_ANNOTATION.to_string(), reference.to_string());
+        request.annotations.insert(
+            SOURCE_DIGEST_ANNOTATION.to_string(),
+            manifest.resolved_digest.clone(),
+        );
+        // Registering from a reference always persists
+        request.dry_run = None;
+
+        Ok(request)
+    }
+}
+
+#[async_trait]
+impl SourceRegistrationService for DefaultSourceRegistrationService {
+    #[instrument(skip(self, request), fields(reference = %request.reference))]
+    async fn register_from_ref(
+        &self,
+        request: RegisterFromRefRequest,
+    ) -> ServiceResult<RegisterFromRefResponse> {
+        Self::validate_reference(&request.reference)?;
+
+        let manifest = self.fetcher.fetch(&request.reference).await?;
+        let register_request = Self::map_manifest(&request.reference, &manifest)?;
+
+        let response = self.registration.register_asset(register_request).await?;
+
+        info!(
+            asset_id = %response.asset.id,
+            resolved_digest = %manifest.resolved_digest,
+            "Asset registered from source reference"
+        );
+
+        Ok(RegisterFromRefResponse {
+            asset: response.asset,
+            reference: request.reference,
+            resolved_digest: manifest.resolved_digest,
+            warnings: response.warnings,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+asset_type: model
+name: sentiment-classifier
+version: 1.2.0
+tags: [nlp]
+storage:
+  backend:
+    type: s3
+    bucket: models
+    region: us-east-1
+  path: sentiment/1.2.0/model.bin
+checksum:
+  algorithm: SHA256
+  value: "0000000000000000000000000000000000000000000000000000000000000000"
+"#;
+
+    fn git_ref(commit: Option<&str>) -> SourceReference {
+        SourceReference::Git {
+            url: "https://example.com/models.git".to_string(),
+            rev: None,
+            commit: commit.map(str::to_string),
+            path: "asset.yaml".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_map_manifest_records_source() {
+        let reference = git_ref(Some("0123abc"));
+        let manifest = FetchedManifest {
+            resolved_digest: "0123abcdef".to_string(),
+            content: MANIFEST.as_bytes().to_vec(),
+        };
+
+        let request =
+            DefaultSourceRegistrationService::map_manifest(&reference, &manifest).unwrap();
+        assert_eq!(request.name, "sentiment-classifier");
+        assert_eq!(request.annotations[SOURCE_KIND_ANNOTATION], "git");
+        assert_eq!(request.annotations[SOURCE_DIGEST_ANNOTATION], "0123abcdef");
+    }
+
+    #[test]
+    fn test_map_manifest_rejects_garbage() {
+        let manifest = FetchedManifest {
+            resolved_digest: "abc".to_string(),
+            content: b"not: [a, manifest".to_vec(),
+        };
+
+        let result = DefaultSourceRegistrationService::map_manifest(&git_ref(None), &manifest);
+        assert!(matches!(result, Err(ServiceError::ValidationFailed(_))));
+    }
+
+    #[test]
+    fn test_validate_reference() {
+        let full = "0123abcdef0123abcdef0123abcdef0123abcdef";
+        assert!(DefaultSourceRegistrationService::validate_reference(&git_ref(Some(full))).is_ok());
+        assert!(
+            DefaultSourceRegistrationService::validate_reference(&git_ref(Some("0123abc")))
+                .is_err()
+        );
+        assert!(
+            DefaultSourceRegistrationService::validate_reference(&git_ref(Some("main"))).is_err()
+        );
+
+        let bad_digest = SourceReference::Oci {
+            registry: "ghcr.io".to_string(),
+            repository: "org/model".to_string(),
+            tag: None,
+            digest: Some("sha256:1234".to_string()),
+        };
+        assert!(DefaultSourceRegistrationService::validate_reference(&bad_digest).is_err());
+    }
+
+    #[test]
+    fn test_validate_reference_rejects_unsafe_git_values() {
+        let git = |url: &str, rev: Option<&str>, path: &str| SourceReference::Git {
+            url: url.to_string(),
+            rev: rev.map(str::to_string),
+            commit: None,
+            path: path.to_string(),
+        };
+
+        let rejected = [
+            git("--upload-pack=touch /tmp/pwned", None, "asset.yaml"),
+            git("file:///etc", None, "asset.yaml"),
+            git("ssh://example.com/models.git", None, "asset.yaml"),
+            git("https://localhost/models.git", None, "asset.yaml"),
+            git("https://127.0.0.1:8080/models.git", None, "asset.yaml"),
+            git("https://user@10.0.0.5/models.git", None, "asset.yaml"),
+            git("https://[::1]/models.git", None, "asset.yaml"),
+            git(
+                "https://example.com/models.git",
+                Some("--upload-pack=id"),
+                "asset.yaml",
+            ),
+            git("https://example.com/models.git", None, "../secrets.yaml"),
+        ];
+        for reference in &rejected {
+            assert!(
+                matches!(
+                    DefaultSourceRegistrationService::validate_reference(reference),
+                    Err(ServiceError::InvalidInput(_))
+                ),
+                "{} should be rejected",
+                reference
+            );
+        }
+
+        let accepted = git(
+            "HTTPS://example.com/models.git",
+            Some("release/v1"),
+            "models/asset.yaml",
+        );
+        assert!(DefaultSourceRegistrationService::validate_reference(&accepted).is_ok());
+    }
+
+    #[test]
+    fn test_validate_reference_rejects_unsafe_oci_values() {
+        let oci = |registry: &str, repository: &str, tag: Option<&str>| SourceReference::Oci {
+            registry: registry.to_string(),
+            repository: repository.to_string(),
+            tag: tag.map(str::to_string),
+            digest: None,
+        };
+
+        let rejected = [
+            oci("169.254.169.254", "latest/meta-data", None),
+            oci("localhost:5432", "org/model", None),
+            oci("10.0.0.5", "org/model", None),
+            oci("[::1]:5000", "org/model", None),
+            oci("ghcr.io/evil", "org/model", None),
+            oci("user@ghcr.io", "org/model", None),
+            oci("ghcr.io", "org/../admin", None),
+            oci("ghcr.io", "org/model?x=1", None),
+            oci("ghcr.io", "org/model#frag", None),
+            oci("ghcr.io", "Org/Model", None),
+            oci("ghcr.io", "org//model", None),
+            oci("ghcr.io", "org/model.", None),
+            oci("ghcr.io", "org/model", Some("v1/../../x")),
+            oci("ghcr.io", "org/model", Some(".hidden")),
+        ];
+        for reference in &rejected {
+            assert!(
+                matches!(
+                    DefaultSourceRegistrationService::validate_reference(reference),
+                    Err(ServiceError::InvalidInput(_))
+                ),
+                "{} should be rejected",
+                reference
+            );
+        }
+
+        for accepted in [
+            oci("ghcr.io", "org/sentiment-classifier", Some("v1.2.0")),
+            oci(
+                "registry.example.com:5000",
+                "a__b/c--d/e.f",
+                Some("_latest"),
+            ),
+        ] {
+            assert!(DefaultSourceRegistrationService::validate_reference(&accepted).is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oci_fetcher_rejects_internal_registry_before_fetching() {
+        let reference = SourceReference::Oci {
+            registry: "169.254.169.254".to_string(),
+            repository: "org/model".to_string(),
+            tag: None,
+            digest: None,
+        };
+        assert!(matches!(
+            OciSourceFetcher::new().fetch(&reference).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_oci_get_caps_body_size() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/large"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_bytes(vec![b'a'; MAX_MANIFEST_BYTES + 1]),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(path("/small"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(b"{}".to_vec()))
+            .mount(&server)
+            .await;
+
+        let fetcher = OciSourceFetcher::new();
+        assert!(matches!(
+            fetcher.get(&format!("{}/large", server.uri()), "*/*").await,
+            Err(ServiceError::ValidationFailed(_))
+        ));
+        assert_eq!(
+            fetcher
+                .get(&format!("{}/small", server.uri()), "*/*")
+                .await
+                .unwrap(),
+            b"{}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oci_get_refuses_redirect_to_internal_host() {
+        use wiremock::matchers::path;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(path("/manifest"))
+            .respond_with(
+                ResponseTemplate::new(302)
+                    .insert_header("Location", "http://169.254.169.254/latest/meta-data"),
+            )
+            .mount(&server)
+            .await;
+
+        let result = OciSourceFetcher::new()
+            .get(&format!("{}/manifest", server.uri()), "*/*")
+            .await;
+        assert!(matches!(result, Err(ServiceError::SourceUnavailable(_))));
+    }
+
+    #[tokio::test]
+    async fn test_git_fetcher_rejects_option_injection_before_running_git() {
+        // A missing binary would surface as SourceUnavailable if git were run
+        let fetcher = GitSourceFetcher {
+            git_binary: PathBuf::from("/nonexistent/git"),
+            work_dir: std::env::temp_dir(),
+        };
+
+        let reference = SourceReference::Git {
+            url: "--upload-pack=touch /tmp/pwned".to_string(),
+            rev: None,
+            commit: None,
+            path: "asset.yaml".to_string(),
+        };
+        assert!(matches!(
+            fetcher.fetch(&reference).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+
+        let reference = SourceReference::Git {
+            url: "https://example.com/models.git".to_string(),
+            rev: Some("-oProxyCommand=id".to_string()),
+            commit: None,
+            path: "asset.yaml".to_string(),
+        };
+        assert!(matches!(
+            fetcher.fetch(&reference).await,
+            Err(ServiceError::InvalidInput(_))
+        ));
+
+        let reference = git_ref(None);
+        assert!(matches!(
+            fetcher.fetch(&reference).await,
+            Err(ServiceError::SourceUnavailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_public_host_rejects_alternate_internal_forms() {
+        for host in [
+            "localhost",
+            "2130706433",
+            "0x7f000001",
+            "127.1",
+            "0177.0.0.1",
+            "0",
+        ] {
+            assert!(
+                matches!(
+                    ensure_public_host(host).await,
+                    Err(ServiceError::InvalidInput(_))
+                ),
+                "{} was not rejected",
+                host
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_git_output_is_capped() {
+        let dir = std::env::temp_dir().join(format!("llm-registry-git-cap-{}", std::process::id()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        tokio::fs::write(dir.join("large.yaml"), vec![b'a'; MAX_MANIFEST_BYTES + 1])
+            .await
+            .unwrap();
+        tokio::fs::write(dir.join("small.yaml"), b"name: small\n")
+            .await
+            .unwrap();
+
+        let fetcher = GitSourceFetcher::new();
+        fetcher.git(&dir, &["init", "--quiet"]).await.unwrap();
+        fetcher.git(&dir, &["add", "."]).await.unwrap();
+        fetcher
+            .git(
+                &dir,
+                &[
+                    "-c",
+                    "user.name=test",
+                    "-c",
+                    "user.email=test@example.com",
+                    "commit",
+                    "--quiet",
+                    "-m",
+                    "manifests",
+                ],
+            )
+            .await
+            .unwrap();
+
+        let large = fetcher.git(&dir, &["show", "HEAD:large.yaml"]).await;
+        let small = fetcher.git(&dir, &["show", "HEAD:small.yaml"]).await;
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+
+        assert!(matches!(large, Err(ServiceError::ValidationFailed(_))));
+        assert_eq!(small.unwrap(), b"name: small\n");
+    }
+
+    #[test]
+    fn test_verify_digest() {
+        let content = b"manifest";
+        let digest = sha256_digest(content);
+        assert_eq!(verify_digest(&digest, content).unwrap(), digest);
+        assert!(matches!(
+            verify_digest(&digest, b"tampered"),
+            Err(ServiceError::ChecksumVerificationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn test_find_manifest_layer() {
+        let manifest = serde_json::json!({
+            "config": {"mediaType": "application/vnd.oci.empty.v1+json", "digest": "sha256:aaa"},
+            "layers": [
+                {"mediaType": "application/octet-stream", "digest": "sha256:bbb"},
+                {"mediaType": ASSET_MANIFEST_MEDIA_TYPE, "digest": "sha256:ccc"}
+            ]
+        });
+        assert_eq!(
+            find_manifest_layer(&manifest).as_deref(),
+            Some("sha256:ccc")
+        );
+    }
+
+    #[test]
+    fn test_source_reference_serde() {
+        let json = serde_json::json!({
+            "kind": "oci",
+            "registry": "ghcr.io",
+            "repository": "org/model",
+            "tag": "v1"
+        });
+        let reference: SourceReference = serde_json::from_value(json).unwrap();
+        assert_eq!(reference.to_string(), "ghcr.io/org/model:v1");
+
+        let git: SourceReference =
+            serde_json::from_value(serde_json::json!({"kind": "git", "url": "u"})).unwrap();
+        assert!(matches!(git, SourceReference::Git { ref path, .. } if path == "asset.yaml"));
+    }
+}
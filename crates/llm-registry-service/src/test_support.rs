@@ -0,0 +1,202 @@
+//! In-memory backends for service tests
+
+use async_trait::async_trait;
+use llm_registry_core::{Asset, AssetId, AssetType, RegistryEvent, Version};
+use llm_registry_db::{
+    AssetRepository, DbResult, EventQuery, EventQueryResults, EventStore, SearchQuery,
+    SearchResults,
+};
+use std::sync::Mutex;
+
+/// Asset repository backed by a vector
+///
+/// Searches page through the assets in insertion order without filtering.
+#[derive(Default)]
+pub struct InMemoryRepository {
+    pub assets: Mutex<Vec<Asset>>,
+}
+
+impl InMemoryRepository {
+    /// Repository holding `assets`
+    pub fn with_assets(assets: Vec<Asset>) -> Self {
+        Self {
+            assets: Mutex::new(assets),
+        }
+    }
+}
+
+#[async_trait]
+impl AssetRepository for InMemoryRepository {
+    async fn create(&self, asset: Asset) -> DbResult<Asset> {
+        self.assets.lock().unwrap().push(asset.clone());
+        Ok(asset)
+    }
+
+    async fn find_by_id(&self, id: &AssetId) -> DbResult<Option<Asset>> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|a| a.id == *id)
+            .cloned())
+    }
+
+    async fn find_by_name_and_version(
+        &self,
+        name: &str,
+        version: &Version,
+    ) -> DbResult<Option<Asset>> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|a| a.metadata.name == name && a.metadata.version == *version)
+            .cloned())
+    }
+
+    async fn find_by_ids(&self, ids: &[AssetId]) -> DbResult<Vec<Asset>> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| ids.contains(&a.id))
+            .cloned()
+            .collect())
+    }
+
+    async fn search(&self, query: &SearchQuery) -> DbResult<SearchResults> {
+        let assets = self.assets.lock().unwrap();
+        let page: Vec<Asset> = assets
+            .iter()
+            .skip(query.offset as usize)
+            .take(query.limit as usize)
+            .cloned()
+            .collect();
+        Ok(SearchResults {
+            total: assets.len() as i64,
+            assets: page,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn update(&self, asset: Asset) -> DbResult<Asset> {
+        let mut assets = self.assets.lock().unwrap();
+        if let Some(stored) = assets.iter_mut().find(|a| a.id == asset.id) {
+            *stored = asset.clone();
+        }
+        Ok(asset)
+    }
+
+    async fn delete(&self, id: &AssetId) -> DbResult<()> {
+        self.assets.lock().unwrap().retain(|a| a.id != *id);
+        Ok(())
+    }
+
+    async fn list_versions(&self, name: &str) -> DbResult<Vec<Asset>> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.metadata.name == name)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_dependencies(&self, _: &AssetId) -> DbResult<Vec<Asset>> {
+        Ok(vec![])
+    }
+
+    async fn list_reverse_dependencies(&self, _: &AssetId) -> DbResult<Vec<Asset>> {
+        Ok(vec![])
+    }
+
+    async fn add_tag(&self, _: &AssetId, _: &str) -> DbResult<()> {
+        Ok(())
+    }
+
+    async fn remove_tag(&self, _: &AssetId, _: &str) -> DbResult<()> {
+        Ok(())
+    }
+
+    async fn get_tags(&self, _: &AssetId) -> DbResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn list_all_tags(&self) -> DbResult<Vec<String>> {
+        Ok(vec![])
+    }
+
+    async fn add_dependency(&self, _: &AssetId, _: &AssetId, _: Option<&str>) -> DbResult<()> {
+        Ok(())
+    }
+
+    async fn remove_dependency(&self, _: &AssetId, _: &AssetId) -> DbResult<()> {
+        Ok(())
+    }
+
+    async fn count_assets(&self) -> DbResult<i64> {
+        Ok(self.assets.lock().unwrap().len() as i64)
+    }
+
+    async fn count_by_type(&self, asset_type: &AssetType) -> DbResult<i64> {
+        Ok(self
+            .assets
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|a| a.asset_type == *asset_type)
+            .count() as i64)
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        Ok(())
+    }
+}
+
+/// Event store that accepts and discards every event
+pub struct NullEventStore;
+
+#[async_trait]
+impl EventStore for NullEventStore {
+    async fn append(&self, event: RegistryEvent) -> DbResult<RegistryEvent> {
+        Ok(event)
+    }
+
+    async fn append_batch(&self, events: Vec<RegistryEvent>) -> DbResult<Vec<RegistryEvent>> {
+        Ok(events)
+    }
+
+    async fn query(&self, query: &EventQuery) -> DbResult<EventQueryResults> {
+        Ok(EventQueryResults {
+            events: vec![],
+            total: 0,
+            offset: query.offset,
+            limit: query.limit,
+        })
+    }
+
+    async fn get_asset_events(&self, _: &AssetId, _: i64) -> DbResult<Vec<RegistryEvent>> {
+        Ok(vec![])
+    }
+
+    async fn get_latest_event(&self, _: &AssetId) -> DbResult<Option<RegistryEvent>> {
+        Ok(None)
+    }
+
+    async fn count_events(&self) -> DbResult<i64> {
+        Ok(0)
+    }
+
+    async fn count_by_type(&self, _: &str) -> DbResult<i64> {
+        Ok(0)
+    }
+
+    async fn health_check(&self) -> DbResult<()> {
+        Ok(())
+    }
+}
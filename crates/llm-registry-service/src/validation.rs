@@ -4,7 +4,8 @@
 //! policy validation, and business rule enforcement.
 
 use async_trait::async_trait;
-use llm_registry_core::{Asset, AssetId, AssetType, EventType, RegistryEvent};
+use llm_registry_core::labels::reserved_char;
+use llm_registry_core::{Asset, AssetId, AssetType, EventType, Labels, RegistryEvent};
 use llm_registry_db::{AssetRepository, EventStore};
 use std::sync::Arc;
 use tracing::{debug, instrument, warn};
@@ -12,11 +13,49 @@ use tracing::{debug, instrument, warn};
 use crate::dto::{ValidateAssetRequest, ValidationError, ValidationResult, ValidationWarning};
 use crate::error::{ServiceError, ServiceResult};
 
+/// Longest label key or value accepted
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// Errors for labels that cannot be expressed in a label selector
+pub(crate) fn label_errors(labels: &Labels) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    for (key, value) in labels {
+        if key.is_empty() {
+            errors.push(ValidationError {
+                field: "metadata.labels".to_string(),
+                message: "Label key cannot be empty".to_string(),
+                code: Some("LABEL_KEY_EMPTY".to_string()),
+            });
+        }
+        if key.len() > MAX_LABEL_LENGTH || value.len() > MAX_LABEL_LENGTH {
+            errors.push(ValidationError {
+                field: format!("metadata.labels.{}", key),
+                message: format!(
+                    "Label keys and values cannot exceed {} characters",
+                    MAX_LABEL_LENGTH
+                ),
+                code: Some("LABEL_TOO_LONG".to_string()),
+            });
+        }
+        if let Some(c) = reserved_char(key).or_else(|| reserved_char(value)) {
+            errors.push(ValidationError {
+                field: format!("metadata.labels.{}", key),
+                message: format!("Label keys and values cannot contain '{}'", c),
+                code: Some("LABEL_INVALID_CHARACTER".to_string()),
+            });
+        }
+    }
+    errors
+}
+
 /// Trait for validation operations
 #[async_trait]
 pub trait ValidationService: Send + Sync {
     /// Validate an asset according to schema and business rules
-    async fn validate_asset(&self, request: ValidateAssetRequest) -> ServiceResult<ValidationResult>;
+    async fn validate_asset(
+        &self,
+        request: ValidateAssetRequest,
+    ) -> ServiceResult<ValidationResult>;
 
     /// Validate asset metadata
     async fn validate_metadata(&self, asset: &Asset) -> ServiceResult<ValidationResult>;
@@ -25,7 +64,11 @@ pub trait ValidationService: Send + Sync {
     async fn validate_dependencies(&self, asset: &Asset) -> ServiceResult<ValidationResult>;
 
     /// Apply policy validation
-    async fn validate_policy(&self, asset: &Asset, policy_name: &str) -> ServiceResult<ValidationResult>;
+    async fn validate_policy(
+        &self,
+        asset: &Asset,
+        policy_name: &str,
+    ) -> ServiceResult<ValidationResult>;
 
     /// Validate all policies for an asset
     async fn validate_all_policies(&self, asset: &Asset) -> ServiceResult<ValidationResult>;
@@ -47,7 +90,13 @@ impl DefaultValidationService {
     }
 
     /// Emit policy validation event
-    async fn emit_policy_event(&self, asset_id: AssetId, policy_name: String, passed: bool, message: Option<String>) {
+    async fn emit_policy_event(
+        &self,
+        asset_id: AssetId,
+        policy_name: String,
+        passed: bool,
+        message: Option<String>,
+    ) {
         let event = RegistryEvent::new(EventType::PolicyValidated {
             asset_id,
             policy_name,
@@ -166,6 +215,9 @@ impl DefaultValidationService {
             }
         }
 
+        // Validate labels; keys and values must be expressible in a label selector
+        errors.extend(label_errors(&asset.metadata.labels));
+
         // Validate asset type
         if let AssetType::Custom(ref name) = asset.asset_type {
             if name.is_empty() {
@@ -198,7 +250,12 @@ impl DefaultValidationService {
         } else if let Some(ref license) = asset.metadata.license {
             // List of approved licenses (example)
             let approved_licenses = vec![
-                "MIT", "Apache-2.0", "GPL-3.0", "BSD-3-Clause", "ISC", "CC0-1.0",
+                "MIT",
+                "Apache-2.0",
+                "GPL-3.0",
+                "BSD-3-Clause",
+                "ISC",
+                "CC0-1.0",
             ];
 
             if !approved_licenses.iter().any(|&l| license.contains(l)) {
@@ -219,25 +276,17 @@ impl DefaultValidationService {
         }
     }
 
-    /// Validate size constraints (example policy)
+    /// Warn about very large assets
+    ///
+    /// The size limit itself comes from the configured validation
+    /// constraints and is enforced at registration.
     fn validate_size_policy(&self, asset: &Asset) -> ValidationResult {
-        let mut errors = Vec::new();
         let mut warnings = Vec::new();
 
         if let Some(size) = asset.metadata.size_bytes {
-            const MAX_SIZE: u64 = 10 * 1024 * 1024 * 1024; // 10 GB
             const WARN_SIZE: u64 = 1 * 1024 * 1024 * 1024; // 1 GB
 
-            if size > MAX_SIZE {
-                errors.push(ValidationError {
-                    field: "metadata.size_bytes".to_string(),
-                    message: format!(
-                        "Asset size {} exceeds maximum allowed size of {}",
-                        size, MAX_SIZE
-                    ),
-                    code: Some("SIZE_EXCEEDS_LIMIT".to_string()),
-                });
-            } else if size > WARN_SIZE {
+            if size > WARN_SIZE {
                 warnings.push(ValidationWarning {
                     field: "metadata.size_bytes".to_string(),
                     message: format!("Asset size {} is very large (>1 GB)", size),
@@ -246,8 +295,8 @@ impl DefaultValidationService {
         }
 
         ValidationResult {
-            valid: errors.is_empty(),
-            errors,
+            valid: true,
+            errors: Vec::new(),
             warnings,
         }
     }
@@ -273,7 +322,10 @@ impl DefaultValidationService {
 #[async_trait]
 impl ValidationService for DefaultValidationService {
     #[instrument(skip(self, request))]
-    async fn validate_asset(&self, request: ValidateAssetRequest) -> ServiceResult<ValidationResult> {
+    async fn validate_asset(
+        &self,
+        request: ValidateAssetRequest,
+    ) -> ServiceResult<ValidationResult> {
         debug!("Validating asset: {}", request.asset.id);
 
         let mut results = Vec::new();
@@ -377,7 +429,11 @@ impl ValidationService for DefaultValidationService {
     }
 
     #[instrument(skip(self, asset), fields(asset_id = %asset.id, policy = %policy_name))]
-    async fn validate_policy(&self, asset: &Asset, policy_name: &str) -> ServiceResult<ValidationResult> {
+    async fn validate_policy(
+        &self,
+        asset: &Asset,
+        policy_name: &str,
+    ) -> ServiceResult<ValidationResult> {
         debug!("Validating policy: {}", policy_name);
 
         let result = match policy_name {
@@ -426,8 +482,11 @@ impl ValidationService for DefaultValidationService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use llm_registry_core::{AssetMetadata, Checksum, HashAlgorithm, StorageBackend, StorageLocation};
-    use semver::Version;
+    use crate::test_support::{InMemoryRepository, NullEventStore};
+    use llm_registry_core::Version;
+    use llm_registry_core::{
+        AssetMetadata, Checksum, HashAlgorithm, StorageBackend, StorageLocation,
+    };
 
     fn create_test_asset() -> Asset {
         let metadata = AssetMetadata::new("test-asset", Version::parse("1.0.0").unwrap());
@@ -441,20 +500,23 @@ mod tests {
             None,
         )
         .unwrap();
-        let checksum = Checksum::new(
-            HashAlgorithm::SHA256,
-            "a".repeat(64),
+        let checksum = Checksum::new(HashAlgorithm::SHA256, "a".repeat(64)).unwrap();
+
+        Asset::new(
+            AssetId::new(),
+            AssetType::Model,
+            metadata,
+            storage,
+            checksum,
         )
-        .unwrap();
-
-        Asset::new(AssetId::new(), AssetType::Model, metadata, storage, checksum).unwrap()
+        .unwrap()
     }
 
     #[test]
     fn test_validate_schema_valid_asset() {
         let service = DefaultValidationService {
-            repository: Arc::new(MockRepository),
-            event_store: Arc::new(MockEventStore),
+            repository: Arc::new(InMemoryRepository::default()),
+            event_store: Arc::new(NullEventStore),
         };
 
         let asset = create_test_asset();
@@ -466,8 +528,8 @@ mod tests {
     #[test]
     fn test_validate_schema_empty_name() {
         let service = DefaultValidationService {
-            repository: Arc::new(MockRepository),
-            event_store: Arc::new(MockEventStore),
+            repository: Arc::new(InMemoryRepository::default()),
+            event_store: Arc::new(NullEventStore),
         };
 
         let mut asset = create_test_asset();
@@ -482,8 +544,8 @@ mod tests {
     #[test]
     fn test_validate_license_policy() {
         let service = DefaultValidationService {
-            repository: Arc::new(MockRepository),
-            event_store: Arc::new(MockEventStore),
+            repository: Arc::new(InMemoryRepository::default()),
+            event_store: Arc::new(NullEventStore),
         };
 
         let mut asset = create_test_asset();
@@ -492,97 +554,4 @@ mod tests {
         let result = service.validate_license_policy(&asset);
         assert!(result.valid);
     }
-
-    // Mock implementations for testing
-    struct MockRepository;
-    struct MockEventStore;
-
-    #[async_trait]
-    impl AssetRepository for MockRepository {
-        async fn create(&self, _: Asset) -> llm_registry_db::DbResult<Asset> {
-            unimplemented!()
-        }
-        async fn find_by_id(&self, _: &AssetId) -> llm_registry_db::DbResult<Option<Asset>> {
-            Ok(None)
-        }
-        async fn find_by_name_and_version(&self, _: &str, _: &semver::Version) -> llm_registry_db::DbResult<Option<Asset>> {
-            Ok(None)
-        }
-        async fn find_by_ids(&self, _: &[AssetId]) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn search(&self, _: &llm_registry_db::SearchQuery) -> llm_registry_db::DbResult<llm_registry_db::SearchResults> {
-            unimplemented!()
-        }
-        async fn update(&self, asset: Asset) -> llm_registry_db::DbResult<Asset> {
-            Ok(asset)
-        }
-        async fn delete(&self, _: &AssetId) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn list_versions(&self, _: &str) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn list_dependencies(&self, _: &AssetId) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn list_reverse_dependencies(&self, _: &AssetId) -> llm_registry_db::DbResult<Vec<Asset>> {
-            Ok(vec![])
-        }
-        async fn add_tag(&self, _: &AssetId, _: &str) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn remove_tag(&self, _: &AssetId, _: &str) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn get_tags(&self, _: &AssetId) -> llm_registry_db::DbResult<Vec<String>> {
-            Ok(vec![])
-        }
-        async fn list_all_tags(&self) -> llm_registry_db::DbResult<Vec<String>> {
-            Ok(vec![])
-        }
-        async fn add_dependency(&self, _: &AssetId, _: &AssetId, _: Option<&str>) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn remove_dependency(&self, _: &AssetId, _: &AssetId) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-        async fn count_assets(&self) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn count_by_type(&self, _: &AssetType) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn health_check(&self) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-    }
-
-    #[async_trait]
-    impl EventStore for MockEventStore {
-        async fn append(&self, event: RegistryEvent) -> llm_registry_db::DbResult<RegistryEvent> {
-            Ok(event)
-        }
-        async fn append_batch(&self, events: Vec<RegistryEvent>) -> llm_registry_db::DbResult<Vec<RegistryEvent>> {
-            Ok(events)
-        }
-        async fn query(&self, _: &llm_registry_db::EventQuery) -> llm_registry_db::DbResult<llm_registry_db::EventQueryResults> {
-            unimplemented!()
-        }
-        async fn get_asset_events(&self, _: &AssetId, _: i64) -> llm_registry_db::DbResult<Vec<RegistryEvent>> {
-            Ok(vec![])
-        }
-        async fn get_latest_event(&self, _: &AssetId) -> llm_registry_db::DbResult<Option<RegistryEvent>> {
-            Ok(None)
-        }
-        async fn count_events(&self) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn count_by_type(&self, _: &str) -> llm_registry_db::DbResult<i64> {
-            Ok(0)
-        }
-        async fn health_check(&self) -> llm_registry_db::DbResult<()> {
-            Ok(())
-        }
-    }
 }
@@ -5,9 +5,10 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use llm_registry_core::Version;
 use llm_registry_core::{Asset, AssetId, AssetStatus, EventType, RegistryEvent};
 use llm_registry_db::{AssetRepository, EventStore};
-use semver::{Version, VersionReq};
+use semver::VersionReq;
 use std::sync::Arc;
 use tracing::{debug, instrument, warn};
 
@@ -21,10 +22,16 @@ use crate::error::{ServiceError, ServiceResult};
 #[async_trait]
 pub trait VersioningService: Send + Sync {
     /// List all versions of an asset
-    async fn list_versions(&self, request: ListVersionsRequest) -> ServiceResult<ListVersionsResponse>;
+    async fn list_versions(
+        &self,
+        request: ListVersionsRequest,
+    ) -> ServiceResult<ListVersionsResponse>;
 
     /// Check for version conflicts
-    async fn check_version_conflict(&self, request: CheckVersionConflictRequest) -> ServiceResult<VersionConflictResult>;
+    async fn check_version_conflict(
+        &self,
+        request: CheckVersionConflictRequest,
+    ) -> ServiceResult<VersionConflictResult>;
 
     /// Get the latest version of an asset
     async fn get_latest_version(&self, name: &str) -> ServiceResult<Option<Asset>>;
@@ -33,13 +40,20 @@ pub trait VersioningService: Send + Sync {
     async fn find_by_version_req(&self, name: &str, req: &VersionReq) -> ServiceResult<Vec<Asset>>;
 
     /// Deprecate a specific version
-    async fn deprecate_version(&self, asset_id: &AssetId, reason: Option<String>) -> ServiceResult<Asset>;
+    async fn deprecate_version(
+        &self,
+        asset_id: &AssetId,
+        reason: Option<String>,
+    ) -> ServiceResult<Asset>;
 
     /// Check if a version is deprecated
     async fn is_deprecated(&self, asset_id: &AssetId) -> ServiceResult<bool>;
 
     /// Get deprecation info
-    async fn get_deprecation_info(&self, asset_id: &AssetId) -> ServiceResult<Option<DeprecationInfo>>;
+    async fn get_deprecation_info(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<Option<DeprecationInfo>>;
 }
 
 /// Information about a deprecated version
@@ -97,7 +111,10 @@ impl DefaultVersioningService {
 #[async_trait]
 impl VersioningService for DefaultVersioningService {
     #[instrument(skip(self, request), fields(name = %request.name))]
-    async fn list_versions(&self, request: ListVersionsRequest) -> ServiceResult<ListVersionsResponse> {
+    async fn list_versions(
+        &self,
+        request: ListVersionsRequest,
+    ) -> ServiceResult<ListVersionsResponse> {
         debug!("Listing versions for asset: {}", request.name);
 
         // Get all versions from repository
@@ -112,7 +129,10 @@ impl VersioningService for DefaultVersioningService {
         assets = self.sort_versions_desc(assets);
 
         // Convert to VersionInfo
-        let versions: Vec<VersionInfo> = assets.iter().map(|a| self.asset_to_version_info(a)).collect();
+        let versions: Vec<VersionInfo> = assets
+            .iter()
+            .map(|a| self.asset_to_version_info(a))
+            .collect();
 
         // Find latest active version
         let latest = self
@@ -127,8 +147,14 @@ impl VersioningService for DefaultVersioningService {
     }
 
     #[instrument(skip(self, request), fields(name = %request.name, version = %request.version))]
-    async fn check_version_conflict(&self, request: CheckVersionConflictRequest) -> ServiceResult<VersionConflictResult> {
-        debug!("Checking version conflict for {}@{}", request.name, request.version);
+    async fn check_version_conflict(
+        &self,
+        request: CheckVersionConflictRequest,
+    ) -> ServiceResult<VersionConflictResult> {
+        debug!(
+            "Checking version conflict for {}@{}",
+            request.name, request.version
+        );
 
         // Check if this exact version already exists
         match self
@@ -180,7 +206,11 @@ impl VersioningService for DefaultVersioningService {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn deprecate_version(&self, asset_id: &AssetId, reason: Option<String>) -> ServiceResult<Asset> {
+    async fn deprecate_version(
+        &self,
+        asset_id: &AssetId,
+        reason: Option<String>,
+    ) -> ServiceResult<Asset> {
         debug!("Deprecating version");
 
         // Fetch the asset
@@ -224,7 +254,11 @@ impl VersioningService for DefaultVersioningService {
             updated_copy
                 .metadata
                 .add_annotation("deprecation_reason", reason_text);
-            return self.repository.update(updated_copy).await.map_err(Into::into);
+            return self
+                .repository
+                .update(updated_copy)
+                .await
+                .map_err(Into::into);
         }
 
         Ok(updated)
@@ -244,7 +278,10 @@ impl VersioningService for DefaultVersioningService {
     }
 
     #[instrument(skip(self), fields(asset_id = %asset_id))]
-    async fn get_deprecation_info(&self, asset_id: &AssetId) -> ServiceResult<Option<DeprecationInfo>> {
+    async fn get_deprecation_info(
+        &self,
+        asset_id: &AssetId,
+    ) -> ServiceResult<Option<DeprecationInfo>> {
         debug!("Getting deprecation info");
 
         let asset = self
@@ -257,14 +294,9 @@ impl VersioningService for DefaultVersioningService {
             return Ok(None);
         }
 
-        let deprecated_at = asset
-            .deprecated_at
-            .unwrap_or_else(|| asset.updated_at);
+        let deprecated_at = asset.deprecated_at.unwrap_or_else(|| asset.updated_at);
 
-        let reason = asset
-            .metadata
-            .get_annotation("deprecation_reason")
-            .cloned();
+        let reason = asset.metadata.get_annotation("deprecation_reason").cloned();
 
         let alternative = asset
             .metadata
@@ -286,7 +318,10 @@ pub mod utils {
     /// Parse a version requirement string
     pub fn parse_version_req(req_str: &str) -> ServiceResult<VersionReq> {
         VersionReq::parse(req_str).map_err(|e| {
-            ServiceError::ValidationFailed(format!("Invalid version requirement '{}': {}", req_str, e))
+            ServiceError::ValidationFailed(format!(
+                "Invalid version requirement '{}': {}",
+                req_str, e
+            ))
         })
     }
 
@@ -348,8 +383,14 @@ mod tests {
     #[test]
     fn test_parse_version_req() {
         let req = utils::parse_version_req("^1.0.0").unwrap();
-        assert!(utils::version_matches(&Version::parse("1.2.3").unwrap(), &req));
-        assert!(!utils::version_matches(&Version::parse("2.0.0").unwrap(), &req));
+        assert!(utils::version_matches(
+            &Version::parse("1.2.3").unwrap(),
+            &req
+        ));
+        assert!(!utils::version_matches(
+            &Version::parse("2.0.0").unwrap(),
+            &req
+        ));
     }
 
     #[test]
@@ -357,7 +398,10 @@ mod tests {
         let v1 = Version::parse("1.0.0").unwrap();
         let v2 = Version::parse("2.0.0").unwrap();
         assert_eq!(utils::compare_versions(&v1, &v2), std::cmp::Ordering::Less);
-        assert_eq!(utils::compare_versions(&v2, &v1), std::cmp::Ordering::Greater);
+        assert_eq!(
+            utils::compare_versions(&v2, &v1),
+            std::cmp::Ordering::Greater
+        );
         assert_eq!(utils::compare_versions(&v1, &v1), std::cmp::Ordering::Equal);
     }
 
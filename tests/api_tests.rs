@@ -127,7 +127,9 @@ async fn test_cors_headers() {
         .expect("Failed to send request");
 
     // Should have CORS headers
-    assert!(response.headers().contains_key("access-control-allow-origin"));
+    assert!(response
+        .headers()
+        .contains_key("access-control-allow-origin"));
 }
 
 #[tokio::test]
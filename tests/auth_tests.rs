@@ -4,7 +4,7 @@
 
 mod common;
 
-use common::{assert_status, assert_success, TestApp, fixtures::TestUser};
+use common::{assert_status, assert_success, fixtures::TestUser, TestApp};
 use reqwest::StatusCode;
 use serde_json::json;
 
@@ -131,10 +131,7 @@ async fn test_refresh_token() {
         .await
         .expect("Failed to send request");
 
-    let login_body: serde_json::Value = login_response
-        .json()
-        .await
-        .expect("Failed to parse JSON");
+    let login_body: serde_json::Value = login_response.json().await.expect("Failed to parse JSON");
 
     let refresh_token = login_body["data"]["refresh_token"]
         .as_str()
@@ -225,7 +222,10 @@ async fn test_me_endpoint() {
 
     let body: serde_json::Value = response.json().await.expect("Failed to parse JSON");
     assert_eq!(body["data"]["id"], user.id);
-    assert!(body["data"]["roles"].as_array().unwrap().contains(&json!("admin")));
+    assert!(body["data"]["roles"]
+        .as_array()
+        .unwrap()
+        .contains(&json!("admin")));
 }
 
 #[tokio::test]
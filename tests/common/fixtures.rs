@@ -12,7 +12,7 @@ pub fn create_test_asset(name: &str) -> Asset {
     Asset {
         id: llm_registry_core::AssetId::new(),
         name: name.to_string(),
-        version: semver::Version::new(1, 0, 0),
+        version: llm_registry_core::Version::new(1, 0, 0),
         asset_type: AssetType::Model,
         description: Some(format!("Test asset: {}", name)),
         location: format!("file:///tmp/assets/{}", name),
@@ -39,7 +39,8 @@ impl TestApp {
             .with_issuer("test")
             .with_audience("test")
             .with_expiration(3600);
-        let jwt_manager = Arc::new(JwtManager::new(jwt_config).expect("Failed to create JWT manager"));
+        let jwt_manager =
+            Arc::new(JwtManager::new(jwt_config).expect("Failed to create JWT manager"));
 
         // Build router
         let app = build_api_server(services.clone());
@@ -90,12 +91,7 @@ impl TestApp {
 
     /// Generate token with roles
     pub fn generate_token_with_roles(&self, user_id: &str, roles: Vec<String>) -> String {
-        let claims = llm_registry_api::Claims::new(
-            user_id,
-            "test",
-            "test",
-            3600,
-        ).with_roles(roles);
+        let claims = llm_registry_api::Claims::new(user_id, "test", "test", 3600).with_roles(roles);
 
         self.jwt_manager
             .generate_token_with_claims(claims)
@@ -138,11 +134,7 @@ async fn setup_test_database() -> DbPool {
 }
 
 /// Helper to make authenticated GET request
-pub async fn get_with_auth(
-    client: &reqwest::Client,
-    url: &str,
-    token: &str,
-) -> reqwest::Response {
+pub async fn get_with_auth(client: &reqwest::Client, url: &str, token: &str) -> reqwest::Response {
     client
         .get(url)
         .header("Authorization", format!("Bearer {}", token))
@@ -22,9 +22,14 @@ async fn test_rate_limit_enforcement() {
             .await
             .expect("Failed to send request");
 
-        if i < 100 {  // Assuming default limit of 100
+        if i < 100 {
+            // Assuming default limit of 100
             // Should succeed
-            assert!(response.status().is_success() || i >= 100, "Request {} failed", i);
+            assert!(
+                response.status().is_success() || i >= 100,
+                "Request {} failed",
+                i
+            );
         }
     }
 }
@@ -41,8 +46,7 @@ async fn test_rate_limit_headers() {
         .expect("Failed to send request");
 
     // Should have rate limit headers
-    assert!(response.headers().contains_key("x-ratelimit-limit") ||
-            response.status().is_success());
+    assert!(response.headers().contains_key("x-ratelimit-limit") || response.status().is_success());
 }
 
 #[tokio::test]